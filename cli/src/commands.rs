@@ -4,7 +4,7 @@ use std::sync::Arc;
 use std::thread;
 use std::time::Duration;
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use breez_liquid_sdk::model::*;
 use breez_liquid_sdk::sdk::LiquidSdk;
 use clap::{arg, Parser};
@@ -20,6 +20,10 @@ use serde_json::to_string_pretty;
 
 #[derive(Parser, Debug, Clone, PartialEq)]
 pub(crate) enum Command {
+    /// Fetch the current limits and fees of Lightning payments
+    FetchLightningLimits,
+    /// Fetch the recommended fee rates of Liquid and Bitcoin onchain txs
+    FetchRecommendedFees,
     /// Send lbtc and receive btc through a swap
     SendPayment {
         /// Invoice which has to be paid
@@ -28,16 +32,243 @@ pub(crate) enum Command {
         /// Delay for the send, in seconds
         #[arg(short, long)]
         delay: Option<u64>,
+
+        /// Maximum fees, in satoshi
+        #[arg(long)]
+        max_fee_sat: Option<u64>,
+
+        /// Maximum fees, as a percentage of the invoice amount
+        #[arg(long)]
+        max_fee_percent: Option<f64>,
+
+        /// Onchain fee rate: a rate in sat/vbyte, or one of fastest, half-hour, hour, economy
+        /// and minimum
+        #[arg(long, value_parser = parse_fee_rate)]
+        fee_rate: Option<OnchainFeeRate>,
     },
     /// Receive lbtc and send btc through a swap
     ReceivePayment {
+        /// Amount the payer will send, in satoshi
+        payer_amount_sat: Option<u64>,
+
+        /// Amount to receive after fees, in satoshi, instead of the payer amount
+        #[arg(short, long)]
+        receiver_amount_sat: Option<u64>,
+
+        /// Description of the invoice
+        #[arg(short, long)]
+        description: Option<String>,
+
+        /// Hex-encoded SHA256 hash of the invoice description
+        #[arg(long)]
+        description_hash: Option<String>,
+
+        /// Invoice expiry, in seconds
+        #[arg(short, long)]
+        expiry_secs: Option<u64>,
+
+        /// Maximum fees, in satoshi
+        #[arg(long)]
+        max_fee_sat: Option<u64>,
+
+        /// Maximum fees, as a percentage of the invoice amount
+        #[arg(long)]
+        max_fee_percent: Option<f64>,
+
+        /// Onchain fee rate: a rate in sat/vbyte, or one of fastest, half-hour, hour, economy
+        /// and minimum
+        #[arg(long, value_parser = parse_fee_rate)]
+        fee_rate: Option<OnchainFeeRate>,
+    },
+    /// Send lbtc and receive btc onchain through a swap
+    SendOnchainPayment {
+        /// Btc onchain address to send to
+        address: String,
+
+        /// Amount that will be received, in satoshi
+        receiver_amount_sat: u64,
+
+        /// Onchain fee rate: a rate in sat/vbyte, or one of fastest, half-hour, hour, economy
+        /// and minimum
+        #[arg(long, value_parser = parse_fee_rate)]
+        fee_rate: Option<OnchainFeeRate>,
+    },
+    /// Withdraw from an LNURL-withdraw service through a swap
+    LnurlWithdraw {
+        /// LNURL-withdraw to withdraw from
+        lnurl: String,
+
+        /// Amount to withdraw, in satoshi
+        amount_sat: u64,
+    },
+    /// Pay an LNURL-pay or a Lightning Address through a swap
+    LnurlPay {
+        /// LNURL-pay or Lightning Address to pay
+        lnurl: String,
+
+        /// Amount to pay, in satoshi
+        amount_sat: u64,
+
+        /// Comment for the payee
+        #[arg(short, long)]
+        comment: Option<String>,
+    },
+    /// Send lbtc directly to a Liquid address or BIP21 URI
+    SendLiquidPayment {
+        /// Liquid address or BIP21 URI to send to
+        destination: String,
+
+        /// Amount that will be received, in satoshi or in the asset's base units. Optional if
+        /// the BIP21 URI has an amount
+        #[arg(short, long)]
+        amount_sat: Option<u64>,
+
+        /// Send the whole wallet balance, minus fees
+        #[arg(long)]
+        send_all: bool,
+
+        /// Asset ID of the issued asset to send, instead of L-BTC
+        #[arg(long)]
+        asset_id: Option<String>,
+
+        /// Onchain fee rate: a rate in sat/vbyte, or one of fastest, half-hour, hour, economy
+        /// and minimum
+        #[arg(long, value_parser = parse_fee_rate)]
+        fee_rate: Option<OnchainFeeRate>,
+    },
+    /// Receive lbtc and send btc onchain through a swap
+    ReceiveOnchainPayment {
         /// Amount the payer will send, in satoshi
         payer_amount_sat: u64,
+
+        /// Onchain fee rate: a rate in sat/vbyte, or one of fastest, half-hour, hour, economy
+        /// and minimum
+        #[arg(long, value_parser = parse_fee_rate)]
+        fee_rate: Option<OnchainFeeRate>,
+    },
+    /// Receive lbtc directly to a Liquid address
+    ReceiveLiquidPayment {
+        /// Amount the payer should send, in satoshi
+        #[arg(short, long)]
+        amount_sat: Option<u64>,
+
+        /// Label of the payment, also added to the BIP21 URI
+        #[arg(short, long)]
+        label: Option<String>,
+    },
+    /// Get a payment by exactly one of its identifiers
+    GetPayment {
+        /// Tx id of the payment
+        #[arg(short, long)]
+        tx_id: Option<String>,
+
+        /// Swap id of the payment
+        #[arg(short, long)]
+        swap_id: Option<String>,
+
+        /// Invoice of the payment
+        #[arg(short, long)]
+        bolt11: Option<String>,
+
+        /// Payment hash of the invoice of the payment
+        #[arg(short, long)]
+        payment_hash: Option<String>,
     },
     /// List incoming and outgoing payments
-    ListPayments,
+    ListPayments {
+        /// Only list payments from this epoch time, in seconds
+        #[arg(short, long)]
+        from_timestamp: Option<u32>,
+
+        /// Only list payments before this epoch time, in seconds
+        #[arg(short, long)]
+        to_timestamp: Option<u32>,
+
+        /// Number of payments to skip
+        #[arg(short, long)]
+        offset: Option<u32>,
+
+        /// Max number of payments to list
+        #[arg(short, long)]
+        limit: Option<u32>,
+
+        /// List the newest payments first
+        #[arg(short, long)]
+        descending: bool,
+
+        /// Only list payments whose metadata label, note or external id contains this text
+        #[arg(long)]
+        search: Option<String>,
+    },
+    /// Export payments to a CSV or JSON file
+    ExportPayments {
+        /// File to write
+        path: String,
+
+        /// File format: csv or json
+        #[arg(long, default_value = "csv")]
+        format: String,
+
+        /// Only export payments from this epoch time, in seconds
+        #[arg(short, long)]
+        from_timestamp: Option<u32>,
+
+        /// Only export payments before this epoch time, in seconds
+        #[arg(short, long)]
+        to_timestamp: Option<u32>,
+    },
+    /// Set the metadata of a payment, replacing any it already has
+    SetPaymentMetadata {
+        /// Swap id of the payment, else its tx id
+        id: String,
+
+        /// Label of the payment
+        #[arg(long)]
+        label: Option<String>,
+
+        /// Note of the payment
+        #[arg(long)]
+        note: Option<String>,
+
+        /// Id of the payment in an external system, like an order id
+        #[arg(long)]
+        external_id: Option<String>,
+    },
+    /// List refundable swaps
+    ListRefundables,
+    /// Prepare a refund transaction for an incomplete swap
+    PrepareRefund {
+        /// Swap ID of the refundable swap
+        swap_id: String,
+
+        /// Address to send the refund to: lbtc for send swaps, btc for onchain receive swaps
+        refund_address: String,
+
+        /// Fee rate to use: a rate in sat/vbyte, or one of fastest, half-hour, hour, economy
+        /// and minimum
+        #[arg(value_parser = parse_fee_rate)]
+        fee_rate: OnchainFeeRate,
+    },
+    /// Broadcast a refund transaction for an incomplete swap
+    Refund {
+        /// Swap ID of the refundable swap
+        swap_id: String,
+
+        /// Address to send the refund to: lbtc for send swaps, btc for onchain receive swaps
+        refund_address: String,
+
+        /// Fee rate to use, in sat/vbyte, as returned by prepare-refund
+        fee_rate_sat_per_vbyte: f64,
+    },
     /// Get the balance and general info of the current instance
     GetInfo,
+    /// Parse an invoice, address, BIP21 URI, LNURL or Lightning Address
+    Parse {
+        /// Input to parse
+        input: String,
+    },
+    /// List the Liquid Electrum servers, with the one in use and their health
+    ListElectrumServers,
     /// Sync local data with mempool and onchain data
     Sync,
     /// Empties the encrypted transaction cache
@@ -102,15 +333,37 @@ pub(crate) async fn handle_command(
     command: Command,
 ) -> Result<String> {
     Ok(match command {
-        Command::ReceivePayment { payer_amount_sat } => {
+        Command::ReceivePayment {
+            payer_amount_sat,
+            receiver_amount_sat,
+            description,
+            description_hash,
+            expiry_secs,
+            max_fee_sat,
+            max_fee_percent,
+            fee_rate,
+        } => {
             let prepare_response = sdk
-                .prepare_receive_payment(&PrepareReceiveRequest { payer_amount_sat })
+                .prepare_receive_payment(&PrepareReceiveRequest {
+                    payer_amount_sat,
+                    receiver_amount_sat,
+                    description,
+                    description_hash,
+                    invoice_expiry_secs: expiry_secs,
+                    metadata: None,
+                    max_fee_sat,
+                    max_fee_percent,
+                    fee_rate,
+                })
                 .await?;
 
             wait_confirmation!(
                 format!(
-                    "Fees: {} sat. Are the fees acceptable? (y/N) ",
-                    prepare_response.fees_sat
+                    "Invoice amount: {} sat, amount received: {} sat. Fees: {} sat ({}). Are the fees acceptable? (y/N) ",
+                    prepare_response.payer_amount_sat,
+                    prepare_response.receiver_amount_sat,
+                    prepare_response.fees_sat,
+                    format_fee_breakdown(&prepare_response.fee_breakdown)
                 ),
                 "Payment receive halted"
             );
@@ -123,15 +376,36 @@ pub(crate) async fn handle_command(
             result.push_str(&build_qr_text(&invoice));
             result
         }
-        Command::SendPayment { bolt11, delay } => {
+        Command::FetchLightningLimits => {
+            let limits = sdk.fetch_lightning_limits().await?;
+            command_result!(limits)
+        }
+        Command::FetchRecommendedFees => {
+            let fees = sdk.fetch_recommended_fees().await?;
+            command_result!(fees)
+        }
+        Command::SendPayment {
+            bolt11,
+            delay,
+            max_fee_sat,
+            max_fee_percent,
+            fee_rate,
+        } => {
             let prepare_response = sdk
-                .prepare_send_payment(&PrepareSendRequest { invoice: bolt11 })
+                .prepare_send_payment(&PrepareSendRequest {
+                    invoice: bolt11,
+                    metadata: None,
+                    max_fee_sat,
+                    max_fee_percent,
+                    fee_rate,
+                })
                 .await?;
 
             wait_confirmation!(
                 format!(
-                    "Fees: {} sat. Are the fees acceptable? (y/N) ",
-                    prepare_response.fees_sat
+                    "Fees: {} sat ({}). Are the fees acceptable? (y/N) ",
+                    prepare_response.fees_sat,
+                    format_fee_breakdown(&prepare_response.fee_breakdown)
                 ),
                 "Payment send halted"
             );
@@ -150,13 +424,255 @@ pub(crate) async fn handle_command(
                 command_result!(response)
             }
         }
+        Command::SendOnchainPayment {
+            address,
+            receiver_amount_sat,
+            fee_rate,
+        } => {
+            let prepare_res = sdk
+                .prepare_pay_onchain(&PreparePayOnchainRequest {
+                    receiver_amount_sat,
+                    fee_rate,
+                })
+                .await?;
+
+            wait_confirmation!(
+                format!(
+                    "Fees: {} sat. Are the fees acceptable? (y/N) ",
+                    prepare_res.fees_sat
+                ),
+                "Payment send halted"
+            );
+
+            let response = sdk
+                .pay_onchain(&PayOnchainRequest {
+                    address,
+                    prepare_res,
+                })
+                .await?;
+            command_result!(response)
+        }
+        Command::LnurlWithdraw { lnurl, amount_sat } => {
+            let result = sdk
+                .lnurl_withdraw(&LnUrlWithdrawRequest { lnurl, amount_sat })
+                .await?;
+            command_result!(result)
+        }
+        Command::LnurlPay {
+            lnurl,
+            amount_sat,
+            comment,
+        } => {
+            let prepare_response = sdk
+                .prepare_lnurl_pay(&PrepareLnUrlPayRequest {
+                    lnurl,
+                    amount_sat,
+                    comment,
+                })
+                .await?;
+
+            wait_confirmation!(
+                format!(
+                    "Fees: {} sat. Are the fees acceptable? (y/N) ",
+                    prepare_response.prepare_send_response.fees_sat
+                ),
+                "Payment send halted"
+            );
+
+            let result = sdk.lnurl_pay(&prepare_response).await?;
+            command_result!(result)
+        }
+        Command::SendLiquidPayment {
+            destination,
+            amount_sat,
+            send_all,
+            asset_id,
+            fee_rate,
+        } => {
+            let prepare_response = sdk
+                .prepare_send_onchain(&PrepareSendOnchainRequest {
+                    destination,
+                    amount_sat,
+                    send_all,
+                    asset_id,
+                    fee_rate,
+                })
+                .await?;
+
+            wait_confirmation!(
+                format!(
+                    "Sending {} sat with fees of {} sat. Are the fees acceptable? (y/N) ",
+                    prepare_response.receiver_amount_sat, prepare_response.fees_sat
+                ),
+                "Payment send halted"
+            );
+
+            let response = sdk.send_onchain(&prepare_response).await?;
+            command_result!(response)
+        }
+        Command::ReceiveOnchainPayment {
+            payer_amount_sat,
+            fee_rate,
+        } => {
+            let prepare_res = sdk
+                .prepare_receive_onchain(&PrepareReceiveOnchainRequest {
+                    payer_amount_sat,
+                    fee_rate,
+                })
+                .await?;
+
+            wait_confirmation!(
+                format!(
+                    "Fees: {} sat. Are the fees acceptable? (y/N) ",
+                    prepare_res.fees_sat
+                ),
+                "Payment receive halted"
+            );
+
+            let response = sdk.receive_onchain(&prepare_res).await?;
+            let bip21 = response.bip21.clone();
+
+            let mut result = command_result!(response);
+            result.push('\n');
+            result.push_str(&build_qr_text(&bip21));
+            result
+        }
+        Command::ReceiveLiquidPayment { amount_sat, label } => {
+            let response = sdk
+                .receive_liquid(&ReceiveLiquidRequest { amount_sat, label })
+                .await?;
+            let bip21 = response.bip21.clone();
+
+            let mut result = command_result!(response);
+            result.push('\n');
+            result.push_str(&build_qr_text(&bip21));
+            result
+        }
         Command::GetInfo => {
             command_result!(sdk.get_info().await?)
         }
-        Command::ListPayments => {
-            let payments = sdk.list_payments().await?;
+        Command::Parse { input } => {
+            command_result!(sdk.parse(&input)?)
+        }
+        Command::GetPayment {
+            tx_id,
+            swap_id,
+            bolt11,
+            payment_hash,
+        } => {
+            let req = match (tx_id, swap_id, bolt11, payment_hash) {
+                (Some(tx_id), None, None, None) => GetPaymentRequest::TxId { tx_id },
+                (None, Some(swap_id), None, None) => GetPaymentRequest::SwapId { swap_id },
+                (None, None, Some(bolt11), None) => GetPaymentRequest::Bolt11 { bolt11 },
+                (None, None, None, Some(payment_hash)) => {
+                    GetPaymentRequest::PaymentHash { payment_hash }
+                }
+                _ => {
+                    return Err(anyhow!(
+                        "Set exactly one of tx_id, swap_id, bolt11 or payment_hash"
+                    ))
+                }
+            };
+            command_result!(sdk.get_payment(&req).await?)
+        }
+        Command::ListPayments {
+            from_timestamp,
+            to_timestamp,
+            offset,
+            limit,
+            descending,
+            search,
+        } => {
+            let payments = sdk
+                .list_payments(&ListPaymentsRequest {
+                    from_timestamp,
+                    to_timestamp,
+                    offset,
+                    limit,
+                    sort_descending: Some(descending),
+                    metadata_search: search,
+                    ..Default::default()
+                })
+                .await?;
             command_result!(payments)
         }
+        Command::ExportPayments {
+            path,
+            format,
+            from_timestamp,
+            to_timestamp,
+        } => {
+            let format = match format.to_lowercase().as_str() {
+                "csv" => PaymentExportFormat::Csv,
+                "json" => PaymentExportFormat::Json,
+                _ => return Err(anyhow!("Format must be csv or json")),
+            };
+            let response = sdk
+                .export_payments(&ExportPaymentsRequest {
+                    format,
+                    filter: ListPaymentsRequest {
+                        from_timestamp,
+                        to_timestamp,
+                        ..Default::default()
+                    },
+                    path,
+                })
+                .await?;
+            command_result!(response)
+        }
+        Command::SetPaymentMetadata {
+            id,
+            label,
+            note,
+            external_id,
+        } => {
+            sdk.set_payment_metadata(&SetPaymentMetadataRequest {
+                id,
+                metadata: PaymentMetadata {
+                    label,
+                    note,
+                    external_id,
+                },
+            })
+            .await?;
+            command_result!("Payment metadata set")
+        }
+        Command::ListRefundables => {
+            let refundables = sdk.list_refundables().await?;
+            command_result!(refundables)
+        }
+        Command::PrepareRefund {
+            swap_id,
+            refund_address,
+            fee_rate,
+        } => {
+            let res = sdk
+                .prepare_refund(&PrepareRefundRequest {
+                    swap_id,
+                    refund_address,
+                    fee_rate,
+                })
+                .await?;
+            command_result!(res)
+        }
+        Command::Refund {
+            swap_id,
+            refund_address,
+            fee_rate_sat_per_vbyte,
+        } => {
+            let res = sdk
+                .refund(&RefundRequest {
+                    swap_id,
+                    refund_address,
+                    fee_rate_sat_per_vbyte,
+                })
+                .await?;
+            command_result!(res)
+        }
+        Command::ListElectrumServers => {
+            let servers = sdk.list_electrum_servers().await?;
+            command_result!(servers)
+        }
         Command::Sync => {
             sdk.sync().await?;
             command_result!("Synced successfully")
@@ -180,6 +696,23 @@ pub(crate) async fn handle_command(
     })
 }
 
+fn parse_fee_rate(value: &str) -> Result<OnchainFeeRate, String> {
+    let priority = match value.to_lowercase().as_str() {
+        "fastest" => FeePriority::Fastest,
+        "half-hour" => FeePriority::HalfHour,
+        "hour" => FeePriority::Hour,
+        "economy" => FeePriority::Economy,
+        "minimum" => FeePriority::Minimum,
+        _ => {
+            let sat_per_vbyte = value
+                .parse::<f64>()
+                .map_err(|_| format!("Invalid fee rate: {value}"))?;
+            return Ok(OnchainFeeRate::SatPerVbyte { sat_per_vbyte });
+        }
+    };
+    Ok(OnchainFeeRate::Priority { priority })
+}
+
 fn build_qr_text(text: &str) -> String {
     QrCode::with_error_correction_level(text, EcLevel::L)
         .unwrap()
@@ -188,3 +721,13 @@ fn build_qr_text(text: &str) -> String {
         .light_color(unicode::Dense1x2::Dark)
         .build()
 }
+
+fn format_fee_breakdown(fee_breakdown: &FeeBreakdown) -> String {
+    format!(
+        "service fee {} sat ({}%), swapper miner fees {} sat, network fee {} sat",
+        fee_breakdown.service_fee_sat,
+        fee_breakdown.service_fee_percentage,
+        fee_breakdown.swapper_miner_fees_sat,
+        fee_breakdown.network_fee_sat
+    )
+}
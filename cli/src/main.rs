@@ -24,6 +24,15 @@ pub(crate) struct Args {
 
     #[clap(short, long, value_parser = parse_network_arg)]
     pub(crate) network: Option<Network>,
+
+    /// Liquid Esplora REST API to use instead of the Electrum servers
+    #[clap(long)]
+    pub(crate) esplora_url: Option<String>,
+
+    /// Liquid Electrum server to use instead of the default one, over TLS. Can be repeated to
+    /// fail over between servers, in the given order.
+    #[clap(long = "electrum-server")]
+    pub(crate) electrum_servers: Vec<String>,
 }
 
 fn parse_network_arg(s: &str) -> Result<Network, String> {
@@ -76,6 +85,14 @@ async fn main() -> Result<()> {
     let network = args.network.unwrap_or(Network::Testnet);
     let mut config = LiquidSdk::default_config(network);
     config.working_dir = data_dir_str;
+    config.esplora_url = args.esplora_url;
+    if !args.electrum_servers.is_empty() {
+        config.electrum_servers = args
+            .electrum_servers
+            .iter()
+            .map(|url| ElectrumServer::new(url))
+            .collect();
+    }
     let sdk = LiquidSdk::connect(ConnectRequest {
         mnemonic: mnemonic.to_string(),
         config,
@@ -88,6 +105,7 @@ async fn main() -> Result<()> {
     let cli_prompt = match network {
         Network::Mainnet => "breez-liquid-cli [mainnet]> ",
         Network::Testnet => "breez-liquid-cli [testnet]> ",
+        Network::Regtest => "breez-liquid-cli [regtest]> ",
     };
 
     loop {
@@ -35,27 +35,250 @@ typedef struct wire_cst_backup_request {
   struct wire_cst_list_prim_u_8_strict *backup_path;
 } wire_cst_backup_request;
 
-typedef struct wire_cst_prepare_receive_request {
+typedef struct wire_cst_list_payment_type {
+  int32_t *ptr;
+  int32_t len;
+} wire_cst_list_payment_type;
+
+typedef struct wire_cst_list_payment_state {
+  int32_t *ptr;
+  int32_t len;
+} wire_cst_list_payment_state;
+
+typedef struct wire_cst_list_payments_request {
+  struct wire_cst_list_payment_type *payment_types;
+  struct wire_cst_list_payment_state *states;
+  uint32_t *from_timestamp;
+  uint32_t *to_timestamp;
+  uint32_t *offset;
+  uint32_t *limit;
+  bool *sort_descending;
+  struct wire_cst_list_prim_u_8_strict *metadata_search;
+} wire_cst_list_payments_request;
+
+typedef struct wire_cst_export_payments_request {
+  int32_t format;
+  struct wire_cst_list_payments_request filter;
+  struct wire_cst_list_prim_u_8_strict *path;
+} wire_cst_export_payments_request;
+
+typedef struct wire_cst_GetPaymentRequest_TxId {
+  struct wire_cst_list_prim_u_8_strict *tx_id;
+} wire_cst_GetPaymentRequest_TxId;
+
+typedef struct wire_cst_GetPaymentRequest_SwapId {
+  struct wire_cst_list_prim_u_8_strict *swap_id;
+} wire_cst_GetPaymentRequest_SwapId;
+
+typedef struct wire_cst_GetPaymentRequest_Bolt11 {
+  struct wire_cst_list_prim_u_8_strict *bolt11;
+} wire_cst_GetPaymentRequest_Bolt11;
+
+typedef struct wire_cst_GetPaymentRequest_PaymentHash {
+  struct wire_cst_list_prim_u_8_strict *payment_hash;
+} wire_cst_GetPaymentRequest_PaymentHash;
+
+typedef union GetPaymentRequestKind {
+  struct wire_cst_GetPaymentRequest_TxId TxId;
+  struct wire_cst_GetPaymentRequest_SwapId SwapId;
+  struct wire_cst_GetPaymentRequest_Bolt11 Bolt11;
+  struct wire_cst_GetPaymentRequest_PaymentHash PaymentHash;
+} GetPaymentRequestKind;
+
+typedef struct wire_cst_get_payment_request {
+  int32_t tag;
+  union GetPaymentRequestKind kind;
+} wire_cst_get_payment_request;
+
+typedef struct wire_cst_payment_metadata {
+  struct wire_cst_list_prim_u_8_strict *label;
+  struct wire_cst_list_prim_u_8_strict *note;
+  struct wire_cst_list_prim_u_8_strict *external_id;
+} wire_cst_payment_metadata;
+
+typedef struct wire_cst_fee_breakdown {
+  double service_fee_percentage;
+  uint64_t service_fee_sat;
+  uint64_t swapper_miner_fees_sat;
+  uint64_t network_fee_sat;
+  uint64_t total_fees_sat;
+} wire_cst_fee_breakdown;
+
+typedef struct wire_cst_prepare_send_response {
+  struct wire_cst_list_prim_u_8_strict *invoice;
+  uint64_t fees_sat;
+  struct wire_cst_payment_metadata *metadata;
+  struct wire_cst_fee_breakdown fee_breakdown;
+  uint64_t *max_fee_sat;
+  double *max_fee_percent;
+  struct wire_cst_list_prim_u_8_strict *pair_hash;
+  uint32_t expires_at;
+  double *fee_rate_sat_per_vbyte;
+} wire_cst_prepare_send_response;
+
+typedef struct wire_cst_SuccessAction_Message {
+  struct wire_cst_list_prim_u_8_strict *message;
+} wire_cst_SuccessAction_Message;
+
+typedef struct wire_cst_SuccessAction_Url {
+  struct wire_cst_list_prim_u_8_strict *description;
+  struct wire_cst_list_prim_u_8_strict *url;
+} wire_cst_SuccessAction_Url;
+
+typedef struct wire_cst_SuccessAction_Aes {
+  struct wire_cst_list_prim_u_8_strict *description;
+  struct wire_cst_list_prim_u_8_strict *ciphertext;
+  struct wire_cst_list_prim_u_8_strict *iv;
+} wire_cst_SuccessAction_Aes;
+
+typedef union SuccessActionKind {
+  struct wire_cst_SuccessAction_Message Message;
+  struct wire_cst_SuccessAction_Url Url;
+  struct wire_cst_SuccessAction_Aes Aes;
+} SuccessActionKind;
+
+typedef struct wire_cst_success_action {
+  int32_t tag;
+  union SuccessActionKind kind;
+} wire_cst_success_action;
+
+typedef struct wire_cst_prepare_ln_url_pay_response {
+  struct wire_cst_prepare_send_response prepare_send_response;
+  struct wire_cst_success_action *success_action;
+} wire_cst_prepare_ln_url_pay_response;
+
+typedef struct wire_cst_ln_url_withdraw_request {
+  struct wire_cst_list_prim_u_8_strict *lnurl;
+  uint64_t amount_sat;
+} wire_cst_ln_url_withdraw_request;
+
+typedef struct wire_cst_prepare_pay_onchain_response {
+  uint64_t receiver_amount_sat;
+  uint64_t fees_sat;
+  double *fee_rate_sat_per_vbyte;
+} wire_cst_prepare_pay_onchain_response;
+
+typedef struct wire_cst_pay_onchain_request {
+  struct wire_cst_list_prim_u_8_strict *address;
+  struct wire_cst_prepare_pay_onchain_response prepare_res;
+} wire_cst_pay_onchain_request;
+
+typedef struct wire_cst_prepare_ln_url_pay_request {
+  struct wire_cst_list_prim_u_8_strict *lnurl;
+  uint64_t amount_sat;
+  struct wire_cst_list_prim_u_8_strict *comment;
+} wire_cst_prepare_ln_url_pay_request;
+
+typedef struct wire_cst_OnchainFeeRate_SatPerVbyte {
+  double sat_per_vbyte;
+} wire_cst_OnchainFeeRate_SatPerVbyte;
+
+typedef struct wire_cst_OnchainFeeRate_Priority {
+  int32_t priority;
+} wire_cst_OnchainFeeRate_Priority;
+
+typedef union OnchainFeeRateKind {
+  struct wire_cst_OnchainFeeRate_SatPerVbyte SatPerVbyte;
+  struct wire_cst_OnchainFeeRate_Priority Priority;
+} OnchainFeeRateKind;
+
+typedef struct wire_cst_onchain_fee_rate {
+  int32_t tag;
+  union OnchainFeeRateKind kind;
+} wire_cst_onchain_fee_rate;
+
+typedef struct wire_cst_prepare_pay_onchain_request {
+  uint64_t receiver_amount_sat;
+  struct wire_cst_onchain_fee_rate *fee_rate;
+} wire_cst_prepare_pay_onchain_request;
+
+typedef struct wire_cst_prepare_receive_onchain_request {
   uint64_t payer_amount_sat;
+  struct wire_cst_onchain_fee_rate *fee_rate;
+} wire_cst_prepare_receive_onchain_request;
+
+typedef struct wire_cst_prepare_receive_request {
+  uint64_t *payer_amount_sat;
+  uint64_t *receiver_amount_sat;
+  struct wire_cst_list_prim_u_8_strict *description;
+  struct wire_cst_list_prim_u_8_strict *description_hash;
+  uint64_t *invoice_expiry_secs;
+  struct wire_cst_payment_metadata *metadata;
+  uint64_t *max_fee_sat;
+  double *max_fee_percent;
+  struct wire_cst_onchain_fee_rate *fee_rate;
 } wire_cst_prepare_receive_request;
 
+typedef struct wire_cst_prepare_refund_request {
+  struct wire_cst_list_prim_u_8_strict *swap_id;
+  struct wire_cst_list_prim_u_8_strict *refund_address;
+  struct wire_cst_onchain_fee_rate fee_rate;
+} wire_cst_prepare_refund_request;
+
+typedef struct wire_cst_prepare_send_onchain_request {
+  struct wire_cst_list_prim_u_8_strict *destination;
+  uint64_t *amount_sat;
+  bool send_all;
+  struct wire_cst_list_prim_u_8_strict *asset_id;
+  struct wire_cst_onchain_fee_rate *fee_rate;
+} wire_cst_prepare_send_onchain_request;
+
 typedef struct wire_cst_prepare_send_request {
   struct wire_cst_list_prim_u_8_strict *invoice;
+  struct wire_cst_payment_metadata *metadata;
+  uint64_t *max_fee_sat;
+  double *max_fee_percent;
+  struct wire_cst_onchain_fee_rate *fee_rate;
 } wire_cst_prepare_send_request;
 
+typedef struct wire_cst_receive_liquid_request {
+  uint64_t *amount_sat;
+  struct wire_cst_list_prim_u_8_strict *label;
+} wire_cst_receive_liquid_request;
+
+typedef struct wire_cst_prepare_receive_onchain_response {
+  uint64_t payer_amount_sat;
+  uint64_t fees_sat;
+  double *fee_rate_sat_per_vbyte;
+} wire_cst_prepare_receive_onchain_response;
+
 typedef struct wire_cst_prepare_receive_response {
   uint64_t payer_amount_sat;
+  uint64_t receiver_amount_sat;
   uint64_t fees_sat;
+  struct wire_cst_list_prim_u_8_strict *description;
+  struct wire_cst_list_prim_u_8_strict *description_hash;
+  uint64_t *invoice_expiry_secs;
+  struct wire_cst_payment_metadata *metadata;
+  struct wire_cst_fee_breakdown fee_breakdown;
+  struct wire_cst_list_prim_u_8_strict *pair_hash;
+  uint32_t expires_at;
+  double *fee_rate_sat_per_vbyte;
 } wire_cst_prepare_receive_response;
 
+typedef struct wire_cst_refund_request {
+  struct wire_cst_list_prim_u_8_strict *swap_id;
+  struct wire_cst_list_prim_u_8_strict *refund_address;
+  double fee_rate_sat_per_vbyte;
+} wire_cst_refund_request;
+
 typedef struct wire_cst_restore_request {
   struct wire_cst_list_prim_u_8_strict *backup_path;
 } wire_cst_restore_request;
 
-typedef struct wire_cst_prepare_send_response {
-  struct wire_cst_list_prim_u_8_strict *invoice;
+typedef struct wire_cst_prepare_send_onchain_response {
+  struct wire_cst_list_prim_u_8_strict *address;
+  uint64_t receiver_amount_sat;
   uint64_t fees_sat;
-} wire_cst_prepare_send_response;
+  bool send_all;
+  struct wire_cst_list_prim_u_8_strict *asset_id;
+  double *fee_rate_sat_per_vbyte;
+} wire_cst_prepare_send_onchain_response;
+
+typedef struct wire_cst_set_payment_metadata_request {
+  struct wire_cst_list_prim_u_8_strict *id;
+  struct wire_cst_payment_metadata metadata;
+} wire_cst_set_payment_metadata_request;
 
 typedef struct wire_cst_binding_event_listener {
   struct wire_cst_list_prim_u_8_strict *stream;
@@ -64,14 +287,28 @@ typedef struct wire_cst_binding_event_listener {
 typedef struct wire_cst_payment {
   struct wire_cst_list_prim_u_8_strict *tx_id;
   struct wire_cst_list_prim_u_8_strict *swap_id;
+  int32_t swap_type;
   uint32_t timestamp;
   uint64_t amount_sat;
   uint64_t fees_sat;
+  uint64_t *network_fees_sat;
+  struct wire_cst_fee_breakdown *fee_breakdown;
+  double *fee_rate_sat_per_vbyte;
   struct wire_cst_list_prim_u_8_strict *preimage;
+  struct wire_cst_list_prim_u_8_strict *bolt11;
+  struct wire_cst_list_prim_u_8_strict *payment_hash;
+  struct wire_cst_list_prim_u_8_strict *payee_pubkey;
+  struct wire_cst_list_prim_u_8_strict *lockup_tx_id;
+  struct wire_cst_list_prim_u_8_strict *claim_tx_id;
   struct wire_cst_list_prim_u_8_strict *refund_tx_id;
   uint64_t *refund_tx_amount_sat;
   int32_t payment_type;
   int32_t status;
+  struct wire_cst_list_prim_u_8_strict *label;
+  struct wire_cst_list_prim_u_8_strict *description;
+  struct wire_cst_payment_metadata *metadata;
+  struct wire_cst_list_prim_u_8_strict *asset_id;
+  uint64_t *asset_amount;
 } wire_cst_payment;
 
 typedef struct wire_cst_LiquidSdkEvent_PaymentFailed {
@@ -86,6 +323,10 @@ typedef struct wire_cst_LiquidSdkEvent_PaymentRefunded {
   struct wire_cst_payment *details;
 } wire_cst_LiquidSdkEvent_PaymentRefunded;
 
+typedef struct wire_cst_LiquidSdkEvent_PaymentRefundable {
+  struct wire_cst_payment *details;
+} wire_cst_LiquidSdkEvent_PaymentRefundable;
+
 typedef struct wire_cst_LiquidSdkEvent_PaymentRefundPending {
   struct wire_cst_payment *details;
 } wire_cst_LiquidSdkEvent_PaymentRefundPending;
@@ -102,6 +343,7 @@ typedef union LiquidSdkEventKind {
   struct wire_cst_LiquidSdkEvent_PaymentFailed PaymentFailed;
   struct wire_cst_LiquidSdkEvent_PaymentPending PaymentPending;
   struct wire_cst_LiquidSdkEvent_PaymentRefunded PaymentRefunded;
+  struct wire_cst_LiquidSdkEvent_PaymentRefundable PaymentRefundable;
   struct wire_cst_LiquidSdkEvent_PaymentRefundPending PaymentRefundPending;
   struct wire_cst_LiquidSdkEvent_PaymentSucceeded PaymentSucceeded;
   struct wire_cst_LiquidSdkEvent_PaymentWaitingConfirmation PaymentWaitingConfirmation;
@@ -112,14 +354,45 @@ typedef struct wire_cst_liquid_sdk_event {
   union LiquidSdkEventKind kind;
 } wire_cst_liquid_sdk_event;
 
+typedef struct wire_cst_electrum_server {
+  struct wire_cst_list_prim_u_8_strict *url;
+  bool tls;
+  bool validate_domain;
+} wire_cst_electrum_server;
+
+typedef struct wire_cst_list_electrum_server {
+  struct wire_cst_electrum_server *ptr;
+  int32_t len;
+} wire_cst_list_electrum_server;
+
+typedef struct wire_cst_asset_metadata {
+  struct wire_cst_list_prim_u_8_strict *asset_id;
+  struct wire_cst_list_prim_u_8_strict *ticker;
+  struct wire_cst_list_prim_u_8_strict *name;
+  uint8_t precision;
+} wire_cst_asset_metadata;
+
+typedef struct wire_cst_list_asset_metadata {
+  struct wire_cst_asset_metadata *ptr;
+  int32_t len;
+} wire_cst_list_asset_metadata;
+
 typedef struct wire_cst_config {
   struct wire_cst_list_prim_u_8_strict *boltz_url;
+  struct wire_cst_list_electrum_server *electrum_servers;
   struct wire_cst_list_prim_u_8_strict *electrum_url;
+  struct wire_cst_list_prim_u_8_strict *bitcoin_electrum_url;
+  struct wire_cst_list_prim_u_8_strict *esplora_url;
   struct wire_cst_list_prim_u_8_strict *working_dir;
   int32_t network;
   uint64_t payment_timeout_sec;
+  uint64_t sync_fallback_interval_sec;
   float zero_conf_min_fee_rate;
   uint64_t *zero_conf_max_amount_sat;
+  struct wire_cst_list_asset_metadata *asset_metadata;
+  uint64_t *max_fee_sat;
+  double *max_fee_percent;
+  struct wire_cst_list_prim_u_8_strict *regtest_policy_asset;
 } wire_cst_config;
 
 typedef struct wire_cst_connect_request {
@@ -127,10 +400,22 @@ typedef struct wire_cst_connect_request {
   struct wire_cst_config config;
 } wire_cst_connect_request;
 
-typedef struct wire_cst_list_payment {
-  struct wire_cst_payment *ptr;
-  int32_t len;
-} wire_cst_list_payment;
+typedef struct wire_cst_bitcoin_address_data {
+  struct wire_cst_list_prim_u_8_strict *address;
+  int32_t network;
+  uint64_t *amount_sat;
+  struct wire_cst_list_prim_u_8_strict *label;
+  struct wire_cst_list_prim_u_8_strict *message;
+} wire_cst_bitcoin_address_data;
+
+typedef struct wire_cst_liquid_address_data {
+  struct wire_cst_list_prim_u_8_strict *address;
+  int32_t network;
+  uint64_t *amount_sat;
+  struct wire_cst_list_prim_u_8_strict *asset_id;
+  struct wire_cst_list_prim_u_8_strict *label;
+  struct wire_cst_list_prim_u_8_strict *message;
+} wire_cst_liquid_address_data;
 
 typedef struct wire_cst_route_hint_hop {
   struct wire_cst_list_prim_u_8_strict *src_node_id;
@@ -156,13 +441,146 @@ typedef struct wire_cst_list_route_hint {
   int32_t len;
 } wire_cst_list_route_hint;
 
+typedef struct wire_cst_ln_invoice {
+  struct wire_cst_list_prim_u_8_strict *bolt11;
+  int32_t network;
+  struct wire_cst_list_prim_u_8_strict *payee_pubkey;
+  struct wire_cst_list_prim_u_8_strict *payment_hash;
+  struct wire_cst_list_prim_u_8_strict *description;
+  struct wire_cst_list_prim_u_8_strict *description_hash;
+  uint64_t *amount_msat;
+  uint64_t timestamp;
+  uint64_t expiry;
+  struct wire_cst_list_route_hint *routing_hints;
+  struct wire_cst_list_prim_u_8_strict *payment_secret;
+  uint64_t min_final_cltv_expiry_delta;
+} wire_cst_ln_invoice;
+
+typedef struct wire_cst_SuccessActionProcessed_Message {
+  struct wire_cst_list_prim_u_8_strict *message;
+} wire_cst_SuccessActionProcessed_Message;
+
+typedef struct wire_cst_SuccessActionProcessed_Url {
+  struct wire_cst_list_prim_u_8_strict *description;
+  struct wire_cst_list_prim_u_8_strict *url;
+} wire_cst_SuccessActionProcessed_Url;
+
+typedef struct wire_cst_SuccessActionProcessed_Aes {
+  struct wire_cst_list_prim_u_8_strict *description;
+  struct wire_cst_list_prim_u_8_strict *plaintext;
+} wire_cst_SuccessActionProcessed_Aes;
+
+typedef union SuccessActionProcessedKind {
+  struct wire_cst_SuccessActionProcessed_Message Message;
+  struct wire_cst_SuccessActionProcessed_Url Url;
+  struct wire_cst_SuccessActionProcessed_Aes Aes;
+} SuccessActionProcessedKind;
+
+typedef struct wire_cst_success_action_processed {
+  int32_t tag;
+  union SuccessActionProcessedKind kind;
+} wire_cst_success_action_processed;
+
+typedef struct wire_cst_asset_balance {
+  struct wire_cst_list_prim_u_8_strict *asset_id;
+  uint64_t balance;
+  struct wire_cst_asset_metadata *asset_metadata;
+} wire_cst_asset_balance;
+
+typedef struct wire_cst_list_asset_balance {
+  struct wire_cst_asset_balance *ptr;
+  int32_t len;
+} wire_cst_list_asset_balance;
+
+typedef struct wire_cst_electrum_server_status {
+  struct wire_cst_list_prim_u_8_strict *url;
+  bool in_use;
+  bool healthy;
+  uint32_t consecutive_failures;
+  struct wire_cst_list_prim_u_8_strict *last_error;
+} wire_cst_electrum_server_status;
+
+typedef struct wire_cst_list_electrum_server_status {
+  struct wire_cst_electrum_server_status *ptr;
+  int32_t len;
+} wire_cst_list_electrum_server_status;
+
+typedef struct wire_cst_list_payment {
+  struct wire_cst_payment *ptr;
+  int32_t len;
+} wire_cst_list_payment;
+
+typedef struct wire_cst_refundable_swap {
+  struct wire_cst_list_prim_u_8_strict *swap_id;
+  struct wire_cst_list_prim_u_8_strict *swap_address;
+  uint32_t timestamp;
+  uint64_t amount_sat;
+  int32_t payment_type;
+} wire_cst_refundable_swap;
+
+typedef struct wire_cst_list_refundable_swap {
+  struct wire_cst_refundable_swap *ptr;
+  int32_t len;
+} wire_cst_list_refundable_swap;
+
+typedef struct wire_cst_export_payments_response {
+  uint32_t payments_count;
+} wire_cst_export_payments_response;
+
 typedef struct wire_cst_get_info_response {
   uint64_t balance_sat;
   uint64_t pending_send_sat;
   uint64_t pending_receive_sat;
   struct wire_cst_list_prim_u_8_strict *pubkey;
+  struct wire_cst_list_asset_balance *asset_balances;
 } wire_cst_get_info_response;
 
+typedef struct wire_cst_InputType_Bolt11 {
+  struct wire_cst_ln_invoice *invoice;
+} wire_cst_InputType_Bolt11;
+
+typedef struct wire_cst_InputType_LiquidAddress {
+  struct wire_cst_liquid_address_data *address;
+} wire_cst_InputType_LiquidAddress;
+
+typedef struct wire_cst_InputType_BitcoinAddress {
+  struct wire_cst_bitcoin_address_data *address;
+} wire_cst_InputType_BitcoinAddress;
+
+typedef struct wire_cst_InputType_LnUrl {
+  struct wire_cst_list_prim_u_8_strict *url;
+} wire_cst_InputType_LnUrl;
+
+typedef struct wire_cst_InputType_LightningAddress {
+  struct wire_cst_list_prim_u_8_strict *address;
+  struct wire_cst_list_prim_u_8_strict *url;
+} wire_cst_InputType_LightningAddress;
+
+typedef union InputTypeKind {
+  struct wire_cst_InputType_Bolt11 Bolt11;
+  struct wire_cst_InputType_LiquidAddress LiquidAddress;
+  struct wire_cst_InputType_BitcoinAddress BitcoinAddress;
+  struct wire_cst_InputType_LnUrl LnUrl;
+  struct wire_cst_InputType_LightningAddress LightningAddress;
+} InputTypeKind;
+
+typedef struct wire_cst_input_type {
+  int32_t tag;
+  union InputTypeKind kind;
+} wire_cst_input_type;
+
+typedef struct wire_cst_lightning_limits {
+  uint64_t min_sat;
+  uint64_t max_sat;
+  double service_fee_percentage;
+  uint64_t miner_fees_sat;
+} wire_cst_lightning_limits;
+
+typedef struct wire_cst_lightning_payment_limits_response {
+  struct wire_cst_lightning_limits send;
+  struct wire_cst_lightning_limits receive;
+} wire_cst_lightning_payment_limits_response;
+
 typedef struct wire_cst_LiquidSdkError_Generic {
   struct wire_cst_list_prim_u_8_strict *err;
 } wire_cst_LiquidSdkError_Generic;
@@ -176,30 +594,54 @@ typedef struct wire_cst_liquid_sdk_error {
   union LiquidSdkErrorKind kind;
 } wire_cst_liquid_sdk_error;
 
-typedef struct wire_cst_ln_invoice {
-  struct wire_cst_list_prim_u_8_strict *bolt11;
-  int32_t network;
-  struct wire_cst_list_prim_u_8_strict *payee_pubkey;
-  struct wire_cst_list_prim_u_8_strict *payment_hash;
-  struct wire_cst_list_prim_u_8_strict *description;
-  struct wire_cst_list_prim_u_8_strict *description_hash;
-  uint64_t *amount_msat;
-  uint64_t timestamp;
-  uint64_t expiry;
-  struct wire_cst_list_route_hint *routing_hints;
-  struct wire_cst_list_prim_u_8_strict *payment_secret;
-  uint64_t min_final_cltv_expiry_delta;
-} wire_cst_ln_invoice;
+typedef struct wire_cst_ln_url_pay_result {
+  struct wire_cst_payment payment;
+  struct wire_cst_success_action_processed *success_action;
+} wire_cst_ln_url_pay_result;
+
+typedef struct wire_cst_receive_payment_response {
+  struct wire_cst_list_prim_u_8_strict *id;
+  struct wire_cst_list_prim_u_8_strict *invoice;
+} wire_cst_receive_payment_response;
+
+typedef struct wire_cst_ln_url_withdraw_result {
+  struct wire_cst_receive_payment_response receive_payment_response;
+} wire_cst_ln_url_withdraw_result;
 
 typedef struct wire_cst_log_entry {
   struct wire_cst_list_prim_u_8_strict *line;
   struct wire_cst_list_prim_u_8_strict *level;
 } wire_cst_log_entry;
 
+typedef struct wire_cst_PaymentError_UnrecognizedInput {
+  struct wire_cst_list_prim_u_8_strict *err;
+} wire_cst_PaymentError_UnrecognizedInput;
+
+typedef struct wire_cst_PaymentError_NetworkMismatch {
+  struct wire_cst_list_prim_u_8_strict *err;
+} wire_cst_PaymentError_NetworkMismatch;
+
+typedef struct wire_cst_PaymentError_LnUrlError {
+  struct wire_cst_list_prim_u_8_strict *err;
+} wire_cst_PaymentError_LnUrlError;
+
+typedef struct wire_cst_PaymentError_LnUrlServiceUnreachable {
+  struct wire_cst_list_prim_u_8_strict *err;
+} wire_cst_PaymentError_LnUrlServiceUnreachable;
+
+typedef struct wire_cst_PaymentError_LnUrlServiceError {
+  struct wire_cst_list_prim_u_8_strict *err;
+} wire_cst_PaymentError_LnUrlServiceError;
+
 typedef struct wire_cst_PaymentError_Generic {
   struct wire_cst_list_prim_u_8_strict *err;
 } wire_cst_PaymentError_Generic;
 
+typedef struct wire_cst_PaymentError_FeeLimitExceeded {
+  uint64_t fee_sat;
+  uint64_t limit_sat;
+} wire_cst_PaymentError_FeeLimitExceeded;
+
 typedef struct wire_cst_PaymentError_InvalidInvoice {
   struct wire_cst_list_prim_u_8_strict *err;
 } wire_cst_PaymentError_InvalidInvoice;
@@ -226,7 +668,13 @@ typedef struct wire_cst_PaymentError_SignerError {
 } wire_cst_PaymentError_SignerError;
 
 typedef union PaymentErrorKind {
+  struct wire_cst_PaymentError_UnrecognizedInput UnrecognizedInput;
+  struct wire_cst_PaymentError_NetworkMismatch NetworkMismatch;
+  struct wire_cst_PaymentError_LnUrlError LnUrlError;
+  struct wire_cst_PaymentError_LnUrlServiceUnreachable LnUrlServiceUnreachable;
+  struct wire_cst_PaymentError_LnUrlServiceError LnUrlServiceError;
   struct wire_cst_PaymentError_Generic Generic;
+  struct wire_cst_PaymentError_FeeLimitExceeded FeeLimitExceeded;
   struct wire_cst_PaymentError_InvalidInvoice InvalidInvoice;
   struct wire_cst_PaymentError_LwkError LwkError;
   struct wire_cst_PaymentError_ReceiveError ReceiveError;
@@ -240,10 +688,40 @@ typedef struct wire_cst_payment_error {
   union PaymentErrorKind kind;
 } wire_cst_payment_error;
 
-typedef struct wire_cst_receive_payment_response {
+typedef struct wire_cst_prepare_refund_response {
+  uint32_t tx_vsize;
+  uint64_t tx_fee_sat;
+  uint64_t refund_amount_sat;
+  double fee_rate_sat_per_vbyte;
+} wire_cst_prepare_refund_response;
+
+typedef struct wire_cst_receive_liquid_response {
+  struct wire_cst_list_prim_u_8_strict *address;
+  struct wire_cst_list_prim_u_8_strict *bip21;
+} wire_cst_receive_liquid_response;
+
+typedef struct wire_cst_receive_onchain_response {
   struct wire_cst_list_prim_u_8_strict *id;
-  struct wire_cst_list_prim_u_8_strict *invoice;
-} wire_cst_receive_payment_response;
+  struct wire_cst_list_prim_u_8_strict *address;
+  struct wire_cst_list_prim_u_8_strict *bip21;
+} wire_cst_receive_onchain_response;
+
+typedef struct wire_cst_recommended_fees {
+  double fastest_fee;
+  double half_hour_fee;
+  double hour_fee;
+  double economy_fee;
+  double minimum_fee;
+} wire_cst_recommended_fees;
+
+typedef struct wire_cst_recommended_fees_response {
+  struct wire_cst_recommended_fees liquid;
+  struct wire_cst_recommended_fees bitcoin;
+} wire_cst_recommended_fees_response;
+
+typedef struct wire_cst_refund_response {
+  struct wire_cst_list_prim_u_8_strict *refund_tx_id;
+} wire_cst_refund_response;
 
 typedef struct wire_cst_send_payment_response {
   struct wire_cst_payment payment;
@@ -261,31 +739,107 @@ void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_disconnect(int6
 
 WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_empty_wallet_cache(uintptr_t that);
 
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_export_payments(int64_t port_,
+                                                                                 uintptr_t that,
+                                                                                 struct wire_cst_export_payments_request *req);
+
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fetch_lightning_limits(int64_t port_,
+                                                                                        uintptr_t that);
+
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fetch_recommended_fees(int64_t port_,
+                                                                                        uintptr_t that);
+
 void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_info(int64_t port_,
                                                                           uintptr_t that);
 
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_payment(int64_t port_,
+                                                                             uintptr_t that,
+                                                                             struct wire_cst_get_payment_request *req);
+
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_electrum_servers(int64_t port_,
+                                                                                       uintptr_t that);
+
 void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_payments(int64_t port_,
-                                                                               uintptr_t that);
+                                                                               uintptr_t that,
+                                                                               struct wire_cst_list_payments_request *req);
+
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_refundables(int64_t port_,
+                                                                                  uintptr_t that);
+
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_lnurl_pay(int64_t port_,
+                                                                           uintptr_t that,
+                                                                           struct wire_cst_prepare_ln_url_pay_response *req);
+
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_lnurl_withdraw(int64_t port_,
+                                                                                uintptr_t that,
+                                                                                struct wire_cst_ln_url_withdraw_request *req);
+
+WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_parse(uintptr_t that,
+                                                                                       struct wire_cst_list_prim_u_8_strict *input);
+
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_pay_onchain(int64_t port_,
+                                                                             uintptr_t that,
+                                                                             struct wire_cst_pay_onchain_request *req);
+
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_lnurl_pay(int64_t port_,
+                                                                                   uintptr_t that,
+                                                                                   struct wire_cst_prepare_ln_url_pay_request *req);
+
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_pay_onchain(int64_t port_,
+                                                                                     uintptr_t that,
+                                                                                     struct wire_cst_prepare_pay_onchain_request *req);
+
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_receive_onchain(int64_t port_,
+                                                                                         uintptr_t that,
+                                                                                         struct wire_cst_prepare_receive_onchain_request *req);
 
 void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_receive_payment(int64_t port_,
                                                                                          uintptr_t that,
                                                                                          struct wire_cst_prepare_receive_request *req);
 
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_refund(int64_t port_,
+                                                                                uintptr_t that,
+                                                                                struct wire_cst_prepare_refund_request *req);
+
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_send_onchain(int64_t port_,
+                                                                                      uintptr_t that,
+                                                                                      struct wire_cst_prepare_send_onchain_request *req);
+
 void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_send_payment(int64_t port_,
                                                                                       uintptr_t that,
                                                                                       struct wire_cst_prepare_send_request *req);
 
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_receive_liquid(int64_t port_,
+                                                                                uintptr_t that,
+                                                                                struct wire_cst_receive_liquid_request *req);
+
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_receive_onchain(int64_t port_,
+                                                                                 uintptr_t that,
+                                                                                 struct wire_cst_prepare_receive_onchain_response *req);
+
 void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_receive_payment(int64_t port_,
                                                                                  uintptr_t that,
                                                                                  struct wire_cst_prepare_receive_response *req);
 
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_refund(int64_t port_,
+                                                                        uintptr_t that,
+                                                                        struct wire_cst_refund_request *req);
+
 WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_restore(uintptr_t that,
                                                                                          struct wire_cst_restore_request *req);
 
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_send_onchain(int64_t port_,
+                                                                              uintptr_t that,
+                                                                              struct wire_cst_prepare_send_onchain_response *req);
+
 void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_send_payment(int64_t port_,
                                                                               uintptr_t that,
                                                                               struct wire_cst_prepare_send_response *req);
 
+void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_set_payment_metadata(int64_t port_,
+                                                                                      uintptr_t that,
+                                                                                      struct wire_cst_set_payment_metadata_request *req);
+
 void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_sync(int64_t port_,
                                                                       uintptr_t that);
 
@@ -307,50 +861,155 @@ void frbgen_breez_liquid_rust_arc_increment_strong_count_RustOpaque_flutter_rust
 
 void frbgen_breez_liquid_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(const void *ptr);
 
+struct wire_cst_asset_metadata *frbgen_breez_liquid_cst_new_box_autoadd_asset_metadata(void);
+
 struct wire_cst_backup_request *frbgen_breez_liquid_cst_new_box_autoadd_backup_request(void);
 
 struct wire_cst_binding_event_listener *frbgen_breez_liquid_cst_new_box_autoadd_binding_event_listener(void);
 
+struct wire_cst_bitcoin_address_data *frbgen_breez_liquid_cst_new_box_autoadd_bitcoin_address_data(void);
+
+bool *frbgen_breez_liquid_cst_new_box_autoadd_bool(bool value);
+
 struct wire_cst_connect_request *frbgen_breez_liquid_cst_new_box_autoadd_connect_request(void);
 
+struct wire_cst_export_payments_request *frbgen_breez_liquid_cst_new_box_autoadd_export_payments_request(void);
+
+double *frbgen_breez_liquid_cst_new_box_autoadd_f_64(double value);
+
+struct wire_cst_fee_breakdown *frbgen_breez_liquid_cst_new_box_autoadd_fee_breakdown(void);
+
+struct wire_cst_get_payment_request *frbgen_breez_liquid_cst_new_box_autoadd_get_payment_request(void);
+
+struct wire_cst_liquid_address_data *frbgen_breez_liquid_cst_new_box_autoadd_liquid_address_data(void);
+
 struct wire_cst_liquid_sdk_event *frbgen_breez_liquid_cst_new_box_autoadd_liquid_sdk_event(void);
 
+struct wire_cst_list_payments_request *frbgen_breez_liquid_cst_new_box_autoadd_list_payments_request(void);
+
+struct wire_cst_ln_invoice *frbgen_breez_liquid_cst_new_box_autoadd_ln_invoice(void);
+
+struct wire_cst_ln_url_withdraw_request *frbgen_breez_liquid_cst_new_box_autoadd_ln_url_withdraw_request(void);
+
+struct wire_cst_onchain_fee_rate *frbgen_breez_liquid_cst_new_box_autoadd_onchain_fee_rate(void);
+
+struct wire_cst_pay_onchain_request *frbgen_breez_liquid_cst_new_box_autoadd_pay_onchain_request(void);
+
 struct wire_cst_payment *frbgen_breez_liquid_cst_new_box_autoadd_payment(void);
 
+struct wire_cst_payment_metadata *frbgen_breez_liquid_cst_new_box_autoadd_payment_metadata(void);
+
+struct wire_cst_prepare_ln_url_pay_request *frbgen_breez_liquid_cst_new_box_autoadd_prepare_ln_url_pay_request(void);
+
+struct wire_cst_prepare_ln_url_pay_response *frbgen_breez_liquid_cst_new_box_autoadd_prepare_ln_url_pay_response(void);
+
+struct wire_cst_prepare_pay_onchain_request *frbgen_breez_liquid_cst_new_box_autoadd_prepare_pay_onchain_request(void);
+
+struct wire_cst_prepare_receive_onchain_request *frbgen_breez_liquid_cst_new_box_autoadd_prepare_receive_onchain_request(void);
+
+struct wire_cst_prepare_receive_onchain_response *frbgen_breez_liquid_cst_new_box_autoadd_prepare_receive_onchain_response(void);
+
 struct wire_cst_prepare_receive_request *frbgen_breez_liquid_cst_new_box_autoadd_prepare_receive_request(void);
 
 struct wire_cst_prepare_receive_response *frbgen_breez_liquid_cst_new_box_autoadd_prepare_receive_response(void);
 
+struct wire_cst_prepare_refund_request *frbgen_breez_liquid_cst_new_box_autoadd_prepare_refund_request(void);
+
+struct wire_cst_prepare_send_onchain_request *frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_onchain_request(void);
+
+struct wire_cst_prepare_send_onchain_response *frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_onchain_response(void);
+
 struct wire_cst_prepare_send_request *frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_request(void);
 
 struct wire_cst_prepare_send_response *frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_response(void);
 
+struct wire_cst_receive_liquid_request *frbgen_breez_liquid_cst_new_box_autoadd_receive_liquid_request(void);
+
+struct wire_cst_refund_request *frbgen_breez_liquid_cst_new_box_autoadd_refund_request(void);
+
 struct wire_cst_restore_request *frbgen_breez_liquid_cst_new_box_autoadd_restore_request(void);
 
+struct wire_cst_set_payment_metadata_request *frbgen_breez_liquid_cst_new_box_autoadd_set_payment_metadata_request(void);
+
+struct wire_cst_success_action *frbgen_breez_liquid_cst_new_box_autoadd_success_action(void);
+
+struct wire_cst_success_action_processed *frbgen_breez_liquid_cst_new_box_autoadd_success_action_processed(void);
+
+uint32_t *frbgen_breez_liquid_cst_new_box_autoadd_u_32(uint32_t value);
+
 uint64_t *frbgen_breez_liquid_cst_new_box_autoadd_u_64(uint64_t value);
 
+struct wire_cst_list_asset_balance *frbgen_breez_liquid_cst_new_list_asset_balance(int32_t len);
+
+struct wire_cst_list_asset_metadata *frbgen_breez_liquid_cst_new_list_asset_metadata(int32_t len);
+
+struct wire_cst_list_electrum_server *frbgen_breez_liquid_cst_new_list_electrum_server(int32_t len);
+
+struct wire_cst_list_electrum_server_status *frbgen_breez_liquid_cst_new_list_electrum_server_status(int32_t len);
+
 struct wire_cst_list_payment *frbgen_breez_liquid_cst_new_list_payment(int32_t len);
 
+struct wire_cst_list_payment_state *frbgen_breez_liquid_cst_new_list_payment_state(int32_t len);
+
+struct wire_cst_list_payment_type *frbgen_breez_liquid_cst_new_list_payment_type(int32_t len);
+
 struct wire_cst_list_prim_u_8_strict *frbgen_breez_liquid_cst_new_list_prim_u_8_strict(int32_t len);
 
+struct wire_cst_list_refundable_swap *frbgen_breez_liquid_cst_new_list_refundable_swap(int32_t len);
+
 struct wire_cst_list_route_hint *frbgen_breez_liquid_cst_new_list_route_hint(int32_t len);
 
 struct wire_cst_list_route_hint_hop *frbgen_breez_liquid_cst_new_list_route_hint_hop(int32_t len);
 static int64_t dummy_method_to_enforce_bundling(void) {
     int64_t dummy_var = 0;
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_asset_metadata);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_backup_request);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_binding_event_listener);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_bitcoin_address_data);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_bool);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_connect_request);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_export_payments_request);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_f_64);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_fee_breakdown);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_get_payment_request);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_liquid_address_data);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_liquid_sdk_event);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_list_payments_request);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_ln_invoice);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_ln_url_withdraw_request);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_onchain_fee_rate);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_pay_onchain_request);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_payment);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_payment_metadata);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_prepare_ln_url_pay_request);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_prepare_ln_url_pay_response);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_prepare_pay_onchain_request);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_prepare_receive_onchain_request);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_prepare_receive_onchain_response);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_prepare_receive_request);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_prepare_receive_response);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_prepare_refund_request);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_onchain_request);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_onchain_response);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_request);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_response);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_receive_liquid_request);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_refund_request);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_restore_request);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_set_payment_metadata_request);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_success_action);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_success_action_processed);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_u_32);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_box_autoadd_u_64);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_asset_balance);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_asset_metadata);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_electrum_server);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_electrum_server_status);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_payment);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_payment_state);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_payment_type);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_prim_u_8_strict);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_refundable_swap);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_route_hint);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_cst_new_list_route_hint_hop);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk);
@@ -359,13 +1018,33 @@ static int64_t dummy_method_to_enforce_bundling(void) {
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_backup);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_disconnect);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_empty_wallet_cache);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_export_payments);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fetch_lightning_limits);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fetch_recommended_fees);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_info);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_payment);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_electrum_servers);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_payments);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_refundables);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_lnurl_pay);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_lnurl_withdraw);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_parse);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_pay_onchain);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_lnurl_pay);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_pay_onchain);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_receive_onchain);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_receive_payment);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_refund);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_send_onchain);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_send_payment);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_receive_liquid);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_receive_onchain);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_receive_payment);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_refund);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_restore);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_send_onchain);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_send_payment);
+    dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_set_payment_metadata);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_sync);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__binding_event_listener_on_event);
     dummy_var ^= ((int64_t) (void*) frbgen_breez_liquid_wire__crate__bindings__breez_log_stream);
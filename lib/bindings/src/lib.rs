@@ -83,6 +83,18 @@ impl BindingLiquidSdk {
         rt().block_on(self.sdk.get_info()).map_err(Into::into)
     }
 
+    pub fn parse(&self, input: String) -> Result<InputType, PaymentError> {
+        self.sdk.parse(&input)
+    }
+
+    pub fn fetch_lightning_limits(&self) -> Result<LightningPaymentLimitsResponse, PaymentError> {
+        rt().block_on(self.sdk.fetch_lightning_limits())
+    }
+
+    pub fn fetch_recommended_fees(&self) -> Result<RecommendedFeesResponse, PaymentError> {
+        rt().block_on(self.sdk.fetch_recommended_fees())
+    }
+
     pub fn prepare_send_payment(
         &self,
         req: PrepareSendRequest,
@@ -97,6 +109,38 @@ impl BindingLiquidSdk {
         rt().block_on(self.sdk.send_payment(&req))
     }
 
+    pub fn lnurl_withdraw(
+        &self,
+        req: LnUrlWithdrawRequest,
+    ) -> Result<LnUrlWithdrawResult, PaymentError> {
+        rt().block_on(self.sdk.lnurl_withdraw(&req))
+    }
+
+    pub fn prepare_lnurl_pay(
+        &self,
+        req: PrepareLnUrlPayRequest,
+    ) -> Result<PrepareLnUrlPayResponse, PaymentError> {
+        rt().block_on(self.sdk.prepare_lnurl_pay(&req))
+    }
+
+    pub fn lnurl_pay(&self, req: PrepareLnUrlPayResponse) -> Result<LnUrlPayResult, PaymentError> {
+        rt().block_on(self.sdk.lnurl_pay(&req))
+    }
+
+    pub fn prepare_send_onchain(
+        &self,
+        req: PrepareSendOnchainRequest,
+    ) -> Result<PrepareSendOnchainResponse, PaymentError> {
+        rt().block_on(self.sdk.prepare_send_onchain(&req))
+    }
+
+    pub fn send_onchain(
+        &self,
+        req: PrepareSendOnchainResponse,
+    ) -> Result<SendPaymentResponse, PaymentError> {
+        rt().block_on(self.sdk.send_onchain(&req))
+    }
+
     pub fn prepare_receive_payment(
         &self,
         req: PrepareReceiveRequest,
@@ -111,8 +155,74 @@ impl BindingLiquidSdk {
         rt().block_on(self.sdk.receive_payment(&req))
     }
 
-    pub fn list_payments(&self) -> Result<Vec<Payment>, PaymentError> {
-        rt().block_on(self.sdk.list_payments())
+    pub fn prepare_pay_onchain(
+        &self,
+        req: PreparePayOnchainRequest,
+    ) -> Result<PreparePayOnchainResponse, PaymentError> {
+        rt().block_on(self.sdk.prepare_pay_onchain(&req))
+    }
+
+    pub fn pay_onchain(&self, req: PayOnchainRequest) -> Result<SendPaymentResponse, PaymentError> {
+        rt().block_on(self.sdk.pay_onchain(&req))
+    }
+
+    pub fn prepare_receive_onchain(
+        &self,
+        req: PrepareReceiveOnchainRequest,
+    ) -> Result<PrepareReceiveOnchainResponse, PaymentError> {
+        rt().block_on(self.sdk.prepare_receive_onchain(&req))
+    }
+
+    pub fn receive_onchain(
+        &self,
+        req: PrepareReceiveOnchainResponse,
+    ) -> Result<ReceiveOnchainResponse, PaymentError> {
+        rt().block_on(self.sdk.receive_onchain(&req))
+    }
+
+    pub fn receive_liquid(
+        &self,
+        req: ReceiveLiquidRequest,
+    ) -> Result<ReceiveLiquidResponse, PaymentError> {
+        rt().block_on(self.sdk.receive_liquid(&req))
+    }
+
+    pub fn list_refundables(&self) -> LiquidSdkResult<Vec<RefundableSwap>> {
+        rt().block_on(self.sdk.list_refundables())
+    }
+
+    pub fn prepare_refund(
+        &self,
+        req: PrepareRefundRequest,
+    ) -> Result<PrepareRefundResponse, PaymentError> {
+        rt().block_on(self.sdk.prepare_refund(&req))
+    }
+
+    pub fn refund(&self, req: RefundRequest) -> Result<RefundResponse, PaymentError> {
+        rt().block_on(self.sdk.refund(&req))
+    }
+
+    pub fn get_payment(&self, req: GetPaymentRequest) -> Result<Option<Payment>, PaymentError> {
+        rt().block_on(self.sdk.get_payment(&req))
+    }
+
+    pub fn set_payment_metadata(&self, req: SetPaymentMetadataRequest) -> Result<(), PaymentError> {
+        rt().block_on(self.sdk.set_payment_metadata(&req))
+    }
+
+    pub fn list_payments(&self, req: ListPaymentsRequest) -> Result<Vec<Payment>, PaymentError> {
+        rt().block_on(self.sdk.list_payments(&req))
+    }
+
+    pub fn export_payments(
+        &self,
+        req: ExportPaymentsRequest,
+    ) -> Result<ExportPaymentsResponse, PaymentError> {
+        rt().block_on(self.sdk.export_payments(&req))
+    }
+
+    pub fn list_electrum_servers(&self) -> LiquidSdkResult<Vec<ElectrumServerStatus>> {
+        rt().block_on(self.sdk.list_electrum_servers())
     }
 
     pub fn sync(&self) -> LiquidSdkResult<()> {
@@ -78,6 +78,11 @@ impl BindingLiquidSdk {
         self.sdk.get_info().await.map_err(Into::into)
     }
 
+    #[frb(sync)]
+    pub fn parse(&self, input: String) -> Result<InputType, PaymentError> {
+        self.sdk.parse(&input)
+    }
+
     pub async fn add_event_listener(
         &self,
         listener: StreamSink<LiquidSdkEvent>,
@@ -87,6 +92,16 @@ impl BindingLiquidSdk {
             .await
     }
 
+    pub async fn fetch_lightning_limits(
+        &self,
+    ) -> Result<LightningPaymentLimitsResponse, PaymentError> {
+        self.sdk.fetch_lightning_limits().await
+    }
+
+    pub async fn fetch_recommended_fees(&self) -> Result<RecommendedFeesResponse, PaymentError> {
+        self.sdk.fetch_recommended_fees().await
+    }
+
     pub async fn prepare_send_payment(
         &self,
         req: PrepareSendRequest,
@@ -101,6 +116,41 @@ impl BindingLiquidSdk {
         self.sdk.send_payment(&req).await
     }
 
+    pub async fn lnurl_withdraw(
+        &self,
+        req: LnUrlWithdrawRequest,
+    ) -> Result<LnUrlWithdrawResult, PaymentError> {
+        self.sdk.lnurl_withdraw(&req).await
+    }
+
+    pub async fn prepare_lnurl_pay(
+        &self,
+        req: PrepareLnUrlPayRequest,
+    ) -> Result<PrepareLnUrlPayResponse, PaymentError> {
+        self.sdk.prepare_lnurl_pay(&req).await
+    }
+
+    pub async fn lnurl_pay(
+        &self,
+        req: PrepareLnUrlPayResponse,
+    ) -> Result<LnUrlPayResult, PaymentError> {
+        self.sdk.lnurl_pay(&req).await
+    }
+
+    pub async fn prepare_send_onchain(
+        &self,
+        req: PrepareSendOnchainRequest,
+    ) -> Result<PrepareSendOnchainResponse, PaymentError> {
+        self.sdk.prepare_send_onchain(&req).await
+    }
+
+    pub async fn send_onchain(
+        &self,
+        req: PrepareSendOnchainResponse,
+    ) -> Result<SendPaymentResponse, PaymentError> {
+        self.sdk.send_onchain(&req).await
+    }
+
     pub async fn prepare_receive_payment(
         &self,
         req: PrepareReceiveRequest,
@@ -115,8 +165,86 @@ impl BindingLiquidSdk {
         self.sdk.receive_payment(&req).await
     }
 
-    pub async fn list_payments(&self) -> Result<Vec<Payment>, PaymentError> {
-        self.sdk.list_payments().await
+    pub async fn prepare_pay_onchain(
+        &self,
+        req: PreparePayOnchainRequest,
+    ) -> Result<PreparePayOnchainResponse, PaymentError> {
+        self.sdk.prepare_pay_onchain(&req).await
+    }
+
+    pub async fn pay_onchain(
+        &self,
+        req: PayOnchainRequest,
+    ) -> Result<SendPaymentResponse, PaymentError> {
+        self.sdk.pay_onchain(&req).await
+    }
+
+    pub async fn prepare_receive_onchain(
+        &self,
+        req: PrepareReceiveOnchainRequest,
+    ) -> Result<PrepareReceiveOnchainResponse, PaymentError> {
+        self.sdk.prepare_receive_onchain(&req).await
+    }
+
+    pub async fn receive_onchain(
+        &self,
+        req: PrepareReceiveOnchainResponse,
+    ) -> Result<ReceiveOnchainResponse, PaymentError> {
+        self.sdk.receive_onchain(&req).await
+    }
+
+    pub async fn receive_liquid(
+        &self,
+        req: ReceiveLiquidRequest,
+    ) -> Result<ReceiveLiquidResponse, PaymentError> {
+        self.sdk.receive_liquid(&req).await
+    }
+
+    pub async fn list_refundables(&self) -> Result<Vec<RefundableSwap>, LiquidSdkError> {
+        self.sdk.list_refundables().await
+    }
+
+    pub async fn prepare_refund(
+        &self,
+        req: PrepareRefundRequest,
+    ) -> Result<PrepareRefundResponse, PaymentError> {
+        self.sdk.prepare_refund(&req).await
+    }
+
+    pub async fn refund(&self, req: RefundRequest) -> Result<RefundResponse, PaymentError> {
+        self.sdk.refund(&req).await
+    }
+
+    pub async fn get_payment(
+        &self,
+        req: GetPaymentRequest,
+    ) -> Result<Option<Payment>, PaymentError> {
+        self.sdk.get_payment(&req).await
+    }
+
+    pub async fn set_payment_metadata(
+        &self,
+        req: SetPaymentMetadataRequest,
+    ) -> Result<(), PaymentError> {
+        self.sdk.set_payment_metadata(&req).await
+    }
+
+    pub async fn list_payments(
+        &self,
+        req: ListPaymentsRequest,
+    ) -> Result<Vec<Payment>, PaymentError> {
+        self.sdk.list_payments(&req).await
+    }
+
+    pub async fn export_payments(
+        &self,
+        req: ExportPaymentsRequest,
+    ) -> Result<ExportPaymentsResponse, PaymentError> {
+        self.sdk.export_payments(&req).await
+    }
+
+    pub async fn list_electrum_servers(&self) -> Result<Vec<ElectrumServerStatus>, LiquidSdkError> {
+        self.sdk.list_electrum_servers().await
     }
 
     pub async fn sync(&self) -> Result<(), LiquidSdkError> {
@@ -0,0 +1,609 @@
+use std::collections::HashSet;
+use std::io::Read;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use anyhow::Result;
+use electrum_client::ElectrumApi;
+use lwk_wollet::elements::{encode, Script, Transaction, Txid};
+use lwk_wollet::hashes::{sha256, Hash};
+use lwk_wollet::{BlockchainBackend, ElectrumClient, ElectrumUrl, EsploraClient, Wollet};
+use serde::Deserialize;
+
+use crate::electrum::{new_electrum_client, ElectrumRequestError, ElectrumServers};
+use crate::error::PaymentError;
+use crate::model::Config;
+
+/// Queries and updates the Liquid chain: wallet scans, broadcasts, txs and script histories
+pub(crate) trait ChainService: Send + Sync {
+    /// Get the height of the chain tip
+    fn tip(&self) -> Result<u32, PaymentError>;
+
+    /// Broadcast a tx, returning its id
+    fn broadcast(&self, tx: &Transaction) -> Result<Txid, PaymentError>;
+
+    /// Get txs by their ids
+    fn get_transactions(&self, txids: &[Txid]) -> Result<Vec<Transaction>, PaymentError>;
+
+    /// Get the ids of the txs spending from or to a script, oldest first
+    fn get_script_history(&self, script: &Script) -> Result<Vec<Txid>, PaymentError>;
+
+    /// Perform a full scan of a wallet, applying the found txs to it
+    fn full_scan(&self, wallet: &mut Wollet) -> Result<(), PaymentError>;
+
+    /// Subscribe to the chain tip and to the given scripts, returning which of them changed
+    /// since the previous call. Scripts stay subscribed once passed.
+    ///
+    /// Returns `None` if the backend does not support subscriptions.
+    fn poll_changes(&self, scripts: &[Script]) -> Result<Option<ChainChanges>, PaymentError>;
+}
+
+/// The changes reported by [ChainService::poll_changes]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) struct ChainChanges {
+    /// A new block was found
+    pub(crate) tip_changed: bool,
+    /// The history of a subscribed script changed: it has a new tx, or one of its txs confirmed
+    pub(crate) scripts_changed: bool,
+}
+
+/// Creates the [ChainService] of the [Config]: Esplora if [Config::esplora_url] is set, else
+/// Electrum
+pub(crate) fn new_chain_service(
+    config: &Config,
+    electrum_servers: Arc<ElectrumServers>,
+) -> Arc<dyn ChainService> {
+    match &config.esplora_url {
+        Some(esplora_url) => Arc::new(EsploraChainService::new(esplora_url)),
+        None => Arc::new(ElectrumChainService::new(electrum_servers)),
+    }
+}
+
+fn lock<T>(client: &Mutex<T>) -> Result<MutexGuard<T>, PaymentError> {
+    client.lock().map_err(|_| PaymentError::Generic {
+        err: "Chain client is poisoned".to_string(),
+    })
+}
+
+/// A connection subscribed to the chain tip and to scripts, see [ChainService::poll_changes]
+struct Subscription {
+    client: electrum_client::Client,
+    scripts: HashSet<Script>,
+}
+
+impl Subscription {
+    fn poll_changes(&mut self, scripts: &[Script]) -> Result<ChainChanges, electrum_client::Error> {
+        for script in scripts {
+            if !self.scripts.contains(script) {
+                self.client.script_subscribe(electrum_script(script))?;
+                self.scripts.insert(script.clone());
+            }
+        }
+
+        // Notifications are only read from the connection along with responses
+        self.client.ping()?;
+        let mut changes = ChainChanges::default();
+        while self.client.block_headers_pop_raw()?.is_some() {
+            changes.tip_changed = true;
+        }
+        for script in &self.scripts {
+            if self.client.script_pop(electrum_script(script))?.is_some() {
+                changes.scripts_changed = true;
+            }
+        }
+        Ok(changes)
+    }
+}
+
+/// `electrum_client` only handles Bitcoin scripts, which have the same encoding
+fn electrum_script(script: &Script) -> &electrum_client::bitcoin::Script {
+    electrum_client::bitcoin::Script::from_bytes(script.as_bytes())
+}
+
+/// [ChainService] using the Liquid [ElectrumServers], failing over between them per request
+pub(crate) struct ElectrumChainService {
+    electrum_servers: Arc<ElectrumServers>,
+    /// The client of each server, connected on first use and dropped after a failed request
+    clients: Vec<Mutex<Option<ElectrumClient>>>,
+    /// Dropped after a failed poll, to subscribe again on the next one
+    subscription: Mutex<Option<Subscription>>,
+}
+
+impl ElectrumChainService {
+    pub(crate) fn new(electrum_servers: Arc<ElectrumServers>) -> Self {
+        let clients = (0..electrum_servers.len())
+            .map(|_| Mutex::new(None))
+            .collect();
+        Self {
+            electrum_servers,
+            clients,
+            subscription: Mutex::new(None),
+        }
+    }
+
+    fn subscribe(&self) -> Result<Subscription, PaymentError> {
+        self.electrum_servers.with_failover(|_, server| {
+            let subscription_error = |e: electrum_client::Error| {
+                let err = PaymentError::Generic {
+                    err: format!(
+                        "Failed to subscribe to Electrum server {}: {e:?}",
+                        server.url
+                    ),
+                };
+                ElectrumRequestError::electrum(&e, err)
+            };
+            let client = new_electrum_client(server).map_err(subscription_error)?;
+            client
+                .block_headers_subscribe_raw()
+                .map_err(subscription_error)?;
+            Ok(Subscription {
+                client,
+                scripts: HashSet::new(),
+            })
+        })
+    }
+
+    fn with_client<T>(
+        &self,
+        mut request: impl FnMut(&mut ElectrumClient) -> Result<T, lwk_wollet::Error>,
+    ) -> Result<T, PaymentError> {
+        self.electrum_servers.with_failover(|index, server| {
+            let mut cached_client =
+                lock(&self.clients[index]).map_err(ElectrumRequestError::Response)?;
+            let mut client = match cached_client.take() {
+                Some(client) => client,
+                None => ElectrumClient::new(&ElectrumUrl::new(
+                    &server.url,
+                    server.tls,
+                    server.validate_domain,
+                ))?,
+            };
+            let res = request(&mut client)?;
+            *cached_client = Some(client);
+            Ok(res)
+        })
+    }
+}
+
+impl ChainService for ElectrumChainService {
+    fn tip(&self) -> Result<u32, PaymentError> {
+        self.with_client(|client| Ok(client.tip()?.height))
+    }
+
+    fn broadcast(&self, tx: &Transaction) -> Result<Txid, PaymentError> {
+        self.with_client(|client| client.broadcast(tx))
+    }
+
+    fn get_transactions(&self, txids: &[Txid]) -> Result<Vec<Transaction>, PaymentError> {
+        self.with_client(|client| client.get_transactions(txids))
+    }
+
+    fn get_script_history(&self, script: &Script) -> Result<Vec<Txid>, PaymentError> {
+        self.with_client(|client| {
+            Ok(client
+                .get_scripts_history(&[script])?
+                .into_iter()
+                .flatten()
+                .map(|history| history.txid)
+                .collect())
+        })
+    }
+
+    fn full_scan(&self, wallet: &mut Wollet) -> Result<(), PaymentError> {
+        self.with_client(|client| lwk_wollet::full_scan_with_electrum_client(wallet, client))
+    }
+
+    fn poll_changes(&self, scripts: &[Script]) -> Result<Option<ChainChanges>, PaymentError> {
+        let mut subscription = lock(&self.subscription)?;
+        let (mut active_subscription, resubscribed) = match subscription.take() {
+            Some(active_subscription) => (active_subscription, false),
+            None => (self.subscribe()?, true),
+        };
+        let changes =
+            active_subscription
+                .poll_changes(scripts)
+                .map_err(|e| PaymentError::Generic {
+                    err: format!("Failed to poll Electrum subscriptions: {e:?}"),
+                })?;
+        *subscription = Some(active_subscription);
+        // Changes may have been missed while not subscribed
+        Ok(Some(ChainChanges {
+            tip_changed: changes.tip_changed || resubscribed,
+            scripts_changed: changes.scripts_changed || resubscribed,
+        }))
+    }
+}
+
+/// The number of confirmed txs per page of an Esplora script history
+const ESPLORA_CHAIN_TXS_PAGE_SIZE: usize = 25;
+
+/// A tx of an Esplora script history
+#[derive(Deserialize)]
+struct EsploraTx {
+    txid: String,
+    status: EsploraTxStatus,
+}
+
+#[derive(Deserialize)]
+struct EsploraTxStatus {
+    confirmed: bool,
+}
+
+/// [ChainService] using an Esplora REST API, for deployments without access to Electrum servers
+pub(crate) struct EsploraChainService {
+    base_url: String,
+    client: Mutex<EsploraClient>,
+}
+
+impl EsploraChainService {
+    pub(crate) fn new(esplora_url: &str) -> Self {
+        let base_url = esplora_url.trim_end_matches('/').to_string();
+        Self {
+            client: Mutex::new(EsploraClient::new(&base_url)),
+            base_url,
+        }
+    }
+
+    fn get(&self, path: &str) -> Result<ureq::Response, PaymentError> {
+        ureq::get(&format!("{}{path}", self.base_url))
+            .call()
+            .map_err(|e| esplora_error(path, e))
+    }
+
+    fn get_string(&self, path: &str) -> Result<String, PaymentError> {
+        self.get(path)?
+            .into_string()
+            .map_err(|e| esplora_error(path, e))
+    }
+
+    fn get_txs(&self, path: &str) -> Result<Vec<EsploraTx>, PaymentError> {
+        serde_json::from_str(&self.get_string(path)?).map_err(|e| esplora_error(path, e))
+    }
+}
+
+pub(crate) fn esplora_error(path: &str, err: impl std::fmt::Display) -> PaymentError {
+    PaymentError::Generic {
+        err: format!("Esplora request {path} failed: {err}"),
+    }
+}
+
+impl ChainService for EsploraChainService {
+    fn tip(&self) -> Result<u32, PaymentError> {
+        let path = "/blocks/tip/height";
+        self.get_string(path)?
+            .trim()
+            .parse()
+            .map_err(|e| esplora_error(path, e))
+    }
+
+    fn broadcast(&self, tx: &Transaction) -> Result<Txid, PaymentError> {
+        let path = "/tx";
+        ureq::post(&format!("{}{path}", self.base_url))
+            .send_string(&encode::serialize_hex(tx))
+            .map_err(|e| esplora_error(path, e))?
+            .into_string()
+            .map_err(|e| esplora_error(path, e))?
+            .trim()
+            .parse()
+            .map_err(|e| esplora_error(path, e))
+    }
+
+    fn get_transactions(&self, txids: &[Txid]) -> Result<Vec<Transaction>, PaymentError> {
+        txids
+            .iter()
+            .map(|txid| {
+                let path = format!("/tx/{txid}/raw");
+                let mut tx_bytes = vec![];
+                self.get(&path)?
+                    .into_reader()
+                    .read_to_end(&mut tx_bytes)
+                    .map_err(|e| esplora_error(&path, e))?;
+                encode::deserialize(&tx_bytes).map_err(|e| esplora_error(&path, e))
+            })
+            .collect()
+    }
+
+    fn get_script_history(&self, script: &Script) -> Result<Vec<Txid>, PaymentError> {
+        let script_hash = sha256::Hash::hash(script.as_bytes());
+        let path = format!("/scripthash/{script_hash}/txs");
+        // Esplora lists the unconfirmed txs first, then the confirmed ones, newest first. Only the
+        // first page of confirmed txs comes with the unconfirmed ones, the next pages are listed
+        // after the last confirmed tx seen.
+        let mut txs = self.get_txs(&path)?;
+        let mut page_len = txs.iter().filter(|tx| tx.status.confirmed).count();
+        while page_len == ESPLORA_CHAIN_TXS_PAGE_SIZE {
+            let Some(last_seen) = txs.last() else {
+                break;
+            };
+            let page = self.get_txs(&format!("{path}/chain/{}", last_seen.txid))?;
+            page_len = page.len();
+            txs.extend(page);
+        }
+        txs.into_iter()
+            .rev()
+            .map(|tx| tx.txid.parse().map_err(|e| esplora_error(&path, e)))
+            .collect()
+    }
+
+    fn full_scan(&self, wallet: &mut Wollet) -> Result<(), PaymentError> {
+        if let Some(update) = lock(&self.client)?.full_scan(wallet)? {
+            wallet.apply_update(update)?;
+        }
+        Ok(())
+    }
+
+    fn poll_changes(&self, _scripts: &[Script]) -> Result<Option<ChainChanges>, PaymentError> {
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Write};
+    use std::sync::Arc;
+
+    use anyhow::Result;
+    use lwk_wollet::bitcoin::hex::DisplayHex;
+    use lwk_wollet::elements::{
+        encode, BlockExtData, BlockHash, BlockHeader, LockTime, OutPoint, Script, Transaction,
+        TxIn, TxMerkleNode, TxOut,
+    };
+    use lwk_wollet::hashes::{sha256, Hash};
+    use serde_json::{json, Value};
+
+    use crate::chain::{ChainChanges, ChainService, ElectrumChainService, EsploraChainService};
+    use crate::electrum::ElectrumServers;
+    use crate::model::{Config, ElectrumServer};
+    use crate::test_utils::chain::EsploraStandIn;
+    use crate::test_utils::mock_hash;
+    use crate::test_utils::persist::create_temp_dir;
+    use crate::test_utils::TEST_MNEMONIC;
+    use crate::wallet::{LiquidOnchainWallet, OnchainWallet};
+
+    #[test]
+    fn electrum_subscriptions() -> Result<()> {
+        let script = Script::from(vec![0x51]);
+        let mut script_hash = sha256::Hash::hash(script.as_bytes()).to_byte_array();
+        script_hash.reverse();
+        let script_hash = script_hash.to_lower_hex_string();
+
+        // Local stand-in Electrum server, notifying a new tip before the 3rd ping response and a
+        // script change before the 4th one
+        let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
+        let url = listener.local_addr()?.to_string();
+        std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut writer = stream.try_clone().unwrap();
+            let mut pings = 0;
+            for line in BufReader::new(stream).lines() {
+                let request: Value = serde_json::from_str(&line.unwrap()).unwrap();
+                let result = match request["method"].as_str() {
+                    Some("blockchain.headers.subscribe") => json!({"height": 100, "hex": "00"}),
+                    Some("server.ping") => {
+                        pings += 1;
+                        let notification = match pings {
+                            3 => Some(json!({
+                                "method": "blockchain.headers.subscribe",
+                                "params": [{"height": 101, "hex": "00"}],
+                            })),
+                            4 => Some(json!({
+                                "method": "blockchain.scripthash.subscribe",
+                                "params": [script_hash, "aa".repeat(32)],
+                            })),
+                            _ => None,
+                        };
+                        if let Some(mut notification) = notification {
+                            notification["jsonrpc"] = json!("2.0");
+                            writeln!(writer, "{notification}").unwrap();
+                        }
+                        Value::Null
+                    }
+                    _ => Value::Null,
+                };
+                let response = json!({"jsonrpc": "2.0", "id": request["id"], "result": result});
+                writeln!(writer, "{response}").unwrap();
+            }
+        });
+
+        let chain_service =
+            ElectrumChainService::new(Arc::new(ElectrumServers::new(vec![ElectrumServer {
+                url,
+                tls: false,
+                validate_domain: false,
+            }])));
+        let scripts = [script];
+
+        let changes = |tip_changed, scripts_changed| {
+            Some(ChainChanges {
+                tip_changed,
+                scripts_changed,
+            })
+        };
+
+        // Subscribing reports all changes, as they may have been missed before
+        assert_eq!(chain_service.poll_changes(&scripts)?, changes(true, true));
+        assert_eq!(chain_service.poll_changes(&scripts)?, changes(false, false));
+        assert_eq!(chain_service.poll_changes(&scripts)?, changes(true, false));
+        assert_eq!(chain_service.poll_changes(&scripts)?, changes(false, true));
+        assert_eq!(chain_service.poll_changes(&scripts)?, changes(false, false));
+
+        Ok(())
+    }
+
+    fn esplora_script_hash(script: &Script) -> sha256::Hash {
+        sha256::Hash::hash(script.as_bytes())
+    }
+
+    /// Esplora txs, confirmed or not
+    fn esplora_txs(txids: &[String], confirmed: bool) -> Vec<Value> {
+        txids
+            .iter()
+            .map(|txid| json!({"txid": txid, "status": {"confirmed": confirmed}}))
+            .collect()
+    }
+
+    #[test]
+    fn esplora_chain_service() -> Result<()> {
+        let lockup_tx_id = "1".repeat(64);
+        let claim_tx_id = "2".repeat(64);
+        let script = Script::from(vec![0x51]);
+        let script_hash = esplora_script_hash(&script);
+
+        let tx = Transaction {
+            version: 2,
+            lock_time: LockTime::ZERO,
+            input: vec![TxIn {
+                previous_output: OutPoint::new(lockup_tx_id.parse()?, 0),
+                ..Default::default()
+            }],
+            output: vec![TxOut::default()],
+        };
+        let tx_id = tx.txid();
+        let esplora = EsploraStandIn::start(vec![
+            ("/blocks/tip/height".to_string(), b"1500000\n".to_vec()),
+            (
+                format!("/scripthash/{script_hash}/txs"),
+                serde_json::to_vec(
+                    &[
+                        esplora_txs(&[claim_tx_id.clone()], false),
+                        esplora_txs(&[lockup_tx_id.clone()], true),
+                    ]
+                    .concat(),
+                )?,
+            ),
+            ("/tx".to_string(), tx_id.to_string().into_bytes()),
+            (format!("/tx/{tx_id}/raw"), encode::serialize(&tx)),
+        ])?;
+        let chain_service = EsploraChainService::new(&format!("{}/", esplora.url));
+
+        assert_eq!(chain_service.tip()?, 1_500_000);
+
+        let history: Vec<String> = chain_service
+            .get_script_history(&script)?
+            .iter()
+            .map(|txid| txid.to_string())
+            .collect();
+        assert_eq!(history, vec![lockup_tx_id, claim_tx_id]);
+
+        // Txs are broadcast hex-encoded, and fetched raw
+        assert_eq!(chain_service.broadcast(&tx)?, tx_id);
+        assert!(esplora
+            .requests
+            .lock()
+            .unwrap()
+            .contains(&("/tx".to_string(), encode::serialize_hex(&tx))));
+        assert_eq!(chain_service.get_transactions(&[tx_id])?, vec![tx]);
+
+        let unknown_tx_id = mock_hash("unknown tx").parse()?;
+        assert!(chain_service.get_transactions(&[unknown_tx_id]).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn esplora_script_history_pages() -> Result<()> {
+        let script = Script::from(vec![0x51]);
+        let script_hash = esplora_script_hash(&script);
+        // Newest first, like Esplora lists them
+        let txids = |label: &str, count: usize| -> Vec<String> {
+            (0..count)
+                .rev()
+                .map(|index| mock_hash(&format!("{label} {index}")))
+                .collect()
+        };
+        let unconfirmed = txids("unconfirmed", 2);
+        let first_page = txids("first page", 25);
+        let second_page = txids("second page", 25);
+        let last_page = txids("last page", 3);
+
+        let path = format!("/scripthash/{script_hash}/txs");
+        let esplora = EsploraStandIn::start(vec![
+            (
+                path.clone(),
+                serde_json::to_vec(
+                    &[
+                        esplora_txs(&unconfirmed, false),
+                        esplora_txs(&first_page, true),
+                    ]
+                    .concat(),
+                )?,
+            ),
+            (
+                format!("{path}/chain/{}", first_page.last().unwrap()),
+                serde_json::to_vec(&esplora_txs(&second_page, true))?,
+            ),
+            (
+                format!("{path}/chain/{}", second_page.last().unwrap()),
+                serde_json::to_vec(&esplora_txs(&last_page, true))?,
+            ),
+        ])?;
+        let chain_service = EsploraChainService::new(&esplora.url);
+
+        let history: Vec<String> = chain_service
+            .get_script_history(&script)?
+            .iter()
+            .map(|txid| txid.to_string())
+            .collect();
+        let expected_history: Vec<String> = [unconfirmed, first_page, second_page, last_page]
+            .concat()
+            .into_iter()
+            .rev()
+            .collect();
+        assert_eq!(history, expected_history);
+        // A short page is the last one
+        assert_eq!(esplora.requests.lock().unwrap().len(), 3);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn esplora_full_scan() -> Result<()> {
+        let tip = BlockHeader {
+            version: 0x2000_0000,
+            prev_blockhash: BlockHash::all_zeros(),
+            merkle_root: TxMerkleNode::all_zeros(),
+            time: 1_700_000_000,
+            height: 100,
+            ext: BlockExtData::Proof {
+                challenge: Script::new(),
+                solution: Script::new(),
+            },
+        };
+        let tip_hash = tip.block_hash();
+        let esplora = EsploraStandIn::start(vec![
+            (
+                "/blocks/tip/hash".to_string(),
+                tip_hash.to_string().into_bytes(),
+            ),
+            (
+                format!("/block/{tip_hash}/header"),
+                encode::serialize_hex(&tip).into_bytes(),
+            ),
+            // The wallet has no txs yet
+            ("/scripthash/*".to_string(), b"[]".to_vec()),
+        ])?;
+
+        let (_data_dir, data_dir_str) = create_temp_dir()?;
+        let mut config = Config::testnet();
+        config.working_dir = data_dir_str;
+        let chain_service = Arc::new(EsploraChainService::new(&esplora.url));
+        let wallet = LiquidOnchainWallet::new(TEST_MNEMONIC.to_string(), config, chain_service)?;
+        wallet.full_scan().await?;
+        assert!(wallet.transactions().await?.is_empty());
+
+        // The histories of the wallet scripts are fetched from Esplora
+        let requested_paths: Vec<String> = esplora
+            .requests
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(path, _)| path.clone())
+            .collect();
+        assert!(requested_paths.contains(&"/blocks/tip/hash".to_string()));
+        for script in wallet.watched_scripts().await?.iter().take(5) {
+            let path = format!("/scripthash/{}/txs", esplora_script_hash(script));
+            assert!(requested_paths.contains(&path));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,846 @@
+use std::{str::FromStr, sync::Arc};
+
+use anyhow::{anyhow, Result};
+use boltz_client::swaps::boltz::ChainSwapStates;
+use boltz_client::swaps::boltzv2;
+use boltz_client::Amount;
+use log::{debug, error, info, warn};
+use lwk_wollet::elements::{LockTime, Transaction};
+use tokio::sync::broadcast;
+
+use crate::chain::ChainService;
+use crate::model::PaymentState::{Complete, Created, Failed, Pending, Refundable, TimedOut};
+use crate::model::{ChainSwap, Config, Direction, PaymentTxData, PaymentType};
+use crate::swapper::Swapper;
+use crate::wallet::OnchainWallet;
+use crate::{ensure_sdk, utils};
+use crate::{error::PaymentError, model::PaymentState, persist::Persister};
+
+#[derive(Clone)]
+pub(crate) struct ChainSwapStateHandler {
+    config: Config,
+    onchain_wallet: Arc<dyn OnchainWallet>,
+    persister: Arc<Persister>,
+    swapper: Arc<dyn Swapper>,
+    chain_service: Arc<dyn ChainService>,
+    subscription_notifier: broadcast::Sender<String>,
+}
+
+impl ChainSwapStateHandler {
+    pub(crate) fn new(
+        config: Config,
+        onchain_wallet: Arc<dyn OnchainWallet>,
+        persister: Arc<Persister>,
+        swapper: Arc<dyn Swapper>,
+        chain_service: Arc<dyn ChainService>,
+    ) -> Self {
+        let (subscription_notifier, _) = broadcast::channel::<String>(30);
+        Self {
+            config,
+            onchain_wallet,
+            persister,
+            swapper,
+            chain_service,
+            subscription_notifier,
+        }
+    }
+
+    pub(crate) fn subscribe_payment_updates(&self) -> broadcast::Receiver<String> {
+        self.subscription_notifier.subscribe()
+    }
+
+    /// Handles status updates from Boltz for Chain swaps
+    pub(crate) async fn on_new_status(&self, update: &boltzv2::Update) -> Result<()> {
+        let id = update.id();
+        let swap = self
+            .persister
+            .fetch_chain_swap_by_id(id)?
+            .ok_or(anyhow!("No ongoing Chain Swap found for ID {id}"))?;
+
+        match swap.direction {
+            Direction::Incoming => self.on_new_incoming_status(&swap, update).await,
+            Direction::Outgoing => self.on_new_outgoing_status(&swap, update).await,
+        }
+    }
+
+    async fn on_new_incoming_status(
+        &self,
+        swap: &ChainSwap,
+        update: &boltzv2::Update,
+    ) -> Result<()> {
+        let id = &swap.id;
+        let swap_state = update.status();
+        info!("Handling incoming Chain Swap transition to {swap_state:?} for swap {id}");
+
+        // See https://docs.boltz.exchange/v/api/lifecycle#chain-swaps
+        match ChainSwapStates::from_str(swap_state) {
+            // The user lockup tx is in the mempool or confirmed
+            Ok(ChainSwapStates::TransactionMempool | ChainSwapStates::TransactionConfirmed) => {
+                let user_lockup_tx_id = match update {
+                    boltzv2::Update::TransactionMempool { transaction, .. } => {
+                        Some(transaction.id.as_str())
+                    }
+                    _ => None,
+                };
+                match swap.state {
+                    Created | Pending => {
+                        self.update_swap_info(id, Pending, None, user_lockup_tx_id, None, None)
+                            .await?
+                    }
+                    state => {
+                        debug!("Chain Swap {id} is in an invalid state for {swap_state}: {state:?}")
+                    }
+                }
+                Ok(())
+            }
+
+            // The server lockup tx is in the mempool. We claim it if it satisfies the zero-conf
+            // requirements, otherwise we wait for it to be confirmed.
+            Ok(ChainSwapStates::TransactionServerMempool) => {
+                if let Some(claim_tx_id) = &swap.claim_tx_id {
+                    warn!("Claim tx for Chain Swap {id} was already broadcast: txid {claim_tx_id}");
+                    return Ok(());
+                }
+
+                let server_lockup_tx = self.fetch_liquid_server_lockup_tx(swap)?;
+                self.update_swap_info(
+                    id,
+                    Pending,
+                    Some(&server_lockup_tx.txid().to_string()),
+                    None,
+                    None,
+                    None,
+                )
+                .await?;
+
+                if !swap.accept_zero_conf {
+                    warn!(
+                        "[Chain Swap {id}] Zero-conf is not accepted. Waiting for confirmation..."
+                    );
+                    return Ok(());
+                }
+
+                if !self.is_zero_conf_claimable(swap, &server_lockup_tx) {
+                    return Ok(());
+                }
+
+                self.claim_and_log(swap).await;
+                Ok(())
+            }
+
+            // The server lockup tx is confirmed, we claim it
+            Ok(ChainSwapStates::TransactionServerConfirmed) => {
+                match &swap.claim_tx_id {
+                    Some(claim_tx_id) => {
+                        warn!("Claim tx for Chain Swap {id} was already broadcast: txid {claim_tx_id}")
+                    }
+                    None => {
+                        self.update_swap_info(id, Pending, None, None, None, None)
+                            .await?;
+                        self.claim_and_log(swap).await;
+                    }
+                }
+                Ok(())
+            }
+
+            // If swap state is unrecoverable, either:
+            // 1. The user lockup had an unexpected amount
+            // 2. The swap has expired
+            // 3. The server lockup failed or was refunded
+            // If the user already locked up funds, they have to be refunded manually to a BTC address
+            Ok(
+                ChainSwapStates::TransactionLockupFailed
+                | ChainSwapStates::TransactionFailed
+                | ChainSwapStates::TransactionRefunded
+                | ChainSwapStates::SwapExpired,
+            ) => {
+                match (&swap.claim_tx_id, swap.state) {
+                    (Some(claim_tx_id), _) => {
+                        warn!("Chain Swap {id} is in an unrecoverable state: {swap_state:?}, but claim tx was already broadcast: txid {claim_tx_id}")
+                    }
+                    (None, Pending) => {
+                        warn!("Chain Swap {id} is in an unrecoverable state: {swap_state:?}, and the user lockup tx was broadcast. Marking as refundable.");
+                        self.update_swap_info(id, Refundable, None, None, None, None)
+                            .await?;
+                    }
+                    (None, Created) => {
+                        warn!("Chain Swap {id} is in an unrecoverable state: {swap_state:?}, and the user lockup tx was never broadcast. Resolving payment as failed.");
+                        self.update_swap_info(id, Failed, None, None, None, None)
+                            .await?;
+                    }
+                    (None, state) => {
+                        debug!("Chain Swap {id} is in an invalid state for {swap_state}: {state:?}")
+                    }
+                }
+                Ok(())
+            }
+
+            Ok(_) => {
+                debug!("Unhandled state for Chain Swap {id}: {swap_state}");
+                Ok(())
+            }
+
+            _ => Err(anyhow!(
+                "Invalid ChainSwapState for Chain Swap {id}: {swap_state}"
+            )),
+        }
+    }
+
+    async fn on_new_outgoing_status(
+        &self,
+        swap: &ChainSwap,
+        update: &boltzv2::Update,
+    ) -> Result<()> {
+        let id = &swap.id;
+        let swap_state = update.status();
+        info!("Handling outgoing Chain Swap transition to {swap_state:?} for swap {id}");
+
+        // See https://docs.boltz.exchange/v/api/lifecycle#chain-swaps
+        match ChainSwapStates::from_str(swap_state) {
+            // The server lockup tx is in the BTC mempool. To not reveal the preimage for an
+            // unconfirmed lockup, we wait for confirmation before claiming.
+            Ok(ChainSwapStates::TransactionServerMempool) => {
+                info!("[Chain Swap {id}] Server lockup tx is in the mempool. Waiting for confirmation...");
+                Ok(())
+            }
+
+            // The server lockup tx is confirmed, we claim it to the destination BTC address
+            Ok(ChainSwapStates::TransactionServerConfirmed) => {
+                match &swap.claim_tx_id {
+                    Some(claim_tx_id) => {
+                        warn!("Claim tx for Chain Swap {id} was already broadcast: txid {claim_tx_id}")
+                    }
+                    None => self.claim_and_log(swap).await,
+                }
+                Ok(())
+            }
+
+            // Boltz claimed the user lockup, after our claim revealed the preimage
+            Ok(ChainSwapStates::TransactionClaimed) => {
+                debug!("Chain Swap {id} has been claimed");
+                self.update_swap_info(id, Complete, None, None, None, None)
+                    .await?;
+                Ok(())
+            }
+
+            // If swap state is unrecoverable, either:
+            // 1. The user lockup failed (we sent too little funds)
+            // 2. The swap has expired
+            // 3. The server lockup failed or was refunded
+            // We initiate a cooperative refund, and then fallback to a regular one
+            Ok(
+                ChainSwapStates::TransactionLockupFailed
+                | ChainSwapStates::TransactionFailed
+                | ChainSwapStates::TransactionRefunded
+                | ChainSwapStates::SwapExpired,
+            ) => {
+                match &swap.user_lockup_tx_id {
+                    Some(_) => match (&swap.claim_tx_id, &swap.refund_tx_id) {
+                        (Some(claim_tx_id), _) => warn!(
+                            "Claim tx for Chain Swap {id} was already broadcast: txid {claim_tx_id}"
+                        ),
+                        (None, Some(refund_tx_id)) => warn!(
+                            "Refund tx for Chain Swap {id} was already broadcast: txid {refund_tx_id}"
+                        ),
+                        (None, None) => {
+                            warn!("Chain Swap {id} is in an unrecoverable state: {swap_state:?}, and lockup tx has been broadcast. Attempting refund.");
+                            self.refund_outgoing_swap_and_update(swap).await?;
+                        }
+                    },
+                    // Do not attempt broadcasting a refund if lockup tx was never sent and swap is
+                    // unrecoverable. We resolve the payment as failed.
+                    None => {
+                        warn!("Chain Swap {id} is in an unrecoverable state: {swap_state:?}, and lockup tx has never been broadcast. Resolving payment as failed.");
+                        self.update_swap_info(id, Failed, None, None, None, None)
+                            .await?;
+                    }
+                }
+                Ok(())
+            }
+
+            Ok(_) => {
+                debug!("Unhandled state for Chain Swap {id}: {swap_state}");
+                Ok(())
+            }
+
+            _ => Err(anyhow!(
+                "Invalid ChainSwapState for Chain Swap {id}: {swap_state}"
+            )),
+        }
+    }
+
+    /// Fetches the L-BTC server lockup tx of an Incoming swap
+    fn fetch_liquid_server_lockup_tx(&self, swap: &ChainSwap) -> Result<Transaction> {
+        let id = &swap.id;
+        let swap_script_pk = swap
+            .get_claim_swap_script_lbtc()?
+            .to_address(self.config.network.into())?
+            .script_pubkey();
+
+        let history = self.chain_service.get_script_history(&swap_script_pk)?;
+        let lockup_tx_id = *history
+            .first()
+            .ok_or(anyhow!("Chain Swap {id} has no server lockup tx"))?;
+
+        self.chain_service
+            .get_transactions(&[lockup_tx_id])
+            .map_err(|e| anyhow!("Failed to fetch server lockup tx {lockup_tx_id}: {e}"))?
+            .first()
+            .cloned()
+            .ok_or_else(|| anyhow!("Fetching server lockup tx returned an empty list"))
+    }
+
+    fn is_zero_conf_claimable(&self, swap: &ChainSwap, lockup_tx: &Transaction) -> bool {
+        let id = &swap.id;
+
+        // If the amount is greater than the zero-conf limit
+        let max_amount_sat = self.config.zero_conf_max_amount_sat();
+        let receiver_amount_sat = swap.receiver_amount_sat;
+        if receiver_amount_sat > max_amount_sat {
+            warn!("[Chain Swap {id}] Amount is too high to claim with zero-conf ({receiver_amount_sat} sat > {max_amount_sat} sat). Waiting for confirmation...");
+            return false;
+        }
+
+        // If the transaction has RBF, see https://github.com/bitcoin/bips/blob/master/bip-0125.mediawiki
+        let rbf_explicit = lockup_tx.input.iter().any(|input| input.sequence.is_rbf());
+        if rbf_explicit {
+            warn!("[Chain Swap {id}] Lockup transaction signals RBF. Waiting for confirmation...");
+            return false;
+        }
+
+        // If the fees are higher than our estimated value
+        let tx_fees: u64 = lockup_tx.all_fees().values().sum();
+        let min_fee_rate = self.config.zero_conf_min_fee_rate;
+        let lower_bound_estimated_fees = lockup_tx.vsize() as f32 * min_fee_rate * 0.8;
+        if lower_bound_estimated_fees > tx_fees as f32 {
+            warn!("[Chain Swap {id}] Lockup tx fees are too low: Expected at least {lower_bound_estimated_fees} sat, got {tx_fees} sat. Waiting for confirmation...");
+            return false;
+        }
+
+        debug!("[Chain Swap {id}] Lockup tx satisfies the zero-conf requirements. Proceeding with claim.");
+        true
+    }
+
+    async fn claim_and_log(&self, swap: &ChainSwap) {
+        let id = &swap.id;
+        match self.claim(swap).await {
+            Ok(_) => {}
+            Err(err) => match err {
+                PaymentError::AlreadyClaimed => {
+                    warn!("Funds already claimed for Chain Swap {id}")
+                }
+                _ => error!("Claim for Chain Swap {id} failed: {err}"),
+            },
+        }
+    }
+
+    async fn claim(&self, swap: &ChainSwap) -> Result<(), PaymentError> {
+        ensure_sdk!(swap.claim_tx_id.is_none(), PaymentError::AlreadyClaimed);
+        let swap_id = &swap.id;
+        let claim_address = match swap.direction {
+            Direction::Incoming => self.onchain_wallet.next_unused_address().await?.to_string(),
+            Direction::Outgoing => swap.claim_address.clone().ok_or(PaymentError::Generic {
+                err: format!("Chain Swap {swap_id} has no claim address"),
+            })?,
+        };
+        let claim_tx_id = self.swapper.claim_chain_swap(swap, claim_address)?;
+
+        if swap.direction == Direction::Incoming {
+            // We insert a pseudo-claim-tx in case LWK fails to pick up the new mempool tx for a while
+            // This makes the tx known to the SDK (get_info, list_payments) instantly
+            self.persister.insert_or_update_payment(PaymentTxData {
+                tx_id: claim_tx_id.clone(),
+                timestamp: None,
+                amount_sat: swap.receiver_amount_sat,
+                fees_sat: 0,
+                payment_type: PaymentType::Receive,
+                is_confirmed: false,
+                asset_id: None,
+                asset_amount: None,
+                fee_rate_sat_per_vbyte: swap.fee_rate_sat_per_vbyte,
+            })?;
+        }
+
+        self.update_swap_info(swap_id, Pending, None, None, Some(&claim_tx_id), None)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Refunds the L-BTC lockup of an Outgoing swap to this wallet and marks the swap as
+    /// Pending until the refund tx is confirmed
+    pub(crate) async fn refund_outgoing_swap_and_update(
+        &self,
+        swap: &ChainSwap,
+    ) -> Result<(), PaymentError> {
+        let id = &swap.id;
+        let refund_tx_id = self.refund_outgoing_swap(swap).await?;
+        info!("Broadcast refund tx for Chain Swap {id}. Tx id: {refund_tx_id}");
+        self.update_swap_info(id, Pending, None, None, None, Some(&refund_tx_id))
+            .await
+    }
+
+    /// Refunds the L-BTC lockup of an Outgoing swap to this wallet
+    async fn refund_outgoing_swap(&self, swap: &ChainSwap) -> Result<String, PaymentError> {
+        let output_address = self.onchain_wallet.next_unused_address().await?.to_string();
+        let fee = self
+            .onchain_wallet
+            .build_tx(
+                swap.fee_rate_sat_per_vbyte,
+                &output_address,
+                swap.payer_amount_sat,
+            )
+            .await?
+            .all_fees()
+            .values()
+            .sum();
+        let broadcast_fees_sat = Amount::from_sat(fee);
+
+        let refund_res =
+            self.swapper
+                .refund_chain_swap_cooperative(swap, &output_address, broadcast_fees_sat);
+        match refund_res {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                warn!("Cooperative refund failed: {:?}", e);
+                self.refund_outgoing_swap_non_cooperative(swap, broadcast_fees_sat, &output_address)
+                    .await
+            }
+        }
+    }
+
+    async fn refund_outgoing_swap_non_cooperative(
+        &self,
+        swap: &ChainSwap,
+        broadcast_fees_sat: Amount,
+        output_address: &str,
+    ) -> Result<String, PaymentError> {
+        let swap_script = swap.get_lockup_swap_script_lbtc()?;
+        let current_height = self.chain_service.tip()?;
+        let locktime_from_height =
+            LockTime::from_height(current_height).map_err(|e| PaymentError::Generic {
+                err: format!("Cannot convert current block height to lock time: {e:?}"),
+            })?;
+
+        info!("locktime info: locktime_from_height = {locktime_from_height:?},  swap_script.locktime = {:?}",  swap_script.locktime);
+        ensure_sdk!(
+            utils::is_locktime_expired(locktime_from_height, swap_script.locktime),
+            PaymentError::Generic {
+                err: format!(
+                    "Cannot refund non-cooperatively. Lock time not elapsed yet. Current tip: {:?}. Script lock time: {:?}",
+                    locktime_from_height, swap_script.locktime
+                )
+            }
+        );
+
+        self.swapper
+            .refund_chain_swap_non_cooperative(swap, broadcast_fees_sat, output_address)
+    }
+
+    /// Refunds the BTC lockup of a [Refundable] Incoming swap to the given BTC address
+    pub(crate) async fn refund_incoming_swap(
+        &self,
+        swap: &ChainSwap,
+        refund_address: &str,
+        broadcast_fees_sat: Amount,
+    ) -> Result<String, PaymentError> {
+        let id = &swap.id;
+        ensure_sdk!(
+            swap.direction == Direction::Incoming && swap.state == Refundable,
+            PaymentError::Generic {
+                err: format!("Chain Swap {id} is not refundable")
+            }
+        );
+
+        let refund_tx_id = match self.swapper.refund_chain_swap_cooperative(
+            swap,
+            refund_address,
+            broadcast_fees_sat,
+        ) {
+            Ok(refund_tx_id) => refund_tx_id,
+            Err(e) => {
+                warn!("Cooperative refund failed: {:?}", e);
+                self.swapper.refund_chain_swap_non_cooperative(
+                    swap,
+                    broadcast_fees_sat,
+                    refund_address,
+                )?
+            }
+        };
+
+        // The BTC refund tx is not tracked by the wallet, so the swap is resolved once it is broadcast
+        self.update_swap_info(id, Failed, None, None, None, Some(&refund_tx_id))
+            .await?;
+        Ok(refund_tx_id)
+    }
+
+    /// Transitions a Chain swap to a new state
+    pub(crate) async fn update_swap_info(
+        &self,
+        swap_id: &str,
+        to_state: PaymentState,
+        server_lockup_tx_id: Option<&str>,
+        user_lockup_tx_id: Option<&str>,
+        claim_tx_id: Option<&str>,
+        refund_tx_id: Option<&str>,
+    ) -> Result<(), PaymentError> {
+        info!("Transitioning Chain swap {swap_id} to {to_state:?} (server_lockup_tx_id = {server_lockup_tx_id:?}, user_lockup_tx_id = {user_lockup_tx_id:?}, claim_tx_id = {claim_tx_id:?}, refund_tx_id = {refund_tx_id:?})");
+
+        let swap: ChainSwap = self
+            .persister
+            .fetch_chain_swap_by_id(swap_id)
+            .map_err(|_| PaymentError::PersistError)?
+            .ok_or(PaymentError::Generic {
+                err: format!("Chain Swap not found {swap_id}"),
+            })?;
+        // The payment is identified by the tx of this wallet: the L-BTC claim for Incoming swaps
+        // (or the swap ID, until it is claimed) and the L-BTC lockup for Outgoing swaps
+        let payment_id = match swap.direction {
+            Direction::Incoming => claim_tx_id
+                .map(|c| c.to_string())
+                .or(swap.claim_tx_id)
+                .or(Some(swap.id.clone())),
+            Direction::Outgoing => user_lockup_tx_id
+                .map(|c| c.to_string())
+                .or(swap.user_lockup_tx_id),
+        };
+
+        Self::validate_state_transition(swap.state, to_state)?;
+        self.persister.try_handle_chain_swap_update(
+            swap_id,
+            to_state,
+            server_lockup_tx_id,
+            user_lockup_tx_id,
+            claim_tx_id,
+            refund_tx_id,
+        )?;
+        if let Some(payment_id) = payment_id {
+            let _ = self.subscription_notifier.send(payment_id);
+        }
+        Ok(())
+    }
+
+    fn validate_state_transition(
+        from_state: PaymentState,
+        to_state: PaymentState,
+    ) -> Result<(), PaymentError> {
+        match (from_state, to_state) {
+            (_, Created) => Err(PaymentError::Generic {
+                err: "Cannot transition to Created state".to_string(),
+            }),
+
+            (Created | Pending, Pending) => Ok(()),
+            (Complete | Failed | TimedOut | Refundable, Pending) => Err(PaymentError::Generic {
+                err: format!("Cannot transition from {from_state:?} to Pending state"),
+            }),
+
+            (Created | Pending, Complete) => Ok(()),
+            (Complete | Failed | TimedOut | Refundable, Complete) => Err(PaymentError::Generic {
+                err: format!("Cannot transition from {from_state:?} to Complete state"),
+            }),
+
+            (Created, TimedOut) => Ok(()),
+            (_, TimedOut) => Err(PaymentError::Generic {
+                err: format!("Cannot transition from {from_state:?} to TimedOut state"),
+            }),
+
+            (Created | Pending, Refundable) => Ok(()),
+            (_, Refundable) => Err(PaymentError::Generic {
+                err: format!("Cannot transition from {from_state:?} to Refundable state"),
+            }),
+
+            (_, Failed) => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use anyhow::Result;
+    use boltz_client::Amount;
+
+    use crate::model::{Direction, PaymentState::*};
+    use crate::test_utils::chain::MockChainService;
+    use crate::test_utils::chain_swap::{new_chain_swap, new_chain_swap_state_handler};
+    use crate::test_utils::persist::new_persister;
+    use crate::test_utils::status_stream::new_swap_update;
+    use crate::test_utils::swapper::{MockRefund, MockSwapper};
+    use crate::test_utils::{mock_hash, TEST_BITCOIN_ADDRESS, TEST_LIQUID_ADDRESS};
+
+    use super::ChainSwapStateHandler;
+
+    #[tokio::test]
+    async fn incoming_user_lockup_marks_swap_pending() -> Result<()> {
+        let (_data_dir, persister) = new_persister()?;
+        let persister = Arc::new(persister);
+        let swapper = Arc::new(MockSwapper::new());
+        let handler = new_chain_swap_state_handler(
+            persister.clone(),
+            swapper.clone(),
+            Arc::new(MockChainService::new()),
+        );
+        persister.insert_chain_swap(&new_chain_swap("incoming", Direction::Incoming, Created))?;
+
+        let user_lockup_tx_id = mock_hash("user lockup");
+        handler
+            .on_new_status(&new_swap_update(
+                "incoming",
+                "transaction.mempool",
+                Some(&user_lockup_tx_id),
+            ))
+            .await?;
+
+        let swap = persister.fetch_chain_swap_by_id("incoming")?.unwrap();
+        assert_eq!(swap.state, Pending);
+        assert_eq!(swap.user_lockup_tx_id, Some(user_lockup_tx_id));
+        assert!(swapper.claimed_swap_ids.lock().unwrap().is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn incoming_server_lockup_confirmed_is_claimed() -> Result<()> {
+        let (_data_dir, persister) = new_persister()?;
+        let persister = Arc::new(persister);
+        let swapper = Arc::new(MockSwapper::new());
+        let handler = new_chain_swap_state_handler(
+            persister.clone(),
+            swapper.clone(),
+            Arc::new(MockChainService::new()),
+        );
+        let mut swap = new_chain_swap("incoming", Direction::Incoming, Pending);
+        swap.user_lockup_tx_id = Some(mock_hash("user lockup"));
+        persister.insert_chain_swap(&swap)?;
+
+        handler
+            .on_new_status(&new_swap_update(
+                "incoming",
+                "transaction.server.confirmed",
+                None,
+            ))
+            .await?;
+
+        let claim_tx_id = mock_hash("claim incoming");
+        let swap = persister.fetch_chain_swap_by_id("incoming")?.unwrap();
+        assert_eq!(swap.state, Pending);
+        assert_eq!(swap.claim_tx_id, Some(claim_tx_id.clone()));
+        assert_eq!(*swapper.claimed_swap_ids.lock().unwrap(), vec!["incoming"]);
+
+        // The claim tx is known before the wallet picks it up
+        let payment = persister.get_payment(claim_tx_id)?.unwrap();
+        assert_eq!(payment.status, Pending);
+        assert_eq!(payment.amount_sat, swap.receiver_amount_sat);
+
+        // A repeated update does not claim twice
+        handler
+            .on_new_status(&new_swap_update(
+                "incoming",
+                "transaction.server.confirmed",
+                None,
+            ))
+            .await?;
+        assert_eq!(swapper.claimed_swap_ids.lock().unwrap().len(), 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn incoming_unrecoverable_swap() -> Result<()> {
+        let (_data_dir, persister) = new_persister()?;
+        let persister = Arc::new(persister);
+        let handler = new_chain_swap_state_handler(
+            persister.clone(),
+            Arc::new(MockSwapper::new()),
+            Arc::new(MockChainService::new()),
+        );
+
+        // With a user lockup, the funds have to be refunded
+        let mut locked_up = new_chain_swap("locked-up", Direction::Incoming, Pending);
+        locked_up.user_lockup_tx_id = Some(mock_hash("user lockup"));
+        persister.insert_chain_swap(&locked_up)?;
+        // Without, there is nothing to refund
+        persister.insert_chain_swap(&new_chain_swap(
+            "not-locked-up",
+            Direction::Incoming,
+            Created,
+        ))?;
+
+        for (swap_id, status, expected_state) in [
+            ("locked-up", "transaction.lockupFailed", Refundable),
+            ("not-locked-up", "swap.expired", Failed),
+        ] {
+            handler
+                .on_new_status(&new_swap_update(swap_id, status, None))
+                .await?;
+            let swap = persister.fetch_chain_swap_by_id(swap_id)?.unwrap();
+            assert_eq!(swap.state, expected_state, "Unexpected state for {swap_id}");
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn outgoing_swap_is_claimed_then_completed() -> Result<()> {
+        let (_data_dir, persister) = new_persister()?;
+        let persister = Arc::new(persister);
+        let swapper = Arc::new(MockSwapper::new());
+        let handler = new_chain_swap_state_handler(
+            persister.clone(),
+            swapper.clone(),
+            Arc::new(MockChainService::new()),
+        );
+        let mut swap = new_chain_swap("outgoing", Direction::Outgoing, Pending);
+        swap.user_lockup_tx_id = Some(mock_hash("user lockup"));
+        persister.insert_chain_swap(&swap)?;
+
+        // The server lockup is only claimed once confirmed
+        handler
+            .on_new_status(&new_swap_update(
+                "outgoing",
+                "transaction.server.mempool",
+                None,
+            ))
+            .await?;
+        assert!(swapper.claimed_swap_ids.lock().unwrap().is_empty());
+
+        handler
+            .on_new_status(&new_swap_update(
+                "outgoing",
+                "transaction.server.confirmed",
+                None,
+            ))
+            .await?;
+        let swap = persister.fetch_chain_swap_by_id("outgoing")?.unwrap();
+        assert_eq!(swap.state, Pending);
+        assert_eq!(swap.claim_tx_id, Some(mock_hash("claim outgoing")));
+
+        handler
+            .on_new_status(&new_swap_update("outgoing", "transaction.claimed", None))
+            .await?;
+        let swap = persister.fetch_chain_swap_by_id("outgoing")?.unwrap();
+        assert_eq!(swap.state, Complete);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn outgoing_unrecoverable_swap() -> Result<()> {
+        let (_data_dir, persister) = new_persister()?;
+        let persister = Arc::new(persister);
+        let swapper = Arc::new(MockSwapper::new());
+        let handler = new_chain_swap_state_handler(
+            persister.clone(),
+            swapper.clone(),
+            Arc::new(MockChainService::new()),
+        );
+        let mut locked_up = new_chain_swap("locked-up", Direction::Outgoing, Pending);
+        locked_up.user_lockup_tx_id = Some(mock_hash("user lockup"));
+        persister.insert_chain_swap(&locked_up)?;
+        persister.insert_chain_swap(&new_chain_swap(
+            "not-locked-up",
+            Direction::Outgoing,
+            Created,
+        ))?;
+
+        // The lockup is refunded cooperatively to the wallet
+        handler
+            .on_new_status(&new_swap_update("locked-up", "swap.expired", None))
+            .await?;
+        let swap = persister.fetch_chain_swap_by_id("locked-up")?.unwrap();
+        assert_eq!(swap.state, Pending);
+        assert_eq!(swap.refund_tx_id, Some(mock_hash("refund locked-up")));
+        assert_eq!(
+            *swapper.refunds.lock().unwrap(),
+            vec![MockRefund {
+                swap_id: "locked-up".to_string(),
+                output_address: TEST_LIQUID_ADDRESS.to_string(),
+                broadcast_fees_sat: 100,
+                is_cooperative: true,
+            }]
+        );
+
+        // A repeated update does not refund twice
+        handler
+            .on_new_status(&new_swap_update("locked-up", "swap.expired", None))
+            .await?;
+        assert_eq!(swapper.refunds.lock().unwrap().len(), 1);
+
+        // Without a lockup, the swap fails without a refund
+        handler
+            .on_new_status(&new_swap_update("not-locked-up", "swap.expired", None))
+            .await?;
+        let swap = persister.fetch_chain_swap_by_id("not-locked-up")?.unwrap();
+        assert_eq!(swap.state, Failed);
+        assert_eq!(swapper.refunds.lock().unwrap().len(), 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn refund_incoming_swap() -> Result<()> {
+        let (_data_dir, persister) = new_persister()?;
+        let persister = Arc::new(persister);
+        let swapper = Arc::new(MockSwapper::new());
+        let handler = new_chain_swap_state_handler(
+            persister.clone(),
+            swapper.clone(),
+            Arc::new(MockChainService::new()),
+        );
+        let broadcast_fees_sat = Amount::from_sat(300);
+
+        // Only Refundable swaps can be refunded
+        let pending = new_chain_swap("pending", Direction::Incoming, Pending);
+        persister.insert_chain_swap(&pending)?;
+        assert!(handler
+            .refund_incoming_swap(&pending, TEST_BITCOIN_ADDRESS, broadcast_fees_sat)
+            .await
+            .is_err());
+
+        let cooperative = new_chain_swap("cooperative", Direction::Incoming, Refundable);
+        persister.insert_chain_swap(&cooperative)?;
+        let refund_tx_id = handler
+            .refund_incoming_swap(&cooperative, TEST_BITCOIN_ADDRESS, broadcast_fees_sat)
+            .await?;
+        assert_eq!(refund_tx_id, mock_hash("refund cooperative"));
+        let swap = persister.fetch_chain_swap_by_id("cooperative")?.unwrap();
+        assert_eq!(swap.state, Failed);
+        assert_eq!(swap.refund_tx_id, Some(refund_tx_id));
+
+        // A rejected cooperative refund falls back to a non-cooperative one
+        *swapper.fail_cooperative_refunds.lock().unwrap() = true;
+        let non_cooperative = new_chain_swap("non-cooperative", Direction::Incoming, Refundable);
+        persister.insert_chain_swap(&non_cooperative)?;
+        handler
+            .refund_incoming_swap(&non_cooperative, TEST_BITCOIN_ADDRESS, broadcast_fees_sat)
+            .await?;
+        let refunds = swapper.refunds.lock().unwrap();
+        assert_eq!(
+            refunds
+                .iter()
+                .map(|r| (r.swap_id.as_str(), r.is_cooperative))
+                .collect::<Vec<_>>(),
+            vec![("cooperative", true), ("non-cooperative", false)]
+        );
+        assert!(refunds
+            .iter()
+            .all(|r| r.output_address == TEST_BITCOIN_ADDRESS && r.broadcast_fees_sat == 300));
+        Ok(())
+    }
+
+    #[test]
+    fn validate_state_transition() {
+        let all_states = [Created, Pending, Complete, Failed, TimedOut, Refundable];
+        for from_state in all_states {
+            for to_state in all_states {
+                let expected_valid = match to_state {
+                    Created => false,
+                    Pending | Complete | Refundable => matches!(from_state, Created | Pending),
+                    TimedOut => from_state == Created,
+                    Failed => true,
+                };
+                assert_eq!(
+                    ChainSwapStateHandler::validate_state_transition(from_state, to_state).is_ok(),
+                    expected_valid,
+                    "Unexpected result for {from_state:?} -> {to_state:?}"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,369 @@
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
+
+use electrum_client::{Client, ConfigBuilder};
+use log::{info, warn};
+
+use crate::error::PaymentError;
+use crate::model::{ElectrumServer, ElectrumServerStatus};
+
+/// How long a failed server is tried only after the healthy ones
+const FAILED_SERVER_COOLDOWN: Duration = Duration::from_secs(60);
+
+/// Connects an `electrum_client` [Client] to a server, with its TLS settings
+pub(crate) fn new_electrum_client(
+    server: &ElectrumServer,
+) -> Result<Client, electrum_client::Error> {
+    let scheme = match server.tls {
+        true => "ssl",
+        false => "tcp",
+    };
+    let config = ConfigBuilder::new()
+        .validate_domain(server.validate_domain)
+        .build();
+    Client::from_config(&format!("{scheme}://{}", server.url), config)
+}
+
+/// Whether an `electrum_client` error is a connection failure, as opposed to an error response
+/// of the server
+pub(crate) fn is_connection_error(err: &electrum_client::Error) -> bool {
+    match err {
+        electrum_client::Error::IOError(_)
+        | electrum_client::Error::SharedIOError(_)
+        | electrum_client::Error::CouldntLockReader
+        | electrum_client::Error::Mpsc => true,
+        electrum_client::Error::AllAttemptsErrored(errors) => {
+            errors.iter().any(is_connection_error)
+        }
+        _ => false,
+    }
+}
+
+/// The error of a request to an Electrum server, which decides whether the next server is tried
+pub(crate) enum ElectrumRequestError {
+    /// The server could not be reached or the connection failed, so another server may succeed
+    Connection(PaymentError),
+    /// The server answered with an error, e.g. rejecting a tx, which other servers would too
+    Response(PaymentError),
+}
+
+impl ElectrumRequestError {
+    /// Classifies an `electrum_client` error, described by `payment_error`
+    pub(crate) fn electrum(err: &electrum_client::Error, payment_error: PaymentError) -> Self {
+        match is_connection_error(err) {
+            true => Self::Connection(payment_error),
+            false => Self::Response(payment_error),
+        }
+    }
+}
+
+impl From<ElectrumRequestError> for PaymentError {
+    fn from(err: ElectrumRequestError) -> Self {
+        match err {
+            ElectrumRequestError::Connection(err) | ElectrumRequestError::Response(err) => err,
+        }
+    }
+}
+
+impl From<lwk_wollet::Error> for ElectrumRequestError {
+    fn from(err: lwk_wollet::Error) -> Self {
+        match &err {
+            lwk_wollet::Error::ElectrumClient(e) => Self::electrum(e, err.into()),
+            _ => Self::Response(err.into()),
+        }
+    }
+}
+
+impl From<boltz_client::error::Error> for ElectrumRequestError {
+    fn from(err: boltz_client::error::Error) -> Self {
+        match &err {
+            boltz_client::error::Error::Electrum(e) => Self::electrum(e, err.into()),
+            _ => Self::Response(err.into()),
+        }
+    }
+}
+
+#[derive(Default)]
+struct ServerHealth {
+    consecutive_failures: u32,
+    last_error: Option<String>,
+    last_failure_at: Option<Instant>,
+}
+
+impl ServerHealth {
+    fn is_healthy(&self) -> bool {
+        self.last_failure_at.map_or(true, |failed_at| {
+            failed_at.elapsed() >= FAILED_SERVER_COOLDOWN
+        })
+    }
+}
+
+struct ServersState {
+    health: Vec<ServerHealth>,
+    /// Index of the server of the last successful request
+    in_use: Option<usize>,
+}
+
+/// The Liquid Electrum servers of the [Config](crate::model::Config), shared by every component
+/// making Electrum requests so they agree on which servers are healthy
+pub(crate) struct ElectrumServers {
+    servers: Vec<ElectrumServer>,
+    state: Mutex<ServersState>,
+}
+
+impl ElectrumServers {
+    pub(crate) fn new(servers: Vec<ElectrumServer>) -> Self {
+        let health = servers.iter().map(|_| ServerHealth::default()).collect();
+        Self {
+            servers,
+            state: Mutex::new(ServersState {
+                health,
+                in_use: None,
+            }),
+        }
+    }
+
+    fn state(&self) -> MutexGuard<ServersState> {
+        // The state is only health bookkeeping, so it is still usable if a holder panicked
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Indexes of the servers in the order they should be tried: the healthy ones first, then
+    /// the failed ones, each in the configured order
+    fn failover_order(&self) -> Vec<usize> {
+        let state = self.state();
+        let (healthy, failed): (Vec<usize>, Vec<usize>) =
+            (0..self.servers.len()).partition(|&index| state.health[index].is_healthy());
+        healthy.into_iter().chain(failed).collect()
+    }
+
+    fn report_success(&self, index: usize) {
+        let mut state = self.state();
+        state.health[index] = ServerHealth::default();
+        if state.in_use != Some(index) {
+            info!("Using Electrum server {}", self.servers[index].url);
+            state.in_use = Some(index);
+        }
+    }
+
+    fn report_failure(&self, index: usize, err: &PaymentError) {
+        let url = &self.servers[index].url;
+        warn!("Request to Electrum server {url} failed: {err}");
+        let mut state = self.state();
+        let health = &mut state.health[index];
+        health.consecutive_failures += 1;
+        health.last_error = Some(err.to_string());
+        health.last_failure_at = Some(Instant::now());
+    }
+
+    /// Runs a request against the servers in [ElectrumServers::failover_order], until one
+    /// answers. The request gets the index of the server and the server itself.
+    ///
+    /// Only [ElectrumRequestError::Connection] errors fail over to the next server. An error
+    /// response is returned as is, as the server is healthy. If all servers fail, the error of
+    /// the last one is returned.
+    pub(crate) fn with_failover<T>(
+        &self,
+        mut request: impl FnMut(usize, &ElectrumServer) -> Result<T, ElectrumRequestError>,
+    ) -> Result<T, PaymentError> {
+        let mut last_err = PaymentError::Generic {
+            err: "No Electrum server is configured".to_string(),
+        };
+        for index in self.failover_order() {
+            match request(index, &self.servers[index]) {
+                Ok(res) => {
+                    self.report_success(index);
+                    return Ok(res);
+                }
+                Err(ElectrumRequestError::Response(e)) => {
+                    self.report_success(index);
+                    return Err(e);
+                }
+                Err(ElectrumRequestError::Connection(e)) => {
+                    self.report_failure(index, &e);
+                    last_err = e;
+                }
+            }
+        }
+        Err(last_err)
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.servers.len()
+    }
+
+    pub(crate) fn status(&self) -> Vec<ElectrumServerStatus> {
+        let state = self.state();
+        self.servers
+            .iter()
+            .zip(&state.health)
+            .enumerate()
+            .map(|(index, (server, health))| ElectrumServerStatus {
+                url: server.url.clone(),
+                in_use: state.in_use == Some(index),
+                healthy: health.is_healthy(),
+                consecutive_failures: health.consecutive_failures,
+                last_error: health.last_error.clone(),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use anyhow::Result;
+
+    use crate::electrum::{is_connection_error, ElectrumRequestError, ElectrumServers};
+    use crate::error::PaymentError;
+    use crate::model::{Config, ElectrumServer};
+
+    fn error(err: &str) -> PaymentError {
+        PaymentError::Generic {
+            err: err.to_string(),
+        }
+    }
+
+    #[test]
+    fn electrum_failover() -> Result<()> {
+        let servers = ElectrumServers::new(vec![
+            ElectrumServer::new("primary:995"),
+            ElectrumServer::new("backup:995"),
+        ]);
+        // Returns the server which answered and the servers tried, in order
+        let request_with_primary_down = || -> Result<(String, Vec<String>)> {
+            let mut tried = vec![];
+            let answered_by = servers.with_failover(|_, server| {
+                tried.push(server.url.clone());
+                match server.url.as_str() {
+                    "primary:995" => Err(ElectrumRequestError::Connection(error(
+                        "Connection refused",
+                    ))),
+                    _ => Ok(server.url.clone()),
+                }
+            })?;
+            Ok((answered_by, tried))
+        };
+
+        let (answered_by, tried) = request_with_primary_down()?;
+        assert_eq!(answered_by, "backup:995");
+        assert_eq!(tried, vec!["primary:995", "backup:995"]);
+
+        // The failed primary is only tried after the backup until its cooldown ends
+        let (answered_by, tried) = request_with_primary_down()?;
+        assert_eq!(answered_by, "backup:995");
+        assert_eq!(tried, vec!["backup:995"]);
+
+        let status = servers.status();
+        assert!(!status[0].in_use && !status[0].healthy);
+        assert_eq!(status[0].consecutive_failures, 1);
+        assert_eq!(
+            status[0].last_error.as_deref(),
+            Some("Generic error: Connection refused")
+        );
+        assert!(status[1].in_use && status[1].healthy);
+        assert_eq!(status[1].consecutive_failures, 0);
+
+        // If all servers fail, the last error is returned
+        let res: Result<(), PaymentError> = servers
+            .with_failover(|_, server| Err(ElectrumRequestError::Connection(error(&server.url))));
+        assert!(matches!(res, Err(PaymentError::Generic { err }) if err == "primary:995"));
+        assert_eq!(servers.status()[1].consecutive_failures, 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn electrum_error_responses() -> Result<()> {
+        let servers = ElectrumServers::new(vec![
+            ElectrumServer::new("primary:995"),
+            ElectrumServer::new("backup:995"),
+        ]);
+
+        // An error response, like a rejected tx, is returned without trying the next server
+        let mut tried = vec![];
+        let res: Result<(), PaymentError> = servers.with_failover(|_, server| {
+            tried.push(server.url.clone());
+            Err(ElectrumRequestError::Response(error("Tx rejected")))
+        });
+        assert!(matches!(res, Err(PaymentError::Generic { err }) if err == "Tx rejected"));
+        assert_eq!(tried, vec!["primary:995"]);
+
+        // The server answered, so it is still healthy and in use
+        let status = servers.status();
+        assert!(status[0].in_use && status[0].healthy);
+        assert_eq!(status[0].consecutive_failures, 0);
+        assert!(status[0].last_error.is_none());
+        assert!(!status[1].in_use && status[1].healthy);
+
+        Ok(())
+    }
+
+    #[test]
+    fn electrum_connection_errors() {
+        let io_error = || std::io::Error::new(std::io::ErrorKind::ConnectionRefused, "refused");
+        for (err, expected_is_connection_error) in [
+            (electrum_client::Error::IOError(io_error()), true),
+            (
+                electrum_client::Error::SharedIOError(Arc::new(io_error())),
+                true,
+            ),
+            (electrum_client::Error::CouldntLockReader, true),
+            (
+                electrum_client::Error::AllAttemptsErrored(vec![electrum_client::Error::IOError(
+                    io_error(),
+                )]),
+                true,
+            ),
+            (
+                electrum_client::Error::Protocol(serde_json::json!({
+                    "code": 1,
+                    "message": "min relay fee not met",
+                })),
+                false,
+            ),
+            (
+                electrum_client::Error::Message("Invalid response".to_string()),
+                false,
+            ),
+        ] {
+            assert_eq!(
+                is_connection_error(&err),
+                expected_is_connection_error,
+                "{err:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn deprecated_electrum_url() {
+        let mut config = Config::mainnet();
+        assert_eq!(
+            config.liquid_electrum_servers(),
+            vec![ElectrumServer::new("blockstream.info:995")]
+        );
+
+        // The deprecated server is tried first
+        config.electrum_url = Some("legacy:995".to_string());
+        assert_eq!(
+            config.liquid_electrum_servers(),
+            vec![
+                ElectrumServer::new("legacy:995"),
+                ElectrumServer::new("blockstream.info:995"),
+            ]
+        );
+
+        // On Regtest, it is connected to without TLS like the Bitcoin server
+        let mut config = Config::regtest();
+        config.electrum_url = Some("localhost:50001".to_string());
+        assert_eq!(
+            config.liquid_electrum_servers()[0],
+            ElectrumServer {
+                url: "localhost:50001".to_string(),
+                tls: false,
+                validate_domain: false,
+            }
+        );
+    }
+}
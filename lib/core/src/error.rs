@@ -44,12 +44,30 @@ pub enum PaymentError {
     #[error("Invoice amount is out of range")]
     AmountOutOfRange,
 
+    #[error("The input is not a supported payment destination: {err}")]
+    UnrecognizedInput { err: String },
+
+    #[error("The input is for a different network: {err}")]
+    NetworkMismatch { err: String },
+
+    #[error("LNURL error: {err}")]
+    LnUrlError { err: String },
+
+    #[error("The LNURL service could not be reached: {err}")]
+    LnUrlServiceUnreachable { err: String },
+
+    #[error("The LNURL service returned an error: {err}")]
+    LnUrlServiceError { err: String },
+
     #[error("Generic error: {err}")]
     Generic { err: String },
 
     #[error("The provided fees have expired")]
     InvalidOrExpiredFees,
 
+    #[error("The fees of {fee_sat} sat exceed the limit of {limit_sat} sat")]
+    FeeLimitExceeded { fee_sat: u64, limit_sat: u64 },
+
     #[error("Cannot pay: not enough funds")]
     InsufficientFunds,
 
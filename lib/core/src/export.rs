@@ -0,0 +1,336 @@
+use std::fs::File;
+use std::io::{BufWriter, Write};
+
+use anyhow::Result;
+use serde::Serialize;
+
+use crate::model::{Payment, PaymentExportFormat, PaymentState, PaymentSwapType, PaymentType};
+
+/// Bumped whenever the exported columns change, see [PaymentExportFormat]
+pub(crate) const EXPORT_FORMAT_VERSION: u32 = 1;
+
+const CSV_COLUMNS: [&str; 26] = [
+    "version",
+    "timestamp",
+    "date",
+    "direction",
+    "status",
+    "swap_type",
+    "amount_sat",
+    "fees_sat",
+    "swap_fees_sat",
+    "network_fees_sat",
+    "asset_id",
+    "asset_amount",
+    "tx_id",
+    "swap_id",
+    "lockup_tx_id",
+    "claim_tx_id",
+    "bolt11",
+    "payment_hash",
+    "preimage",
+    "description",
+    "refund_tx_id",
+    "refund_tx_amount_sat",
+    "label",
+    "metadata_label",
+    "metadata_note",
+    "metadata_external_id",
+];
+
+/// The exported `status` values, which do not change with the Rust names of the states
+fn status_value(status: PaymentState) -> &'static str {
+    match status {
+        PaymentState::Created => "created",
+        PaymentState::Pending => "pending",
+        PaymentState::Complete => "complete",
+        PaymentState::Failed => "failed",
+        PaymentState::TimedOut => "timed_out",
+        PaymentState::Refundable => "refundable",
+    }
+}
+
+/// The exported `swap_type` values, which do not change with the Rust names of the swap types
+fn swap_type_value(swap_type: PaymentSwapType) -> &'static str {
+    match swap_type {
+        PaymentSwapType::Submarine => "submarine",
+        PaymentSwapType::Reverse => "reverse",
+        PaymentSwapType::Chain => "chain",
+        PaymentSwapType::Direct => "direct",
+    }
+}
+
+/// A payment as exported, in the column order of [CSV_COLUMNS] after `version`
+#[derive(Serialize)]
+struct PaymentExportRow {
+    timestamp: u32,
+    date: String,
+    direction: String,
+    status: String,
+    swap_type: String,
+    amount_sat: u64,
+    fees_sat: u64,
+    swap_fees_sat: Option<u64>,
+    network_fees_sat: Option<u64>,
+    asset_id: Option<String>,
+    asset_amount: Option<u64>,
+    tx_id: Option<String>,
+    swap_id: Option<String>,
+    lockup_tx_id: Option<String>,
+    claim_tx_id: Option<String>,
+    bolt11: Option<String>,
+    payment_hash: Option<String>,
+    preimage: Option<String>,
+    description: Option<String>,
+    refund_tx_id: Option<String>,
+    refund_tx_amount_sat: Option<u64>,
+    label: Option<String>,
+    metadata_label: Option<String>,
+    metadata_note: Option<String>,
+    metadata_external_id: Option<String>,
+}
+
+impl From<&Payment> for PaymentExportRow {
+    fn from(payment: &Payment) -> Self {
+        let metadata = payment.metadata.clone().unwrap_or_default();
+        PaymentExportRow {
+            timestamp: payment.timestamp,
+            date: chrono::DateTime::from_timestamp(payment.timestamp as i64, 0)
+                .map(|date| date.to_rfc3339())
+                .unwrap_or_default(),
+            direction: match payment.payment_type {
+                PaymentType::Receive => "receive",
+                PaymentType::Send => "send",
+            }
+            .to_string(),
+            status: status_value(payment.status).to_string(),
+            swap_type: swap_type_value(payment.swap_type).to_string(),
+            amount_sat: payment.amount_sat,
+            fees_sat: payment.fees_sat,
+            swap_fees_sat: payment
+                .network_fees_sat
+                .map(|network_fees_sat| payment.fees_sat.saturating_sub(network_fees_sat)),
+            network_fees_sat: payment.network_fees_sat,
+            asset_id: payment.asset_id.clone(),
+            asset_amount: payment.asset_amount,
+            tx_id: payment.tx_id.clone(),
+            swap_id: payment.swap_id.clone(),
+            lockup_tx_id: payment.lockup_tx_id.clone(),
+            claim_tx_id: payment.claim_tx_id.clone(),
+            bolt11: payment.bolt11.clone(),
+            payment_hash: payment.payment_hash.clone(),
+            preimage: payment.preimage.clone(),
+            description: payment.description.clone(),
+            refund_tx_id: payment.refund_tx_id.clone(),
+            refund_tx_amount_sat: payment.refund_tx_amount_sat,
+            label: payment.label.clone(),
+            metadata_label: metadata.label,
+            metadata_note: metadata.note,
+            metadata_external_id: metadata.external_id,
+        }
+    }
+}
+
+impl PaymentExportRow {
+    fn csv_values(&self) -> Vec<String> {
+        fn opt<T: ToString>(value: &Option<T>) -> String {
+            value.as_ref().map(T::to_string).unwrap_or_default()
+        }
+
+        vec![
+            EXPORT_FORMAT_VERSION.to_string(),
+            self.timestamp.to_string(),
+            self.date.clone(),
+            self.direction.clone(),
+            self.status.clone(),
+            self.swap_type.clone(),
+            self.amount_sat.to_string(),
+            self.fees_sat.to_string(),
+            opt(&self.swap_fees_sat),
+            opt(&self.network_fees_sat),
+            opt(&self.asset_id),
+            opt(&self.asset_amount),
+            opt(&self.tx_id),
+            opt(&self.swap_id),
+            opt(&self.lockup_tx_id),
+            opt(&self.claim_tx_id),
+            opt(&self.bolt11),
+            opt(&self.payment_hash),
+            opt(&self.preimage),
+            opt(&self.description),
+            opt(&self.refund_tx_id),
+            opt(&self.refund_tx_amount_sat),
+            opt(&self.label),
+            opt(&self.metadata_label),
+            opt(&self.metadata_note),
+            opt(&self.metadata_external_id),
+        ]
+    }
+}
+
+#[derive(Serialize)]
+struct JsonExport {
+    version: u32,
+    payments: Vec<PaymentExportRow>,
+}
+
+/// Quotes a CSV field if needed, as per RFC 4180
+fn csv_field(value: &str) -> String {
+    match value.contains([',', '"', '\r', '\n']) {
+        true => format!("\"{}\"", value.replace('"', "\"\"")),
+        false => value.to_string(),
+    }
+}
+
+fn write_csv_line(writer: &mut impl Write, values: &[String]) -> Result<()> {
+    let line: Vec<String> = values.iter().map(|value| csv_field(value)).collect();
+    write!(writer, "{}\r\n", line.join(","))?;
+    Ok(())
+}
+
+pub(crate) fn write_payments(
+    writer: &mut impl Write,
+    payments: &[Payment],
+    format: PaymentExportFormat,
+) -> Result<()> {
+    let rows = payments.iter().map(PaymentExportRow::from);
+    match format {
+        PaymentExportFormat::Csv => {
+            let header: Vec<String> = CSV_COLUMNS.iter().map(|c| c.to_string()).collect();
+            write_csv_line(writer, &header)?;
+            for row in rows {
+                write_csv_line(writer, &row.csv_values())?;
+            }
+        }
+        PaymentExportFormat::Json => serde_json::to_writer_pretty(
+            &mut *writer,
+            &JsonExport {
+                version: EXPORT_FORMAT_VERSION,
+                payments: rows.collect(),
+            },
+        )?,
+    }
+    Ok(())
+}
+
+pub(crate) fn export_payments(
+    path: &str,
+    payments: &[Payment],
+    format: PaymentExportFormat,
+) -> Result<()> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    write_payments(&mut writer, payments, format)?;
+    writer.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+
+    use crate::export::write_payments;
+    use crate::model::*;
+
+    fn new_payment() -> Payment {
+        Payment::from_tx_data(
+            PaymentTxData {
+                tx_id: "11".repeat(32),
+                timestamp: Some(1_700_000_000),
+                amount_sat: 1_250,
+                fees_sat: 250,
+                payment_type: PaymentType::Send,
+                is_confirmed: true,
+                asset_id: None,
+                asset_amount: None,
+                fee_rate_sat_per_vbyte: None,
+            },
+            None,
+        )
+    }
+
+    #[test]
+    fn export_payments() -> Result<()> {
+        let mut payment = new_payment();
+        payment.metadata = Some(PaymentMetadata {
+            label: None,
+            note: Some("Invoice 7, \"rush\"".to_string()),
+            external_id: Some("order-42".to_string()),
+        });
+
+        let mut csv = vec![];
+        write_payments(&mut csv, &[payment.clone()], PaymentExportFormat::Csv)?;
+        let csv = String::from_utf8(csv)?;
+        let lines: Vec<&str> = csv.split_terminator("\r\n").collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("version,timestamp,date,direction,"));
+        assert!(lines[1].starts_with(
+            "1,1700000000,2023-11-14T22:13:20+00:00,send,complete,direct,1250,250,0,250,"
+        ));
+        assert!(lines[1].ends_with(",\"Invoice 7, \"\"rush\"\"\",order-42"));
+
+        let mut json = vec![];
+        write_payments(&mut json, &[payment], PaymentExportFormat::Json)?;
+        let json: serde_json::Value = serde_json::from_slice(&json)?;
+        assert_eq!(json["version"], 1);
+        assert_eq!(json["payments"][0]["network_fees_sat"], 250);
+        assert_eq!(json["payments"][0]["metadata_external_id"], "order-42");
+
+        Ok(())
+    }
+
+    #[test]
+    fn export_status_and_swap_type() -> Result<()> {
+        let cases = [
+            (
+                PaymentState::Created,
+                PaymentSwapType::Submarine,
+                "created",
+                "submarine",
+            ),
+            (
+                PaymentState::Pending,
+                PaymentSwapType::Reverse,
+                "pending",
+                "reverse",
+            ),
+            (
+                PaymentState::Complete,
+                PaymentSwapType::Chain,
+                "complete",
+                "chain",
+            ),
+            (
+                PaymentState::Failed,
+                PaymentSwapType::Direct,
+                "failed",
+                "direct",
+            ),
+            (
+                PaymentState::TimedOut,
+                PaymentSwapType::Submarine,
+                "timed_out",
+                "submarine",
+            ),
+            (
+                PaymentState::Refundable,
+                PaymentSwapType::Chain,
+                "refundable",
+                "chain",
+            ),
+        ];
+
+        for (status, swap_type, status_value, swap_type_value) in cases {
+            let mut payment = new_payment();
+            payment.status = status;
+            payment.swap_type = swap_type;
+
+            let mut json = vec![];
+            write_payments(&mut json, &[payment], PaymentExportFormat::Json)?;
+            let json: serde_json::Value = serde_json::from_slice(&json)?;
+            assert_eq!(json["payments"][0]["status"], status_value);
+            assert_eq!(json["payments"][0]["swap_type"], swap_type_value);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,227 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use electrum_client::ElectrumApi;
+
+use crate::chain::esplora_error;
+use crate::electrum::{new_electrum_client, ElectrumRequestError, ElectrumServers};
+use crate::error::PaymentError;
+use crate::model::{Config, ElectrumServer, RecommendedFees};
+
+/// Lowest fee rate relayed by Liquid nodes, in sat/vbyte
+pub(crate) const LIQUID_MIN_FEE_RATE_SAT_PER_VBYTE: f64 = 0.1;
+/// Lowest fee rate relayed by Bitcoin nodes, in sat/vbyte
+pub(crate) const BITCOIN_MIN_FEE_RATE_SAT_PER_VBYTE: f64 = 1.0;
+
+/// Confirmation targets, in blocks, of the fastest, half hour, hour and economy fee rates of
+/// [RecommendedFees]
+const CONFIRMATION_TARGETS: [usize; 4] = [1, 3, 6, 144];
+
+/// Estimates the fee rates of onchain txs
+pub(crate) trait FeeEstimator: Send + Sync {
+    /// Fee rates for Liquid txs: lockups, claims, refunds of Send Swaps and direct sends
+    fn recommended_liquid_fees(&self) -> Result<RecommendedFees, PaymentError>;
+
+    /// Fee rates for Bitcoin txs: refunds of Incoming Chain Swaps
+    fn recommended_bitcoin_fees(&self) -> Result<RecommendedFees, PaymentError>;
+}
+
+/// Creates the [FeeEstimator] of the [Config]: Liquid fees are estimated by Esplora if
+/// [Config::esplora_url] is set, else by the Electrum servers
+pub(crate) fn new_fee_estimator(
+    config: &Config,
+    electrum_servers: Arc<ElectrumServers>,
+) -> Arc<dyn FeeEstimator> {
+    let electrum_fee_estimator = ElectrumFeeEstimator::new(config.clone(), electrum_servers);
+    match &config.esplora_url {
+        Some(esplora_url) => Arc::new(EsploraFeeEstimator::new(
+            esplora_url,
+            electrum_fee_estimator,
+        )),
+        None => Arc::new(electrum_fee_estimator),
+    }
+}
+
+/// [FeeEstimator] querying `blockchain.estimatefee` on the Electrum servers of the [Config]
+pub(crate) struct ElectrumFeeEstimator {
+    config: Config,
+    electrum_servers: Arc<ElectrumServers>,
+}
+
+impl ElectrumFeeEstimator {
+    pub(crate) fn new(config: Config, electrum_servers: Arc<ElectrumServers>) -> Self {
+        Self {
+            config,
+            electrum_servers,
+        }
+    }
+
+    fn estimate_fees(
+        server: &ElectrumServer,
+        min_fee_rate_sat_per_vbyte: f64,
+    ) -> Result<RecommendedFees, ElectrumRequestError> {
+        let electrum_url = &server.url;
+        let electrum_error = |e: electrum_client::Error| {
+            let err = PaymentError::Generic {
+                err: format!("Failed to estimate fees with Electrum server {electrum_url}: {e:?}"),
+            };
+            ElectrumRequestError::electrum(&e, err)
+        };
+        let client = new_electrum_client(server).map_err(electrum_error)?;
+        // The estimates are in BTC/kvB, and negative if the server does not have enough data
+        let fee_rates: Vec<f64> = client
+            .batch_estimate_fee(CONFIRMATION_TARGETS)
+            .map_err(electrum_error)?
+            .into_iter()
+            .map(|btc_per_kvb| (btc_per_kvb * 100_000.0).max(min_fee_rate_sat_per_vbyte))
+            .collect();
+        let [fastest_fee, half_hour_fee, hour_fee, economy_fee] = fee_rates[..] else {
+            return Err(ElectrumRequestError::Response(PaymentError::Generic {
+                err: format!("Electrum server {electrum_url} returned an unexpected estimate"),
+            }));
+        };
+
+        Ok(RecommendedFees {
+            fastest_fee,
+            half_hour_fee,
+            hour_fee,
+            economy_fee,
+            minimum_fee: min_fee_rate_sat_per_vbyte,
+        })
+    }
+}
+
+impl FeeEstimator for ElectrumFeeEstimator {
+    fn recommended_liquid_fees(&self) -> Result<RecommendedFees, PaymentError> {
+        self.electrum_servers.with_failover(|_, server| {
+            Self::estimate_fees(server, LIQUID_MIN_FEE_RATE_SAT_PER_VBYTE)
+        })
+    }
+
+    fn recommended_bitcoin_fees(&self) -> Result<RecommendedFees, PaymentError> {
+        Ok(Self::estimate_fees(
+            &self.config.bitcoin_electrum_server(),
+            BITCOIN_MIN_FEE_RATE_SAT_PER_VBYTE,
+        )?)
+    }
+}
+
+/// [FeeEstimator] querying the `/fee-estimates` of an Esplora REST API for Liquid fees. Bitcoin
+/// fees are still estimated by the Bitcoin Electrum server.
+pub(crate) struct EsploraFeeEstimator {
+    base_url: String,
+    bitcoin_fee_estimator: ElectrumFeeEstimator,
+}
+
+impl EsploraFeeEstimator {
+    pub(crate) fn new(esplora_url: &str, bitcoin_fee_estimator: ElectrumFeeEstimator) -> Self {
+        Self {
+            base_url: esplora_url.trim_end_matches('/').to_string(),
+            bitcoin_fee_estimator,
+        }
+    }
+}
+
+impl FeeEstimator for EsploraFeeEstimator {
+    fn recommended_liquid_fees(&self) -> Result<RecommendedFees, PaymentError> {
+        let path = "/fee-estimates";
+        let estimates: HashMap<String, f64> = ureq::get(&format!("{}{path}", self.base_url))
+            .call()
+            .map_err(|e| esplora_error(path, e))?
+            .into_string()
+            .map_err(|e| esplora_error(path, e))
+            .and_then(|body| serde_json::from_str(&body).map_err(|e| esplora_error(path, e)))?;
+        // The estimates are in sat/vbyte, by confirmation target. Targets without enough data
+        // are left out.
+        let [fastest_fee, half_hour_fee, hour_fee, economy_fee] =
+            CONFIRMATION_TARGETS.map(|target| {
+                estimates
+                    .get(&target.to_string())
+                    .map_or(LIQUID_MIN_FEE_RATE_SAT_PER_VBYTE, |fee_rate| {
+                        fee_rate.max(LIQUID_MIN_FEE_RATE_SAT_PER_VBYTE)
+                    })
+            });
+
+        Ok(RecommendedFees {
+            fastest_fee,
+            half_hour_fee,
+            hour_fee,
+            economy_fee,
+            minimum_fee: LIQUID_MIN_FEE_RATE_SAT_PER_VBYTE,
+        })
+    }
+
+    fn recommended_bitcoin_fees(&self) -> Result<RecommendedFees, PaymentError> {
+        self.bitcoin_fee_estimator.recommended_bitcoin_fees()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use anyhow::Result;
+
+    use crate::electrum::ElectrumServers;
+    use crate::fee_estimator::{
+        ElectrumFeeEstimator, EsploraFeeEstimator, FeeEstimator, LIQUID_MIN_FEE_RATE_SAT_PER_VBYTE,
+    };
+    use crate::model::{Config, RecommendedFees};
+    use crate::test_utils::chain::EsploraStandIn;
+
+    fn esplora_fee_estimator(esplora_url: &str) -> EsploraFeeEstimator {
+        let electrum_fee_estimator =
+            ElectrumFeeEstimator::new(Config::testnet(), Arc::new(ElectrumServers::new(vec![])));
+        EsploraFeeEstimator::new(esplora_url, electrum_fee_estimator)
+    }
+
+    #[test]
+    fn esplora_liquid_fees() -> Result<()> {
+        let esplora = EsploraStandIn::start(vec![(
+            "/fee-estimates".to_string(),
+            br#"{"1": 0.25, "2": 0.2, "3": 0.15, "6": 0.12, "144": 0.05}"#.to_vec(),
+        )])?;
+        let fee_estimator = esplora_fee_estimator(&format!("{}/", esplora.url));
+
+        // Estimates below the minimum fee rate are raised to it
+        assert_eq!(
+            fee_estimator.recommended_liquid_fees()?,
+            RecommendedFees {
+                fastest_fee: 0.25,
+                half_hour_fee: 0.15,
+                hour_fee: 0.12,
+                economy_fee: LIQUID_MIN_FEE_RATE_SAT_PER_VBYTE,
+                minimum_fee: LIQUID_MIN_FEE_RATE_SAT_PER_VBYTE,
+            }
+        );
+        assert_eq!(
+            esplora.requests.lock().unwrap().clone(),
+            vec![("/fee-estimates".to_string(), String::new())]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn esplora_liquid_fees_without_estimates() -> Result<()> {
+        let esplora = EsploraStandIn::start(vec![("/fee-estimates".to_string(), b"{}".to_vec())])?;
+        let fee_estimator = esplora_fee_estimator(&esplora.url);
+
+        // Targets without an estimate get the minimum fee rate
+        let fees = fee_estimator.recommended_liquid_fees()?;
+        for fee_rate in [
+            fees.fastest_fee,
+            fees.half_hour_fee,
+            fees.hour_fee,
+            fees.economy_fee,
+        ] {
+            assert_eq!(fee_rate, LIQUID_MIN_FEE_RATE_SAT_PER_VBYTE);
+        }
+
+        // Failed requests are errors
+        let unavailable = esplora_fee_estimator(&format!("{}/unavailable", esplora.url));
+        assert!(unavailable.recommended_liquid_fees().is_err());
+
+        Ok(())
+    }
+}
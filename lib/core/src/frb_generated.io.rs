@@ -81,6 +81,27 @@ impl CstDecode<String> for *mut wire_cst_list_prim_u_8_strict {
         String::from_utf8(vec).unwrap()
     }
 }
+impl CstDecode<crate::model::AssetBalance> for wire_cst_asset_balance {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::AssetBalance {
+        crate::model::AssetBalance {
+            asset_id: self.asset_id.cst_decode(),
+            balance: self.balance.cst_decode(),
+            asset_metadata: self.asset_metadata.cst_decode(),
+        }
+    }
+}
+impl CstDecode<crate::model::AssetMetadata> for wire_cst_asset_metadata {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::AssetMetadata {
+        crate::model::AssetMetadata {
+            asset_id: self.asset_id.cst_decode(),
+            ticker: self.ticker.cst_decode(),
+            name: self.name.cst_decode(),
+            precision: self.precision.cst_decode(),
+        }
+    }
+}
 impl CstDecode<crate::model::BackupRequest> for wire_cst_backup_request {
     // Codec=Cst (C-struct based), see doc to use other codecs
     fn cst_decode(self) -> crate::model::BackupRequest {
@@ -97,6 +118,25 @@ impl CstDecode<crate::bindings::BindingEventListener> for wire_cst_binding_event
         }
     }
 }
+impl CstDecode<crate::model::BitcoinAddressData> for wire_cst_bitcoin_address_data {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::BitcoinAddressData {
+        crate::model::BitcoinAddressData {
+            address: self.address.cst_decode(),
+            network: self.network.cst_decode(),
+            amount_sat: self.amount_sat.cst_decode(),
+            label: self.label.cst_decode(),
+            message: self.message.cst_decode(),
+        }
+    }
+}
+impl CstDecode<crate::model::AssetMetadata> for *mut wire_cst_asset_metadata {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::AssetMetadata {
+        let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+        CstDecode::<crate::model::AssetMetadata>::cst_decode(*wrap).into()
+    }
+}
 impl CstDecode<crate::model::BackupRequest> for *mut wire_cst_backup_request {
     // Codec=Cst (C-struct based), see doc to use other codecs
     fn cst_decode(self) -> crate::model::BackupRequest {
@@ -111,6 +151,19 @@ impl CstDecode<crate::bindings::BindingEventListener> for *mut wire_cst_binding_
         CstDecode::<crate::bindings::BindingEventListener>::cst_decode(*wrap).into()
     }
 }
+impl CstDecode<crate::model::BitcoinAddressData> for *mut wire_cst_bitcoin_address_data {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::BitcoinAddressData {
+        let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+        CstDecode::<crate::model::BitcoinAddressData>::cst_decode(*wrap).into()
+    }
+}
+impl CstDecode<bool> for *mut bool {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> bool {
+        unsafe { *flutter_rust_bridge::for_generated::box_from_leak_ptr(self) }
+    }
+}
 impl CstDecode<crate::model::ConnectRequest> for *mut wire_cst_connect_request {
     // Codec=Cst (C-struct based), see doc to use other codecs
     fn cst_decode(self) -> crate::model::ConnectRequest {
@@ -118,6 +171,40 @@ impl CstDecode<crate::model::ConnectRequest> for *mut wire_cst_connect_request {
         CstDecode::<crate::model::ConnectRequest>::cst_decode(*wrap).into()
     }
 }
+impl CstDecode<crate::model::ExportPaymentsRequest> for *mut wire_cst_export_payments_request {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::ExportPaymentsRequest {
+        let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+        CstDecode::<crate::model::ExportPaymentsRequest>::cst_decode(*wrap).into()
+    }
+}
+impl CstDecode<f64> for *mut f64 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> f64 {
+        unsafe { *flutter_rust_bridge::for_generated::box_from_leak_ptr(self) }
+    }
+}
+impl CstDecode<crate::model::FeeBreakdown> for *mut wire_cst_fee_breakdown {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::FeeBreakdown {
+        let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+        CstDecode::<crate::model::FeeBreakdown>::cst_decode(*wrap).into()
+    }
+}
+impl CstDecode<crate::model::GetPaymentRequest> for *mut wire_cst_get_payment_request {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::GetPaymentRequest {
+        let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+        CstDecode::<crate::model::GetPaymentRequest>::cst_decode(*wrap).into()
+    }
+}
+impl CstDecode<crate::model::LiquidAddressData> for *mut wire_cst_liquid_address_data {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::LiquidAddressData {
+        let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+        CstDecode::<crate::model::LiquidAddressData>::cst_decode(*wrap).into()
+    }
+}
 impl CstDecode<crate::model::LiquidSdkEvent> for *mut wire_cst_liquid_sdk_event {
     // Codec=Cst (C-struct based), see doc to use other codecs
     fn cst_decode(self) -> crate::model::LiquidSdkEvent {
@@ -125,6 +212,41 @@ impl CstDecode<crate::model::LiquidSdkEvent> for *mut wire_cst_liquid_sdk_event
         CstDecode::<crate::model::LiquidSdkEvent>::cst_decode(*wrap).into()
     }
 }
+impl CstDecode<crate::model::ListPaymentsRequest> for *mut wire_cst_list_payments_request {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::ListPaymentsRequest {
+        let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+        CstDecode::<crate::model::ListPaymentsRequest>::cst_decode(*wrap).into()
+    }
+}
+impl CstDecode<crate::model::LNInvoice> for *mut wire_cst_ln_invoice {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::LNInvoice {
+        let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+        CstDecode::<crate::model::LNInvoice>::cst_decode(*wrap).into()
+    }
+}
+impl CstDecode<crate::model::LnUrlWithdrawRequest> for *mut wire_cst_ln_url_withdraw_request {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::LnUrlWithdrawRequest {
+        let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+        CstDecode::<crate::model::LnUrlWithdrawRequest>::cst_decode(*wrap).into()
+    }
+}
+impl CstDecode<crate::model::OnchainFeeRate> for *mut wire_cst_onchain_fee_rate {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::OnchainFeeRate {
+        let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+        CstDecode::<crate::model::OnchainFeeRate>::cst_decode(*wrap).into()
+    }
+}
+impl CstDecode<crate::model::PayOnchainRequest> for *mut wire_cst_pay_onchain_request {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::PayOnchainRequest {
+        let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+        CstDecode::<crate::model::PayOnchainRequest>::cst_decode(*wrap).into()
+    }
+}
 impl CstDecode<crate::model::Payment> for *mut wire_cst_payment {
     // Codec=Cst (C-struct based), see doc to use other codecs
     fn cst_decode(self) -> crate::model::Payment {
@@ -132,6 +254,56 @@ impl CstDecode<crate::model::Payment> for *mut wire_cst_payment {
         CstDecode::<crate::model::Payment>::cst_decode(*wrap).into()
     }
 }
+impl CstDecode<crate::model::PaymentMetadata> for *mut wire_cst_payment_metadata {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::PaymentMetadata {
+        let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+        CstDecode::<crate::model::PaymentMetadata>::cst_decode(*wrap).into()
+    }
+}
+impl CstDecode<crate::model::PrepareLnUrlPayRequest> for *mut wire_cst_prepare_ln_url_pay_request {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::PrepareLnUrlPayRequest {
+        let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+        CstDecode::<crate::model::PrepareLnUrlPayRequest>::cst_decode(*wrap).into()
+    }
+}
+impl CstDecode<crate::model::PrepareLnUrlPayResponse>
+    for *mut wire_cst_prepare_ln_url_pay_response
+{
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::PrepareLnUrlPayResponse {
+        let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+        CstDecode::<crate::model::PrepareLnUrlPayResponse>::cst_decode(*wrap).into()
+    }
+}
+impl CstDecode<crate::model::PreparePayOnchainRequest>
+    for *mut wire_cst_prepare_pay_onchain_request
+{
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::PreparePayOnchainRequest {
+        let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+        CstDecode::<crate::model::PreparePayOnchainRequest>::cst_decode(*wrap).into()
+    }
+}
+impl CstDecode<crate::model::PrepareReceiveOnchainRequest>
+    for *mut wire_cst_prepare_receive_onchain_request
+{
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::PrepareReceiveOnchainRequest {
+        let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+        CstDecode::<crate::model::PrepareReceiveOnchainRequest>::cst_decode(*wrap).into()
+    }
+}
+impl CstDecode<crate::model::PrepareReceiveOnchainResponse>
+    for *mut wire_cst_prepare_receive_onchain_response
+{
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::PrepareReceiveOnchainResponse {
+        let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+        CstDecode::<crate::model::PrepareReceiveOnchainResponse>::cst_decode(*wrap).into()
+    }
+}
 impl CstDecode<crate::model::PrepareReceiveRequest> for *mut wire_cst_prepare_receive_request {
     // Codec=Cst (C-struct based), see doc to use other codecs
     fn cst_decode(self) -> crate::model::PrepareReceiveRequest {
@@ -146,6 +318,31 @@ impl CstDecode<crate::model::PrepareReceiveResponse> for *mut wire_cst_prepare_r
         CstDecode::<crate::model::PrepareReceiveResponse>::cst_decode(*wrap).into()
     }
 }
+impl CstDecode<crate::model::PrepareRefundRequest> for *mut wire_cst_prepare_refund_request {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::PrepareRefundRequest {
+        let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+        CstDecode::<crate::model::PrepareRefundRequest>::cst_decode(*wrap).into()
+    }
+}
+impl CstDecode<crate::model::PrepareSendOnchainRequest>
+    for *mut wire_cst_prepare_send_onchain_request
+{
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::PrepareSendOnchainRequest {
+        let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+        CstDecode::<crate::model::PrepareSendOnchainRequest>::cst_decode(*wrap).into()
+    }
+}
+impl CstDecode<crate::model::PrepareSendOnchainResponse>
+    for *mut wire_cst_prepare_send_onchain_response
+{
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::PrepareSendOnchainResponse {
+        let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+        CstDecode::<crate::model::PrepareSendOnchainResponse>::cst_decode(*wrap).into()
+    }
+}
 impl CstDecode<crate::model::PrepareSendRequest> for *mut wire_cst_prepare_send_request {
     // Codec=Cst (C-struct based), see doc to use other codecs
     fn cst_decode(self) -> crate::model::PrepareSendRequest {
@@ -160,6 +357,20 @@ impl CstDecode<crate::model::PrepareSendResponse> for *mut wire_cst_prepare_send
         CstDecode::<crate::model::PrepareSendResponse>::cst_decode(*wrap).into()
     }
 }
+impl CstDecode<crate::model::ReceiveLiquidRequest> for *mut wire_cst_receive_liquid_request {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::ReceiveLiquidRequest {
+        let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+        CstDecode::<crate::model::ReceiveLiquidRequest>::cst_decode(*wrap).into()
+    }
+}
+impl CstDecode<crate::model::RefundRequest> for *mut wire_cst_refund_request {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::RefundRequest {
+        let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+        CstDecode::<crate::model::RefundRequest>::cst_decode(*wrap).into()
+    }
+}
 impl CstDecode<crate::model::RestoreRequest> for *mut wire_cst_restore_request {
     // Codec=Cst (C-struct based), see doc to use other codecs
     fn cst_decode(self) -> crate::model::RestoreRequest {
@@ -167,6 +378,35 @@ impl CstDecode<crate::model::RestoreRequest> for *mut wire_cst_restore_request {
         CstDecode::<crate::model::RestoreRequest>::cst_decode(*wrap).into()
     }
 }
+impl CstDecode<crate::model::SetPaymentMetadataRequest>
+    for *mut wire_cst_set_payment_metadata_request
+{
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::SetPaymentMetadataRequest {
+        let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+        CstDecode::<crate::model::SetPaymentMetadataRequest>::cst_decode(*wrap).into()
+    }
+}
+impl CstDecode<crate::model::SuccessAction> for *mut wire_cst_success_action {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::SuccessAction {
+        let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+        CstDecode::<crate::model::SuccessAction>::cst_decode(*wrap).into()
+    }
+}
+impl CstDecode<crate::model::SuccessActionProcessed> for *mut wire_cst_success_action_processed {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::SuccessActionProcessed {
+        let wrap = unsafe { flutter_rust_bridge::for_generated::box_from_leak_ptr(self) };
+        CstDecode::<crate::model::SuccessActionProcessed>::cst_decode(*wrap).into()
+    }
+}
+impl CstDecode<u32> for *mut u32 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> u32 {
+        unsafe { *flutter_rust_bridge::for_generated::box_from_leak_ptr(self) }
+    }
+}
 impl CstDecode<u64> for *mut u64 {
     // Codec=Cst (C-struct based), see doc to use other codecs
     fn cst_decode(self) -> u64 {
@@ -178,12 +418,20 @@ impl CstDecode<crate::model::Config> for wire_cst_config {
     fn cst_decode(self) -> crate::model::Config {
         crate::model::Config {
             boltz_url: self.boltz_url.cst_decode(),
+            electrum_servers: self.electrum_servers.cst_decode(),
             electrum_url: self.electrum_url.cst_decode(),
+            bitcoin_electrum_url: self.bitcoin_electrum_url.cst_decode(),
+            esplora_url: self.esplora_url.cst_decode(),
             working_dir: self.working_dir.cst_decode(),
             network: self.network.cst_decode(),
             payment_timeout_sec: self.payment_timeout_sec.cst_decode(),
+            sync_fallback_interval_sec: self.sync_fallback_interval_sec.cst_decode(),
             zero_conf_min_fee_rate: self.zero_conf_min_fee_rate.cst_decode(),
             zero_conf_max_amount_sat: self.zero_conf_max_amount_sat.cst_decode(),
+            asset_metadata: self.asset_metadata.cst_decode(),
+            max_fee_sat: self.max_fee_sat.cst_decode(),
+            max_fee_percent: self.max_fee_percent.cst_decode(),
+            regtest_policy_asset: self.regtest_policy_asset.cst_decode(),
         }
     }
 }
@@ -196,6 +444,58 @@ impl CstDecode<crate::model::ConnectRequest> for wire_cst_connect_request {
         }
     }
 }
+impl CstDecode<crate::model::ElectrumServer> for wire_cst_electrum_server {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::ElectrumServer {
+        crate::model::ElectrumServer {
+            url: self.url.cst_decode(),
+            tls: self.tls.cst_decode(),
+            validate_domain: self.validate_domain.cst_decode(),
+        }
+    }
+}
+impl CstDecode<crate::model::ElectrumServerStatus> for wire_cst_electrum_server_status {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::ElectrumServerStatus {
+        crate::model::ElectrumServerStatus {
+            url: self.url.cst_decode(),
+            in_use: self.in_use.cst_decode(),
+            healthy: self.healthy.cst_decode(),
+            consecutive_failures: self.consecutive_failures.cst_decode(),
+            last_error: self.last_error.cst_decode(),
+        }
+    }
+}
+impl CstDecode<crate::model::ExportPaymentsRequest> for wire_cst_export_payments_request {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::ExportPaymentsRequest {
+        crate::model::ExportPaymentsRequest {
+            format: self.format.cst_decode(),
+            filter: self.filter.cst_decode(),
+            path: self.path.cst_decode(),
+        }
+    }
+}
+impl CstDecode<crate::model::ExportPaymentsResponse> for wire_cst_export_payments_response {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::ExportPaymentsResponse {
+        crate::model::ExportPaymentsResponse {
+            payments_count: self.payments_count.cst_decode(),
+        }
+    }
+}
+impl CstDecode<crate::model::FeeBreakdown> for wire_cst_fee_breakdown {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::FeeBreakdown {
+        crate::model::FeeBreakdown {
+            service_fee_percentage: self.service_fee_percentage.cst_decode(),
+            service_fee_sat: self.service_fee_sat.cst_decode(),
+            swapper_miner_fees_sat: self.swapper_miner_fees_sat.cst_decode(),
+            network_fee_sat: self.network_fee_sat.cst_decode(),
+            total_fees_sat: self.total_fees_sat.cst_decode(),
+        }
+    }
+}
 impl CstDecode<crate::model::GetInfoResponse> for wire_cst_get_info_response {
     // Codec=Cst (C-struct based), see doc to use other codecs
     fn cst_decode(self) -> crate::model::GetInfoResponse {
@@ -204,6 +504,113 @@ impl CstDecode<crate::model::GetInfoResponse> for wire_cst_get_info_response {
             pending_send_sat: self.pending_send_sat.cst_decode(),
             pending_receive_sat: self.pending_receive_sat.cst_decode(),
             pubkey: self.pubkey.cst_decode(),
+            asset_balances: self.asset_balances.cst_decode(),
+        }
+    }
+}
+impl CstDecode<crate::model::GetPaymentRequest> for wire_cst_get_payment_request {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::GetPaymentRequest {
+        match self.tag {
+            0 => {
+                let ans = unsafe { self.kind.TxId };
+                crate::model::GetPaymentRequest::TxId {
+                    tx_id: ans.tx_id.cst_decode(),
+                }
+            }
+            1 => {
+                let ans = unsafe { self.kind.SwapId };
+                crate::model::GetPaymentRequest::SwapId {
+                    swap_id: ans.swap_id.cst_decode(),
+                }
+            }
+            2 => {
+                let ans = unsafe { self.kind.Bolt11 };
+                crate::model::GetPaymentRequest::Bolt11 {
+                    bolt11: ans.bolt11.cst_decode(),
+                }
+            }
+            3 => {
+                let ans = unsafe { self.kind.PaymentHash };
+                crate::model::GetPaymentRequest::PaymentHash {
+                    payment_hash: ans.payment_hash.cst_decode(),
+                }
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+impl CstDecode<crate::model::InputType> for wire_cst_input_type {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::InputType {
+        match self.tag {
+            0 => {
+                let ans = unsafe { self.kind.Bolt11 };
+                crate::model::InputType::Bolt11 {
+                    invoice: ans.invoice.cst_decode(),
+                }
+            }
+            1 => {
+                let ans = unsafe { self.kind.LiquidAddress };
+                crate::model::InputType::LiquidAddress {
+                    address: ans.address.cst_decode(),
+                }
+            }
+            2 => {
+                let ans = unsafe { self.kind.BitcoinAddress };
+                crate::model::InputType::BitcoinAddress {
+                    address: ans.address.cst_decode(),
+                }
+            }
+            3 => {
+                let ans = unsafe { self.kind.LnUrl };
+                crate::model::InputType::LnUrl {
+                    url: ans.url.cst_decode(),
+                }
+            }
+            4 => {
+                let ans = unsafe { self.kind.LightningAddress };
+                crate::model::InputType::LightningAddress {
+                    address: ans.address.cst_decode(),
+                    url: ans.url.cst_decode(),
+                }
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+impl CstDecode<crate::model::LightningLimits> for wire_cst_lightning_limits {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::LightningLimits {
+        crate::model::LightningLimits {
+            min_sat: self.min_sat.cst_decode(),
+            max_sat: self.max_sat.cst_decode(),
+            service_fee_percentage: self.service_fee_percentage.cst_decode(),
+            miner_fees_sat: self.miner_fees_sat.cst_decode(),
+        }
+    }
+}
+impl CstDecode<crate::model::LightningPaymentLimitsResponse>
+    for wire_cst_lightning_payment_limits_response
+{
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::LightningPaymentLimitsResponse {
+        crate::model::LightningPaymentLimitsResponse {
+            send: self.send.cst_decode(),
+            receive: self.receive.cst_decode(),
+        }
+    }
+}
+impl CstDecode<crate::model::LiquidAddressData> for wire_cst_liquid_address_data {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::LiquidAddressData {
+        crate::model::LiquidAddressData {
+            address: self.address.cst_decode(),
+            network: self.network.cst_decode(),
+            amount_sat: self.amount_sat.cst_decode(),
+            asset_id: self.asset_id.cst_decode(),
+            label: self.label.cst_decode(),
+            message: self.message.cst_decode(),
         }
     }
 }
@@ -246,31 +653,37 @@ impl CstDecode<crate::model::LiquidSdkEvent> for wire_cst_liquid_sdk_event {
                 }
             }
             3 => {
+                let ans = unsafe { self.kind.PaymentRefundable };
+                crate::model::LiquidSdkEvent::PaymentRefundable {
+                    details: ans.details.cst_decode(),
+                }
+            }
+            4 => {
                 let ans = unsafe { self.kind.PaymentRefundPending };
                 crate::model::LiquidSdkEvent::PaymentRefundPending {
                     details: ans.details.cst_decode(),
                 }
             }
-            4 => {
+            5 => {
                 let ans = unsafe { self.kind.PaymentSucceeded };
                 crate::model::LiquidSdkEvent::PaymentSucceeded {
                     details: ans.details.cst_decode(),
                 }
             }
-            5 => {
+            6 => {
                 let ans = unsafe { self.kind.PaymentWaitingConfirmation };
                 crate::model::LiquidSdkEvent::PaymentWaitingConfirmation {
                     details: ans.details.cst_decode(),
                 }
             }
-            6 => crate::model::LiquidSdkEvent::Synced,
+            7 => crate::model::LiquidSdkEvent::Synced,
             _ => unreachable!(),
         }
     }
 }
-impl CstDecode<Vec<crate::model::Payment>> for *mut wire_cst_list_payment {
+impl CstDecode<Vec<crate::model::AssetBalance>> for *mut wire_cst_list_asset_balance {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    fn cst_decode(self) -> Vec<crate::model::Payment> {
+    fn cst_decode(self) -> Vec<crate::model::AssetBalance> {
         let vec = unsafe {
             let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
             flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
@@ -278,18 +691,19 @@ impl CstDecode<Vec<crate::model::Payment>> for *mut wire_cst_list_payment {
         vec.into_iter().map(CstDecode::cst_decode).collect()
     }
 }
-impl CstDecode<Vec<u8>> for *mut wire_cst_list_prim_u_8_strict {
+impl CstDecode<Vec<crate::model::AssetMetadata>> for *mut wire_cst_list_asset_metadata {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    fn cst_decode(self) -> Vec<u8> {
-        unsafe {
+    fn cst_decode(self) -> Vec<crate::model::AssetMetadata> {
+        let vec = unsafe {
             let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
             flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
-        }
+        };
+        vec.into_iter().map(CstDecode::cst_decode).collect()
     }
 }
-impl CstDecode<Vec<crate::model::RouteHint>> for *mut wire_cst_list_route_hint {
+impl CstDecode<Vec<crate::model::ElectrumServer>> for *mut wire_cst_list_electrum_server {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    fn cst_decode(self) -> Vec<crate::model::RouteHint> {
+    fn cst_decode(self) -> Vec<crate::model::ElectrumServer> {
         let vec = unsafe {
             let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
             flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
@@ -297,9 +711,11 @@ impl CstDecode<Vec<crate::model::RouteHint>> for *mut wire_cst_list_route_hint {
         vec.into_iter().map(CstDecode::cst_decode).collect()
     }
 }
-impl CstDecode<Vec<crate::model::RouteHintHop>> for *mut wire_cst_list_route_hint_hop {
+impl CstDecode<Vec<crate::model::ElectrumServerStatus>>
+    for *mut wire_cst_list_electrum_server_status
+{
     // Codec=Cst (C-struct based), see doc to use other codecs
-    fn cst_decode(self) -> Vec<crate::model::RouteHintHop> {
+    fn cst_decode(self) -> Vec<crate::model::ElectrumServerStatus> {
         let vec = unsafe {
             let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
             flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
@@ -307,48 +723,201 @@ impl CstDecode<Vec<crate::model::RouteHintHop>> for *mut wire_cst_list_route_hin
         vec.into_iter().map(CstDecode::cst_decode).collect()
     }
 }
-impl CstDecode<crate::model::LNInvoice> for wire_cst_ln_invoice {
+impl CstDecode<Vec<crate::model::Payment>> for *mut wire_cst_list_payment {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    fn cst_decode(self) -> crate::model::LNInvoice {
-        crate::model::LNInvoice {
-            bolt11: self.bolt11.cst_decode(),
-            network: self.network.cst_decode(),
-            payee_pubkey: self.payee_pubkey.cst_decode(),
-            payment_hash: self.payment_hash.cst_decode(),
-            description: self.description.cst_decode(),
-            description_hash: self.description_hash.cst_decode(),
-            amount_msat: self.amount_msat.cst_decode(),
-            timestamp: self.timestamp.cst_decode(),
-            expiry: self.expiry.cst_decode(),
-            routing_hints: self.routing_hints.cst_decode(),
-            payment_secret: self.payment_secret.cst_decode(),
-            min_final_cltv_expiry_delta: self.min_final_cltv_expiry_delta.cst_decode(),
-        }
+    fn cst_decode(self) -> Vec<crate::model::Payment> {
+        let vec = unsafe {
+            let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+            flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+        };
+        vec.into_iter().map(CstDecode::cst_decode).collect()
     }
 }
-impl CstDecode<crate::model::LogEntry> for wire_cst_log_entry {
+impl CstDecode<Vec<crate::model::PaymentState>> for *mut wire_cst_list_payment_state {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    fn cst_decode(self) -> crate::model::LogEntry {
-        crate::model::LogEntry {
-            line: self.line.cst_decode(),
+    fn cst_decode(self) -> Vec<crate::model::PaymentState> {
+        let vec = unsafe {
+            let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+            flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+        };
+        vec.into_iter().map(CstDecode::cst_decode).collect()
+    }
+}
+impl CstDecode<Vec<crate::model::PaymentType>> for *mut wire_cst_list_payment_type {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> Vec<crate::model::PaymentType> {
+        let vec = unsafe {
+            let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+            flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+        };
+        vec.into_iter().map(CstDecode::cst_decode).collect()
+    }
+}
+impl CstDecode<crate::model::ListPaymentsRequest> for wire_cst_list_payments_request {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::ListPaymentsRequest {
+        crate::model::ListPaymentsRequest {
+            payment_types: self.payment_types.cst_decode(),
+            states: self.states.cst_decode(),
+            from_timestamp: self.from_timestamp.cst_decode(),
+            to_timestamp: self.to_timestamp.cst_decode(),
+            offset: self.offset.cst_decode(),
+            limit: self.limit.cst_decode(),
+            sort_descending: self.sort_descending.cst_decode(),
+            metadata_search: self.metadata_search.cst_decode(),
+        }
+    }
+}
+impl CstDecode<Vec<u8>> for *mut wire_cst_list_prim_u_8_strict {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> Vec<u8> {
+        unsafe {
+            let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+            flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+        }
+    }
+}
+impl CstDecode<Vec<crate::model::RefundableSwap>> for *mut wire_cst_list_refundable_swap {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> Vec<crate::model::RefundableSwap> {
+        let vec = unsafe {
+            let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+            flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+        };
+        vec.into_iter().map(CstDecode::cst_decode).collect()
+    }
+}
+impl CstDecode<Vec<crate::model::RouteHint>> for *mut wire_cst_list_route_hint {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> Vec<crate::model::RouteHint> {
+        let vec = unsafe {
+            let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+            flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+        };
+        vec.into_iter().map(CstDecode::cst_decode).collect()
+    }
+}
+impl CstDecode<Vec<crate::model::RouteHintHop>> for *mut wire_cst_list_route_hint_hop {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> Vec<crate::model::RouteHintHop> {
+        let vec = unsafe {
+            let wrap = flutter_rust_bridge::for_generated::box_from_leak_ptr(self);
+            flutter_rust_bridge::for_generated::vec_from_leak_ptr(wrap.ptr, wrap.len)
+        };
+        vec.into_iter().map(CstDecode::cst_decode).collect()
+    }
+}
+impl CstDecode<crate::model::LNInvoice> for wire_cst_ln_invoice {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::LNInvoice {
+        crate::model::LNInvoice {
+            bolt11: self.bolt11.cst_decode(),
+            network: self.network.cst_decode(),
+            payee_pubkey: self.payee_pubkey.cst_decode(),
+            payment_hash: self.payment_hash.cst_decode(),
+            description: self.description.cst_decode(),
+            description_hash: self.description_hash.cst_decode(),
+            amount_msat: self.amount_msat.cst_decode(),
+            timestamp: self.timestamp.cst_decode(),
+            expiry: self.expiry.cst_decode(),
+            routing_hints: self.routing_hints.cst_decode(),
+            payment_secret: self.payment_secret.cst_decode(),
+            min_final_cltv_expiry_delta: self.min_final_cltv_expiry_delta.cst_decode(),
+        }
+    }
+}
+impl CstDecode<crate::model::LnUrlPayResult> for wire_cst_ln_url_pay_result {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::LnUrlPayResult {
+        crate::model::LnUrlPayResult {
+            payment: self.payment.cst_decode(),
+            success_action: self.success_action.cst_decode(),
+        }
+    }
+}
+impl CstDecode<crate::model::LnUrlWithdrawRequest> for wire_cst_ln_url_withdraw_request {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::LnUrlWithdrawRequest {
+        crate::model::LnUrlWithdrawRequest {
+            lnurl: self.lnurl.cst_decode(),
+            amount_sat: self.amount_sat.cst_decode(),
+        }
+    }
+}
+impl CstDecode<crate::model::LnUrlWithdrawResult> for wire_cst_ln_url_withdraw_result {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::LnUrlWithdrawResult {
+        crate::model::LnUrlWithdrawResult {
+            receive_payment_response: self.receive_payment_response.cst_decode(),
+        }
+    }
+}
+impl CstDecode<crate::model::LogEntry> for wire_cst_log_entry {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::LogEntry {
+        crate::model::LogEntry {
+            line: self.line.cst_decode(),
             level: self.level.cst_decode(),
         }
     }
 }
+impl CstDecode<crate::model::OnchainFeeRate> for wire_cst_onchain_fee_rate {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::OnchainFeeRate {
+        match self.tag {
+            0 => {
+                let ans = unsafe { self.kind.SatPerVbyte };
+                crate::model::OnchainFeeRate::SatPerVbyte {
+                    sat_per_vbyte: ans.sat_per_vbyte.cst_decode(),
+                }
+            }
+            1 => {
+                let ans = unsafe { self.kind.Priority };
+                crate::model::OnchainFeeRate::Priority {
+                    priority: ans.priority.cst_decode(),
+                }
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+impl CstDecode<crate::model::PayOnchainRequest> for wire_cst_pay_onchain_request {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::PayOnchainRequest {
+        crate::model::PayOnchainRequest {
+            address: self.address.cst_decode(),
+            prepare_res: self.prepare_res.cst_decode(),
+        }
+    }
+}
 impl CstDecode<crate::model::Payment> for wire_cst_payment {
     // Codec=Cst (C-struct based), see doc to use other codecs
     fn cst_decode(self) -> crate::model::Payment {
         crate::model::Payment {
             tx_id: self.tx_id.cst_decode(),
             swap_id: self.swap_id.cst_decode(),
+            swap_type: self.swap_type.cst_decode(),
             timestamp: self.timestamp.cst_decode(),
             amount_sat: self.amount_sat.cst_decode(),
             fees_sat: self.fees_sat.cst_decode(),
+            network_fees_sat: self.network_fees_sat.cst_decode(),
+            fee_breakdown: self.fee_breakdown.cst_decode(),
+            fee_rate_sat_per_vbyte: self.fee_rate_sat_per_vbyte.cst_decode(),
             preimage: self.preimage.cst_decode(),
+            bolt11: self.bolt11.cst_decode(),
+            payment_hash: self.payment_hash.cst_decode(),
+            payee_pubkey: self.payee_pubkey.cst_decode(),
+            lockup_tx_id: self.lockup_tx_id.cst_decode(),
+            claim_tx_id: self.claim_tx_id.cst_decode(),
             refund_tx_id: self.refund_tx_id.cst_decode(),
             refund_tx_amount_sat: self.refund_tx_amount_sat.cst_decode(),
             payment_type: self.payment_type.cst_decode(),
             status: self.status.cst_decode(),
+            label: self.label.cst_decode(),
+            description: self.description.cst_decode(),
+            metadata: self.metadata.cst_decode(),
+            asset_id: self.asset_id.cst_decode(),
+            asset_amount: self.asset_amount.cst_decode(),
         }
     }
 }
@@ -361,50 +930,87 @@ impl CstDecode<crate::error::PaymentError> for wire_cst_payment_error {
             2 => crate::error::PaymentError::PaymentInProgress,
             3 => crate::error::PaymentError::AmountOutOfRange,
             4 => {
+                let ans = unsafe { self.kind.UnrecognizedInput };
+                crate::error::PaymentError::UnrecognizedInput {
+                    err: ans.err.cst_decode(),
+                }
+            }
+            5 => {
+                let ans = unsafe { self.kind.NetworkMismatch };
+                crate::error::PaymentError::NetworkMismatch {
+                    err: ans.err.cst_decode(),
+                }
+            }
+            6 => {
+                let ans = unsafe { self.kind.LnUrlError };
+                crate::error::PaymentError::LnUrlError {
+                    err: ans.err.cst_decode(),
+                }
+            }
+            7 => {
+                let ans = unsafe { self.kind.LnUrlServiceUnreachable };
+                crate::error::PaymentError::LnUrlServiceUnreachable {
+                    err: ans.err.cst_decode(),
+                }
+            }
+            8 => {
+                let ans = unsafe { self.kind.LnUrlServiceError };
+                crate::error::PaymentError::LnUrlServiceError {
+                    err: ans.err.cst_decode(),
+                }
+            }
+            9 => {
                 let ans = unsafe { self.kind.Generic };
                 crate::error::PaymentError::Generic {
                     err: ans.err.cst_decode(),
                 }
             }
-            5 => crate::error::PaymentError::InvalidOrExpiredFees,
-            6 => crate::error::PaymentError::InsufficientFunds,
-            7 => {
+            10 => crate::error::PaymentError::InvalidOrExpiredFees,
+            11 => {
+                let ans = unsafe { self.kind.FeeLimitExceeded };
+                crate::error::PaymentError::FeeLimitExceeded {
+                    fee_sat: ans.fee_sat.cst_decode(),
+                    limit_sat: ans.limit_sat.cst_decode(),
+                }
+            }
+            12 => crate::error::PaymentError::InsufficientFunds,
+            13 => {
                 let ans = unsafe { self.kind.InvalidInvoice };
                 crate::error::PaymentError::InvalidInvoice {
                     err: ans.err.cst_decode(),
                 }
             }
-            8 => crate::error::PaymentError::InvalidPreimage,
-            9 => {
+            14 => crate::error::PaymentError::InvalidPreimage,
+            15 => {
                 let ans = unsafe { self.kind.LwkError };
                 crate::error::PaymentError::LwkError {
                     err: ans.err.cst_decode(),
                 }
             }
-            10 => crate::error::PaymentError::PairsNotFound,
-            11 => crate::error::PaymentError::PaymentTimeout,
-            12 => crate::error::PaymentError::PersistError,
-            13 => {
+            16 => crate::error::PaymentError::PairsNotFound,
+            17 => crate::error::PaymentError::PaymentTimeout,
+            18 => crate::error::PaymentError::PersistError,
+            19 => {
                 let ans = unsafe { self.kind.ReceiveError };
                 crate::error::PaymentError::ReceiveError {
                     err: ans.err.cst_decode(),
                 }
             }
-            14 => {
+            20 => {
                 let ans = unsafe { self.kind.Refunded };
                 crate::error::PaymentError::Refunded {
                     err: ans.err.cst_decode(),
                     refund_tx_id: ans.refund_tx_id.cst_decode(),
                 }
             }
-            15 => crate::error::PaymentError::SelfTransferNotSupported,
-            16 => {
+            21 => crate::error::PaymentError::SelfTransferNotSupported,
+            22 => {
                 let ans = unsafe { self.kind.SendError };
                 crate::error::PaymentError::SendError {
                     err: ans.err.cst_decode(),
                 }
             }
-            17 => {
+            23 => {
                 let ans = unsafe { self.kind.SignerError };
                 crate::error::PaymentError::SignerError {
                     err: ans.err.cst_decode(),
@@ -414,11 +1020,90 @@ impl CstDecode<crate::error::PaymentError> for wire_cst_payment_error {
         }
     }
 }
+impl CstDecode<crate::model::PaymentMetadata> for wire_cst_payment_metadata {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::PaymentMetadata {
+        crate::model::PaymentMetadata {
+            label: self.label.cst_decode(),
+            note: self.note.cst_decode(),
+            external_id: self.external_id.cst_decode(),
+        }
+    }
+}
+impl CstDecode<crate::model::PrepareLnUrlPayRequest> for wire_cst_prepare_ln_url_pay_request {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::PrepareLnUrlPayRequest {
+        crate::model::PrepareLnUrlPayRequest {
+            lnurl: self.lnurl.cst_decode(),
+            amount_sat: self.amount_sat.cst_decode(),
+            comment: self.comment.cst_decode(),
+        }
+    }
+}
+impl CstDecode<crate::model::PrepareLnUrlPayResponse> for wire_cst_prepare_ln_url_pay_response {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::PrepareLnUrlPayResponse {
+        crate::model::PrepareLnUrlPayResponse {
+            prepare_send_response: self.prepare_send_response.cst_decode(),
+            success_action: self.success_action.cst_decode(),
+        }
+    }
+}
+impl CstDecode<crate::model::PreparePayOnchainRequest> for wire_cst_prepare_pay_onchain_request {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::PreparePayOnchainRequest {
+        crate::model::PreparePayOnchainRequest {
+            receiver_amount_sat: self.receiver_amount_sat.cst_decode(),
+            fee_rate: self.fee_rate.cst_decode(),
+        }
+    }
+}
+impl CstDecode<crate::model::PreparePayOnchainResponse> for wire_cst_prepare_pay_onchain_response {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::PreparePayOnchainResponse {
+        crate::model::PreparePayOnchainResponse {
+            receiver_amount_sat: self.receiver_amount_sat.cst_decode(),
+            fees_sat: self.fees_sat.cst_decode(),
+            fee_rate_sat_per_vbyte: self.fee_rate_sat_per_vbyte.cst_decode(),
+        }
+    }
+}
+impl CstDecode<crate::model::PrepareReceiveOnchainRequest>
+    for wire_cst_prepare_receive_onchain_request
+{
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::PrepareReceiveOnchainRequest {
+        crate::model::PrepareReceiveOnchainRequest {
+            payer_amount_sat: self.payer_amount_sat.cst_decode(),
+            fee_rate: self.fee_rate.cst_decode(),
+        }
+    }
+}
+impl CstDecode<crate::model::PrepareReceiveOnchainResponse>
+    for wire_cst_prepare_receive_onchain_response
+{
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::PrepareReceiveOnchainResponse {
+        crate::model::PrepareReceiveOnchainResponse {
+            payer_amount_sat: self.payer_amount_sat.cst_decode(),
+            fees_sat: self.fees_sat.cst_decode(),
+            fee_rate_sat_per_vbyte: self.fee_rate_sat_per_vbyte.cst_decode(),
+        }
+    }
+}
 impl CstDecode<crate::model::PrepareReceiveRequest> for wire_cst_prepare_receive_request {
     // Codec=Cst (C-struct based), see doc to use other codecs
     fn cst_decode(self) -> crate::model::PrepareReceiveRequest {
         crate::model::PrepareReceiveRequest {
             payer_amount_sat: self.payer_amount_sat.cst_decode(),
+            receiver_amount_sat: self.receiver_amount_sat.cst_decode(),
+            description: self.description.cst_decode(),
+            description_hash: self.description_hash.cst_decode(),
+            invoice_expiry_secs: self.invoice_expiry_secs.cst_decode(),
+            metadata: self.metadata.cst_decode(),
+            max_fee_sat: self.max_fee_sat.cst_decode(),
+            max_fee_percent: self.max_fee_percent.cst_decode(),
+            fee_rate: self.fee_rate.cst_decode(),
         }
     }
 }
@@ -427,7 +1112,64 @@ impl CstDecode<crate::model::PrepareReceiveResponse> for wire_cst_prepare_receiv
     fn cst_decode(self) -> crate::model::PrepareReceiveResponse {
         crate::model::PrepareReceiveResponse {
             payer_amount_sat: self.payer_amount_sat.cst_decode(),
+            receiver_amount_sat: self.receiver_amount_sat.cst_decode(),
+            fees_sat: self.fees_sat.cst_decode(),
+            description: self.description.cst_decode(),
+            description_hash: self.description_hash.cst_decode(),
+            invoice_expiry_secs: self.invoice_expiry_secs.cst_decode(),
+            metadata: self.metadata.cst_decode(),
+            fee_breakdown: self.fee_breakdown.cst_decode(),
+            pair_hash: self.pair_hash.cst_decode(),
+            expires_at: self.expires_at.cst_decode(),
+            fee_rate_sat_per_vbyte: self.fee_rate_sat_per_vbyte.cst_decode(),
+        }
+    }
+}
+impl CstDecode<crate::model::PrepareRefundRequest> for wire_cst_prepare_refund_request {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::PrepareRefundRequest {
+        crate::model::PrepareRefundRequest {
+            swap_id: self.swap_id.cst_decode(),
+            refund_address: self.refund_address.cst_decode(),
+            fee_rate: self.fee_rate.cst_decode(),
+        }
+    }
+}
+impl CstDecode<crate::model::PrepareRefundResponse> for wire_cst_prepare_refund_response {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::PrepareRefundResponse {
+        crate::model::PrepareRefundResponse {
+            tx_vsize: self.tx_vsize.cst_decode(),
+            tx_fee_sat: self.tx_fee_sat.cst_decode(),
+            refund_amount_sat: self.refund_amount_sat.cst_decode(),
+            fee_rate_sat_per_vbyte: self.fee_rate_sat_per_vbyte.cst_decode(),
+        }
+    }
+}
+impl CstDecode<crate::model::PrepareSendOnchainRequest> for wire_cst_prepare_send_onchain_request {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::PrepareSendOnchainRequest {
+        crate::model::PrepareSendOnchainRequest {
+            destination: self.destination.cst_decode(),
+            amount_sat: self.amount_sat.cst_decode(),
+            send_all: self.send_all.cst_decode(),
+            asset_id: self.asset_id.cst_decode(),
+            fee_rate: self.fee_rate.cst_decode(),
+        }
+    }
+}
+impl CstDecode<crate::model::PrepareSendOnchainResponse>
+    for wire_cst_prepare_send_onchain_response
+{
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::PrepareSendOnchainResponse {
+        crate::model::PrepareSendOnchainResponse {
+            address: self.address.cst_decode(),
+            receiver_amount_sat: self.receiver_amount_sat.cst_decode(),
             fees_sat: self.fees_sat.cst_decode(),
+            send_all: self.send_all.cst_decode(),
+            asset_id: self.asset_id.cst_decode(),
+            fee_rate_sat_per_vbyte: self.fee_rate_sat_per_vbyte.cst_decode(),
         }
     }
 }
@@ -436,6 +1178,10 @@ impl CstDecode<crate::model::PrepareSendRequest> for wire_cst_prepare_send_reque
     fn cst_decode(self) -> crate::model::PrepareSendRequest {
         crate::model::PrepareSendRequest {
             invoice: self.invoice.cst_decode(),
+            metadata: self.metadata.cst_decode(),
+            max_fee_sat: self.max_fee_sat.cst_decode(),
+            max_fee_percent: self.max_fee_percent.cst_decode(),
+            fee_rate: self.fee_rate.cst_decode(),
         }
     }
 }
@@ -445,6 +1191,41 @@ impl CstDecode<crate::model::PrepareSendResponse> for wire_cst_prepare_send_resp
         crate::model::PrepareSendResponse {
             invoice: self.invoice.cst_decode(),
             fees_sat: self.fees_sat.cst_decode(),
+            metadata: self.metadata.cst_decode(),
+            fee_breakdown: self.fee_breakdown.cst_decode(),
+            max_fee_sat: self.max_fee_sat.cst_decode(),
+            max_fee_percent: self.max_fee_percent.cst_decode(),
+            pair_hash: self.pair_hash.cst_decode(),
+            expires_at: self.expires_at.cst_decode(),
+            fee_rate_sat_per_vbyte: self.fee_rate_sat_per_vbyte.cst_decode(),
+        }
+    }
+}
+impl CstDecode<crate::model::ReceiveLiquidRequest> for wire_cst_receive_liquid_request {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::ReceiveLiquidRequest {
+        crate::model::ReceiveLiquidRequest {
+            amount_sat: self.amount_sat.cst_decode(),
+            label: self.label.cst_decode(),
+        }
+    }
+}
+impl CstDecode<crate::model::ReceiveLiquidResponse> for wire_cst_receive_liquid_response {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::ReceiveLiquidResponse {
+        crate::model::ReceiveLiquidResponse {
+            address: self.address.cst_decode(),
+            bip21: self.bip21.cst_decode(),
+        }
+    }
+}
+impl CstDecode<crate::model::ReceiveOnchainResponse> for wire_cst_receive_onchain_response {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::ReceiveOnchainResponse {
+        crate::model::ReceiveOnchainResponse {
+            id: self.id.cst_decode(),
+            address: self.address.cst_decode(),
+            bip21: self.bip21.cst_decode(),
         }
     }
 }
@@ -457,6 +1238,57 @@ impl CstDecode<crate::model::ReceivePaymentResponse> for wire_cst_receive_paymen
         }
     }
 }
+impl CstDecode<crate::model::RecommendedFees> for wire_cst_recommended_fees {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::RecommendedFees {
+        crate::model::RecommendedFees {
+            fastest_fee: self.fastest_fee.cst_decode(),
+            half_hour_fee: self.half_hour_fee.cst_decode(),
+            hour_fee: self.hour_fee.cst_decode(),
+            economy_fee: self.economy_fee.cst_decode(),
+            minimum_fee: self.minimum_fee.cst_decode(),
+        }
+    }
+}
+impl CstDecode<crate::model::RecommendedFeesResponse> for wire_cst_recommended_fees_response {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::RecommendedFeesResponse {
+        crate::model::RecommendedFeesResponse {
+            liquid: self.liquid.cst_decode(),
+            bitcoin: self.bitcoin.cst_decode(),
+        }
+    }
+}
+impl CstDecode<crate::model::RefundRequest> for wire_cst_refund_request {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::RefundRequest {
+        crate::model::RefundRequest {
+            swap_id: self.swap_id.cst_decode(),
+            refund_address: self.refund_address.cst_decode(),
+            fee_rate_sat_per_vbyte: self.fee_rate_sat_per_vbyte.cst_decode(),
+        }
+    }
+}
+impl CstDecode<crate::model::RefundResponse> for wire_cst_refund_response {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::RefundResponse {
+        crate::model::RefundResponse {
+            refund_tx_id: self.refund_tx_id.cst_decode(),
+        }
+    }
+}
+impl CstDecode<crate::model::RefundableSwap> for wire_cst_refundable_swap {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::RefundableSwap {
+        crate::model::RefundableSwap {
+            swap_id: self.swap_id.cst_decode(),
+            swap_address: self.swap_address.cst_decode(),
+            timestamp: self.timestamp.cst_decode(),
+            amount_sat: self.amount_sat.cst_decode(),
+            payment_type: self.payment_type.cst_decode(),
+        }
+    }
+}
 impl CstDecode<crate::model::RestoreRequest> for wire_cst_restore_request {
     // Codec=Cst (C-struct based), see doc to use other codecs
     fn cst_decode(self) -> crate::model::RestoreRequest {
@@ -495,26 +1327,137 @@ impl CstDecode<crate::model::SendPaymentResponse> for wire_cst_send_payment_resp
         }
     }
 }
-impl NewWithNullPtr for wire_cst_backup_request {
-    fn new_with_null_ptr() -> Self {
-        Self {
-            backup_path: core::ptr::null_mut(),
-        }
-    }
-}
-impl Default for wire_cst_backup_request {
-    fn default() -> Self {
-        Self::new_with_null_ptr()
-    }
-}
-impl NewWithNullPtr for wire_cst_binding_event_listener {
-    fn new_with_null_ptr() -> Self {
-        Self {
-            stream: core::ptr::null_mut(),
+impl CstDecode<crate::model::SetPaymentMetadataRequest> for wire_cst_set_payment_metadata_request {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::SetPaymentMetadataRequest {
+        crate::model::SetPaymentMetadataRequest {
+            id: self.id.cst_decode(),
+            metadata: self.metadata.cst_decode(),
         }
     }
 }
-impl Default for wire_cst_binding_event_listener {
+impl CstDecode<crate::model::SuccessAction> for wire_cst_success_action {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::SuccessAction {
+        match self.tag {
+            0 => {
+                let ans = unsafe { self.kind.Message };
+                crate::model::SuccessAction::Message {
+                    message: ans.message.cst_decode(),
+                }
+            }
+            1 => {
+                let ans = unsafe { self.kind.Url };
+                crate::model::SuccessAction::Url {
+                    description: ans.description.cst_decode(),
+                    url: ans.url.cst_decode(),
+                }
+            }
+            2 => {
+                let ans = unsafe { self.kind.Aes };
+                crate::model::SuccessAction::Aes {
+                    description: ans.description.cst_decode(),
+                    ciphertext: ans.ciphertext.cst_decode(),
+                    iv: ans.iv.cst_decode(),
+                }
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+impl CstDecode<crate::model::SuccessActionProcessed> for wire_cst_success_action_processed {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::SuccessActionProcessed {
+        match self.tag {
+            0 => {
+                let ans = unsafe { self.kind.Message };
+                crate::model::SuccessActionProcessed::Message {
+                    message: ans.message.cst_decode(),
+                }
+            }
+            1 => {
+                let ans = unsafe { self.kind.Url };
+                crate::model::SuccessActionProcessed::Url {
+                    description: ans.description.cst_decode(),
+                    url: ans.url.cst_decode(),
+                }
+            }
+            2 => {
+                let ans = unsafe { self.kind.Aes };
+                crate::model::SuccessActionProcessed::Aes {
+                    description: ans.description.cst_decode(),
+                    plaintext: ans.plaintext.cst_decode(),
+                }
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+impl NewWithNullPtr for wire_cst_asset_balance {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            asset_id: core::ptr::null_mut(),
+            balance: Default::default(),
+            asset_metadata: core::ptr::null_mut(),
+        }
+    }
+}
+impl Default for wire_cst_asset_balance {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+impl NewWithNullPtr for wire_cst_asset_metadata {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            asset_id: core::ptr::null_mut(),
+            ticker: core::ptr::null_mut(),
+            name: core::ptr::null_mut(),
+            precision: Default::default(),
+        }
+    }
+}
+impl Default for wire_cst_asset_metadata {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+impl NewWithNullPtr for wire_cst_backup_request {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            backup_path: core::ptr::null_mut(),
+        }
+    }
+}
+impl Default for wire_cst_backup_request {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+impl NewWithNullPtr for wire_cst_binding_event_listener {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            stream: core::ptr::null_mut(),
+        }
+    }
+}
+impl Default for wire_cst_binding_event_listener {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+impl NewWithNullPtr for wire_cst_bitcoin_address_data {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            address: core::ptr::null_mut(),
+            network: Default::default(),
+            amount_sat: core::ptr::null_mut(),
+            label: core::ptr::null_mut(),
+            message: core::ptr::null_mut(),
+        }
+    }
+}
+impl Default for wire_cst_bitcoin_address_data {
     fn default() -> Self {
         Self::new_with_null_ptr()
     }
@@ -523,12 +1466,20 @@ impl NewWithNullPtr for wire_cst_config {
     fn new_with_null_ptr() -> Self {
         Self {
             boltz_url: core::ptr::null_mut(),
+            electrum_servers: core::ptr::null_mut(),
             electrum_url: core::ptr::null_mut(),
+            bitcoin_electrum_url: core::ptr::null_mut(),
+            esplora_url: core::ptr::null_mut(),
             working_dir: core::ptr::null_mut(),
             network: Default::default(),
             payment_timeout_sec: Default::default(),
+            sync_fallback_interval_sec: Default::default(),
             zero_conf_min_fee_rate: Default::default(),
             zero_conf_max_amount_sat: core::ptr::null_mut(),
+            asset_metadata: core::ptr::null_mut(),
+            max_fee_sat: core::ptr::null_mut(),
+            max_fee_percent: core::ptr::null_mut(),
+            regtest_policy_asset: core::ptr::null_mut(),
         }
     }
 }
@@ -550,6 +1501,78 @@ impl Default for wire_cst_connect_request {
         Self::new_with_null_ptr()
     }
 }
+impl NewWithNullPtr for wire_cst_electrum_server {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            url: core::ptr::null_mut(),
+            tls: Default::default(),
+            validate_domain: Default::default(),
+        }
+    }
+}
+impl Default for wire_cst_electrum_server {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+impl NewWithNullPtr for wire_cst_electrum_server_status {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            url: core::ptr::null_mut(),
+            in_use: Default::default(),
+            healthy: Default::default(),
+            consecutive_failures: Default::default(),
+            last_error: core::ptr::null_mut(),
+        }
+    }
+}
+impl Default for wire_cst_electrum_server_status {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+impl NewWithNullPtr for wire_cst_export_payments_request {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            format: Default::default(),
+            filter: Default::default(),
+            path: core::ptr::null_mut(),
+        }
+    }
+}
+impl Default for wire_cst_export_payments_request {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+impl NewWithNullPtr for wire_cst_export_payments_response {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            payments_count: Default::default(),
+        }
+    }
+}
+impl Default for wire_cst_export_payments_response {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+impl NewWithNullPtr for wire_cst_fee_breakdown {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            service_fee_percentage: Default::default(),
+            service_fee_sat: Default::default(),
+            swapper_miner_fees_sat: Default::default(),
+            network_fee_sat: Default::default(),
+            total_fees_sat: Default::default(),
+        }
+    }
+}
+impl Default for wire_cst_fee_breakdown {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
 impl NewWithNullPtr for wire_cst_get_info_response {
     fn new_with_null_ptr() -> Self {
         Self {
@@ -557,6 +1580,7 @@ impl NewWithNullPtr for wire_cst_get_info_response {
             pending_send_sat: Default::default(),
             pending_receive_sat: Default::default(),
             pubkey: core::ptr::null_mut(),
+            asset_balances: core::ptr::null_mut(),
         }
     }
 }
@@ -565,6 +1589,77 @@ impl Default for wire_cst_get_info_response {
         Self::new_with_null_ptr()
     }
 }
+impl NewWithNullPtr for wire_cst_get_payment_request {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            tag: -1,
+            kind: GetPaymentRequestKind { nil__: () },
+        }
+    }
+}
+impl Default for wire_cst_get_payment_request {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+impl NewWithNullPtr for wire_cst_input_type {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            tag: -1,
+            kind: InputTypeKind { nil__: () },
+        }
+    }
+}
+impl Default for wire_cst_input_type {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+impl NewWithNullPtr for wire_cst_lightning_limits {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            min_sat: Default::default(),
+            max_sat: Default::default(),
+            service_fee_percentage: Default::default(),
+            miner_fees_sat: Default::default(),
+        }
+    }
+}
+impl Default for wire_cst_lightning_limits {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+impl NewWithNullPtr for wire_cst_lightning_payment_limits_response {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            send: Default::default(),
+            receive: Default::default(),
+        }
+    }
+}
+impl Default for wire_cst_lightning_payment_limits_response {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+impl NewWithNullPtr for wire_cst_liquid_address_data {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            address: core::ptr::null_mut(),
+            network: Default::default(),
+            amount_sat: core::ptr::null_mut(),
+            asset_id: core::ptr::null_mut(),
+            label: core::ptr::null_mut(),
+            message: core::ptr::null_mut(),
+        }
+    }
+}
+impl Default for wire_cst_liquid_address_data {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
 impl NewWithNullPtr for wire_cst_liquid_sdk_error {
     fn new_with_null_ptr() -> Self {
         Self {
@@ -591,6 +1686,25 @@ impl Default for wire_cst_liquid_sdk_event {
         Self::new_with_null_ptr()
     }
 }
+impl NewWithNullPtr for wire_cst_list_payments_request {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            payment_types: core::ptr::null_mut(),
+            states: core::ptr::null_mut(),
+            from_timestamp: core::ptr::null_mut(),
+            to_timestamp: core::ptr::null_mut(),
+            offset: core::ptr::null_mut(),
+            limit: core::ptr::null_mut(),
+            sort_descending: core::ptr::null_mut(),
+            metadata_search: core::ptr::null_mut(),
+        }
+    }
+}
+impl Default for wire_cst_list_payments_request {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
 impl NewWithNullPtr for wire_cst_ln_invoice {
     fn new_with_null_ptr() -> Self {
         Self {
@@ -614,6 +1728,44 @@ impl Default for wire_cst_ln_invoice {
         Self::new_with_null_ptr()
     }
 }
+impl NewWithNullPtr for wire_cst_ln_url_pay_result {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            payment: Default::default(),
+            success_action: core::ptr::null_mut(),
+        }
+    }
+}
+impl Default for wire_cst_ln_url_pay_result {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+impl NewWithNullPtr for wire_cst_ln_url_withdraw_request {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            lnurl: core::ptr::null_mut(),
+            amount_sat: Default::default(),
+        }
+    }
+}
+impl Default for wire_cst_ln_url_withdraw_request {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+impl NewWithNullPtr for wire_cst_ln_url_withdraw_result {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            receive_payment_response: Default::default(),
+        }
+    }
+}
+impl Default for wire_cst_ln_url_withdraw_result {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
 impl NewWithNullPtr for wire_cst_log_entry {
     fn new_with_null_ptr() -> Self {
         Self {
@@ -627,19 +1779,59 @@ impl Default for wire_cst_log_entry {
         Self::new_with_null_ptr()
     }
 }
+impl NewWithNullPtr for wire_cst_onchain_fee_rate {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            tag: -1,
+            kind: OnchainFeeRateKind { nil__: () },
+        }
+    }
+}
+impl Default for wire_cst_onchain_fee_rate {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+impl NewWithNullPtr for wire_cst_pay_onchain_request {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            address: core::ptr::null_mut(),
+            prepare_res: Default::default(),
+        }
+    }
+}
+impl Default for wire_cst_pay_onchain_request {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
 impl NewWithNullPtr for wire_cst_payment {
     fn new_with_null_ptr() -> Self {
         Self {
             tx_id: core::ptr::null_mut(),
             swap_id: core::ptr::null_mut(),
+            swap_type: Default::default(),
             timestamp: Default::default(),
             amount_sat: Default::default(),
             fees_sat: Default::default(),
+            network_fees_sat: core::ptr::null_mut(),
+            fee_breakdown: core::ptr::null_mut(),
+            fee_rate_sat_per_vbyte: core::ptr::null_mut(),
             preimage: core::ptr::null_mut(),
+            bolt11: core::ptr::null_mut(),
+            payment_hash: core::ptr::null_mut(),
+            payee_pubkey: core::ptr::null_mut(),
+            lockup_tx_id: core::ptr::null_mut(),
+            claim_tx_id: core::ptr::null_mut(),
             refund_tx_id: core::ptr::null_mut(),
             refund_tx_amount_sat: core::ptr::null_mut(),
             payment_type: Default::default(),
             status: Default::default(),
+            label: core::ptr::null_mut(),
+            description: core::ptr::null_mut(),
+            metadata: core::ptr::null_mut(),
+            asset_id: core::ptr::null_mut(),
+            asset_amount: core::ptr::null_mut(),
         }
     }
 }
@@ -661,77 +1853,373 @@ impl Default for wire_cst_payment_error {
         Self::new_with_null_ptr()
     }
 }
-impl NewWithNullPtr for wire_cst_prepare_receive_request {
+impl NewWithNullPtr for wire_cst_payment_metadata {
     fn new_with_null_ptr() -> Self {
         Self {
-            payer_amount_sat: Default::default(),
+            label: core::ptr::null_mut(),
+            note: core::ptr::null_mut(),
+            external_id: core::ptr::null_mut(),
         }
     }
 }
-impl Default for wire_cst_prepare_receive_request {
+impl Default for wire_cst_payment_metadata {
     fn default() -> Self {
         Self::new_with_null_ptr()
     }
 }
-impl NewWithNullPtr for wire_cst_prepare_receive_response {
+impl NewWithNullPtr for wire_cst_prepare_ln_url_pay_request {
     fn new_with_null_ptr() -> Self {
         Self {
-            payer_amount_sat: Default::default(),
-            fees_sat: Default::default(),
+            lnurl: core::ptr::null_mut(),
+            amount_sat: Default::default(),
+            comment: core::ptr::null_mut(),
         }
     }
 }
-impl Default for wire_cst_prepare_receive_response {
+impl Default for wire_cst_prepare_ln_url_pay_request {
     fn default() -> Self {
         Self::new_with_null_ptr()
     }
 }
-impl NewWithNullPtr for wire_cst_prepare_send_request {
+impl NewWithNullPtr for wire_cst_prepare_ln_url_pay_response {
     fn new_with_null_ptr() -> Self {
         Self {
-            invoice: core::ptr::null_mut(),
+            prepare_send_response: Default::default(),
+            success_action: core::ptr::null_mut(),
         }
     }
 }
-impl Default for wire_cst_prepare_send_request {
+impl Default for wire_cst_prepare_ln_url_pay_response {
     fn default() -> Self {
         Self::new_with_null_ptr()
     }
 }
-impl NewWithNullPtr for wire_cst_prepare_send_response {
+impl NewWithNullPtr for wire_cst_prepare_pay_onchain_request {
     fn new_with_null_ptr() -> Self {
         Self {
-            invoice: core::ptr::null_mut(),
-            fees_sat: Default::default(),
+            receiver_amount_sat: Default::default(),
+            fee_rate: core::ptr::null_mut(),
         }
     }
 }
-impl Default for wire_cst_prepare_send_response {
+impl Default for wire_cst_prepare_pay_onchain_request {
     fn default() -> Self {
         Self::new_with_null_ptr()
     }
 }
-impl NewWithNullPtr for wire_cst_receive_payment_response {
+impl NewWithNullPtr for wire_cst_prepare_pay_onchain_response {
     fn new_with_null_ptr() -> Self {
         Self {
-            id: core::ptr::null_mut(),
-            invoice: core::ptr::null_mut(),
+            receiver_amount_sat: Default::default(),
+            fees_sat: Default::default(),
+            fee_rate_sat_per_vbyte: core::ptr::null_mut(),
         }
     }
 }
-impl Default for wire_cst_receive_payment_response {
+impl Default for wire_cst_prepare_pay_onchain_response {
     fn default() -> Self {
         Self::new_with_null_ptr()
     }
 }
-impl NewWithNullPtr for wire_cst_restore_request {
+impl NewWithNullPtr for wire_cst_prepare_receive_onchain_request {
     fn new_with_null_ptr() -> Self {
         Self {
-            backup_path: core::ptr::null_mut(),
+            payer_amount_sat: Default::default(),
+            fee_rate: core::ptr::null_mut(),
         }
     }
 }
-impl Default for wire_cst_restore_request {
+impl Default for wire_cst_prepare_receive_onchain_request {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+impl NewWithNullPtr for wire_cst_prepare_receive_onchain_response {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            payer_amount_sat: Default::default(),
+            fees_sat: Default::default(),
+            fee_rate_sat_per_vbyte: core::ptr::null_mut(),
+        }
+    }
+}
+impl Default for wire_cst_prepare_receive_onchain_response {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+impl NewWithNullPtr for wire_cst_prepare_receive_request {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            payer_amount_sat: core::ptr::null_mut(),
+            receiver_amount_sat: core::ptr::null_mut(),
+            description: core::ptr::null_mut(),
+            description_hash: core::ptr::null_mut(),
+            invoice_expiry_secs: core::ptr::null_mut(),
+            metadata: core::ptr::null_mut(),
+            max_fee_sat: core::ptr::null_mut(),
+            max_fee_percent: core::ptr::null_mut(),
+            fee_rate: core::ptr::null_mut(),
+        }
+    }
+}
+impl Default for wire_cst_prepare_receive_request {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+impl NewWithNullPtr for wire_cst_prepare_receive_response {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            payer_amount_sat: Default::default(),
+            receiver_amount_sat: Default::default(),
+            fees_sat: Default::default(),
+            description: core::ptr::null_mut(),
+            description_hash: core::ptr::null_mut(),
+            invoice_expiry_secs: core::ptr::null_mut(),
+            metadata: core::ptr::null_mut(),
+            fee_breakdown: Default::default(),
+            pair_hash: core::ptr::null_mut(),
+            expires_at: Default::default(),
+            fee_rate_sat_per_vbyte: core::ptr::null_mut(),
+        }
+    }
+}
+impl Default for wire_cst_prepare_receive_response {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+impl NewWithNullPtr for wire_cst_prepare_refund_request {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            swap_id: core::ptr::null_mut(),
+            refund_address: core::ptr::null_mut(),
+            fee_rate: Default::default(),
+        }
+    }
+}
+impl Default for wire_cst_prepare_refund_request {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+impl NewWithNullPtr for wire_cst_prepare_refund_response {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            tx_vsize: Default::default(),
+            tx_fee_sat: Default::default(),
+            refund_amount_sat: Default::default(),
+            fee_rate_sat_per_vbyte: Default::default(),
+        }
+    }
+}
+impl Default for wire_cst_prepare_refund_response {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+impl NewWithNullPtr for wire_cst_prepare_send_onchain_request {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            destination: core::ptr::null_mut(),
+            amount_sat: core::ptr::null_mut(),
+            send_all: Default::default(),
+            asset_id: core::ptr::null_mut(),
+            fee_rate: core::ptr::null_mut(),
+        }
+    }
+}
+impl Default for wire_cst_prepare_send_onchain_request {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+impl NewWithNullPtr for wire_cst_prepare_send_onchain_response {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            address: core::ptr::null_mut(),
+            receiver_amount_sat: Default::default(),
+            fees_sat: Default::default(),
+            send_all: Default::default(),
+            asset_id: core::ptr::null_mut(),
+            fee_rate_sat_per_vbyte: core::ptr::null_mut(),
+        }
+    }
+}
+impl Default for wire_cst_prepare_send_onchain_response {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+impl NewWithNullPtr for wire_cst_prepare_send_request {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            invoice: core::ptr::null_mut(),
+            metadata: core::ptr::null_mut(),
+            max_fee_sat: core::ptr::null_mut(),
+            max_fee_percent: core::ptr::null_mut(),
+            fee_rate: core::ptr::null_mut(),
+        }
+    }
+}
+impl Default for wire_cst_prepare_send_request {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+impl NewWithNullPtr for wire_cst_prepare_send_response {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            invoice: core::ptr::null_mut(),
+            fees_sat: Default::default(),
+            metadata: core::ptr::null_mut(),
+            fee_breakdown: Default::default(),
+            max_fee_sat: core::ptr::null_mut(),
+            max_fee_percent: core::ptr::null_mut(),
+            pair_hash: core::ptr::null_mut(),
+            expires_at: Default::default(),
+            fee_rate_sat_per_vbyte: core::ptr::null_mut(),
+        }
+    }
+}
+impl Default for wire_cst_prepare_send_response {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+impl NewWithNullPtr for wire_cst_receive_liquid_request {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            amount_sat: core::ptr::null_mut(),
+            label: core::ptr::null_mut(),
+        }
+    }
+}
+impl Default for wire_cst_receive_liquid_request {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+impl NewWithNullPtr for wire_cst_receive_liquid_response {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            address: core::ptr::null_mut(),
+            bip21: core::ptr::null_mut(),
+        }
+    }
+}
+impl Default for wire_cst_receive_liquid_response {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+impl NewWithNullPtr for wire_cst_receive_onchain_response {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            id: core::ptr::null_mut(),
+            address: core::ptr::null_mut(),
+            bip21: core::ptr::null_mut(),
+        }
+    }
+}
+impl Default for wire_cst_receive_onchain_response {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+impl NewWithNullPtr for wire_cst_receive_payment_response {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            id: core::ptr::null_mut(),
+            invoice: core::ptr::null_mut(),
+        }
+    }
+}
+impl Default for wire_cst_receive_payment_response {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+impl NewWithNullPtr for wire_cst_recommended_fees {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            fastest_fee: Default::default(),
+            half_hour_fee: Default::default(),
+            hour_fee: Default::default(),
+            economy_fee: Default::default(),
+            minimum_fee: Default::default(),
+        }
+    }
+}
+impl Default for wire_cst_recommended_fees {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+impl NewWithNullPtr for wire_cst_recommended_fees_response {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            liquid: Default::default(),
+            bitcoin: Default::default(),
+        }
+    }
+}
+impl Default for wire_cst_recommended_fees_response {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+impl NewWithNullPtr for wire_cst_refund_request {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            swap_id: core::ptr::null_mut(),
+            refund_address: core::ptr::null_mut(),
+            fee_rate_sat_per_vbyte: Default::default(),
+        }
+    }
+}
+impl Default for wire_cst_refund_request {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+impl NewWithNullPtr for wire_cst_refund_response {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            refund_tx_id: core::ptr::null_mut(),
+        }
+    }
+}
+impl Default for wire_cst_refund_response {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+impl NewWithNullPtr for wire_cst_refundable_swap {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            swap_id: core::ptr::null_mut(),
+            swap_address: core::ptr::null_mut(),
+            timestamp: Default::default(),
+            amount_sat: Default::default(),
+            payment_type: Default::default(),
+        }
+    }
+}
+impl Default for wire_cst_refundable_swap {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+impl NewWithNullPtr for wire_cst_restore_request {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            backup_path: core::ptr::null_mut(),
+        }
+    }
+}
+impl Default for wire_cst_restore_request {
     fn default() -> Self {
         Self::new_with_null_ptr()
     }
@@ -778,6 +2266,45 @@ impl Default for wire_cst_send_payment_response {
         Self::new_with_null_ptr()
     }
 }
+impl NewWithNullPtr for wire_cst_set_payment_metadata_request {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            id: core::ptr::null_mut(),
+            metadata: Default::default(),
+        }
+    }
+}
+impl Default for wire_cst_set_payment_metadata_request {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+impl NewWithNullPtr for wire_cst_success_action {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            tag: -1,
+            kind: SuccessActionKind { nil__: () },
+        }
+    }
+}
+impl Default for wire_cst_success_action {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
+impl NewWithNullPtr for wire_cst_success_action_processed {
+    fn new_with_null_ptr() -> Self {
+        Self {
+            tag: -1,
+            kind: SuccessActionProcessedKind { nil__: () },
+        }
+    }
+}
+impl Default for wire_cst_success_action_processed {
+    fn default() -> Self {
+        Self::new_with_null_ptr()
+    }
+}
 
 #[no_mangle]
 pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_add_event_listener(
@@ -811,6 +2338,31 @@ pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_em
     wire__crate__bindings__BindingLiquidSdk_empty_wallet_cache_impl(that)
 }
 
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_export_payments(
+    port_: i64,
+    that: usize,
+    req: *mut wire_cst_export_payments_request,
+) {
+    wire__crate__bindings__BindingLiquidSdk_export_payments_impl(port_, that, req)
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fetch_lightning_limits(
+    port_: i64,
+    that: usize,
+) {
+    wire__crate__bindings__BindingLiquidSdk_fetch_lightning_limits_impl(port_, that)
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fetch_recommended_fees(
+    port_: i64,
+    that: usize,
+) {
+    wire__crate__bindings__BindingLiquidSdk_fetch_recommended_fees_impl(port_, that)
+}
+
 #[no_mangle]
 pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_info(
     port_: i64,
@@ -819,12 +2371,100 @@ pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_ge
     wire__crate__bindings__BindingLiquidSdk_get_info_impl(port_, that)
 }
 
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_payment(
+    port_: i64,
+    that: usize,
+    req: *mut wire_cst_get_payment_request,
+) {
+    wire__crate__bindings__BindingLiquidSdk_get_payment_impl(port_, that, req)
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_electrum_servers(
+    port_: i64,
+    that: usize,
+) {
+    wire__crate__bindings__BindingLiquidSdk_list_electrum_servers_impl(port_, that)
+}
+
 #[no_mangle]
 pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_payments(
     port_: i64,
     that: usize,
+    req: *mut wire_cst_list_payments_request,
+) {
+    wire__crate__bindings__BindingLiquidSdk_list_payments_impl(port_, that, req)
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_refundables(
+    port_: i64,
+    that: usize,
 ) {
-    wire__crate__bindings__BindingLiquidSdk_list_payments_impl(port_, that)
+    wire__crate__bindings__BindingLiquidSdk_list_refundables_impl(port_, that)
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_lnurl_pay(
+    port_: i64,
+    that: usize,
+    req: *mut wire_cst_prepare_ln_url_pay_response,
+) {
+    wire__crate__bindings__BindingLiquidSdk_lnurl_pay_impl(port_, that, req)
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_lnurl_withdraw(
+    port_: i64,
+    that: usize,
+    req: *mut wire_cst_ln_url_withdraw_request,
+) {
+    wire__crate__bindings__BindingLiquidSdk_lnurl_withdraw_impl(port_, that, req)
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_parse(
+    that: usize,
+    input: *mut wire_cst_list_prim_u_8_strict,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    wire__crate__bindings__BindingLiquidSdk_parse_impl(that, input)
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_pay_onchain(
+    port_: i64,
+    that: usize,
+    req: *mut wire_cst_pay_onchain_request,
+) {
+    wire__crate__bindings__BindingLiquidSdk_pay_onchain_impl(port_, that, req)
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_lnurl_pay(
+    port_: i64,
+    that: usize,
+    req: *mut wire_cst_prepare_ln_url_pay_request,
+) {
+    wire__crate__bindings__BindingLiquidSdk_prepare_lnurl_pay_impl(port_, that, req)
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_pay_onchain(
+    port_: i64,
+    that: usize,
+    req: *mut wire_cst_prepare_pay_onchain_request,
+) {
+    wire__crate__bindings__BindingLiquidSdk_prepare_pay_onchain_impl(port_, that, req)
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_receive_onchain(
+    port_: i64,
+    that: usize,
+    req: *mut wire_cst_prepare_receive_onchain_request,
+) {
+    wire__crate__bindings__BindingLiquidSdk_prepare_receive_onchain_impl(port_, that, req)
 }
 
 #[no_mangle]
@@ -836,6 +2476,24 @@ pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_pr
     wire__crate__bindings__BindingLiquidSdk_prepare_receive_payment_impl(port_, that, req)
 }
 
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_refund(
+    port_: i64,
+    that: usize,
+    req: *mut wire_cst_prepare_refund_request,
+) {
+    wire__crate__bindings__BindingLiquidSdk_prepare_refund_impl(port_, that, req)
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_send_onchain(
+    port_: i64,
+    that: usize,
+    req: *mut wire_cst_prepare_send_onchain_request,
+) {
+    wire__crate__bindings__BindingLiquidSdk_prepare_send_onchain_impl(port_, that, req)
+}
+
 #[no_mangle]
 pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_send_payment(
     port_: i64,
@@ -845,6 +2503,24 @@ pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_pr
     wire__crate__bindings__BindingLiquidSdk_prepare_send_payment_impl(port_, that, req)
 }
 
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_receive_liquid(
+    port_: i64,
+    that: usize,
+    req: *mut wire_cst_receive_liquid_request,
+) {
+    wire__crate__bindings__BindingLiquidSdk_receive_liquid_impl(port_, that, req)
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_receive_onchain(
+    port_: i64,
+    that: usize,
+    req: *mut wire_cst_prepare_receive_onchain_response,
+) {
+    wire__crate__bindings__BindingLiquidSdk_receive_onchain_impl(port_, that, req)
+}
+
 #[no_mangle]
 pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_receive_payment(
     port_: i64,
@@ -854,6 +2530,15 @@ pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_re
     wire__crate__bindings__BindingLiquidSdk_receive_payment_impl(port_, that, req)
 }
 
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_refund(
+    port_: i64,
+    that: usize,
+    req: *mut wire_cst_refund_request,
+) {
+    wire__crate__bindings__BindingLiquidSdk_refund_impl(port_, that, req)
+}
+
 #[no_mangle]
 pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_restore(
     that: usize,
@@ -862,6 +2547,15 @@ pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_re
     wire__crate__bindings__BindingLiquidSdk_restore_impl(that, req)
 }
 
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_send_onchain(
+    port_: i64,
+    that: usize,
+    req: *mut wire_cst_prepare_send_onchain_response,
+) {
+    wire__crate__bindings__BindingLiquidSdk_send_onchain_impl(port_, that, req)
+}
+
 #[no_mangle]
 pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_send_payment(
     port_: i64,
@@ -871,6 +2565,15 @@ pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_se
     wire__crate__bindings__BindingLiquidSdk_send_payment_impl(port_, that, req)
 }
 
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_set_payment_metadata(
+    port_: i64,
+    that: usize,
+    req: *mut wire_cst_set_payment_metadata_request,
+) {
+    wire__crate__bindings__BindingLiquidSdk_set_payment_metadata_impl(port_, that, req)
+}
+
 #[no_mangle]
 pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_sync(
     port_: i64,
@@ -905,72 +2608,213 @@ pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__connect(
 }
 
 #[no_mangle]
-pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__default_config(
-    network: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
-    wire__crate__bindings__default_config_impl(network)
+pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__default_config(
+    network: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    wire__crate__bindings__default_config_impl(network)
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__parse_invoice(
+    input: *mut wire_cst_list_prim_u_8_strict,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    wire__crate__bindings__parse_invoice_impl(input)
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+    ptr: *const std::ffi::c_void,
+) {
+    unsafe {
+        StdArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>::increment_strong_count(ptr as _);
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+    ptr: *const std::ffi::c_void,
+) {
+    unsafe {
+        StdArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>::decrement_strong_count(ptr as _);
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_asset_metadata(
+) -> *mut wire_cst_asset_metadata {
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(
+        wire_cst_asset_metadata::new_with_null_ptr(),
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_backup_request(
+) -> *mut wire_cst_backup_request {
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(
+        wire_cst_backup_request::new_with_null_ptr(),
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_binding_event_listener(
+) -> *mut wire_cst_binding_event_listener {
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(
+        wire_cst_binding_event_listener::new_with_null_ptr(),
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_bitcoin_address_data(
+) -> *mut wire_cst_bitcoin_address_data {
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(
+        wire_cst_bitcoin_address_data::new_with_null_ptr(),
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_bool(value: bool) -> *mut bool {
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(value)
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_connect_request(
+) -> *mut wire_cst_connect_request {
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(
+        wire_cst_connect_request::new_with_null_ptr(),
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_export_payments_request(
+) -> *mut wire_cst_export_payments_request {
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(
+        wire_cst_export_payments_request::new_with_null_ptr(),
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_f_64(value: f64) -> *mut f64 {
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(value)
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_fee_breakdown(
+) -> *mut wire_cst_fee_breakdown {
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(wire_cst_fee_breakdown::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_get_payment_request(
+) -> *mut wire_cst_get_payment_request {
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(
+        wire_cst_get_payment_request::new_with_null_ptr(),
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_liquid_address_data(
+) -> *mut wire_cst_liquid_address_data {
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(
+        wire_cst_liquid_address_data::new_with_null_ptr(),
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_liquid_sdk_event(
+) -> *mut wire_cst_liquid_sdk_event {
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(
+        wire_cst_liquid_sdk_event::new_with_null_ptr(),
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_list_payments_request(
+) -> *mut wire_cst_list_payments_request {
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(
+        wire_cst_list_payments_request::new_with_null_ptr(),
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_ln_invoice() -> *mut wire_cst_ln_invoice {
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(wire_cst_ln_invoice::new_with_null_ptr())
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_ln_url_withdraw_request(
+) -> *mut wire_cst_ln_url_withdraw_request {
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(
+        wire_cst_ln_url_withdraw_request::new_with_null_ptr(),
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_onchain_fee_rate(
+) -> *mut wire_cst_onchain_fee_rate {
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(
+        wire_cst_onchain_fee_rate::new_with_null_ptr(),
+    )
 }
 
 #[no_mangle]
-pub extern "C" fn frbgen_breez_liquid_wire__crate__bindings__parse_invoice(
-    input: *mut wire_cst_list_prim_u_8_strict,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
-    wire__crate__bindings__parse_invoice_impl(input)
+pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_pay_onchain_request(
+) -> *mut wire_cst_pay_onchain_request {
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(
+        wire_cst_pay_onchain_request::new_with_null_ptr(),
+    )
 }
 
 #[no_mangle]
-pub extern "C" fn frbgen_breez_liquid_rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
-    ptr: *const std::ffi::c_void,
-) {
-    unsafe {
-        StdArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>::increment_strong_count(ptr as _);
-    }
+pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_payment() -> *mut wire_cst_payment {
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(wire_cst_payment::new_with_null_ptr())
 }
 
 #[no_mangle]
-pub extern "C" fn frbgen_breez_liquid_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
-    ptr: *const std::ffi::c_void,
-) {
-    unsafe {
-        StdArc::<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>::decrement_strong_count(ptr as _);
-    }
+pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_payment_metadata(
+) -> *mut wire_cst_payment_metadata {
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(
+        wire_cst_payment_metadata::new_with_null_ptr(),
+    )
 }
 
 #[no_mangle]
-pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_backup_request(
-) -> *mut wire_cst_backup_request {
+pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_prepare_ln_url_pay_request(
+) -> *mut wire_cst_prepare_ln_url_pay_request {
     flutter_rust_bridge::for_generated::new_leak_box_ptr(
-        wire_cst_backup_request::new_with_null_ptr(),
+        wire_cst_prepare_ln_url_pay_request::new_with_null_ptr(),
     )
 }
 
 #[no_mangle]
-pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_binding_event_listener(
-) -> *mut wire_cst_binding_event_listener {
+pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_prepare_ln_url_pay_response(
+) -> *mut wire_cst_prepare_ln_url_pay_response {
     flutter_rust_bridge::for_generated::new_leak_box_ptr(
-        wire_cst_binding_event_listener::new_with_null_ptr(),
+        wire_cst_prepare_ln_url_pay_response::new_with_null_ptr(),
     )
 }
 
 #[no_mangle]
-pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_connect_request(
-) -> *mut wire_cst_connect_request {
+pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_prepare_pay_onchain_request(
+) -> *mut wire_cst_prepare_pay_onchain_request {
     flutter_rust_bridge::for_generated::new_leak_box_ptr(
-        wire_cst_connect_request::new_with_null_ptr(),
+        wire_cst_prepare_pay_onchain_request::new_with_null_ptr(),
     )
 }
 
 #[no_mangle]
-pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_liquid_sdk_event(
-) -> *mut wire_cst_liquid_sdk_event {
+pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_prepare_receive_onchain_request(
+) -> *mut wire_cst_prepare_receive_onchain_request {
     flutter_rust_bridge::for_generated::new_leak_box_ptr(
-        wire_cst_liquid_sdk_event::new_with_null_ptr(),
+        wire_cst_prepare_receive_onchain_request::new_with_null_ptr(),
     )
 }
 
 #[no_mangle]
-pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_payment() -> *mut wire_cst_payment {
-    flutter_rust_bridge::for_generated::new_leak_box_ptr(wire_cst_payment::new_with_null_ptr())
+pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_prepare_receive_onchain_response(
+) -> *mut wire_cst_prepare_receive_onchain_response {
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(
+        wire_cst_prepare_receive_onchain_response::new_with_null_ptr(),
+    )
 }
 
 #[no_mangle]
@@ -989,6 +2833,30 @@ pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_prepare_receive_respon
     )
 }
 
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_prepare_refund_request(
+) -> *mut wire_cst_prepare_refund_request {
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(
+        wire_cst_prepare_refund_request::new_with_null_ptr(),
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_onchain_request(
+) -> *mut wire_cst_prepare_send_onchain_request {
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(
+        wire_cst_prepare_send_onchain_request::new_with_null_ptr(),
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_onchain_response(
+) -> *mut wire_cst_prepare_send_onchain_response {
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(
+        wire_cst_prepare_send_onchain_response::new_with_null_ptr(),
+    )
+}
+
 #[no_mangle]
 pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_request(
 ) -> *mut wire_cst_prepare_send_request {
@@ -1005,6 +2873,22 @@ pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_response(
     )
 }
 
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_receive_liquid_request(
+) -> *mut wire_cst_receive_liquid_request {
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(
+        wire_cst_receive_liquid_request::new_with_null_ptr(),
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_refund_request(
+) -> *mut wire_cst_refund_request {
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(
+        wire_cst_refund_request::new_with_null_ptr(),
+    )
+}
+
 #[no_mangle]
 pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_restore_request(
 ) -> *mut wire_cst_restore_request {
@@ -1013,11 +2897,96 @@ pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_restore_request(
     )
 }
 
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_set_payment_metadata_request(
+) -> *mut wire_cst_set_payment_metadata_request {
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(
+        wire_cst_set_payment_metadata_request::new_with_null_ptr(),
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_success_action(
+) -> *mut wire_cst_success_action {
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(
+        wire_cst_success_action::new_with_null_ptr(),
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_success_action_processed(
+) -> *mut wire_cst_success_action_processed {
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(
+        wire_cst_success_action_processed::new_with_null_ptr(),
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_u_32(value: u32) -> *mut u32 {
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(value)
+}
+
 #[no_mangle]
 pub extern "C" fn frbgen_breez_liquid_cst_new_box_autoadd_u_64(value: u64) -> *mut u64 {
     flutter_rust_bridge::for_generated::new_leak_box_ptr(value)
 }
 
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_cst_new_list_asset_balance(
+    len: i32,
+) -> *mut wire_cst_list_asset_balance {
+    let wrap = wire_cst_list_asset_balance {
+        ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+            <wire_cst_asset_balance>::new_with_null_ptr(),
+            len,
+        ),
+        len,
+    };
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_cst_new_list_asset_metadata(
+    len: i32,
+) -> *mut wire_cst_list_asset_metadata {
+    let wrap = wire_cst_list_asset_metadata {
+        ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+            <wire_cst_asset_metadata>::new_with_null_ptr(),
+            len,
+        ),
+        len,
+    };
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_cst_new_list_electrum_server(
+    len: i32,
+) -> *mut wire_cst_list_electrum_server {
+    let wrap = wire_cst_list_electrum_server {
+        ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+            <wire_cst_electrum_server>::new_with_null_ptr(),
+            len,
+        ),
+        len,
+    };
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_cst_new_list_electrum_server_status(
+    len: i32,
+) -> *mut wire_cst_list_electrum_server_status {
+    let wrap = wire_cst_list_electrum_server_status {
+        ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+            <wire_cst_electrum_server_status>::new_with_null_ptr(),
+            len,
+        ),
+        len,
+    };
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
+}
+
 #[no_mangle]
 pub extern "C" fn frbgen_breez_liquid_cst_new_list_payment(len: i32) -> *mut wire_cst_list_payment {
     let wrap = wire_cst_list_payment {
@@ -1030,6 +2999,28 @@ pub extern "C" fn frbgen_breez_liquid_cst_new_list_payment(len: i32) -> *mut wir
     flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
 }
 
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_cst_new_list_payment_state(
+    len: i32,
+) -> *mut wire_cst_list_payment_state {
+    let ans = wire_cst_list_payment_state {
+        ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(Default::default(), len),
+        len,
+    };
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(ans)
+}
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_cst_new_list_payment_type(
+    len: i32,
+) -> *mut wire_cst_list_payment_type {
+    let ans = wire_cst_list_payment_type {
+        ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(Default::default(), len),
+        len,
+    };
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(ans)
+}
+
 #[no_mangle]
 pub extern "C" fn frbgen_breez_liquid_cst_new_list_prim_u_8_strict(
     len: i32,
@@ -1041,6 +3032,20 @@ pub extern "C" fn frbgen_breez_liquid_cst_new_list_prim_u_8_strict(
     flutter_rust_bridge::for_generated::new_leak_box_ptr(ans)
 }
 
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_cst_new_list_refundable_swap(
+    len: i32,
+) -> *mut wire_cst_list_refundable_swap {
+    let wrap = wire_cst_list_refundable_swap {
+        ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+            <wire_cst_refundable_swap>::new_with_null_ptr(),
+            len,
+        ),
+        len,
+    };
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
+}
+
 #[no_mangle]
 pub extern "C" fn frbgen_breez_liquid_cst_new_list_route_hint(
     len: i32,
@@ -1054,55 +3059,226 @@ pub extern "C" fn frbgen_breez_liquid_cst_new_list_route_hint(
     };
     flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
 }
-
-#[no_mangle]
-pub extern "C" fn frbgen_breez_liquid_cst_new_list_route_hint_hop(
-    len: i32,
-) -> *mut wire_cst_list_route_hint_hop {
-    let wrap = wire_cst_list_route_hint_hop {
-        ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
-            <wire_cst_route_hint_hop>::new_with_null_ptr(),
-            len,
-        ),
-        len,
-    };
-    flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
+
+#[no_mangle]
+pub extern "C" fn frbgen_breez_liquid_cst_new_list_route_hint_hop(
+    len: i32,
+) -> *mut wire_cst_list_route_hint_hop {
+    let wrap = wire_cst_list_route_hint_hop {
+        ptr: flutter_rust_bridge::for_generated::new_leak_vec_ptr(
+            <wire_cst_route_hint_hop>::new_with_null_ptr(),
+            len,
+        ),
+        len,
+    };
+    flutter_rust_bridge::for_generated::new_leak_box_ptr(wrap)
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_asset_balance {
+    asset_id: *mut wire_cst_list_prim_u_8_strict,
+    balance: u64,
+    asset_metadata: *mut wire_cst_asset_metadata,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_asset_metadata {
+    asset_id: *mut wire_cst_list_prim_u_8_strict,
+    ticker: *mut wire_cst_list_prim_u_8_strict,
+    name: *mut wire_cst_list_prim_u_8_strict,
+    precision: u8,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_backup_request {
+    backup_path: *mut wire_cst_list_prim_u_8_strict,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_binding_event_listener {
+    stream: *mut wire_cst_list_prim_u_8_strict,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_bitcoin_address_data {
+    address: *mut wire_cst_list_prim_u_8_strict,
+    network: i32,
+    amount_sat: *mut u64,
+    label: *mut wire_cst_list_prim_u_8_strict,
+    message: *mut wire_cst_list_prim_u_8_strict,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_config {
+    boltz_url: *mut wire_cst_list_prim_u_8_strict,
+    electrum_servers: *mut wire_cst_list_electrum_server,
+    electrum_url: *mut wire_cst_list_prim_u_8_strict,
+    bitcoin_electrum_url: *mut wire_cst_list_prim_u_8_strict,
+    esplora_url: *mut wire_cst_list_prim_u_8_strict,
+    working_dir: *mut wire_cst_list_prim_u_8_strict,
+    network: i32,
+    payment_timeout_sec: u64,
+    sync_fallback_interval_sec: u64,
+    zero_conf_min_fee_rate: f32,
+    zero_conf_max_amount_sat: *mut u64,
+    asset_metadata: *mut wire_cst_list_asset_metadata,
+    max_fee_sat: *mut u64,
+    max_fee_percent: *mut f64,
+    regtest_policy_asset: *mut wire_cst_list_prim_u_8_strict,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_connect_request {
+    mnemonic: *mut wire_cst_list_prim_u_8_strict,
+    config: wire_cst_config,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_electrum_server {
+    url: *mut wire_cst_list_prim_u_8_strict,
+    tls: bool,
+    validate_domain: bool,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_electrum_server_status {
+    url: *mut wire_cst_list_prim_u_8_strict,
+    in_use: bool,
+    healthy: bool,
+    consecutive_failures: u32,
+    last_error: *mut wire_cst_list_prim_u_8_strict,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_export_payments_request {
+    format: i32,
+    filter: wire_cst_list_payments_request,
+    path: *mut wire_cst_list_prim_u_8_strict,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_export_payments_response {
+    payments_count: u32,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_fee_breakdown {
+    service_fee_percentage: f64,
+    service_fee_sat: u64,
+    swapper_miner_fees_sat: u64,
+    network_fee_sat: u64,
+    total_fees_sat: u64,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_get_info_response {
+    balance_sat: u64,
+    pending_send_sat: u64,
+    pending_receive_sat: u64,
+    pubkey: *mut wire_cst_list_prim_u_8_strict,
+    asset_balances: *mut wire_cst_list_asset_balance,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_get_payment_request {
+    tag: i32,
+    kind: GetPaymentRequestKind,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union GetPaymentRequestKind {
+    TxId: wire_cst_GetPaymentRequest_TxId,
+    SwapId: wire_cst_GetPaymentRequest_SwapId,
+    Bolt11: wire_cst_GetPaymentRequest_Bolt11,
+    PaymentHash: wire_cst_GetPaymentRequest_PaymentHash,
+    nil__: (),
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_GetPaymentRequest_TxId {
+    tx_id: *mut wire_cst_list_prim_u_8_strict,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_GetPaymentRequest_SwapId {
+    swap_id: *mut wire_cst_list_prim_u_8_strict,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_GetPaymentRequest_Bolt11 {
+    bolt11: *mut wire_cst_list_prim_u_8_strict,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_GetPaymentRequest_PaymentHash {
+    payment_hash: *mut wire_cst_list_prim_u_8_strict,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_input_type {
+    tag: i32,
+    kind: InputTypeKind,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union InputTypeKind {
+    Bolt11: wire_cst_InputType_Bolt11,
+    LiquidAddress: wire_cst_InputType_LiquidAddress,
+    BitcoinAddress: wire_cst_InputType_BitcoinAddress,
+    LnUrl: wire_cst_InputType_LnUrl,
+    LightningAddress: wire_cst_InputType_LightningAddress,
+    nil__: (),
 }
-
 #[repr(C)]
 #[derive(Clone, Copy)]
-pub struct wire_cst_backup_request {
-    backup_path: *mut wire_cst_list_prim_u_8_strict,
+pub struct wire_cst_InputType_Bolt11 {
+    invoice: *mut wire_cst_ln_invoice,
 }
 #[repr(C)]
 #[derive(Clone, Copy)]
-pub struct wire_cst_binding_event_listener {
-    stream: *mut wire_cst_list_prim_u_8_strict,
+pub struct wire_cst_InputType_LiquidAddress {
+    address: *mut wire_cst_liquid_address_data,
 }
 #[repr(C)]
 #[derive(Clone, Copy)]
-pub struct wire_cst_config {
-    boltz_url: *mut wire_cst_list_prim_u_8_strict,
-    electrum_url: *mut wire_cst_list_prim_u_8_strict,
-    working_dir: *mut wire_cst_list_prim_u_8_strict,
-    network: i32,
-    payment_timeout_sec: u64,
-    zero_conf_min_fee_rate: f32,
-    zero_conf_max_amount_sat: *mut u64,
+pub struct wire_cst_InputType_BitcoinAddress {
+    address: *mut wire_cst_bitcoin_address_data,
 }
 #[repr(C)]
 #[derive(Clone, Copy)]
-pub struct wire_cst_connect_request {
-    mnemonic: *mut wire_cst_list_prim_u_8_strict,
-    config: wire_cst_config,
+pub struct wire_cst_InputType_LnUrl {
+    url: *mut wire_cst_list_prim_u_8_strict,
 }
 #[repr(C)]
 #[derive(Clone, Copy)]
-pub struct wire_cst_get_info_response {
-    balance_sat: u64,
-    pending_send_sat: u64,
-    pending_receive_sat: u64,
-    pubkey: *mut wire_cst_list_prim_u_8_strict,
+pub struct wire_cst_InputType_LightningAddress {
+    address: *mut wire_cst_list_prim_u_8_strict,
+    url: *mut wire_cst_list_prim_u_8_strict,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_lightning_limits {
+    min_sat: u64,
+    max_sat: u64,
+    service_fee_percentage: f64,
+    miner_fees_sat: u64,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_lightning_payment_limits_response {
+    send: wire_cst_lightning_limits,
+    receive: wire_cst_lightning_limits,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_liquid_address_data {
+    address: *mut wire_cst_list_prim_u_8_strict,
+    network: i32,
+    amount_sat: *mut u64,
+    asset_id: *mut wire_cst_list_prim_u_8_strict,
+    label: *mut wire_cst_list_prim_u_8_strict,
+    message: *mut wire_cst_list_prim_u_8_strict,
 }
 #[repr(C)]
 #[derive(Clone, Copy)]
@@ -1133,6 +3309,7 @@ pub union LiquidSdkEventKind {
     PaymentFailed: wire_cst_LiquidSdkEvent_PaymentFailed,
     PaymentPending: wire_cst_LiquidSdkEvent_PaymentPending,
     PaymentRefunded: wire_cst_LiquidSdkEvent_PaymentRefunded,
+    PaymentRefundable: wire_cst_LiquidSdkEvent_PaymentRefundable,
     PaymentRefundPending: wire_cst_LiquidSdkEvent_PaymentRefundPending,
     PaymentSucceeded: wire_cst_LiquidSdkEvent_PaymentSucceeded,
     PaymentWaitingConfirmation: wire_cst_LiquidSdkEvent_PaymentWaitingConfirmation,
@@ -1155,6 +3332,11 @@ pub struct wire_cst_LiquidSdkEvent_PaymentRefunded {
 }
 #[repr(C)]
 #[derive(Clone, Copy)]
+pub struct wire_cst_LiquidSdkEvent_PaymentRefundable {
+    details: *mut wire_cst_payment,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
 pub struct wire_cst_LiquidSdkEvent_PaymentRefundPending {
     details: *mut wire_cst_payment,
 }
@@ -1170,18 +3352,72 @@ pub struct wire_cst_LiquidSdkEvent_PaymentWaitingConfirmation {
 }
 #[repr(C)]
 #[derive(Clone, Copy)]
+pub struct wire_cst_list_asset_balance {
+    ptr: *mut wire_cst_asset_balance,
+    len: i32,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_list_asset_metadata {
+    ptr: *mut wire_cst_asset_metadata,
+    len: i32,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_list_electrum_server {
+    ptr: *mut wire_cst_electrum_server,
+    len: i32,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_list_electrum_server_status {
+    ptr: *mut wire_cst_electrum_server_status,
+    len: i32,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
 pub struct wire_cst_list_payment {
     ptr: *mut wire_cst_payment,
     len: i32,
 }
 #[repr(C)]
 #[derive(Clone, Copy)]
+pub struct wire_cst_list_payment_state {
+    ptr: *mut i32,
+    len: i32,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_list_payment_type {
+    ptr: *mut i32,
+    len: i32,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_list_payments_request {
+    payment_types: *mut wire_cst_list_payment_type,
+    states: *mut wire_cst_list_payment_state,
+    from_timestamp: *mut u32,
+    to_timestamp: *mut u32,
+    offset: *mut u32,
+    limit: *mut u32,
+    sort_descending: *mut bool,
+    metadata_search: *mut wire_cst_list_prim_u_8_strict,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
 pub struct wire_cst_list_prim_u_8_strict {
     ptr: *mut u8,
     len: i32,
 }
 #[repr(C)]
 #[derive(Clone, Copy)]
+pub struct wire_cst_list_refundable_swap {
+    ptr: *mut wire_cst_refundable_swap,
+    len: i32,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
 pub struct wire_cst_list_route_hint {
     ptr: *mut wire_cst_route_hint,
     len: i32,
@@ -1210,23 +3446,83 @@ pub struct wire_cst_ln_invoice {
 }
 #[repr(C)]
 #[derive(Clone, Copy)]
+pub struct wire_cst_ln_url_pay_result {
+    payment: wire_cst_payment,
+    success_action: *mut wire_cst_success_action_processed,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_ln_url_withdraw_request {
+    lnurl: *mut wire_cst_list_prim_u_8_strict,
+    amount_sat: u64,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_ln_url_withdraw_result {
+    receive_payment_response: wire_cst_receive_payment_response,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
 pub struct wire_cst_log_entry {
     line: *mut wire_cst_list_prim_u_8_strict,
     level: *mut wire_cst_list_prim_u_8_strict,
 }
 #[repr(C)]
 #[derive(Clone, Copy)]
+pub struct wire_cst_onchain_fee_rate {
+    tag: i32,
+    kind: OnchainFeeRateKind,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union OnchainFeeRateKind {
+    SatPerVbyte: wire_cst_OnchainFeeRate_SatPerVbyte,
+    Priority: wire_cst_OnchainFeeRate_Priority,
+    nil__: (),
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_OnchainFeeRate_SatPerVbyte {
+    sat_per_vbyte: f64,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_OnchainFeeRate_Priority {
+    priority: i32,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_pay_onchain_request {
+    address: *mut wire_cst_list_prim_u_8_strict,
+    prepare_res: wire_cst_prepare_pay_onchain_response,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
 pub struct wire_cst_payment {
     tx_id: *mut wire_cst_list_prim_u_8_strict,
     swap_id: *mut wire_cst_list_prim_u_8_strict,
+    swap_type: i32,
     timestamp: u32,
     amount_sat: u64,
     fees_sat: u64,
+    network_fees_sat: *mut u64,
+    fee_breakdown: *mut wire_cst_fee_breakdown,
+    fee_rate_sat_per_vbyte: *mut f64,
     preimage: *mut wire_cst_list_prim_u_8_strict,
+    bolt11: *mut wire_cst_list_prim_u_8_strict,
+    payment_hash: *mut wire_cst_list_prim_u_8_strict,
+    payee_pubkey: *mut wire_cst_list_prim_u_8_strict,
+    lockup_tx_id: *mut wire_cst_list_prim_u_8_strict,
+    claim_tx_id: *mut wire_cst_list_prim_u_8_strict,
     refund_tx_id: *mut wire_cst_list_prim_u_8_strict,
     refund_tx_amount_sat: *mut u64,
     payment_type: i32,
     status: i32,
+    label: *mut wire_cst_list_prim_u_8_strict,
+    description: *mut wire_cst_list_prim_u_8_strict,
+    metadata: *mut wire_cst_payment_metadata,
+    asset_id: *mut wire_cst_list_prim_u_8_strict,
+    asset_amount: *mut u64,
 }
 #[repr(C)]
 #[derive(Clone, Copy)]
@@ -1237,7 +3533,13 @@ pub struct wire_cst_payment_error {
 #[repr(C)]
 #[derive(Clone, Copy)]
 pub union PaymentErrorKind {
+    UnrecognizedInput: wire_cst_PaymentError_UnrecognizedInput,
+    NetworkMismatch: wire_cst_PaymentError_NetworkMismatch,
+    LnUrlError: wire_cst_PaymentError_LnUrlError,
+    LnUrlServiceUnreachable: wire_cst_PaymentError_LnUrlServiceUnreachable,
+    LnUrlServiceError: wire_cst_PaymentError_LnUrlServiceError,
     Generic: wire_cst_PaymentError_Generic,
+    FeeLimitExceeded: wire_cst_PaymentError_FeeLimitExceeded,
     InvalidInvoice: wire_cst_PaymentError_InvalidInvoice,
     LwkError: wire_cst_PaymentError_LwkError,
     ReceiveError: wire_cst_PaymentError_ReceiveError,
@@ -1248,11 +3550,42 @@ pub union PaymentErrorKind {
 }
 #[repr(C)]
 #[derive(Clone, Copy)]
+pub struct wire_cst_PaymentError_UnrecognizedInput {
+    err: *mut wire_cst_list_prim_u_8_strict,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_PaymentError_NetworkMismatch {
+    err: *mut wire_cst_list_prim_u_8_strict,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_PaymentError_LnUrlError {
+    err: *mut wire_cst_list_prim_u_8_strict,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_PaymentError_LnUrlServiceUnreachable {
+    err: *mut wire_cst_list_prim_u_8_strict,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_PaymentError_LnUrlServiceError {
+    err: *mut wire_cst_list_prim_u_8_strict,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
 pub struct wire_cst_PaymentError_Generic {
     err: *mut wire_cst_list_prim_u_8_strict,
 }
 #[repr(C)]
 #[derive(Clone, Copy)]
+pub struct wire_cst_PaymentError_FeeLimitExceeded {
+    fee_sat: u64,
+    limit_sat: u64,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
 pub struct wire_cst_PaymentError_InvalidInvoice {
     err: *mut wire_cst_list_prim_u_8_strict,
 }
@@ -1284,25 +3617,152 @@ pub struct wire_cst_PaymentError_SignerError {
 }
 #[repr(C)]
 #[derive(Clone, Copy)]
-pub struct wire_cst_prepare_receive_request {
+pub struct wire_cst_payment_metadata {
+    label: *mut wire_cst_list_prim_u_8_strict,
+    note: *mut wire_cst_list_prim_u_8_strict,
+    external_id: *mut wire_cst_list_prim_u_8_strict,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_prepare_ln_url_pay_request {
+    lnurl: *mut wire_cst_list_prim_u_8_strict,
+    amount_sat: u64,
+    comment: *mut wire_cst_list_prim_u_8_strict,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_prepare_ln_url_pay_response {
+    prepare_send_response: wire_cst_prepare_send_response,
+    success_action: *mut wire_cst_success_action,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_prepare_pay_onchain_request {
+    receiver_amount_sat: u64,
+    fee_rate: *mut wire_cst_onchain_fee_rate,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_prepare_pay_onchain_response {
+    receiver_amount_sat: u64,
+    fees_sat: u64,
+    fee_rate_sat_per_vbyte: *mut f64,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_prepare_receive_onchain_request {
+    payer_amount_sat: u64,
+    fee_rate: *mut wire_cst_onchain_fee_rate,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_prepare_receive_onchain_response {
     payer_amount_sat: u64,
+    fees_sat: u64,
+    fee_rate_sat_per_vbyte: *mut f64,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_prepare_receive_request {
+    payer_amount_sat: *mut u64,
+    receiver_amount_sat: *mut u64,
+    description: *mut wire_cst_list_prim_u_8_strict,
+    description_hash: *mut wire_cst_list_prim_u_8_strict,
+    invoice_expiry_secs: *mut u64,
+    metadata: *mut wire_cst_payment_metadata,
+    max_fee_sat: *mut u64,
+    max_fee_percent: *mut f64,
+    fee_rate: *mut wire_cst_onchain_fee_rate,
 }
 #[repr(C)]
 #[derive(Clone, Copy)]
 pub struct wire_cst_prepare_receive_response {
     payer_amount_sat: u64,
+    receiver_amount_sat: u64,
+    fees_sat: u64,
+    description: *mut wire_cst_list_prim_u_8_strict,
+    description_hash: *mut wire_cst_list_prim_u_8_strict,
+    invoice_expiry_secs: *mut u64,
+    metadata: *mut wire_cst_payment_metadata,
+    fee_breakdown: wire_cst_fee_breakdown,
+    pair_hash: *mut wire_cst_list_prim_u_8_strict,
+    expires_at: u32,
+    fee_rate_sat_per_vbyte: *mut f64,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_prepare_refund_request {
+    swap_id: *mut wire_cst_list_prim_u_8_strict,
+    refund_address: *mut wire_cst_list_prim_u_8_strict,
+    fee_rate: wire_cst_onchain_fee_rate,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_prepare_refund_response {
+    tx_vsize: u32,
+    tx_fee_sat: u64,
+    refund_amount_sat: u64,
+    fee_rate_sat_per_vbyte: f64,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_prepare_send_onchain_request {
+    destination: *mut wire_cst_list_prim_u_8_strict,
+    amount_sat: *mut u64,
+    send_all: bool,
+    asset_id: *mut wire_cst_list_prim_u_8_strict,
+    fee_rate: *mut wire_cst_onchain_fee_rate,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_prepare_send_onchain_response {
+    address: *mut wire_cst_list_prim_u_8_strict,
+    receiver_amount_sat: u64,
     fees_sat: u64,
+    send_all: bool,
+    asset_id: *mut wire_cst_list_prim_u_8_strict,
+    fee_rate_sat_per_vbyte: *mut f64,
 }
 #[repr(C)]
 #[derive(Clone, Copy)]
 pub struct wire_cst_prepare_send_request {
     invoice: *mut wire_cst_list_prim_u_8_strict,
+    metadata: *mut wire_cst_payment_metadata,
+    max_fee_sat: *mut u64,
+    max_fee_percent: *mut f64,
+    fee_rate: *mut wire_cst_onchain_fee_rate,
 }
 #[repr(C)]
 #[derive(Clone, Copy)]
 pub struct wire_cst_prepare_send_response {
     invoice: *mut wire_cst_list_prim_u_8_strict,
     fees_sat: u64,
+    metadata: *mut wire_cst_payment_metadata,
+    fee_breakdown: wire_cst_fee_breakdown,
+    max_fee_sat: *mut u64,
+    max_fee_percent: *mut f64,
+    pair_hash: *mut wire_cst_list_prim_u_8_strict,
+    expires_at: u32,
+    fee_rate_sat_per_vbyte: *mut f64,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_receive_liquid_request {
+    amount_sat: *mut u64,
+    label: *mut wire_cst_list_prim_u_8_strict,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_receive_liquid_response {
+    address: *mut wire_cst_list_prim_u_8_strict,
+    bip21: *mut wire_cst_list_prim_u_8_strict,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_receive_onchain_response {
+    id: *mut wire_cst_list_prim_u_8_strict,
+    address: *mut wire_cst_list_prim_u_8_strict,
+    bip21: *mut wire_cst_list_prim_u_8_strict,
 }
 #[repr(C)]
 #[derive(Clone, Copy)]
@@ -1312,6 +3772,42 @@ pub struct wire_cst_receive_payment_response {
 }
 #[repr(C)]
 #[derive(Clone, Copy)]
+pub struct wire_cst_recommended_fees {
+    fastest_fee: f64,
+    half_hour_fee: f64,
+    hour_fee: f64,
+    economy_fee: f64,
+    minimum_fee: f64,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_recommended_fees_response {
+    liquid: wire_cst_recommended_fees,
+    bitcoin: wire_cst_recommended_fees,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_refund_request {
+    swap_id: *mut wire_cst_list_prim_u_8_strict,
+    refund_address: *mut wire_cst_list_prim_u_8_strict,
+    fee_rate_sat_per_vbyte: f64,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_refund_response {
+    refund_tx_id: *mut wire_cst_list_prim_u_8_strict,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_refundable_swap {
+    swap_id: *mut wire_cst_list_prim_u_8_strict,
+    swap_address: *mut wire_cst_list_prim_u_8_strict,
+    timestamp: u32,
+    amount_sat: u64,
+    payment_type: i32,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
 pub struct wire_cst_restore_request {
     backup_path: *mut wire_cst_list_prim_u_8_strict,
 }
@@ -1336,3 +3832,72 @@ pub struct wire_cst_route_hint_hop {
 pub struct wire_cst_send_payment_response {
     payment: wire_cst_payment,
 }
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_set_payment_metadata_request {
+    id: *mut wire_cst_list_prim_u_8_strict,
+    metadata: wire_cst_payment_metadata,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_success_action {
+    tag: i32,
+    kind: SuccessActionKind,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union SuccessActionKind {
+    Message: wire_cst_SuccessAction_Message,
+    Url: wire_cst_SuccessAction_Url,
+    Aes: wire_cst_SuccessAction_Aes,
+    nil__: (),
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_SuccessAction_Message {
+    message: *mut wire_cst_list_prim_u_8_strict,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_SuccessAction_Url {
+    description: *mut wire_cst_list_prim_u_8_strict,
+    url: *mut wire_cst_list_prim_u_8_strict,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_SuccessAction_Aes {
+    description: *mut wire_cst_list_prim_u_8_strict,
+    ciphertext: *mut wire_cst_list_prim_u_8_strict,
+    iv: *mut wire_cst_list_prim_u_8_strict,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_success_action_processed {
+    tag: i32,
+    kind: SuccessActionProcessedKind,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union SuccessActionProcessedKind {
+    Message: wire_cst_SuccessActionProcessed_Message,
+    Url: wire_cst_SuccessActionProcessed_Url,
+    Aes: wire_cst_SuccessActionProcessed_Aes,
+    nil__: (),
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_SuccessActionProcessed_Message {
+    message: *mut wire_cst_list_prim_u_8_strict,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_SuccessActionProcessed_Url {
+    description: *mut wire_cst_list_prim_u_8_strict,
+    url: *mut wire_cst_list_prim_u_8_strict,
+}
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct wire_cst_SuccessActionProcessed_Aes {
+    description: *mut wire_cst_list_prim_u_8_strict,
+    plaintext: *mut wire_cst_list_prim_u_8_strict,
+}
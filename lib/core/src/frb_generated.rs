@@ -35,7 +35,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueNom,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.0.0-dev.38";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 308302012;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -837504212;
 
 // Section: executor
 
@@ -203,20 +203,22 @@ fn wire__crate__bindings__BindingLiquidSdk_empty_wallet_cache_impl(
         },
     )
 }
-fn wire__crate__bindings__BindingLiquidSdk_get_info_impl(
+fn wire__crate__bindings__BindingLiquidSdk_export_payments_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     that: impl CstDecode<
         RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
     >,
+    req: impl CstDecode<crate::model::ExportPaymentsRequest>,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "BindingLiquidSdk_get_info",
+            debug_name: "BindingLiquidSdk_export_payments",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let api_that = that.cst_decode();
+            let api_req = req.cst_decode();
             move |context| async move {
                 transform_result_dco(
                     (move || async move {
@@ -237,7 +239,7 @@ fn wire__crate__bindings__BindingLiquidSdk_get_info_impl(
                             }
                         }
                         let api_that = &*api_that_decoded.unwrap();
-                        crate::bindings::BindingLiquidSdk::get_info(api_that).await
+                        crate::bindings::BindingLiquidSdk::export_payments(api_that, api_req).await
                     })()
                     .await,
                 )
@@ -245,7 +247,7 @@ fn wire__crate__bindings__BindingLiquidSdk_get_info_impl(
         },
     )
 }
-fn wire__crate__bindings__BindingLiquidSdk_list_payments_impl(
+fn wire__crate__bindings__BindingLiquidSdk_fetch_lightning_limits_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     that: impl CstDecode<
         RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
@@ -253,7 +255,7 @@ fn wire__crate__bindings__BindingLiquidSdk_list_payments_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "BindingLiquidSdk_list_payments",
+            debug_name: "BindingLiquidSdk_fetch_lightning_limits",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -279,7 +281,7 @@ fn wire__crate__bindings__BindingLiquidSdk_list_payments_impl(
                             }
                         }
                         let api_that = &*api_that_decoded.unwrap();
-                        crate::bindings::BindingLiquidSdk::list_payments(api_that).await
+                        crate::bindings::BindingLiquidSdk::fetch_lightning_limits(api_that).await
                     })()
                     .await,
                 )
@@ -287,22 +289,20 @@ fn wire__crate__bindings__BindingLiquidSdk_list_payments_impl(
         },
     )
 }
-fn wire__crate__bindings__BindingLiquidSdk_prepare_receive_payment_impl(
+fn wire__crate__bindings__BindingLiquidSdk_fetch_recommended_fees_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     that: impl CstDecode<
         RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
     >,
-    req: impl CstDecode<crate::model::PrepareReceiveRequest>,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "BindingLiquidSdk_prepare_receive_payment",
+            debug_name: "BindingLiquidSdk_fetch_recommended_fees",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let api_that = that.cst_decode();
-            let api_req = req.cst_decode();
             move |context| async move {
                 transform_result_dco(
                     (move || async move {
@@ -323,10 +323,7 @@ fn wire__crate__bindings__BindingLiquidSdk_prepare_receive_payment_impl(
                             }
                         }
                         let api_that = &*api_that_decoded.unwrap();
-                        crate::bindings::BindingLiquidSdk::prepare_receive_payment(
-                            api_that, api_req,
-                        )
-                        .await
+                        crate::bindings::BindingLiquidSdk::fetch_recommended_fees(api_that).await
                     })()
                     .await,
                 )
@@ -334,22 +331,20 @@ fn wire__crate__bindings__BindingLiquidSdk_prepare_receive_payment_impl(
         },
     )
 }
-fn wire__crate__bindings__BindingLiquidSdk_prepare_send_payment_impl(
+fn wire__crate__bindings__BindingLiquidSdk_get_info_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     that: impl CstDecode<
         RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
     >,
-    req: impl CstDecode<crate::model::PrepareSendRequest>,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "BindingLiquidSdk_prepare_send_payment",
+            debug_name: "BindingLiquidSdk_get_info",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let api_that = that.cst_decode();
-            let api_req = req.cst_decode();
             move |context| async move {
                 transform_result_dco(
                     (move || async move {
@@ -370,8 +365,7 @@ fn wire__crate__bindings__BindingLiquidSdk_prepare_send_payment_impl(
                             }
                         }
                         let api_that = &*api_that_decoded.unwrap();
-                        crate::bindings::BindingLiquidSdk::prepare_send_payment(api_that, api_req)
-                            .await
+                        crate::bindings::BindingLiquidSdk::get_info(api_that).await
                     })()
                     .await,
                 )
@@ -379,16 +373,16 @@ fn wire__crate__bindings__BindingLiquidSdk_prepare_send_payment_impl(
         },
     )
 }
-fn wire__crate__bindings__BindingLiquidSdk_receive_payment_impl(
+fn wire__crate__bindings__BindingLiquidSdk_get_payment_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     that: impl CstDecode<
         RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
     >,
-    req: impl CstDecode<crate::model::PrepareReceiveResponse>,
+    req: impl CstDecode<crate::model::GetPaymentRequest>,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "BindingLiquidSdk_receive_payment",
+            debug_name: "BindingLiquidSdk_get_payment",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -415,7 +409,7 @@ fn wire__crate__bindings__BindingLiquidSdk_receive_payment_impl(
                             }
                         }
                         let api_that = &*api_that_decoded.unwrap();
-                        crate::bindings::BindingLiquidSdk::receive_payment(api_that, api_req).await
+                        crate::bindings::BindingLiquidSdk::get_payment(api_that, api_req).await
                     })()
                     .await,
                 )
@@ -423,51 +417,58 @@ fn wire__crate__bindings__BindingLiquidSdk_receive_payment_impl(
         },
     )
 }
-fn wire__crate__bindings__BindingLiquidSdk_restore_impl(
+fn wire__crate__bindings__BindingLiquidSdk_list_electrum_servers_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     that: impl CstDecode<
         RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
     >,
-    req: impl CstDecode<crate::model::RestoreRequest>,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "BindingLiquidSdk_restore",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "BindingLiquidSdk_list_electrum_servers",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let api_that = that.cst_decode();
-            let api_req = req.cst_decode();
-            transform_result_dco((move || {
-                let mut api_that_decoded = None;
-                let decode_indices_ =
-                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
-                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
-                            &api_that, 0, false,
-                        ),
-                    ]);
-                for i in decode_indices_ {
-                    match i {
-                        0 => api_that_decoded = Some(api_that.lockable_decode_sync_ref()),
-                        _ => unreachable!(),
-                    }
-                }
-                let api_that = &*api_that_decoded.unwrap();
-                crate::bindings::BindingLiquidSdk::restore(api_that, api_req)
-            })())
+            move |context| async move {
+                transform_result_dco(
+                    (move || async move {
+                        let mut api_that_decoded = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_decoded =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that = &*api_that_decoded.unwrap();
+                        crate::bindings::BindingLiquidSdk::list_electrum_servers(api_that).await
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__bindings__BindingLiquidSdk_send_payment_impl(
+fn wire__crate__bindings__BindingLiquidSdk_list_payments_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     that: impl CstDecode<
         RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
     >,
-    req: impl CstDecode<crate::model::PrepareSendResponse>,
+    req: impl CstDecode<crate::model::ListPaymentsRequest>,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "BindingLiquidSdk_send_payment",
+            debug_name: "BindingLiquidSdk_list_payments",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -494,7 +495,7 @@ fn wire__crate__bindings__BindingLiquidSdk_send_payment_impl(
                             }
                         }
                         let api_that = &*api_that_decoded.unwrap();
-                        crate::bindings::BindingLiquidSdk::send_payment(api_that, api_req).await
+                        crate::bindings::BindingLiquidSdk::list_payments(api_that, api_req).await
                     })()
                     .await,
                 )
@@ -502,7 +503,7 @@ fn wire__crate__bindings__BindingLiquidSdk_send_payment_impl(
         },
     )
 }
-fn wire__crate__bindings__BindingLiquidSdk_sync_impl(
+fn wire__crate__bindings__BindingLiquidSdk_list_refundables_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     that: impl CstDecode<
         RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
@@ -510,7 +511,7 @@ fn wire__crate__bindings__BindingLiquidSdk_sync_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "BindingLiquidSdk_sync",
+            debug_name: "BindingLiquidSdk_list_refundables",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -536,7 +537,7 @@ fn wire__crate__bindings__BindingLiquidSdk_sync_impl(
                             }
                         }
                         let api_that = &*api_that_decoded.unwrap();
-                        crate::bindings::BindingLiquidSdk::sync(api_that).await
+                        crate::bindings::BindingLiquidSdk::list_refundables(api_that).await
                     })()
                     .await,
                 )
@@ -544,370 +545,3152 @@ fn wire__crate__bindings__BindingLiquidSdk_sync_impl(
         },
     )
 }
-fn wire__crate__bindings__binding_event_listener_on_event_impl(
+fn wire__crate__bindings__BindingLiquidSdk_lnurl_pay_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
-    that: impl CstDecode<crate::bindings::BindingEventListener>,
-    e: impl CstDecode<crate::model::LiquidSdkEvent>,
+    that: impl CstDecode<
+        RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
+    >,
+    req: impl CstDecode<crate::model::PrepareLnUrlPayResponse>,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "binding_event_listener_on_event",
+            debug_name: "BindingLiquidSdk_lnurl_pay",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let api_that = that.cst_decode();
-            let api_e = e.cst_decode();
-            move |context| {
-                transform_result_dco((move || {
-                    Result::<_, ()>::Ok({
-                        crate::bindings::BindingEventListener::on_event(&api_that, api_e);
-                    })
-                })())
+            let api_req = req.cst_decode();
+            move |context| async move {
+                transform_result_dco(
+                    (move || async move {
+                        let mut api_that_decoded = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_decoded =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that = &*api_that_decoded.unwrap();
+                        crate::bindings::BindingLiquidSdk::lnurl_pay(api_that, api_req).await
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__bindings__breez_log_stream_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
-    s: impl CstDecode<StreamSink<crate::model::LogEntry, flutter_rust_bridge::for_generated::DcoCodec>>,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "breez_log_stream", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { let api_s = s.cst_decode(); move |context|  {
-                    transform_result_dco((move ||  {
-                         crate::bindings::breez_log_stream(api_s)
-                    })())
-                } })
-}
-fn wire__crate__bindings__connect_impl(
+fn wire__crate__bindings__BindingLiquidSdk_lnurl_withdraw_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
-    req: impl CstDecode<crate::model::ConnectRequest>,
+    that: impl CstDecode<
+        RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
+    >,
+    req: impl CstDecode<crate::model::LnUrlWithdrawRequest>,
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "connect",
+            debug_name: "BindingLiquidSdk_lnurl_withdraw",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
+            let api_that = that.cst_decode();
             let api_req = req.cst_decode();
             move |context| async move {
                 transform_result_dco(
-                    (move || async move { crate::bindings::connect(api_req).await })().await,
+                    (move || async move {
+                        let mut api_that_decoded = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_decoded =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that = &*api_that_decoded.unwrap();
+                        crate::bindings::BindingLiquidSdk::lnurl_withdraw(api_that, api_req).await
+                    })()
+                    .await,
                 )
             }
         },
     )
 }
-fn wire__crate__bindings__default_config_impl(
-    network: impl CstDecode<crate::model::Network>,
+fn wire__crate__bindings__BindingLiquidSdk_parse_impl(
+    that: impl CstDecode<
+        RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
+    >,
+    input: impl CstDecode<String>,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "default_config",
+            debug_name: "BindingLiquidSdk_parse",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
-            let api_network = network.cst_decode();
+            let api_that = that.cst_decode();
+            let api_input = input.cst_decode();
             transform_result_dco((move || {
-                Result::<_, ()>::Ok(crate::bindings::default_config(api_network))
+                let mut api_that_decoded = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_decoded = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that = &*api_that_decoded.unwrap();
+                crate::bindings::BindingLiquidSdk::parse(api_that, api_input)
             })())
         },
     )
 }
-fn wire__crate__bindings__parse_invoice_impl(
-    input: impl CstDecode<String>,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+fn wire__crate__bindings__BindingLiquidSdk_pay_onchain_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    that: impl CstDecode<
+        RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
+    >,
+    req: impl CstDecode<crate::model::PayOnchainRequest>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "parse_invoice",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "BindingLiquidSdk_pay_onchain",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
-            let api_input = input.cst_decode();
-            transform_result_dco((move || crate::bindings::parse_invoice(api_input))())
+            let api_that = that.cst_decode();
+            let api_req = req.cst_decode();
+            move |context| async move {
+                transform_result_dco(
+                    (move || async move {
+                        let mut api_that_decoded = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_decoded =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that = &*api_that_decoded.unwrap();
+                        crate::bindings::BindingLiquidSdk::pay_onchain(api_that, api_req).await
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-
-// Section: dart2rust
-
-impl CstDecode<f32> for f32 {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    fn cst_decode(self) -> f32 {
-        self
-    }
-}
-impl CstDecode<i32> for i32 {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    fn cst_decode(self) -> i32 {
-        self
-    }
-}
-impl CstDecode<crate::model::Network> for i32 {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    fn cst_decode(self) -> crate::model::Network {
-        match self {
-            0 => crate::model::Network::Mainnet,
+fn wire__crate__bindings__BindingLiquidSdk_prepare_lnurl_pay_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    that: impl CstDecode<
+        RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
+    >,
+    req: impl CstDecode<crate::model::PrepareLnUrlPayRequest>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "BindingLiquidSdk_prepare_lnurl_pay",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.cst_decode();
+            let api_req = req.cst_decode();
+            move |context| async move {
+                transform_result_dco(
+                    (move || async move {
+                        let mut api_that_decoded = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_decoded =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that = &*api_that_decoded.unwrap();
+                        crate::bindings::BindingLiquidSdk::prepare_lnurl_pay(api_that, api_req)
+                            .await
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__bindings__BindingLiquidSdk_prepare_pay_onchain_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    that: impl CstDecode<
+        RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
+    >,
+    req: impl CstDecode<crate::model::PreparePayOnchainRequest>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "BindingLiquidSdk_prepare_pay_onchain",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.cst_decode();
+            let api_req = req.cst_decode();
+            move |context| async move {
+                transform_result_dco(
+                    (move || async move {
+                        let mut api_that_decoded = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_decoded =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that = &*api_that_decoded.unwrap();
+                        crate::bindings::BindingLiquidSdk::prepare_pay_onchain(api_that, api_req)
+                            .await
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__bindings__BindingLiquidSdk_prepare_receive_onchain_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    that: impl CstDecode<
+        RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
+    >,
+    req: impl CstDecode<crate::model::PrepareReceiveOnchainRequest>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "BindingLiquidSdk_prepare_receive_onchain",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.cst_decode();
+            let api_req = req.cst_decode();
+            move |context| async move {
+                transform_result_dco(
+                    (move || async move {
+                        let mut api_that_decoded = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_decoded =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that = &*api_that_decoded.unwrap();
+                        crate::bindings::BindingLiquidSdk::prepare_receive_onchain(
+                            api_that, api_req,
+                        )
+                        .await
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__bindings__BindingLiquidSdk_prepare_receive_payment_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    that: impl CstDecode<
+        RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
+    >,
+    req: impl CstDecode<crate::model::PrepareReceiveRequest>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "BindingLiquidSdk_prepare_receive_payment",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.cst_decode();
+            let api_req = req.cst_decode();
+            move |context| async move {
+                transform_result_dco(
+                    (move || async move {
+                        let mut api_that_decoded = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_decoded =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that = &*api_that_decoded.unwrap();
+                        crate::bindings::BindingLiquidSdk::prepare_receive_payment(
+                            api_that, api_req,
+                        )
+                        .await
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__bindings__BindingLiquidSdk_prepare_refund_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    that: impl CstDecode<
+        RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
+    >,
+    req: impl CstDecode<crate::model::PrepareRefundRequest>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "BindingLiquidSdk_prepare_refund",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.cst_decode();
+            let api_req = req.cst_decode();
+            move |context| async move {
+                transform_result_dco(
+                    (move || async move {
+                        let mut api_that_decoded = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_decoded =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that = &*api_that_decoded.unwrap();
+                        crate::bindings::BindingLiquidSdk::prepare_refund(api_that, api_req).await
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__bindings__BindingLiquidSdk_prepare_send_onchain_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    that: impl CstDecode<
+        RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
+    >,
+    req: impl CstDecode<crate::model::PrepareSendOnchainRequest>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "BindingLiquidSdk_prepare_send_onchain",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.cst_decode();
+            let api_req = req.cst_decode();
+            move |context| async move {
+                transform_result_dco(
+                    (move || async move {
+                        let mut api_that_decoded = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_decoded =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that = &*api_that_decoded.unwrap();
+                        crate::bindings::BindingLiquidSdk::prepare_send_onchain(api_that, api_req)
+                            .await
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__bindings__BindingLiquidSdk_prepare_send_payment_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    that: impl CstDecode<
+        RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
+    >,
+    req: impl CstDecode<crate::model::PrepareSendRequest>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "BindingLiquidSdk_prepare_send_payment",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.cst_decode();
+            let api_req = req.cst_decode();
+            move |context| async move {
+                transform_result_dco(
+                    (move || async move {
+                        let mut api_that_decoded = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_decoded =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that = &*api_that_decoded.unwrap();
+                        crate::bindings::BindingLiquidSdk::prepare_send_payment(api_that, api_req)
+                            .await
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__bindings__BindingLiquidSdk_receive_liquid_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    that: impl CstDecode<
+        RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
+    >,
+    req: impl CstDecode<crate::model::ReceiveLiquidRequest>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "BindingLiquidSdk_receive_liquid",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.cst_decode();
+            let api_req = req.cst_decode();
+            move |context| async move {
+                transform_result_dco(
+                    (move || async move {
+                        let mut api_that_decoded = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_decoded =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that = &*api_that_decoded.unwrap();
+                        crate::bindings::BindingLiquidSdk::receive_liquid(api_that, api_req).await
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__bindings__BindingLiquidSdk_receive_onchain_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    that: impl CstDecode<
+        RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
+    >,
+    req: impl CstDecode<crate::model::PrepareReceiveOnchainResponse>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "BindingLiquidSdk_receive_onchain",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.cst_decode();
+            let api_req = req.cst_decode();
+            move |context| async move {
+                transform_result_dco(
+                    (move || async move {
+                        let mut api_that_decoded = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_decoded =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that = &*api_that_decoded.unwrap();
+                        crate::bindings::BindingLiquidSdk::receive_onchain(api_that, api_req).await
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__bindings__BindingLiquidSdk_receive_payment_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    that: impl CstDecode<
+        RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
+    >,
+    req: impl CstDecode<crate::model::PrepareReceiveResponse>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "BindingLiquidSdk_receive_payment",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.cst_decode();
+            let api_req = req.cst_decode();
+            move |context| async move {
+                transform_result_dco(
+                    (move || async move {
+                        let mut api_that_decoded = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_decoded =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that = &*api_that_decoded.unwrap();
+                        crate::bindings::BindingLiquidSdk::receive_payment(api_that, api_req).await
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__bindings__BindingLiquidSdk_refund_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    that: impl CstDecode<
+        RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
+    >,
+    req: impl CstDecode<crate::model::RefundRequest>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "BindingLiquidSdk_refund",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.cst_decode();
+            let api_req = req.cst_decode();
+            move |context| async move {
+                transform_result_dco(
+                    (move || async move {
+                        let mut api_that_decoded = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_decoded =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that = &*api_that_decoded.unwrap();
+                        crate::bindings::BindingLiquidSdk::refund(api_that, api_req).await
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__bindings__BindingLiquidSdk_restore_impl(
+    that: impl CstDecode<
+        RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
+    >,
+    req: impl CstDecode<crate::model::RestoreRequest>,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "BindingLiquidSdk_restore",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let api_that = that.cst_decode();
+            let api_req = req.cst_decode();
+            transform_result_dco((move || {
+                let mut api_that_decoded = None;
+                let decode_indices_ =
+                    flutter_rust_bridge::for_generated::lockable_compute_decode_order(vec![
+                        flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                            &api_that, 0, false,
+                        ),
+                    ]);
+                for i in decode_indices_ {
+                    match i {
+                        0 => api_that_decoded = Some(api_that.lockable_decode_sync_ref()),
+                        _ => unreachable!(),
+                    }
+                }
+                let api_that = &*api_that_decoded.unwrap();
+                crate::bindings::BindingLiquidSdk::restore(api_that, api_req)
+            })())
+        },
+    )
+}
+fn wire__crate__bindings__BindingLiquidSdk_send_onchain_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    that: impl CstDecode<
+        RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
+    >,
+    req: impl CstDecode<crate::model::PrepareSendOnchainResponse>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "BindingLiquidSdk_send_onchain",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.cst_decode();
+            let api_req = req.cst_decode();
+            move |context| async move {
+                transform_result_dco(
+                    (move || async move {
+                        let mut api_that_decoded = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_decoded =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that = &*api_that_decoded.unwrap();
+                        crate::bindings::BindingLiquidSdk::send_onchain(api_that, api_req).await
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__bindings__BindingLiquidSdk_send_payment_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    that: impl CstDecode<
+        RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
+    >,
+    req: impl CstDecode<crate::model::PrepareSendResponse>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "BindingLiquidSdk_send_payment",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.cst_decode();
+            let api_req = req.cst_decode();
+            move |context| async move {
+                transform_result_dco(
+                    (move || async move {
+                        let mut api_that_decoded = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_decoded =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that = &*api_that_decoded.unwrap();
+                        crate::bindings::BindingLiquidSdk::send_payment(api_that, api_req).await
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__bindings__BindingLiquidSdk_set_payment_metadata_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    that: impl CstDecode<
+        RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
+    >,
+    req: impl CstDecode<crate::model::SetPaymentMetadataRequest>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "BindingLiquidSdk_set_payment_metadata",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.cst_decode();
+            let api_req = req.cst_decode();
+            move |context| async move {
+                transform_result_dco(
+                    (move || async move {
+                        let mut api_that_decoded = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_decoded =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that = &*api_that_decoded.unwrap();
+                        crate::bindings::BindingLiquidSdk::set_payment_metadata(api_that, api_req)
+                            .await
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__bindings__BindingLiquidSdk_sync_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    that: impl CstDecode<
+        RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>,
+    >,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "BindingLiquidSdk_sync",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.cst_decode();
+            move |context| async move {
+                transform_result_dco(
+                    (move || async move {
+                        let mut api_that_decoded = None;
+                        let decode_indices_ =
+                            flutter_rust_bridge::for_generated::lockable_compute_decode_order(
+                                vec![flutter_rust_bridge::for_generated::LockableOrderInfo::new(
+                                    &api_that, 0, false,
+                                )],
+                            );
+                        for i in decode_indices_ {
+                            match i {
+                                0 => {
+                                    api_that_decoded =
+                                        Some(api_that.lockable_decode_async_ref().await)
+                                }
+                                _ => unreachable!(),
+                            }
+                        }
+                        let api_that = &*api_that_decoded.unwrap();
+                        crate::bindings::BindingLiquidSdk::sync(api_that).await
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__bindings__binding_event_listener_on_event_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    that: impl CstDecode<crate::bindings::BindingEventListener>,
+    e: impl CstDecode<crate::model::LiquidSdkEvent>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "binding_event_listener_on_event",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_that = that.cst_decode();
+            let api_e = e.cst_decode();
+            move |context| {
+                transform_result_dco((move || {
+                    Result::<_, ()>::Ok({
+                        crate::bindings::BindingEventListener::on_event(&api_that, api_e);
+                    })
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__bindings__breez_log_stream_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    s: impl CstDecode<StreamSink<crate::model::LogEntry, flutter_rust_bridge::for_generated::DcoCodec>>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::DcoCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "breez_log_stream", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { let api_s = s.cst_decode(); move |context|  {
+                    transform_result_dco((move ||  {
+                         crate::bindings::breez_log_stream(api_s)
+                    })())
+                } })
+}
+fn wire__crate__bindings__connect_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    req: impl CstDecode<crate::model::ConnectRequest>,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::DcoCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "connect",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let api_req = req.cst_decode();
+            move |context| async move {
+                transform_result_dco(
+                    (move || async move { crate::bindings::connect(api_req).await })().await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__bindings__default_config_impl(
+    network: impl CstDecode<crate::model::Network>,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "default_config",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let api_network = network.cst_decode();
+            transform_result_dco((move || {
+                Result::<_, ()>::Ok(crate::bindings::default_config(api_network))
+            })())
+        },
+    )
+}
+fn wire__crate__bindings__parse_invoice_impl(
+    input: impl CstDecode<String>,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartDco {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::DcoCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "parse_invoice",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let api_input = input.cst_decode();
+            transform_result_dco((move || crate::bindings::parse_invoice(api_input))())
+        },
+    )
+}
+
+// Section: dart2rust
+
+impl CstDecode<bool> for bool {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> bool {
+        self
+    }
+}
+impl CstDecode<f32> for f32 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> f32 {
+        self
+    }
+}
+impl CstDecode<f64> for f64 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> f64 {
+        self
+    }
+}
+impl CstDecode<crate::model::FeePriority> for i32 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::FeePriority {
+        match self {
+            0 => crate::model::FeePriority::Fastest,
+            1 => crate::model::FeePriority::HalfHour,
+            2 => crate::model::FeePriority::Hour,
+            3 => crate::model::FeePriority::Economy,
+            4 => crate::model::FeePriority::Minimum,
+            _ => unreachable!("Invalid variant for FeePriority: {}", self),
+        }
+    }
+}
+impl CstDecode<i32> for i32 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> i32 {
+        self
+    }
+}
+impl CstDecode<crate::model::Network> for i32 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::Network {
+        match self {
+            0 => crate::model::Network::Mainnet,
+            1 => crate::model::Network::Testnet,
+            2 => crate::model::Network::Regtest,
+            _ => unreachable!("Invalid variant for Network: {}", self),
+        }
+    }
+}
+impl CstDecode<crate::model::PaymentExportFormat> for i32 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::PaymentExportFormat {
+        match self {
+            0 => crate::model::PaymentExportFormat::Csv,
+            1 => crate::model::PaymentExportFormat::Json,
+            _ => unreachable!("Invalid variant for PaymentExportFormat: {}", self),
+        }
+    }
+}
+impl CstDecode<crate::model::PaymentState> for i32 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::PaymentState {
+        match self {
+            0 => crate::model::PaymentState::Created,
+            1 => crate::model::PaymentState::Pending,
+            2 => crate::model::PaymentState::Complete,
+            3 => crate::model::PaymentState::Failed,
+            4 => crate::model::PaymentState::TimedOut,
+            5 => crate::model::PaymentState::Refundable,
+            _ => unreachable!("Invalid variant for PaymentState: {}", self),
+        }
+    }
+}
+impl CstDecode<crate::model::PaymentSwapType> for i32 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::PaymentSwapType {
+        match self {
+            0 => crate::model::PaymentSwapType::Submarine,
+            1 => crate::model::PaymentSwapType::Reverse,
+            2 => crate::model::PaymentSwapType::Chain,
+            3 => crate::model::PaymentSwapType::Direct,
+            _ => unreachable!("Invalid variant for PaymentSwapType: {}", self),
+        }
+    }
+}
+impl CstDecode<crate::model::PaymentType> for i32 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> crate::model::PaymentType {
+        match self {
+            0 => crate::model::PaymentType::Receive,
+            1 => crate::model::PaymentType::Send,
+            _ => unreachable!("Invalid variant for PaymentType: {}", self),
+        }
+    }
+}
+impl CstDecode<u32> for u32 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> u32 {
+        self
+    }
+}
+impl CstDecode<u64> for u64 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> u64 {
+        self
+    }
+}
+impl CstDecode<u8> for u8 {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> u8 {
+        self
+    }
+}
+impl CstDecode<usize> for usize {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    fn cst_decode(self) -> usize {
+        self
+    }
+}
+impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+    }
+}
+
+impl SseDecode for BindingLiquidSdk {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <RustOpaqueNom<
+            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>,
+        >>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+    }
+}
+
+impl SseDecode
+    for RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <usize>::sse_decode(deserializer);
+        return unsafe { decode_rust_opaque_nom(inner) };
+    }
+}
+
+impl SseDecode
+    for StreamSink<crate::model::LiquidSdkEvent, flutter_rust_bridge::for_generated::DcoCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<crate::model::LogEntry, flutter_rust_bridge::for_generated::DcoCodec>
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return String::from_utf8(inner).unwrap();
+    }
+}
+
+impl SseDecode for crate::model::AssetBalance {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_assetId = <String>::sse_decode(deserializer);
+        let mut var_balance = <u64>::sse_decode(deserializer);
+        let mut var_assetMetadata = <Option<crate::model::AssetMetadata>>::sse_decode(deserializer);
+        return crate::model::AssetBalance {
+            asset_id: var_assetId,
+            balance: var_balance,
+            asset_metadata: var_assetMetadata,
+        };
+    }
+}
+
+impl SseDecode for crate::model::AssetMetadata {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_assetId = <String>::sse_decode(deserializer);
+        let mut var_ticker = <String>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_precision = <u8>::sse_decode(deserializer);
+        return crate::model::AssetMetadata {
+            asset_id: var_assetId,
+            ticker: var_ticker,
+            name: var_name,
+            precision: var_precision,
+        };
+    }
+}
+
+impl SseDecode for crate::model::BackupRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_backupPath = <Option<String>>::sse_decode(deserializer);
+        return crate::model::BackupRequest {
+            backup_path: var_backupPath,
+        };
+    }
+}
+
+impl SseDecode for crate::bindings::BindingEventListener {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_stream = <StreamSink<
+            crate::model::LiquidSdkEvent,
+            flutter_rust_bridge::for_generated::DcoCodec,
+        >>::sse_decode(deserializer);
+        return crate::bindings::BindingEventListener { stream: var_stream };
+    }
+}
+
+impl SseDecode for crate::model::BitcoinAddressData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_address = <String>::sse_decode(deserializer);
+        let mut var_network = <crate::model::Network>::sse_decode(deserializer);
+        let mut var_amountSat = <Option<u64>>::sse_decode(deserializer);
+        let mut var_label = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <Option<String>>::sse_decode(deserializer);
+        return crate::model::BitcoinAddressData {
+            address: var_address,
+            network: var_network,
+            amount_sat: var_amountSat,
+            label: var_label,
+            message: var_message,
+        };
+    }
+}
+
+impl SseDecode for crate::model::Config {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_boltzUrl = <String>::sse_decode(deserializer);
+        let mut var_electrumServers = <Vec<crate::model::ElectrumServer>>::sse_decode(deserializer);
+        let mut var_electrumUrl = <Option<String>>::sse_decode(deserializer);
+        let mut var_bitcoinElectrumUrl = <String>::sse_decode(deserializer);
+        let mut var_esploraUrl = <Option<String>>::sse_decode(deserializer);
+        let mut var_workingDir = <String>::sse_decode(deserializer);
+        let mut var_network = <crate::model::Network>::sse_decode(deserializer);
+        let mut var_paymentTimeoutSec = <u64>::sse_decode(deserializer);
+        let mut var_syncFallbackIntervalSec = <u64>::sse_decode(deserializer);
+        let mut var_zeroConfMinFeeRate = <f32>::sse_decode(deserializer);
+        let mut var_zeroConfMaxAmountSat = <Option<u64>>::sse_decode(deserializer);
+        let mut var_assetMetadata =
+            <Option<Vec<crate::model::AssetMetadata>>>::sse_decode(deserializer);
+        let mut var_maxFeeSat = <Option<u64>>::sse_decode(deserializer);
+        let mut var_maxFeePercent = <Option<f64>>::sse_decode(deserializer);
+        let mut var_regtestPolicyAsset = <Option<String>>::sse_decode(deserializer);
+        return crate::model::Config {
+            boltz_url: var_boltzUrl,
+            electrum_servers: var_electrumServers,
+            electrum_url: var_electrumUrl,
+            bitcoin_electrum_url: var_bitcoinElectrumUrl,
+            esplora_url: var_esploraUrl,
+            working_dir: var_workingDir,
+            network: var_network,
+            payment_timeout_sec: var_paymentTimeoutSec,
+            sync_fallback_interval_sec: var_syncFallbackIntervalSec,
+            zero_conf_min_fee_rate: var_zeroConfMinFeeRate,
+            zero_conf_max_amount_sat: var_zeroConfMaxAmountSat,
+            asset_metadata: var_assetMetadata,
+            max_fee_sat: var_maxFeeSat,
+            max_fee_percent: var_maxFeePercent,
+            regtest_policy_asset: var_regtestPolicyAsset,
+        };
+    }
+}
+
+impl SseDecode for crate::model::ConnectRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_mnemonic = <String>::sse_decode(deserializer);
+        let mut var_config = <crate::model::Config>::sse_decode(deserializer);
+        return crate::model::ConnectRequest {
+            mnemonic: var_mnemonic,
+            config: var_config,
+        };
+    }
+}
+
+impl SseDecode for crate::model::ElectrumServer {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_tls = <bool>::sse_decode(deserializer);
+        let mut var_validateDomain = <bool>::sse_decode(deserializer);
+        return crate::model::ElectrumServer {
+            url: var_url,
+            tls: var_tls,
+            validate_domain: var_validateDomain,
+        };
+    }
+}
+
+impl SseDecode for crate::model::ElectrumServerStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_inUse = <bool>::sse_decode(deserializer);
+        let mut var_healthy = <bool>::sse_decode(deserializer);
+        let mut var_consecutiveFailures = <u32>::sse_decode(deserializer);
+        let mut var_lastError = <Option<String>>::sse_decode(deserializer);
+        return crate::model::ElectrumServerStatus {
+            url: var_url,
+            in_use: var_inUse,
+            healthy: var_healthy,
+            consecutive_failures: var_consecutiveFailures,
+            last_error: var_lastError,
+        };
+    }
+}
+
+impl SseDecode for crate::model::ExportPaymentsRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_format = <crate::model::PaymentExportFormat>::sse_decode(deserializer);
+        let mut var_filter = <crate::model::ListPaymentsRequest>::sse_decode(deserializer);
+        let mut var_path = <String>::sse_decode(deserializer);
+        return crate::model::ExportPaymentsRequest {
+            format: var_format,
+            filter: var_filter,
+            path: var_path,
+        };
+    }
+}
+
+impl SseDecode for crate::model::ExportPaymentsResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_paymentsCount = <u32>::sse_decode(deserializer);
+        return crate::model::ExportPaymentsResponse {
+            payments_count: var_paymentsCount,
+        };
+    }
+}
+
+impl SseDecode for f32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::model::FeeBreakdown {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_serviceFeePercentage = <f64>::sse_decode(deserializer);
+        let mut var_serviceFeeSat = <u64>::sse_decode(deserializer);
+        let mut var_swapperMinerFeesSat = <u64>::sse_decode(deserializer);
+        let mut var_networkFeeSat = <u64>::sse_decode(deserializer);
+        let mut var_totalFeesSat = <u64>::sse_decode(deserializer);
+        return crate::model::FeeBreakdown {
+            service_fee_percentage: var_serviceFeePercentage,
+            service_fee_sat: var_serviceFeeSat,
+            swapper_miner_fees_sat: var_swapperMinerFeesSat,
+            network_fee_sat: var_networkFeeSat,
+            total_fees_sat: var_totalFeesSat,
+        };
+    }
+}
+
+impl SseDecode for crate::model::FeePriority {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::model::FeePriority::Fastest,
+            1 => crate::model::FeePriority::HalfHour,
+            2 => crate::model::FeePriority::Hour,
+            3 => crate::model::FeePriority::Economy,
+            4 => crate::model::FeePriority::Minimum,
+            _ => unreachable!("Invalid variant for FeePriority: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::model::GetInfoResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_balanceSat = <u64>::sse_decode(deserializer);
+        let mut var_pendingSendSat = <u64>::sse_decode(deserializer);
+        let mut var_pendingReceiveSat = <u64>::sse_decode(deserializer);
+        let mut var_pubkey = <String>::sse_decode(deserializer);
+        let mut var_assetBalances = <Vec<crate::model::AssetBalance>>::sse_decode(deserializer);
+        return crate::model::GetInfoResponse {
+            balance_sat: var_balanceSat,
+            pending_send_sat: var_pendingSendSat,
+            pending_receive_sat: var_pendingReceiveSat,
+            pubkey: var_pubkey,
+            asset_balances: var_assetBalances,
+        };
+    }
+}
+
+impl SseDecode for crate::model::GetPaymentRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_txId = <String>::sse_decode(deserializer);
+                return crate::model::GetPaymentRequest::TxId { tx_id: var_txId };
+            }
+            1 => {
+                let mut var_swapId = <String>::sse_decode(deserializer);
+                return crate::model::GetPaymentRequest::SwapId {
+                    swap_id: var_swapId,
+                };
+            }
+            2 => {
+                let mut var_bolt11 = <String>::sse_decode(deserializer);
+                return crate::model::GetPaymentRequest::Bolt11 { bolt11: var_bolt11 };
+            }
+            3 => {
+                let mut var_paymentHash = <String>::sse_decode(deserializer);
+                return crate::model::GetPaymentRequest::PaymentHash {
+                    payment_hash: var_paymentHash,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::model::InputType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_invoice = <crate::model::LNInvoice>::sse_decode(deserializer);
+                return crate::model::InputType::Bolt11 {
+                    invoice: var_invoice,
+                };
+            }
+            1 => {
+                let mut var_address = <crate::model::LiquidAddressData>::sse_decode(deserializer);
+                return crate::model::InputType::LiquidAddress {
+                    address: var_address,
+                };
+            }
+            2 => {
+                let mut var_address = <crate::model::BitcoinAddressData>::sse_decode(deserializer);
+                return crate::model::InputType::BitcoinAddress {
+                    address: var_address,
+                };
+            }
+            3 => {
+                let mut var_url = <String>::sse_decode(deserializer);
+                return crate::model::InputType::LnUrl { url: var_url };
+            }
+            4 => {
+                let mut var_address = <String>::sse_decode(deserializer);
+                let mut var_url = <String>::sse_decode(deserializer);
+                return crate::model::InputType::LightningAddress {
+                    address: var_address,
+                    url: var_url,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::model::LightningLimits {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_minSat = <u64>::sse_decode(deserializer);
+        let mut var_maxSat = <u64>::sse_decode(deserializer);
+        let mut var_serviceFeePercentage = <f64>::sse_decode(deserializer);
+        let mut var_minerFeesSat = <u64>::sse_decode(deserializer);
+        return crate::model::LightningLimits {
+            min_sat: var_minSat,
+            max_sat: var_maxSat,
+            service_fee_percentage: var_serviceFeePercentage,
+            miner_fees_sat: var_minerFeesSat,
+        };
+    }
+}
+
+impl SseDecode for crate::model::LightningPaymentLimitsResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_send = <crate::model::LightningLimits>::sse_decode(deserializer);
+        let mut var_receive = <crate::model::LightningLimits>::sse_decode(deserializer);
+        return crate::model::LightningPaymentLimitsResponse {
+            send: var_send,
+            receive: var_receive,
+        };
+    }
+}
+
+impl SseDecode for crate::model::LiquidAddressData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_address = <String>::sse_decode(deserializer);
+        let mut var_network = <crate::model::Network>::sse_decode(deserializer);
+        let mut var_amountSat = <Option<u64>>::sse_decode(deserializer);
+        let mut var_assetId = <Option<String>>::sse_decode(deserializer);
+        let mut var_label = <Option<String>>::sse_decode(deserializer);
+        let mut var_message = <Option<String>>::sse_decode(deserializer);
+        return crate::model::LiquidAddressData {
+            address: var_address,
+            network: var_network,
+            amount_sat: var_amountSat,
+            asset_id: var_assetId,
+            label: var_label,
+            message: var_message,
+        };
+    }
+}
+
+impl SseDecode for crate::error::LiquidSdkError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::error::LiquidSdkError::AlreadyStarted;
+            }
+            1 => {
+                let mut var_err = <String>::sse_decode(deserializer);
+                return crate::error::LiquidSdkError::Generic { err: var_err };
+            }
+            2 => {
+                return crate::error::LiquidSdkError::NotStarted;
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::model::LiquidSdkEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_details = <crate::model::Payment>::sse_decode(deserializer);
+                return crate::model::LiquidSdkEvent::PaymentFailed {
+                    details: var_details,
+                };
+            }
+            1 => {
+                let mut var_details = <crate::model::Payment>::sse_decode(deserializer);
+                return crate::model::LiquidSdkEvent::PaymentPending {
+                    details: var_details,
+                };
+            }
+            2 => {
+                let mut var_details = <crate::model::Payment>::sse_decode(deserializer);
+                return crate::model::LiquidSdkEvent::PaymentRefunded {
+                    details: var_details,
+                };
+            }
+            3 => {
+                let mut var_details = <crate::model::Payment>::sse_decode(deserializer);
+                return crate::model::LiquidSdkEvent::PaymentRefundable {
+                    details: var_details,
+                };
+            }
+            4 => {
+                let mut var_details = <crate::model::Payment>::sse_decode(deserializer);
+                return crate::model::LiquidSdkEvent::PaymentRefundPending {
+                    details: var_details,
+                };
+            }
+            5 => {
+                let mut var_details = <crate::model::Payment>::sse_decode(deserializer);
+                return crate::model::LiquidSdkEvent::PaymentSucceeded {
+                    details: var_details,
+                };
+            }
+            6 => {
+                let mut var_details = <crate::model::Payment>::sse_decode(deserializer);
+                return crate::model::LiquidSdkEvent::PaymentWaitingConfirmation {
+                    details: var_details,
+                };
+            }
+            7 => {
+                return crate::model::LiquidSdkEvent::Synced;
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for Vec<crate::model::AssetBalance> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::model::AssetBalance>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::model::AssetMetadata> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::model::AssetMetadata>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::model::ElectrumServer> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::model::ElectrumServer>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::model::ElectrumServerStatus> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::model::ElectrumServerStatus>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::model::Payment> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::model::Payment>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::model::PaymentState> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::model::PaymentState>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::model::PaymentType> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::model::PaymentType>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for crate::model::ListPaymentsRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_paymentTypes =
+            <Option<Vec<crate::model::PaymentType>>>::sse_decode(deserializer);
+        let mut var_states = <Option<Vec<crate::model::PaymentState>>>::sse_decode(deserializer);
+        let mut var_fromTimestamp = <Option<u32>>::sse_decode(deserializer);
+        let mut var_toTimestamp = <Option<u32>>::sse_decode(deserializer);
+        let mut var_offset = <Option<u32>>::sse_decode(deserializer);
+        let mut var_limit = <Option<u32>>::sse_decode(deserializer);
+        let mut var_sortDescending = <Option<bool>>::sse_decode(deserializer);
+        let mut var_metadataSearch = <Option<String>>::sse_decode(deserializer);
+        return crate::model::ListPaymentsRequest {
+            payment_types: var_paymentTypes,
+            states: var_states,
+            from_timestamp: var_fromTimestamp,
+            to_timestamp: var_toTimestamp,
+            offset: var_offset,
+            limit: var_limit,
+            sort_descending: var_sortDescending,
+            metadata_search: var_metadataSearch,
+        };
+    }
+}
+
+impl SseDecode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u8>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::model::RefundableSwap> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::model::RefundableSwap>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::model::RouteHint> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::model::RouteHint>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::model::RouteHintHop> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::model::RouteHintHop>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for crate::model::LNInvoice {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_bolt11 = <String>::sse_decode(deserializer);
+        let mut var_network = <crate::model::Network>::sse_decode(deserializer);
+        let mut var_payeePubkey = <String>::sse_decode(deserializer);
+        let mut var_paymentHash = <String>::sse_decode(deserializer);
+        let mut var_description = <Option<String>>::sse_decode(deserializer);
+        let mut var_descriptionHash = <Option<String>>::sse_decode(deserializer);
+        let mut var_amountMsat = <Option<u64>>::sse_decode(deserializer);
+        let mut var_timestamp = <u64>::sse_decode(deserializer);
+        let mut var_expiry = <u64>::sse_decode(deserializer);
+        let mut var_routingHints = <Vec<crate::model::RouteHint>>::sse_decode(deserializer);
+        let mut var_paymentSecret = <Vec<u8>>::sse_decode(deserializer);
+        let mut var_minFinalCltvExpiryDelta = <u64>::sse_decode(deserializer);
+        return crate::model::LNInvoice {
+            bolt11: var_bolt11,
+            network: var_network,
+            payee_pubkey: var_payeePubkey,
+            payment_hash: var_paymentHash,
+            description: var_description,
+            description_hash: var_descriptionHash,
+            amount_msat: var_amountMsat,
+            timestamp: var_timestamp,
+            expiry: var_expiry,
+            routing_hints: var_routingHints,
+            payment_secret: var_paymentSecret,
+            min_final_cltv_expiry_delta: var_minFinalCltvExpiryDelta,
+        };
+    }
+}
+
+impl SseDecode for crate::model::LnUrlPayResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_payment = <crate::model::Payment>::sse_decode(deserializer);
+        let mut var_successAction =
+            <Option<crate::model::SuccessActionProcessed>>::sse_decode(deserializer);
+        return crate::model::LnUrlPayResult {
+            payment: var_payment,
+            success_action: var_successAction,
+        };
+    }
+}
+
+impl SseDecode for crate::model::LnUrlWithdrawRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_lnurl = <String>::sse_decode(deserializer);
+        let mut var_amountSat = <u64>::sse_decode(deserializer);
+        return crate::model::LnUrlWithdrawRequest {
+            lnurl: var_lnurl,
+            amount_sat: var_amountSat,
+        };
+    }
+}
+
+impl SseDecode for crate::model::LnUrlWithdrawResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_receivePaymentResponse =
+            <crate::model::ReceivePaymentResponse>::sse_decode(deserializer);
+        return crate::model::LnUrlWithdrawResult {
+            receive_payment_response: var_receivePaymentResponse,
+        };
+    }
+}
+
+impl SseDecode for crate::model::LogEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_line = <String>::sse_decode(deserializer);
+        let mut var_level = <String>::sse_decode(deserializer);
+        return crate::model::LogEntry {
+            line: var_line,
+            level: var_level,
+        };
+    }
+}
+
+impl SseDecode for crate::model::Network {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::model::Network::Mainnet,
             1 => crate::model::Network::Testnet,
-            _ => unreachable!("Invalid variant for Network: {}", self),
+            2 => crate::model::Network::Regtest,
+            _ => unreachable!("Invalid variant for Network: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::model::OnchainFeeRate {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_satPerVbyte = <f64>::sse_decode(deserializer);
+                return crate::model::OnchainFeeRate::SatPerVbyte {
+                    sat_per_vbyte: var_satPerVbyte,
+                };
+            }
+            1 => {
+                let mut var_priority = <crate::model::FeePriority>::sse_decode(deserializer);
+                return crate::model::OnchainFeeRate::Priority {
+                    priority: var_priority,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<String>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::model::AssetMetadata> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::model::AssetMetadata>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<bool> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<bool>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<f64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::model::FeeBreakdown> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::model::FeeBreakdown>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::model::OnchainFeeRate> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::model::OnchainFeeRate>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::model::Payment> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::model::Payment>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::model::PaymentMetadata> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::model::PaymentMetadata>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::model::SuccessAction> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::model::SuccessAction>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::model::SuccessActionProcessed> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::model::SuccessActionProcessed>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<Vec<crate::model::AssetMetadata>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Vec<crate::model::AssetMetadata>>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<Vec<crate::model::PaymentState>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Vec<crate::model::PaymentState>>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<Vec<crate::model::PaymentType>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<Vec<crate::model::PaymentType>>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for crate::model::PayOnchainRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_address = <String>::sse_decode(deserializer);
+        let mut var_prepareRes =
+            <crate::model::PreparePayOnchainResponse>::sse_decode(deserializer);
+        return crate::model::PayOnchainRequest {
+            address: var_address,
+            prepare_res: var_prepareRes,
+        };
+    }
+}
+
+impl SseDecode for crate::model::Payment {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_txId = <Option<String>>::sse_decode(deserializer);
+        let mut var_swapId = <Option<String>>::sse_decode(deserializer);
+        let mut var_swapType = <crate::model::PaymentSwapType>::sse_decode(deserializer);
+        let mut var_timestamp = <u32>::sse_decode(deserializer);
+        let mut var_amountSat = <u64>::sse_decode(deserializer);
+        let mut var_feesSat = <u64>::sse_decode(deserializer);
+        let mut var_networkFeesSat = <Option<u64>>::sse_decode(deserializer);
+        let mut var_feeBreakdown = <Option<crate::model::FeeBreakdown>>::sse_decode(deserializer);
+        let mut var_feeRateSatPerVbyte = <Option<f64>>::sse_decode(deserializer);
+        let mut var_preimage = <Option<String>>::sse_decode(deserializer);
+        let mut var_bolt11 = <Option<String>>::sse_decode(deserializer);
+        let mut var_paymentHash = <Option<String>>::sse_decode(deserializer);
+        let mut var_payeePubkey = <Option<String>>::sse_decode(deserializer);
+        let mut var_lockupTxId = <Option<String>>::sse_decode(deserializer);
+        let mut var_claimTxId = <Option<String>>::sse_decode(deserializer);
+        let mut var_refundTxId = <Option<String>>::sse_decode(deserializer);
+        let mut var_refundTxAmountSat = <Option<u64>>::sse_decode(deserializer);
+        let mut var_paymentType = <crate::model::PaymentType>::sse_decode(deserializer);
+        let mut var_status = <crate::model::PaymentState>::sse_decode(deserializer);
+        let mut var_label = <Option<String>>::sse_decode(deserializer);
+        let mut var_description = <Option<String>>::sse_decode(deserializer);
+        let mut var_metadata = <Option<crate::model::PaymentMetadata>>::sse_decode(deserializer);
+        let mut var_assetId = <Option<String>>::sse_decode(deserializer);
+        let mut var_assetAmount = <Option<u64>>::sse_decode(deserializer);
+        return crate::model::Payment {
+            tx_id: var_txId,
+            swap_id: var_swapId,
+            swap_type: var_swapType,
+            timestamp: var_timestamp,
+            amount_sat: var_amountSat,
+            fees_sat: var_feesSat,
+            network_fees_sat: var_networkFeesSat,
+            fee_breakdown: var_feeBreakdown,
+            fee_rate_sat_per_vbyte: var_feeRateSatPerVbyte,
+            preimage: var_preimage,
+            bolt11: var_bolt11,
+            payment_hash: var_paymentHash,
+            payee_pubkey: var_payeePubkey,
+            lockup_tx_id: var_lockupTxId,
+            claim_tx_id: var_claimTxId,
+            refund_tx_id: var_refundTxId,
+            refund_tx_amount_sat: var_refundTxAmountSat,
+            payment_type: var_paymentType,
+            status: var_status,
+            label: var_label,
+            description: var_description,
+            metadata: var_metadata,
+            asset_id: var_assetId,
+            asset_amount: var_assetAmount,
+        };
+    }
+}
+
+impl SseDecode for crate::error::PaymentError {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::error::PaymentError::AlreadyClaimed;
+            }
+            1 => {
+                return crate::error::PaymentError::AlreadyPaid;
+            }
+            2 => {
+                return crate::error::PaymentError::PaymentInProgress;
+            }
+            3 => {
+                return crate::error::PaymentError::AmountOutOfRange;
+            }
+            4 => {
+                let mut var_err = <String>::sse_decode(deserializer);
+                return crate::error::PaymentError::UnrecognizedInput { err: var_err };
+            }
+            5 => {
+                let mut var_err = <String>::sse_decode(deserializer);
+                return crate::error::PaymentError::NetworkMismatch { err: var_err };
+            }
+            6 => {
+                let mut var_err = <String>::sse_decode(deserializer);
+                return crate::error::PaymentError::LnUrlError { err: var_err };
+            }
+            7 => {
+                let mut var_err = <String>::sse_decode(deserializer);
+                return crate::error::PaymentError::LnUrlServiceUnreachable { err: var_err };
+            }
+            8 => {
+                let mut var_err = <String>::sse_decode(deserializer);
+                return crate::error::PaymentError::LnUrlServiceError { err: var_err };
+            }
+            9 => {
+                let mut var_err = <String>::sse_decode(deserializer);
+                return crate::error::PaymentError::Generic { err: var_err };
+            }
+            10 => {
+                return crate::error::PaymentError::InvalidOrExpiredFees;
+            }
+            11 => {
+                let mut var_feeSat = <u64>::sse_decode(deserializer);
+                let mut var_limitSat = <u64>::sse_decode(deserializer);
+                return crate::error::PaymentError::FeeLimitExceeded {
+                    fee_sat: var_feeSat,
+                    limit_sat: var_limitSat,
+                };
+            }
+            12 => {
+                return crate::error::PaymentError::InsufficientFunds;
+            }
+            13 => {
+                let mut var_err = <String>::sse_decode(deserializer);
+                return crate::error::PaymentError::InvalidInvoice { err: var_err };
+            }
+            14 => {
+                return crate::error::PaymentError::InvalidPreimage;
+            }
+            15 => {
+                let mut var_err = <String>::sse_decode(deserializer);
+                return crate::error::PaymentError::LwkError { err: var_err };
+            }
+            16 => {
+                return crate::error::PaymentError::PairsNotFound;
+            }
+            17 => {
+                return crate::error::PaymentError::PaymentTimeout;
+            }
+            18 => {
+                return crate::error::PaymentError::PersistError;
+            }
+            19 => {
+                let mut var_err = <String>::sse_decode(deserializer);
+                return crate::error::PaymentError::ReceiveError { err: var_err };
+            }
+            20 => {
+                let mut var_err = <String>::sse_decode(deserializer);
+                let mut var_refundTxId = <String>::sse_decode(deserializer);
+                return crate::error::PaymentError::Refunded {
+                    err: var_err,
+                    refund_tx_id: var_refundTxId,
+                };
+            }
+            21 => {
+                return crate::error::PaymentError::SelfTransferNotSupported;
+            }
+            22 => {
+                let mut var_err = <String>::sse_decode(deserializer);
+                return crate::error::PaymentError::SendError { err: var_err };
+            }
+            23 => {
+                let mut var_err = <String>::sse_decode(deserializer);
+                return crate::error::PaymentError::SignerError { err: var_err };
+            }
+            _ => {
+                unimplemented!("");
+            }
         }
     }
 }
-impl CstDecode<crate::model::PaymentState> for i32 {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    fn cst_decode(self) -> crate::model::PaymentState {
-        match self {
-            0 => crate::model::PaymentState::Created,
-            1 => crate::model::PaymentState::Pending,
-            2 => crate::model::PaymentState::Complete,
-            3 => crate::model::PaymentState::Failed,
-            4 => crate::model::PaymentState::TimedOut,
-            _ => unreachable!("Invalid variant for PaymentState: {}", self),
+
+impl SseDecode for crate::model::PaymentExportFormat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::model::PaymentExportFormat::Csv,
+            1 => crate::model::PaymentExportFormat::Json,
+            _ => unreachable!("Invalid variant for PaymentExportFormat: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::model::PaymentMetadata {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_label = <Option<String>>::sse_decode(deserializer);
+        let mut var_note = <Option<String>>::sse_decode(deserializer);
+        let mut var_externalId = <Option<String>>::sse_decode(deserializer);
+        return crate::model::PaymentMetadata {
+            label: var_label,
+            note: var_note,
+            external_id: var_externalId,
+        };
+    }
+}
+
+impl SseDecode for crate::model::PaymentState {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::model::PaymentState::Created,
+            1 => crate::model::PaymentState::Pending,
+            2 => crate::model::PaymentState::Complete,
+            3 => crate::model::PaymentState::Failed,
+            4 => crate::model::PaymentState::TimedOut,
+            5 => crate::model::PaymentState::Refundable,
+            _ => unreachable!("Invalid variant for PaymentState: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::model::PaymentSwapType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::model::PaymentSwapType::Submarine,
+            1 => crate::model::PaymentSwapType::Reverse,
+            2 => crate::model::PaymentSwapType::Chain,
+            3 => crate::model::PaymentSwapType::Direct,
+            _ => unreachable!("Invalid variant for PaymentSwapType: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::model::PaymentType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::model::PaymentType::Receive,
+            1 => crate::model::PaymentType::Send,
+            _ => unreachable!("Invalid variant for PaymentType: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::model::PrepareLnUrlPayRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_lnurl = <String>::sse_decode(deserializer);
+        let mut var_amountSat = <u64>::sse_decode(deserializer);
+        let mut var_comment = <Option<String>>::sse_decode(deserializer);
+        return crate::model::PrepareLnUrlPayRequest {
+            lnurl: var_lnurl,
+            amount_sat: var_amountSat,
+            comment: var_comment,
+        };
+    }
+}
+
+impl SseDecode for crate::model::PrepareLnUrlPayResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_prepareSendResponse =
+            <crate::model::PrepareSendResponse>::sse_decode(deserializer);
+        let mut var_successAction = <Option<crate::model::SuccessAction>>::sse_decode(deserializer);
+        return crate::model::PrepareLnUrlPayResponse {
+            prepare_send_response: var_prepareSendResponse,
+            success_action: var_successAction,
+        };
+    }
+}
+
+impl SseDecode for crate::model::PreparePayOnchainRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_receiverAmountSat = <u64>::sse_decode(deserializer);
+        let mut var_feeRate = <Option<crate::model::OnchainFeeRate>>::sse_decode(deserializer);
+        return crate::model::PreparePayOnchainRequest {
+            receiver_amount_sat: var_receiverAmountSat,
+            fee_rate: var_feeRate,
+        };
+    }
+}
+
+impl SseDecode for crate::model::PreparePayOnchainResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_receiverAmountSat = <u64>::sse_decode(deserializer);
+        let mut var_feesSat = <u64>::sse_decode(deserializer);
+        let mut var_feeRateSatPerVbyte = <Option<f64>>::sse_decode(deserializer);
+        return crate::model::PreparePayOnchainResponse {
+            receiver_amount_sat: var_receiverAmountSat,
+            fees_sat: var_feesSat,
+            fee_rate_sat_per_vbyte: var_feeRateSatPerVbyte,
+        };
+    }
+}
+
+impl SseDecode for crate::model::PrepareReceiveOnchainRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_payerAmountSat = <u64>::sse_decode(deserializer);
+        let mut var_feeRate = <Option<crate::model::OnchainFeeRate>>::sse_decode(deserializer);
+        return crate::model::PrepareReceiveOnchainRequest {
+            payer_amount_sat: var_payerAmountSat,
+            fee_rate: var_feeRate,
+        };
+    }
+}
+
+impl SseDecode for crate::model::PrepareReceiveOnchainResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_payerAmountSat = <u64>::sse_decode(deserializer);
+        let mut var_feesSat = <u64>::sse_decode(deserializer);
+        let mut var_feeRateSatPerVbyte = <Option<f64>>::sse_decode(deserializer);
+        return crate::model::PrepareReceiveOnchainResponse {
+            payer_amount_sat: var_payerAmountSat,
+            fees_sat: var_feesSat,
+            fee_rate_sat_per_vbyte: var_feeRateSatPerVbyte,
+        };
+    }
+}
+
+impl SseDecode for crate::model::PrepareReceiveRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_payerAmountSat = <Option<u64>>::sse_decode(deserializer);
+        let mut var_receiverAmountSat = <Option<u64>>::sse_decode(deserializer);
+        let mut var_description = <Option<String>>::sse_decode(deserializer);
+        let mut var_descriptionHash = <Option<String>>::sse_decode(deserializer);
+        let mut var_invoiceExpirySecs = <Option<u64>>::sse_decode(deserializer);
+        let mut var_metadata = <Option<crate::model::PaymentMetadata>>::sse_decode(deserializer);
+        let mut var_maxFeeSat = <Option<u64>>::sse_decode(deserializer);
+        let mut var_maxFeePercent = <Option<f64>>::sse_decode(deserializer);
+        let mut var_feeRate = <Option<crate::model::OnchainFeeRate>>::sse_decode(deserializer);
+        return crate::model::PrepareReceiveRequest {
+            payer_amount_sat: var_payerAmountSat,
+            receiver_amount_sat: var_receiverAmountSat,
+            description: var_description,
+            description_hash: var_descriptionHash,
+            invoice_expiry_secs: var_invoiceExpirySecs,
+            metadata: var_metadata,
+            max_fee_sat: var_maxFeeSat,
+            max_fee_percent: var_maxFeePercent,
+            fee_rate: var_feeRate,
+        };
+    }
+}
+
+impl SseDecode for crate::model::PrepareReceiveResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_payerAmountSat = <u64>::sse_decode(deserializer);
+        let mut var_receiverAmountSat = <u64>::sse_decode(deserializer);
+        let mut var_feesSat = <u64>::sse_decode(deserializer);
+        let mut var_description = <Option<String>>::sse_decode(deserializer);
+        let mut var_descriptionHash = <Option<String>>::sse_decode(deserializer);
+        let mut var_invoiceExpirySecs = <Option<u64>>::sse_decode(deserializer);
+        let mut var_metadata = <Option<crate::model::PaymentMetadata>>::sse_decode(deserializer);
+        let mut var_feeBreakdown = <crate::model::FeeBreakdown>::sse_decode(deserializer);
+        let mut var_pairHash = <String>::sse_decode(deserializer);
+        let mut var_expiresAt = <u32>::sse_decode(deserializer);
+        let mut var_feeRateSatPerVbyte = <Option<f64>>::sse_decode(deserializer);
+        return crate::model::PrepareReceiveResponse {
+            payer_amount_sat: var_payerAmountSat,
+            receiver_amount_sat: var_receiverAmountSat,
+            fees_sat: var_feesSat,
+            description: var_description,
+            description_hash: var_descriptionHash,
+            invoice_expiry_secs: var_invoiceExpirySecs,
+            metadata: var_metadata,
+            fee_breakdown: var_feeBreakdown,
+            pair_hash: var_pairHash,
+            expires_at: var_expiresAt,
+            fee_rate_sat_per_vbyte: var_feeRateSatPerVbyte,
+        };
+    }
+}
+
+impl SseDecode for crate::model::PrepareRefundRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_swapId = <String>::sse_decode(deserializer);
+        let mut var_refundAddress = <String>::sse_decode(deserializer);
+        let mut var_feeRate = <crate::model::OnchainFeeRate>::sse_decode(deserializer);
+        return crate::model::PrepareRefundRequest {
+            swap_id: var_swapId,
+            refund_address: var_refundAddress,
+            fee_rate: var_feeRate,
+        };
+    }
+}
+
+impl SseDecode for crate::model::PrepareRefundResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_txVsize = <u32>::sse_decode(deserializer);
+        let mut var_txFeeSat = <u64>::sse_decode(deserializer);
+        let mut var_refundAmountSat = <u64>::sse_decode(deserializer);
+        let mut var_feeRateSatPerVbyte = <f64>::sse_decode(deserializer);
+        return crate::model::PrepareRefundResponse {
+            tx_vsize: var_txVsize,
+            tx_fee_sat: var_txFeeSat,
+            refund_amount_sat: var_refundAmountSat,
+            fee_rate_sat_per_vbyte: var_feeRateSatPerVbyte,
+        };
+    }
+}
+
+impl SseDecode for crate::model::PrepareSendOnchainRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_destination = <String>::sse_decode(deserializer);
+        let mut var_amountSat = <Option<u64>>::sse_decode(deserializer);
+        let mut var_sendAll = <bool>::sse_decode(deserializer);
+        let mut var_assetId = <Option<String>>::sse_decode(deserializer);
+        let mut var_feeRate = <Option<crate::model::OnchainFeeRate>>::sse_decode(deserializer);
+        return crate::model::PrepareSendOnchainRequest {
+            destination: var_destination,
+            amount_sat: var_amountSat,
+            send_all: var_sendAll,
+            asset_id: var_assetId,
+            fee_rate: var_feeRate,
+        };
+    }
+}
+
+impl SseDecode for crate::model::PrepareSendOnchainResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_address = <String>::sse_decode(deserializer);
+        let mut var_receiverAmountSat = <u64>::sse_decode(deserializer);
+        let mut var_feesSat = <u64>::sse_decode(deserializer);
+        let mut var_sendAll = <bool>::sse_decode(deserializer);
+        let mut var_assetId = <Option<String>>::sse_decode(deserializer);
+        let mut var_feeRateSatPerVbyte = <Option<f64>>::sse_decode(deserializer);
+        return crate::model::PrepareSendOnchainResponse {
+            address: var_address,
+            receiver_amount_sat: var_receiverAmountSat,
+            fees_sat: var_feesSat,
+            send_all: var_sendAll,
+            asset_id: var_assetId,
+            fee_rate_sat_per_vbyte: var_feeRateSatPerVbyte,
+        };
+    }
+}
+
+impl SseDecode for crate::model::PrepareSendRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_invoice = <String>::sse_decode(deserializer);
+        let mut var_metadata = <Option<crate::model::PaymentMetadata>>::sse_decode(deserializer);
+        let mut var_maxFeeSat = <Option<u64>>::sse_decode(deserializer);
+        let mut var_maxFeePercent = <Option<f64>>::sse_decode(deserializer);
+        let mut var_feeRate = <Option<crate::model::OnchainFeeRate>>::sse_decode(deserializer);
+        return crate::model::PrepareSendRequest {
+            invoice: var_invoice,
+            metadata: var_metadata,
+            max_fee_sat: var_maxFeeSat,
+            max_fee_percent: var_maxFeePercent,
+            fee_rate: var_feeRate,
+        };
+    }
+}
+
+impl SseDecode for crate::model::PrepareSendResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_invoice = <String>::sse_decode(deserializer);
+        let mut var_feesSat = <u64>::sse_decode(deserializer);
+        let mut var_metadata = <Option<crate::model::PaymentMetadata>>::sse_decode(deserializer);
+        let mut var_feeBreakdown = <crate::model::FeeBreakdown>::sse_decode(deserializer);
+        let mut var_maxFeeSat = <Option<u64>>::sse_decode(deserializer);
+        let mut var_maxFeePercent = <Option<f64>>::sse_decode(deserializer);
+        let mut var_pairHash = <String>::sse_decode(deserializer);
+        let mut var_expiresAt = <u32>::sse_decode(deserializer);
+        let mut var_feeRateSatPerVbyte = <Option<f64>>::sse_decode(deserializer);
+        return crate::model::PrepareSendResponse {
+            invoice: var_invoice,
+            fees_sat: var_feesSat,
+            metadata: var_metadata,
+            fee_breakdown: var_feeBreakdown,
+            max_fee_sat: var_maxFeeSat,
+            max_fee_percent: var_maxFeePercent,
+            pair_hash: var_pairHash,
+            expires_at: var_expiresAt,
+            fee_rate_sat_per_vbyte: var_feeRateSatPerVbyte,
+        };
+    }
+}
+
+impl SseDecode for crate::model::ReceiveLiquidRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_amountSat = <Option<u64>>::sse_decode(deserializer);
+        let mut var_label = <Option<String>>::sse_decode(deserializer);
+        return crate::model::ReceiveLiquidRequest {
+            amount_sat: var_amountSat,
+            label: var_label,
+        };
+    }
+}
+
+impl SseDecode for crate::model::ReceiveLiquidResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_address = <String>::sse_decode(deserializer);
+        let mut var_bip21 = <String>::sse_decode(deserializer);
+        return crate::model::ReceiveLiquidResponse {
+            address: var_address,
+            bip21: var_bip21,
+        };
+    }
+}
+
+impl SseDecode for crate::model::ReceiveOnchainResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_address = <String>::sse_decode(deserializer);
+        let mut var_bip21 = <String>::sse_decode(deserializer);
+        return crate::model::ReceiveOnchainResponse {
+            id: var_id,
+            address: var_address,
+            bip21: var_bip21,
+        };
+    }
+}
+
+impl SseDecode for crate::model::ReceivePaymentResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_invoice = <String>::sse_decode(deserializer);
+        return crate::model::ReceivePaymentResponse {
+            id: var_id,
+            invoice: var_invoice,
+        };
+    }
+}
+
+impl SseDecode for crate::model::RecommendedFees {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_fastestFee = <f64>::sse_decode(deserializer);
+        let mut var_halfHourFee = <f64>::sse_decode(deserializer);
+        let mut var_hourFee = <f64>::sse_decode(deserializer);
+        let mut var_economyFee = <f64>::sse_decode(deserializer);
+        let mut var_minimumFee = <f64>::sse_decode(deserializer);
+        return crate::model::RecommendedFees {
+            fastest_fee: var_fastestFee,
+            half_hour_fee: var_halfHourFee,
+            hour_fee: var_hourFee,
+            economy_fee: var_economyFee,
+            minimum_fee: var_minimumFee,
+        };
+    }
+}
+
+impl SseDecode for crate::model::RecommendedFeesResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_liquid = <crate::model::RecommendedFees>::sse_decode(deserializer);
+        let mut var_bitcoin = <crate::model::RecommendedFees>::sse_decode(deserializer);
+        return crate::model::RecommendedFeesResponse {
+            liquid: var_liquid,
+            bitcoin: var_bitcoin,
+        };
+    }
+}
+
+impl SseDecode for crate::model::RefundRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_swapId = <String>::sse_decode(deserializer);
+        let mut var_refundAddress = <String>::sse_decode(deserializer);
+        let mut var_feeRateSatPerVbyte = <f64>::sse_decode(deserializer);
+        return crate::model::RefundRequest {
+            swap_id: var_swapId,
+            refund_address: var_refundAddress,
+            fee_rate_sat_per_vbyte: var_feeRateSatPerVbyte,
+        };
+    }
+}
+
+impl SseDecode for crate::model::RefundResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_refundTxId = <String>::sse_decode(deserializer);
+        return crate::model::RefundResponse {
+            refund_tx_id: var_refundTxId,
+        };
+    }
+}
+
+impl SseDecode for crate::model::RefundableSwap {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_swapId = <String>::sse_decode(deserializer);
+        let mut var_swapAddress = <String>::sse_decode(deserializer);
+        let mut var_timestamp = <u32>::sse_decode(deserializer);
+        let mut var_amountSat = <u64>::sse_decode(deserializer);
+        let mut var_paymentType = <crate::model::PaymentType>::sse_decode(deserializer);
+        return crate::model::RefundableSwap {
+            swap_id: var_swapId,
+            swap_address: var_swapAddress,
+            timestamp: var_timestamp,
+            amount_sat: var_amountSat,
+            payment_type: var_paymentType,
+        };
+    }
+}
+
+impl SseDecode for crate::model::RestoreRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_backupPath = <Option<String>>::sse_decode(deserializer);
+        return crate::model::RestoreRequest {
+            backup_path: var_backupPath,
+        };
+    }
+}
+
+impl SseDecode for crate::model::RouteHint {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_hops = <Vec<crate::model::RouteHintHop>>::sse_decode(deserializer);
+        return crate::model::RouteHint { hops: var_hops };
+    }
+}
+
+impl SseDecode for crate::model::RouteHintHop {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_srcNodeId = <String>::sse_decode(deserializer);
+        let mut var_shortChannelId = <u64>::sse_decode(deserializer);
+        let mut var_feesBaseMsat = <u32>::sse_decode(deserializer);
+        let mut var_feesProportionalMillionths = <u32>::sse_decode(deserializer);
+        let mut var_cltvExpiryDelta = <u64>::sse_decode(deserializer);
+        let mut var_htlcMinimumMsat = <Option<u64>>::sse_decode(deserializer);
+        let mut var_htlcMaximumMsat = <Option<u64>>::sse_decode(deserializer);
+        return crate::model::RouteHintHop {
+            src_node_id: var_srcNodeId,
+            short_channel_id: var_shortChannelId,
+            fees_base_msat: var_feesBaseMsat,
+            fees_proportional_millionths: var_feesProportionalMillionths,
+            cltv_expiry_delta: var_cltvExpiryDelta,
+            htlc_minimum_msat: var_htlcMinimumMsat,
+            htlc_maximum_msat: var_htlcMaximumMsat,
+        };
+    }
+}
+
+impl SseDecode for crate::model::SendPaymentResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_payment = <crate::model::Payment>::sse_decode(deserializer);
+        return crate::model::SendPaymentResponse {
+            payment: var_payment,
+        };
+    }
+}
+
+impl SseDecode for crate::model::SetPaymentMetadataRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_metadata = <crate::model::PaymentMetadata>::sse_decode(deserializer);
+        return crate::model::SetPaymentMetadataRequest {
+            id: var_id,
+            metadata: var_metadata,
+        };
+    }
+}
+
+impl SseDecode for crate::model::SuccessAction {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::model::SuccessAction::Message {
+                    message: var_message,
+                };
+            }
+            1 => {
+                let mut var_description = <String>::sse_decode(deserializer);
+                let mut var_url = <String>::sse_decode(deserializer);
+                return crate::model::SuccessAction::Url {
+                    description: var_description,
+                    url: var_url,
+                };
+            }
+            2 => {
+                let mut var_description = <String>::sse_decode(deserializer);
+                let mut var_ciphertext = <String>::sse_decode(deserializer);
+                let mut var_iv = <String>::sse_decode(deserializer);
+                return crate::model::SuccessAction::Aes {
+                    description: var_description,
+                    ciphertext: var_ciphertext,
+                    iv: var_iv,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
         }
     }
 }
-impl CstDecode<crate::model::PaymentType> for i32 {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    fn cst_decode(self) -> crate::model::PaymentType {
-        match self {
-            0 => crate::model::PaymentType::Receive,
-            1 => crate::model::PaymentType::Send,
-            _ => unreachable!("Invalid variant for PaymentType: {}", self),
+
+impl SseDecode for crate::model::SuccessActionProcessed {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_message = <String>::sse_decode(deserializer);
+                return crate::model::SuccessActionProcessed::Message {
+                    message: var_message,
+                };
+            }
+            1 => {
+                let mut var_description = <String>::sse_decode(deserializer);
+                let mut var_url = <String>::sse_decode(deserializer);
+                return crate::model::SuccessActionProcessed::Url {
+                    description: var_description,
+                    url: var_url,
+                };
+            }
+            2 => {
+                let mut var_description = <String>::sse_decode(deserializer);
+                let mut var_plaintext = <String>::sse_decode(deserializer);
+                return crate::model::SuccessActionProcessed::Aes {
+                    description: var_description,
+                    plaintext: var_plaintext,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
         }
     }
 }
-impl CstDecode<u32> for u32 {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    fn cst_decode(self) -> u32 {
+
+impl SseDecode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u8 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap()
+    }
+}
+
+impl SseDecode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+}
+
+impl SseDecode for usize {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+    }
+}
+
+impl SseDecode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap() != 0
+    }
+}
+
+fn pde_ffi_dispatcher_primary_impl(
+    func_id: i32,
+    port: flutter_rust_bridge::for_generated::MessagePort,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        _ => unreachable!(),
+    }
+}
+
+fn pde_ffi_dispatcher_sync_impl(
+    func_id: i32,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        _ => unreachable!(),
+    }
+}
+
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for FrbWrapper<BindingLiquidSdk> {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, StdArc<_>>(self.0)
+            .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<BindingLiquidSdk> {}
+
+impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<BindingLiquidSdk>> for BindingLiquidSdk {
+    fn into_into_dart(self) -> FrbWrapper<BindingLiquidSdk> {
+        self.into()
+    }
+}
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::AssetBalance {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.asset_id.into_into_dart().into_dart(),
+            self.balance.into_into_dart().into_dart(),
+            self.asset_metadata.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::AssetBalance {}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::AssetBalance> for crate::model::AssetBalance {
+    fn into_into_dart(self) -> crate::model::AssetBalance {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::AssetMetadata {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.asset_id.into_into_dart().into_dart(),
+            self.ticker.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.precision.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::AssetMetadata {}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::AssetMetadata>
+    for crate::model::AssetMetadata
+{
+    fn into_into_dart(self) -> crate::model::AssetMetadata {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::BackupRequest {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [self.backup_path.into_into_dart().into_dart()].into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::BackupRequest {}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::BackupRequest>
+    for crate::model::BackupRequest
+{
+    fn into_into_dart(self) -> crate::model::BackupRequest {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::bindings::BindingEventListener {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [self.stream.into_into_dart().into_dart()].into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::bindings::BindingEventListener
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::bindings::BindingEventListener>
+    for crate::bindings::BindingEventListener
+{
+    fn into_into_dart(self) -> crate::bindings::BindingEventListener {
         self
     }
 }
-impl CstDecode<u64> for u64 {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    fn cst_decode(self) -> u64 {
-        self
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::BitcoinAddressData {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.address.into_into_dart().into_dart(),
+            self.network.into_into_dart().into_dart(),
+            self.amount_sat.into_into_dart().into_dart(),
+            self.label.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-impl CstDecode<u8> for u8 {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    fn cst_decode(self) -> u8 {
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::model::BitcoinAddressData
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::BitcoinAddressData>
+    for crate::model::BitcoinAddressData
+{
+    fn into_into_dart(self) -> crate::model::BitcoinAddressData {
         self
     }
 }
-impl CstDecode<usize> for usize {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    fn cst_decode(self) -> usize {
-        self
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::Config {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.boltz_url.into_into_dart().into_dart(),
+            self.electrum_servers.into_into_dart().into_dart(),
+            self.electrum_url.into_into_dart().into_dart(),
+            self.bitcoin_electrum_url.into_into_dart().into_dart(),
+            self.esplora_url.into_into_dart().into_dart(),
+            self.working_dir.into_into_dart().into_dart(),
+            self.network.into_into_dart().into_dart(),
+            self.payment_timeout_sec.into_into_dart().into_dart(),
+            self.sync_fallback_interval_sec.into_into_dart().into_dart(),
+            self.zero_conf_min_fee_rate.into_into_dart().into_dart(),
+            self.zero_conf_max_amount_sat.into_into_dart().into_dart(),
+            self.asset_metadata.into_into_dart().into_dart(),
+            self.max_fee_sat.into_into_dart().into_dart(),
+            self.max_fee_percent.into_into_dart().into_dart(),
+            self.regtest_policy_asset.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::Config {}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::Config> for crate::model::Config {
+    fn into_into_dart(self) -> crate::model::Config {
+        self
     }
 }
-
-impl SseDecode for BindingLiquidSdk {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <RustOpaqueNom<
-            flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>,
-        >>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::rust_auto_opaque_decode_owned(inner);
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::ConnectRequest {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.mnemonic.into_into_dart().into_dart(),
+            self.config.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode
-    for RustOpaqueNom<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BindingLiquidSdk>>
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::ConnectRequest {}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::ConnectRequest>
+    for crate::model::ConnectRequest
 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <usize>::sse_decode(deserializer);
-        return unsafe { decode_rust_opaque_nom(inner) };
+    fn into_into_dart(self) -> crate::model::ConnectRequest {
+        self
     }
 }
-
-impl SseDecode
-    for StreamSink<crate::model::LiquidSdkEvent, flutter_rust_bridge::for_generated::DcoCodec>
-{
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return StreamSink::deserialize(inner);
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::ElectrumServer {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.url.into_into_dart().into_dart(),
+            self.tls.into_into_dart().into_dart(),
+            self.validate_domain.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode
-    for StreamSink<crate::model::LogEntry, flutter_rust_bridge::for_generated::DcoCodec>
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::ElectrumServer {}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::ElectrumServer>
+    for crate::model::ElectrumServer
 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return StreamSink::deserialize(inner);
+    fn into_into_dart(self) -> crate::model::ElectrumServer {
+        self
     }
 }
-
-impl SseDecode for String {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return String::from_utf8(inner).unwrap();
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::ElectrumServerStatus {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.url.into_into_dart().into_dart(),
+            self.in_use.into_into_dart().into_dart(),
+            self.healthy.into_into_dart().into_dart(),
+            self.consecutive_failures.into_into_dart().into_dart(),
+            self.last_error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for crate::model::BackupRequest {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_backupPath = <Option<String>>::sse_decode(deserializer);
-        return crate::model::BackupRequest {
-            backup_path: var_backupPath,
-        };
-    }
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::model::ElectrumServerStatus
+{
 }
-
-impl SseDecode for crate::bindings::BindingEventListener {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_stream = <StreamSink<
-            crate::model::LiquidSdkEvent,
-            flutter_rust_bridge::for_generated::DcoCodec,
-        >>::sse_decode(deserializer);
-        return crate::bindings::BindingEventListener { stream: var_stream };
+impl flutter_rust_bridge::IntoIntoDart<crate::model::ElectrumServerStatus>
+    for crate::model::ElectrumServerStatus
+{
+    fn into_into_dart(self) -> crate::model::ElectrumServerStatus {
+        self
     }
 }
-
-impl SseDecode for crate::model::Config {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_boltzUrl = <String>::sse_decode(deserializer);
-        let mut var_electrumUrl = <String>::sse_decode(deserializer);
-        let mut var_workingDir = <String>::sse_decode(deserializer);
-        let mut var_network = <crate::model::Network>::sse_decode(deserializer);
-        let mut var_paymentTimeoutSec = <u64>::sse_decode(deserializer);
-        let mut var_zeroConfMinFeeRate = <f32>::sse_decode(deserializer);
-        let mut var_zeroConfMaxAmountSat = <Option<u64>>::sse_decode(deserializer);
-        return crate::model::Config {
-            boltz_url: var_boltzUrl,
-            electrum_url: var_electrumUrl,
-            working_dir: var_workingDir,
-            network: var_network,
-            payment_timeout_sec: var_paymentTimeoutSec,
-            zero_conf_min_fee_rate: var_zeroConfMinFeeRate,
-            zero_conf_max_amount_sat: var_zeroConfMaxAmountSat,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::ExportPaymentsRequest {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.format.into_into_dart().into_dart(),
+            self.filter.into_into_dart().into_dart(),
+            self.path.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for crate::model::ConnectRequest {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_mnemonic = <String>::sse_decode(deserializer);
-        let mut var_config = <crate::model::Config>::sse_decode(deserializer);
-        return crate::model::ConnectRequest {
-            mnemonic: var_mnemonic,
-            config: var_config,
-        };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::model::ExportPaymentsRequest
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::ExportPaymentsRequest>
+    for crate::model::ExportPaymentsRequest
+{
+    fn into_into_dart(self) -> crate::model::ExportPaymentsRequest {
+        self
     }
 }
-
-impl SseDecode for f32 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_f32::<NativeEndian>().unwrap()
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::ExportPaymentsResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [self.payments_count.into_into_dart().into_dart()].into_dart()
     }
 }
-
-impl SseDecode for crate::model::GetInfoResponse {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_balanceSat = <u64>::sse_decode(deserializer);
-        let mut var_pendingSendSat = <u64>::sse_decode(deserializer);
-        let mut var_pendingReceiveSat = <u64>::sse_decode(deserializer);
-        let mut var_pubkey = <String>::sse_decode(deserializer);
-        return crate::model::GetInfoResponse {
-            balance_sat: var_balanceSat,
-            pending_send_sat: var_pendingSendSat,
-            pending_receive_sat: var_pendingReceiveSat,
-            pubkey: var_pubkey,
-        };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::model::ExportPaymentsResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::ExportPaymentsResponse>
+    for crate::model::ExportPaymentsResponse
+{
+    fn into_into_dart(self) -> crate::model::ExportPaymentsResponse {
+        self
     }
 }
-
-impl SseDecode for i32 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::FeeBreakdown {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.service_fee_percentage.into_into_dart().into_dart(),
+            self.service_fee_sat.into_into_dart().into_dart(),
+            self.swapper_miner_fees_sat.into_into_dart().into_dart(),
+            self.network_fee_sat.into_into_dart().into_dart(),
+            self.total_fees_sat.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for crate::error::LiquidSdkError {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut tag_ = <i32>::sse_decode(deserializer);
-        match tag_ {
-            0 => {
-                return crate::error::LiquidSdkError::AlreadyStarted;
-            }
-            1 => {
-                let mut var_err = <String>::sse_decode(deserializer);
-                return crate::error::LiquidSdkError::Generic { err: var_err };
-            }
-            2 => {
-                return crate::error::LiquidSdkError::NotStarted;
-            }
-            _ => {
-                unimplemented!("");
-            }
-        }
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::FeeBreakdown {}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::FeeBreakdown> for crate::model::FeeBreakdown {
+    fn into_into_dart(self) -> crate::model::FeeBreakdown {
+        self
     }
 }
-
-impl SseDecode for crate::model::LiquidSdkEvent {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut tag_ = <i32>::sse_decode(deserializer);
-        match tag_ {
-            0 => {
-                let mut var_details = <crate::model::Payment>::sse_decode(deserializer);
-                return crate::model::LiquidSdkEvent::PaymentFailed {
-                    details: var_details,
-                };
-            }
-            1 => {
-                let mut var_details = <crate::model::Payment>::sse_decode(deserializer);
-                return crate::model::LiquidSdkEvent::PaymentPending {
-                    details: var_details,
-                };
-            }
-            2 => {
-                let mut var_details = <crate::model::Payment>::sse_decode(deserializer);
-                return crate::model::LiquidSdkEvent::PaymentRefunded {
-                    details: var_details,
-                };
-            }
-            3 => {
-                let mut var_details = <crate::model::Payment>::sse_decode(deserializer);
-                return crate::model::LiquidSdkEvent::PaymentRefundPending {
-                    details: var_details,
-                };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::FeePriority {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Fastest => 0.into_dart(),
+            Self::HalfHour => 1.into_dart(),
+            Self::Hour => 2.into_dart(),
+            Self::Economy => 3.into_dart(),
+            Self::Minimum => 4.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::FeePriority {}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::FeePriority> for crate::model::FeePriority {
+    fn into_into_dart(self) -> crate::model::FeePriority {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::GetInfoResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.balance_sat.into_into_dart().into_dart(),
+            self.pending_send_sat.into_into_dart().into_dart(),
+            self.pending_receive_sat.into_into_dart().into_dart(),
+            self.pubkey.into_into_dart().into_dart(),
+            self.asset_balances.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::GetInfoResponse {}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::GetInfoResponse>
+    for crate::model::GetInfoResponse
+{
+    fn into_into_dart(self) -> crate::model::GetInfoResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::GetPaymentRequest {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::model::GetPaymentRequest::TxId { tx_id } => {
+                [0.into_dart(), tx_id.into_into_dart().into_dart()].into_dart()
             }
-            4 => {
-                let mut var_details = <crate::model::Payment>::sse_decode(deserializer);
-                return crate::model::LiquidSdkEvent::PaymentSucceeded {
-                    details: var_details,
-                };
+            crate::model::GetPaymentRequest::SwapId { swap_id } => {
+                [1.into_dart(), swap_id.into_into_dart().into_dart()].into_dart()
             }
-            5 => {
-                let mut var_details = <crate::model::Payment>::sse_decode(deserializer);
-                return crate::model::LiquidSdkEvent::PaymentWaitingConfirmation {
-                    details: var_details,
-                };
+            crate::model::GetPaymentRequest::Bolt11 { bolt11 } => {
+                [2.into_dart(), bolt11.into_into_dart().into_dart()].into_dart()
             }
-            6 => {
-                return crate::model::LiquidSdkEvent::Synced;
+            crate::model::GetPaymentRequest::PaymentHash { payment_hash } => {
+                [3.into_dart(), payment_hash.into_into_dart().into_dart()].into_dart()
             }
             _ => {
                 unimplemented!("");
@@ -915,846 +3698,969 @@ impl SseDecode for crate::model::LiquidSdkEvent {
         }
     }
 }
-
-impl SseDecode for Vec<crate::model::Payment> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::model::Payment>::sse_decode(deserializer));
-        }
-        return ans_;
-    }
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::model::GetPaymentRequest
+{
 }
-
-impl SseDecode for Vec<u8> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
-        }
-        return ans_;
+impl flutter_rust_bridge::IntoIntoDart<crate::model::GetPaymentRequest>
+    for crate::model::GetPaymentRequest
+{
+    fn into_into_dart(self) -> crate::model::GetPaymentRequest {
+        self
     }
 }
-
-impl SseDecode for Vec<crate::model::RouteHint> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::model::RouteHint>::sse_decode(deserializer));
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::InputType {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::model::InputType::Bolt11 { invoice } => {
+                [0.into_dart(), invoice.into_into_dart().into_dart()].into_dart()
+            }
+            crate::model::InputType::LiquidAddress { address } => {
+                [1.into_dart(), address.into_into_dart().into_dart()].into_dart()
+            }
+            crate::model::InputType::BitcoinAddress { address } => {
+                [2.into_dart(), address.into_into_dart().into_dart()].into_dart()
+            }
+            crate::model::InputType::LnUrl { url } => {
+                [3.into_dart(), url.into_into_dart().into_dart()].into_dart()
+            }
+            crate::model::InputType::LightningAddress { address, url } => [
+                4.into_dart(),
+                address.into_into_dart().into_dart(),
+                url.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            _ => {
+                unimplemented!("");
+            }
         }
-        return ans_;
     }
 }
-
-impl SseDecode for Vec<crate::model::RouteHintHop> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::model::RouteHintHop>::sse_decode(deserializer));
-        }
-        return ans_;
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::InputType {}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::InputType> for crate::model::InputType {
+    fn into_into_dart(self) -> crate::model::InputType {
+        self
     }
 }
-
-impl SseDecode for crate::model::LNInvoice {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_bolt11 = <String>::sse_decode(deserializer);
-        let mut var_network = <crate::model::Network>::sse_decode(deserializer);
-        let mut var_payeePubkey = <String>::sse_decode(deserializer);
-        let mut var_paymentHash = <String>::sse_decode(deserializer);
-        let mut var_description = <Option<String>>::sse_decode(deserializer);
-        let mut var_descriptionHash = <Option<String>>::sse_decode(deserializer);
-        let mut var_amountMsat = <Option<u64>>::sse_decode(deserializer);
-        let mut var_timestamp = <u64>::sse_decode(deserializer);
-        let mut var_expiry = <u64>::sse_decode(deserializer);
-        let mut var_routingHints = <Vec<crate::model::RouteHint>>::sse_decode(deserializer);
-        let mut var_paymentSecret = <Vec<u8>>::sse_decode(deserializer);
-        let mut var_minFinalCltvExpiryDelta = <u64>::sse_decode(deserializer);
-        return crate::model::LNInvoice {
-            bolt11: var_bolt11,
-            network: var_network,
-            payee_pubkey: var_payeePubkey,
-            payment_hash: var_paymentHash,
-            description: var_description,
-            description_hash: var_descriptionHash,
-            amount_msat: var_amountMsat,
-            timestamp: var_timestamp,
-            expiry: var_expiry,
-            routing_hints: var_routingHints,
-            payment_secret: var_paymentSecret,
-            min_final_cltv_expiry_delta: var_minFinalCltvExpiryDelta,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::LightningLimits {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.min_sat.into_into_dart().into_dart(),
+            self.max_sat.into_into_dart().into_dart(),
+            self.service_fee_percentage.into_into_dart().into_dart(),
+            self.miner_fees_sat.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for crate::model::LogEntry {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_line = <String>::sse_decode(deserializer);
-        let mut var_level = <String>::sse_decode(deserializer);
-        return crate::model::LogEntry {
-            line: var_line,
-            level: var_level,
-        };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::LightningLimits {}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::LightningLimits>
+    for crate::model::LightningLimits
+{
+    fn into_into_dart(self) -> crate::model::LightningLimits {
+        self
     }
 }
-
-impl SseDecode for crate::model::Network {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <i32>::sse_decode(deserializer);
-        return match inner {
-            0 => crate::model::Network::Mainnet,
-            1 => crate::model::Network::Testnet,
-            _ => unreachable!("Invalid variant for Network: {}", inner),
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::LightningPaymentLimitsResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.send.into_into_dart().into_dart(),
+            self.receive.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for Option<String> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<String>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
-    }
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::model::LightningPaymentLimitsResponse
+{
 }
-
-impl SseDecode for Option<u64> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<u64>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+impl flutter_rust_bridge::IntoIntoDart<crate::model::LightningPaymentLimitsResponse>
+    for crate::model::LightningPaymentLimitsResponse
+{
+    fn into_into_dart(self) -> crate::model::LightningPaymentLimitsResponse {
+        self
     }
 }
-
-impl SseDecode for crate::model::Payment {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_txId = <Option<String>>::sse_decode(deserializer);
-        let mut var_swapId = <Option<String>>::sse_decode(deserializer);
-        let mut var_timestamp = <u32>::sse_decode(deserializer);
-        let mut var_amountSat = <u64>::sse_decode(deserializer);
-        let mut var_feesSat = <u64>::sse_decode(deserializer);
-        let mut var_preimage = <Option<String>>::sse_decode(deserializer);
-        let mut var_refundTxId = <Option<String>>::sse_decode(deserializer);
-        let mut var_refundTxAmountSat = <Option<u64>>::sse_decode(deserializer);
-        let mut var_paymentType = <crate::model::PaymentType>::sse_decode(deserializer);
-        let mut var_status = <crate::model::PaymentState>::sse_decode(deserializer);
-        return crate::model::Payment {
-            tx_id: var_txId,
-            swap_id: var_swapId,
-            timestamp: var_timestamp,
-            amount_sat: var_amountSat,
-            fees_sat: var_feesSat,
-            preimage: var_preimage,
-            refund_tx_id: var_refundTxId,
-            refund_tx_amount_sat: var_refundTxAmountSat,
-            payment_type: var_paymentType,
-            status: var_status,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::LiquidAddressData {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.address.into_into_dart().into_dart(),
+            self.network.into_into_dart().into_dart(),
+            self.amount_sat.into_into_dart().into_dart(),
+            self.asset_id.into_into_dart().into_dart(),
+            self.label.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for crate::error::PaymentError {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut tag_ = <i32>::sse_decode(deserializer);
-        match tag_ {
-            0 => {
-                return crate::error::PaymentError::AlreadyClaimed;
-            }
-            1 => {
-                return crate::error::PaymentError::AlreadyPaid;
-            }
-            2 => {
-                return crate::error::PaymentError::PaymentInProgress;
-            }
-            3 => {
-                return crate::error::PaymentError::AmountOutOfRange;
-            }
-            4 => {
-                let mut var_err = <String>::sse_decode(deserializer);
-                return crate::error::PaymentError::Generic { err: var_err };
-            }
-            5 => {
-                return crate::error::PaymentError::InvalidOrExpiredFees;
-            }
-            6 => {
-                return crate::error::PaymentError::InsufficientFunds;
-            }
-            7 => {
-                let mut var_err = <String>::sse_decode(deserializer);
-                return crate::error::PaymentError::InvalidInvoice { err: var_err };
-            }
-            8 => {
-                return crate::error::PaymentError::InvalidPreimage;
-            }
-            9 => {
-                let mut var_err = <String>::sse_decode(deserializer);
-                return crate::error::PaymentError::LwkError { err: var_err };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::model::LiquidAddressData
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::LiquidAddressData>
+    for crate::model::LiquidAddressData
+{
+    fn into_into_dart(self) -> crate::model::LiquidAddressData {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::error::LiquidSdkError {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::error::LiquidSdkError::AlreadyStarted => [0.into_dart()].into_dart(),
+            crate::error::LiquidSdkError::Generic { err } => {
+                [1.into_dart(), err.into_into_dart().into_dart()].into_dart()
             }
-            10 => {
-                return crate::error::PaymentError::PairsNotFound;
+            crate::error::LiquidSdkError::NotStarted => [2.into_dart()].into_dart(),
+            _ => {
+                unimplemented!("");
             }
-            11 => {
-                return crate::error::PaymentError::PaymentTimeout;
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::error::LiquidSdkError {}
+impl flutter_rust_bridge::IntoIntoDart<crate::error::LiquidSdkError>
+    for crate::error::LiquidSdkError
+{
+    fn into_into_dart(self) -> crate::error::LiquidSdkError {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::LiquidSdkEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::model::LiquidSdkEvent::PaymentFailed { details } => {
+                [0.into_dart(), details.into_into_dart().into_dart()].into_dart()
             }
-            12 => {
-                return crate::error::PaymentError::PersistError;
+            crate::model::LiquidSdkEvent::PaymentPending { details } => {
+                [1.into_dart(), details.into_into_dart().into_dart()].into_dart()
             }
-            13 => {
-                let mut var_err = <String>::sse_decode(deserializer);
-                return crate::error::PaymentError::ReceiveError { err: var_err };
+            crate::model::LiquidSdkEvent::PaymentRefunded { details } => {
+                [2.into_dart(), details.into_into_dart().into_dart()].into_dart()
             }
-            14 => {
-                let mut var_err = <String>::sse_decode(deserializer);
-                let mut var_refundTxId = <String>::sse_decode(deserializer);
-                return crate::error::PaymentError::Refunded {
-                    err: var_err,
-                    refund_tx_id: var_refundTxId,
-                };
+            crate::model::LiquidSdkEvent::PaymentRefundable { details } => {
+                [3.into_dart(), details.into_into_dart().into_dart()].into_dart()
             }
-            15 => {
-                return crate::error::PaymentError::SelfTransferNotSupported;
+            crate::model::LiquidSdkEvent::PaymentRefundPending { details } => {
+                [4.into_dart(), details.into_into_dart().into_dart()].into_dart()
             }
-            16 => {
-                let mut var_err = <String>::sse_decode(deserializer);
-                return crate::error::PaymentError::SendError { err: var_err };
+            crate::model::LiquidSdkEvent::PaymentSucceeded { details } => {
+                [5.into_dart(), details.into_into_dart().into_dart()].into_dart()
             }
-            17 => {
-                let mut var_err = <String>::sse_decode(deserializer);
-                return crate::error::PaymentError::SignerError { err: var_err };
+            crate::model::LiquidSdkEvent::PaymentWaitingConfirmation { details } => {
+                [6.into_dart(), details.into_into_dart().into_dart()].into_dart()
             }
+            crate::model::LiquidSdkEvent::Synced => [7.into_dart()].into_dart(),
             _ => {
                 unimplemented!("");
             }
         }
     }
 }
-
-impl SseDecode for crate::model::PaymentState {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <i32>::sse_decode(deserializer);
-        return match inner {
-            0 => crate::model::PaymentState::Created,
-            1 => crate::model::PaymentState::Pending,
-            2 => crate::model::PaymentState::Complete,
-            3 => crate::model::PaymentState::Failed,
-            4 => crate::model::PaymentState::TimedOut,
-            _ => unreachable!("Invalid variant for PaymentState: {}", inner),
-        };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::LiquidSdkEvent {}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::LiquidSdkEvent>
+    for crate::model::LiquidSdkEvent
+{
+    fn into_into_dart(self) -> crate::model::LiquidSdkEvent {
+        self
     }
 }
-
-impl SseDecode for crate::model::PaymentType {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <i32>::sse_decode(deserializer);
-        return match inner {
-            0 => crate::model::PaymentType::Receive,
-            1 => crate::model::PaymentType::Send,
-            _ => unreachable!("Invalid variant for PaymentType: {}", inner),
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::ListPaymentsRequest {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.payment_types.into_into_dart().into_dart(),
+            self.states.into_into_dart().into_dart(),
+            self.from_timestamp.into_into_dart().into_dart(),
+            self.to_timestamp.into_into_dart().into_dart(),
+            self.offset.into_into_dart().into_dart(),
+            self.limit.into_into_dart().into_dart(),
+            self.sort_descending.into_into_dart().into_dart(),
+            self.metadata_search.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for crate::model::PrepareReceiveRequest {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_payerAmountSat = <u64>::sse_decode(deserializer);
-        return crate::model::PrepareReceiveRequest {
-            payer_amount_sat: var_payerAmountSat,
-        };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::model::ListPaymentsRequest
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::ListPaymentsRequest>
+    for crate::model::ListPaymentsRequest
+{
+    fn into_into_dart(self) -> crate::model::ListPaymentsRequest {
+        self
     }
 }
-
-impl SseDecode for crate::model::PrepareReceiveResponse {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_payerAmountSat = <u64>::sse_decode(deserializer);
-        let mut var_feesSat = <u64>::sse_decode(deserializer);
-        return crate::model::PrepareReceiveResponse {
-            payer_amount_sat: var_payerAmountSat,
-            fees_sat: var_feesSat,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::LNInvoice {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.bolt11.into_into_dart().into_dart(),
+            self.network.into_into_dart().into_dart(),
+            self.payee_pubkey.into_into_dart().into_dart(),
+            self.payment_hash.into_into_dart().into_dart(),
+            self.description.into_into_dart().into_dart(),
+            self.description_hash.into_into_dart().into_dart(),
+            self.amount_msat.into_into_dart().into_dart(),
+            self.timestamp.into_into_dart().into_dart(),
+            self.expiry.into_into_dart().into_dart(),
+            self.routing_hints.into_into_dart().into_dart(),
+            self.payment_secret.into_into_dart().into_dart(),
+            self.min_final_cltv_expiry_delta
+                .into_into_dart()
+                .into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for crate::model::PrepareSendRequest {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_invoice = <String>::sse_decode(deserializer);
-        return crate::model::PrepareSendRequest {
-            invoice: var_invoice,
-        };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::LNInvoice {}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::LNInvoice> for crate::model::LNInvoice {
+    fn into_into_dart(self) -> crate::model::LNInvoice {
+        self
     }
 }
-
-impl SseDecode for crate::model::PrepareSendResponse {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_invoice = <String>::sse_decode(deserializer);
-        let mut var_feesSat = <u64>::sse_decode(deserializer);
-        return crate::model::PrepareSendResponse {
-            invoice: var_invoice,
-            fees_sat: var_feesSat,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::LnUrlPayResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.payment.into_into_dart().into_dart(),
+            self.success_action.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::LnUrlPayResult {}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::LnUrlPayResult>
+    for crate::model::LnUrlPayResult
+{
+    fn into_into_dart(self) -> crate::model::LnUrlPayResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::LnUrlWithdrawRequest {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.lnurl.into_into_dart().into_dart(),
+            self.amount_sat.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::model::LnUrlWithdrawRequest
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::LnUrlWithdrawRequest>
+    for crate::model::LnUrlWithdrawRequest
+{
+    fn into_into_dart(self) -> crate::model::LnUrlWithdrawRequest {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::LnUrlWithdrawResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [self.receive_payment_response.into_into_dart().into_dart()].into_dart()
     }
 }
-
-impl SseDecode for crate::model::ReceivePaymentResponse {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_id = <String>::sse_decode(deserializer);
-        let mut var_invoice = <String>::sse_decode(deserializer);
-        return crate::model::ReceivePaymentResponse {
-            id: var_id,
-            invoice: var_invoice,
-        };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::model::LnUrlWithdrawResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::LnUrlWithdrawResult>
+    for crate::model::LnUrlWithdrawResult
+{
+    fn into_into_dart(self) -> crate::model::LnUrlWithdrawResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::LogEntry {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.line.into_into_dart().into_dart(),
+            self.level.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::LogEntry {}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::LogEntry> for crate::model::LogEntry {
+    fn into_into_dart(self) -> crate::model::LogEntry {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::Network {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Mainnet => 0.into_dart(),
+            Self::Testnet => 1.into_dart(),
+            Self::Regtest => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::Network {}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::Network> for crate::model::Network {
+    fn into_into_dart(self) -> crate::model::Network {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::OnchainFeeRate {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::model::OnchainFeeRate::SatPerVbyte { sat_per_vbyte } => {
+                [0.into_dart(), sat_per_vbyte.into_into_dart().into_dart()].into_dart()
+            }
+            crate::model::OnchainFeeRate::Priority { priority } => {
+                [1.into_dart(), priority.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::OnchainFeeRate {}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::OnchainFeeRate>
+    for crate::model::OnchainFeeRate
+{
+    fn into_into_dart(self) -> crate::model::OnchainFeeRate {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::PayOnchainRequest {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.address.into_into_dart().into_dart(),
+            self.prepare_res.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::model::PayOnchainRequest
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::PayOnchainRequest>
+    for crate::model::PayOnchainRequest
+{
+    fn into_into_dart(self) -> crate::model::PayOnchainRequest {
+        self
     }
 }
-
-impl SseDecode for crate::model::RestoreRequest {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_backupPath = <Option<String>>::sse_decode(deserializer);
-        return crate::model::RestoreRequest {
-            backup_path: var_backupPath,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::Payment {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.tx_id.into_into_dart().into_dart(),
+            self.swap_id.into_into_dart().into_dart(),
+            self.swap_type.into_into_dart().into_dart(),
+            self.timestamp.into_into_dart().into_dart(),
+            self.amount_sat.into_into_dart().into_dart(),
+            self.fees_sat.into_into_dart().into_dart(),
+            self.network_fees_sat.into_into_dart().into_dart(),
+            self.fee_breakdown.into_into_dart().into_dart(),
+            self.fee_rate_sat_per_vbyte.into_into_dart().into_dart(),
+            self.preimage.into_into_dart().into_dart(),
+            self.bolt11.into_into_dart().into_dart(),
+            self.payment_hash.into_into_dart().into_dart(),
+            self.payee_pubkey.into_into_dart().into_dart(),
+            self.lockup_tx_id.into_into_dart().into_dart(),
+            self.claim_tx_id.into_into_dart().into_dart(),
+            self.refund_tx_id.into_into_dart().into_dart(),
+            self.refund_tx_amount_sat.into_into_dart().into_dart(),
+            self.payment_type.into_into_dart().into_dart(),
+            self.status.into_into_dart().into_dart(),
+            self.label.into_into_dart().into_dart(),
+            self.description.into_into_dart().into_dart(),
+            self.metadata.into_into_dart().into_dart(),
+            self.asset_id.into_into_dart().into_dart(),
+            self.asset_amount.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for crate::model::RouteHint {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_hops = <Vec<crate::model::RouteHintHop>>::sse_decode(deserializer);
-        return crate::model::RouteHint { hops: var_hops };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::Payment {}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::Payment> for crate::model::Payment {
+    fn into_into_dart(self) -> crate::model::Payment {
+        self
     }
 }
-
-impl SseDecode for crate::model::RouteHintHop {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_srcNodeId = <String>::sse_decode(deserializer);
-        let mut var_shortChannelId = <u64>::sse_decode(deserializer);
-        let mut var_feesBaseMsat = <u32>::sse_decode(deserializer);
-        let mut var_feesProportionalMillionths = <u32>::sse_decode(deserializer);
-        let mut var_cltvExpiryDelta = <u64>::sse_decode(deserializer);
-        let mut var_htlcMinimumMsat = <Option<u64>>::sse_decode(deserializer);
-        let mut var_htlcMaximumMsat = <Option<u64>>::sse_decode(deserializer);
-        return crate::model::RouteHintHop {
-            src_node_id: var_srcNodeId,
-            short_channel_id: var_shortChannelId,
-            fees_base_msat: var_feesBaseMsat,
-            fees_proportional_millionths: var_feesProportionalMillionths,
-            cltv_expiry_delta: var_cltvExpiryDelta,
-            htlc_minimum_msat: var_htlcMinimumMsat,
-            htlc_maximum_msat: var_htlcMaximumMsat,
-        };
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::error::PaymentError {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::error::PaymentError::AlreadyClaimed => [0.into_dart()].into_dart(),
+            crate::error::PaymentError::AlreadyPaid => [1.into_dart()].into_dart(),
+            crate::error::PaymentError::PaymentInProgress => [2.into_dart()].into_dart(),
+            crate::error::PaymentError::AmountOutOfRange => [3.into_dart()].into_dart(),
+            crate::error::PaymentError::UnrecognizedInput { err } => {
+                [4.into_dart(), err.into_into_dart().into_dart()].into_dart()
+            }
+            crate::error::PaymentError::NetworkMismatch { err } => {
+                [5.into_dart(), err.into_into_dart().into_dart()].into_dart()
+            }
+            crate::error::PaymentError::LnUrlError { err } => {
+                [6.into_dart(), err.into_into_dart().into_dart()].into_dart()
+            }
+            crate::error::PaymentError::LnUrlServiceUnreachable { err } => {
+                [7.into_dart(), err.into_into_dart().into_dart()].into_dart()
+            }
+            crate::error::PaymentError::LnUrlServiceError { err } => {
+                [8.into_dart(), err.into_into_dart().into_dart()].into_dart()
+            }
+            crate::error::PaymentError::Generic { err } => {
+                [9.into_dart(), err.into_into_dart().into_dart()].into_dart()
+            }
+            crate::error::PaymentError::InvalidOrExpiredFees => [10.into_dart()].into_dart(),
+            crate::error::PaymentError::FeeLimitExceeded { fee_sat, limit_sat } => [
+                11.into_dart(),
+                fee_sat.into_into_dart().into_dart(),
+                limit_sat.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::error::PaymentError::InsufficientFunds => [12.into_dart()].into_dart(),
+            crate::error::PaymentError::InvalidInvoice { err } => {
+                [13.into_dart(), err.into_into_dart().into_dart()].into_dart()
+            }
+            crate::error::PaymentError::InvalidPreimage => [14.into_dart()].into_dart(),
+            crate::error::PaymentError::LwkError { err } => {
+                [15.into_dart(), err.into_into_dart().into_dart()].into_dart()
+            }
+            crate::error::PaymentError::PairsNotFound => [16.into_dart()].into_dart(),
+            crate::error::PaymentError::PaymentTimeout => [17.into_dart()].into_dart(),
+            crate::error::PaymentError::PersistError => [18.into_dart()].into_dart(),
+            crate::error::PaymentError::ReceiveError { err } => {
+                [19.into_dart(), err.into_into_dart().into_dart()].into_dart()
+            }
+            crate::error::PaymentError::Refunded { err, refund_tx_id } => [
+                20.into_dart(),
+                err.into_into_dart().into_dart(),
+                refund_tx_id.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::error::PaymentError::SelfTransferNotSupported => [21.into_dart()].into_dart(),
+            crate::error::PaymentError::SendError { err } => {
+                [22.into_dart(), err.into_into_dart().into_dart()].into_dart()
+            }
+            crate::error::PaymentError::SignerError { err } => {
+                [23.into_dart(), err.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
-
-impl SseDecode for crate::model::SendPaymentResponse {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_payment = <crate::model::Payment>::sse_decode(deserializer);
-        return crate::model::SendPaymentResponse {
-            payment: var_payment,
-        };
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::error::PaymentError {}
+impl flutter_rust_bridge::IntoIntoDart<crate::error::PaymentError> for crate::error::PaymentError {
+    fn into_into_dart(self) -> crate::error::PaymentError {
+        self
     }
 }
-
-impl SseDecode for u32 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::PaymentExportFormat {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Csv => 0.into_dart(),
+            Self::Json => 1.into_dart(),
+            _ => unreachable!(),
+        }
     }
 }
-
-impl SseDecode for u64 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::model::PaymentExportFormat
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::PaymentExportFormat>
+    for crate::model::PaymentExportFormat
+{
+    fn into_into_dart(self) -> crate::model::PaymentExportFormat {
+        self
     }
 }
-
-impl SseDecode for u8 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap()
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::PaymentMetadata {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.label.into_into_dart().into_dart(),
+            self.note.into_into_dart().into_dart(),
+            self.external_id.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-
-impl SseDecode for () {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::PaymentMetadata {}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::PaymentMetadata>
+    for crate::model::PaymentMetadata
+{
+    fn into_into_dart(self) -> crate::model::PaymentMetadata {
+        self
+    }
 }
-
-impl SseDecode for usize {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::PaymentState {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Created => 0.into_dart(),
+            Self::Pending => 1.into_dart(),
+            Self::Complete => 2.into_dart(),
+            Self::Failed => 3.into_dart(),
+            Self::TimedOut => 4.into_dart(),
+            Self::Refundable => 5.into_dart(),
+            _ => unreachable!(),
+        }
     }
 }
-
-impl SseDecode for bool {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::PaymentState {}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::PaymentState> for crate::model::PaymentState {
+    fn into_into_dart(self) -> crate::model::PaymentState {
+        self
     }
 }
-
-fn pde_ffi_dispatcher_primary_impl(
-    func_id: i32,
-    port: flutter_rust_bridge::for_generated::MessagePort,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        _ => unreachable!(),
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::PaymentSwapType {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Submarine => 0.into_dart(),
+            Self::Reverse => 1.into_dart(),
+            Self::Chain => 2.into_dart(),
+            Self::Direct => 3.into_dart(),
+            _ => unreachable!(),
+        }
     }
 }
-
-fn pde_ffi_dispatcher_sync_impl(
-    func_id: i32,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        _ => unreachable!(),
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::PaymentSwapType {}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::PaymentSwapType>
+    for crate::model::PaymentSwapType
+{
+    fn into_into_dart(self) -> crate::model::PaymentSwapType {
+        self
     }
 }
-
-// Section: rust2dart
-
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for FrbWrapper<BindingLiquidSdk> {
+impl flutter_rust_bridge::IntoDart for crate::model::PaymentType {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        flutter_rust_bridge::for_generated::rust_auto_opaque_encode::<_, StdArc<_>>(self.0)
-            .into_dart()
+        match self {
+            Self::Receive => 0.into_dart(),
+            Self::Send => 1.into_dart(),
+            _ => unreachable!(),
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for FrbWrapper<BindingLiquidSdk> {}
-
-impl flutter_rust_bridge::IntoIntoDart<FrbWrapper<BindingLiquidSdk>> for BindingLiquidSdk {
-    fn into_into_dart(self) -> FrbWrapper<BindingLiquidSdk> {
-        self.into()
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::PaymentType {}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::PaymentType> for crate::model::PaymentType {
+    fn into_into_dart(self) -> crate::model::PaymentType {
+        self
     }
 }
-
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::model::BackupRequest {
+impl flutter_rust_bridge::IntoDart for crate::model::PrepareLnUrlPayRequest {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [self.backup_path.into_into_dart().into_dart()].into_dart()
+        [
+            self.lnurl.into_into_dart().into_dart(),
+            self.amount_sat.into_into_dart().into_dart(),
+            self.comment.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::BackupRequest {}
-impl flutter_rust_bridge::IntoIntoDart<crate::model::BackupRequest>
-    for crate::model::BackupRequest
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::model::PrepareLnUrlPayRequest
 {
-    fn into_into_dart(self) -> crate::model::BackupRequest {
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::PrepareLnUrlPayRequest>
+    for crate::model::PrepareLnUrlPayRequest
+{
+    fn into_into_dart(self) -> crate::model::PrepareLnUrlPayRequest {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::bindings::BindingEventListener {
+impl flutter_rust_bridge::IntoDart for crate::model::PrepareLnUrlPayResponse {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [self.stream.into_into_dart().into_dart()].into_dart()
+        [
+            self.prepare_send_response.into_into_dart().into_dart(),
+            self.success_action.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::bindings::BindingEventListener
+    for crate::model::PrepareLnUrlPayResponse
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::bindings::BindingEventListener>
-    for crate::bindings::BindingEventListener
+impl flutter_rust_bridge::IntoIntoDart<crate::model::PrepareLnUrlPayResponse>
+    for crate::model::PrepareLnUrlPayResponse
 {
-    fn into_into_dart(self) -> crate::bindings::BindingEventListener {
+    fn into_into_dart(self) -> crate::model::PrepareLnUrlPayResponse {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::model::Config {
+impl flutter_rust_bridge::IntoDart for crate::model::PreparePayOnchainRequest {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.boltz_url.into_into_dart().into_dart(),
-            self.electrum_url.into_into_dart().into_dart(),
-            self.working_dir.into_into_dart().into_dart(),
-            self.network.into_into_dart().into_dart(),
-            self.payment_timeout_sec.into_into_dart().into_dart(),
-            self.zero_conf_min_fee_rate.into_into_dart().into_dart(),
-            self.zero_conf_max_amount_sat.into_into_dart().into_dart(),
+            self.receiver_amount_sat.into_into_dart().into_dart(),
+            self.fee_rate.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::Config {}
-impl flutter_rust_bridge::IntoIntoDart<crate::model::Config> for crate::model::Config {
-    fn into_into_dart(self) -> crate::model::Config {
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::model::PreparePayOnchainRequest
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::PreparePayOnchainRequest>
+    for crate::model::PreparePayOnchainRequest
+{
+    fn into_into_dart(self) -> crate::model::PreparePayOnchainRequest {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::model::ConnectRequest {
+impl flutter_rust_bridge::IntoDart for crate::model::PreparePayOnchainResponse {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.mnemonic.into_into_dart().into_dart(),
-            self.config.into_into_dart().into_dart(),
+            self.receiver_amount_sat.into_into_dart().into_dart(),
+            self.fees_sat.into_into_dart().into_dart(),
+            self.fee_rate_sat_per_vbyte.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::ConnectRequest {}
-impl flutter_rust_bridge::IntoIntoDart<crate::model::ConnectRequest>
-    for crate::model::ConnectRequest
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::model::PreparePayOnchainResponse
 {
-    fn into_into_dart(self) -> crate::model::ConnectRequest {
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::PreparePayOnchainResponse>
+    for crate::model::PreparePayOnchainResponse
+{
+    fn into_into_dart(self) -> crate::model::PreparePayOnchainResponse {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::model::GetInfoResponse {
+impl flutter_rust_bridge::IntoDart for crate::model::PrepareReceiveOnchainRequest {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.balance_sat.into_into_dart().into_dart(),
-            self.pending_send_sat.into_into_dart().into_dart(),
-            self.pending_receive_sat.into_into_dart().into_dart(),
-            self.pubkey.into_into_dart().into_dart(),
+            self.payer_amount_sat.into_into_dart().into_dart(),
+            self.fee_rate.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::GetInfoResponse {}
-impl flutter_rust_bridge::IntoIntoDart<crate::model::GetInfoResponse>
-    for crate::model::GetInfoResponse
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::model::PrepareReceiveOnchainRequest
 {
-    fn into_into_dart(self) -> crate::model::GetInfoResponse {
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::PrepareReceiveOnchainRequest>
+    for crate::model::PrepareReceiveOnchainRequest
+{
+    fn into_into_dart(self) -> crate::model::PrepareReceiveOnchainRequest {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::error::LiquidSdkError {
+impl flutter_rust_bridge::IntoDart for crate::model::PrepareReceiveOnchainResponse {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            crate::error::LiquidSdkError::AlreadyStarted => [0.into_dart()].into_dart(),
-            crate::error::LiquidSdkError::Generic { err } => {
-                [1.into_dart(), err.into_into_dart().into_dart()].into_dart()
-            }
-            crate::error::LiquidSdkError::NotStarted => [2.into_dart()].into_dart(),
-            _ => {
-                unimplemented!("");
-            }
-        }
+        [
+            self.payer_amount_sat.into_into_dart().into_dart(),
+            self.fees_sat.into_into_dart().into_dart(),
+            self.fee_rate_sat_per_vbyte.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::error::LiquidSdkError {}
-impl flutter_rust_bridge::IntoIntoDart<crate::error::LiquidSdkError>
-    for crate::error::LiquidSdkError
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::model::PrepareReceiveOnchainResponse
 {
-    fn into_into_dart(self) -> crate::error::LiquidSdkError {
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::PrepareReceiveOnchainResponse>
+    for crate::model::PrepareReceiveOnchainResponse
+{
+    fn into_into_dart(self) -> crate::model::PrepareReceiveOnchainResponse {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::model::LiquidSdkEvent {
+impl flutter_rust_bridge::IntoDart for crate::model::PrepareReceiveRequest {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            crate::model::LiquidSdkEvent::PaymentFailed { details } => {
-                [0.into_dart(), details.into_into_dart().into_dart()].into_dart()
-            }
-            crate::model::LiquidSdkEvent::PaymentPending { details } => {
-                [1.into_dart(), details.into_into_dart().into_dart()].into_dart()
-            }
-            crate::model::LiquidSdkEvent::PaymentRefunded { details } => {
-                [2.into_dart(), details.into_into_dart().into_dart()].into_dart()
-            }
-            crate::model::LiquidSdkEvent::PaymentRefundPending { details } => {
-                [3.into_dart(), details.into_into_dart().into_dart()].into_dart()
-            }
-            crate::model::LiquidSdkEvent::PaymentSucceeded { details } => {
-                [4.into_dart(), details.into_into_dart().into_dart()].into_dart()
-            }
-            crate::model::LiquidSdkEvent::PaymentWaitingConfirmation { details } => {
-                [5.into_dart(), details.into_into_dart().into_dart()].into_dart()
-            }
-            crate::model::LiquidSdkEvent::Synced => [6.into_dart()].into_dart(),
-            _ => {
-                unimplemented!("");
-            }
-        }
+        [
+            self.payer_amount_sat.into_into_dart().into_dart(),
+            self.receiver_amount_sat.into_into_dart().into_dart(),
+            self.description.into_into_dart().into_dart(),
+            self.description_hash.into_into_dart().into_dart(),
+            self.invoice_expiry_secs.into_into_dart().into_dart(),
+            self.metadata.into_into_dart().into_dart(),
+            self.max_fee_sat.into_into_dart().into_dart(),
+            self.max_fee_percent.into_into_dart().into_dart(),
+            self.fee_rate.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::LiquidSdkEvent {}
-impl flutter_rust_bridge::IntoIntoDart<crate::model::LiquidSdkEvent>
-    for crate::model::LiquidSdkEvent
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::model::PrepareReceiveRequest
 {
-    fn into_into_dart(self) -> crate::model::LiquidSdkEvent {
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::PrepareReceiveRequest>
+    for crate::model::PrepareReceiveRequest
+{
+    fn into_into_dart(self) -> crate::model::PrepareReceiveRequest {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::model::LNInvoice {
+impl flutter_rust_bridge::IntoDart for crate::model::PrepareReceiveResponse {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.bolt11.into_into_dart().into_dart(),
-            self.network.into_into_dart().into_dart(),
-            self.payee_pubkey.into_into_dart().into_dart(),
-            self.payment_hash.into_into_dart().into_dart(),
+            self.payer_amount_sat.into_into_dart().into_dart(),
+            self.receiver_amount_sat.into_into_dart().into_dart(),
+            self.fees_sat.into_into_dart().into_dart(),
             self.description.into_into_dart().into_dart(),
             self.description_hash.into_into_dart().into_dart(),
-            self.amount_msat.into_into_dart().into_dart(),
-            self.timestamp.into_into_dart().into_dart(),
-            self.expiry.into_into_dart().into_dart(),
-            self.routing_hints.into_into_dart().into_dart(),
-            self.payment_secret.into_into_dart().into_dart(),
-            self.min_final_cltv_expiry_delta
-                .into_into_dart()
-                .into_dart(),
+            self.invoice_expiry_secs.into_into_dart().into_dart(),
+            self.metadata.into_into_dart().into_dart(),
+            self.fee_breakdown.into_into_dart().into_dart(),
+            self.pair_hash.into_into_dart().into_dart(),
+            self.expires_at.into_into_dart().into_dart(),
+            self.fee_rate_sat_per_vbyte.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::LNInvoice {}
-impl flutter_rust_bridge::IntoIntoDart<crate::model::LNInvoice> for crate::model::LNInvoice {
-    fn into_into_dart(self) -> crate::model::LNInvoice {
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::model::PrepareReceiveResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::PrepareReceiveResponse>
+    for crate::model::PrepareReceiveResponse
+{
+    fn into_into_dart(self) -> crate::model::PrepareReceiveResponse {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::model::LogEntry {
+impl flutter_rust_bridge::IntoDart for crate::model::PrepareRefundRequest {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.line.into_into_dart().into_dart(),
-            self.level.into_into_dart().into_dart(),
+            self.swap_id.into_into_dart().into_dart(),
+            self.refund_address.into_into_dart().into_dart(),
+            self.fee_rate.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::LogEntry {}
-impl flutter_rust_bridge::IntoIntoDart<crate::model::LogEntry> for crate::model::LogEntry {
-    fn into_into_dart(self) -> crate::model::LogEntry {
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::model::PrepareRefundRequest
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::PrepareRefundRequest>
+    for crate::model::PrepareRefundRequest
+{
+    fn into_into_dart(self) -> crate::model::PrepareRefundRequest {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::model::Network {
+impl flutter_rust_bridge::IntoDart for crate::model::PrepareRefundResponse {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            Self::Mainnet => 0.into_dart(),
-            Self::Testnet => 1.into_dart(),
-            _ => unreachable!(),
-        }
+        [
+            self.tx_vsize.into_into_dart().into_dart(),
+            self.tx_fee_sat.into_into_dart().into_dart(),
+            self.refund_amount_sat.into_into_dart().into_dart(),
+            self.fee_rate_sat_per_vbyte.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::Network {}
-impl flutter_rust_bridge::IntoIntoDart<crate::model::Network> for crate::model::Network {
-    fn into_into_dart(self) -> crate::model::Network {
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::model::PrepareRefundResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::PrepareRefundResponse>
+    for crate::model::PrepareRefundResponse
+{
+    fn into_into_dart(self) -> crate::model::PrepareRefundResponse {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::model::Payment {
+impl flutter_rust_bridge::IntoDart for crate::model::PrepareSendOnchainRequest {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.tx_id.into_into_dart().into_dart(),
-            self.swap_id.into_into_dart().into_dart(),
-            self.timestamp.into_into_dart().into_dart(),
+            self.destination.into_into_dart().into_dart(),
             self.amount_sat.into_into_dart().into_dart(),
-            self.fees_sat.into_into_dart().into_dart(),
-            self.preimage.into_into_dart().into_dart(),
-            self.refund_tx_id.into_into_dart().into_dart(),
-            self.refund_tx_amount_sat.into_into_dart().into_dart(),
-            self.payment_type.into_into_dart().into_dart(),
-            self.status.into_into_dart().into_dart(),
+            self.send_all.into_into_dart().into_dart(),
+            self.asset_id.into_into_dart().into_dart(),
+            self.fee_rate.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::Payment {}
-impl flutter_rust_bridge::IntoIntoDart<crate::model::Payment> for crate::model::Payment {
-    fn into_into_dart(self) -> crate::model::Payment {
-        self
-    }
-}
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::error::PaymentError {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            crate::error::PaymentError::AlreadyClaimed => [0.into_dart()].into_dart(),
-            crate::error::PaymentError::AlreadyPaid => [1.into_dart()].into_dart(),
-            crate::error::PaymentError::PaymentInProgress => [2.into_dart()].into_dart(),
-            crate::error::PaymentError::AmountOutOfRange => [3.into_dart()].into_dart(),
-            crate::error::PaymentError::Generic { err } => {
-                [4.into_dart(), err.into_into_dart().into_dart()].into_dart()
-            }
-            crate::error::PaymentError::InvalidOrExpiredFees => [5.into_dart()].into_dart(),
-            crate::error::PaymentError::InsufficientFunds => [6.into_dart()].into_dart(),
-            crate::error::PaymentError::InvalidInvoice { err } => {
-                [7.into_dart(), err.into_into_dart().into_dart()].into_dart()
-            }
-            crate::error::PaymentError::InvalidPreimage => [8.into_dart()].into_dart(),
-            crate::error::PaymentError::LwkError { err } => {
-                [9.into_dart(), err.into_into_dart().into_dart()].into_dart()
-            }
-            crate::error::PaymentError::PairsNotFound => [10.into_dart()].into_dart(),
-            crate::error::PaymentError::PaymentTimeout => [11.into_dart()].into_dart(),
-            crate::error::PaymentError::PersistError => [12.into_dart()].into_dart(),
-            crate::error::PaymentError::ReceiveError { err } => {
-                [13.into_dart(), err.into_into_dart().into_dart()].into_dart()
-            }
-            crate::error::PaymentError::Refunded { err, refund_tx_id } => [
-                14.into_dart(),
-                err.into_into_dart().into_dart(),
-                refund_tx_id.into_into_dart().into_dart(),
-            ]
-            .into_dart(),
-            crate::error::PaymentError::SelfTransferNotSupported => [15.into_dart()].into_dart(),
-            crate::error::PaymentError::SendError { err } => {
-                [16.into_dart(), err.into_into_dart().into_dart()].into_dart()
-            }
-            crate::error::PaymentError::SignerError { err } => {
-                [17.into_dart(), err.into_into_dart().into_dart()].into_dart()
-            }
-            _ => {
-                unimplemented!("");
-            }
-        }
-    }
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::model::PrepareSendOnchainRequest
+{
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::error::PaymentError {}
-impl flutter_rust_bridge::IntoIntoDart<crate::error::PaymentError> for crate::error::PaymentError {
-    fn into_into_dart(self) -> crate::error::PaymentError {
+impl flutter_rust_bridge::IntoIntoDart<crate::model::PrepareSendOnchainRequest>
+    for crate::model::PrepareSendOnchainRequest
+{
+    fn into_into_dart(self) -> crate::model::PrepareSendOnchainRequest {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::model::PaymentState {
+impl flutter_rust_bridge::IntoDart for crate::model::PrepareSendOnchainResponse {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            Self::Created => 0.into_dart(),
-            Self::Pending => 1.into_dart(),
-            Self::Complete => 2.into_dart(),
-            Self::Failed => 3.into_dart(),
-            Self::TimedOut => 4.into_dart(),
-            _ => unreachable!(),
-        }
+        [
+            self.address.into_into_dart().into_dart(),
+            self.receiver_amount_sat.into_into_dart().into_dart(),
+            self.fees_sat.into_into_dart().into_dart(),
+            self.send_all.into_into_dart().into_dart(),
+            self.asset_id.into_into_dart().into_dart(),
+            self.fee_rate_sat_per_vbyte.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::PaymentState {}
-impl flutter_rust_bridge::IntoIntoDart<crate::model::PaymentState> for crate::model::PaymentState {
-    fn into_into_dart(self) -> crate::model::PaymentState {
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::model::PrepareSendOnchainResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::PrepareSendOnchainResponse>
+    for crate::model::PrepareSendOnchainResponse
+{
+    fn into_into_dart(self) -> crate::model::PrepareSendOnchainResponse {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::model::PaymentType {
+impl flutter_rust_bridge::IntoDart for crate::model::PrepareSendRequest {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            Self::Receive => 0.into_dart(),
-            Self::Send => 1.into_dart(),
-            _ => unreachable!(),
-        }
+        [
+            self.invoice.into_into_dart().into_dart(),
+            self.metadata.into_into_dart().into_dart(),
+            self.max_fee_sat.into_into_dart().into_dart(),
+            self.max_fee_percent.into_into_dart().into_dart(),
+            self.fee_rate.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::PaymentType {}
-impl flutter_rust_bridge::IntoIntoDart<crate::model::PaymentType> for crate::model::PaymentType {
-    fn into_into_dart(self) -> crate::model::PaymentType {
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::model::PrepareSendRequest
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::PrepareSendRequest>
+    for crate::model::PrepareSendRequest
+{
+    fn into_into_dart(self) -> crate::model::PrepareSendRequest {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::model::PrepareReceiveRequest {
+impl flutter_rust_bridge::IntoDart for crate::model::PrepareSendResponse {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [self.payer_amount_sat.into_into_dart().into_dart()].into_dart()
+        [
+            self.invoice.into_into_dart().into_dart(),
+            self.fees_sat.into_into_dart().into_dart(),
+            self.metadata.into_into_dart().into_dart(),
+            self.fee_breakdown.into_into_dart().into_dart(),
+            self.max_fee_sat.into_into_dart().into_dart(),
+            self.max_fee_percent.into_into_dart().into_dart(),
+            self.pair_hash.into_into_dart().into_dart(),
+            self.expires_at.into_into_dart().into_dart(),
+            self.fee_rate_sat_per_vbyte.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::model::PrepareReceiveRequest
+    for crate::model::PrepareSendResponse
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::model::PrepareReceiveRequest>
-    for crate::model::PrepareReceiveRequest
+impl flutter_rust_bridge::IntoIntoDart<crate::model::PrepareSendResponse>
+    for crate::model::PrepareSendResponse
 {
-    fn into_into_dart(self) -> crate::model::PrepareReceiveRequest {
+    fn into_into_dart(self) -> crate::model::PrepareSendResponse {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::model::PrepareReceiveResponse {
+impl flutter_rust_bridge::IntoDart for crate::model::ReceiveLiquidRequest {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.payer_amount_sat.into_into_dart().into_dart(),
-            self.fees_sat.into_into_dart().into_dart(),
+            self.amount_sat.into_into_dart().into_dart(),
+            self.label.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::model::PrepareReceiveResponse
+    for crate::model::ReceiveLiquidRequest
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::model::PrepareReceiveResponse>
-    for crate::model::PrepareReceiveResponse
+impl flutter_rust_bridge::IntoIntoDart<crate::model::ReceiveLiquidRequest>
+    for crate::model::ReceiveLiquidRequest
 {
-    fn into_into_dart(self) -> crate::model::PrepareReceiveResponse {
+    fn into_into_dart(self) -> crate::model::ReceiveLiquidRequest {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::model::PrepareSendRequest {
+impl flutter_rust_bridge::IntoDart for crate::model::ReceiveLiquidResponse {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [self.invoice.into_into_dart().into_dart()].into_dart()
+        [
+            self.address.into_into_dart().into_dart(),
+            self.bip21.into_into_dart().into_dart(),
+        ]
+        .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::model::PrepareSendRequest
+    for crate::model::ReceiveLiquidResponse
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::model::PrepareSendRequest>
-    for crate::model::PrepareSendRequest
+impl flutter_rust_bridge::IntoIntoDart<crate::model::ReceiveLiquidResponse>
+    for crate::model::ReceiveLiquidResponse
 {
-    fn into_into_dart(self) -> crate::model::PrepareSendRequest {
+    fn into_into_dart(self) -> crate::model::ReceiveLiquidResponse {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::model::PrepareSendResponse {
+impl flutter_rust_bridge::IntoDart for crate::model::ReceiveOnchainResponse {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.invoice.into_into_dart().into_dart(),
-            self.fees_sat.into_into_dart().into_dart(),
+            self.id.into_into_dart().into_dart(),
+            self.address.into_into_dart().into_dart(),
+            self.bip21.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::model::PrepareSendResponse
+    for crate::model::ReceiveOnchainResponse
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::model::PrepareSendResponse>
-    for crate::model::PrepareSendResponse
+impl flutter_rust_bridge::IntoIntoDart<crate::model::ReceiveOnchainResponse>
+    for crate::model::ReceiveOnchainResponse
 {
-    fn into_into_dart(self) -> crate::model::PrepareSendResponse {
+    fn into_into_dart(self) -> crate::model::ReceiveOnchainResponse {
         self
     }
 }
@@ -1780,6 +4686,102 @@ impl flutter_rust_bridge::IntoIntoDart<crate::model::ReceivePaymentResponse>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::RecommendedFees {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.fastest_fee.into_into_dart().into_dart(),
+            self.half_hour_fee.into_into_dart().into_dart(),
+            self.hour_fee.into_into_dart().into_dart(),
+            self.economy_fee.into_into_dart().into_dart(),
+            self.minimum_fee.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::RecommendedFees {}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::RecommendedFees>
+    for crate::model::RecommendedFees
+{
+    fn into_into_dart(self) -> crate::model::RecommendedFees {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::RecommendedFeesResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.liquid.into_into_dart().into_dart(),
+            self.bitcoin.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::model::RecommendedFeesResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::RecommendedFeesResponse>
+    for crate::model::RecommendedFeesResponse
+{
+    fn into_into_dart(self) -> crate::model::RecommendedFeesResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::RefundRequest {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.swap_id.into_into_dart().into_dart(),
+            self.refund_address.into_into_dart().into_dart(),
+            self.fee_rate_sat_per_vbyte.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::RefundRequest {}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::RefundRequest>
+    for crate::model::RefundRequest
+{
+    fn into_into_dart(self) -> crate::model::RefundRequest {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::RefundResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [self.refund_tx_id.into_into_dart().into_dart()].into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::RefundResponse {}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::RefundResponse>
+    for crate::model::RefundResponse
+{
+    fn into_into_dart(self) -> crate::model::RefundResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::RefundableSwap {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.swap_id.into_into_dart().into_dart(),
+            self.swap_address.into_into_dart().into_dart(),
+            self.timestamp.into_into_dart().into_dart(),
+            self.amount_sat.into_into_dart().into_dart(),
+            self.payment_type.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::RefundableSwap {}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::RefundableSwap>
+    for crate::model::RefundableSwap
+{
+    fn into_into_dart(self) -> crate::model::RefundableSwap {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::model::RestoreRequest {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [self.backup_path.into_into_dart().into_dart()].into_dart()
@@ -1845,6 +4847,104 @@ impl flutter_rust_bridge::IntoIntoDart<crate::model::SendPaymentResponse>
         self
     }
 }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::SetPaymentMetadataRequest {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.metadata.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::model::SetPaymentMetadataRequest
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::SetPaymentMetadataRequest>
+    for crate::model::SetPaymentMetadataRequest
+{
+    fn into_into_dart(self) -> crate::model::SetPaymentMetadataRequest {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::SuccessAction {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::model::SuccessAction::Message { message } => {
+                [0.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            crate::model::SuccessAction::Url { description, url } => [
+                1.into_dart(),
+                description.into_into_dart().into_dart(),
+                url.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::model::SuccessAction::Aes {
+                description,
+                ciphertext,
+                iv,
+            } => [
+                2.into_dart(),
+                description.into_into_dart().into_dart(),
+                ciphertext.into_into_dart().into_dart(),
+                iv.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::model::SuccessAction {}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::SuccessAction>
+    for crate::model::SuccessAction
+{
+    fn into_into_dart(self) -> crate::model::SuccessAction {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::model::SuccessActionProcessed {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::model::SuccessActionProcessed::Message { message } => {
+                [0.into_dart(), message.into_into_dart().into_dart()].into_dart()
+            }
+            crate::model::SuccessActionProcessed::Url { description, url } => [
+                1.into_dart(),
+                description.into_into_dart().into_dart(),
+                url.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::model::SuccessActionProcessed::Aes {
+                description,
+                plaintext,
+            } => [
+                2.into_dart(),
+                description.into_into_dart().into_dart(),
+                plaintext.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::model::SuccessActionProcessed
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::model::SuccessActionProcessed>
+    for crate::model::SuccessActionProcessed
+{
+    fn into_into_dart(self) -> crate::model::SuccessActionProcessed {
+        self
+    }
+}
 
 impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1896,6 +4996,25 @@ impl SseEncode for String {
     }
 }
 
+impl SseEncode for crate::model::AssetBalance {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.asset_id, serializer);
+        <u64>::sse_encode(self.balance, serializer);
+        <Option<crate::model::AssetMetadata>>::sse_encode(self.asset_metadata, serializer);
+    }
+}
+
+impl SseEncode for crate::model::AssetMetadata {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.asset_id, serializer);
+        <String>::sse_encode(self.ticker, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <u8>::sse_encode(self.precision, serializer);
+    }
+}
+
 impl SseEncode for crate::model::BackupRequest {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1910,48 +5029,230 @@ impl SseEncode for crate::bindings::BindingEventListener {
     }
 }
 
-impl SseEncode for crate::model::Config {
+impl SseEncode for crate::model::BitcoinAddressData {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.address, serializer);
+        <crate::model::Network>::sse_encode(self.network, serializer);
+        <Option<u64>>::sse_encode(self.amount_sat, serializer);
+        <Option<String>>::sse_encode(self.label, serializer);
+        <Option<String>>::sse_encode(self.message, serializer);
+    }
+}
+
+impl SseEncode for crate::model::Config {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.boltz_url, serializer);
+        <Vec<crate::model::ElectrumServer>>::sse_encode(self.electrum_servers, serializer);
+        <Option<String>>::sse_encode(self.electrum_url, serializer);
+        <String>::sse_encode(self.bitcoin_electrum_url, serializer);
+        <Option<String>>::sse_encode(self.esplora_url, serializer);
+        <String>::sse_encode(self.working_dir, serializer);
+        <crate::model::Network>::sse_encode(self.network, serializer);
+        <u64>::sse_encode(self.payment_timeout_sec, serializer);
+        <u64>::sse_encode(self.sync_fallback_interval_sec, serializer);
+        <f32>::sse_encode(self.zero_conf_min_fee_rate, serializer);
+        <Option<u64>>::sse_encode(self.zero_conf_max_amount_sat, serializer);
+        <Option<Vec<crate::model::AssetMetadata>>>::sse_encode(self.asset_metadata, serializer);
+        <Option<u64>>::sse_encode(self.max_fee_sat, serializer);
+        <Option<f64>>::sse_encode(self.max_fee_percent, serializer);
+        <Option<String>>::sse_encode(self.regtest_policy_asset, serializer);
+    }
+}
+
+impl SseEncode for crate::model::ConnectRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.mnemonic, serializer);
+        <crate::model::Config>::sse_encode(self.config, serializer);
+    }
+}
+
+impl SseEncode for crate::model::ElectrumServer {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.url, serializer);
+        <bool>::sse_encode(self.tls, serializer);
+        <bool>::sse_encode(self.validate_domain, serializer);
+    }
+}
+
+impl SseEncode for crate::model::ElectrumServerStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.url, serializer);
+        <bool>::sse_encode(self.in_use, serializer);
+        <bool>::sse_encode(self.healthy, serializer);
+        <u32>::sse_encode(self.consecutive_failures, serializer);
+        <Option<String>>::sse_encode(self.last_error, serializer);
+    }
+}
+
+impl SseEncode for crate::model::ExportPaymentsRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::model::PaymentExportFormat>::sse_encode(self.format, serializer);
+        <crate::model::ListPaymentsRequest>::sse_encode(self.filter, serializer);
+        <String>::sse_encode(self.path, serializer);
+    }
+}
+
+impl SseEncode for crate::model::ExportPaymentsResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.payments_count, serializer);
+    }
+}
+
+impl SseEncode for f32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f32::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for crate::model::FeeBreakdown {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f64>::sse_encode(self.service_fee_percentage, serializer);
+        <u64>::sse_encode(self.service_fee_sat, serializer);
+        <u64>::sse_encode(self.swapper_miner_fees_sat, serializer);
+        <u64>::sse_encode(self.network_fee_sat, serializer);
+        <u64>::sse_encode(self.total_fees_sat, serializer);
+    }
+}
+
+impl SseEncode for crate::model::FeePriority {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::model::FeePriority::Fastest => 0,
+                crate::model::FeePriority::HalfHour => 1,
+                crate::model::FeePriority::Hour => 2,
+                crate::model::FeePriority::Economy => 3,
+                crate::model::FeePriority::Minimum => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::model::GetInfoResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.balance_sat, serializer);
+        <u64>::sse_encode(self.pending_send_sat, serializer);
+        <u64>::sse_encode(self.pending_receive_sat, serializer);
+        <String>::sse_encode(self.pubkey, serializer);
+        <Vec<crate::model::AssetBalance>>::sse_encode(self.asset_balances, serializer);
+    }
+}
+
+impl SseEncode for crate::model::GetPaymentRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::model::GetPaymentRequest::TxId { tx_id } => {
+                <i32>::sse_encode(0, serializer);
+                <String>::sse_encode(tx_id, serializer);
+            }
+            crate::model::GetPaymentRequest::SwapId { swap_id } => {
+                <i32>::sse_encode(1, serializer);
+                <String>::sse_encode(swap_id, serializer);
+            }
+            crate::model::GetPaymentRequest::Bolt11 { bolt11 } => {
+                <i32>::sse_encode(2, serializer);
+                <String>::sse_encode(bolt11, serializer);
+            }
+            crate::model::GetPaymentRequest::PaymentHash { payment_hash } => {
+                <i32>::sse_encode(3, serializer);
+                <String>::sse_encode(payment_hash, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for i32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.boltz_url, serializer);
-        <String>::sse_encode(self.electrum_url, serializer);
-        <String>::sse_encode(self.working_dir, serializer);
-        <crate::model::Network>::sse_encode(self.network, serializer);
-        <u64>::sse_encode(self.payment_timeout_sec, serializer);
-        <f32>::sse_encode(self.zero_conf_min_fee_rate, serializer);
-        <Option<u64>>::sse_encode(self.zero_conf_max_amount_sat, serializer);
+        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
     }
 }
 
-impl SseEncode for crate::model::ConnectRequest {
+impl SseEncode for crate::model::InputType {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.mnemonic, serializer);
-        <crate::model::Config>::sse_encode(self.config, serializer);
+        match self {
+            crate::model::InputType::Bolt11 { invoice } => {
+                <i32>::sse_encode(0, serializer);
+                <crate::model::LNInvoice>::sse_encode(invoice, serializer);
+            }
+            crate::model::InputType::LiquidAddress { address } => {
+                <i32>::sse_encode(1, serializer);
+                <crate::model::LiquidAddressData>::sse_encode(address, serializer);
+            }
+            crate::model::InputType::BitcoinAddress { address } => {
+                <i32>::sse_encode(2, serializer);
+                <crate::model::BitcoinAddressData>::sse_encode(address, serializer);
+            }
+            crate::model::InputType::LnUrl { url } => {
+                <i32>::sse_encode(3, serializer);
+                <String>::sse_encode(url, serializer);
+            }
+            crate::model::InputType::LightningAddress { address, url } => {
+                <i32>::sse_encode(4, serializer);
+                <String>::sse_encode(address, serializer);
+                <String>::sse_encode(url, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
 
-impl SseEncode for f32 {
+impl SseEncode for crate::model::LightningLimits {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_f32::<NativeEndian>(self).unwrap();
+        <u64>::sse_encode(self.min_sat, serializer);
+        <u64>::sse_encode(self.max_sat, serializer);
+        <f64>::sse_encode(self.service_fee_percentage, serializer);
+        <u64>::sse_encode(self.miner_fees_sat, serializer);
     }
 }
 
-impl SseEncode for crate::model::GetInfoResponse {
+impl SseEncode for crate::model::LightningPaymentLimitsResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <u64>::sse_encode(self.balance_sat, serializer);
-        <u64>::sse_encode(self.pending_send_sat, serializer);
-        <u64>::sse_encode(self.pending_receive_sat, serializer);
-        <String>::sse_encode(self.pubkey, serializer);
+        <crate::model::LightningLimits>::sse_encode(self.send, serializer);
+        <crate::model::LightningLimits>::sse_encode(self.receive, serializer);
     }
 }
 
-impl SseEncode for i32 {
+impl SseEncode for crate::model::LiquidAddressData {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+        <String>::sse_encode(self.address, serializer);
+        <crate::model::Network>::sse_encode(self.network, serializer);
+        <Option<u64>>::sse_encode(self.amount_sat, serializer);
+        <Option<String>>::sse_encode(self.asset_id, serializer);
+        <Option<String>>::sse_encode(self.label, serializer);
+        <Option<String>>::sse_encode(self.message, serializer);
     }
 }
 
@@ -1992,20 +5293,24 @@ impl SseEncode for crate::model::LiquidSdkEvent {
                 <i32>::sse_encode(2, serializer);
                 <crate::model::Payment>::sse_encode(details, serializer);
             }
-            crate::model::LiquidSdkEvent::PaymentRefundPending { details } => {
+            crate::model::LiquidSdkEvent::PaymentRefundable { details } => {
                 <i32>::sse_encode(3, serializer);
                 <crate::model::Payment>::sse_encode(details, serializer);
             }
-            crate::model::LiquidSdkEvent::PaymentSucceeded { details } => {
+            crate::model::LiquidSdkEvent::PaymentRefundPending { details } => {
                 <i32>::sse_encode(4, serializer);
                 <crate::model::Payment>::sse_encode(details, serializer);
             }
-            crate::model::LiquidSdkEvent::PaymentWaitingConfirmation { details } => {
+            crate::model::LiquidSdkEvent::PaymentSucceeded { details } => {
                 <i32>::sse_encode(5, serializer);
                 <crate::model::Payment>::sse_encode(details, serializer);
             }
-            crate::model::LiquidSdkEvent::Synced => {
+            crate::model::LiquidSdkEvent::PaymentWaitingConfirmation { details } => {
                 <i32>::sse_encode(6, serializer);
+                <crate::model::Payment>::sse_encode(details, serializer);
+            }
+            crate::model::LiquidSdkEvent::Synced => {
+                <i32>::sse_encode(7, serializer);
             }
             _ => {
                 unimplemented!("");
@@ -2014,6 +5319,46 @@ impl SseEncode for crate::model::LiquidSdkEvent {
     }
 }
 
+impl SseEncode for Vec<crate::model::AssetBalance> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::model::AssetBalance>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::model::AssetMetadata> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::model::AssetMetadata>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::model::ElectrumServer> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::model::ElectrumServer>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::model::ElectrumServerStatus> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::model::ElectrumServerStatus>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::model::Payment> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -2024,6 +5369,40 @@ impl SseEncode for Vec<crate::model::Payment> {
     }
 }
 
+impl SseEncode for Vec<crate::model::PaymentState> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::model::PaymentState>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::model::PaymentType> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::model::PaymentType>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for crate::model::ListPaymentsRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<Vec<crate::model::PaymentType>>>::sse_encode(self.payment_types, serializer);
+        <Option<Vec<crate::model::PaymentState>>>::sse_encode(self.states, serializer);
+        <Option<u32>>::sse_encode(self.from_timestamp, serializer);
+        <Option<u32>>::sse_encode(self.to_timestamp, serializer);
+        <Option<u32>>::sse_encode(self.offset, serializer);
+        <Option<u32>>::sse_encode(self.limit, serializer);
+        <Option<bool>>::sse_encode(self.sort_descending, serializer);
+        <Option<String>>::sse_encode(self.metadata_search, serializer);
+    }
+}
+
 impl SseEncode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -2034,6 +5413,16 @@ impl SseEncode for Vec<u8> {
     }
 }
 
+impl SseEncode for Vec<crate::model::RefundableSwap> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::model::RefundableSwap>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::model::RouteHint> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -2072,63 +5461,261 @@ impl SseEncode for crate::model::LNInvoice {
     }
 }
 
-impl SseEncode for crate::model::LogEntry {
+impl SseEncode for crate::model::LnUrlPayResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::model::Payment>::sse_encode(self.payment, serializer);
+        <Option<crate::model::SuccessActionProcessed>>::sse_encode(self.success_action, serializer);
+    }
+}
+
+impl SseEncode for crate::model::LnUrlWithdrawRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.lnurl, serializer);
+        <u64>::sse_encode(self.amount_sat, serializer);
+    }
+}
+
+impl SseEncode for crate::model::LnUrlWithdrawResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::model::ReceivePaymentResponse>::sse_encode(
+            self.receive_payment_response,
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::model::LogEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.line, serializer);
+        <String>::sse_encode(self.level, serializer);
+    }
+}
+
+impl SseEncode for crate::model::Network {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::model::Network::Mainnet => 0,
+                crate::model::Network::Testnet => 1,
+                crate::model::Network::Regtest => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::model::OnchainFeeRate {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::model::OnchainFeeRate::SatPerVbyte { sat_per_vbyte } => {
+                <i32>::sse_encode(0, serializer);
+                <f64>::sse_encode(sat_per_vbyte, serializer);
+            }
+            crate::model::OnchainFeeRate::Priority { priority } => {
+                <i32>::sse_encode(1, serializer);
+                <crate::model::FeePriority>::sse_encode(priority, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <String>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::model::AssetMetadata> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::model::AssetMetadata>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<bool> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <bool>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <f64>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::model::FeeBreakdown> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::model::FeeBreakdown>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::model::OnchainFeeRate> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::model::OnchainFeeRate>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::model::Payment> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::model::Payment>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::model::PaymentMetadata> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::model::PaymentMetadata>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::model::SuccessAction> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::model::SuccessAction>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::model::SuccessActionProcessed> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::model::SuccessActionProcessed>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<u32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.line, serializer);
-        <String>::sse_encode(self.level, serializer);
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u32>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for crate::model::Network {
+impl SseEncode for Option<u64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(
-            match self {
-                crate::model::Network::Mainnet => 0,
-                crate::model::Network::Testnet => 1,
-                _ => {
-                    unimplemented!("");
-                }
-            },
-            serializer,
-        );
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u64>::sse_encode(value, serializer);
+        }
     }
 }
 
-impl SseEncode for Option<String> {
+impl SseEncode for Option<Vec<crate::model::AssetMetadata>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <bool>::sse_encode(self.is_some(), serializer);
         if let Some(value) = self {
-            <String>::sse_encode(value, serializer);
+            <Vec<crate::model::AssetMetadata>>::sse_encode(value, serializer);
         }
     }
 }
 
-impl SseEncode for Option<u64> {
+impl SseEncode for Option<Vec<crate::model::PaymentState>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <bool>::sse_encode(self.is_some(), serializer);
         if let Some(value) = self {
-            <u64>::sse_encode(value, serializer);
+            <Vec<crate::model::PaymentState>>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<Vec<crate::model::PaymentType>> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <Vec<crate::model::PaymentType>>::sse_encode(value, serializer);
         }
     }
 }
 
+impl SseEncode for crate::model::PayOnchainRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.address, serializer);
+        <crate::model::PreparePayOnchainResponse>::sse_encode(self.prepare_res, serializer);
+    }
+}
+
 impl SseEncode for crate::model::Payment {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <Option<String>>::sse_encode(self.tx_id, serializer);
         <Option<String>>::sse_encode(self.swap_id, serializer);
+        <crate::model::PaymentSwapType>::sse_encode(self.swap_type, serializer);
         <u32>::sse_encode(self.timestamp, serializer);
         <u64>::sse_encode(self.amount_sat, serializer);
         <u64>::sse_encode(self.fees_sat, serializer);
+        <Option<u64>>::sse_encode(self.network_fees_sat, serializer);
+        <Option<crate::model::FeeBreakdown>>::sse_encode(self.fee_breakdown, serializer);
+        <Option<f64>>::sse_encode(self.fee_rate_sat_per_vbyte, serializer);
         <Option<String>>::sse_encode(self.preimage, serializer);
+        <Option<String>>::sse_encode(self.bolt11, serializer);
+        <Option<String>>::sse_encode(self.payment_hash, serializer);
+        <Option<String>>::sse_encode(self.payee_pubkey, serializer);
+        <Option<String>>::sse_encode(self.lockup_tx_id, serializer);
+        <Option<String>>::sse_encode(self.claim_tx_id, serializer);
         <Option<String>>::sse_encode(self.refund_tx_id, serializer);
         <Option<u64>>::sse_encode(self.refund_tx_amount_sat, serializer);
         <crate::model::PaymentType>::sse_encode(self.payment_type, serializer);
         <crate::model::PaymentState>::sse_encode(self.status, serializer);
+        <Option<String>>::sse_encode(self.label, serializer);
+        <Option<String>>::sse_encode(self.description, serializer);
+        <Option<crate::model::PaymentMetadata>>::sse_encode(self.metadata, serializer);
+        <Option<String>>::sse_encode(self.asset_id, serializer);
+        <Option<u64>>::sse_encode(self.asset_amount, serializer);
     }
 }
 
@@ -2148,54 +5735,79 @@ impl SseEncode for crate::error::PaymentError {
             crate::error::PaymentError::AmountOutOfRange => {
                 <i32>::sse_encode(3, serializer);
             }
-            crate::error::PaymentError::Generic { err } => {
+            crate::error::PaymentError::UnrecognizedInput { err } => {
                 <i32>::sse_encode(4, serializer);
                 <String>::sse_encode(err, serializer);
             }
-            crate::error::PaymentError::InvalidOrExpiredFees => {
+            crate::error::PaymentError::NetworkMismatch { err } => {
                 <i32>::sse_encode(5, serializer);
+                <String>::sse_encode(err, serializer);
             }
-            crate::error::PaymentError::InsufficientFunds => {
+            crate::error::PaymentError::LnUrlError { err } => {
                 <i32>::sse_encode(6, serializer);
+                <String>::sse_encode(err, serializer);
             }
-            crate::error::PaymentError::InvalidInvoice { err } => {
+            crate::error::PaymentError::LnUrlServiceUnreachable { err } => {
                 <i32>::sse_encode(7, serializer);
                 <String>::sse_encode(err, serializer);
             }
-            crate::error::PaymentError::InvalidPreimage => {
+            crate::error::PaymentError::LnUrlServiceError { err } => {
                 <i32>::sse_encode(8, serializer);
+                <String>::sse_encode(err, serializer);
             }
-            crate::error::PaymentError::LwkError { err } => {
+            crate::error::PaymentError::Generic { err } => {
                 <i32>::sse_encode(9, serializer);
                 <String>::sse_encode(err, serializer);
             }
-            crate::error::PaymentError::PairsNotFound => {
+            crate::error::PaymentError::InvalidOrExpiredFees => {
                 <i32>::sse_encode(10, serializer);
             }
-            crate::error::PaymentError::PaymentTimeout => {
+            crate::error::PaymentError::FeeLimitExceeded { fee_sat, limit_sat } => {
                 <i32>::sse_encode(11, serializer);
+                <u64>::sse_encode(fee_sat, serializer);
+                <u64>::sse_encode(limit_sat, serializer);
             }
-            crate::error::PaymentError::PersistError => {
+            crate::error::PaymentError::InsufficientFunds => {
                 <i32>::sse_encode(12, serializer);
             }
-            crate::error::PaymentError::ReceiveError { err } => {
+            crate::error::PaymentError::InvalidInvoice { err } => {
                 <i32>::sse_encode(13, serializer);
                 <String>::sse_encode(err, serializer);
             }
-            crate::error::PaymentError::Refunded { err, refund_tx_id } => {
+            crate::error::PaymentError::InvalidPreimage => {
                 <i32>::sse_encode(14, serializer);
+            }
+            crate::error::PaymentError::LwkError { err } => {
+                <i32>::sse_encode(15, serializer);
+                <String>::sse_encode(err, serializer);
+            }
+            crate::error::PaymentError::PairsNotFound => {
+                <i32>::sse_encode(16, serializer);
+            }
+            crate::error::PaymentError::PaymentTimeout => {
+                <i32>::sse_encode(17, serializer);
+            }
+            crate::error::PaymentError::PersistError => {
+                <i32>::sse_encode(18, serializer);
+            }
+            crate::error::PaymentError::ReceiveError { err } => {
+                <i32>::sse_encode(19, serializer);
+                <String>::sse_encode(err, serializer);
+            }
+            crate::error::PaymentError::Refunded { err, refund_tx_id } => {
+                <i32>::sse_encode(20, serializer);
                 <String>::sse_encode(err, serializer);
                 <String>::sse_encode(refund_tx_id, serializer);
             }
             crate::error::PaymentError::SelfTransferNotSupported => {
-                <i32>::sse_encode(15, serializer);
+                <i32>::sse_encode(21, serializer);
             }
             crate::error::PaymentError::SendError { err } => {
-                <i32>::sse_encode(16, serializer);
+                <i32>::sse_encode(22, serializer);
                 <String>::sse_encode(err, serializer);
             }
             crate::error::PaymentError::SignerError { err } => {
-                <i32>::sse_encode(17, serializer);
+                <i32>::sse_encode(23, serializer);
                 <String>::sse_encode(err, serializer);
             }
             _ => {
@@ -2205,6 +5817,31 @@ impl SseEncode for crate::error::PaymentError {
     }
 }
 
+impl SseEncode for crate::model::PaymentExportFormat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::model::PaymentExportFormat::Csv => 0,
+                crate::model::PaymentExportFormat::Json => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::model::PaymentMetadata {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.label, serializer);
+        <Option<String>>::sse_encode(self.note, serializer);
+        <Option<String>>::sse_encode(self.external_id, serializer);
+    }
+}
+
 impl SseEncode for crate::model::PaymentState {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -2215,6 +5852,25 @@ impl SseEncode for crate::model::PaymentState {
                 crate::model::PaymentState::Complete => 2,
                 crate::model::PaymentState::Failed => 3,
                 crate::model::PaymentState::TimedOut => 4,
+                crate::model::PaymentState::Refundable => 5,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::model::PaymentSwapType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::model::PaymentSwapType::Submarine => 0,
+                crate::model::PaymentSwapType::Reverse => 1,
+                crate::model::PaymentSwapType::Chain => 2,
+                crate::model::PaymentSwapType::Direct => 3,
                 _ => {
                     unimplemented!("");
                 }
@@ -2240,10 +5896,69 @@ impl SseEncode for crate::model::PaymentType {
     }
 }
 
-impl SseEncode for crate::model::PrepareReceiveRequest {
+impl SseEncode for crate::model::PrepareLnUrlPayRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.lnurl, serializer);
+        <u64>::sse_encode(self.amount_sat, serializer);
+        <Option<String>>::sse_encode(self.comment, serializer);
+    }
+}
+
+impl SseEncode for crate::model::PrepareLnUrlPayResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::model::PrepareSendResponse>::sse_encode(self.prepare_send_response, serializer);
+        <Option<crate::model::SuccessAction>>::sse_encode(self.success_action, serializer);
+    }
+}
+
+impl SseEncode for crate::model::PreparePayOnchainRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.receiver_amount_sat, serializer);
+        <Option<crate::model::OnchainFeeRate>>::sse_encode(self.fee_rate, serializer);
+    }
+}
+
+impl SseEncode for crate::model::PreparePayOnchainResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.receiver_amount_sat, serializer);
+        <u64>::sse_encode(self.fees_sat, serializer);
+        <Option<f64>>::sse_encode(self.fee_rate_sat_per_vbyte, serializer);
+    }
+}
+
+impl SseEncode for crate::model::PrepareReceiveOnchainRequest {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <u64>::sse_encode(self.payer_amount_sat, serializer);
+        <Option<crate::model::OnchainFeeRate>>::sse_encode(self.fee_rate, serializer);
+    }
+}
+
+impl SseEncode for crate::model::PrepareReceiveOnchainResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.payer_amount_sat, serializer);
+        <u64>::sse_encode(self.fees_sat, serializer);
+        <Option<f64>>::sse_encode(self.fee_rate_sat_per_vbyte, serializer);
+    }
+}
+
+impl SseEncode for crate::model::PrepareReceiveRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<u64>>::sse_encode(self.payer_amount_sat, serializer);
+        <Option<u64>>::sse_encode(self.receiver_amount_sat, serializer);
+        <Option<String>>::sse_encode(self.description, serializer);
+        <Option<String>>::sse_encode(self.description_hash, serializer);
+        <Option<u64>>::sse_encode(self.invoice_expiry_secs, serializer);
+        <Option<crate::model::PaymentMetadata>>::sse_encode(self.metadata, serializer);
+        <Option<u64>>::sse_encode(self.max_fee_sat, serializer);
+        <Option<f64>>::sse_encode(self.max_fee_percent, serializer);
+        <Option<crate::model::OnchainFeeRate>>::sse_encode(self.fee_rate, serializer);
     }
 }
 
@@ -2251,7 +5966,58 @@ impl SseEncode for crate::model::PrepareReceiveResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <u64>::sse_encode(self.payer_amount_sat, serializer);
+        <u64>::sse_encode(self.receiver_amount_sat, serializer);
+        <u64>::sse_encode(self.fees_sat, serializer);
+        <Option<String>>::sse_encode(self.description, serializer);
+        <Option<String>>::sse_encode(self.description_hash, serializer);
+        <Option<u64>>::sse_encode(self.invoice_expiry_secs, serializer);
+        <Option<crate::model::PaymentMetadata>>::sse_encode(self.metadata, serializer);
+        <crate::model::FeeBreakdown>::sse_encode(self.fee_breakdown, serializer);
+        <String>::sse_encode(self.pair_hash, serializer);
+        <u32>::sse_encode(self.expires_at, serializer);
+        <Option<f64>>::sse_encode(self.fee_rate_sat_per_vbyte, serializer);
+    }
+}
+
+impl SseEncode for crate::model::PrepareRefundRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.swap_id, serializer);
+        <String>::sse_encode(self.refund_address, serializer);
+        <crate::model::OnchainFeeRate>::sse_encode(self.fee_rate, serializer);
+    }
+}
+
+impl SseEncode for crate::model::PrepareRefundResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.tx_vsize, serializer);
+        <u64>::sse_encode(self.tx_fee_sat, serializer);
+        <u64>::sse_encode(self.refund_amount_sat, serializer);
+        <f64>::sse_encode(self.fee_rate_sat_per_vbyte, serializer);
+    }
+}
+
+impl SseEncode for crate::model::PrepareSendOnchainRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.destination, serializer);
+        <Option<u64>>::sse_encode(self.amount_sat, serializer);
+        <bool>::sse_encode(self.send_all, serializer);
+        <Option<String>>::sse_encode(self.asset_id, serializer);
+        <Option<crate::model::OnchainFeeRate>>::sse_encode(self.fee_rate, serializer);
+    }
+}
+
+impl SseEncode for crate::model::PrepareSendOnchainResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.address, serializer);
+        <u64>::sse_encode(self.receiver_amount_sat, serializer);
         <u64>::sse_encode(self.fees_sat, serializer);
+        <bool>::sse_encode(self.send_all, serializer);
+        <Option<String>>::sse_encode(self.asset_id, serializer);
+        <Option<f64>>::sse_encode(self.fee_rate_sat_per_vbyte, serializer);
     }
 }
 
@@ -2259,6 +6025,10 @@ impl SseEncode for crate::model::PrepareSendRequest {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <String>::sse_encode(self.invoice, serializer);
+        <Option<crate::model::PaymentMetadata>>::sse_encode(self.metadata, serializer);
+        <Option<u64>>::sse_encode(self.max_fee_sat, serializer);
+        <Option<f64>>::sse_encode(self.max_fee_percent, serializer);
+        <Option<crate::model::OnchainFeeRate>>::sse_encode(self.fee_rate, serializer);
     }
 }
 
@@ -2267,6 +6037,38 @@ impl SseEncode for crate::model::PrepareSendResponse {
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <String>::sse_encode(self.invoice, serializer);
         <u64>::sse_encode(self.fees_sat, serializer);
+        <Option<crate::model::PaymentMetadata>>::sse_encode(self.metadata, serializer);
+        <crate::model::FeeBreakdown>::sse_encode(self.fee_breakdown, serializer);
+        <Option<u64>>::sse_encode(self.max_fee_sat, serializer);
+        <Option<f64>>::sse_encode(self.max_fee_percent, serializer);
+        <String>::sse_encode(self.pair_hash, serializer);
+        <u32>::sse_encode(self.expires_at, serializer);
+        <Option<f64>>::sse_encode(self.fee_rate_sat_per_vbyte, serializer);
+    }
+}
+
+impl SseEncode for crate::model::ReceiveLiquidRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<u64>>::sse_encode(self.amount_sat, serializer);
+        <Option<String>>::sse_encode(self.label, serializer);
+    }
+}
+
+impl SseEncode for crate::model::ReceiveLiquidResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.address, serializer);
+        <String>::sse_encode(self.bip21, serializer);
+    }
+}
+
+impl SseEncode for crate::model::ReceiveOnchainResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.address, serializer);
+        <String>::sse_encode(self.bip21, serializer);
     }
 }
 
@@ -2278,6 +6080,52 @@ impl SseEncode for crate::model::ReceivePaymentResponse {
     }
 }
 
+impl SseEncode for crate::model::RecommendedFees {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f64>::sse_encode(self.fastest_fee, serializer);
+        <f64>::sse_encode(self.half_hour_fee, serializer);
+        <f64>::sse_encode(self.hour_fee, serializer);
+        <f64>::sse_encode(self.economy_fee, serializer);
+        <f64>::sse_encode(self.minimum_fee, serializer);
+    }
+}
+
+impl SseEncode for crate::model::RecommendedFeesResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::model::RecommendedFees>::sse_encode(self.liquid, serializer);
+        <crate::model::RecommendedFees>::sse_encode(self.bitcoin, serializer);
+    }
+}
+
+impl SseEncode for crate::model::RefundRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.swap_id, serializer);
+        <String>::sse_encode(self.refund_address, serializer);
+        <f64>::sse_encode(self.fee_rate_sat_per_vbyte, serializer);
+    }
+}
+
+impl SseEncode for crate::model::RefundResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.refund_tx_id, serializer);
+    }
+}
+
+impl SseEncode for crate::model::RefundableSwap {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.swap_id, serializer);
+        <String>::sse_encode(self.swap_address, serializer);
+        <u32>::sse_encode(self.timestamp, serializer);
+        <u64>::sse_encode(self.amount_sat, serializer);
+        <crate::model::PaymentType>::sse_encode(self.payment_type, serializer);
+    }
+}
+
 impl SseEncode for crate::model::RestoreRequest {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -2312,6 +6160,72 @@ impl SseEncode for crate::model::SendPaymentResponse {
     }
 }
 
+impl SseEncode for crate::model::SetPaymentMetadataRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <crate::model::PaymentMetadata>::sse_encode(self.metadata, serializer);
+    }
+}
+
+impl SseEncode for crate::model::SuccessAction {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::model::SuccessAction::Message { message } => {
+                <i32>::sse_encode(0, serializer);
+                <String>::sse_encode(message, serializer);
+            }
+            crate::model::SuccessAction::Url { description, url } => {
+                <i32>::sse_encode(1, serializer);
+                <String>::sse_encode(description, serializer);
+                <String>::sse_encode(url, serializer);
+            }
+            crate::model::SuccessAction::Aes {
+                description,
+                ciphertext,
+                iv,
+            } => {
+                <i32>::sse_encode(2, serializer);
+                <String>::sse_encode(description, serializer);
+                <String>::sse_encode(ciphertext, serializer);
+                <String>::sse_encode(iv, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::model::SuccessActionProcessed {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::model::SuccessActionProcessed::Message { message } => {
+                <i32>::sse_encode(0, serializer);
+                <String>::sse_encode(message, serializer);
+            }
+            crate::model::SuccessActionProcessed::Url { description, url } => {
+                <i32>::sse_encode(1, serializer);
+                <String>::sse_encode(description, serializer);
+                <String>::sse_encode(url, serializer);
+            }
+            crate::model::SuccessActionProcessed::Aes {
+                description,
+                plaintext,
+            } => {
+                <i32>::sse_encode(2, serializer);
+                <String>::sse_encode(description, serializer);
+                <String>::sse_encode(plaintext, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
 impl SseEncode for u32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -0,0 +1,400 @@
+use std::str::FromStr;
+
+use bech32::FromBase32;
+
+use crate::ensure_sdk;
+use crate::error::PaymentError;
+use crate::model::*;
+use crate::sdk::LiquidSdk;
+use crate::utils;
+
+const LIGHTNING_SCHEME: &str = "lightning:";
+const BITCOIN_SCHEME: &str = "bitcoin:";
+const LIQUID_SCHEMES: [&str; 2] = ["liquidnetwork:", "liquidtestnet:"];
+const LNURL_HRP: &str = "lnurl";
+/// LUD-17 schemes, which replace the `https://` scheme of a plain LNURL
+const LNURL_SCHEMES: [&str; 3] = ["lnurlp://", "lnurlw://", "keyauth://"];
+
+/// Classifies and decodes the input, checking it is meant for `network`.
+///
+/// No network requests are made: LNURLs and Lightning Addresses are only decoded to their
+/// endpoint URL.
+pub(crate) fn parse(input: &str, network: Network) -> Result<InputType, PaymentError> {
+    let input = input.trim();
+    ensure_sdk!(
+        !input.is_empty(),
+        PaymentError::UnrecognizedInput {
+            err: "Empty input".to_string()
+        }
+    );
+
+    if let Some(res) = parse_lightning_address(input) {
+        return Ok(res);
+    }
+
+    // A `lightning:` prefix can wrap either an invoice or an LNURL
+    let lightning_payload = strip_prefix_ignore_case(input, LIGHTNING_SCHEME).unwrap_or(input);
+    if let Some(url) = parse_lnurl(lightning_payload)? {
+        return Ok(InputType::LnUrl { url });
+    }
+
+    if let Some(bip21) = strip_prefix_ignore_case(input, BITCOIN_SCHEME) {
+        return parse_bitcoin_bip21(bip21, network);
+    }
+
+    if let Some(scheme) = LIQUID_SCHEMES
+        .into_iter()
+        .find(|scheme| strip_prefix_ignore_case(input, scheme).is_some())
+    {
+        return parse_liquid_bip21(input, scheme, network);
+    }
+
+    if let Ok(address) = LiquidAddressData::from_addr_or_uri(input) {
+        ensure_network(network, address.network)?;
+        return Ok(InputType::LiquidAddress { address });
+    }
+
+    if let Ok(invoice) = LiquidSdk::parse_invoice(input) {
+        ensure_network(network, invoice.network)?;
+        return Ok(InputType::Bolt11 { invoice });
+    }
+
+    match parse_bitcoin_address(input, network) {
+        Ok(address) => Ok(InputType::BitcoinAddress { address }),
+        Err(e @ PaymentError::NetworkMismatch { .. }) => Err(e),
+        Err(_) => Err(PaymentError::UnrecognizedInput {
+            err: format!("Could not parse {input}"),
+        }),
+    }
+}
+
+fn strip_prefix_ignore_case<'a>(input: &'a str, prefix: &str) -> Option<&'a str> {
+    match input.get(..prefix.len()) {
+        Some(start) if start.eq_ignore_ascii_case(prefix) => Some(&input[prefix.len()..]),
+        _ => None,
+    }
+}
+
+fn ensure_network(expected: Network, found: Network) -> Result<(), PaymentError> {
+    ensure_sdk!(
+        expected == found,
+        PaymentError::NetworkMismatch {
+            err: format!("expected {expected:?}, found {found:?}")
+        }
+    );
+    Ok(())
+}
+
+/// Onion domains are only reachable over plain HTTP, see LUD-01 and LUD-17
+fn lnurl_http_scheme(domain: &str) -> &'static str {
+    match domain.ends_with(".onion") {
+        true => "http",
+        false => "https",
+    }
+}
+
+/// Parses a `user@domain` Lightning Address into its LNURL-pay endpoint, as per LUD-16
+fn parse_lightning_address(input: &str) -> Option<InputType> {
+    let address = strip_prefix_ignore_case(input, LIGHTNING_SCHEME).unwrap_or(input);
+    let (user, domain) = address.split_once('@')?;
+
+    let is_valid_user = !user.is_empty()
+        && user
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-_.+".contains(c));
+    let is_valid_domain = domain.contains('.')
+        && !domain.starts_with('.')
+        && !domain.ends_with('.')
+        && domain
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-.".contains(c));
+    if !is_valid_user || !is_valid_domain {
+        return None;
+    }
+
+    let user = user.to_lowercase();
+    let domain = domain.to_lowercase();
+    Some(InputType::LightningAddress {
+        url: format!(
+            "{}://{domain}/.well-known/lnurlp/{user}",
+            lnurl_http_scheme(&domain)
+        ),
+        address: format!("{user}@{domain}"),
+    })
+}
+
+/// Decodes a bech32 LNURL or a LUD-17 URL into its endpoint URL.
+///
+/// Returns `None` if the input is not an LNURL, and an error if it is a malformed one.
+fn parse_lnurl(input: &str) -> Result<Option<String>, PaymentError> {
+    for scheme in LNURL_SCHEMES {
+        if let Some(rest) = strip_prefix_ignore_case(input, scheme) {
+            let domain = rest.split(['/', '?', ':']).next().unwrap_or_default();
+            return Ok(Some(format!("{}://{rest}", lnurl_http_scheme(domain))));
+        }
+    }
+
+    if strip_prefix_ignore_case(input, LNURL_HRP).is_none() {
+        return Ok(None);
+    }
+
+    let invalid_lnurl = |err: String| PaymentError::UnrecognizedInput {
+        err: format!("Invalid LNURL: {err}"),
+    };
+    let (hrp, data, _) = bech32::decode(input).map_err(|e| invalid_lnurl(e.to_string()))?;
+    ensure_sdk!(
+        hrp == LNURL_HRP,
+        invalid_lnurl(format!("unexpected prefix {hrp}"))
+    );
+    let bytes = Vec::<u8>::from_base32(&data).map_err(|e| invalid_lnurl(e.to_string()))?;
+    let url = String::from_utf8(bytes).map_err(|e| invalid_lnurl(e.to_string()))?;
+    Ok(Some(url))
+}
+
+fn parse_bitcoin_address(
+    address: &str,
+    network: Network,
+) -> Result<BitcoinAddressData, PaymentError> {
+    let address = lwk_wollet::bitcoin::Address::from_str(address).map_err(|err| {
+        PaymentError::UnrecognizedInput {
+            err: format!("Invalid BTC address: {err}"),
+        }
+    })?;
+    let address =
+        address
+            .require_network(network.into())
+            .map_err(|_| PaymentError::NetworkMismatch {
+                err: format!("BTC address is not a {network:?} address"),
+            })?;
+
+    Ok(BitcoinAddressData {
+        address: address.to_string(),
+        network,
+        amount_sat: None,
+        label: None,
+        message: None,
+    })
+}
+
+/// Parses a Liquid BIP21 URI. Both its scheme and its address have to be for `network`.
+fn parse_liquid_bip21(
+    bip21: &str,
+    scheme: &str,
+    network: Network,
+) -> Result<InputType, PaymentError> {
+    let expected_scheme = LiquidAddressData::uri_scheme(network);
+    ensure_sdk!(
+        scheme.trim_end_matches(':') == expected_scheme,
+        PaymentError::NetworkMismatch {
+            err: format!("expected a {expected_scheme}: URI, found {scheme}")
+        }
+    );
+
+    let invalid_uri = |e: PaymentError| PaymentError::UnrecognizedInput {
+        err: format!("Invalid Liquid BIP21 URI: {e}"),
+    };
+    let address_and_params = &bip21[scheme.len()..];
+    let address = address_and_params
+        .split_once('?')
+        .map_or(address_and_params, |(address, _)| address);
+    let address_network = LiquidAddressData::from_addr_or_uri(address)
+        .map_err(invalid_uri)?
+        .network;
+    ensure_network(network, address_network)?;
+
+    let address = LiquidAddressData::from_addr_or_uri(bip21).map_err(invalid_uri)?;
+    Ok(InputType::LiquidAddress { address })
+}
+
+/// Parses the part after `bitcoin:` of a BIP21 URI. If it has a `lightning` param, the
+/// BOLT11 invoice takes precedence over the BTC address.
+fn parse_bitcoin_bip21(bip21: &str, network: Network) -> Result<InputType, PaymentError> {
+    let (address, params) = bip21.split_once('?').unwrap_or((bip21, ""));
+
+    let mut amount_sat = None;
+    let mut label = None;
+    let mut message = None;
+    for (key, value) in url::form_urlencoded::parse(params.as_bytes()) {
+        match key.to_lowercase().as_str() {
+            "lightning" => {
+                let invoice = LiquidSdk::parse_invoice(&value)?;
+                ensure_network(network, invoice.network)?;
+                return Ok(InputType::Bolt11 { invoice });
+            }
+            "amount" => {
+                amount_sat = Some(utils::parse_btc_amount_sat(&value).ok_or(
+                    PaymentError::UnrecognizedInput {
+                        err: format!("Invalid BIP21 amount: {value}"),
+                    },
+                )?)
+            }
+            "label" => label = Some(value.into_owned()),
+            "message" => message = Some(value.into_owned()),
+            _ => {}
+        }
+    }
+
+    Ok(InputType::BitcoinAddress {
+        address: BitcoinAddressData {
+            amount_sat,
+            label,
+            message,
+            ..parse_bitcoin_address(address, network)?
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use lwk_wollet::hashes::{sha256, Hash};
+
+    use crate::error::PaymentError;
+    use crate::model::*;
+    use crate::test_utils::{new_test_invoice, TEST_BITCOIN_ADDRESS, TEST_LIQUID_ADDRESS};
+
+    use super::parse;
+
+    /// A Liquid mainnet address
+    const MAINNET_LIQUID_ADDRESS: &str = "lq1pqvzxvqhrf54dd4sny4cag7497pe38252qefk46t92frs7us8r80ja9ha8r5me09nn22m4tmdqp5p4wafq3s59cql3v9n45t5trwtxrmxfsyxjnstkctj";
+    /// The Tether USD asset ID on Liquid testnet
+    const ASSET_ID: &str = "b612eb46313a2cd6ebabd8b7a8eed5696e29898b87a43bff41c94f51acef9d73";
+
+    #[test]
+    fn parse_lnurl_and_lightning_address() -> Result<()> {
+        let lnurl = "LNURL1DP68GURN8GHJ7UM9WFMXJCM99E3K7MF0V9CXJ0M385EKVCENXC6R2C35XVUKXEFCV5MKVV34X5EKZD3EV56NYD3HXQURZEPEXEJXXEPNXSCRVWFNV9NXZCN9XQ6XYEFHVGCXXCMYXYMNSERXFQ5FNS";
+        assert!(matches!(
+            parse(lnurl, Network::Testnet)?,
+            InputType::LnUrl { url } if url == "https://service.com/api?q=3fc3645b439ce8e7f2553a69e5267081d96dcd340693afabe04be7b0ccd178df"
+        ));
+        assert!(matches!(
+            parse(&format!("lightning:{lnurl}"), Network::Testnet)?,
+            InputType::LnUrl { .. }
+        ));
+        assert!(matches!(
+            parse("lnurlw://service.com/withdraw", Network::Testnet)?,
+            InputType::LnUrl { url } if url == "https://service.com/withdraw"
+        ));
+
+        assert!(matches!(
+            parse("Satoshi@Example.com", Network::Testnet)?,
+            InputType::LightningAddress { address, url }
+                if address == "satoshi@example.com" && url == "https://example.com/.well-known/lnurlp/satoshi"
+        ));
+
+        assert!(matches!(
+            parse("not a destination", Network::Testnet),
+            Err(PaymentError::UnrecognizedInput { .. })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn parse_liquid_address() -> Result<()> {
+        assert!(matches!(
+            parse(TEST_LIQUID_ADDRESS, Network::Testnet)?,
+            InputType::LiquidAddress { .. }
+        ));
+        assert!(matches!(
+            parse(TEST_LIQUID_ADDRESS, Network::Mainnet),
+            Err(PaymentError::NetworkMismatch { .. })
+        ));
+
+        let uri = format!("liquidtestnet:{TEST_LIQUID_ADDRESS}?amount=0.0001&assetid={ASSET_ID}&label=Coffee%20shop");
+        let InputType::LiquidAddress { address } = parse(&uri, Network::Testnet)? else {
+            panic!("Expected a Liquid address");
+        };
+        assert_eq!(address.address, TEST_LIQUID_ADDRESS);
+        assert_eq!(address.network, Network::Testnet);
+        assert_eq!(address.amount_sat, Some(10_000));
+        assert_eq!(address.asset_id.as_deref(), Some(ASSET_ID));
+        assert_eq!(address.label.as_deref(), Some("Coffee shop"));
+        assert_eq!(address.to_uri(), uri);
+        Ok(())
+    }
+
+    #[test]
+    fn parse_cross_network_liquid_bip21() {
+        for (input, network) in [
+            // The scheme and the address are for another network
+            (
+                format!("liquidtestnet:{TEST_LIQUID_ADDRESS}"),
+                Network::Mainnet,
+            ),
+            (
+                format!("liquidnetwork:{MAINNET_LIQUID_ADDRESS}"),
+                Network::Testnet,
+            ),
+            // The scheme does not match the address
+            (
+                format!("liquidnetwork:{TEST_LIQUID_ADDRESS}"),
+                Network::Testnet,
+            ),
+            (
+                format!("liquidnetwork:{TEST_LIQUID_ADDRESS}"),
+                Network::Mainnet,
+            ),
+            (
+                format!("liquidtestnet:{MAINNET_LIQUID_ADDRESS}"),
+                Network::Testnet,
+            ),
+            (
+                format!("LIQUIDTESTNET:{MAINNET_LIQUID_ADDRESS}?amount=0.1"),
+                Network::Mainnet,
+            ),
+        ] {
+            assert!(
+                matches!(
+                    parse(&input, network),
+                    Err(PaymentError::NetworkMismatch { .. })
+                ),
+                "Expected a network mismatch for {input} on {network:?}"
+            );
+        }
+
+        assert!(matches!(
+            parse("liquidtestnet:not-an-address", Network::Testnet),
+            Err(PaymentError::UnrecognizedInput { .. })
+        ));
+    }
+
+    #[test]
+    fn parse_bitcoin_bip21() -> Result<()> {
+        let uri = format!("bitcoin:{TEST_BITCOIN_ADDRESS}?amount=0.001&label=Coffee%20shop");
+        let InputType::BitcoinAddress { address } = parse(&uri, Network::Testnet)? else {
+            panic!("Expected a BTC address");
+        };
+        assert_eq!(address.address, TEST_BITCOIN_ADDRESS);
+        assert_eq!(address.amount_sat, Some(100_000));
+        assert_eq!(address.label.as_deref(), Some("Coffee shop"));
+        assert!(matches!(
+            parse(&uri, Network::Mainnet),
+            Err(PaymentError::NetworkMismatch { .. })
+        ));
+
+        // The invoice of the `lightning` param takes precedence, with or without an address
+        let payment_hash = sha256::Hash::hash(b"preimage");
+        let invoice = new_test_invoice(100_000_000, payment_hash, "Coffee", None, None);
+        for uri in [
+            format!("bitcoin:{TEST_BITCOIN_ADDRESS}?amount=0.001&lightning={invoice}"),
+            format!("bitcoin:?lightning={invoice}"),
+            format!("BITCOIN:?LIGHTNING={invoice}"),
+        ] {
+            let InputType::Bolt11 { invoice: parsed } = parse(&uri, Network::Testnet)? else {
+                panic!("Expected an invoice for {uri}");
+            };
+            assert_eq!(parsed.bolt11, invoice);
+            assert_eq!(parsed.payment_hash, payment_hash.to_string());
+            assert!(matches!(
+                parse(&uri, Network::Mainnet),
+                Err(PaymentError::NetworkMismatch { .. })
+            ));
+        }
+
+        assert!(matches!(
+            parse("bitcoin:?lightning=lntb1invalid", Network::Testnet),
+            Err(PaymentError::InvalidInvoice { .. })
+        ));
+        Ok(())
+    }
+}
@@ -1,9 +1,16 @@
 #[cfg(feature = "frb")]
 pub(crate) mod bindings;
+pub(crate) mod chain;
+pub(crate) mod chain_swap;
+pub(crate) mod electrum;
 pub mod error;
 pub(crate) mod event;
+pub(crate) mod export;
+pub(crate) mod fee_estimator;
 #[cfg(feature = "frb")]
 pub(crate) mod frb_generated;
+pub(crate) mod input_parser;
+pub mod lnurl;
 pub mod logger;
 pub mod model;
 pub mod persist;
@@ -11,5 +18,7 @@ pub(crate) mod receive_swap;
 pub mod sdk;
 pub(crate) mod send_swap;
 pub(crate) mod swapper;
+#[cfg(test)]
+pub(crate) mod test_utils;
 pub(crate) mod utils;
 pub(crate) mod wallet;
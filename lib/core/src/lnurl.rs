@@ -0,0 +1,651 @@
+use aes::cipher::generic_array::GenericArray;
+use aes::{Aes256, BlockDecrypt, NewBlockCipher};
+use base64::Engine;
+use lwk_wollet::elements::hex::FromHex;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+
+use crate::ensure_sdk;
+use crate::error::PaymentError;
+use crate::model::SuccessAction;
+
+/// LUD-09 limit on the length of the `message` and `description` of a success action
+const SUCCESS_ACTION_MAX_TEXT_LEN: usize = 144;
+/// LUD-10 limit on the length of the base64-encoded AES ciphertext
+const SUCCESS_ACTION_MAX_CIPHERTEXT_LEN: usize = 4096;
+const AES_BLOCK_LEN: usize = 16;
+
+/// HTTP client used to talk to LNURL services.
+///
+/// The SDK uses [UreqRestClient] by default. A custom client can be set with
+/// [LiquidSdk::connect_with_rest_client](crate::sdk::LiquidSdk::connect_with_rest_client), for
+/// example to route the requests to a local stand-in server.
+pub trait RestClient: Send + Sync {
+    /// Makes a GET request, returning the HTTP status code and the response body
+    fn get(&self, url: &str) -> Result<(u16, String), PaymentError>;
+}
+
+/// Default [RestClient], based on `ureq`
+pub struct UreqRestClient;
+
+impl RestClient for UreqRestClient {
+    fn get(&self, url: &str) -> Result<(u16, String), PaymentError> {
+        let response = match ureq::get(url).call() {
+            Ok(response) => response,
+            // Non-2xx responses are returned to the caller, which can read the LNURL error
+            Err(ureq::Error::Status(_, response)) => response,
+            Err(e) => return Err(PaymentError::LnUrlServiceUnreachable { err: e.to_string() }),
+        };
+        let status = response.status();
+        let body = response
+            .into_string()
+            .map_err(|e| PaymentError::LnUrlServiceUnreachable {
+                err: format!("Could not read response: {e}"),
+            })?;
+        Ok((status, body))
+    }
+}
+
+/// The first response of an LNURL-pay service, as per LUD-06
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct LnUrlPayRequestData {
+    pub(crate) callback: String,
+    pub(crate) min_sendable: u64,
+    pub(crate) max_sendable: u64,
+    pub(crate) metadata: String,
+    /// Max length of the comment the payer can attach, as per LUD-12
+    #[serde(default)]
+    pub(crate) comment_allowed: usize,
+    pub(crate) tag: String,
+}
+
+/// The response of an LNURL-pay callback, as per LUD-06
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct LnUrlPayCallbackResponse {
+    pub(crate) pr: String,
+    pub(crate) success_action: Option<SuccessAction>,
+}
+
+/// The first response of an LNURL-withdraw service, as per LUD-03
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct LnUrlWithdrawRequestData {
+    pub(crate) callback: String,
+    pub(crate) k1: String,
+    pub(crate) min_withdrawable: u64,
+    pub(crate) max_withdrawable: u64,
+    /// Used as the invoice description
+    #[serde(default)]
+    pub(crate) default_description: String,
+    pub(crate) tag: String,
+}
+
+/// The status response of an LNURL service, as per LUD-03 and LUD-06. It is also the whole
+/// response of an LNURL-withdraw callback.
+#[derive(Debug, Deserialize)]
+pub(crate) struct LnUrlStatusResponse {
+    pub(crate) status: String,
+    pub(crate) reason: Option<String>,
+}
+
+pub(crate) fn lnurl_error(err: impl Into<String>) -> PaymentError {
+    PaymentError::LnUrlError { err: err.into() }
+}
+
+/// Fetches and deserializes an LNURL service response, turning LNURL error responses into
+/// [PaymentError::LnUrlServiceError]
+pub(crate) fn get_json<T: DeserializeOwned>(
+    rest_client: &dyn RestClient,
+    url: &str,
+) -> Result<T, PaymentError> {
+    let (status, body) = rest_client.get(url)?;
+
+    // Services may report errors with any HTTP status, including 200
+    if let Ok(status_response) = serde_json::from_str::<LnUrlStatusResponse>(&body) {
+        if status_response.status.eq_ignore_ascii_case("ERROR") {
+            return Err(PaymentError::LnUrlServiceError {
+                err: status_response
+                    .reason
+                    .unwrap_or("Unknown error".to_string()),
+            });
+        }
+    }
+    ensure_sdk!(
+        (200..300).contains(&status),
+        PaymentError::LnUrlServiceError {
+            err: format!("HTTP status {status}")
+        }
+    );
+
+    serde_json::from_str(&body)
+        .map_err(|e| lnurl_error(format!("Invalid LNURL service response: {e}")))
+}
+
+/// Appends query params to an LNURL callback URL, which may already have its own params
+pub(crate) fn build_callback_url(
+    callback: &str,
+    params: &[(&str, String)],
+) -> Result<String, PaymentError> {
+    let mut url = url::Url::parse(callback)
+        .map_err(|e| lnurl_error(format!("Invalid LNURL callback URL: {e}")))?;
+    for (key, value) in params {
+        url.query_pairs_mut().append_pair(key, value);
+    }
+    Ok(url.to_string())
+}
+
+/// Checks a success action against the limits of LUD-09 and LUD-10
+pub(crate) fn validate_success_action(
+    success_action: &SuccessAction,
+    callback: &str,
+) -> Result<(), PaymentError> {
+    match success_action {
+        SuccessAction::Message { message } => ensure_sdk!(
+            message.len() <= SUCCESS_ACTION_MAX_TEXT_LEN,
+            lnurl_error("Success action message is too long")
+        ),
+        SuccessAction::Url { description, url } => {
+            ensure_sdk!(
+                description.len() <= SUCCESS_ACTION_MAX_TEXT_LEN,
+                lnurl_error("Success action description is too long")
+            );
+            let callback_domain = url::Url::parse(callback)
+                .ok()
+                .and_then(|u| u.domain().map(str::to_string));
+            let url_domain = url::Url::parse(url)
+                .map_err(|e| lnurl_error(format!("Invalid success action URL: {e}")))?
+                .domain()
+                .map(str::to_string);
+            ensure_sdk!(
+                url_domain.is_some() && url_domain == callback_domain,
+                lnurl_error("Success action URL domain does not match the LNURL callback domain")
+            );
+        }
+        SuccessAction::Aes {
+            description,
+            ciphertext,
+            iv,
+        } => {
+            ensure_sdk!(
+                description.len() <= SUCCESS_ACTION_MAX_TEXT_LEN,
+                lnurl_error("Success action description is too long")
+            );
+            ensure_sdk!(
+                ciphertext.len() <= SUCCESS_ACTION_MAX_CIPHERTEXT_LEN,
+                lnurl_error("Success action ciphertext is too long")
+            );
+            ensure_sdk!(
+                iv.len() == 24,
+                lnurl_error("Success action IV must be 24 base64 characters")
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Decrypts the ciphertext of an AES success action with the payment preimage, as per LUD-10
+pub(crate) fn decrypt_aes_success_action(
+    ciphertext: &str,
+    iv: &str,
+    preimage: &str,
+) -> Result<String, PaymentError> {
+    let engine = base64::engine::general_purpose::STANDARD;
+    let ciphertext = engine
+        .decode(ciphertext)
+        .map_err(|e| lnurl_error(format!("Invalid success action ciphertext: {e}")))?;
+    let iv = engine
+        .decode(iv)
+        .map_err(|e| lnurl_error(format!("Invalid success action IV: {e}")))?;
+    let key =
+        Vec::<u8>::from_hex(preimage).map_err(|e| lnurl_error(format!("Invalid preimage: {e}")))?;
+
+    let plaintext = aes_256_cbc_decrypt(&key, &iv, &ciphertext)?;
+    String::from_utf8(plaintext)
+        .map_err(|e| lnurl_error(format!("Decrypted success action is not UTF-8: {e}")))
+}
+
+/// AES-256-CBC decryption with PKCS#7 padding
+fn aes_256_cbc_decrypt(key: &[u8], iv: &[u8], ciphertext: &[u8]) -> Result<Vec<u8>, PaymentError> {
+    let cipher = Aes256::new_from_slice(key).map_err(|_| lnurl_error("Invalid AES key length"))?;
+    ensure_sdk!(
+        iv.len() == AES_BLOCK_LEN,
+        lnurl_error("Invalid AES IV length")
+    );
+    ensure_sdk!(
+        !ciphertext.is_empty() && ciphertext.len() % AES_BLOCK_LEN == 0,
+        lnurl_error("Invalid AES ciphertext length")
+    );
+
+    let mut plaintext = Vec::with_capacity(ciphertext.len());
+    let mut previous_block = iv;
+    for block in ciphertext.chunks(AES_BLOCK_LEN) {
+        let mut decrypted = GenericArray::clone_from_slice(block);
+        cipher.decrypt_block(&mut decrypted);
+        plaintext.extend(
+            decrypted
+                .iter()
+                .zip(previous_block)
+                .map(|(byte, previous_byte)| byte ^ previous_byte),
+        );
+        previous_block = block;
+    }
+
+    let padding_len = *plaintext.last().unwrap_or(&0) as usize;
+    ensure_sdk!(
+        (1..=AES_BLOCK_LEN).contains(&padding_len)
+            && plaintext[plaintext.len() - padding_len..]
+                .iter()
+                .all(|byte| *byte as usize == padding_len),
+        lnurl_error("Invalid AES padding")
+    );
+    plaintext.truncate(plaintext.len() - padding_len);
+    Ok(plaintext)
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use lwk_wollet::hashes::{sha256, Hash};
+    use serde_json::{json, Value};
+
+    use crate::error::PaymentError;
+    use crate::lnurl::decrypt_aes_success_action;
+    use crate::model::*;
+    use crate::test_utils::rest_client::MockRestClient;
+    use crate::test_utils::sdk::{new_test_sdk, TestSdk};
+    use crate::test_utils::{mock_hash, new_test_invoice, TEST_LIQUID_ADDRESS};
+
+    const LIGHTNING_ADDRESS: &str = "satoshi@example.com";
+    const PAY_URL: &str = "https://example.com/.well-known/lnurlp/satoshi";
+    const CALLBACK_URL: &str = "https://example.com/lnurlp/satoshi/callback";
+    const METADATA: &str = "[[\"text/plain\",\"Pay to satoshi\"]]";
+    const WITHDRAW_LNURL: &str = "lnurlw://example.com/withdraw";
+    const WITHDRAW_URL: &str = "https://example.com/withdraw";
+    const WITHDRAW_CALLBACK_URL: &str = "https://example.com/withdraw/callback";
+
+    fn add_pay_request(rest_client: &MockRestClient, min_sendable_sat: u64, max_sendable_sat: u64) {
+        rest_client.add_response(
+            PAY_URL,
+            json!({
+                "callback": CALLBACK_URL,
+                "minSendable": min_sendable_sat * 1000,
+                "maxSendable": max_sendable_sat * 1000,
+                "metadata": METADATA,
+                "commentAllowed": 20,
+                "tag": "payRequest",
+            }),
+        );
+    }
+
+    /// Sets the callback response to an invoice of `amount_sat` committing to
+    /// `description_hash`, and makes the invoice payable by a direct onchain tx (MRH) so the
+    /// payment completes on the mocks
+    fn add_callback_response(
+        test_sdk: &TestSdk,
+        amount_sat: u64,
+        description_hash: sha256::Hash,
+        success_action: Option<Value>,
+    ) -> String {
+        let invoice = new_test_invoice(
+            amount_sat * 1000,
+            sha256::Hash::hash(mock_hash("lnurl preimage").as_bytes()),
+            "",
+            Some(description_hash),
+            None,
+        );
+        test_sdk.rest_client.add_response(
+            CALLBACK_URL,
+            json!({ "pr": invoice, "successAction": success_action }),
+        );
+        test_sdk.swapper.mrh.lock().unwrap().insert(
+            invoice.clone(),
+            (
+                TEST_LIQUID_ADDRESS.to_string(),
+                amount_sat as f64 / 100_000_000.0,
+            ),
+        );
+        invoice
+    }
+
+    fn add_withdraw_request(
+        rest_client: &MockRestClient,
+        min_withdrawable_sat: u64,
+        max_withdrawable_sat: u64,
+    ) {
+        rest_client.add_response(
+            WITHDRAW_URL,
+            json!({
+                "callback": WITHDRAW_CALLBACK_URL,
+                "k1": "withdraw-k1",
+                "minWithdrawable": min_withdrawable_sat * 1000,
+                "maxWithdrawable": max_withdrawable_sat * 1000,
+                "defaultDescription": "Withdrawal",
+                "tag": "withdrawRequest",
+            }),
+        );
+    }
+
+    fn withdraw_request(amount_sat: u64) -> LnUrlWithdrawRequest {
+        LnUrlWithdrawRequest {
+            lnurl: WITHDRAW_LNURL.to_string(),
+            amount_sat,
+        }
+    }
+
+    fn metadata_hash() -> sha256::Hash {
+        sha256::Hash::hash(METADATA.as_bytes())
+    }
+
+    fn prepare_request(amount_sat: u64, comment: Option<&str>) -> PrepareLnUrlPayRequest {
+        PrepareLnUrlPayRequest {
+            lnurl: LIGHTNING_ADDRESS.to_string(),
+            amount_sat,
+            comment: comment.map(str::to_string),
+        }
+    }
+
+    #[tokio::test]
+    async fn prepare_lnurl_pay_amount_limits() -> Result<()> {
+        let test_sdk = new_test_sdk().await?;
+        let sdk = &test_sdk.sdk;
+        // The Send Swap minimum of 1000 sat is above the service minimum
+        add_pay_request(&test_sdk.rest_client, 500, 100_000);
+        add_callback_response(&test_sdk, 50_000, metadata_hash(), None);
+
+        for amount_sat in [0, 500, 999, 100_001] {
+            assert!(
+                matches!(
+                    sdk.prepare_lnurl_pay(&prepare_request(amount_sat, None))
+                        .await,
+                    Err(PaymentError::AmountOutOfRange)
+                ),
+                "Expecting {amount_sat} sat to be out of range"
+            );
+        }
+        // The callback is not called for amounts out of range
+        assert!(test_sdk
+            .rest_client
+            .requested_urls
+            .lock()
+            .unwrap()
+            .iter()
+            .all(|url| url == PAY_URL));
+
+        add_pay_request(&test_sdk.rest_client, 2_000, 100_000);
+        assert!(matches!(
+            sdk.prepare_lnurl_pay(&prepare_request(1_999, None)).await,
+            Err(PaymentError::AmountOutOfRange)
+        ));
+
+        let response = sdk
+            .prepare_lnurl_pay(&prepare_request(50_000, Some("Thanks")))
+            .await?;
+        assert!(response.success_action.is_none());
+        assert_eq!(
+            response.prepare_send_response.fees_sat,
+            response.prepare_send_response.fee_breakdown.total_fees_sat
+        );
+        assert_eq!(
+            test_sdk.rest_client.requested_urls.lock().unwrap().last(),
+            Some(&format!("{CALLBACK_URL}?amount=50000000&comment=Thanks"))
+        );
+
+        // The comment is limited to the length set by the service
+        assert!(matches!(
+            sdk.prepare_lnurl_pay(&prepare_request(50_000, Some(&"a".repeat(21))))
+                .await,
+            Err(PaymentError::LnUrlError { .. })
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn prepare_lnurl_pay_invoice_checks() -> Result<()> {
+        let test_sdk = new_test_sdk().await?;
+        let sdk = &test_sdk.sdk;
+        add_pay_request(&test_sdk.rest_client, 1_000, 100_000);
+
+        add_callback_response(
+            &test_sdk,
+            50_000,
+            sha256::Hash::hash(b"other metadata"),
+            None,
+        );
+        assert!(matches!(
+            sdk.prepare_lnurl_pay(&prepare_request(50_000, None)).await,
+            Err(PaymentError::LnUrlError { .. })
+        ));
+
+        add_callback_response(&test_sdk, 40_000, metadata_hash(), None);
+        assert!(matches!(
+            sdk.prepare_lnurl_pay(&prepare_request(50_000, None)).await,
+            Err(PaymentError::LnUrlError { .. })
+        ));
+
+        test_sdk.rest_client.add_response(
+            CALLBACK_URL,
+            json!({ "status": "ERROR", "reason": "Service offline" }),
+        );
+        assert!(matches!(
+            sdk.prepare_lnurl_pay(&prepare_request(50_000, None)).await,
+            Err(PaymentError::LnUrlServiceError { err }) if err == "Service offline"
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn lnurl_pay_message_success_action() -> Result<()> {
+        let test_sdk = new_test_sdk().await?;
+        let sdk = &test_sdk.sdk;
+        add_pay_request(&test_sdk.rest_client, 1_000, 100_000);
+        let invoice = add_callback_response(
+            &test_sdk,
+            50_000,
+            metadata_hash(),
+            Some(json!({ "tag": "message", "message": "Thank you" })),
+        );
+
+        let prepare_response = sdk
+            .prepare_lnurl_pay(&prepare_request(50_000, None))
+            .await?;
+        assert_eq!(prepare_response.prepare_send_response.invoice, invoice);
+        let result = sdk.lnurl_pay(&prepare_response).await?;
+
+        assert!(matches!(
+            result.success_action,
+            Some(SuccessActionProcessed::Message { message }) if message == "Thank you"
+        ));
+        assert_eq!(result.payment.payment_type, PaymentType::Send);
+        assert_eq!(
+            result.payment.fees_sat,
+            prepare_response.prepare_send_response.fees_sat
+        );
+        assert_eq!(test_sdk.swapper.broadcast_tx_hexes.lock().unwrap().len(), 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn lnurl_pay_url_success_action() -> Result<()> {
+        let test_sdk = new_test_sdk().await?;
+        let sdk = &test_sdk.sdk;
+        add_pay_request(&test_sdk.rest_client, 1_000, 100_000);
+
+        // The URL must be on the domain of the callback
+        add_callback_response(
+            &test_sdk,
+            50_000,
+            metadata_hash(),
+            Some(json!({
+                "tag": "url",
+                "description": "Your order",
+                "url": "https://example.org/order/1",
+            })),
+        );
+        assert!(matches!(
+            sdk.prepare_lnurl_pay(&prepare_request(50_000, None)).await,
+            Err(PaymentError::LnUrlError { .. })
+        ));
+
+        add_callback_response(
+            &test_sdk,
+            50_000,
+            metadata_hash(),
+            Some(json!({
+                "tag": "url",
+                "description": "Your order",
+                "url": "https://example.com/order/1",
+            })),
+        );
+        let prepare_response = sdk
+            .prepare_lnurl_pay(&prepare_request(50_000, None))
+            .await?;
+        let result = sdk.lnurl_pay(&prepare_response).await?;
+
+        assert!(matches!(
+            result.success_action,
+            Some(SuccessActionProcessed::Url { description, url })
+                if description == "Your order" && url == "https://example.com/order/1"
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn lnurl_withdraw_amount_limits() -> Result<()> {
+        let test_sdk = new_test_sdk().await?;
+        let sdk = &test_sdk.sdk;
+        test_sdk
+            .rest_client
+            .add_response(WITHDRAW_CALLBACK_URL, json!({ "status": "OK" }));
+
+        add_withdraw_request(&test_sdk.rest_client, 2_000, 100_000);
+        for amount_sat in [1_999, 100_001] {
+            assert!(
+                matches!(
+                    sdk.lnurl_withdraw(&withdraw_request(amount_sat)).await,
+                    Err(PaymentError::AmountOutOfRange)
+                ),
+                "Expecting {amount_sat} sat to be out of range"
+            );
+        }
+
+        // The Receive Swap minimum of 1000 sat is above the service minimum
+        add_withdraw_request(&test_sdk.rest_client, 500, 100_000);
+        assert!(matches!(
+            sdk.lnurl_withdraw(&withdraw_request(999)).await,
+            Err(PaymentError::AmountOutOfRange)
+        ));
+
+        // No invoice was created or submitted for amounts out of range
+        assert!(test_sdk
+            .rest_client
+            .requested_urls
+            .lock()
+            .unwrap()
+            .iter()
+            .all(|url| url == WITHDRAW_URL));
+        assert!(test_sdk.persister.list_ongoing_swaps()?.is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn lnurl_withdraw_submits_invoice() -> Result<()> {
+        let test_sdk = new_test_sdk().await?;
+        let sdk = &test_sdk.sdk;
+        add_withdraw_request(&test_sdk.rest_client, 1_000, 100_000);
+        test_sdk
+            .rest_client
+            .add_response(WITHDRAW_CALLBACK_URL, json!({ "status": "OK" }));
+
+        let result = sdk.lnurl_withdraw(&withdraw_request(50_000)).await?;
+        let ReceivePaymentResponse { id, invoice } = result.receive_payment_response;
+
+        let callback_url = test_sdk
+            .rest_client
+            .requested_urls
+            .lock()
+            .unwrap()
+            .last()
+            .cloned()
+            .expect("Expecting the callback to be called");
+        let callback_url = url::Url::parse(&callback_url)?;
+        let params: Vec<(String, String)> = callback_url.query_pairs().into_owned().collect();
+        assert_eq!(
+            params,
+            vec![
+                ("k1".to_string(), "withdraw-k1".to_string()),
+                ("pr".to_string(), invoice.clone()),
+            ]
+        );
+
+        let swap = test_sdk
+            .persister
+            .fetch_receive_swap(&id)?
+            .expect("Expecting a persisted Receive Swap");
+        assert_eq!(swap.invoice, invoice);
+        assert_eq!(swap.payer_amount_sat, 50_000);
+        assert_eq!(swap.description.as_deref(), Some("Withdrawal"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn lnurl_withdraw_errors() -> Result<()> {
+        let test_sdk = new_test_sdk().await?;
+        let sdk = &test_sdk.sdk;
+
+        // A Lightning Address is an LNURL-pay
+        assert!(matches!(
+            sdk.lnurl_withdraw(&LnUrlWithdrawRequest {
+                lnurl: LIGHTNING_ADDRESS.to_string(),
+                amount_sat: 50_000,
+            })
+            .await,
+            Err(PaymentError::UnrecognizedInput { .. })
+        ));
+
+        add_pay_request(&test_sdk.rest_client, 1_000, 100_000);
+        assert!(matches!(
+            sdk.lnurl_withdraw(&LnUrlWithdrawRequest {
+                lnurl: "lnurlw://example.com/.well-known/lnurlp/satoshi".to_string(),
+                amount_sat: 50_000,
+            })
+            .await,
+            Err(PaymentError::LnUrlError { .. })
+        ));
+
+        add_withdraw_request(&test_sdk.rest_client, 1_000, 100_000);
+        test_sdk.rest_client.add_response(
+            WITHDRAW_CALLBACK_URL,
+            json!({ "status": "ERROR", "reason": "Already withdrawn" }),
+        );
+        assert!(matches!(
+            sdk.lnurl_withdraw(&withdraw_request(50_000)).await,
+            Err(PaymentError::LnUrlServiceError { err }) if err == "Already withdrawn"
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn decrypt_aes_success_action_with_preimage() -> Result<()> {
+        let preimage = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+        let iv = "EBESExQVFhcYGRobHB0eHw==";
+        let ciphertext = "O1mtJdgVgSHVsL7iO3ARbCTJGZlUwFDkLf/PPrfEoV8=";
+        assert_eq!(
+            decrypt_aes_success_action(ciphertext, iv, preimage)?,
+            "Your voucher code is 1234"
+        );
+
+        let wrong_preimage = "ff0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+        assert!(decrypt_aes_success_action(ciphertext, iv, wrong_preimage).is_err());
+
+        Ok(())
+    }
+}
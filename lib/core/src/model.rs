@@ -1,15 +1,19 @@
+use std::str::FromStr;
+
 use anyhow::{anyhow, Result};
 use boltz_client::network::Chain;
 use boltz_client::swaps::boltzv2::{
-    CreateReverseResponse, CreateSubmarineResponse, Leaf, SwapTree, BOLTZ_MAINNET_URL_V2,
-    BOLTZ_TESTNET_URL_V2,
+    ChainSwapDetails, CreateChainResponse, CreateReverseResponse, CreateSubmarineResponse, Leaf,
+    Side, SwapTree, BOLTZ_MAINNET_URL_V2, BOLTZ_TESTNET_URL_V2,
 };
-use boltz_client::{Keypair, LBtcSwapScriptV2, ToHex};
+use boltz_client::{BtcSwapScriptV2, Keypair, LBtcSwapScriptV2, ToHex};
+use lwk_wollet::elements::AddressParams;
 use lwk_wollet::ElementsNetwork;
 use rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ToSqlOutput, ValueRef};
 use rusqlite::ToSql;
 use serde::{Deserialize, Serialize};
 
+use crate::ensure_sdk;
 use crate::error::PaymentError;
 use crate::receive_swap::{
     DEFAULT_ZERO_CONF_MAX_SAT, DEFAULT_ZERO_CONF_MIN_FEE_RATE_MAINNET,
@@ -17,11 +21,28 @@ use crate::receive_swap::{
 };
 use crate::utils;
 
+/// Default URL of the Boltz API of the local Regtest stack
+const BOLTZ_REGTEST_URL_V2: &str = "http://localhost:9001/v2";
+/// Policy asset of a default `elementsd` Regtest chain
+const DEFAULT_REGTEST_POLICY_ASSET: &str =
+    "5ac9f65c0efcc4775e0baec4ec03abdde22473cd3cf33c0419ca290e0751b225";
+
 /// Configuration for the Liquid SDK
 #[derive(Clone, Debug, Serialize)]
 pub struct Config {
     pub boltz_url: String,
-    pub electrum_url: String,
+    /// Liquid Electrum servers, used for wallet sync and L-BTC swap txs. They are tried in
+    /// order, failing over to the next one when a request fails.
+    pub electrum_servers: Vec<ElectrumServer>,
+    /// Deprecated, use [Config::electrum_servers] instead. If set, this Liquid Electrum server
+    /// is tried first, before [Config::electrum_servers].
+    pub electrum_url: Option<String>,
+    /// Bitcoin Electrum server, used for the BTC side of chain swaps
+    pub bitcoin_electrum_url: String,
+    /// Liquid Esplora REST API, like `https://blockstream.info/liquid/api`. If set, it is used
+    /// instead of [Config::electrum_servers] for wallet sync, broadcasts, chain queries and fee
+    /// estimation.
+    pub esplora_url: Option<String>,
     /// Directory in which all SDK files (DB, log, cache) are stored.
     ///
     /// Prefix can be a relative or absolute path to this directory.
@@ -29,35 +50,144 @@ pub struct Config {
     pub network: Network,
     /// Send payment timeout. See [crate::sdk::LiquidSdk::send_payment]
     pub payment_timeout_sec: u64,
+    /// Interval of the background wallet sync, in seconds. With Electrum, the wallet is also
+    /// synced whenever a tx pays to or spends from one of its addresses or an ongoing swap
+    /// script, so this is only a fallback. Esplora has no such notifications, so a shorter
+    /// interval may be needed.
+    pub sync_fallback_interval_sec: u64,
     /// Zero-conf minimum accepted fee-rate in sat/vbyte
     pub zero_conf_min_fee_rate: f32,
     /// Maximum amount in satoshi to accept zero-conf payments with
     /// Defaults to [crate::receive_swap::DEFAULT_ZERO_CONF_MAX_SAT]
     pub zero_conf_max_amount_sat: Option<u64>,
+    /// Custom asset metadata, extending the built-in registry of [Config::default_asset_metadata].
+    /// Entries override the built-in ones with the same asset ID.
+    pub asset_metadata: Option<Vec<AssetMetadata>>,
+    /// Maximum fees of a Lightning payment (Send or Receive), in satoshi. Can be overridden per
+    /// payment.
+    pub max_fee_sat: Option<u64>,
+    /// Maximum fees of a Lightning payment (Send or Receive), as a percentage of the invoice
+    /// amount. Can be overridden per payment. Must be a non-negative number.
+    pub max_fee_percent: Option<f64>,
+    /// Policy asset (L-BTC) of the Regtest network, in hex. Defaults to the one of a default
+    /// `elementsd` Regtest chain. Ignored on the other networks.
+    pub regtest_policy_asset: Option<String>,
 }
 
 impl Config {
     pub fn mainnet() -> Self {
         Config {
             boltz_url: BOLTZ_MAINNET_URL_V2.to_owned(),
-            electrum_url: "blockstream.info:995".to_string(),
+            electrum_servers: vec![ElectrumServer::new("blockstream.info:995")],
+            electrum_url: None,
+            bitcoin_electrum_url: "blockstream.info:700".to_string(),
+            esplora_url: None,
             working_dir: ".".to_string(),
             network: Network::Mainnet,
             payment_timeout_sec: 15,
+            sync_fallback_interval_sec: 300,
             zero_conf_min_fee_rate: DEFAULT_ZERO_CONF_MIN_FEE_RATE_MAINNET,
             zero_conf_max_amount_sat: None,
+            asset_metadata: None,
+            max_fee_sat: None,
+            max_fee_percent: None,
+            regtest_policy_asset: None,
         }
     }
 
     pub fn testnet() -> Self {
         Config {
             boltz_url: BOLTZ_TESTNET_URL_V2.to_owned(),
-            electrum_url: "blockstream.info:465".to_string(),
+            electrum_servers: vec![ElectrumServer::new("blockstream.info:465")],
+            electrum_url: None,
+            bitcoin_electrum_url: "blockstream.info:993".to_string(),
+            esplora_url: None,
             working_dir: ".".to_string(),
             network: Network::Testnet,
             payment_timeout_sec: 15,
+            sync_fallback_interval_sec: 300,
             zero_conf_min_fee_rate: DEFAULT_ZERO_CONF_MIN_FEE_RATE_TESTNET,
             zero_conf_max_amount_sat: None,
+            asset_metadata: None,
+            max_fee_sat: None,
+            max_fee_percent: None,
+            regtest_policy_asset: None,
+        }
+    }
+
+    /// Config of a local Regtest stack of `elementsd`, `bitcoind`, `electrs` and Boltz, as run
+    /// for end-to-end tests
+    pub fn regtest() -> Self {
+        Config {
+            boltz_url: BOLTZ_REGTEST_URL_V2.to_owned(),
+            electrum_servers: vec![ElectrumServer {
+                url: "localhost:19002".to_string(),
+                tls: false,
+                validate_domain: false,
+            }],
+            electrum_url: None,
+            bitcoin_electrum_url: "localhost:19001".to_string(),
+            esplora_url: None,
+            working_dir: ".".to_string(),
+            network: Network::Regtest,
+            payment_timeout_sec: 15,
+            sync_fallback_interval_sec: 300,
+            zero_conf_min_fee_rate: DEFAULT_ZERO_CONF_MIN_FEE_RATE_TESTNET,
+            zero_conf_max_amount_sat: None,
+            asset_metadata: None,
+            max_fee_sat: None,
+            max_fee_percent: None,
+            regtest_policy_asset: None,
+        }
+    }
+
+    /// The Liquid network of the [Config], with the [Config::regtest_policy_asset] on Regtest
+    pub(crate) fn elements_network(&self) -> Result<ElementsNetwork> {
+        Ok(match self.network {
+            Network::Regtest => {
+                let policy_asset = self
+                    .regtest_policy_asset
+                    .as_deref()
+                    .unwrap_or(DEFAULT_REGTEST_POLICY_ASSET);
+                ElementsNetwork::ElementsRegtest {
+                    policy_asset: lwk_wollet::elements::AssetId::from_str(policy_asset)
+                        .map_err(|e| anyhow!("Invalid Regtest policy asset: {e}"))?,
+                }
+            }
+            Network::Mainnet => ElementsNetwork::Liquid,
+            Network::Testnet => ElementsNetwork::LiquidTestnet,
+        })
+    }
+
+    /// The asset ID of L-BTC, the policy asset of the Liquid network
+    pub(crate) fn lbtc_asset_id(&self) -> Result<lwk_wollet::elements::AssetId> {
+        Ok(self.elements_network()?.policy_asset())
+    }
+
+    /// The Liquid Electrum servers to fail over between: the deprecated [Config::electrum_url]
+    /// if set, then [Config::electrum_servers]
+    pub(crate) fn liquid_electrum_servers(&self) -> Vec<ElectrumServer> {
+        self.electrum_url
+            .iter()
+            .map(|url| self.electrum_server(url))
+            .chain(self.electrum_servers.iter().cloned())
+            .collect()
+    }
+
+    /// The server of [Config::bitcoin_electrum_url]
+    pub(crate) fn bitcoin_electrum_server(&self) -> ElectrumServer {
+        self.electrum_server(&self.bitcoin_electrum_url)
+    }
+
+    /// A server given by its URL only. On Regtest, it is connected to without TLS.
+    fn electrum_server(&self, url: &str) -> ElectrumServer {
+        match self.network {
+            Network::Regtest => ElectrumServer {
+                url: url.to_string(),
+                tls: false,
+                validate_domain: false,
+            },
+            _ => ElectrumServer::new(url),
         }
     }
 
@@ -65,6 +195,135 @@ impl Config {
         self.zero_conf_max_amount_sat
             .unwrap_or(DEFAULT_ZERO_CONF_MAX_SAT)
     }
+
+    /// Built-in registry of well-known assets of the network. On Regtest, it only has L-BTC,
+    /// the [Config::regtest_policy_asset].
+    pub fn default_asset_metadata(&self) -> Vec<AssetMetadata> {
+        match self.network {
+            Network::Mainnet => vec![
+                AssetMetadata::new(
+                    "6f0279e9ed041c3d710a9f57d0c02928416460c4b722ae3457a11eec381c526d",
+                    "L-BTC",
+                    "Liquid Bitcoin",
+                    8,
+                ),
+                AssetMetadata::new(
+                    "ce091c998b83c78bb71a632313ba3760f1763d9cfcffae02258ffa9865a37bd2",
+                    "USDt",
+                    "Tether USD",
+                    8,
+                ),
+            ],
+            Network::Testnet => vec![
+                AssetMetadata::new(
+                    "144c654344aa716d6f3abcc1ca90e5641e4e2a7f633bc09fe3baf64585819a49",
+                    "L-BTC",
+                    "Liquid Bitcoin",
+                    8,
+                ),
+                AssetMetadata::new(
+                    "b612eb46313a2cd6ebabd8b7a8eed5696e29898b87a43bff41c94f51acef9d73",
+                    "USDt",
+                    "Tether USD",
+                    8,
+                ),
+            ],
+            // An invalid policy asset fails the SDK connection, so it has no metadata either
+            Network::Regtest => self
+                .lbtc_asset_id()
+                .map(|asset_id| {
+                    vec![AssetMetadata::new(
+                        &asset_id.to_string(),
+                        "L-BTC",
+                        "Liquid Bitcoin",
+                        8,
+                    )]
+                })
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Looks up the metadata of an asset, first in [Config::asset_metadata], then in the built-in
+    /// registry of the network
+    pub fn get_asset_metadata(&self, asset_id: &str) -> Option<AssetMetadata> {
+        self.asset_metadata
+            .iter()
+            .flatten()
+            .cloned()
+            .chain(self.default_asset_metadata())
+            .find(|metadata| metadata.asset_id == asset_id)
+    }
+}
+
+/// A Liquid Electrum server, see [Config::electrum_servers]
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ElectrumServer {
+    /// Host and port of the server, like `blockstream.info:995`
+    pub url: String,
+    /// Whether to connect over TLS
+    pub tls: bool,
+    /// Whether to check that the TLS certificate matches the host. Only disable it for servers
+    /// with self-signed certificates.
+    pub validate_domain: bool,
+}
+impl ElectrumServer {
+    /// A server connected to over TLS, with domain validation
+    pub fn new(url: &str) -> Self {
+        ElectrumServer {
+            url: url.to_string(),
+            tls: true,
+            validate_domain: true,
+        }
+    }
+}
+
+/// The health of an [ElectrumServer], as returned by
+/// [crate::sdk::LiquidSdk::list_electrum_servers]
+#[derive(Clone, Debug, Serialize)]
+pub struct ElectrumServerStatus {
+    pub url: String,
+    /// Whether the last successful request went to this server
+    pub in_use: bool,
+    /// Whether the server is tried before the failed ones. A failed server becomes healthy
+    /// again after a cooldown or a successful request.
+    pub healthy: bool,
+    /// Number of failed requests since the last successful one
+    pub consecutive_failures: u32,
+    /// Error of the last failed request, cleared by a successful one
+    pub last_error: Option<String>,
+}
+
+/// Display metadata of a Liquid asset
+#[derive(Clone, Debug, Serialize)]
+pub struct AssetMetadata {
+    /// The asset ID, in hex
+    pub asset_id: String,
+    pub ticker: String,
+    pub name: String,
+    /// Number of decimals of the asset: an amount in base units is divided by `10^precision`
+    /// to get the amount to display
+    pub precision: u8,
+}
+impl AssetMetadata {
+    fn new(asset_id: &str, ticker: &str, name: &str, precision: u8) -> Self {
+        AssetMetadata {
+            asset_id: asset_id.to_string(),
+            ticker: ticker.to_string(),
+            name: name.to_string(),
+            precision,
+        }
+    }
+}
+
+/// The balance of a single asset held by the wallet
+#[derive(Clone, Debug, Serialize)]
+pub struct AssetBalance {
+    /// The asset ID, in hex
+    pub asset_id: String,
+    /// Balance in the asset's base units. For L-BTC, this is in satoshi.
+    pub balance: u64,
+    /// Metadata of the asset, if it's known
+    pub asset_metadata: Option<AssetMetadata>,
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Serialize)]
@@ -73,13 +332,20 @@ pub enum Network {
     Mainnet,
     /// Testnet Bitcoin and Liquid chains
     Testnet,
+    /// Local Regtest Bitcoin and Liquid chains, see [Config::regtest]
+    Regtest,
 }
 
+/// On Regtest, this is the network of the default policy asset. Use [Config::elements_network]
+/// for the one of a [Config].
 impl From<Network> for ElementsNetwork {
     fn from(value: Network) -> Self {
         match value {
             Network::Mainnet => ElementsNetwork::Liquid,
             Network::Testnet => ElementsNetwork::LiquidTestnet,
+            Network::Regtest => Config::regtest()
+                .elements_network()
+                .expect("Default Regtest policy asset is valid"),
         }
     }
 }
@@ -89,6 +355,28 @@ impl From<Network> for Chain {
         match value {
             Network::Mainnet => Chain::Liquid,
             Network::Testnet => Chain::LiquidTestnet,
+            Network::Regtest => Chain::LiquidRegtest,
+        }
+    }
+}
+
+impl From<Network> for lwk_wollet::bitcoin::Network {
+    fn from(value: Network) -> Self {
+        match value {
+            Network::Mainnet => lwk_wollet::bitcoin::Network::Bitcoin,
+            Network::Testnet => lwk_wollet::bitcoin::Network::Testnet,
+            Network::Regtest => lwk_wollet::bitcoin::Network::Regtest,
+        }
+    }
+}
+
+impl Network {
+    /// The Bitcoin chain paired with this network, used for the BTC side of chain swaps
+    pub(crate) fn as_bitcoin_chain(&self) -> Chain {
+        match self {
+            Network::Mainnet => Chain::Bitcoin,
+            Network::Testnet => Chain::BitcoinTestnet,
+            Network::Regtest => Chain::BitcoinRegtest,
         }
     }
 }
@@ -100,6 +388,7 @@ impl TryFrom<&str> for Network {
         match value.to_lowercase().as_str() {
             "mainnet" => Ok(Network::Mainnet),
             "testnet" => Ok(Network::Testnet),
+            "regtest" => Ok(Network::Regtest),
             _ => Err(anyhow!("Invalid network")),
         }
     }
@@ -114,6 +403,7 @@ impl TryFrom<boltz_client::lightning_invoice::Currency> for Network {
         match value {
             boltz_client::lightning_invoice::Currency::Bitcoin => Ok(Network::Mainnet),
             boltz_client::lightning_invoice::Currency::BitcoinTestnet => Ok(Network::Testnet),
+            boltz_client::lightning_invoice::Currency::Regtest => Ok(Network::Regtest),
             _ => Err(anyhow!("Invalid network")),
         }
     }
@@ -131,6 +421,7 @@ pub enum LiquidSdkEvent {
     PaymentFailed { details: Payment },
     PaymentPending { details: Payment },
     PaymentRefunded { details: Payment },
+    PaymentRefundable { details: Payment },
     PaymentRefundPending { details: Payment },
     PaymentSucceeded { details: Payment },
     PaymentWaitingConfirmation { details: Payment },
@@ -145,13 +436,48 @@ pub struct ConnectRequest {
 
 #[derive(Debug, Serialize)]
 pub struct PrepareReceiveRequest {
-    pub payer_amount_sat: u64,
+    /// The invoice amount. Exactly one of `payer_amount_sat` and `receiver_amount_sat` must be
+    /// set.
+    pub payer_amount_sat: Option<u64>,
+    /// The amount to receive after fees. The invoice amount is derived from it.
+    pub receiver_amount_sat: Option<u64>,
+    /// Description of the invoice. Cannot be set together with `description_hash`.
+    pub description: Option<String>,
+    /// Hex-encoded SHA256 hash of the invoice description, as needed for example by LNURL-pay
+    /// services. Cannot be set together with `description`.
+    pub description_hash: Option<String>,
+    /// Invoice expiry in seconds. If not set, the swapper default is used.
+    pub invoice_expiry_secs: Option<u64>,
+    /// Stored with the Receive Swap once it is created
+    pub metadata: Option<PaymentMetadata>,
+    /// Overrides the `max_fee_sat` of the [Config]
+    pub max_fee_sat: Option<u64>,
+    /// Overrides the `max_fee_percent` of the [Config]
+    pub max_fee_percent: Option<f64>,
+    /// Fee rate of the claim tx. If not set, the claim fee estimated by the swapper is used.
+    pub fee_rate: Option<OnchainFeeRate>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Clone)]
 pub struct PrepareReceiveResponse {
+    /// The invoice amount
     pub payer_amount_sat: u64,
+    /// The amount received after fees, `payer_amount_sat` minus `fees_sat`
+    pub receiver_amount_sat: u64,
     pub fees_sat: u64,
+    pub description: Option<String>,
+    pub description_hash: Option<String>,
+    pub invoice_expiry_secs: Option<u64>,
+    pub metadata: Option<PaymentMetadata>,
+    /// The parts of `fees_sat`
+    pub fee_breakdown: FeeBreakdown,
+    /// The hash of the swapper pair the fees were quoted from
+    pub pair_hash: String,
+    /// Epoch time, in seconds, until which the quoted fees are honored. Afterwards, receiving
+    /// fails with [PaymentError::InvalidOrExpiredFees] and the payment must be prepared again.
+    pub expires_at: u32,
+    /// The fee rate of the claim tx in sat/vbyte, or `None` for the swapper estimate
+    pub fee_rate_sat_per_vbyte: Option<f64>,
 }
 
 #[derive(Debug, Serialize)]
@@ -160,15 +486,163 @@ pub struct ReceivePaymentResponse {
     pub invoice: String,
 }
 
+#[derive(Debug, Serialize, Clone)]
+pub struct LnUrlWithdrawRequest {
+    /// An LNURL-withdraw (bech32 or LUD-17 URL)
+    pub lnurl: String,
+    /// Amount to withdraw. It is the amount of the invoice, so swap fees are deducted from it.
+    pub amount_sat: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LnUrlWithdrawResult {
+    /// The Receive Swap whose invoice was submitted to the LNURL service. Its `id` is the
+    /// swap id of the resulting [Payment].
+    pub receive_payment_response: ReceivePaymentResponse,
+}
+
 #[derive(Debug, Serialize, Clone)]
 pub struct PrepareSendRequest {
     pub invoice: String,
+    /// Stored with the payment once it is sent
+    pub metadata: Option<PaymentMetadata>,
+    /// Overrides the `max_fee_sat` of the [Config]
+    pub max_fee_sat: Option<u64>,
+    /// Overrides the `max_fee_percent` of the [Config]
+    pub max_fee_percent: Option<f64>,
+    /// Fee rate of the lockup tx, or of the onchain tx if the invoice has an MRH. If not set, the
+    /// wallet default is used.
+    pub fee_rate: Option<OnchainFeeRate>,
 }
 
 #[derive(Debug, Serialize, Clone)]
 pub struct PrepareSendResponse {
     pub invoice: String,
     pub fees_sat: u64,
+    pub metadata: Option<PaymentMetadata>,
+    /// The parts of `fees_sat`
+    pub fee_breakdown: FeeBreakdown,
+    /// The fee limits of the [PrepareSendRequest], checked again when sending
+    pub max_fee_sat: Option<u64>,
+    pub max_fee_percent: Option<f64>,
+    /// The hash of the swapper pair the fees were quoted from
+    pub pair_hash: String,
+    /// Epoch time, in seconds, until which the quoted swapper fees are honored. Afterwards,
+    /// sending fails with [PaymentError::InvalidOrExpiredFees] and the payment must be prepared
+    /// again.
+    pub expires_at: u32,
+    /// The fee rate of the wallet tx in sat/vbyte, or `None` for the wallet default
+    pub fee_rate_sat_per_vbyte: Option<f64>,
+}
+
+/// The fees of a Send or Receive Swap, as quoted by the swapper when the swap is prepared
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FeeBreakdown {
+    /// The swapper service fee, as a percentage of the invoice amount
+    pub service_fee_percentage: f64,
+    pub service_fee_sat: u64,
+    /// The miner fees of the swapper txs, charged by the swapper: the claim tx of a Send Swap,
+    /// or the lockup tx of a Receive Swap
+    pub swapper_miner_fees_sat: u64,
+    /// The Liquid network fee of the wallet tx: the lockup tx of a Send Swap, or the claim tx of
+    /// a Receive Swap (estimated by the swapper). For a payment without swap, this is the fee of
+    /// the onchain tx, and the other fees are zero.
+    pub network_fee_sat: u64,
+    /// The sum of all the fees above
+    pub total_fees_sat: u64,
+}
+/// Persisted as JSON, so that fields can be added without a migration
+impl ToSql for FeeBreakdown {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        let json = serde_json::to_string(self)
+            .map_err(|e| rusqlite::Error::ToSqlConversionFailure(Box::new(e)))?;
+        Ok(rusqlite::types::ToSqlOutput::from(json))
+    }
+}
+impl FromSql for FeeBreakdown {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        match value {
+            ValueRef::Text(json) => {
+                serde_json::from_slice(json).map_err(|e| FromSqlError::Other(Box::new(e)))
+            }
+            _ => Err(FromSqlError::InvalidType),
+        }
+    }
+}
+
+/// The current limits and fees of Lightning payments, see
+/// [LiquidSdk::fetch_lightning_limits](crate::sdk::LiquidSdk::fetch_lightning_limits)
+#[derive(Debug, Clone, Serialize)]
+pub struct LightningPaymentLimitsResponse {
+    pub send: LightningLimits,
+    pub receive: LightningLimits,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LightningLimits {
+    /// Minimum invoice amount
+    pub min_sat: u64,
+    /// Maximum invoice amount
+    pub max_sat: u64,
+    /// The swapper service fee, as a percentage of the invoice amount
+    pub service_fee_percentage: f64,
+    /// The fixed miner fees, on top of the service fee: for Send, the swapper claim tx fee (the
+    /// fee of the wallet lockup tx comes on top), for Receive, the swapper lockup tx fee and the
+    /// estimated claim tx fee
+    pub miner_fees_sat: u64,
+}
+
+/// How fast an onchain tx should confirm, see [RecommendedFees]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub enum FeePriority {
+    /// Next block
+    Fastest,
+    /// Within 3 blocks
+    HalfHour,
+    /// Within 6 blocks
+    Hour,
+    /// Within a day
+    Economy,
+    /// The lowest fee rate relayed by the network
+    Minimum,
+}
+
+/// The fee rate of an onchain tx, either explicit or estimated for a [FeePriority]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub enum OnchainFeeRate {
+    SatPerVbyte { sat_per_vbyte: f64 },
+    Priority { priority: FeePriority },
+}
+
+/// Fee rates of a chain, in sat/vbyte, estimated by its Electrum server. The estimates are never
+/// lower than `minimum_fee`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RecommendedFees {
+    pub fastest_fee: f64,
+    pub half_hour_fee: f64,
+    pub hour_fee: f64,
+    pub economy_fee: f64,
+    pub minimum_fee: f64,
+}
+impl RecommendedFees {
+    pub fn fee_rate(&self, priority: FeePriority) -> f64 {
+        match priority {
+            FeePriority::Fastest => self.fastest_fee,
+            FeePriority::HalfHour => self.half_hour_fee,
+            FeePriority::Hour => self.hour_fee,
+            FeePriority::Economy => self.economy_fee,
+            FeePriority::Minimum => self.minimum_fee,
+        }
+    }
+}
+
+/// See [LiquidSdk::fetch_recommended_fees](crate::sdk::LiquidSdk::fetch_recommended_fees)
+#[derive(Debug, Clone, Serialize)]
+pub struct RecommendedFeesResponse {
+    /// For Liquid txs: lockups, claims, refunds of Send Swaps and direct sends
+    pub liquid: RecommendedFees,
+    /// For Bitcoin txs: refunds of Incoming Chain Swaps
+    pub bitcoin: RecommendedFees,
 }
 
 #[derive(Debug, Serialize)]
@@ -176,6 +650,365 @@ pub struct SendPaymentResponse {
     pub payment: Payment,
 }
 
+#[derive(Debug, Serialize, Clone)]
+pub struct PrepareLnUrlPayRequest {
+    /// An LNURL-pay (bech32 or LUD-17 URL) or a Lightning Address
+    pub lnurl: String,
+    pub amount_sat: u64,
+    /// Comment for the payee, if the LNURL service allows it
+    pub comment: Option<String>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct PrepareLnUrlPayResponse {
+    /// The prepared payment of the invoice returned by the LNURL service
+    pub prepare_send_response: PrepareSendResponse,
+    /// The success action to process once the payment succeeds
+    pub success_action: Option<SuccessAction>,
+}
+
+/// A success action of an LNURL-pay invoice, as returned by the service (LUD-09 and LUD-10)
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "tag", rename_all = "lowercase")]
+pub enum SuccessAction {
+    Message {
+        message: String,
+    },
+    Url {
+        description: String,
+        url: String,
+    },
+    /// A message encrypted with the payment preimage
+    Aes {
+        description: String,
+        ciphertext: String,
+        iv: String,
+    },
+}
+
+/// A success action after the payment, with the AES message decrypted
+#[derive(Clone, Debug, Serialize)]
+pub enum SuccessActionProcessed {
+    Message {
+        message: String,
+    },
+    Url {
+        description: String,
+        url: String,
+    },
+    Aes {
+        description: String,
+        plaintext: String,
+    },
+}
+
+#[derive(Debug, Serialize)]
+pub struct LnUrlPayResult {
+    pub payment: Payment,
+    pub success_action: Option<SuccessActionProcessed>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct PrepareSendOnchainRequest {
+    /// A Liquid address, or a BIP21 URI like `liquidnetwork:<address>?amount=<amount>`
+    pub destination: String,
+    /// Amount to send. If not set, the amount of the BIP21 URI is used.
+    pub amount_sat: Option<u64>,
+    /// If set, the whole wallet balance is sent, minus fees, and `amount_sat` is ignored
+    pub send_all: bool,
+    /// The issued asset to send. If not set, the asset of the BIP21 URI is used, else L-BTC.
+    ///
+    /// For an issued asset, `amount_sat` is in the asset's base units.
+    pub asset_id: Option<String>,
+    /// Fee rate of the tx. If not set, the wallet default is used.
+    pub fee_rate: Option<OnchainFeeRate>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct PrepareSendOnchainResponse {
+    /// The Liquid address to send to
+    pub address: String,
+    /// Amount that will be received. For an issued asset, this is in the asset's base units.
+    pub receiver_amount_sat: u64,
+    /// The L-BTC tx fees
+    pub fees_sat: u64,
+    pub send_all: bool,
+    /// The issued asset to send, or `None` for L-BTC
+    pub asset_id: Option<String>,
+    /// The fee rate of the tx in sat/vbyte, or `None` for the wallet default
+    pub fee_rate_sat_per_vbyte: Option<f64>,
+}
+
+/// The type of a user input, as classified by [LiquidSdk::parse](crate::sdk::LiquidSdk::parse)
+#[derive(Clone, Debug, Serialize)]
+pub enum InputType {
+    /// A BOLT11 invoice, either on its own or from the `lightning` param of a bitcoin BIP21 URI
+    Bolt11 { invoice: LNInvoice },
+    /// A Liquid address, or a `liquidnetwork:`/`liquidtestnet:` BIP21 URI
+    LiquidAddress { address: LiquidAddressData },
+    /// A BTC address, or a `bitcoin:` BIP21 URI without a `lightning` param
+    BitcoinAddress { address: BitcoinAddressData },
+    /// A bech32-encoded LNURL or a LUD-17 URL (`lnurlp://`, `lnurlw://`), decoded to the
+    /// endpoint URL
+    LnUrl { url: String },
+    /// A Lightning Address (`user@domain`), with the LNURL-pay endpoint URL it resolves to
+    LightningAddress { address: String, url: String },
+}
+
+/// A BTC address, optionally with the parameters of a BIP21 URI
+#[derive(Clone, Debug, Serialize)]
+pub struct BitcoinAddressData {
+    pub address: String,
+    pub network: Network,
+    pub amount_sat: Option<u64>,
+    pub label: Option<String>,
+    pub message: Option<String>,
+}
+
+/// A Liquid address, optionally with the parameters of a BIP21 URI
+#[derive(Clone, Debug, Serialize)]
+pub struct LiquidAddressData {
+    pub address: String,
+    pub network: Network,
+    pub amount_sat: Option<u64>,
+    pub asset_id: Option<String>,
+    pub label: Option<String>,
+    pub message: Option<String>,
+}
+impl LiquidAddressData {
+    pub(crate) fn uri_scheme(network: Network) -> &'static str {
+        match network {
+            Network::Mainnet => "liquidnetwork",
+            // Regtest has no scheme of its own
+            Network::Testnet | Network::Regtest => "liquidtestnet",
+        }
+    }
+
+    /// Parses a Liquid address, or a BIP21 URI with the `liquidnetwork:` or `liquidtestnet:` scheme
+    pub(crate) fn from_addr_or_uri(input: &str) -> Result<Self, PaymentError> {
+        let input = input.trim();
+        let (scheme, address_and_params) = match input.split_once(':') {
+            Some((scheme, rest)) => (Some(scheme.to_lowercase()), rest),
+            None => (None, input),
+        };
+        let (address, params) = address_and_params
+            .split_once('?')
+            .unwrap_or((address_and_params, ""));
+
+        let parsed_address = lwk_wollet::elements::Address::from_str(address).map_err(|err| {
+            PaymentError::Generic {
+                err: format!("Invalid L-BTC address: {err}"),
+            }
+        })?;
+        let network = match parsed_address.params {
+            params if *params == AddressParams::LIQUID => Network::Mainnet,
+            params if *params == AddressParams::LIQUID_TESTNET => Network::Testnet,
+            params if *params == AddressParams::ELEMENTS => Network::Regtest,
+            _ => {
+                return Err(PaymentError::Generic {
+                    err: "L-BTC address is not a Liquid mainnet, testnet or regtest address"
+                        .to_string(),
+                })
+            }
+        };
+        if let Some(scheme) = scheme {
+            ensure_sdk!(
+                scheme == Self::uri_scheme(network),
+                PaymentError::Generic {
+                    err: format!("Invalid BIP21 URI scheme: {scheme}")
+                }
+            );
+        }
+
+        let mut res = LiquidAddressData {
+            address: address.to_string(),
+            network,
+            amount_sat: None,
+            asset_id: None,
+            label: None,
+            message: None,
+        };
+        for (key, value) in url::form_urlencoded::parse(params.as_bytes()) {
+            match key.as_ref() {
+                "amount" => {
+                    res.amount_sat = Some(utils::parse_btc_amount_sat(&value).ok_or(
+                        PaymentError::Generic {
+                            err: format!("Invalid BIP21 amount: {value}"),
+                        },
+                    )?)
+                }
+                "assetid" => res.asset_id = Some(value.into_owned()),
+                "label" => res.label = Some(value.into_owned()),
+                "message" => res.message = Some(value.into_owned()),
+                _ => {}
+            }
+        }
+        Ok(res)
+    }
+
+    /// Builds a BIP21 URI with the `liquidnetwork:` or `liquidtestnet:` scheme
+    pub(crate) fn to_uri(&self) -> String {
+        let mut params = vec![];
+        if let Some(amount_sat) = self.amount_sat {
+            params.push(("amount", utils::format_btc_amount(amount_sat)));
+        }
+        if let Some(asset_id) = &self.asset_id {
+            params.push(("assetid", asset_id.clone()));
+        }
+        if let Some(label) = &self.label {
+            params.push(("label", label.clone()));
+        }
+        if let Some(message) = &self.message {
+            params.push(("message", message.clone()));
+        }
+
+        let scheme = Self::uri_scheme(self.network);
+        let query = params
+            .iter()
+            // BIP21 expects percent-encoded spaces, rather than `+`
+            .map(|(key, value)| {
+                let value: String =
+                    url::form_urlencoded::byte_serialize(value.as_bytes()).collect();
+                format!("{key}={}", value.replace('+', "%20"))
+            })
+            .collect::<Vec<_>>()
+            .join("&");
+        match query.is_empty() {
+            true => format!("{scheme}:{}", self.address),
+            false => format!("{scheme}:{}?{query}", self.address),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct PreparePayOnchainRequest {
+    /// Amount the BTC destination address should receive
+    pub receiver_amount_sat: u64,
+    /// Fee rate of the L-BTC lockup tx. If not set, the wallet default is used.
+    pub fee_rate: Option<OnchainFeeRate>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct PreparePayOnchainResponse {
+    pub receiver_amount_sat: u64,
+    /// Total fees: swap service fee, swapper miner fees, BTC claim fee and L-BTC lockup fee
+    pub fees_sat: u64,
+    /// The fee rate of the L-BTC lockup tx in sat/vbyte, or `None` for the wallet default
+    pub fee_rate_sat_per_vbyte: Option<f64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PayOnchainRequest {
+    /// The BTC address to send to
+    pub address: String,
+    pub prepare_res: PreparePayOnchainResponse,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PrepareReceiveOnchainRequest {
+    /// Amount of BTC the payer will send to the swap lockup address
+    pub payer_amount_sat: u64,
+    /// Fee rate of the L-BTC claim tx. If not set, the claim fee estimated by the swapper is
+    /// used.
+    pub fee_rate: Option<OnchainFeeRate>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct PrepareReceiveOnchainResponse {
+    pub payer_amount_sat: u64,
+    /// Total fees: swap service fee, swapper miner fees and L-BTC claim fee
+    pub fees_sat: u64,
+    /// The fee rate of the L-BTC claim tx in sat/vbyte, or `None` for the swapper estimate
+    pub fee_rate_sat_per_vbyte: Option<f64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ReceiveOnchainResponse {
+    /// The swap ID
+    pub id: String,
+    /// The BTC lockup address the payer has to send funds to
+    pub address: String,
+    /// BIP21 URI for the lockup address, including the amount
+    pub bip21: String,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct ReceiveLiquidRequest {
+    /// Amount the payer should send. If not set, the payer chooses the amount.
+    pub amount_sat: Option<u64>,
+    /// Label shown on the payment once received, and added to the BIP21 URI
+    pub label: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ReceiveLiquidResponse {
+    /// The wallet L-BTC address the payer has to send funds to
+    pub address: String,
+    /// BIP21 URI for the address, including the amount and label
+    pub bip21: String,
+}
+
+/// A request to receive L-BTC directly to a wallet address, created by
+/// [LiquidSdk::receive_liquid](crate::sdk::LiquidSdk::receive_liquid)
+#[derive(Clone, Debug)]
+pub(crate) struct LiquidReceiveRequest {
+    pub(crate) address: String,
+    /// Hex-encoded script pubkey of the address, used to find the txs paying this request
+    pub(crate) script_pubkey: String,
+    pub(crate) amount_sat: Option<u64>,
+    pub(crate) label: Option<String>,
+    pub(crate) created_at: u32,
+    /// The first tx paying this request
+    pub(crate) tx_id: Option<String>,
+}
+
+/// A swap whose lockup can be refunded
+#[derive(Clone, Debug, Serialize)]
+pub struct RefundableSwap {
+    pub swap_id: String,
+    /// The lockup address of the swap
+    pub swap_address: String,
+    /// Swap creation timestamp
+    pub timestamp: u32,
+    /// Amount locked up by the payer
+    pub amount_sat: u64,
+    /// [PaymentType::Send] swaps are refunded to an L-BTC address, [PaymentType::Receive] swaps
+    /// to a BTC address
+    pub payment_type: PaymentType,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PrepareRefundRequest {
+    pub swap_id: String,
+    /// The address the refunded funds are sent to
+    pub refund_address: String,
+    /// Fee rate of the refund tx, on the chain of the refund address
+    pub fee_rate: OnchainFeeRate,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PrepareRefundResponse {
+    pub tx_vsize: u32,
+    pub tx_fee_sat: u64,
+    /// Amount the refund address will receive
+    pub refund_amount_sat: u64,
+    /// The fee rate of the refund tx, in sat/vbyte
+    pub fee_rate_sat_per_vbyte: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RefundRequest {
+    pub swap_id: String,
+    pub refund_address: String,
+    /// The fee rate of the refund tx, in sat/vbyte, as resolved by [crate::sdk::LiquidSdk::prepare_refund]
+    pub fee_rate_sat_per_vbyte: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RefundResponse {
+    pub refund_tx_id: String,
+}
+
 #[derive(Debug, Serialize)]
 pub struct GetInfoResponse {
     /// Usable balance. This is the confirmed onchain balance minus `pending_send_sat`.
@@ -185,6 +1018,8 @@ pub struct GetInfoResponse {
     /// Incoming amount that is pending from ongoing Receive swaps
     pub pending_receive_sat: u64,
     pub pubkey: String,
+    /// Onchain balances of all assets in the wallet, including L-BTC
+    pub asset_balances: Vec<AssetBalance>,
 }
 
 #[derive(Debug, Serialize)]
@@ -203,17 +1038,197 @@ pub struct RestoreRequest {
 
 #[derive(Clone, Debug)]
 pub(crate) enum Swap {
+    Chain(ChainSwap),
     Send(SendSwap),
     Receive(ReceiveSwap),
 }
 impl Swap {
     pub(crate) fn id(&self) -> String {
         match &self {
-            Swap::Send(SendSwap { id, .. }) | Swap::Receive(ReceiveSwap { id, .. }) => id.clone(),
+            Swap::Chain(ChainSwap { id, .. })
+            | Swap::Send(SendSwap { id, .. })
+            | Swap::Receive(ReceiveSwap { id, .. }) => id.clone(),
         }
     }
 }
 
+/// The direction of a chain swap, from the point of view of this wallet
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub enum Direction {
+    /// BTC to L-BTC, used for Receive
+    Incoming = 0,
+    /// L-BTC to BTC, used for Send
+    Outgoing = 1,
+}
+impl ToSql for Direction {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(rusqlite::types::ToSqlOutput::from(*self as i8))
+    }
+}
+impl FromSql for Direction {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        match value {
+            ValueRef::Integer(i) => match i as u8 {
+                0 => Ok(Direction::Incoming),
+                1 => Ok(Direction::Outgoing),
+                _ => Err(FromSqlError::OutOfRange(i)),
+            },
+            _ => Err(FromSqlError::InvalidType),
+        }
+    }
+}
+
+/// A chain swap, used for moving funds between the Bitcoin and Liquid chains
+///
+/// The `lockup` side is the chain on which the user locks funds, the `claim` side is the chain on
+/// which the swapper locks funds and the user claims them:
+/// - [Direction::Incoming]: user locks BTC, claims L-BTC
+/// - [Direction::Outgoing]: user locks L-BTC, claims BTC
+#[derive(Clone, Debug)]
+pub(crate) struct ChainSwap {
+    pub(crate) id: String,
+    pub(crate) direction: Direction,
+    /// The BTC destination address. Only set for Outgoing swaps.
+    pub(crate) claim_address: Option<String>,
+    /// The address on the lockup side the user has to send funds to
+    pub(crate) lockup_address: String,
+    pub(crate) timeout_block_height: u32,
+    pub(crate) preimage: String,
+    /// Amount locked up by the user
+    pub(crate) payer_amount_sat: u64,
+    /// Amount the user receives on the claim side
+    pub(crate) receiver_amount_sat: u64,
+    pub(crate) claim_fees_sat: u64,
+    pub(crate) accept_zero_conf: bool,
+    /// JSON representation of [crate::persist::chain::InternalCreateChainResponse]
+    pub(crate) create_response_json: String,
+    /// Persisted only when the server lockup tx is seen
+    pub(crate) server_lockup_tx_id: Option<String>,
+    /// Persisted only when the user lockup tx is broadcast or seen
+    pub(crate) user_lockup_tx_id: Option<String>,
+    /// Persisted as soon as a claim tx is broadcast
+    pub(crate) claim_tx_id: Option<String>,
+    /// Persisted as soon as a refund tx is broadcast
+    pub(crate) refund_tx_id: Option<String>,
+    pub(crate) created_at: u32,
+    pub(crate) state: PaymentState,
+    pub(crate) claim_private_key: String,
+    pub(crate) refund_private_key: String,
+    /// Index of the swap keys and preimage derived from the mnemonic
+    pub(crate) key_index: Option<u32>,
+    /// The fee rate chosen for the L-BTC tx of the swap: the lockup tx of an Outgoing swap, or the
+    /// claim tx of an Incoming swap
+    pub(crate) fee_rate_sat_per_vbyte: Option<f64>,
+}
+impl ChainSwap {
+    pub(crate) fn get_claim_keypair(&self) -> Result<Keypair, PaymentError> {
+        utils::decode_keypair(&self.claim_private_key).map_err(Into::into)
+    }
+
+    pub(crate) fn get_refund_keypair(&self) -> Result<Keypair, PaymentError> {
+        utils::decode_keypair(&self.refund_private_key).map_err(Into::into)
+    }
+
+    pub(crate) fn get_boltz_create_response(&self) -> Result<CreateChainResponse, PaymentError> {
+        let internal_create_response: crate::persist::chain::InternalCreateChainResponse =
+            serde_json::from_str(&self.create_response_json).map_err(|e| {
+                PaymentError::Generic {
+                    err: format!("Failed to deserialize InternalCreateChainResponse: {e:?}"),
+                }
+            })?;
+
+        Ok(CreateChainResponse {
+            id: self.id.clone(),
+            claim_details: internal_create_response.claim_details.try_into()?,
+            lockup_details: internal_create_response.lockup_details.try_into()?,
+        })
+    }
+
+    /// The swap script of the server lockup, which the user claims
+    fn get_claim_swap_details(&self) -> Result<(ChainSwapDetails, boltz_client::PublicKey)> {
+        Ok((
+            self.get_boltz_create_response()?.claim_details,
+            self.get_claim_keypair()?.public_key().into(),
+        ))
+    }
+
+    /// The swap script of the user lockup, which the user can refund
+    fn get_lockup_swap_details(&self) -> Result<(ChainSwapDetails, boltz_client::PublicKey)> {
+        Ok((
+            self.get_boltz_create_response()?.lockup_details,
+            self.get_refund_keypair()?.public_key().into(),
+        ))
+    }
+
+    pub(crate) fn get_claim_swap_script_lbtc(&self) -> Result<LBtcSwapScriptV2, PaymentError> {
+        let (details, pubkey) = self.get_claim_swap_details()?;
+        LBtcSwapScriptV2::chain_from_swap_resp(Side::Claim, details, pubkey).map_err(|e| {
+            PaymentError::Generic {
+                err: format!(
+                    "Failed to create claim swap script for Chain Swap {}: {e:?}",
+                    self.id
+                ),
+            }
+        })
+    }
+
+    pub(crate) fn get_claim_swap_script_btc(&self) -> Result<BtcSwapScriptV2, PaymentError> {
+        let (details, pubkey) = self.get_claim_swap_details()?;
+        BtcSwapScriptV2::chain_from_swap_resp(Side::Claim, details, pubkey).map_err(|e| {
+            PaymentError::Generic {
+                err: format!(
+                    "Failed to create claim swap script for Chain Swap {}: {e:?}",
+                    self.id
+                ),
+            }
+        })
+    }
+
+    pub(crate) fn get_lockup_swap_script_lbtc(&self) -> Result<LBtcSwapScriptV2, PaymentError> {
+        let (details, pubkey) = self.get_lockup_swap_details()?;
+        LBtcSwapScriptV2::chain_from_swap_resp(Side::Lockup, details, pubkey).map_err(|e| {
+            PaymentError::Generic {
+                err: format!(
+                    "Failed to create lockup swap script for Chain Swap {}: {e:?}",
+                    self.id
+                ),
+            }
+        })
+    }
+
+    pub(crate) fn get_lockup_swap_script_btc(&self) -> Result<BtcSwapScriptV2, PaymentError> {
+        let (details, pubkey) = self.get_lockup_swap_details()?;
+        BtcSwapScriptV2::chain_from_swap_resp(Side::Lockup, details, pubkey).map_err(|e| {
+            PaymentError::Generic {
+                err: format!(
+                    "Failed to create lockup swap script for Chain Swap {}: {e:?}",
+                    self.id
+                ),
+            }
+        })
+    }
+
+    pub(crate) fn from_boltz_struct_to_json(
+        create_response: &CreateChainResponse,
+        expected_swap_id: &str,
+    ) -> Result<String, PaymentError> {
+        let internal_create_response =
+            crate::persist::chain::InternalCreateChainResponse::try_convert_from_boltz(
+                create_response,
+                expected_swap_id,
+            )?;
+
+        let create_response_json =
+            serde_json::to_string(&internal_create_response).map_err(|e| {
+                PaymentError::Generic {
+                    err: format!("Failed to serialize InternalCreateChainResponse: {e:?}"),
+                }
+            })?;
+
+        Ok(create_response_json)
+    }
+}
+
 /// A submarine swap, used for Send
 #[derive(Clone, Debug)]
 pub(crate) struct SendSwap {
@@ -231,6 +1246,12 @@ pub(crate) struct SendSwap {
     pub(crate) created_at: u32,
     pub(crate) state: PaymentState,
     pub(crate) refund_private_key: String,
+    /// Index of the swap keys derived from the mnemonic. Not set for swaps with random keys.
+    pub(crate) key_index: Option<u32>,
+    /// Not set for swaps created before the breakdown was persisted
+    pub(crate) fee_breakdown: Option<FeeBreakdown>,
+    /// The fee rate chosen for the lockup tx, also used for refunds to this wallet
+    pub(crate) fee_rate_sat_per_vbyte: Option<f64>,
 }
 impl SendSwap {
     pub(crate) fn get_refund_keypair(&self) -> Result<Keypair, PaymentError> {
@@ -311,6 +1332,15 @@ pub(crate) struct ReceiveSwap {
     /// Afterwards, it shows the lockup tx creation time.    
     pub(crate) created_at: u32,
     pub(crate) state: PaymentState,
+    /// Index of the swap keys and preimage derived from the mnemonic. Not set for swaps with
+    /// random keys.
+    pub(crate) key_index: Option<u32>,
+    /// The description of the invoice, if it has one
+    pub(crate) description: Option<String>,
+    /// Not set for swaps created before the breakdown was persisted
+    pub(crate) fee_breakdown: Option<FeeBreakdown>,
+    /// The fee rate chosen for the claim tx
+    pub(crate) fee_rate_sat_per_vbyte: Option<f64>,
 }
 impl ReceiveSwap {
     pub(crate) fn get_claim_keypair(&self) -> Result<Keypair, PaymentError> {
@@ -402,6 +1432,13 @@ pub enum PaymentState {
     ///
     /// When the refund tx is broadcast, `refund_tx_id` is set in the swap.
     ///
+    /// ## Chain Swaps
+    ///
+    /// Covers the cases when
+    /// - the user lockup tx was broadcast or seen in the mempool or
+    /// - our claim tx is broadcast or
+    /// - an Outgoing swap refund tx was broadcast
+    ///
     /// ## No swap data available
     ///
     /// If no associated swap is found, this indicates the underlying tx is not confirmed yet.
@@ -415,6 +1452,11 @@ pub enum PaymentState {
     ///
     /// This is the status when the claim tx is broadcast and we see it in the mempool.
     ///
+    /// ## Chain Swaps
+    ///
+    /// For Incoming swaps, this is the status when the claim tx is confirmed. For Outgoing swaps,
+    /// this is the status when the swapper claimed the user lockup, after our BTC claim.
+    ///
     /// ## No swap data available
     ///
     /// If no associated swap is found, this indicates the underlying tx is confirmed.
@@ -427,6 +1469,11 @@ pub enum PaymentState {
     /// ## Send Swaps
     ///
     /// This is the status when a swap refund was initiated and the refund tx is confirmed.
+    ///
+    /// ## Chain Swaps
+    ///
+    /// This is the status when the swap failed before any funds were locked up, when an Outgoing
+    /// swap refund tx is confirmed or when an Incoming swap refund tx is broadcast.
     Failed = 3,
 
     /// ## Send Swaps
@@ -434,6 +1481,12 @@ pub enum PaymentState {
     /// This covers the case when the swap state is still Created and the swap fails to reach the
     /// Pending state in time. The TimedOut state indicates the lockup tx should never be broadcast.
     TimedOut = 4,
+
+    /// ## Incoming Chain Swaps
+    ///
+    /// This covers the case when the swap failed after the payer locked up BTC. The funds have to
+    /// be refunded manually to a BTC address. See [crate::sdk::LiquidSdk::refund].
+    Refundable = 5,
 }
 impl ToSql for PaymentState {
     fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
@@ -449,6 +1502,7 @@ impl FromSql for PaymentState {
                 2 => Ok(PaymentState::Complete),
                 3 => Ok(PaymentState::Failed),
                 4 => Ok(PaymentState::TimedOut),
+                5 => Ok(PaymentState::Refundable),
                 _ => Err(FromSqlError::OutOfRange(i)),
             },
             _ => Err(FromSqlError::InvalidType),
@@ -479,6 +1533,20 @@ impl FromSql for PaymentType {
     }
 }
 
+/// How a payment was made
+#[derive(Debug, Copy, Clone, PartialEq, Serialize)]
+pub enum PaymentSwapType {
+    /// A Send Swap, paying a Lightning invoice
+    Submarine,
+    /// A Receive Swap, receiving a Lightning payment
+    Reverse,
+    /// A Chain Swap, sending or receiving BTC onchain
+    Chain,
+    /// No swap: a Liquid tx sent or received directly, including invoices paid via their
+    /// magic routing hint (MRH)
+    Direct,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Serialize)]
 pub enum PaymentStatus {
     Pending = 0,
@@ -522,6 +1590,15 @@ pub struct PaymentTxData {
 
     /// Onchain tx status
     pub is_confirmed: bool,
+
+    /// The issued asset moved by this tx, or `None` for L-BTC
+    pub asset_id: Option<String>,
+
+    /// The amount of the issued asset moved by this tx, in the asset's base units
+    pub asset_amount: Option<u64>,
+
+    /// The fee rate chosen for this tx, if it was built by this wallet with an explicit rate
+    pub fee_rate_sat_per_vbyte: Option<f64>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -544,6 +1621,124 @@ pub struct PaymentSwapData {
 
     /// Payment status derived from the swap status
     pub status: PaymentState,
+
+    pub swap_type: PaymentSwapType,
+
+    /// The invoice of a Send or Receive Swap
+    pub bolt11: Option<String>,
+
+    /// The description stored with the swap, if any
+    pub description: Option<String>,
+
+    pub lockup_tx_id: Option<String>,
+    pub claim_tx_id: Option<String>,
+
+    /// The fees of a Send or Receive Swap, as quoted when it was prepared
+    pub fee_breakdown: Option<FeeBreakdown>,
+
+    /// The fee rate chosen for the wallet tx of the swap
+    pub fee_rate_sat_per_vbyte: Option<f64>,
+}
+impl PaymentSwapData {
+    fn parse_invoice(&self) -> Option<LNInvoice> {
+        self.bolt11
+            .as_ref()
+            .and_then(|bolt11| crate::sdk::LiquidSdk::parse_invoice(bolt11).ok())
+    }
+}
+
+/// Filters and pagination for [LiquidSdk::list_payments](crate::sdk::LiquidSdk::list_payments).
+/// By default, all payments are listed, sorted by ascending timestamp.
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct ListPaymentsRequest {
+    /// Only list payments of these types
+    pub payment_types: Option<Vec<PaymentType>>,
+    /// Only list payments in these states
+    pub states: Option<Vec<PaymentState>>,
+    /// Epoch time, in seconds. Only list payments with a timestamp from this time on.
+    pub from_timestamp: Option<u32>,
+    /// Epoch time, in seconds. Only list payments with a timestamp before this time.
+    pub to_timestamp: Option<u32>,
+    /// Number of payments to skip
+    pub offset: Option<u32>,
+    /// Max number of payments to list
+    pub limit: Option<u32>,
+    /// Sort by descending timestamp, newest first
+    pub sort_descending: Option<bool>,
+    /// Only list payments whose metadata label, note or external ID contains this text
+    pub metadata_search: Option<String>,
+}
+
+/// User data attached to a payment, see
+/// [LiquidSdk::set_payment_metadata](crate::sdk::LiquidSdk::set_payment_metadata)
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct PaymentMetadata {
+    pub label: Option<String>,
+    pub note: Option<String>,
+    /// An ID from an external system, like an order ID
+    pub external_id: Option<String>,
+}
+
+/// The file format of [LiquidSdk::export_payments](crate::sdk::LiquidSdk::export_payments).
+///
+/// Both formats have one entry per payment, with the same columns. The columns only change with
+/// a new format version, which is the `version` column of a CSV row and the top-level `version`
+/// of a JSON export.
+///
+/// Version 1 columns: `timestamp`, `date` (RFC 3339, UTC), `direction`, `status`, `swap_type`,
+/// `amount_sat`, `fees_sat`, `swap_fees_sat`, `network_fees_sat`, `asset_id`, `asset_amount`,
+/// `tx_id`, `swap_id`, `lockup_tx_id`, `claim_tx_id`, `bolt11`, `payment_hash`, `preimage`,
+/// `description`, `refund_tx_id`, `refund_tx_amount_sat`, `label`, `metadata_label`,
+/// `metadata_note`, `metadata_external_id`.
+///
+/// The `direction` is `send` or `receive`. The `status` is one of `created`, `pending`,
+/// `complete`, `failed`, `timed_out` and `refundable`. The `swap_type` is one of `submarine`,
+/// `reverse`, `chain` and `direct`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub enum PaymentExportFormat {
+    Csv,
+    Json,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ExportPaymentsRequest {
+    pub format: PaymentExportFormat,
+    /// The payments to export
+    pub filter: ListPaymentsRequest,
+    /// The file to write, which is replaced if it exists
+    pub path: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ExportPaymentsResponse {
+    pub payments_count: u32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SetPaymentMetadataRequest {
+    /// The swap ID or one of the tx IDs of the payment
+    pub id: String,
+    /// Replaces any metadata the payment already has
+    pub metadata: PaymentMetadata,
+}
+
+/// The identifier to look up a payment with [LiquidSdk::get_payment](crate::sdk::LiquidSdk::get_payment)
+#[derive(Debug, Clone, Serialize)]
+pub enum GetPaymentRequest {
+    TxId {
+        tx_id: String,
+    },
+    SwapId {
+        swap_id: String,
+    },
+    /// The invoice of a Send or Receive Swap
+    Bolt11 {
+        bolt11: String,
+    },
+    /// The payment hash of the invoice of a Send or Receive Swap
+    PaymentHash {
+        payment_hash: String,
+    },
 }
 
 /// Represents an SDK payment.
@@ -556,6 +1751,9 @@ pub struct Payment {
     /// The swap ID, if any swap is associated with this payment
     pub swap_id: Option<String>,
 
+    /// The kind of swap used for this payment, or [PaymentSwapType::Direct] if none
+    pub swap_type: PaymentSwapType,
+
     /// Composite timestamp that can be used for sorting or displaying the payment.
     ///
     /// If this payment has an associated swap, it is the swap creation time. Otherwise, the point
@@ -583,13 +1781,44 @@ pub struct Payment {
     /// - for Receive payments, this is zero
     pub fees_sat: u64,
 
+    /// The part of `fees_sat` paid as Liquid network fee for the wallet's own tx: the lockup tx
+    /// of a Send or outgoing Chain Swap, the claim tx of a Receive or incoming Chain Swap, or the
+    /// tx of a direct Send. The rest of `fees_sat` goes to the swapper.
+    ///
+    /// It is `None` while the tx of a swap is not known yet.
+    pub network_fees_sat: Option<u64>,
+
+    /// For a Send or Receive Swap, this is the breakdown of `fees_sat` as quoted when the swap was
+    /// prepared. It is `None` for swaps created by older SDK versions.
+    pub fee_breakdown: Option<FeeBreakdown>,
+
+    /// The fee rate, in sat/vbyte, chosen for the wallet's own tx (see `network_fees_sat`). It is
+    /// `None` if the wallet default or the swapper estimate was used.
+    pub fee_rate_sat_per_vbyte: Option<f64>,
+
     /// In case of a Send swap, this is the preimage of the paid invoice (proof of payment).
     pub preimage: Option<String>,
 
-    /// For a Send swap which was refunded, this is the refund tx id
+    /// For a Send or Receive Swap, this is the BOLT11 invoice that was paid or received
+    pub bolt11: Option<String>,
+
+    /// For a Send or Receive Swap, this is the payment hash of the invoice
+    pub payment_hash: Option<String>,
+
+    /// For a Send or Receive Swap, this is the pubkey of the invoice payee
+    pub payee_pubkey: Option<String>,
+
+    /// For a swap, this is the tx locking the funds in the swap: the wallet lockup tx of a Send
+    /// or Chain Swap, or the swapper lockup tx of a Receive Swap
+    pub lockup_tx_id: Option<String>,
+
+    /// For a Receive or Chain Swap, this is the tx claiming the funds into the wallet
+    pub claim_tx_id: Option<String>,
+
+    /// For a Send or Chain swap which was refunded, this is the refund tx id
     pub refund_tx_id: Option<String>,
 
-    /// For a Send swap which was refunded, this is the refund amount
+    /// For a Send or Chain swap which was refunded, this is the refund amount
     pub refund_tx_amount_sat: Option<u64>,
 
     pub payment_type: PaymentType,
@@ -600,6 +1829,26 @@ pub struct Payment {
     ///
     /// If the tx has an associated swap, this is determined by the swap status (pending or complete).
     pub status: PaymentState,
+
+    /// For a Receive paying a [LiquidSdk::receive_liquid](crate::sdk::LiquidSdk::receive_liquid)
+    /// request, this is the label of the request
+    pub label: Option<String>,
+
+    /// For a Send or Receive Swap, this is the description of the invoice, if it has one
+    pub description: Option<String>,
+
+    /// The user metadata of the payment, if any was set
+    pub metadata: Option<PaymentMetadata>,
+
+    /// For a payment of an issued asset (e.g. USDt), this is the asset ID. It is `None` for L-BTC
+    /// payments.
+    ///
+    /// In this case `amount_sat` only covers the L-BTC part of the tx: the fees for a Send,
+    /// usually zero for a Receive.
+    pub asset_id: Option<String>,
+
+    /// For a payment of an issued asset, this is the amount in the asset's base units
+    pub asset_amount: Option<u64>,
 }
 impl Payment {
     pub(crate) fn from_pending_swap(swap: PaymentSwapData, payment_type: PaymentType) -> Payment {
@@ -607,25 +1856,46 @@ impl Payment {
             PaymentType::Receive => swap.receiver_amount_sat,
             PaymentType::Send => swap.payer_amount_sat,
         };
+        let invoice = swap.parse_invoice();
 
         Payment {
             tx_id: None,
             swap_id: Some(swap.swap_id),
+            swap_type: swap.swap_type,
             timestamp: swap.created_at,
             amount_sat,
             fees_sat: swap.payer_amount_sat - swap.receiver_amount_sat,
+            network_fees_sat: None,
+            fee_breakdown: swap.fee_breakdown,
+            fee_rate_sat_per_vbyte: swap.fee_rate_sat_per_vbyte,
             preimage: swap.preimage,
+            bolt11: swap.bolt11,
+            payment_hash: invoice.as_ref().map(|i| i.payment_hash.clone()),
+            payee_pubkey: invoice.as_ref().map(|i| i.payee_pubkey.clone()),
+            lockup_tx_id: swap.lockup_tx_id,
+            claim_tx_id: swap.claim_tx_id,
             refund_tx_id: swap.refund_tx_id,
             refund_tx_amount_sat: swap.refund_tx_amount_sat,
             payment_type,
             status: swap.status,
+            label: None,
+            description: swap.description.or(invoice.and_then(|i| i.description)),
+            metadata: None,
+            asset_id: None,
+            asset_amount: None,
         }
     }
 
     pub(crate) fn from_tx_data(tx: PaymentTxData, swap: Option<PaymentSwapData>) -> Payment {
+        let invoice = swap.as_ref().and_then(PaymentSwapData::parse_invoice);
+
         Payment {
             tx_id: Some(tx.tx_id),
             swap_id: swap.as_ref().map(|s| s.swap_id.clone()),
+            swap_type: swap
+                .as_ref()
+                .map(|s| s.swap_type)
+                .unwrap_or(PaymentSwapType::Direct),
             timestamp: match swap {
                 Some(ref swap) => swap.created_at,
                 None => tx.timestamp.unwrap_or(utils::now()),
@@ -638,10 +1908,30 @@ impl Payment {
                     PaymentType::Send => tx.fees_sat,
                 },
             },
+            // The tx of a swap is always built by this wallet
+            network_fees_sat: match (swap.as_ref(), tx.payment_type) {
+                (None, PaymentType::Receive) => Some(0),
+                _ => Some(tx.fees_sat),
+            },
+            fee_breakdown: swap.as_ref().and_then(|s| s.fee_breakdown.clone()),
+            fee_rate_sat_per_vbyte: swap
+                .as_ref()
+                .and_then(|s| s.fee_rate_sat_per_vbyte)
+                .or(tx.fee_rate_sat_per_vbyte),
             preimage: swap.as_ref().and_then(|s| s.preimage.clone()),
+            bolt11: swap.as_ref().and_then(|s| s.bolt11.clone()),
+            payment_hash: invoice.as_ref().map(|i| i.payment_hash.clone()),
+            payee_pubkey: invoice.as_ref().map(|i| i.payee_pubkey.clone()),
+            lockup_tx_id: swap.as_ref().and_then(|s| s.lockup_tx_id.clone()),
+            claim_tx_id: swap.as_ref().and_then(|s| s.claim_tx_id.clone()),
             refund_tx_id: swap.as_ref().and_then(|s| s.refund_tx_id.clone()),
             refund_tx_amount_sat: swap.as_ref().and_then(|s| s.refund_tx_amount_sat),
             payment_type: tx.payment_type,
+            description: swap
+                .as_ref()
+                .and_then(|s| s.description.clone())
+                .or(invoice.and_then(|i| i.description)),
+            metadata: None,
             status: match swap {
                 Some(swap) => swap.status,
                 None => match tx.is_confirmed {
@@ -649,6 +1939,9 @@ impl Payment {
                     false => PaymentState::Pending,
                 },
             },
+            label: None,
+            asset_id: tx.asset_id,
+            asset_amount: tx.asset_amount,
         }
     }
 }
@@ -661,7 +1954,7 @@ pub struct LogEntry {
 }
 
 /// Wrapper for a BOLT11 LN invoice
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 pub struct LNInvoice {
     pub bolt11: String,
     pub network: Network,
@@ -678,7 +1971,7 @@ pub struct LNInvoice {
 }
 
 /// A route hint for a LN payment
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 pub struct RouteHint {
     pub hops: Vec<RouteHintHop>,
 }
@@ -778,3 +2071,53 @@ macro_rules! get_invoice_amount {
             / 1000
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use anyhow::Result;
+    use lwk_wollet::elements::{Address, AddressParams};
+    use lwk_wollet::ElementsNetwork;
+
+    use crate::model::*;
+
+    #[test]
+    fn regtest_policy_asset() -> Result<()> {
+        let default_policy_asset =
+            "5ac9f65c0efcc4775e0baec4ec03abdde22473cd3cf33c0419ca290e0751b225";
+        let mut config = Config::regtest();
+        assert_eq!(config.lbtc_asset_id()?.to_string(), default_policy_asset);
+        assert_eq!(
+            ElementsNetwork::from(Network::Regtest).policy_asset(),
+            config.elements_network()?.policy_asset()
+        );
+        assert!(config.get_asset_metadata(default_policy_asset).is_some());
+
+        let policy_asset = "144c654344aa716d6f3abcc1ca90e5641e4e2a7f633bc09fe3baf64585819a49";
+        config.regtest_policy_asset = Some(policy_asset.to_string());
+        assert_eq!(config.lbtc_asset_id()?.to_string(), policy_asset);
+        let metadata = config
+            .get_asset_metadata(policy_asset)
+            .expect("Expecting L-BTC metadata");
+        assert_eq!(metadata.ticker, "L-BTC");
+        assert!(config.get_asset_metadata(default_policy_asset).is_none());
+
+        config.regtest_policy_asset = Some("not an asset".to_string());
+        assert!(config.lbtc_asset_id().is_err());
+        assert!(config.default_asset_metadata().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn regtest_network() -> Result<()> {
+        let testnet_address = "tlq1pq0wqu32e2xacxeyps22x8gjre4qk3u6r70pj4r62hzczxeyz8x3yxucrpn79zy28plc4x37aaf33kwt6dz2nn6gtkya6h02mwpzy4eh69zzexq7cf5y5";
+        let mut address = Address::from_str(testnet_address)?;
+        address.params = &AddressParams::ELEMENTS;
+        let data = LiquidAddressData::from_addr_or_uri(&address.to_string())?;
+        assert_eq!(data.network, Network::Regtest);
+
+        Ok(())
+    }
+}
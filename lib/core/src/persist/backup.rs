@@ -11,6 +11,7 @@ impl Persister {
         self.main_db_dir.join(match self.network {
             Network::Mainnet => "backup.sql",
             Network::Testnet => "backup-testnet.sql",
+            Network::Regtest => "backup-regtest.sql",
         })
     }
 
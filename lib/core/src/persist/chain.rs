@@ -0,0 +1,363 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use boltz_client::swaps::boltzv2::{ChainSwapDetails, CreateChainResponse};
+use rusqlite::{named_params, params, Connection, Row};
+use serde::{Deserialize, Serialize};
+
+use crate::ensure_sdk;
+use crate::error::PaymentError;
+use crate::model::*;
+use crate::persist::Persister;
+
+impl Persister {
+    pub(crate) fn insert_chain_swap(&self, chain_swap: &ChainSwap) -> Result<()> {
+        let con = self.get_connection()?;
+
+        let mut stmt = con.prepare(
+            "
+            INSERT INTO chain_swaps (
+                id,
+                direction,
+                claim_address,
+                lockup_address,
+                timeout_block_height,
+                preimage,
+                payer_amount_sat,
+                receiver_amount_sat,
+                claim_fees_sat,
+                accept_zero_conf,
+                create_response_json,
+                claim_private_key,
+                refund_private_key,
+                server_lockup_tx_id,
+                user_lockup_tx_id,
+                claim_tx_id,
+                refund_tx_id,
+                created_at,
+                state,
+                key_index,
+                fee_rate_sat_per_vbyte
+            )
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        )?;
+        _ = stmt.execute(params![
+            &chain_swap.id,
+            &chain_swap.direction,
+            &chain_swap.claim_address,
+            &chain_swap.lockup_address,
+            &chain_swap.timeout_block_height,
+            &chain_swap.preimage,
+            &chain_swap.payer_amount_sat,
+            &chain_swap.receiver_amount_sat,
+            &chain_swap.claim_fees_sat,
+            &chain_swap.accept_zero_conf,
+            &chain_swap.create_response_json,
+            &chain_swap.claim_private_key,
+            &chain_swap.refund_private_key,
+            &chain_swap.server_lockup_tx_id,
+            &chain_swap.user_lockup_tx_id,
+            &chain_swap.claim_tx_id,
+            &chain_swap.refund_tx_id,
+            &chain_swap.created_at,
+            &chain_swap.state,
+            &chain_swap.key_index,
+            &chain_swap.fee_rate_sat_per_vbyte,
+        ])?;
+
+        Ok(())
+    }
+
+    fn list_chain_swaps_query(where_clauses: Vec<String>) -> String {
+        let mut where_clause_str = String::new();
+        if !where_clauses.is_empty() {
+            where_clause_str = String::from("WHERE ");
+            where_clause_str.push_str(where_clauses.join(" AND ").as_str());
+        }
+
+        format!(
+            "
+            SELECT
+                id,
+                direction,
+                claim_address,
+                lockup_address,
+                timeout_block_height,
+                preimage,
+                payer_amount_sat,
+                receiver_amount_sat,
+                claim_fees_sat,
+                accept_zero_conf,
+                create_response_json,
+                claim_private_key,
+                refund_private_key,
+                server_lockup_tx_id,
+                user_lockup_tx_id,
+                claim_tx_id,
+                refund_tx_id,
+                created_at,
+                state,
+                key_index,
+                fee_rate_sat_per_vbyte
+            FROM chain_swaps
+            {where_clause_str}
+            ORDER BY created_at
+        "
+        )
+    }
+
+    pub(crate) fn fetch_chain_swap_by_id(&self, id: &str) -> Result<Option<ChainSwap>> {
+        let con: Connection = self.get_connection()?;
+        let query = Self::list_chain_swaps_query(vec!["id = ?1".to_string()]);
+        let res = con.query_row(&query, [id], Self::sql_row_to_chain_swap);
+
+        Ok(res.ok())
+    }
+
+    pub(crate) fn fetch_chain_swap_by_lockup_address(
+        &self,
+        lockup_address: &str,
+    ) -> Result<Option<ChainSwap>> {
+        let con: Connection = self.get_connection()?;
+        let query = Self::list_chain_swaps_query(vec!["lockup_address = ?1".to_string()]);
+        let res = con.query_row(&query, [lockup_address], Self::sql_row_to_chain_swap);
+
+        Ok(res.ok())
+    }
+
+    fn sql_row_to_chain_swap(row: &Row) -> rusqlite::Result<ChainSwap> {
+        Ok(ChainSwap {
+            id: row.get(0)?,
+            direction: row.get(1)?,
+            claim_address: row.get(2)?,
+            lockup_address: row.get(3)?,
+            timeout_block_height: row.get(4)?,
+            preimage: row.get(5)?,
+            payer_amount_sat: row.get(6)?,
+            receiver_amount_sat: row.get(7)?,
+            claim_fees_sat: row.get(8)?,
+            accept_zero_conf: row.get(9)?,
+            create_response_json: row.get(10)?,
+            claim_private_key: row.get(11)?,
+            refund_private_key: row.get(12)?,
+            server_lockup_tx_id: row.get(13)?,
+            user_lockup_tx_id: row.get(14)?,
+            claim_tx_id: row.get(15)?,
+            refund_tx_id: row.get(16)?,
+            created_at: row.get(17)?,
+            state: row.get(18)?,
+            key_index: row.get(19)?,
+            fee_rate_sat_per_vbyte: row.get(20)?,
+        })
+    }
+
+    pub(crate) fn list_chain_swaps(
+        &self,
+        con: &Connection,
+        where_clauses: Vec<String>,
+    ) -> rusqlite::Result<Vec<ChainSwap>> {
+        let query = Self::list_chain_swaps_query(where_clauses);
+        let chain_swaps = con
+            .prepare(&query)?
+            .query_map(params![], Self::sql_row_to_chain_swap)?
+            .map(|i| i.unwrap())
+            .collect();
+        Ok(chain_swaps)
+    }
+
+    pub(crate) fn list_chain_swaps_by_state(
+        &self,
+        con: &Connection,
+        states: Vec<PaymentState>,
+    ) -> rusqlite::Result<Vec<ChainSwap>> {
+        let mut where_clause: Vec<String> = Vec::new();
+        where_clause.push(format!(
+            "state in ({})",
+            states
+                .iter()
+                .map(|t| format!("'{}'", *t as i8))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+
+        self.list_chain_swaps(con, where_clause)
+    }
+
+    pub(crate) fn list_ongoing_chain_swaps(
+        &self,
+        con: &Connection,
+    ) -> rusqlite::Result<Vec<ChainSwap>> {
+        self.list_chain_swaps_by_state(con, vec![PaymentState::Created, PaymentState::Pending])
+    }
+
+    pub(crate) fn list_pending_chain_swaps(&self) -> Result<Vec<ChainSwap>> {
+        let con: Connection = self.get_connection()?;
+        self.list_chain_swaps_by_state(&con, vec![PaymentState::Pending])
+            .map_err(Into::into)
+    }
+
+    pub(crate) fn list_refundable_chain_swaps(&self) -> Result<Vec<ChainSwap>> {
+        let con: Connection = self.get_connection()?;
+        self.list_chain_swaps_by_state(&con, vec![PaymentState::Refundable])
+            .map_err(Into::into)
+    }
+
+    /// Pending Chain swaps, indexed by claim tx id
+    pub(crate) fn list_pending_chain_swaps_by_claim_tx_id(
+        &self,
+    ) -> Result<HashMap<String, ChainSwap>> {
+        let res: HashMap<String, ChainSwap> = self
+            .list_pending_chain_swaps()?
+            .iter()
+            .filter_map(|pending_chain_swap| {
+                pending_chain_swap
+                    .claim_tx_id
+                    .as_ref()
+                    .map(|claim_tx_id| (claim_tx_id.clone(), pending_chain_swap.clone()))
+            })
+            .collect();
+        Ok(res)
+    }
+
+    /// Pending Chain swaps, indexed by refund tx id
+    pub(crate) fn list_pending_chain_swaps_by_refund_tx_id(
+        &self,
+    ) -> Result<HashMap<String, ChainSwap>> {
+        let res: HashMap<String, ChainSwap> = self
+            .list_pending_chain_swaps()?
+            .iter()
+            .filter_map(|pending_chain_swap| {
+                pending_chain_swap
+                    .refund_tx_id
+                    .as_ref()
+                    .map(|refund_tx_id| (refund_tx_id.clone(), pending_chain_swap.clone()))
+            })
+            .collect();
+        Ok(res)
+    }
+
+    pub(crate) fn try_handle_chain_swap_update(
+        &self,
+        swap_id: &str,
+        to_state: PaymentState,
+        server_lockup_tx_id: Option<&str>,
+        user_lockup_tx_id: Option<&str>,
+        claim_tx_id: Option<&str>,
+        refund_tx_id: Option<&str>,
+    ) -> Result<(), PaymentError> {
+        // Do not overwrite server_lockup_tx_id, user_lockup_tx_id, claim_tx_id, refund_tx_id
+        let con: Connection = self.get_connection()?;
+        con.execute(
+            "UPDATE chain_swaps
+            SET
+                server_lockup_tx_id =
+                    CASE
+                        WHEN server_lockup_tx_id IS NULL THEN :server_lockup_tx_id
+                        ELSE server_lockup_tx_id
+                    END,
+
+                user_lockup_tx_id =
+                    CASE
+                        WHEN user_lockup_tx_id IS NULL THEN :user_lockup_tx_id
+                        ELSE user_lockup_tx_id
+                    END,
+
+                claim_tx_id =
+                    CASE
+                        WHEN claim_tx_id IS NULL THEN :claim_tx_id
+                        ELSE claim_tx_id
+                    END,
+
+                refund_tx_id =
+                    CASE
+                        WHEN refund_tx_id IS NULL THEN :refund_tx_id
+                        ELSE refund_tx_id
+                    END,
+
+                state = :state
+            WHERE
+                id = :id",
+            named_params! {
+                ":id": swap_id,
+                ":server_lockup_tx_id": server_lockup_tx_id,
+                ":user_lockup_tx_id": user_lockup_tx_id,
+                ":claim_tx_id": claim_tx_id,
+                ":refund_tx_id": refund_tx_id,
+                ":state": to_state,
+            },
+        )
+        .map_err(|_| PaymentError::PersistError)?;
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) struct InternalChainSwapDetails {
+    pub(crate) swap_tree: InternalSwapTree,
+    pub(crate) lockup_address: String,
+    pub(crate) server_public_key: String,
+    pub(crate) timeout_block_height: u32,
+    pub(crate) amount: u32,
+    pub(crate) blinding_key: Option<String>,
+    pub(crate) refund_address: Option<String>,
+    pub(crate) claim_address: Option<String>,
+    pub(crate) bip21: Option<String>,
+}
+impl From<ChainSwapDetails> for InternalChainSwapDetails {
+    fn from(value: ChainSwapDetails) -> Self {
+        InternalChainSwapDetails {
+            swap_tree: value.swap_tree.into(),
+            lockup_address: value.lockup_address,
+            server_public_key: value.server_public_key.to_string(),
+            timeout_block_height: value.timeout_block_height,
+            amount: value.amount,
+            blinding_key: value.blinding_key,
+            refund_address: value.refund_address,
+            claim_address: value.claim_address,
+            bip21: value.bip21,
+        }
+    }
+}
+impl TryFrom<InternalChainSwapDetails> for ChainSwapDetails {
+    type Error = PaymentError;
+
+    fn try_from(value: InternalChainSwapDetails) -> Result<Self, Self::Error> {
+        Ok(ChainSwapDetails {
+            swap_tree: value.swap_tree.into(),
+            lockup_address: value.lockup_address,
+            server_public_key: crate::utils::json_to_pubkey(&value.server_public_key)?,
+            timeout_block_height: value.timeout_block_height,
+            amount: value.amount,
+            blinding_key: value.blinding_key,
+            refund_address: value.refund_address,
+            claim_address: value.claim_address,
+            bip21: value.bip21,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) struct InternalCreateChainResponse {
+    pub(crate) claim_details: InternalChainSwapDetails,
+    pub(crate) lockup_details: InternalChainSwapDetails,
+}
+impl InternalCreateChainResponse {
+    pub(crate) fn try_convert_from_boltz(
+        boltz_create_response: &CreateChainResponse,
+        expected_swap_id: &str,
+    ) -> Result<InternalCreateChainResponse, PaymentError> {
+        // Do not store the CreateResponse fields that are already stored separately
+        // Before skipping them, ensure they match the separately stored ones
+        ensure_sdk!(
+            boltz_create_response.id == expected_swap_id,
+            PaymentError::PersistError
+        );
+
+        let res = InternalCreateChainResponse {
+            claim_details: boltz_create_response.claim_details.clone().into(),
+            lockup_details: boltz_create_response.lockup_details.clone().into(),
+        };
+        Ok(res)
+    }
+}
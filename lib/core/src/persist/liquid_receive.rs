@@ -0,0 +1,98 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use rusqlite::{named_params, params, Connection, Row};
+
+use crate::model::*;
+use crate::persist::Persister;
+
+impl Persister {
+    /// Stores a Liquid receive request. Since the wallet hands out the same unused address until
+    /// it receives funds, a new request for an unpaid address replaces the previous one.
+    pub(crate) fn insert_or_update_liquid_receive_request(
+        &self,
+        request: &LiquidReceiveRequest,
+    ) -> Result<()> {
+        let con = self.get_connection()?;
+
+        let mut stmt = con.prepare(
+            "
+            INSERT OR REPLACE INTO liquid_receive_requests (
+                address,
+                script_pubkey,
+                amount_sat,
+                label,
+                created_at,
+                tx_id
+            )
+            VALUES (?, ?, ?, ?, ?, ?)",
+        )?;
+        _ = stmt.execute((
+            &request.address,
+            &request.script_pubkey,
+            &request.amount_sat,
+            &request.label,
+            &request.created_at,
+            &request.tx_id,
+        ))?;
+
+        Ok(())
+    }
+
+    fn sql_row_to_liquid_receive_request(row: &Row) -> rusqlite::Result<LiquidReceiveRequest> {
+        Ok(LiquidReceiveRequest {
+            address: row.get(0)?,
+            script_pubkey: row.get(1)?,
+            amount_sat: row.get(2)?,
+            label: row.get(3)?,
+            created_at: row.get(4)?,
+            tx_id: row.get(5)?,
+        })
+    }
+
+    /// Liquid receive requests which were not yet paid, indexed by script pubkey
+    pub(crate) fn list_unpaid_liquid_receive_requests_by_script_pubkey(
+        &self,
+    ) -> Result<HashMap<String, LiquidReceiveRequest>> {
+        let con: Connection = self.get_connection()?;
+        let mut stmt = con.prepare(
+            "
+            SELECT
+                address,
+                script_pubkey,
+                amount_sat,
+                label,
+                created_at,
+                tx_id
+            FROM liquid_receive_requests
+            WHERE tx_id IS NULL
+            ",
+        )?;
+        let res: HashMap<String, LiquidReceiveRequest> = stmt
+            .query_map(params![], Self::sql_row_to_liquid_receive_request)?
+            .map(|i| i.unwrap())
+            .map(|request| (request.script_pubkey.clone(), request))
+            .collect();
+        Ok(res)
+    }
+
+    /// Ties a Liquid receive request to the first tx paying it
+    pub(crate) fn set_liquid_receive_request_tx_id(
+        &self,
+        address: &str,
+        tx_id: &str,
+    ) -> Result<()> {
+        let con: Connection = self.get_connection()?;
+        con.execute(
+            "UPDATE liquid_receive_requests
+            SET tx_id = :tx_id
+            WHERE address = :address AND tx_id IS NULL",
+            named_params! {
+                ":address": address,
+                ":tx_id": tx_id,
+            },
+        )?;
+
+        Ok(())
+    }
+}
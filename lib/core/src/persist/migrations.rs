@@ -35,5 +35,70 @@ pub(crate) fn current_migrations() -> Vec<&'static str> {
             amount_sat INTEGER NOT NULL,
             fees_sat INTEGER NOT NULL
         ) STRICT;",
+        "CREATE TABLE IF NOT EXISTS chain_swaps (
+            id TEXT NOT NULL PRIMARY KEY,
+            direction INTEGER NOT NULL,
+            claim_address TEXT,
+            lockup_address TEXT NOT NULL,
+            timeout_block_height INTEGER NOT NULL,
+            preimage TEXT NOT NULL,
+            payer_amount_sat INTEGER NOT NULL,
+            receiver_amount_sat INTEGER NOT NULL,
+            claim_fees_sat INTEGER NOT NULL,
+            accept_zero_conf INTEGER NOT NULL,
+            create_response_json TEXT NOT NULL,
+            claim_private_key TEXT NOT NULL,
+            refund_private_key TEXT NOT NULL,
+            server_lockup_tx_id TEXT,
+            user_lockup_tx_id TEXT,
+            claim_tx_id TEXT,
+            refund_tx_id TEXT,
+            created_at INTEGER NOT NULL,
+            state INTEGER NOT NULL
+        ) STRICT;",
+        "ALTER TABLE receive_swaps ADD COLUMN key_index INTEGER;",
+        "ALTER TABLE send_swaps ADD COLUMN key_index INTEGER;",
+        "ALTER TABLE chain_swaps ADD COLUMN key_index INTEGER;",
+        "CREATE TABLE IF NOT EXISTS swap_key_index (
+            id INTEGER NOT NULL PRIMARY KEY CHECK (id = 0),
+            next_index INTEGER NOT NULL
+        ) STRICT;",
+        "CREATE TABLE IF NOT EXISTS liquid_receive_requests (
+            address TEXT NOT NULL PRIMARY KEY,
+            script_pubkey TEXT NOT NULL,
+            amount_sat INTEGER,
+            label TEXT,
+            created_at INTEGER NOT NULL,
+            tx_id TEXT
+        ) STRICT;",
+        "ALTER TABLE payment_tx_data ADD COLUMN asset_id TEXT;",
+        "ALTER TABLE payment_tx_data ADD COLUMN asset_amount INTEGER;",
+        "ALTER TABLE receive_swaps ADD COLUMN description TEXT;",
+        "CREATE INDEX IF NOT EXISTS idx_payment_tx_data_timestamp ON payment_tx_data(timestamp);",
+        "CREATE INDEX IF NOT EXISTS idx_receive_swaps_claim_tx_id ON receive_swaps(claim_tx_id);",
+        "CREATE INDEX IF NOT EXISTS idx_receive_swaps_created_at ON receive_swaps(created_at);",
+        "CREATE INDEX IF NOT EXISTS idx_send_swaps_lockup_tx_id ON send_swaps(lockup_tx_id);",
+        "CREATE INDEX IF NOT EXISTS idx_send_swaps_created_at ON send_swaps(created_at);",
+        "CREATE INDEX IF NOT EXISTS idx_chain_swaps_claim_tx_id ON chain_swaps(claim_tx_id);",
+        "CREATE INDEX IF NOT EXISTS idx_chain_swaps_user_lockup_tx_id ON chain_swaps(user_lockup_tx_id);",
+        "CREATE INDEX IF NOT EXISTS idx_chain_swaps_created_at ON chain_swaps(created_at);",
+        "CREATE INDEX IF NOT EXISTS idx_liquid_receive_requests_tx_id ON liquid_receive_requests(tx_id);",
+        "ALTER TABLE send_swaps ADD COLUMN payment_hash TEXT;",
+        "ALTER TABLE receive_swaps ADD COLUMN payment_hash TEXT;",
+        "CREATE INDEX IF NOT EXISTS idx_send_swaps_payment_hash ON send_swaps(payment_hash);",
+        "CREATE INDEX IF NOT EXISTS idx_receive_swaps_payment_hash ON receive_swaps(payment_hash);",
+        "CREATE TABLE IF NOT EXISTS payment_metadata (
+            id TEXT NOT NULL PRIMARY KEY,
+            label TEXT,
+            note TEXT,
+            external_id TEXT
+        ) STRICT;",
+        "CREATE INDEX IF NOT EXISTS idx_payment_metadata_external_id ON payment_metadata(external_id);",
+        "ALTER TABLE send_swaps ADD COLUMN fee_breakdown_json TEXT;",
+        "ALTER TABLE receive_swaps ADD COLUMN fee_breakdown_json TEXT;",
+        "ALTER TABLE send_swaps ADD COLUMN fee_rate_sat_per_vbyte REAL;",
+        "ALTER TABLE receive_swaps ADD COLUMN fee_rate_sat_per_vbyte REAL;",
+        "ALTER TABLE chain_swaps ADD COLUMN fee_rate_sat_per_vbyte REAL;",
+        "ALTER TABLE payment_tx_data ADD COLUMN fee_rate_sat_per_vbyte REAL;",
     ]
 }
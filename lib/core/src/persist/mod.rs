@@ -1,13 +1,16 @@
 mod backup;
+pub(crate) mod chain;
+pub(crate) mod liquid_receive;
 mod migrations;
+pub(crate) mod payment_metadata;
 pub(crate) mod receive;
 pub(crate) mod send;
 
 use std::{fs::create_dir_all, path::PathBuf, str::FromStr};
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use migrations::current_migrations;
-use rusqlite::{params, Connection, OptionalExtension, Row};
+use rusqlite::{params, params_from_iter, Connection, OptionalExtension, Row};
 use rusqlite_migration::{Migrations, M};
 
 use crate::model::{Network::*, *};
@@ -34,36 +37,80 @@ impl Persister {
         let db_file = match self.network {
             Mainnet => "storage.sql",
             Testnet => "storage-testnet.sql",
+            Regtest => "storage-regtest.sql",
         };
         Ok(Connection::open(self.main_db_dir.join(db_file))?)
     }
 
     pub fn init(&self) -> Result<()> {
-        self.migrate_main_db()?;
+        let is_new_db = self.migrate_main_db()?;
+        // A new database has no swap keys to recover, so they start at the first index
+        if is_new_db {
+            self.set_min_next_swap_key_index(0)?;
+        }
+        self.backfill_swap_payment_hashes()?;
+        Ok(())
+    }
+
+    /// Sets the payment hash of the swaps persisted before it was stored
+    fn backfill_swap_payment_hashes(&self) -> Result<()> {
+        let con = self.get_connection()?;
+        for table in ["send_swaps", "receive_swaps"] {
+            let mut stmt = con.prepare(&format!(
+                "SELECT id, invoice FROM {table} WHERE payment_hash IS NULL"
+            ))?;
+            let swaps: Vec<(String, String)> = stmt
+                .query_map(params![], |row| Ok((row.get(0)?, row.get(1)?)))?
+                .collect::<Result<_, _>>()?;
+            for (id, invoice) in swaps {
+                if let Some(payment_hash) = utils::get_invoice_payment_hash(&invoice) {
+                    con.execute(
+                        &format!("UPDATE {table} SET payment_hash = ?1 WHERE id = ?2"),
+                        params![payment_hash, id],
+                    )?;
+                }
+            }
+        }
         Ok(())
     }
 
-    fn migrate_main_db(&self) -> Result<()> {
+    /// Migrates the database to the latest schema, returning whether it was just created
+    fn migrate_main_db(&self) -> Result<bool> {
         let migrations = Migrations::new(current_migrations().into_iter().map(M::up).collect());
         let mut conn = self.get_connection()?;
+        let schema_version: u32 =
+            conn.pragma_query_value(None, "user_version", |row| row.get(0))?;
         migrations.to_latest(&mut conn)?;
-        Ok(())
+        Ok(schema_version == 0)
     }
 
     pub(crate) fn insert_or_update_payment(&self, ptx: PaymentTxData) -> Result<()> {
         let mut con = self.get_connection()?;
 
         let tx = con.transaction()?;
+        // The fee rate is only known when the tx is built, so it is kept when the tx is synced
         tx.execute(
-            "INSERT OR REPLACE INTO payment_tx_data (
+            "INSERT INTO payment_tx_data (
            tx_id,
            timestamp,
            amount_sat,
            fees_sat,
            payment_type,
-           is_confirmed
+           is_confirmed,
+           asset_id,
+           asset_amount,
+           fee_rate_sat_per_vbyte
         )
-        VALUES (?, ?, ?, ?, ?, ?)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+        ON CONFLICT (tx_id) DO UPDATE SET
+           timestamp = excluded.timestamp,
+           amount_sat = excluded.amount_sat,
+           fees_sat = excluded.fees_sat,
+           payment_type = excluded.payment_type,
+           is_confirmed = excluded.is_confirmed,
+           asset_id = excluded.asset_id,
+           asset_amount = excluded.asset_amount,
+           fee_rate_sat_per_vbyte = COALESCE(excluded.fee_rate_sat_per_vbyte, fee_rate_sat_per_vbyte)
         ",
             (
                 ptx.tx_id,
@@ -72,6 +119,9 @@ impl Persister {
                 ptx.fees_sat,
                 ptx.payment_type,
                 ptx.is_confirmed,
+                ptx.asset_id,
+                ptx.asset_amount,
+                ptx.fee_rate_sat_per_vbyte,
             ),
         )?;
         tx.commit()?;
@@ -79,8 +129,84 @@ impl Persister {
         Ok(())
     }
 
+    /// Reserves the next swap key index, used to derive the keys and preimage of a new swap.
+    ///
+    /// Fails if the index has not been recovered yet, see [Persister::set_min_next_swap_key_index].
+    pub(crate) fn next_swap_key_index(&self) -> Result<u32> {
+        let con = self.get_connection()?;
+        let key_index = con
+            .query_row(
+                "UPDATE swap_key_index SET next_index = next_index + 1
+                WHERE id = 0
+                RETURNING next_index - 1",
+                [],
+                |row| row.get(0),
+            )
+            .optional()?
+            .ok_or(anyhow!("The swap key index has not been recovered yet"))?;
+        Ok(key_index)
+    }
+
+    pub(crate) fn fetch_next_swap_key_index(&self) -> Result<Option<u32>> {
+        let con = self.get_connection()?;
+        Ok(con
+            .query_row(
+                "SELECT next_index FROM swap_key_index WHERE id = 0",
+                [],
+                |row| row.get(0),
+            )
+            .optional()?)
+    }
+
+    /// Sets the next swap key index, unless it is already past the given one
+    pub(crate) fn set_min_next_swap_key_index(&self, next_index: u32) -> Result<()> {
+        let con = self.get_connection()?;
+        con.execute(
+            "INSERT INTO swap_key_index (id, next_index) VALUES (0, ?1)
+            ON CONFLICT (id) DO UPDATE SET next_index = MAX(next_index, excluded.next_index)",
+            [next_index],
+        )?;
+        Ok(())
+    }
+
+    /// Forgets the next swap key index, so that it is recovered again on the next start
+    pub(crate) fn delete_swap_key_index(&self) -> Result<()> {
+        let con = self.get_connection()?;
+        con.execute("DELETE FROM swap_key_index", [])?;
+        Ok(())
+    }
+
+    /// The highest key index of the persisted swaps, if any swap has one
+    pub(crate) fn fetch_max_swap_key_index(&self) -> Result<Option<u32>> {
+        let con = self.get_connection()?;
+        Ok(con.query_row(
+            "SELECT MAX(key_index) FROM (
+                SELECT key_index FROM send_swaps
+                UNION ALL SELECT key_index FROM receive_swaps
+                UNION ALL SELECT key_index FROM chain_swaps
+            )",
+            [],
+            |row| row.get(0),
+        )?)
+    }
+
+    pub(crate) fn fetch_swap_by_id(&self, id: &str) -> Result<Option<Swap>> {
+        if let Some(swap) = self.fetch_send_swap_by_id(id)? {
+            return Ok(Some(Swap::Send(swap)));
+        }
+        if let Some(swap) = self.fetch_receive_swap(id)? {
+            return Ok(Some(Swap::Receive(swap)));
+        }
+        Ok(self.fetch_chain_swap_by_id(id)?.map(Swap::Chain))
+    }
+
     pub(crate) fn list_ongoing_swaps(&self) -> Result<Vec<Swap>> {
         let con = self.get_connection()?;
+        let ongoing_chain_swaps: Vec<Swap> = self
+            .list_ongoing_chain_swaps(&con)?
+            .into_iter()
+            .map(Swap::Chain)
+            .collect();
         let ongoing_send_swaps: Vec<Swap> = self
             .list_ongoing_send_swaps(&con)?
             .into_iter()
@@ -91,10 +217,22 @@ impl Persister {
             .into_iter()
             .map(Swap::Receive)
             .collect();
-        Ok([ongoing_send_swaps, ongoing_receive_swaps].concat())
+        Ok([
+            ongoing_send_swaps,
+            ongoing_receive_swaps,
+            ongoing_chain_swaps,
+        ]
+        .concat())
     }
 
-    fn select_payment_query(&self, where_clause: Option<&str>) -> String {
+    /// The swaps without any tx yet (unpaid Receive Swaps, Send Swaps not locked up yet and
+    /// Chain Swaps without a user lockup) are only included if `include_swaps_without_txs` is
+    /// set, so that the swaps which never got funded do not show up as payments.
+    fn select_payment_query(
+        &self,
+        where_clause: Option<&str>,
+        include_swaps_without_txs: bool,
+    ) -> String {
         format!(
             "
             SELECT
@@ -116,21 +254,65 @@ impl Persister {
                 ss.payer_amount_sat,
                 ss.receiver_amount_sat,
                 ss.state,
-                rtx.amount_sat
+                rtx.amount_sat,
+                cs.id,
+                cs.created_at,
+                cs.direction,
+                cs.refund_tx_id,
+                cs.payer_amount_sat,
+                cs.receiver_amount_sat,
+                cs.state,
+                lr.label,
+                ptx.asset_id,
+                ptx.asset_amount,
+                rs.description,
+                rs.invoice,
+                rs.lockup_tx_id,
+                rs.claim_tx_id,
+                ss.invoice,
+                cs.user_lockup_tx_id,
+                cs.claim_tx_id,
+                pm.label,
+                pm.note,
+                pm.external_id,
+                rs.fee_breakdown_json,
+                ss.fee_breakdown_json,
+                ptx.fee_rate_sat_per_vbyte,
+                COALESCE(rs.fee_rate_sat_per_vbyte, cs.fee_rate_sat_per_vbyte, ss.fee_rate_sat_per_vbyte)
             FROM payment_tx_data AS ptx          -- Payment tx (each tx results in a Payment)
             FULL JOIN (
                 SELECT * FROM receive_swaps
-                WHERE claim_tx_id IS NOT NULL OR lockup_tx_id IS NOT NULL
+                WHERE claim_tx_id IS NOT NULL OR lockup_tx_id IS NOT NULL OR {}
             ) rs                                 -- Receive Swap data (by claim)
                 ON ptx.tx_id = rs.claim_tx_id
-            LEFT JOIN send_swaps AS ss           -- Send Swap data
+            FULL JOIN (
+                SELECT * FROM chain_swaps
+                WHERE claim_tx_id IS NOT NULL OR user_lockup_tx_id IS NOT NULL OR state = {} OR {}
+            ) cs                                 -- Chain Swap data (by L-BTC claim or L-BTC lockup)
+                ON ptx.tx_id IN (cs.claim_tx_id, cs.user_lockup_tx_id)
+            FULL JOIN (
+                SELECT * FROM send_swaps
+                WHERE lockup_tx_id IS NOT NULL OR {}
+            ) ss                                 -- Send Swap data (by lockup)
                 ON ptx.tx_id = ss.lockup_tx_id
             LEFT JOIN payment_tx_data AS rtx     -- Refund tx data
-                ON rtx.tx_id = ss.refund_tx_id
+                ON rtx.tx_id IN (ss.refund_tx_id, cs.refund_tx_id)
+            LEFT JOIN liquid_receive_requests AS lr -- Liquid receive request data (by paying tx)
+                ON ptx.tx_id = lr.tx_id
+            LEFT JOIN payment_metadata AS pm     -- User metadata (by swap ID, else tx ID)
+                ON pm.id = COALESCE(rs.id, cs.id, ss.id, ptx.tx_id)
             WHERE                                -- Filter out refund txs from Payment tx list
-                ptx.tx_id NOT IN (SELECT refund_tx_id FROM send_swaps WHERE refund_tx_id NOT NULL)
+                (ptx.tx_id IS NULL OR ptx.tx_id NOT IN (
+                    SELECT refund_tx_id FROM send_swaps WHERE refund_tx_id NOT NULL
+                    UNION
+                    SELECT refund_tx_id FROM chain_swaps WHERE refund_tx_id NOT NULL
+                ))
             AND {}
             ",
+            include_swaps_without_txs,
+            PaymentState::Refundable as i8,
+            include_swaps_without_txs,
+            include_swaps_without_txs,
             where_clause.unwrap_or("true")
         )
     }
@@ -145,6 +327,9 @@ impl Persister {
                 fees_sat: row.get(3)?,
                 payment_type: row.get(4)?,
                 is_confirmed: row.get(5)?,
+                asset_id: row.get(27)?,
+                asset_amount: row.get(28)?,
+                fee_rate_sat_per_vbyte: row.get(41)?,
             }),
             _ => None,
         };
@@ -154,6 +339,11 @@ impl Persister {
         let maybe_receive_swap_payer_amount_sat: Option<u64> = row.get(8)?;
         let maybe_receive_swap_receiver_amount_sat: Option<u64> = row.get(9)?;
         let maybe_receive_swap_receiver_state: Option<PaymentState> = row.get(10)?;
+        let maybe_receive_swap_description: Option<String> = row.get(29)?;
+        let maybe_receive_swap_invoice: Option<String> = row.get(30)?;
+        let maybe_receive_swap_lockup_tx_id: Option<String> = row.get(31)?;
+        let maybe_receive_swap_claim_tx_id: Option<String> = row.get(32)?;
+        let maybe_receive_swap_fee_breakdown: Option<FeeBreakdown> = row.get(39)?;
 
         let maybe_send_swap_id: Option<String> = row.get(11)?;
         let maybe_send_swap_created_at: Option<u32> = row.get(12)?;
@@ -162,10 +352,65 @@ impl Persister {
         let maybe_send_swap_payer_amount_sat: Option<u64> = row.get(15)?;
         let maybe_send_swap_receiver_amount_sat: Option<u64> = row.get(16)?;
         let maybe_send_swap_state: Option<PaymentState> = row.get(17)?;
-        let maybe_send_swap_refund_tx_amount_sat: Option<u64> = row.get(18)?;
+        let maybe_refund_tx_amount_sat: Option<u64> = row.get(18)?;
+        let maybe_send_swap_invoice: Option<String> = row.get(33)?;
+        let maybe_send_swap_fee_breakdown: Option<FeeBreakdown> = row.get(40)?;
+
+        let maybe_chain_swap_id: Option<String> = row.get(19)?;
+        let maybe_chain_swap_created_at: Option<u32> = row.get(20)?;
+        let maybe_chain_swap_direction: Option<Direction> = row.get(21)?;
+        let maybe_chain_swap_refund_tx_id: Option<String> = row.get(22)?;
+        let maybe_chain_swap_payer_amount_sat: Option<u64> = row.get(23)?;
+        let maybe_chain_swap_receiver_amount_sat: Option<u64> = row.get(24)?;
+        let maybe_chain_swap_state: Option<PaymentState> = row.get(25)?;
+        let maybe_chain_swap_user_lockup_tx_id: Option<String> = row.get(34)?;
+        let maybe_chain_swap_claim_tx_id: Option<String> = row.get(35)?;
+
+        let maybe_swap_fee_rate_sat_per_vbyte: Option<f64> = row.get(42)?;
 
-        let (swap, payment_type) = match maybe_receive_swap_id {
-            Some(receive_swap_id) => (
+        let maybe_liquid_receive_label: Option<String> = row.get(26)?;
+
+        let maybe_metadata_label: Option<String> = row.get(36)?;
+        let maybe_metadata_note: Option<String> = row.get(37)?;
+        let maybe_metadata_external_id: Option<String> = row.get(38)?;
+        let metadata = match (
+            maybe_metadata_label,
+            maybe_metadata_note,
+            maybe_metadata_external_id,
+        ) {
+            (None, None, None) => None,
+            (label, note, external_id) => Some(PaymentMetadata {
+                label,
+                note,
+                external_id,
+            }),
+        };
+
+        let (swap, payment_type) = match (maybe_receive_swap_id, maybe_chain_swap_id) {
+            (None, Some(chain_swap_id)) => (
+                Some(PaymentSwapData {
+                    swap_id: chain_swap_id,
+                    created_at: maybe_chain_swap_created_at.unwrap_or(utils::now()),
+                    preimage: None,
+                    payer_amount_sat: maybe_chain_swap_payer_amount_sat.unwrap_or(0),
+                    receiver_amount_sat: maybe_chain_swap_receiver_amount_sat.unwrap_or(0),
+                    refund_tx_id: maybe_chain_swap_refund_tx_id,
+                    refund_tx_amount_sat: maybe_refund_tx_amount_sat,
+                    status: maybe_chain_swap_state.unwrap_or(PaymentState::Created),
+                    swap_type: PaymentSwapType::Chain,
+                    bolt11: None,
+                    description: None,
+                    lockup_tx_id: maybe_chain_swap_user_lockup_tx_id,
+                    claim_tx_id: maybe_chain_swap_claim_tx_id,
+                    fee_breakdown: None,
+                    fee_rate_sat_per_vbyte: maybe_swap_fee_rate_sat_per_vbyte,
+                }),
+                match maybe_chain_swap_direction {
+                    Some(Direction::Outgoing) => PaymentType::Send,
+                    _ => PaymentType::Receive,
+                },
+            ),
+            (Some(receive_swap_id), _) => (
                 Some(PaymentSwapData {
                     swap_id: receive_swap_id,
                     created_at: maybe_receive_swap_created_at.unwrap_or(utils::now()),
@@ -175,10 +420,17 @@ impl Persister {
                     refund_tx_id: None,
                     refund_tx_amount_sat: None,
                     status: maybe_receive_swap_receiver_state.unwrap_or(PaymentState::Created),
+                    swap_type: PaymentSwapType::Reverse,
+                    bolt11: maybe_receive_swap_invoice,
+                    description: maybe_receive_swap_description,
+                    lockup_tx_id: maybe_receive_swap_lockup_tx_id,
+                    claim_tx_id: maybe_receive_swap_claim_tx_id,
+                    fee_breakdown: maybe_receive_swap_fee_breakdown,
+                    fee_rate_sat_per_vbyte: maybe_swap_fee_rate_sat_per_vbyte,
                 }),
                 PaymentType::Receive,
             ),
-            None => (
+            (None, None) => (
                 maybe_send_swap_id.map(|send_swap_id| PaymentSwapData {
                     swap_id: send_swap_id,
                     created_at: maybe_send_swap_created_at.unwrap_or(utils::now()),
@@ -186,41 +438,644 @@ impl Persister {
                     payer_amount_sat: maybe_send_swap_payer_amount_sat.unwrap_or(0),
                     receiver_amount_sat: maybe_send_swap_receiver_amount_sat.unwrap_or(0),
                     refund_tx_id: maybe_send_swap_refund_tx_id,
-                    refund_tx_amount_sat: maybe_send_swap_refund_tx_amount_sat,
+                    refund_tx_amount_sat: maybe_refund_tx_amount_sat,
                     status: maybe_send_swap_state.unwrap_or(PaymentState::Created),
+                    swap_type: PaymentSwapType::Submarine,
+                    bolt11: maybe_send_swap_invoice,
+                    description: None,
+                    // The Send Swap is joined by its lockup tx
+                    lockup_tx_id: maybe_tx_tx_id.as_ref().ok().cloned(),
+                    claim_tx_id: None,
+                    fee_breakdown: maybe_send_swap_fee_breakdown,
+                    fee_rate_sat_per_vbyte: maybe_swap_fee_rate_sat_per_vbyte,
                 }),
                 PaymentType::Send,
             ),
         };
 
-        match (tx, swap.clone()) {
-            (None, None) => Err(maybe_tx_tx_id.err().unwrap()),
-            (None, Some(swap)) => Ok(Payment::from_pending_swap(swap, payment_type)),
-            (Some(tx), None) => Ok(Payment::from_tx_data(tx, None)),
-            (Some(tx), Some(swap)) => Ok(Payment::from_tx_data(tx, Some(swap))),
-        }
+        let mut payment = match (tx, swap.clone()) {
+            (None, None) => return Err(maybe_tx_tx_id.err().unwrap()),
+            (None, Some(swap)) => Payment::from_pending_swap(swap, payment_type),
+            (Some(tx), None) => Payment::from_tx_data(tx, None),
+            (Some(tx), Some(swap)) => Payment::from_tx_data(tx, Some(swap)),
+        };
+        payment.label = maybe_liquid_receive_label;
+        payment.metadata = metadata;
+        Ok(payment)
     }
 
     pub fn get_payment(&self, id: String) -> Result<Option<Payment>> {
         Ok(self
             .get_connection()?
             .query_row(
-                &self.select_payment_query(Some("ptx.tx_id = ?1")),
+                // Incoming Chain Swaps are identified by the swap ID until they are claimed
+                &self.select_payment_query(Some("(ptx.tx_id = ?1 OR cs.id = ?1)"), false),
                 params![id],
                 |row| self.sql_row_to_payment(row),
             )
             .optional()?)
     }
 
-    pub fn get_payments(&self) -> Result<Vec<Payment>> {
+    /// Builds the filter and pagination of a [ListPaymentsRequest], with the params it binds. The
+    /// expressions mirror how [Persister::sql_row_to_payment] derives the timestamp, type and
+    /// status of a payment.
+    fn list_payments_filter(req: &ListPaymentsRequest) -> (String, Vec<String>) {
+        let timestamp = "COALESCE(rs.created_at, cs.created_at, ss.created_at, ptx.timestamp, CAST(strftime('%s', 'now') AS INTEGER))";
+        let payment_type = format!(
+            "COALESCE(ptx.payment_type, CASE
+                WHEN rs.id IS NOT NULL THEN {receive}
+                WHEN cs.id IS NOT NULL AND cs.direction = {incoming} THEN {receive}
+                ELSE {send}
+            END)",
+            receive = PaymentType::Receive as i8,
+            send = PaymentType::Send as i8,
+            incoming = Direction::Incoming as i8,
+        );
+        let state = format!(
+            "COALESCE(rs.state, cs.state, ss.state, CASE
+                WHEN ptx.is_confirmed THEN {complete}
+                ELSE {pending}
+            END)",
+            complete = PaymentState::Complete as i8,
+            pending = PaymentState::Pending as i8,
+        );
+
+        let join = |values: Vec<i8>| {
+            values
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<String>>()
+                .join(", ")
+        };
+        let mut where_clauses = vec![];
+        let mut timestamp_ranges = vec![];
+        if let Some(from_timestamp) = req.from_timestamp {
+            timestamp_ranges.push(format!(">= {from_timestamp}"));
+        }
+        if let Some(to_timestamp) = req.to_timestamp {
+            timestamp_ranges.push(format!("< {to_timestamp}"));
+        }
+        if !timestamp_ranges.is_empty() {
+            // The derived timestamp is not indexed, so the candidates are first looked up by the
+            // indexed timestamp columns of each table. A payment without a swap and without a
+            // tx timestamp yet is timestamped now.
+            let in_range = |column: &str| {
+                timestamp_ranges
+                    .iter()
+                    .map(|range| format!("{column} {range}"))
+                    .collect::<Vec<String>>()
+                    .join(" AND ")
+            };
+            where_clauses.push(format!(
+                "(rs.id IN (SELECT id FROM receive_swaps WHERE {})
+                    OR cs.id IN (SELECT id FROM chain_swaps WHERE {})
+                    OR ss.id IN (SELECT id FROM send_swaps WHERE {})
+                    OR ptx.tx_id IN (SELECT tx_id FROM payment_tx_data WHERE {})
+                    OR ptx.timestamp IS NULL)",
+                in_range("created_at"),
+                in_range("created_at"),
+                in_range("created_at"),
+                in_range("timestamp"),
+            ));
+            where_clauses.push(in_range(timestamp));
+        }
+        if let Some(payment_types) = &req.payment_types {
+            let payment_types = join(payment_types.iter().map(|t| *t as i8).collect());
+            where_clauses.push(format!("{payment_type} IN ({payment_types})"));
+        }
+        if let Some(states) = &req.states {
+            let states = join(states.iter().map(|s| *s as i8).collect());
+            where_clauses.push(format!("{state} IN ({states})"));
+        }
+        let mut params = vec![];
+        if let Some(metadata_search) = &req.metadata_search {
+            // Matched as a substring, so the LIKE wildcards in the search text are escaped
+            let pattern = metadata_search
+                .replace('\\', "\\\\")
+                .replace('%', "\\%")
+                .replace('_', "\\_");
+            params.push(format!("%{pattern}%"));
+            where_clauses.push(
+                "(pm.label LIKE ?1 ESCAPE '\\' OR pm.note LIKE ?1 ESCAPE '\\' OR pm.external_id LIKE ?1 ESCAPE '\\')"
+                    .to_string(),
+            );
+        }
+        let where_clause = match where_clauses.is_empty() {
+            true => "true".to_string(),
+            false => where_clauses.join(" AND "),
+        };
+
+        let direction = match req.sort_descending {
+            Some(true) => "DESC",
+            _ => "ASC",
+        };
+        // SQLite needs a LIMIT for an OFFSET, where -1 means no limit
+        let limit = req.limit.map(i64::from).unwrap_or(-1);
+        let offset = req.offset.unwrap_or(0);
+        (
+            format!(
+                "{where_clause} ORDER BY {timestamp} {direction} LIMIT {limit} OFFSET {offset}"
+            ),
+            params,
+        )
+    }
+
+    /// Looks up a payment, including the pending swaps without any tx yet and the Receive Swaps
+    /// whose invoice was not paid yet
+    pub(crate) fn get_payment_by_request(
+        &self,
+        req: &GetPaymentRequest,
+    ) -> Result<Option<Payment>> {
+        let (where_clause, param) = match req {
+            GetPaymentRequest::TxId { tx_id } => ("ptx.tx_id = ?1", tx_id.clone()),
+            GetPaymentRequest::SwapId { swap_id } => {
+                ("(rs.id = ?1 OR ss.id = ?1 OR cs.id = ?1)", swap_id.clone())
+            }
+            GetPaymentRequest::Bolt11 { bolt11 } => {
+                ("(rs.invoice = ?1 OR ss.invoice = ?1)", bolt11.clone())
+            }
+            GetPaymentRequest::PaymentHash { payment_hash } => (
+                "(rs.payment_hash = ?1 OR ss.payment_hash = ?1)",
+                payment_hash.to_lowercase(),
+            ),
+        };
+        Ok(self
+            .get_connection()?
+            .query_row(
+                &self.select_payment_query(Some(where_clause), true),
+                params![param],
+                |row| self.sql_row_to_payment(row),
+            )
+            .optional()?)
+    }
+
+    pub fn get_payments(&self, req: &ListPaymentsRequest) -> Result<Vec<Payment>> {
         let con = self.get_connection()?;
 
         // Assumes there is no swap chaining (send swap lockup tx = receive swap claim tx)
-        let mut stmt = con.prepare(&self.select_payment_query(None))?;
+        let (filter, filter_params) = Self::list_payments_filter(req);
+        let mut stmt = con.prepare(&self.select_payment_query(Some(&filter), false))?;
         let payments: Vec<Payment> = stmt
-            .query_map(params![], |row| self.sql_row_to_payment(row))?
+            .query_map(params_from_iter(filter_params), |row| {
+                self.sql_row_to_payment(row)
+            })?
             .map(|i| i.unwrap())
             .collect();
         Ok(payments)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use anyhow::Result;
+
+    use crate::model::*;
+    use crate::persist::Persister;
+    use crate::test_utils::chain_swap::new_chain_swap;
+    use crate::test_utils::persist::{
+        create_temp_dir, new_persister, new_receive_swap, new_send_swap,
+    };
+    use crate::utils;
+
+    fn new_tx_data(
+        tx_id: &str,
+        timestamp: u32,
+        payment_type: PaymentType,
+        is_confirmed: bool,
+    ) -> PaymentTxData {
+        PaymentTxData {
+            tx_id: tx_id.to_string(),
+            timestamp: Some(timestamp),
+            amount_sat: 50_000,
+            fees_sat: 100,
+            payment_type,
+            is_confirmed,
+            asset_id: None,
+            asset_amount: None,
+            fee_rate_sat_per_vbyte: None,
+        }
+    }
+
+    /// Inserts, from oldest to newest:
+    /// - a pending Send Swap created at 1000, whose lockup tx is timestamped 1500
+    /// - a complete Receive Swap created at 2000, whose claim tx is timestamped 2500
+    /// - a pending incoming Chain Swap created at 3000, not claimed yet
+    /// - an unconfirmed outgoing tx without swap, timestamped 4000
+    fn insert_payments(persister: &Persister) -> Result<()> {
+        let mut send_swap = new_send_swap("send", PaymentState::Pending);
+        send_swap.created_at = 1_000;
+        send_swap.lockup_tx_id = Some("send-lockup".to_string());
+        persister.insert_send_swap(&send_swap)?;
+        persister.insert_or_update_payment(new_tx_data(
+            "send-lockup",
+            1_500,
+            PaymentType::Send,
+            true,
+        ))?;
+
+        let mut receive_swap = new_receive_swap("receive", PaymentState::Complete);
+        receive_swap.created_at = 2_000;
+        receive_swap.claim_tx_id = Some("receive-claim".to_string());
+        persister.insert_receive_swap(&receive_swap)?;
+        persister.insert_or_update_payment(new_tx_data(
+            "receive-claim",
+            2_500,
+            PaymentType::Receive,
+            true,
+        ))?;
+
+        let mut chain_swap = new_chain_swap("chain", Direction::Incoming, PaymentState::Pending);
+        chain_swap.created_at = 3_000;
+        chain_swap.user_lockup_tx_id = Some("chain-btc-lockup".to_string());
+        persister.insert_chain_swap(&chain_swap)?;
+
+        persister.insert_or_update_payment(new_tx_data("tx", 4_000, PaymentType::Send, false))?;
+        Ok(())
+    }
+
+    fn list_ids(persister: &Persister, req: ListPaymentsRequest) -> Result<Vec<String>> {
+        Ok(persister
+            .get_payments(&req)?
+            .into_iter()
+            .map(|p| p.swap_id.or(p.tx_id).unwrap_or_default())
+            .collect())
+    }
+
+    #[test]
+    fn list_payments_filter() -> Result<()> {
+        let (_data_dir, persister) = new_persister()?;
+        insert_payments(&persister)?;
+
+        let cases = [
+            (
+                ListPaymentsRequest::default(),
+                vec!["send", "receive", "chain", "tx"],
+            ),
+            (
+                ListPaymentsRequest {
+                    sort_descending: Some(true),
+                    ..Default::default()
+                },
+                vec!["tx", "chain", "receive", "send"],
+            ),
+            (
+                ListPaymentsRequest {
+                    sort_descending: Some(true),
+                    offset: Some(1),
+                    limit: Some(2),
+                    ..Default::default()
+                },
+                vec!["chain", "receive"],
+            ),
+            (
+                ListPaymentsRequest {
+                    offset: Some(3),
+                    ..Default::default()
+                },
+                vec!["tx"],
+            ),
+            (
+                ListPaymentsRequest {
+                    payment_types: Some(vec![PaymentType::Receive]),
+                    ..Default::default()
+                },
+                vec!["receive", "chain"],
+            ),
+            (
+                ListPaymentsRequest {
+                    payment_types: Some(vec![PaymentType::Send]),
+                    sort_descending: Some(true),
+                    ..Default::default()
+                },
+                vec!["tx", "send"],
+            ),
+            (
+                ListPaymentsRequest {
+                    states: Some(vec![PaymentState::Pending]),
+                    ..Default::default()
+                },
+                vec!["send", "chain", "tx"],
+            ),
+            (
+                ListPaymentsRequest {
+                    states: Some(vec![PaymentState::Complete, PaymentState::Failed]),
+                    ..Default::default()
+                },
+                vec!["receive"],
+            ),
+            // The Send Swap is timestamped by its creation, not by its lockup tx
+            (
+                ListPaymentsRequest {
+                    from_timestamp: Some(1_500),
+                    to_timestamp: Some(4_000),
+                    ..Default::default()
+                },
+                vec!["receive", "chain"],
+            ),
+            (
+                ListPaymentsRequest {
+                    from_timestamp: Some(1_000),
+                    to_timestamp: Some(2_000),
+                    ..Default::default()
+                },
+                vec!["send"],
+            ),
+            (
+                ListPaymentsRequest {
+                    payment_types: Some(vec![PaymentType::Receive]),
+                    states: Some(vec![PaymentState::Pending]),
+                    from_timestamp: Some(2_000),
+                    limit: Some(1),
+                    ..Default::default()
+                },
+                vec!["chain"],
+            ),
+        ];
+        for (req, expected_ids) in cases {
+            assert_eq!(list_ids(&persister, req.clone())?, expected_ids, "{req:?}");
+        }
+
+        Ok(())
+    }
+
+    fn get_payment(persister: &Persister, req: GetPaymentRequest) -> Result<Payment> {
+        persister
+            .get_payment_by_request(&req)?
+            .ok_or(anyhow::anyhow!("Expecting a payment for {req:?}"))
+    }
+
+    /// Looks up a swap by its ID, its invoice (if it has one) and its payment hash
+    fn get_swap_payment(
+        persister: &Persister,
+        swap_id: &str,
+        invoice: Option<&str>,
+    ) -> Result<Payment> {
+        let payment = get_payment(
+            persister,
+            GetPaymentRequest::SwapId {
+                swap_id: swap_id.to_string(),
+            },
+        )?;
+        assert_eq!(payment.swap_id.as_deref(), Some(swap_id));
+
+        if let Some(invoice) = invoice {
+            let payment_hash =
+                utils::get_invoice_payment_hash(invoice).expect("Expecting a valid invoice");
+            for req in [
+                GetPaymentRequest::Bolt11 {
+                    bolt11: invoice.to_string(),
+                },
+                GetPaymentRequest::PaymentHash {
+                    payment_hash: payment_hash.to_uppercase(),
+                },
+            ] {
+                assert_eq!(
+                    get_payment(persister, req)?.swap_id.as_deref(),
+                    Some(swap_id)
+                );
+            }
+        }
+        Ok(payment)
+    }
+
+    #[test]
+    fn get_payment_of_swaps_without_txs() -> Result<()> {
+        let (_data_dir, persister) = new_persister()?;
+
+        let send_swap = new_send_swap("send", PaymentState::Created);
+        persister.insert_send_swap(&send_swap)?;
+        let receive_swap = new_receive_swap("receive", PaymentState::Created);
+        persister.insert_receive_swap(&receive_swap)?;
+        persister.insert_chain_swap(&new_chain_swap(
+            "chain-in",
+            Direction::Incoming,
+            PaymentState::Created,
+        ))?;
+        persister.insert_chain_swap(&new_chain_swap(
+            "chain-out",
+            Direction::Outgoing,
+            PaymentState::Created,
+        ))?;
+
+        let payment = get_swap_payment(&persister, "send", Some(&send_swap.invoice))?;
+        assert_eq!(payment.swap_type, PaymentSwapType::Submarine);
+        assert_eq!(payment.payment_type, PaymentType::Send);
+        assert_eq!(payment.status, PaymentState::Created);
+        assert!(payment.tx_id.is_none());
+
+        let payment = get_swap_payment(&persister, "receive", Some(&receive_swap.invoice))?;
+        assert_eq!(payment.swap_type, PaymentSwapType::Reverse);
+        assert_eq!(payment.payment_type, PaymentType::Receive);
+        assert_eq!(payment.status, PaymentState::Created);
+
+        let payment = get_swap_payment(&persister, "chain-in", None)?;
+        assert_eq!(payment.swap_type, PaymentSwapType::Chain);
+        assert_eq!(payment.payment_type, PaymentType::Receive);
+        let payment = get_swap_payment(&persister, "chain-out", None)?;
+        assert_eq!(payment.swap_type, PaymentSwapType::Chain);
+        assert_eq!(payment.payment_type, PaymentType::Send);
+
+        // The swaps which were never funded are not listed as payments
+        assert!(persister
+            .get_payments(&ListPaymentsRequest::default())?
+            .is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn get_payment_of_swaps_with_txs() -> Result<()> {
+        let (_data_dir, persister) = new_persister()?;
+        insert_payments(&persister)?;
+
+        let mut chain_swap =
+            new_chain_swap("chain-out", Direction::Outgoing, PaymentState::Pending);
+        chain_swap.user_lockup_tx_id = Some("chain-lockup".to_string());
+        persister.insert_chain_swap(&chain_swap)?;
+        persister.insert_or_update_payment(new_tx_data(
+            "chain-lockup",
+            5_000,
+            PaymentType::Send,
+            false,
+        ))?;
+
+        let send_invoice = persister
+            .fetch_send_swap_by_id("send")?
+            .expect("Expecting a Send Swap")
+            .invoice;
+        let receive_invoice = persister
+            .fetch_receive_swap("receive")?
+            .expect("Expecting a Receive Swap")
+            .invoice;
+        for (swap_id, invoice, tx_id) in [
+            ("send", Some(send_invoice.as_str()), Some("send-lockup")),
+            (
+                "receive",
+                Some(receive_invoice.as_str()),
+                Some("receive-claim"),
+            ),
+            ("chain", None, None),
+            ("chain-out", None, Some("chain-lockup")),
+        ] {
+            let payment = get_swap_payment(&persister, swap_id, invoice)?;
+            assert_eq!(payment.tx_id.as_deref(), tx_id);
+
+            if let Some(tx_id) = tx_id {
+                let payment = get_payment(
+                    &persister,
+                    GetPaymentRequest::TxId {
+                        tx_id: tx_id.to_string(),
+                    },
+                )?;
+                assert_eq!(payment.swap_id.as_deref(), Some(swap_id));
+            }
+        }
+
+        let payment = get_payment(
+            &persister,
+            GetPaymentRequest::TxId {
+                tx_id: "tx".to_string(),
+            },
+        )?;
+        assert!(payment.swap_id.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn payment_queries() -> Result<()> {
+        let (_data_dir, persister) = new_persister()?;
+
+        let payments = persister.get_payments(&ListPaymentsRequest {
+            payment_types: Some(vec![PaymentType::Send]),
+            states: Some(vec![PaymentState::Pending, PaymentState::Complete]),
+            from_timestamp: Some(1_700_000_000),
+            to_timestamp: Some(1_800_000_000),
+            offset: Some(10),
+            limit: Some(10),
+            sort_descending: Some(true),
+            metadata_search: Some("order".to_string()),
+        })?;
+        assert!(payments.is_empty());
+
+        let payment = persister.get_payment_by_request(&GetPaymentRequest::PaymentHash {
+            payment_hash: "00".repeat(32),
+        })?;
+        assert!(payment.is_none());
+
+        let tx_id = "11".repeat(32);
+        persister.insert_or_update_payment(new_tx_data(
+            &tx_id,
+            1_700_000_000,
+            PaymentType::Receive,
+            true,
+        ))?;
+        let metadata = PaymentMetadata {
+            label: Some("Groceries".to_string()),
+            note: None,
+            external_id: Some("order_42".to_string()),
+        };
+        persister.insert_or_update_payment_metadata(&tx_id, &metadata)?;
+        let search = |text: &str| {
+            persister.get_payments(&ListPaymentsRequest {
+                metadata_search: Some(text.to_string()),
+                ..Default::default()
+            })
+        };
+        assert_eq!(search("order_4")?[0].metadata, Some(metadata));
+        // LIKE wildcards in the search text are matched literally
+        assert!(search("order%")?.is_empty());
+        assert!(search("groceries")?.len() == 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fee_breakdown_persistence() -> Result<()> {
+        let fee_breakdown = FeeBreakdown {
+            service_fee_percentage: 0.1,
+            service_fee_sat: 50,
+            swapper_miner_fees_sat: 300,
+            network_fee_sat: 26,
+            total_fees_sat: 376,
+        };
+        let con = rusqlite::Connection::open_in_memory()?;
+        let persisted: FeeBreakdown =
+            con.query_row("SELECT ?1", [&fee_breakdown], |row| row.get(0))?;
+        assert_eq!(persisted, fee_breakdown);
+
+        // The breakdown of a swap is returned with its payment
+        let (_data_dir, persister) = new_persister()?;
+        let mut send_swap = new_send_swap("send", PaymentState::Created);
+        send_swap.fee_breakdown = Some(fee_breakdown.clone());
+        persister.insert_send_swap(&send_swap)?;
+        let payment = persister
+            .get_payment_by_request(&GetPaymentRequest::SwapId {
+                swap_id: "send".to_string(),
+            })?
+            .expect("Expecting a payment");
+        assert_eq!(payment.fee_breakdown, Some(fee_breakdown));
+
+        Ok(())
+    }
+
+    #[test]
+    fn fee_rate_persistence() -> Result<()> {
+        let (_data_dir, persister) = new_persister()?;
+
+        let tx_data = PaymentTxData {
+            tx_id: "22".repeat(32),
+            timestamp: None,
+            amount_sat: 1_030,
+            fees_sat: 30,
+            payment_type: PaymentType::Send,
+            is_confirmed: false,
+            asset_id: None,
+            asset_amount: None,
+            fee_rate_sat_per_vbyte: Some(0.25),
+        };
+        persister.insert_or_update_payment(tx_data.clone())?;
+        // Syncing the tx does not know the chosen fee rate, which must be kept
+        persister.insert_or_update_payment(PaymentTxData {
+            timestamp: Some(1_700_000_000),
+            is_confirmed: true,
+            fee_rate_sat_per_vbyte: None,
+            ..tx_data.clone()
+        })?;
+        let payment = persister
+            .get_payment(tx_data.tx_id)?
+            .expect("Expecting the payment to be persisted");
+        assert_eq!(payment.fee_rate_sat_per_vbyte, Some(0.25));
+        assert_eq!(payment.status, PaymentState::Complete);
+
+        Ok(())
+    }
+
+    #[test]
+    fn swap_key_index_of_new_db() -> Result<()> {
+        // A new database starts at the first swap key index
+        let (_data_dir, persister) = new_persister()?;
+        assert_eq!(persister.fetch_next_swap_key_index()?, Some(0));
+        assert_eq!(persister.next_swap_key_index()?, 0);
+
+        // An existing database is not reset, so a forgotten index stays to be recovered
+        persister.delete_swap_key_index()?;
+        persister.init()?;
+        assert_eq!(persister.fetch_next_swap_key_index()?, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn regtest_storage() -> Result<()> {
+        let (_data_dir, data_dir_str) = create_temp_dir()?;
+        Persister::new(&data_dir_str, Network::Regtest)?.init()?;
+        assert!(Path::new(&data_dir_str)
+            .join("storage-regtest.sql")
+            .exists());
+
+        Ok(())
+    }
+}
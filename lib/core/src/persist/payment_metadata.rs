@@ -0,0 +1,34 @@
+use anyhow::Result;
+use rusqlite::named_params;
+
+use crate::model::*;
+use crate::persist::Persister;
+
+impl Persister {
+    /// Stores the user metadata of a payment, keyed by its swap ID or, without swap, its tx ID.
+    /// Any previous metadata of the payment is replaced.
+    pub(crate) fn insert_or_update_payment_metadata(
+        &self,
+        id: &str,
+        metadata: &PaymentMetadata,
+    ) -> Result<()> {
+        let con = self.get_connection()?;
+        con.execute(
+            "INSERT OR REPLACE INTO payment_metadata (
+                id,
+                label,
+                note,
+                external_id
+            )
+            VALUES (:id, :label, :note, :external_id)",
+            named_params! {
+                ":id": id,
+                ":label": metadata.label,
+                ":note": metadata.note,
+                ":external_id": metadata.external_id,
+            },
+        )?;
+
+        Ok(())
+    }
+}
@@ -9,6 +9,7 @@ use crate::ensure_sdk;
 use crate::error::PaymentError;
 use crate::model::*;
 use crate::persist::Persister;
+use crate::utils;
 
 impl Persister {
     pub(crate) fn insert_receive_swap(&self, receive_swap: &ReceiveSwap) -> Result<()> {
@@ -27,9 +28,14 @@ impl Persister {
                 created_at,
                 claim_fees_sat,
                 claim_tx_id,
-                state
+                state,
+                key_index,
+                description,
+                payment_hash,
+                fee_breakdown_json,
+                fee_rate_sat_per_vbyte
             )
-            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
         )?;
         _ = stmt.execute((
             &receive_swap.id,
@@ -43,6 +49,11 @@ impl Persister {
             &receive_swap.claim_fees_sat,
             &receive_swap.claim_tx_id,
             &receive_swap.state,
+            &receive_swap.key_index,
+            &receive_swap.description,
+            utils::get_invoice_payment_hash(&receive_swap.invoice),
+            &receive_swap.fee_breakdown,
+            &receive_swap.fee_rate_sat_per_vbyte,
         ))?;
 
         Ok(())
@@ -68,7 +79,11 @@ impl Persister {
                 rs.claim_fees_sat,
                 rs.claim_tx_id,
                 rs.created_at,
-                rs.state
+                rs.state,
+                rs.key_index,
+                rs.description,
+                rs.fee_breakdown_json,
+                rs.fee_rate_sat_per_vbyte
             FROM receive_swaps AS rs
             {where_clause_str}
             ORDER BY rs.created_at
@@ -108,6 +123,10 @@ impl Persister {
             claim_tx_id: row.get(8)?,
             created_at: row.get(9)?,
             state: row.get(10)?,
+            key_index: row.get(11)?,
+            description: row.get(12)?,
+            fee_breakdown: row.get(13)?,
+            fee_rate_sat_per_vbyte: row.get(14)?,
         })
     }
 
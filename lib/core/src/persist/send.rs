@@ -9,6 +9,7 @@ use crate::ensure_sdk;
 use crate::error::PaymentError;
 use crate::model::*;
 use crate::persist::Persister;
+use crate::utils;
 
 impl Persister {
     pub(crate) fn insert_send_swap(&self, send_swap: &SendSwap) -> Result<()> {
@@ -26,9 +27,13 @@ impl Persister {
                 lockup_tx_id,
                 refund_tx_id,
                 created_at,
-                state
+                state,
+                key_index,
+                payment_hash,
+                fee_breakdown_json,
+                fee_rate_sat_per_vbyte
             )
-            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
         )?;
         _ = stmt.execute((
             &send_swap.id,
@@ -41,6 +46,10 @@ impl Persister {
             &send_swap.refund_tx_id,
             &send_swap.created_at,
             &send_swap.state,
+            &send_swap.key_index,
+            utils::get_invoice_payment_hash(&send_swap.invoice),
+            &send_swap.fee_breakdown,
+            &send_swap.fee_rate_sat_per_vbyte,
         ))?;
 
         Ok(())
@@ -89,7 +98,10 @@ impl Persister {
                 lockup_tx_id,
                 refund_tx_id,
                 created_at,
-                state
+                state,
+                key_index,
+                fee_breakdown_json,
+                fee_rate_sat_per_vbyte
             FROM send_swaps
             {where_clause_str}
             ORDER BY created_at
@@ -126,6 +138,9 @@ impl Persister {
             refund_tx_id: row.get(8)?,
             created_at: row.get(9)?,
             state: row.get(10)?,
+            key_index: row.get(11)?,
+            fee_breakdown: row.get(12)?,
+            fee_rate_sat_per_vbyte: row.get(13)?,
         })
     }
 
@@ -171,6 +186,17 @@ impl Persister {
         Ok(res)
     }
 
+    /// Pending Send swaps with a lockup tx and no refund tx
+    pub(crate) fn list_refundable_send_swaps(&self) -> Result<Vec<SendSwap>> {
+        let con: Connection = self.get_connection()?;
+        let where_clause = vec![
+            format!("state = {}", PaymentState::Pending as i8),
+            "lockup_tx_id IS NOT NULL".to_string(),
+            "refund_tx_id IS NULL".to_string(),
+        ];
+        Ok(self.list_send_swaps(&con, where_clause)?)
+    }
+
     /// Pending Send swaps, indexed by refund tx id
     pub(crate) fn list_pending_send_swaps_by_refund_tx_id(
         &self,
@@ -6,7 +6,7 @@ use boltz_client::swaps::boltzv2;
 use log::{debug, error, info, warn};
 use tokio::sync::broadcast;
 
-use crate::model::PaymentState::{Complete, Created, Failed, Pending, TimedOut};
+use crate::model::PaymentState::{Complete, Created, Failed, Pending, Refundable, TimedOut};
 use crate::model::{Config, PaymentTxData, PaymentType, ReceiveSwap};
 use crate::{ensure_sdk, utils};
 use crate::{
@@ -233,6 +233,9 @@ impl ReceiveSwapStateHandler {
             fees_sat: 0,
             payment_type: PaymentType::Receive,
             is_confirmed: false,
+            asset_id: None,
+            asset_amount: None,
+            fee_rate_sat_per_vbyte: ongoing_receive_swap.fee_rate_sat_per_vbyte,
         })?;
 
         self.update_swap_info(swap_id, Pending, Some(&claim_tx_id), None)
@@ -251,12 +254,12 @@ impl ReceiveSwapStateHandler {
             }),
 
             (Created | Pending, Pending) => Ok(()),
-            (Complete | Failed | TimedOut, Pending) => Err(PaymentError::Generic {
+            (Complete | Failed | TimedOut | Refundable, Pending) => Err(PaymentError::Generic {
                 err: format!("Cannot transition from {from_state:?} to Pending state"),
             }),
 
             (Created | Pending, Complete) => Ok(()),
-            (Complete | Failed | TimedOut, Complete) => Err(PaymentError::Generic {
+            (Complete | Failed | TimedOut | Refundable, Complete) => Err(PaymentError::Generic {
                 err: format!("Cannot transition from {from_state:?} to Complete state"),
             }),
 
@@ -265,6 +268,10 @@ impl ReceiveSwapStateHandler {
                 err: format!("Cannot transition from {from_state:?} to TimedOut state"),
             }),
 
+            (_, Refundable) => Err(PaymentError::Generic {
+                err: format!("Cannot transition from {from_state:?} to Refundable state"),
+            }),
+
             (_, Failed) => Ok(()),
         }
     }
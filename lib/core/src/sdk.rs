@@ -12,82 +12,136 @@ use anyhow::Result;
 use async_trait::async_trait;
 use boltz_client::lightning_invoice::Bolt11InvoiceDescription;
 use boltz_client::ToHex;
-use boltz_client::{swaps::boltzv2::*, util::secrets::Preimage, Amount, Bolt11Invoice};
+use boltz_client::{swaps::boltzv2::*, Amount, Bolt11Invoice};
 use futures_util::stream::select_all;
 use futures_util::StreamExt;
 use log::{debug, error, info, warn};
 use lwk_wollet::bitcoin::hex::DisplayHex;
+use lwk_wollet::elements::{LockTime, Script, Transaction};
 use lwk_wollet::hashes::{sha256, Hash};
-use lwk_wollet::{elements::LockTime, ElementsNetwork};
-use lwk_wollet::{BlockchainBackend, ElectrumClient, ElectrumUrl};
 use tokio::sync::{watch, RwLock};
 use tokio::time::MissedTickBehavior;
 use tokio_stream::wrappers::BroadcastStream;
 
+use crate::chain::{self, ChainChanges, ChainService};
+use crate::chain_swap::ChainSwapStateHandler;
+use crate::electrum::ElectrumServers;
 use crate::error::LiquidSdkError;
+use crate::fee_estimator::{
+    self, FeeEstimator, BITCOIN_MIN_FEE_RATE_SAT_PER_VBYTE, LIQUID_MIN_FEE_RATE_SAT_PER_VBYTE,
+};
+use crate::lnurl::{self, RestClient, UreqRestClient};
 use crate::model::PaymentState::*;
 use crate::receive_swap::ReceiveSwapStateHandler;
 use crate::send_swap::SendSwapStateHandler;
 use crate::swapper::{BoltzSwapper, ReconnectHandler, Swapper, SwapperStatusStream};
-use crate::wallet::{LiquidOnchainWallet, OnchainWallet};
+use crate::wallet::{reveals_swap_keys, LiquidOnchainWallet, OnchainWallet};
 use crate::{
     ensure_sdk,
     error::{LiquidSdkResult, PaymentError},
     event::EventManager,
-    get_invoice_amount,
+    export, get_invoice_amount, input_parser,
     model::*,
     persist::Persister,
     utils,
 };
 
 pub const DEFAULT_DATA_DIR: &str = ".data";
-
-pub(crate) trait ChainService: Send + Sync + BlockchainBackend {}
-impl ChainService for ElectrumClient {}
+/// How long the swapper fees quoted in a prepared Lightning payment are honored
+const FEE_QUOTE_VALIDITY_SECS: u32 = 60;
+/// How often the chain subscriptions are checked for changes
+const CHAIN_SUBSCRIPTIONS_POLL_SECS: u64 = 5;
+/// Number of consecutive unused swap key indexes after which the swap key index recovery stops
+const SWAP_KEY_RECOVERY_GAP_LIMIT: u32 = 20;
 
 pub struct LiquidSdk {
     config: Config,
     onchain_wallet: Arc<dyn OnchainWallet>,
+    chain_service: Arc<dyn ChainService>,
     persister: Arc<Persister>,
     event_manager: Arc<EventManager>,
     status_stream: Arc<dyn SwapperStatusStream>,
     swapper: Arc<dyn Swapper>,
+    rest_client: Arc<dyn RestClient>,
+    fee_estimator: Arc<dyn FeeEstimator>,
+    electrum_servers: Arc<ElectrumServers>,
     is_started: RwLock<bool>,
     shutdown_sender: watch::Sender<()>,
     shutdown_receiver: watch::Receiver<()>,
     send_swap_state_handler: SendSwapStateHandler,
     receive_swap_state_handler: ReceiveSwapStateHandler,
+    chain_swap_state_handler: ChainSwapStateHandler,
 }
 
 impl LiquidSdk {
     pub async fn connect(req: ConnectRequest) -> Result<Arc<LiquidSdk>> {
+        Self::connect_with_rest_client(req, Arc::new(UreqRestClient)).await
+    }
+
+    /// Like [LiquidSdk::connect], but with a custom HTTP client for the LNURL requests
+    pub async fn connect_with_rest_client(
+        req: ConnectRequest,
+        rest_client: Arc<dyn RestClient>,
+    ) -> Result<Arc<LiquidSdk>> {
         let config = req.config;
-        let sdk = LiquidSdk::new(config, req.mnemonic)?;
+        let sdk = LiquidSdk::new(config, req.mnemonic, rest_client)?;
         sdk.start().await?;
 
         Ok(sdk)
     }
 
-    fn new(config: Config, mnemonic: String) -> Result<Arc<Self>> {
+    fn new(
+        config: Config,
+        mnemonic: String,
+        rest_client: Arc<dyn RestClient>,
+    ) -> Result<Arc<Self>> {
         fs::create_dir_all(&config.working_dir)?;
 
         let persister = Arc::new(Persister::new(&config.working_dir, config.network)?);
         persister.init()?;
 
+        let electrum_servers = Arc::new(ElectrumServers::new(config.liquid_electrum_servers()));
+        let chain_service = chain::new_chain_service(&config, electrum_servers.clone());
+        let swapper = Arc::new(BoltzSwapper::new(
+            config.clone(),
+            electrum_servers.clone(),
+            chain_service.clone(),
+        ));
+        let onchain_wallet = Arc::new(LiquidOnchainWallet::new(
+            mnemonic,
+            config.clone(),
+            chain_service.clone(),
+        )?);
+        let fee_estimator = fee_estimator::new_fee_estimator(&config, electrum_servers.clone());
+
+        Ok(Self::new_with_components(
+            config,
+            persister,
+            swapper,
+            onchain_wallet,
+            chain_service,
+            fee_estimator,
+            rest_client,
+            electrum_servers,
+        ))
+    }
+
+    /// Assembles an SDK instance from its components, which tests replace with mocks
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new_with_components(
+        config: Config,
+        persister: Arc<Persister>,
+        swapper: Arc<dyn Swapper>,
+        onchain_wallet: Arc<dyn OnchainWallet>,
+        chain_service: Arc<dyn ChainService>,
+        fee_estimator: Arc<dyn FeeEstimator>,
+        rest_client: Arc<dyn RestClient>,
+        electrum_servers: Arc<ElectrumServers>,
+    ) -> Arc<Self> {
         let event_manager = Arc::new(EventManager::new());
         let (shutdown_sender, shutdown_receiver) = watch::channel::<()>(());
-
-        let swapper = Arc::new(BoltzSwapper::new(config.clone()));
         let status_stream = Arc::<dyn SwapperStatusStream>::from(swapper.create_status_stream());
 
-        let chain_service = Arc::new(ElectrumClient::new(&ElectrumUrl::new(
-            &config.electrum_url,
-            true,
-            true,
-        ))?);
-
-        let onchain_wallet = Arc::new(LiquidOnchainWallet::new(mnemonic, config.clone())?);
-
         let send_swap_state_handler = SendSwapStateHandler::new(
             config.clone(),
             onchain_wallet.clone(),
@@ -103,20 +157,32 @@ impl LiquidSdk {
             swapper.clone(),
         );
 
-        let sdk = Arc::new(LiquidSdk {
-            config: config.clone(),
+        let chain_swap_state_handler = ChainSwapStateHandler::new(
+            config.clone(),
+            onchain_wallet.clone(),
+            persister.clone(),
+            swapper.clone(),
+            chain_service.clone(),
+        );
+
+        Arc::new(LiquidSdk {
+            config,
             onchain_wallet,
-            persister: persister.clone(),
+            chain_service,
+            persister,
             event_manager,
-            status_stream: status_stream.clone(),
+            status_stream,
             swapper,
+            rest_client,
+            fee_estimator,
+            electrum_servers,
             is_started: RwLock::new(false),
             shutdown_sender,
             shutdown_receiver,
             send_swap_state_handler,
             receive_swap_state_handler,
-        });
-        Ok(sdk)
+            chain_swap_state_handler,
+        })
     }
 
     /// Starts an SDK instance.
@@ -124,34 +190,161 @@ impl LiquidSdk {
     /// Internal method. Should only be called once per instance.
     /// Should only be called as part of [LiquidSdk::connect].
     async fn start(self: &Arc<LiquidSdk>) -> LiquidSdkResult<()> {
-        let mut is_started = self.is_started.write().await;
-        let start_ts = Instant::now();
+        {
+            let mut is_started = self.is_started.write().await;
+            let start_ts = Instant::now();
 
-        self.persister
-            .update_send_swaps_by_state(Created, TimedOut)?;
-        self.start_background_tasks().await?;
-        *is_started = true;
+            self.persister
+                .update_send_swaps_by_state(Created, TimedOut)?;
+            self.start_background_tasks().await?;
+            *is_started = true;
+
+            let start_duration = start_ts.elapsed();
+            info!("Liquid SDK initialized in: {start_duration:?}");
+        }
 
-        let start_duration = start_ts.elapsed();
-        info!("Liquid SDK initialized in: {start_duration:?}");
+        // New swaps can only be created once the swap key index is recovered
+        let sdk_clone = self.clone();
+        tokio::spawn(async move {
+            if let Err(e) = sdk_clone.recover_swap_key_index().await {
+                warn!("Could not recover the swap key index: {e:?}");
+            }
+        });
         Ok(())
     }
 
+    /// Marks an SDK instance as started, without running the background tasks
+    #[cfg(test)]
+    pub(crate) async fn mark_as_started(&self) {
+        *self.is_started.write().await = true;
+    }
+
+    /// Recovers the index of the next swap keys if it is not persisted, e.g. after the database
+    /// was lost or restored from a backup, so that the keys and preimages of past swaps are never
+    /// reused.
+    ///
+    /// The swap keys are derived until [SWAP_KEY_RECOVERY_GAP_LIMIT] consecutive key indexes are
+    /// unused, i.e. neither known to the swapper nor revealed by the wallet txs. The swapper is
+    /// queried in a blocking task.
+    async fn recover_swap_key_index(self: &Arc<LiquidSdk>) -> Result<()> {
+        if let Some(next_index) = self.persister.fetch_next_swap_key_index()? {
+            debug!("Next swap key index: {next_index}");
+            return Ok(());
+        }
+
+        self.onchain_wallet.full_scan().await?;
+        let wallet_txs: Vec<Transaction> = self
+            .onchain_wallet
+            .transactions()
+            .await?
+            .into_iter()
+            .map(|wallet_tx| wallet_tx.tx)
+            .collect();
+
+        let sdk = self.clone();
+        let next_index =
+            tokio::task::spawn_blocking(move || sdk.find_next_swap_key_index(&wallet_txs))
+                .await??;
+        info!("Recovered the next swap key index: {next_index}");
+        self.persister.set_min_next_swap_key_index(next_index)
+    }
+
+    /// The index following the last used swap keys, see [LiquidSdk::recover_swap_key_index]
+    fn find_next_swap_key_index(&self, wallet_txs: &[Transaction]) -> Result<u32> {
+        let mut next_index = self
+            .persister
+            .fetch_max_swap_key_index()?
+            .map_or(0, |key_index| key_index + 1);
+        let mut key_index = 0;
+        let mut unused_key_indexes = 0;
+        while unused_key_indexes < SWAP_KEY_RECOVERY_GAP_LIMIT {
+            if self.is_swap_key_index_used(key_index, wallet_txs)? {
+                next_index = next_index.max(key_index + 1);
+                unused_key_indexes = 0;
+            } else {
+                unused_key_indexes += 1;
+            }
+            key_index += 1;
+        }
+        Ok(next_index)
+    }
+
+    /// Whether the swapper knows a swap created with the keys of the given index, or a wallet tx
+    /// spent a swap output with them
+    fn is_swap_key_index_used(
+        &self,
+        key_index: u32,
+        wallet_txs: &[Transaction],
+    ) -> Result<bool, PaymentError> {
+        let public_keys = [
+            self.onchain_wallet
+                .derive_swap_claim_keypair(key_index)?
+                .public_key(),
+            self.onchain_wallet
+                .derive_swap_refund_keypair(key_index)?
+                .public_key(),
+        ];
+        let preimage_hash = self.onchain_wallet.derive_swap_preimage(key_index)?.sha256;
+        if wallet_txs
+            .iter()
+            .any(|tx| reveals_swap_keys(tx, &public_keys, &preimage_hash))
+        {
+            return Ok(true);
+        }
+
+        for public_key in &public_keys {
+            if !self
+                .swapper
+                .list_swap_ids_by_public_key(public_key)?
+                .is_empty()
+            {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
     /// Starts background tasks.
     ///
     /// Internal method. Should only be used as part of [LiquidSdk::start].
     async fn start_background_tasks(self: &Arc<LiquidSdk>) -> LiquidSdkResult<()> {
-        // Periodically run sync() in the background
+        // Run sync() in the background on startup, when a watched script changes, and
+        // periodically as a fallback
         let sdk_clone = self.clone();
         let mut shutdown_rx_sync_loop = self.shutdown_receiver.clone();
         tokio::spawn(async move {
+            let fallback_interval =
+                Duration::from_secs(sdk_clone.config.sync_fallback_interval_sec);
+            let mut last_sync: Option<Instant> = None;
+            let mut watched_scripts = vec![];
             loop {
-                _ = sdk_clone.sync().await;
+                let changes = sdk_clone.poll_chain_changes(&watched_scripts).await;
+                let fallback_due =
+                    last_sync.map_or(true, |last_sync| last_sync.elapsed() >= fallback_interval);
+
+                if changes.scripts_changed || fallback_due {
+                    if let Err(e) = sdk_clone.sync().await {
+                        warn!("Background sync failed: {e:?}");
+                    }
+                    last_sync = Some(Instant::now());
+                    // The scan may have found used addresses and new swaps may have been created,
+                    // so new scripts are watched
+                    match sdk_clone.watched_scripts().await {
+                        Ok(scripts) => watched_scripts = scripts,
+                        Err(e) => warn!("Could not get the scripts to watch: {e:?}"),
+                    }
+                } else if changes.tip_changed {
+                    // A tx confirming also changes the history of its scripts, so a new block alone
+                    // needs no scan: the confirmations are refreshed from the current wallet txs
+                    if let Err(e) = sdk_clone.sync_payments_with_chain_data(false).await {
+                        warn!("Could not refresh the payment confirmations: {e:?}");
+                    }
+                }
 
                 tokio::select! {
-                    _ = tokio::time::sleep(Duration::from_secs(30)) => {}
+                    _ = tokio::time::sleep(Duration::from_secs(CHAIN_SUBSCRIPTIONS_POLL_SECS)) => {}
                     _ = shutdown_rx_sync_loop.changed() => {
-                        info!("Received shutdown signal, exiting periodic sync loop");
+                        info!("Received shutdown signal, exiting background sync loop");
                         return;
                     }
                 }
@@ -172,6 +365,49 @@ impl LiquidSdk {
         Ok(())
     }
 
+    /// Checks the chain subscriptions for changes, in a blocking task as it waits on the
+    /// connection. Nothing is reported as changed if the check fails.
+    async fn poll_chain_changes(&self, watched_scripts: &[Script]) -> ChainChanges {
+        let chain_service = self.chain_service.clone();
+        let scripts = watched_scripts.to_vec();
+        match tokio::task::spawn_blocking(move || chain_service.poll_changes(&scripts)).await {
+            Ok(Ok(changes)) => changes.unwrap_or_default(),
+            Ok(Err(e)) => {
+                warn!("Could not check the chain subscriptions: {e:?}");
+                ChainChanges::default()
+            }
+            Err(e) => {
+                warn!("Chain subscriptions check did not complete: {e:?}");
+                ChainChanges::default()
+            }
+        }
+    }
+
+    /// The scripts to sync on changes of: those of the wallet addresses and the Liquid scripts
+    /// of the ongoing swaps, which their lockup, claim and refund txs pay to or spend from
+    async fn watched_scripts(&self) -> Result<Vec<Script>> {
+        let mut scripts = self.onchain_wallet.watched_scripts().await?;
+        for swap in self.persister.list_ongoing_swaps()? {
+            let swap_script = match &swap {
+                Swap::Send(send_swap) => send_swap.get_swap_script(),
+                Swap::Receive(receive_swap) => receive_swap.get_swap_script(),
+                // The Liquid side is the server lockup of Incoming swaps, the user lockup of
+                // Outgoing ones
+                Swap::Chain(chain_swap) => match chain_swap.direction {
+                    Direction::Incoming => chain_swap.get_claim_swap_script_lbtc(),
+                    Direction::Outgoing => chain_swap.get_lockup_swap_script_lbtc(),
+                },
+            };
+            match swap_script
+                .and_then(|swap_script| Ok(swap_script.to_address(self.config.network.into())?))
+            {
+                Ok(address) => scripts.push(address.script_pubkey()),
+                Err(e) => warn!("Could not watch the script of swap {}: {e:?}", swap.id()),
+            }
+        }
+        Ok(scripts)
+    }
+
     async fn ensure_is_started(&self) -> LiquidSdkResult<()> {
         let is_started = self.is_started.read().await;
         ensure_sdk!(*is_started, LiquidSdkError::NotStarted);
@@ -202,6 +438,7 @@ impl LiquidSdk {
                 cloned
                     .receive_swap_state_handler
                     .subscribe_payment_updates(),
+                cloned.chain_swap_state_handler.subscribe_payment_updates(),
             ];
             let mut combined_swap_streams =
                 select_all(swaps_streams.into_iter().map(BroadcastStream::new));
@@ -223,26 +460,28 @@ impl LiquidSdk {
                         Ok(update) => {
                             let _ = cloned.sync().await;
                             let id = update.id();
-                            match cloned.persister.fetch_send_swap_by_id(id) {
-                                Ok(Some(_)) => {
+                            match cloned.persister.fetch_swap_by_id(id) {
+                                Ok(Some(Swap::Send(_))) => {
                                     match cloned.send_swap_state_handler.on_new_status(&update).await {
                                         Ok(_) => info!("Successfully handled Send Swap {id} update"),
                                         Err(e) => error!("Failed to handle Send Swap {id} update: {e}")
                                     }
                                 }
-                                _ => {
-                                    match cloned.persister.fetch_receive_swap(id) {
-                                        Ok(Some(_)) => {
-                                            match cloned.receive_swap_state_handler.on_new_status(&update).await {
-                                                Ok(_) => info!("Successfully handled Receive Swap {id} update"),
-                                                Err(e) => error!("Failed to handle Receive Swap {id} update: {e}")
-                                            }
-                                        }
-                                        _ => {
-                                            error!("Could not find Swap {id}");
-                                        }
+                                Ok(Some(Swap::Receive(_))) => {
+                                    match cloned.receive_swap_state_handler.on_new_status(&update).await {
+                                        Ok(_) => info!("Successfully handled Receive Swap {id} update"),
+                                        Err(e) => error!("Failed to handle Receive Swap {id} update: {e}")
+                                    }
+                                }
+                                Ok(Some(Swap::Chain(_))) => {
+                                    match cloned.chain_swap_state_handler.on_new_status(&update).await {
+                                        Ok(_) => info!("Successfully handled Chain Swap {id} update"),
+                                        Err(e) => error!("Failed to handle Chain Swap {id} update: {e}")
                                     }
                                 }
+                                _ => {
+                                    error!("Could not find Swap {id}");
+                                }
                             }
                         }
                         Err(e) => error!("Received stream error: {e:?}"),
@@ -275,6 +514,16 @@ impl LiquidSdk {
                             }
                             Err(e) => error!("Error listing pending send swaps: {e:?}"),
                         }
+                        match cloned.persister.list_pending_chain_swaps() {
+                            Ok(pending_chain_swaps) => {
+                                for swap in pending_chain_swaps {
+                                    if let Err(e) = cloned.check_chain_swap_expiration(&swap).await {
+                                        error!("Error checking expiration for Chain Swap {}: {e:?}", swap.id);
+                                    }
+                                }
+                            }
+                            Err(e) => error!("Error listing pending chain swaps: {e:?}"),
+                        }
                     },
                     _ = shutdown_receiver.changed() => {
                         info!("Received shutdown signal, exiting refundable swaps loop");
@@ -288,13 +537,13 @@ impl LiquidSdk {
     async fn check_send_swap_expiration(&self, send_swap: &SendSwap) -> Result<()> {
         if send_swap.lockup_tx_id.is_some() && send_swap.refund_tx_id.is_none() {
             let swap_script = send_swap.get_swap_script()?;
-            let current_height = self.onchain_wallet.tip().await.height();
+            let current_height = self.chain_service.tip()?;
             let locktime_from_height = LockTime::from_height(current_height)?;
 
             info!("Checking Send Swap {} expiration: locktime_from_height = {locktime_from_height:?},  swap_script.locktime = {:?}", send_swap.id, swap_script.locktime);
             if utils::is_locktime_expired(locktime_from_height, swap_script.locktime) {
                 let id = &send_swap.id;
-                let refund_tx_id = self.try_refund(send_swap).await?;
+                let refund_tx_id = self.try_refund_to_wallet(send_swap).await?;
                 info!("Broadcast refund tx for Send Swap {id}. Tx id: {refund_tx_id}");
                 self.send_swap_state_handler
                     .update_swap_info(id, Pending, None, None, Some(&refund_tx_id))
@@ -304,6 +553,27 @@ impl LiquidSdk {
         Ok(())
     }
 
+    async fn check_chain_swap_expiration(&self, chain_swap: &ChainSwap) -> Result<()> {
+        // Only Outgoing swaps lock funds of this wallet, Incoming ones are refunded via [LiquidSdk::refund]
+        if chain_swap.direction == Direction::Outgoing
+            && chain_swap.user_lockup_tx_id.is_some()
+            && chain_swap.claim_tx_id.is_none()
+            && chain_swap.refund_tx_id.is_none()
+        {
+            let swap_script = chain_swap.get_lockup_swap_script_lbtc()?;
+            let current_height = self.chain_service.tip()?;
+            let locktime_from_height = LockTime::from_height(current_height)?;
+
+            info!("Checking Chain Swap {} expiration: locktime_from_height = {locktime_from_height:?},  swap_script.locktime = {:?}", chain_swap.id, swap_script.locktime);
+            if utils::is_locktime_expired(locktime_from_height, swap_script.locktime) {
+                self.chain_swap_state_handler
+                    .refund_outgoing_swap_and_update(chain_swap)
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
     async fn notify_event_listeners(&self, e: LiquidSdkEvent) -> Result<()> {
         self.event_manager.notify(e).await;
         Ok(())
@@ -335,61 +605,66 @@ impl LiquidSdk {
                         Pending => {
                             // The swap state has changed to Pending
                             match payment.swap_id.clone() {
-                                Some(swap_id) => match payment.payment_type {
-                                    PaymentType::Receive => {
-                                        match self.persister.fetch_receive_swap(&swap_id)? {
-                                            Some(swap) => match swap.claim_tx_id {
-                                                Some(_) => {
-                                                    // The claim tx has now been broadcast
-                                                    self.notify_event_listeners(
-                                                        LiquidSdkEvent::PaymentWaitingConfirmation {
-                                                            details: payment,
-                                                        },
-                                                    )
-                                                    .await?
-                                                }
-                                                None => {
-                                                    // The lockup tx is in the mempool/confirmed
-                                                    self.notify_event_listeners(
-                                                        LiquidSdkEvent::PaymentPending {
-                                                            details: payment,
-                                                        },
-                                                    )
-                                                    .await?
-                                                }
+                                Some(swap_id) => match self.persister.fetch_swap_by_id(&swap_id)? {
+                                    Some(Swap::Receive(ReceiveSwap {
+                                        claim_tx_id: Some(_),
+                                        ..
+                                    }))
+                                    | Some(Swap::Chain(ChainSwap {
+                                        direction: Direction::Incoming,
+                                        claim_tx_id: Some(_),
+                                        ..
+                                    })) => {
+                                        // The claim tx has now been broadcast
+                                        self.notify_event_listeners(
+                                            LiquidSdkEvent::PaymentWaitingConfirmation {
+                                                details: payment,
                                             },
-                                            None => debug!("Swap not found: {swap_id}"),
-                                        }
+                                        )
+                                        .await?
                                     }
-                                    PaymentType::Send => {
-                                        match self.persister.fetch_send_swap_by_id(&swap_id)? {
-                                            Some(swap) => match swap.refund_tx_id {
-                                                Some(_) => {
-                                                    // The refund tx has now been broadcast
-                                                    self.notify_event_listeners(
-                                                        LiquidSdkEvent::PaymentRefundPending {
-                                                            details: payment,
-                                                        },
-                                                    )
-                                                    .await?
-                                                }
-                                                None => {
-                                                    // The lockup tx is in the mempool/confirmed
-                                                    self.notify_event_listeners(
-                                                        LiquidSdkEvent::PaymentPending {
-                                                            details: payment,
-                                                        },
-                                                    )
-                                                    .await?
-                                                }
+                                    Some(Swap::Send(SendSwap {
+                                        refund_tx_id: Some(_),
+                                        ..
+                                    }))
+                                    | Some(Swap::Chain(ChainSwap {
+                                        direction: Direction::Outgoing,
+                                        refund_tx_id: Some(_),
+                                        ..
+                                    })) => {
+                                        // The refund tx has now been broadcast
+                                        self.notify_event_listeners(
+                                            LiquidSdkEvent::PaymentRefundPending {
+                                                details: payment,
                                             },
-                                            None => debug!("Swap not found: {swap_id}"),
-                                        }
+                                        )
+                                        .await?
+                                    }
+                                    Some(_) => {
+                                        // The lockup tx is in the mempool/confirmed
+                                        self.notify_event_listeners(
+                                            LiquidSdkEvent::PaymentPending { details: payment },
+                                        )
+                                        .await?
                                     }
+                                    None => debug!("Swap not found: {swap_id}"),
                                 },
-                                None => debug!("Payment has no swap id"),
+                                None => {
+                                    // A direct onchain payment, with its tx in the mempool
+                                    self.notify_event_listeners(LiquidSdkEvent::PaymentPending {
+                                        details: payment,
+                                    })
+                                    .await?
+                                }
                             }
                         }
+                        Refundable => {
+                            // The swap failed after the payer locked up funds
+                            self.notify_event_listeners(LiquidSdkEvent::PaymentRefundable {
+                                details: payment,
+                            })
+                            .await?
+                        }
                         Failed => match payment.payment_type {
                             PaymentType::Receive => {
                                 self.notify_event_listeners(LiquidSdkEvent::PaymentFailed {
@@ -426,7 +701,7 @@ impl LiquidSdk {
         let mut confirmed_sent_sat = 0;
         let mut confirmed_received_sat = 0;
 
-        for p in self.list_payments().await? {
+        for p in self.list_payments(&ListPaymentsRequest::default()).await? {
             match p.payment_type {
                 PaymentType::Send => match p.status {
                     Complete => confirmed_sent_sat += p.amount_sat,
@@ -442,21 +717,34 @@ impl LiquidSdk {
                         None => pending_send_sat += p.amount_sat,
                     },
                     Created => pending_send_sat += p.amount_sat,
-                    TimedOut => {}
+                    TimedOut | Refundable => {}
                 },
                 PaymentType::Receive => match p.status {
                     Complete => confirmed_received_sat += p.amount_sat,
-                    Failed => {}
+                    Failed | Refundable => {}
                     _ => pending_receive_sat += p.amount_sat,
                 },
             }
         }
 
+        let asset_balances = self
+            .onchain_wallet
+            .asset_balances()
+            .await?
+            .into_iter()
+            .map(|(asset_id, balance)| AssetBalance {
+                asset_metadata: self.config.get_asset_metadata(&asset_id),
+                asset_id,
+                balance,
+            })
+            .collect();
+
         Ok(GetInfoResponse {
             balance_sat: confirmed_received_sat - confirmed_sent_sat - pending_send_sat,
             pending_send_sat,
             pending_receive_sat,
             pubkey: self.onchain_wallet.pubkey(),
+            asset_balances,
         })
     }
 
@@ -470,6 +758,7 @@ impl LiquidSdk {
         match (invoice.network().to_string().as_str(), self.config.network) {
             ("bitcoin", Network::Mainnet) => {}
             ("testnet", Network::Testnet) => {}
+            ("regtest", Network::Regtest) => {}
             _ => {
                 return Err(PaymentError::InvalidInvoice {
                     err: "Invoice cannot be paid on the current network".to_string(),
@@ -508,26 +797,236 @@ impl LiquidSdk {
         Ok(lbtc_pair)
     }
 
+    /// Fetches the current limits and fees of Lightning payments, for both Send and Receive.
+    ///
+    /// Use it to validate amounts before preparing a payment. The swapper pairs are cached
+    /// briefly, so calling it right before preparing a payment is cheap.
+    pub async fn fetch_lightning_limits(
+        &self,
+    ) -> Result<LightningPaymentLimitsResponse, PaymentError> {
+        self.ensure_is_started().await?;
+
+        let submarine_pair = self
+            .swapper
+            .get_submarine_pairs()?
+            .ok_or(PaymentError::PairsNotFound)?;
+        let reverse_pair = self
+            .swapper
+            .get_reverse_swap_pairs()?
+            .ok_or(PaymentError::PairsNotFound)?;
+        Ok(LightningPaymentLimitsResponse {
+            send: LightningLimits {
+                min_sat: submarine_pair.limits.minimal,
+                max_sat: submarine_pair.limits.maximal,
+                service_fee_percentage: submarine_pair.fees.percentage,
+                miner_fees_sat: submarine_pair.fees.miner_fees,
+            },
+            receive: LightningLimits {
+                min_sat: reverse_pair.limits.minimal,
+                max_sat: reverse_pair.limits.maximal,
+                service_fee_percentage: reverse_pair.fees.percentage,
+                miner_fees_sat: reverse_pair.fees.miner_fees.lockup
+                    + reverse_pair.fees.claim_estimate(),
+            },
+        })
+    }
+
+    /// Fetches the recommended fee rates of the Liquid and Bitcoin chains, to choose the fee
+    /// rate of an onchain tx with [OnchainFeeRate::SatPerVbyte]
+    pub async fn fetch_recommended_fees(&self) -> Result<RecommendedFeesResponse, PaymentError> {
+        self.ensure_is_started().await?;
+
+        Ok(RecommendedFeesResponse {
+            liquid: self.fee_estimator.recommended_liquid_fees()?,
+            bitcoin: self.fee_estimator.recommended_bitcoin_fees()?,
+        })
+    }
+
+    /// Resolves a fee rate to sat/vbyte. A [FeePriority] is looked up in the fees returned by
+    /// `recommended_fees`, which is only called in that case.
+    fn resolve_fee_rate(
+        fee_rate: &OnchainFeeRate,
+        min_fee_rate_sat_per_vbyte: f64,
+        recommended_fees: impl FnOnce() -> Result<RecommendedFees, PaymentError>,
+    ) -> Result<f64, PaymentError> {
+        match fee_rate {
+            OnchainFeeRate::SatPerVbyte { sat_per_vbyte } => {
+                ensure_sdk!(
+                    *sat_per_vbyte >= min_fee_rate_sat_per_vbyte,
+                    PaymentError::Generic {
+                        err: format!(
+                            "Fee rate must be at least {min_fee_rate_sat_per_vbyte} sat/vbyte"
+                        )
+                    }
+                );
+                Ok(*sat_per_vbyte)
+            }
+            OnchainFeeRate::Priority { priority } => Ok(recommended_fees()?.fee_rate(*priority)),
+        }
+    }
+
+    /// Resolves the optional fee rate of a Liquid tx. `None` leaves the fee rate to the wallet
+    /// or the swapper.
+    fn resolve_liquid_fee_rate(
+        &self,
+        fee_rate: &Option<OnchainFeeRate>,
+    ) -> Result<Option<f64>, PaymentError> {
+        fee_rate
+            .as_ref()
+            .map(|fee_rate| {
+                Self::resolve_fee_rate(fee_rate, LIQUID_MIN_FEE_RATE_SAT_PER_VBYTE, || {
+                    self.fee_estimator.recommended_liquid_fees()
+                })
+            })
+            .transpose()
+    }
+
+    /// The fee of an L-BTC claim tx. The swapper estimates it at the minimum Liquid fee rate,
+    /// which gives the claim tx vsize to apply a custom fee rate to. This fee is persisted with
+    /// the swap and is the one the claim tx pays.
+    fn liquid_claim_fees_sat(claim_estimate_sat: u64, fee_rate_sat_per_vbyte: Option<f64>) -> u64 {
+        // Scaled in msat/vbyte, so that rates like 0.1 sat/vbyte are exact
+        let to_msat_per_vbyte = |fee_rate: f64| (fee_rate * 1000.0).round() as u64;
+        match fee_rate_sat_per_vbyte {
+            Some(fee_rate) => (claim_estimate_sat * to_msat_per_vbyte(fee_rate))
+                .div_ceil(to_msat_per_vbyte(LIQUID_MIN_FEE_RATE_SAT_PER_VBYTE)),
+            None => claim_estimate_sat,
+        }
+    }
+
+    /// Checks that the fees of a prepared Lightning payment are still honored, and that they are
+    /// the ones of the current pair: the pair must still have the quoted hash, and the quoted
+    /// breakdown must add up and be the one `pair_fee_breakdown` derives from the pair.
+    fn ensure_fee_quote_is_valid(
+        fees_sat: u64,
+        fee_breakdown: &FeeBreakdown,
+        quoted_pair_hash: &str,
+        expires_at: u32,
+        pair_hash: &str,
+        pair_fee_breakdown: &FeeBreakdown,
+    ) -> Result<(), PaymentError> {
+        let components_sat = fee_breakdown
+            .service_fee_sat
+            .checked_add(fee_breakdown.swapper_miner_fees_sat)
+            .and_then(|sum_sat| sum_sat.checked_add(fee_breakdown.network_fee_sat));
+        ensure_sdk!(
+            utils::now() < expires_at
+                && quoted_pair_hash == pair_hash
+                && components_sat == Some(fee_breakdown.total_fees_sat)
+                && fees_sat == fee_breakdown.total_fees_sat
+                && fee_breakdown == pair_fee_breakdown,
+            PaymentError::InvalidOrExpiredFees
+        );
+        Ok(())
+    }
+
+    /// Checks the fees of a Lightning payment against the fee limits of the request or, if not
+    /// set there, of the [Config]. The percentage limit applies to the invoice amount.
+    fn ensure_fees_within_limits(
+        &self,
+        fees_sat: u64,
+        invoice_amount_sat: u64,
+        max_fee_sat: Option<u64>,
+        max_fee_percent: Option<f64>,
+    ) -> Result<(), PaymentError> {
+        let max_fee_sat = max_fee_sat.or(self.config.max_fee_sat);
+        let max_fee_percent = max_fee_percent.or(self.config.max_fee_percent);
+        if let Some(percent) = max_fee_percent {
+            ensure_sdk!(
+                percent >= 0.0,
+                PaymentError::Generic {
+                    err: format!(
+                        "Invalid max fee percent {percent}, must be a non-negative number"
+                    )
+                }
+            );
+        }
+        let max_fee_percent_sat =
+            max_fee_percent.map(|percent| (invoice_amount_sat as f64 * percent / 100.0) as u64);
+        if let Some(limit_sat) = [max_fee_sat, max_fee_percent_sat]
+            .into_iter()
+            .flatten()
+            .min()
+        {
+            ensure_sdk!(
+                fees_sat <= limit_sat,
+                PaymentError::FeeLimitExceeded {
+                    fee_sat: fees_sat,
+                    limit_sat
+                }
+            );
+        }
+        Ok(())
+    }
+
+    /// Breaks down the fees of a Send Swap, given the estimated fee of its lockup tx
+    fn submarine_fee_breakdown(
+        lbtc_pair: &SubmarinePair,
+        receiver_amount_sat: u64,
+        lockup_fees_sat: u64,
+    ) -> FeeBreakdown {
+        FeeBreakdown {
+            service_fee_percentage: lbtc_pair.fees.percentage,
+            service_fee_sat: lbtc_pair.fees.boltz(receiver_amount_sat),
+            swapper_miner_fees_sat: lbtc_pair.fees.miner_fees,
+            network_fee_sat: lockup_fees_sat,
+            total_fees_sat: lbtc_pair.fees.total(receiver_amount_sat) + lockup_fees_sat,
+        }
+    }
+
+    /// Breaks down the fees of a Receive Swap, given the fee of its claim tx
+    fn reverse_fee_breakdown(
+        reverse_pair: &ReversePair,
+        payer_amount_sat: u64,
+        claim_fees_sat: u64,
+    ) -> FeeBreakdown {
+        FeeBreakdown {
+            service_fee_percentage: reverse_pair.fees.percentage,
+            service_fee_sat: reverse_pair.fees.boltz(payer_amount_sat),
+            swapper_miner_fees_sat: reverse_pair.fees.miner_fees.lockup,
+            network_fee_sat: claim_fees_sat,
+            total_fees_sat: reverse_pair.fees.total(payer_amount_sat)
+                - reverse_pair.fees.claim_estimate()
+                + claim_fees_sat,
+        }
+    }
+
     /// Estimate the onchain fee for sending the given amount to the given destination address
-    async fn estimate_onchain_tx_fee(&self, amount_sat: u64, address: &str) -> Result<u64> {
+    async fn estimate_onchain_tx_fee(
+        &self,
+        amount_sat: u64,
+        address: &str,
+        fee_rate_sat_per_vbyte: Option<f64>,
+    ) -> Result<u64> {
         Ok(self
             .onchain_wallet
-            .build_tx(None, address, amount_sat)
+            .build_tx(fee_rate_sat_per_vbyte, address, amount_sat)
             .await?
             .all_fees()
             .values()
             .sum())
     }
 
-    async fn estimate_lockup_tx_fee(&self, amount_sat: u64) -> Result<u64> {
+    async fn estimate_lockup_tx_fee(
+        &self,
+        amount_sat: u64,
+        fee_rate_sat_per_vbyte: Option<f64>,
+    ) -> Result<u64> {
         // TODO Replace this with own address when LWK supports taproot
         //  https://github.com/Blockstream/lwk/issues/31
+        let testnet_p2tr_addr = "tlq1pq0wqu32e2xacxeyps22x8gjre4qk3u6r70pj4r62hzczxeyz8x3yxucrpn79zy28plc4x37aaf33kwt6dz2nn6gtkya6h02mwpzy4eh69zzexq7cf5y5";
         let temp_p2tr_addr = match self.config.network {
-            Network::Mainnet => "lq1pqvzxvqhrf54dd4sny4cag7497pe38252qefk46t92frs7us8r80ja9ha8r5me09nn22m4tmdqp5p4wafq3s59cql3v9n45t5trwtxrmxfsyxjnstkctj",
-            Network::Testnet => "tlq1pq0wqu32e2xacxeyps22x8gjre4qk3u6r70pj4r62hzczxeyz8x3yxucrpn79zy28plc4x37aaf33kwt6dz2nn6gtkya6h02mwpzy4eh69zzexq7cf5y5"
+            Network::Mainnet => "lq1pqvzxvqhrf54dd4sny4cag7497pe38252qefk46t92frs7us8r80ja9ha8r5me09nn22m4tmdqp5p4wafq3s59cql3v9n45t5trwtxrmxfsyxjnstkctj".to_string(),
+            Network::Testnet => testnet_p2tr_addr.to_string(),
+            // The same output as on testnet, with the Regtest address prefixes
+            Network::Regtest => {
+                let mut address = lwk_wollet::elements::Address::from_str(testnet_p2tr_addr)?;
+                address.params = &lwk_wollet::elements::AddressParams::ELEMENTS;
+                address.to_string()
+            }
         };
 
-        self.estimate_onchain_tx_fee(amount_sat, temp_p2tr_addr)
+        self.estimate_onchain_tx_fee(amount_sat, &temp_p2tr_addr, fee_rate_sat_per_vbyte)
             .await
     }
 
@@ -545,21 +1044,50 @@ impl LiquidSdk {
             .ok_or(PaymentError::AmountOutOfRange)?
             / 1000;
         let lbtc_pair = self.validate_submarine_pairs(receiver_amount_sat)?;
+        let fee_rate_sat_per_vbyte = self.resolve_liquid_fee_rate(&req.fee_rate)?;
 
-        let fees_sat = match self.swapper.check_for_mrh(&req.invoice)? {
+        let fee_breakdown = match self.swapper.check_for_mrh(&req.invoice)? {
             Some((lbtc_address, _)) => {
-                self.estimate_onchain_tx_fee(receiver_amount_sat, &lbtc_address)
-                    .await?
+                let onchain_fees_sat = self
+                    .estimate_onchain_tx_fee(
+                        receiver_amount_sat,
+                        &lbtc_address,
+                        fee_rate_sat_per_vbyte,
+                    )
+                    .await?;
+                FeeBreakdown {
+                    service_fee_percentage: 0.0,
+                    service_fee_sat: 0,
+                    swapper_miner_fees_sat: 0,
+                    network_fee_sat: onchain_fees_sat,
+                    total_fees_sat: onchain_fees_sat,
+                }
             }
             None => {
-                let lockup_fees_sat = self.estimate_lockup_tx_fee(receiver_amount_sat).await?;
-                lbtc_pair.fees.total(receiver_amount_sat) + lockup_fees_sat
+                let lockup_fees_sat = self
+                    .estimate_lockup_tx_fee(receiver_amount_sat, fee_rate_sat_per_vbyte)
+                    .await?;
+                Self::submarine_fee_breakdown(&lbtc_pair, receiver_amount_sat, lockup_fees_sat)
             }
         };
 
+        self.ensure_fees_within_limits(
+            fee_breakdown.total_fees_sat,
+            receiver_amount_sat,
+            req.max_fee_sat,
+            req.max_fee_percent,
+        )?;
+
         Ok(PrepareSendResponse {
             invoice: req.invoice.clone(),
-            fees_sat,
+            fees_sat: fee_breakdown.total_fees_sat,
+            metadata: req.metadata.clone(),
+            fee_breakdown,
+            max_fee_sat: req.max_fee_sat,
+            max_fee_percent: req.max_fee_percent,
+            pair_hash: lbtc_pair.hash,
+            expires_at: utils::now() + FEE_QUOTE_VALIDITY_SECS,
+            fee_rate_sat_per_vbyte,
         })
     }
 
@@ -567,18 +1095,18 @@ impl LiquidSdk {
         &self,
         swap: &SendSwap,
         broadcast_fees_sat: Amount,
+        output_address: &str,
     ) -> Result<String, PaymentError> {
         info!(
             "Initiating non-cooperative refund for Send Swap {}",
             &swap.id
         );
 
-        let current_height = self.onchain_wallet.tip().await.height();
-        let output_address = self.onchain_wallet.next_unused_address().await?.to_string();
+        let current_height = self.chain_service.tip()?;
         let refund_tx_id = self.swapper.refund_send_swap_non_cooperative(
             swap,
             broadcast_fees_sat,
-            &output_address,
+            output_address,
             current_height,
         )?;
 
@@ -589,21 +1117,32 @@ impl LiquidSdk {
         Ok(refund_tx_id)
     }
 
-    async fn try_refund(&self, swap: &SendSwap) -> Result<String, PaymentError> {
+    /// Refunds a Send Swap to this wallet, with the fees of a regular onchain tx
+    async fn try_refund_to_wallet(&self, swap: &SendSwap) -> Result<String, PaymentError> {
         let amount_sat = get_invoice_amount!(swap.invoice);
         let output_address = self.onchain_wallet.next_unused_address().await?.to_string();
         let refund_tx_fees_sat = Amount::from_sat(
-            self.estimate_onchain_tx_fee(amount_sat, &output_address)
+            self.estimate_onchain_tx_fee(amount_sat, &output_address, swap.fee_rate_sat_per_vbyte)
                 .await?,
         );
+        self.try_refund(swap, &output_address, refund_tx_fees_sat)
+            .await
+    }
+
+    async fn try_refund(
+        &self,
+        swap: &SendSwap,
+        output_address: &str,
+        broadcast_fees_sat: Amount,
+    ) -> Result<String, PaymentError> {
         let refund_res =
             self.swapper
-                .refund_send_swap_cooperative(swap, &output_address, refund_tx_fees_sat);
+                .refund_send_swap_cooperative(swap, output_address, broadcast_fees_sat);
         match refund_res {
             Ok(res) => Ok(res),
             Err(e) => {
                 warn!("Cooperative refund failed: {:?}", e);
-                self.try_refund_non_cooperative(swap, refund_tx_fees_sat)
+                self.try_refund_non_cooperative(swap, broadcast_fees_sat, output_address)
                     .await
             }
         }
@@ -631,6 +1170,12 @@ impl LiquidSdk {
 
         self.ensure_send_is_not_self_transfer(&req.invoice)?;
         self.validate_invoice(&req.invoice)?;
+        self.ensure_fees_within_limits(
+            req.fees_sat,
+            get_invoice_amount!(req.invoice),
+            req.max_fee_sat,
+            req.max_fee_percent,
+        )?;
 
         match self.swapper.check_for_mrh(&req.invoice)? {
             // If we find a valid MRH, extract the BIP21 amount and address, then pay via onchain tx
@@ -656,15 +1201,54 @@ impl LiquidSdk {
         let receiver_amount_sat = get_invoice_amount!(req.invoice);
         let tx = self
             .onchain_wallet
-            .build_tx(None, lbtc_address, receiver_amount_sat)
+            .build_tx(
+                req.fee_rate_sat_per_vbyte,
+                lbtc_address,
+                receiver_amount_sat,
+            )
             .await?;
         let onchain_fees_sat: u64 = tx.all_fees().values().sum();
+        // The tx fees may have changed since the payment was prepared
+        self.ensure_fees_within_limits(
+            onchain_fees_sat,
+            receiver_amount_sat,
+            req.max_fee_sat,
+            req.max_fee_percent,
+        )?;
         let payer_amount_sat = receiver_amount_sat + onchain_fees_sat;
         info!("Built onchain L-BTC tx with receiver_amount_sat = {receiver_amount_sat}, fees_sat = {onchain_fees_sat}");
         info!("Built onchain L-BTC tx with ID {}", tx.txid());
 
+        if let Some(metadata) = &req.metadata {
+            self.persister
+                .insert_or_update_payment_metadata(&tx.txid().to_string(), metadata)?;
+        }
+        let payment = self
+            .broadcast_onchain_send_tx(
+                &tx,
+                payer_amount_sat,
+                onchain_fees_sat,
+                None,
+                req.fee_rate_sat_per_vbyte,
+            )
+            .await?;
+        Ok(SendPaymentResponse { payment })
+    }
+
+    /// Broadcasts a direct onchain send tx, stores it as a payment and emits the Pending event.
+    ///
+    /// For issued asset sends, `asset` is the asset ID and amount, and `payer_amount_sat` only
+    /// covers the L-BTC fees.
+    async fn broadcast_onchain_send_tx(
+        &self,
+        tx: &Transaction,
+        payer_amount_sat: u64,
+        fees_sat: u64,
+        asset: Option<(String, u64)>,
+        fee_rate_sat_per_vbyte: Option<f64>,
+    ) -> Result<Payment, PaymentError> {
         let tx_id = tx.txid().to_string();
-        let tx_hex = lwk_wollet::elements::encode::serialize(&tx).to_lower_hex_string();
+        let tx_hex = lwk_wollet::elements::encode::serialize(tx).to_lower_hex_string();
         self.swapper
             .broadcast_tx(self.config.network.into(), &tx_hex)?;
 
@@ -674,16 +1258,17 @@ impl LiquidSdk {
             tx_id: tx_id.clone(),
             timestamp: None,
             amount_sat: payer_amount_sat,
-            fees_sat: onchain_fees_sat,
+            fees_sat,
             payment_type: PaymentType::Send,
             is_confirmed: false,
+            asset_id: asset.as_ref().map(|(asset_id, _)| asset_id.clone()),
+            asset_amount: asset.map(|(_, asset_amount)| asset_amount),
+            fee_rate_sat_per_vbyte,
         };
         self.persister.insert_or_update_payment(tx_data.clone())?;
         self.emit_payment_updated(Some(tx_id)).await?; // Emit Pending event
 
-        Ok(SendPaymentResponse {
-            payment: Payment::from_tx_data(tx_data, None),
-        })
+        Ok(Payment::from_tx_data(tx_data, None))
     }
 
     /// Performs a Send Payment by doing a swap (create it, fund it, track it, etc).
@@ -693,11 +1278,31 @@ impl LiquidSdk {
     ) -> Result<SendPaymentResponse, PaymentError> {
         let receiver_amount_sat = get_invoice_amount!(req.invoice);
         let lbtc_pair = self.validate_submarine_pairs(receiver_amount_sat)?;
-        let lockup_tx_fees_sat = self.estimate_lockup_tx_fee(receiver_amount_sat).await?;
-        ensure_sdk!(
-            req.fees_sat == lbtc_pair.fees.total(receiver_amount_sat) + lockup_tx_fees_sat,
-            PaymentError::InvalidOrExpiredFees
-        );
+        Self::ensure_fee_quote_is_valid(
+            req.fees_sat,
+            &req.fee_breakdown,
+            &req.pair_hash,
+            req.expires_at,
+            &lbtc_pair.hash,
+            &Self::submarine_fee_breakdown(
+                &lbtc_pair,
+                receiver_amount_sat,
+                req.fee_breakdown.network_fee_sat,
+            ),
+        )?;
+
+        // The swapper fees are locked by the pair hash, only the lockup tx fee is re-estimated
+        let lockup_tx_fees_sat = self
+            .estimate_lockup_tx_fee(receiver_amount_sat, req.fee_rate_sat_per_vbyte)
+            .await?;
+        let fee_breakdown =
+            Self::submarine_fee_breakdown(&lbtc_pair, receiver_amount_sat, lockup_tx_fees_sat);
+        self.ensure_fees_within_limits(
+            fee_breakdown.total_fees_sat,
+            receiver_amount_sat,
+            req.max_fee_sat,
+            req.max_fee_percent,
+        )?;
 
         let swap = match self.persister.fetch_send_swap_by_invoice(&req.invoice)? {
             Some(swap) => match swap.state {
@@ -712,7 +1317,8 @@ impl LiquidSdk {
                 _ => swap,
             },
             None => {
-                let keypair = utils::generate_keypair();
+                let key_index = self.persister.next_swap_key_index()?;
+                let keypair = self.onchain_wallet.derive_swap_refund_keypair(key_index)?;
                 let refund_public_key = boltz_client::PublicKey {
                     compressed: true,
                     inner: keypair.public_key(),
@@ -722,7 +1328,7 @@ impl LiquidSdk {
                     to: "BTC".to_string(),
                     invoice: req.invoice.to_string(),
                     refund_public_key,
-                    pair_hash: Some(lbtc_pair.hash),
+                    pair_hash: Some(req.pair_hash.clone()),
                     referral_id: None,
                 })?;
 
@@ -730,7 +1336,7 @@ impl LiquidSdk {
                 let create_response_json =
                     SendSwap::from_boltz_struct_to_json(&create_response, swap_id)?;
 
-                let payer_amount_sat = req.fees_sat + receiver_amount_sat;
+                let payer_amount_sat = fee_breakdown.total_fees_sat + receiver_amount_sat;
                 let swap = SendSwap {
                     id: swap_id.clone(),
                     invoice: req.invoice.clone(),
@@ -743,11 +1349,18 @@ impl LiquidSdk {
                     created_at: utils::now(),
                     state: PaymentState::Created,
                     refund_private_key: keypair.display_secret().to_string(),
+                    key_index: Some(key_index),
+                    fee_breakdown: Some(fee_breakdown),
+                    fee_rate_sat_per_vbyte: req.fee_rate_sat_per_vbyte,
                 };
                 self.persister.insert_send_swap(&swap)?;
                 swap
             }
         };
+        if let Some(metadata) = &req.metadata {
+            self.persister
+                .insert_or_update_payment_metadata(&swap.id, metadata)?;
+        }
         self.status_stream.track_swap_id(&swap.id)?;
 
         let accept_zero_conf = swap.get_boltz_create_response()?.accept_zero_conf;
@@ -807,55 +1420,506 @@ impl LiquidSdk {
         }
     }
 
-    pub async fn prepare_receive_payment(
+    /// Builds the tx of a direct onchain send. L-BTC is sent either for a fixed amount or by
+    /// draining the wallet, issued assets only for a fixed amount.
+    async fn build_send_onchain_tx(
         &self,
-        req: &PrepareReceiveRequest,
-    ) -> Result<PrepareReceiveResponse, PaymentError> {
-        self.ensure_is_started().await?;
-        let reverse_pair = self
-            .swapper
-            .get_reverse_swap_pairs()?
-            .ok_or(PaymentError::PairsNotFound)?;
-
-        let payer_amount_sat = req.payer_amount_sat;
-        let fees_sat = reverse_pair.fees.total(req.payer_amount_sat);
+        address: &str,
+        receiver_amount_sat: u64,
+        send_all: bool,
+        asset_id: Option<&str>,
+        fee_rate_sat_per_vbyte: Option<f64>,
+    ) -> Result<Transaction, PaymentError> {
+        match (asset_id, send_all) {
+            (Some(asset_id), _) => {
+                self.onchain_wallet
+                    .build_asset_tx(
+                        fee_rate_sat_per_vbyte,
+                        address,
+                        receiver_amount_sat,
+                        asset_id,
+                    )
+                    .await
+            }
+            (None, true) => {
+                self.onchain_wallet
+                    .build_drain_tx(fee_rate_sat_per_vbyte, address)
+                    .await
+            }
+            (None, false) => {
+                self.onchain_wallet
+                    .build_tx(fee_rate_sat_per_vbyte, address, receiver_amount_sat)
+                    .await
+            }
+        }
+    }
 
-        ensure_sdk!(payer_amount_sat > fees_sat, PaymentError::AmountOutOfRange);
+    /// Prepares a direct onchain send to a Liquid address or a BIP21 URI.
+    ///
+    /// The amount is taken from [PrepareSendOnchainRequest::amount_sat] if set, otherwise from
+    /// the BIP21 URI. If [PrepareSendOnchainRequest::send_all] is set, the whole wallet balance
+    /// is sent and the fees are deducted from it.
+    ///
+    /// An issued asset is sent instead of L-BTC if [PrepareSendOnchainRequest::asset_id] is set,
+    /// or if the BIP21 URI has an `assetid`. The fees are always paid in L-BTC.
+    pub async fn prepare_send_onchain(
+        &self,
+        req: &PrepareSendOnchainRequest,
+    ) -> Result<PrepareSendOnchainResponse, PaymentError> {
+        self.ensure_is_started().await?;
 
-        reverse_pair
-            .limits
-            .within(payer_amount_sat)
-            .map_err(|_| PaymentError::AmountOutOfRange)?;
+        let address_data = LiquidAddressData::from_addr_or_uri(&req.destination)?;
+        ensure_sdk!(
+            address_data.network == self.config.network,
+            PaymentError::Generic {
+                err: "L-BTC address cannot be used on the current network".to_string()
+            }
+        );
+        let lbtc_asset_id = self.config.lbtc_asset_id()?.to_string();
+        let asset_id = req
+            .asset_id
+            .clone()
+            .or(address_data.asset_id)
+            .filter(|asset_id| *asset_id != lbtc_asset_id);
 
-        debug!("Preparing Receive Swap with: payer_amount_sat {payer_amount_sat} sat, fees_sat {fees_sat} sat");
+        ensure_sdk!(
+            asset_id.is_none() || !req.send_all,
+            PaymentError::Generic {
+                err: "Sending the whole balance is only supported for L-BTC".to_string()
+            }
+        );
+        let fee_rate_sat_per_vbyte = self.resolve_liquid_fee_rate(&req.fee_rate)?;
+
+        let (receiver_amount_sat, fees_sat) =
+            match (&asset_id, req.send_all) {
+                (Some(asset_id), _) => {
+                    let receiver_amount = req.amount_sat.or(address_data.amount_sat).ok_or(
+                        PaymentError::Generic {
+                            err: "An amount is required to send an asset".to_string(),
+                        },
+                    )?;
+                    ensure_sdk!(receiver_amount > 0, PaymentError::AmountOutOfRange);
+                    let fees_sat: u64 = self
+                        .build_send_onchain_tx(
+                            &address_data.address,
+                            receiver_amount,
+                            false,
+                            Some(asset_id.as_str()),
+                            fee_rate_sat_per_vbyte,
+                        )
+                        .await?
+                        .all_fees()
+                        .values()
+                        .sum();
+                    (receiver_amount, fees_sat)
+                }
+                (None, true) => {
+                    let balance_sat = self.onchain_wallet.lbtc_balance_sat().await?;
+                    let fees_sat: u64 = self
+                        .build_send_onchain_tx(
+                            &address_data.address,
+                            balance_sat,
+                            true,
+                            None,
+                            fee_rate_sat_per_vbyte,
+                        )
+                        .await?
+                        .all_fees()
+                        .values()
+                        .sum();
+                    ensure_sdk!(balance_sat > fees_sat, PaymentError::InsufficientFunds);
+                    (balance_sat - fees_sat, fees_sat)
+                }
+                (None, false) => {
+                    let receiver_amount_sat = req.amount_sat.or(address_data.amount_sat).ok_or(
+                        PaymentError::Generic {
+                            err: "An amount is required to send to a Liquid address".to_string(),
+                        },
+                    )?;
+                    ensure_sdk!(receiver_amount_sat > 0, PaymentError::AmountOutOfRange);
+                    let fees_sat = self
+                        .estimate_onchain_tx_fee(
+                            receiver_amount_sat,
+                            &address_data.address,
+                            fee_rate_sat_per_vbyte,
+                        )
+                        .await?;
+                    (receiver_amount_sat, fees_sat)
+                }
+            };
 
-        Ok(PrepareReceiveResponse {
-            payer_amount_sat,
+        Ok(PrepareSendOnchainResponse {
+            address: address_data.address,
+            receiver_amount_sat,
             fees_sat,
+            send_all: req.send_all,
+            asset_id,
+            fee_rate_sat_per_vbyte,
         })
     }
 
-    pub async fn receive_payment(
+    /// Sends L-BTC or an issued asset directly onchain, as prepared by
+    /// [LiquidSdk::prepare_send_onchain].
+    ///
+    /// The payment is stored and reported as Pending as soon as the tx is broadcast.
+    pub async fn send_onchain(
         &self,
-        req: &PrepareReceiveResponse,
-    ) -> Result<ReceivePaymentResponse, PaymentError> {
+        req: &PrepareSendOnchainResponse,
+    ) -> Result<SendPaymentResponse, PaymentError> {
         self.ensure_is_started().await?;
+        self.validate_liquid_address(&req.address)?;
 
-        let payer_amount_sat = req.payer_amount_sat;
-        let fees_sat = req.fees_sat;
+        let tx = self
+            .build_send_onchain_tx(
+                &req.address,
+                req.receiver_amount_sat,
+                req.send_all,
+                req.asset_id.as_deref(),
+                req.fee_rate_sat_per_vbyte,
+            )
+            .await?;
+        let fees_sat: u64 = tx.all_fees().values().sum();
+        ensure_sdk!(fees_sat == req.fees_sat, PaymentError::InvalidOrExpiredFees);
 
-        let reverse_pair = self
+        info!(
+            "Built onchain tx with ID {} for receiver_amount_sat = {}, fees_sat = {fees_sat}, asset_id = {:?}",
+            tx.txid(),
+            req.receiver_amount_sat,
+            req.asset_id
+        );
+        let payment = match &req.asset_id {
+            Some(asset_id) => {
+                self.broadcast_onchain_send_tx(
+                    &tx,
+                    fees_sat,
+                    fees_sat,
+                    Some((asset_id.clone(), req.receiver_amount_sat)),
+                    req.fee_rate_sat_per_vbyte,
+                )
+                .await?
+            }
+            None => {
+                self.broadcast_onchain_send_tx(
+                    &tx,
+                    req.receiver_amount_sat + fees_sat,
+                    fees_sat,
+                    None,
+                    req.fee_rate_sat_per_vbyte,
+                )
+                .await?
+            }
+        };
+        Ok(SendPaymentResponse { payment })
+    }
+
+    /// Prepares the payment of an LNURL-pay or a Lightning Address.
+    ///
+    /// The amount has to be accepted both by the LNURL service and by the Send Swap. The invoice
+    /// of the service is checked against the requested amount and the service metadata, then
+    /// prepared with [LiquidSdk::prepare_send_payment].
+    pub async fn prepare_lnurl_pay(
+        &self,
+        req: &PrepareLnUrlPayRequest,
+    ) -> Result<PrepareLnUrlPayResponse, PaymentError> {
+        self.ensure_is_started().await?;
+
+        let url = match input_parser::parse(&req.lnurl, self.config.network)? {
+            InputType::LnUrl { url } | InputType::LightningAddress { url, .. } => url,
+            _ => {
+                return Err(PaymentError::UnrecognizedInput {
+                    err: "Not an LNURL or a Lightning Address".to_string(),
+                })
+            }
+        };
+        let pay_data: lnurl::LnUrlPayRequestData =
+            lnurl::get_json(self.rest_client.as_ref(), &url)?;
+        ensure_sdk!(
+            pay_data.tag == "payRequest",
+            lnurl::lnurl_error(format!("Not an LNURL-pay, found tag {}", pay_data.tag))
+        );
+
+        // Only amounts both the LNURL service and the Send Swap accept can be paid
+        let lbtc_pair = self
+            .swapper
+            .get_submarine_pairs()?
+            .ok_or(PaymentError::PairsNotFound)?;
+        let min_sat = pay_data
+            .min_sendable
+            .div_ceil(1000)
+            .max(lbtc_pair.limits.minimal);
+        let max_sat = (pay_data.max_sendable / 1000).min(lbtc_pair.limits.maximal);
+        ensure_sdk!(
+            (min_sat..=max_sat).contains(&req.amount_sat),
+            PaymentError::AmountOutOfRange
+        );
+
+        let amount_msat = req.amount_sat * 1000;
+        let mut callback_params = vec![("amount", amount_msat.to_string())];
+        if let Some(comment) = &req.comment {
+            ensure_sdk!(
+                comment.chars().count() <= pay_data.comment_allowed,
+                lnurl::lnurl_error(format!(
+                    "Comment is longer than the {} characters allowed",
+                    pay_data.comment_allowed
+                ))
+            );
+            callback_params.push(("comment", comment.clone()));
+        }
+        let callback_url = lnurl::build_callback_url(&pay_data.callback, &callback_params)?;
+        let callback_response: lnurl::LnUrlPayCallbackResponse =
+            lnurl::get_json(self.rest_client.as_ref(), &callback_url)?;
+
+        let invoice = Self::parse_invoice(&callback_response.pr)?;
+        ensure_sdk!(
+            invoice.amount_msat == Some(amount_msat),
+            lnurl::lnurl_error("Invoice amount does not match the requested amount")
+        );
+        let metadata_hash = sha256::Hash::hash(pay_data.metadata.as_bytes()).to_string();
+        ensure_sdk!(
+            invoice.description_hash == Some(metadata_hash),
+            lnurl::lnurl_error("Invoice description hash does not match the LNURL metadata")
+        );
+        if let Some(success_action) = &callback_response.success_action {
+            lnurl::validate_success_action(success_action, &pay_data.callback)?;
+        }
+
+        let prepare_send_response = self
+            .prepare_send_payment(&PrepareSendRequest {
+                invoice: callback_response.pr,
+                metadata: None,
+                max_fee_sat: None,
+                max_fee_percent: None,
+                fee_rate: None,
+            })
+            .await?;
+        Ok(PrepareLnUrlPayResponse {
+            prepare_send_response,
+            success_action: callback_response.success_action,
+        })
+    }
+
+    /// Pays an LNURL-pay or a Lightning Address, as prepared by [LiquidSdk::prepare_lnurl_pay].
+    ///
+    /// Once paid, the success action is processed. An AES success action is decrypted with the
+    /// payment preimage. If that is not possible, the success action is skipped.
+    pub async fn lnurl_pay(
+        &self,
+        req: &PrepareLnUrlPayResponse,
+    ) -> Result<LnUrlPayResult, PaymentError> {
+        let SendPaymentResponse { payment } = self.send_payment(&req.prepare_send_response).await?;
+
+        let success_action = match req.success_action.clone() {
+            None => None,
+            Some(SuccessAction::Message { message }) => {
+                Some(SuccessActionProcessed::Message { message })
+            }
+            Some(SuccessAction::Url { description, url }) => {
+                Some(SuccessActionProcessed::Url { description, url })
+            }
+            Some(SuccessAction::Aes {
+                description,
+                ciphertext,
+                iv,
+            }) => match &payment.preimage {
+                Some(preimage) => {
+                    match lnurl::decrypt_aes_success_action(&ciphertext, &iv, preimage) {
+                        Ok(plaintext) => Some(SuccessActionProcessed::Aes {
+                            description,
+                            plaintext,
+                        }),
+                        Err(e) => {
+                            warn!("Could not decrypt AES success action: {e}");
+                            None
+                        }
+                    }
+                }
+                None => {
+                    warn!("Could not decrypt AES success action: the payment has no preimage");
+                    None
+                }
+            },
+        };
+
+        Ok(LnUrlPayResult {
+            payment,
+            success_action,
+        })
+    }
+
+    pub async fn prepare_receive_payment(
+        &self,
+        req: &PrepareReceiveRequest,
+    ) -> Result<PrepareReceiveResponse, PaymentError> {
+        self.ensure_is_started().await?;
+        let reverse_pair = self
+            .swapper
+            .get_reverse_swap_pairs()?
+            .ok_or(PaymentError::PairsNotFound)?;
+        let fee_rate_sat_per_vbyte = self.resolve_liquid_fee_rate(&req.fee_rate)?;
+        let claim_fees_sat =
+            Self::liquid_claim_fees_sat(reverse_pair.fees.claim_estimate(), fee_rate_sat_per_vbyte);
+
+        let payer_amount_sat = match (req.payer_amount_sat, req.receiver_amount_sat) {
+            (Some(payer_amount_sat), None) => payer_amount_sat,
+            (None, Some(receiver_amount_sat)) => {
+                Self::reverse_payer_amount_sat(&reverse_pair, receiver_amount_sat, claim_fees_sat)?
+            }
+            _ => {
+                return Err(PaymentError::receive_error(
+                    "Set exactly one of the payer amount and the receiver amount",
+                ))
+            }
+        };
+        let fee_breakdown =
+            Self::reverse_fee_breakdown(&reverse_pair, payer_amount_sat, claim_fees_sat);
+        let fees_sat = fee_breakdown.total_fees_sat;
+
+        ensure_sdk!(payer_amount_sat > fees_sat, PaymentError::AmountOutOfRange);
+        let receiver_amount_sat = payer_amount_sat - fees_sat;
+        self.ensure_fees_within_limits(
+            fees_sat,
+            payer_amount_sat,
+            req.max_fee_sat,
+            req.max_fee_percent,
+        )?;
+
+        reverse_pair
+            .limits
+            .within(payer_amount_sat)
+            .map_err(|_| PaymentError::AmountOutOfRange)?;
+
+        Self::validate_invoice_description(&req.description, &req.description_hash)?;
+        ensure_sdk!(
+            req.invoice_expiry_secs != Some(0),
+            PaymentError::receive_error("Invoice expiry must be greater than zero")
+        );
+
+        debug!("Preparing Receive Swap with: payer_amount_sat {payer_amount_sat} sat, receiver_amount_sat {receiver_amount_sat} sat, fees_sat {fees_sat} sat");
+
+        Ok(PrepareReceiveResponse {
+            payer_amount_sat,
+            receiver_amount_sat,
+            fees_sat,
+            description: req.description.clone(),
+            description_hash: req
+                .description_hash
+                .as_ref()
+                .map(|hash| hash.to_lowercase()),
+            invoice_expiry_secs: req.invoice_expiry_secs,
+            metadata: req.metadata.clone(),
+            fee_breakdown,
+            pair_hash: reverse_pair.hash,
+            expires_at: utils::now() + FEE_QUOTE_VALIDITY_SECS,
+            fee_rate_sat_per_vbyte,
+        })
+    }
+
+    /// Finds the smallest invoice amount of a Receive Swap which results in `receiver_amount_sat`
+    /// after fees, by inverting the fee formula of the reverse pair.
+    ///
+    /// Since the percentage fee is rounded to the sat, the received amount grows by at most one
+    /// sat per sat of invoice amount, so every receiver amount has a matching invoice amount.
+    fn reverse_payer_amount_sat(
+        reverse_pair: &ReversePair,
+        receiver_amount_sat: u64,
+        claim_fees_sat: u64,
+    ) -> Result<u64, PaymentError> {
+        let receiver_amount_of = |payer_amount_sat: u64| {
+            let fee_breakdown =
+                Self::reverse_fee_breakdown(reverse_pair, payer_amount_sat, claim_fees_sat);
+            payer_amount_sat.saturating_sub(fee_breakdown.total_fees_sat)
+        };
+        let payer_share = 1.0 - reverse_pair.fees.percentage / 100.0;
+        ensure_sdk!(
+            receiver_amount_sat > 0 && payer_share > 0.0,
+            PaymentError::AmountOutOfRange
+        );
+
+        let miner_fees_sat = reverse_pair.fees.miner_fees.lockup + claim_fees_sat;
+        let mut payer_amount_sat =
+            ((receiver_amount_sat + miner_fees_sat) as f64 / payer_share).ceil() as u64;
+        // The estimate is only off by the rounding of the percentage fee
+        while payer_amount_sat > 0
+            && receiver_amount_of(payer_amount_sat - 1) >= receiver_amount_sat
+        {
+            payer_amount_sat -= 1;
+        }
+        while receiver_amount_of(payer_amount_sat) < receiver_amount_sat {
+            payer_amount_sat += 1;
+        }
+        ensure_sdk!(
+            receiver_amount_of(payer_amount_sat) == receiver_amount_sat,
+            PaymentError::AmountOutOfRange
+        );
+        Ok(payer_amount_sat)
+    }
+
+    /// An invoice commits either to a description or to its hash, as per BOLT11
+    fn validate_invoice_description(
+        description: &Option<String>,
+        description_hash: &Option<String>,
+    ) -> Result<(), PaymentError> {
+        match (description, description_hash) {
+            (Some(_), Some(_)) => Err(PaymentError::receive_error(
+                "Cannot set both a description and a description hash",
+            )),
+            (Some(description), None) => {
+                // A BOLT11 description field holds at most 639 bytes
+                ensure_sdk!(
+                    description.len() <= 639,
+                    PaymentError::receive_error("Description is too long")
+                );
+                Ok(())
+            }
+            (None, Some(description_hash)) => {
+                sha256::Hash::from_str(description_hash).map_err(|_| {
+                    PaymentError::receive_error("Description hash must be a hex-encoded SHA256")
+                })?;
+                Ok(())
+            }
+            (None, None) => Ok(()),
+        }
+    }
+
+    pub async fn receive_payment(
+        &self,
+        req: &PrepareReceiveResponse,
+    ) -> Result<ReceivePaymentResponse, PaymentError> {
+        self.ensure_is_started().await?;
+
+        let payer_amount_sat = req.payer_amount_sat;
+        let fees_sat = req.fees_sat;
+
+        // The claim fee is derived again from the pair and the fee rate, as the quoted one is
+        // persisted and used to build the claim tx
+        let reverse_pair = self
             .swapper
             .get_reverse_swap_pairs()?
             .ok_or(PaymentError::PairsNotFound)?;
-        let new_fees_sat = reverse_pair.fees.total(req.payer_amount_sat);
-        ensure_sdk!(fees_sat == new_fees_sat, PaymentError::InvalidOrExpiredFees);
+        let claim_fees_sat = Self::liquid_claim_fees_sat(
+            reverse_pair.fees.claim_estimate(),
+            req.fee_rate_sat_per_vbyte,
+        );
+        Self::ensure_fee_quote_is_valid(
+            fees_sat,
+            &req.fee_breakdown,
+            &req.pair_hash,
+            req.expires_at,
+            &reverse_pair.hash,
+            &Self::reverse_fee_breakdown(&reverse_pair, payer_amount_sat, claim_fees_sat),
+        )?;
+        ensure_sdk!(
+            Some(req.receiver_amount_sat) == payer_amount_sat.checked_sub(fees_sat),
+            PaymentError::InvalidOrExpiredFees
+        );
+        Self::validate_invoice_description(&req.description, &req.description_hash)?;
 
         debug!("Creating Receive Swap with: payer_amount_sat {payer_amount_sat} sat, fees_sat {fees_sat} sat");
 
-        let keypair = utils::generate_keypair();
+        let key_index = self.persister.next_swap_key_index()?;
+        let keypair = self.onchain_wallet.derive_swap_claim_keypair(key_index)?;
 
-        let preimage = Preimage::new();
+        let preimage = self.onchain_wallet.derive_swap_preimage(key_index)?;
         let preimage_str = preimage.to_string().ok_or(PaymentError::InvalidPreimage)?;
         let preimage_hash = preimage.sha256.to_string();
 
@@ -873,8 +1937,12 @@ impl LiquidSdk {
             to: "L-BTC".to_string(),
             preimage_hash: preimage.sha256,
             claim_public_key: keypair.public_key().into(),
+            description: req.description.clone(),
+            description_hash: req.description_hash.clone(),
+            invoice_expiry: req.invoice_expiry_secs,
             address: Some(mrh_addr_str.clone()),
             address_signature: Some(mrh_addr_hash_sig.to_hex()),
+            pair_hash: Some(req.pair_hash.clone()),
             referral_id: None,
         };
         let create_response = self.swapper.create_receive_swap(v2_req)?;
@@ -889,8 +1957,11 @@ impl LiquidSdk {
             bip21_lbtc_address == mrh_addr_str,
             PaymentError::receive_error("Invoice has incorrect address in MRH")
         );
-        // The swap fee savings are passed on to the Sender: MRH amount = invoice amount - fees
-        let expected_bip21_amount_sat = req.payer_amount_sat - req.fees_sat;
+        // The swap fee savings are passed on to the Sender: MRH amount = invoice amount - fees.
+        // The swapper only knows its own fees, so the claim fee is the one it estimates, not the
+        // one of a custom fee rate.
+        let expected_bip21_amount_sat =
+            payer_amount_sat - reverse_pair.fees.total(payer_amount_sat);
         ensure_sdk!(
             received_bip21_amount_sat == expected_bip21_amount_sat,
             PaymentError::receive_error(&format!(
@@ -914,11 +1985,46 @@ impl LiquidSdk {
 
         // Double check that the generated invoice includes our data
         // https://docs.boltz.exchange/v/api/dont-trust-verify#lightning-invoice-verification
+        ensure_sdk!(
+            payer_amount_sat == req.payer_amount_sat,
+            PaymentError::InvalidInvoice {
+                err: "Invalid amount returned by swapper".to_string(),
+            }
+        );
         if invoice.payment_hash().to_string() != preimage_hash {
             return Err(PaymentError::InvalidInvoice {
                 err: "Invalid preimage returned by swapper".to_string(),
             });
         };
+        // Without a requested description, the swapper is free to use its default one
+        let (invoice_description, invoice_description_hash) = match invoice.description() {
+            Bolt11InvoiceDescription::Direct(description) => (Some(description.to_string()), None),
+            Bolt11InvoiceDescription::Hash(hash) => (None, Some(hash.0.to_string())),
+        };
+        if req.description.is_some() {
+            ensure_sdk!(
+                invoice_description == req.description,
+                PaymentError::InvalidInvoice {
+                    err: "Invalid description returned by swapper".to_string(),
+                }
+            );
+        }
+        if req.description_hash.is_some() {
+            ensure_sdk!(
+                invoice_description_hash == req.description_hash,
+                PaymentError::InvalidInvoice {
+                    err: "Invalid description hash returned by swapper".to_string(),
+                }
+            );
+        }
+        if let Some(invoice_expiry_secs) = req.invoice_expiry_secs {
+            ensure_sdk!(
+                invoice.expiry_time().as_secs() == invoice_expiry_secs,
+                PaymentError::InvalidInvoice {
+                    err: "Invalid expiry returned by swapper".to_string(),
+                }
+            );
+        }
 
         let create_response_json = ReceiveSwap::from_boltz_struct_to_json(
             &create_response,
@@ -934,12 +2040,21 @@ impl LiquidSdk {
                 invoice: invoice.to_string(),
                 payer_amount_sat,
                 receiver_amount_sat: payer_amount_sat - req.fees_sat,
-                claim_fees_sat: reverse_pair.fees.claim_estimate(),
+                claim_fees_sat,
                 claim_tx_id: None,
                 created_at: utils::now(),
                 state: PaymentState::Created,
+                key_index: Some(key_index),
+                description: req.description.clone(),
+                fee_breakdown: Some(req.fee_breakdown.clone()),
+                fee_rate_sat_per_vbyte: req.fee_rate_sat_per_vbyte,
             })
             .map_err(|_| PaymentError::PersistError)?;
+        if let Some(metadata) = &req.metadata {
+            self.persister
+                .insert_or_update_payment_metadata(&swap_id, metadata)
+                .map_err(|_| PaymentError::PersistError)?;
+        }
         self.status_stream.track_swap_id(&swap_id)?;
 
         Ok(ReceivePaymentResponse {
@@ -948,11 +2063,649 @@ impl LiquidSdk {
         })
     }
 
+    /// Withdraws funds from an LNURL-withdraw service, like a voucher or an exchange account.
+    ///
+    /// A Receive Swap invoice for `amount_sat` is created with [LiquidSdk::receive_payment] and
+    /// submitted to the service, which pays it asynchronously. The resulting payment can be
+    /// followed with the returned swap id.
+    pub async fn lnurl_withdraw(
+        &self,
+        req: &LnUrlWithdrawRequest,
+    ) -> Result<LnUrlWithdrawResult, PaymentError> {
+        self.ensure_is_started().await?;
+
+        let url = match input_parser::parse(&req.lnurl, self.config.network)? {
+            InputType::LnUrl { url } => url,
+            _ => {
+                return Err(PaymentError::UnrecognizedInput {
+                    err: "Not an LNURL".to_string(),
+                })
+            }
+        };
+        let withdraw_data: lnurl::LnUrlWithdrawRequestData =
+            lnurl::get_json(self.rest_client.as_ref(), &url)?;
+        ensure_sdk!(
+            withdraw_data.tag == "withdrawRequest",
+            lnurl::lnurl_error(format!(
+                "Not an LNURL-withdraw, found tag {}",
+                withdraw_data.tag
+            ))
+        );
+
+        let min_sat = withdraw_data.min_withdrawable.div_ceil(1000);
+        let max_sat = withdraw_data.max_withdrawable / 1000;
+        ensure_sdk!(
+            (min_sat..=max_sat).contains(&req.amount_sat),
+            PaymentError::AmountOutOfRange
+        );
+
+        // Also checks the amount against the Receive Swap limits
+        let prepare_response = self
+            .prepare_receive_payment(&PrepareReceiveRequest {
+                payer_amount_sat: Some(req.amount_sat),
+                receiver_amount_sat: None,
+                description: Some(withdraw_data.default_description.clone())
+                    .filter(|description| !description.is_empty()),
+                description_hash: None,
+                invoice_expiry_secs: None,
+                metadata: None,
+                max_fee_sat: None,
+                max_fee_percent: None,
+                fee_rate: None,
+            })
+            .await?;
+        let receive_payment_response = self.receive_payment(&prepare_response).await?;
+
+        let callback_url = lnurl::build_callback_url(
+            &withdraw_data.callback,
+            &[
+                ("k1", withdraw_data.k1),
+                ("pr", receive_payment_response.invoice.clone()),
+            ],
+        )?;
+        let callback_response: lnurl::LnUrlStatusResponse =
+            lnurl::get_json(self.rest_client.as_ref(), &callback_url)?;
+        ensure_sdk!(
+            callback_response.status.eq_ignore_ascii_case("OK"),
+            PaymentError::LnUrlServiceError {
+                err: format!("Unexpected status {}", callback_response.status)
+            }
+        );
+
+        Ok(LnUrlWithdrawResult {
+            receive_payment_response,
+        })
+    }
+
+    fn get_chain_pair(&self, direction: Direction) -> Result<ChainPair, PaymentError> {
+        self.swapper
+            .get_chain_pairs(direction)?
+            .ok_or(PaymentError::PairsNotFound)
+    }
+
+    fn validate_user_lockup_amount_for_chain_pair(
+        pair: &ChainPair,
+        user_lockup_amount_sat: u64,
+    ) -> Result<(), PaymentError> {
+        ensure_sdk!(
+            user_lockup_amount_sat >= pair.limits.minimal,
+            PaymentError::AmountOutOfRange
+        );
+        ensure_sdk!(
+            user_lockup_amount_sat <= pair.limits.maximal,
+            PaymentError::AmountOutOfRange
+        );
+        Ok(())
+    }
+
+    fn validate_bitcoin_address(&self, address: &str) -> Result<(), PaymentError> {
+        lwk_wollet::bitcoin::Address::from_str(address)
+            .map_err(|err| PaymentError::Generic {
+                err: format!("Invalid BTC address: {err}"),
+            })?
+            .require_network(self.config.network.into())
+            .map_err(|_| PaymentError::Generic {
+                err: "BTC address cannot be used on the current network".to_string(),
+            })?;
+        Ok(())
+    }
+
+    /// The L-BTC amount to lock up for the swapper to send `receiver_amount_sat` BTC
+    fn get_outgoing_chain_swap_user_lockup_amount_sat(
+        pair: &ChainPair,
+        receiver_amount_sat: u64,
+    ) -> u64 {
+        let server_lockup_amount_sat = receiver_amount_sat + pair.fees.claim_estimate();
+        let user_lockup_amount_sat_without_service_fee =
+            server_lockup_amount_sat + pair.fees.server();
+        // The service fee is a percentage of the user lockup amount, rounded up
+        (user_lockup_amount_sat_without_service_fee as f64 * 100.0 / (100.0 - pair.fees.percentage))
+            .ceil() as u64
+    }
+
+    async fn get_outgoing_chain_swap_fees_sat(
+        &self,
+        pair: &ChainPair,
+        receiver_amount_sat: u64,
+        lockup_fee_rate_sat_per_vbyte: Option<f64>,
+    ) -> Result<u64, PaymentError> {
+        let user_lockup_amount_sat =
+            Self::get_outgoing_chain_swap_user_lockup_amount_sat(pair, receiver_amount_sat);
+        Self::validate_user_lockup_amount_for_chain_pair(pair, user_lockup_amount_sat)?;
+
+        let lockup_fees_sat = self
+            .estimate_lockup_tx_fee(user_lockup_amount_sat, lockup_fee_rate_sat_per_vbyte)
+            .await?;
+        Ok(user_lockup_amount_sat - receiver_amount_sat + lockup_fees_sat)
+    }
+
+    fn get_incoming_chain_swap_fees_sat(
+        pair: &ChainPair,
+        payer_amount_sat: u64,
+        claim_fees_sat: u64,
+    ) -> u64 {
+        pair.fees.boltz(payer_amount_sat) + pair.fees.server() + claim_fees_sat
+    }
+
+    /// Prepares to pay to a BTC address, by estimating the fees of a chain swap from L-BTC to BTC
+    pub async fn prepare_pay_onchain(
+        &self,
+        req: &PreparePayOnchainRequest,
+    ) -> Result<PreparePayOnchainResponse, PaymentError> {
+        self.ensure_is_started().await?;
+
+        let receiver_amount_sat = req.receiver_amount_sat;
+        let pair = self.get_chain_pair(Direction::Outgoing)?;
+        let fee_rate_sat_per_vbyte = self.resolve_liquid_fee_rate(&req.fee_rate)?;
+        let fees_sat = self
+            .get_outgoing_chain_swap_fees_sat(&pair, receiver_amount_sat, fee_rate_sat_per_vbyte)
+            .await?;
+
+        debug!("Preparing Chain Swap with: receiver_amount_sat {receiver_amount_sat} sat, fees_sat {fees_sat} sat");
+
+        Ok(PreparePayOnchainResponse {
+            receiver_amount_sat,
+            fees_sat,
+            fee_rate_sat_per_vbyte,
+        })
+    }
+
+    /// Pays to a BTC address, by creating and funding a chain swap from L-BTC to BTC.
+    ///
+    /// The swap is claimed to the BTC address once the swapper lockup is confirmed. If the swap
+    /// fails, the L-BTC lockup is refunded to this wallet.
+    pub async fn pay_onchain(
+        &self,
+        req: &PayOnchainRequest,
+    ) -> Result<SendPaymentResponse, PaymentError> {
+        self.ensure_is_started().await?;
+        self.validate_bitcoin_address(&req.address)?;
+
+        let receiver_amount_sat = req.prepare_res.receiver_amount_sat;
+        let fee_rate_sat_per_vbyte = req.prepare_res.fee_rate_sat_per_vbyte;
+        let pair = self.get_chain_pair(Direction::Outgoing)?;
+        let fees_sat = self
+            .get_outgoing_chain_swap_fees_sat(&pair, receiver_amount_sat, fee_rate_sat_per_vbyte)
+            .await?;
+        ensure_sdk!(
+            req.prepare_res.fees_sat == fees_sat,
+            PaymentError::InvalidOrExpiredFees
+        );
+        let user_lockup_amount_sat =
+            Self::get_outgoing_chain_swap_user_lockup_amount_sat(&pair, receiver_amount_sat);
+
+        let key_index = self.persister.next_swap_key_index()?;
+        let preimage = self.onchain_wallet.derive_swap_preimage(key_index)?;
+        let preimage_str = preimage.to_string().ok_or(PaymentError::InvalidPreimage)?;
+        let claim_keypair = self.onchain_wallet.derive_swap_claim_keypair(key_index)?;
+        let refund_keypair = self.onchain_wallet.derive_swap_refund_keypair(key_index)?;
+
+        let create_response = self.swapper.create_chain_swap(CreateChainRequest {
+            from: "L-BTC".to_string(),
+            to: "BTC".to_string(),
+            preimage_hash: preimage.sha256,
+            claim_public_key: Some(claim_keypair.public_key().into()),
+            refund_public_key: Some(refund_keypair.public_key().into()),
+            user_lock_amount: Some(user_lockup_amount_sat as u32),
+            server_lock_amount: None,
+            pair_hash: Some(pair.hash.clone()),
+            referral_id: None,
+        })?;
+        ensure_sdk!(
+            create_response.lockup_details.amount as u64 == user_lockup_amount_sat,
+            PaymentError::Generic {
+                err: "Swapper returned an unexpected lockup amount".to_string()
+            }
+        );
+
+        let swap_id = create_response.id.clone();
+        let lockup_address = create_response.lockup_details.lockup_address.clone();
+        let create_response_json =
+            ChainSwap::from_boltz_struct_to_json(&create_response, &swap_id)?;
+        let swap = ChainSwap {
+            id: swap_id.clone(),
+            direction: Direction::Outgoing,
+            claim_address: Some(req.address.clone()),
+            lockup_address: lockup_address.clone(),
+            timeout_block_height: create_response.lockup_details.timeout_block_height,
+            preimage: preimage_str,
+            payer_amount_sat: receiver_amount_sat + fees_sat,
+            receiver_amount_sat,
+            claim_fees_sat: pair.fees.claim_estimate(),
+            accept_zero_conf: user_lockup_amount_sat <= pair.limits.maximal_zero_conf,
+            create_response_json,
+            server_lockup_tx_id: None,
+            user_lockup_tx_id: None,
+            claim_tx_id: None,
+            refund_tx_id: None,
+            created_at: utils::now(),
+            state: PaymentState::Created,
+            claim_private_key: claim_keypair.display_secret().to_string(),
+            refund_private_key: refund_keypair.display_secret().to_string(),
+            key_index: Some(key_index),
+            fee_rate_sat_per_vbyte,
+        };
+        self.persister.insert_chain_swap(&swap)?;
+        self.status_stream.track_swap_id(&swap_id)?;
+
+        let lockup_tx = self
+            .onchain_wallet
+            .build_tx(
+                fee_rate_sat_per_vbyte,
+                &lockup_address,
+                user_lockup_amount_sat,
+            )
+            .await?;
+        let lockup_tx_id = lockup_tx.txid().to_string();
+        let lockup_tx_hex =
+            lwk_wollet::elements::encode::serialize(&lockup_tx).to_lower_hex_string();
+        self.swapper
+            .broadcast_tx(self.config.network.into(), &lockup_tx_hex)?;
+        info!("Broadcast lockup tx for Chain Swap {swap_id}. Tx id: {lockup_tx_id}");
+
+        // We insert a pseudo-lockup-tx in case LWK fails to pick up the new mempool tx for a while
+        // This makes the tx known to the SDK (get_info, list_payments) instantly
+        self.persister.insert_or_update_payment(PaymentTxData {
+            tx_id: lockup_tx_id.clone(),
+            timestamp: None,
+            amount_sat: swap.payer_amount_sat,
+            fees_sat: lockup_tx.all_fees().values().sum(),
+            payment_type: PaymentType::Send,
+            is_confirmed: false,
+            asset_id: None,
+            asset_amount: None,
+            fee_rate_sat_per_vbyte,
+        })?;
+        self.chain_swap_state_handler
+            .update_swap_info(&swap_id, Pending, None, Some(&lockup_tx_id), None, None)
+            .await?;
+
+        let payment = self
+            .persister
+            .get_payment(lockup_tx_id)?
+            .ok_or(PaymentError::PersistError)?;
+        Ok(SendPaymentResponse { payment })
+    }
+
+    /// Prepares to receive from a BTC address, by estimating the fees of a chain swap from BTC
+    /// to L-BTC
+    pub async fn prepare_receive_onchain(
+        &self,
+        req: &PrepareReceiveOnchainRequest,
+    ) -> Result<PrepareReceiveOnchainResponse, PaymentError> {
+        self.ensure_is_started().await?;
+
+        let payer_amount_sat = req.payer_amount_sat;
+        let pair = self.get_chain_pair(Direction::Incoming)?;
+        Self::validate_user_lockup_amount_for_chain_pair(&pair, payer_amount_sat)?;
+
+        let fee_rate_sat_per_vbyte = self.resolve_liquid_fee_rate(&req.fee_rate)?;
+        let claim_fees_sat =
+            Self::liquid_claim_fees_sat(pair.fees.claim_estimate(), fee_rate_sat_per_vbyte);
+        let fees_sat =
+            Self::get_incoming_chain_swap_fees_sat(&pair, payer_amount_sat, claim_fees_sat);
+        ensure_sdk!(payer_amount_sat > fees_sat, PaymentError::AmountOutOfRange);
+
+        debug!("Preparing Chain Swap with: payer_amount_sat {payer_amount_sat} sat, fees_sat {fees_sat} sat");
+
+        Ok(PrepareReceiveOnchainResponse {
+            payer_amount_sat,
+            fees_sat,
+            fee_rate_sat_per_vbyte,
+        })
+    }
+
+    /// Creates a chain swap from BTC to L-BTC, returning the BTC address the payer has to send
+    /// funds to.
+    ///
+    /// If the swap fails after the payer locked up funds, it becomes [PaymentState::Refundable].
+    /// See [LiquidSdk::list_refundables] and [LiquidSdk::refund].
+    pub async fn receive_onchain(
+        &self,
+        req: &PrepareReceiveOnchainResponse,
+    ) -> Result<ReceiveOnchainResponse, PaymentError> {
+        self.ensure_is_started().await?;
+
+        let payer_amount_sat = req.payer_amount_sat;
+        let pair = self.get_chain_pair(Direction::Incoming)?;
+        let claim_fees_sat =
+            Self::liquid_claim_fees_sat(pair.fees.claim_estimate(), req.fee_rate_sat_per_vbyte);
+        let fees_sat =
+            Self::get_incoming_chain_swap_fees_sat(&pair, payer_amount_sat, claim_fees_sat);
+        ensure_sdk!(req.fees_sat == fees_sat, PaymentError::InvalidOrExpiredFees);
+
+        debug!("Creating Chain Swap with: payer_amount_sat {payer_amount_sat} sat, fees_sat {fees_sat} sat");
+
+        let key_index = self.persister.next_swap_key_index()?;
+        let preimage = self.onchain_wallet.derive_swap_preimage(key_index)?;
+        let preimage_str = preimage.to_string().ok_or(PaymentError::InvalidPreimage)?;
+        let claim_keypair = self.onchain_wallet.derive_swap_claim_keypair(key_index)?;
+        let refund_keypair = self.onchain_wallet.derive_swap_refund_keypair(key_index)?;
+
+        let create_response = self.swapper.create_chain_swap(CreateChainRequest {
+            from: "BTC".to_string(),
+            to: "L-BTC".to_string(),
+            preimage_hash: preimage.sha256,
+            claim_public_key: Some(claim_keypair.public_key().into()),
+            refund_public_key: Some(refund_keypair.public_key().into()),
+            user_lock_amount: Some(payer_amount_sat as u32),
+            server_lock_amount: None,
+            pair_hash: Some(pair.hash.clone()),
+            referral_id: None,
+        })?;
+        ensure_sdk!(
+            create_response.lockup_details.amount as u64 == payer_amount_sat,
+            PaymentError::receive_error("Swapper returned an unexpected lockup amount")
+        );
+
+        let swap_id = create_response.id.clone();
+        let address = create_response.lockup_details.lockup_address.clone();
+        let bip21 = create_response
+            .lockup_details
+            .bip21
+            .clone()
+            .unwrap_or(format!(
+                "bitcoin:{address}?amount={}",
+                payer_amount_sat as f64 / 100_000_000.0
+            ));
+        let create_response_json =
+            ChainSwap::from_boltz_struct_to_json(&create_response, &swap_id)?;
+        self.persister
+            .insert_chain_swap(&ChainSwap {
+                id: swap_id.clone(),
+                direction: Direction::Incoming,
+                claim_address: None,
+                lockup_address: address.clone(),
+                timeout_block_height: create_response.lockup_details.timeout_block_height,
+                preimage: preimage_str,
+                payer_amount_sat,
+                receiver_amount_sat: payer_amount_sat - fees_sat,
+                claim_fees_sat,
+                accept_zero_conf: payer_amount_sat <= pair.limits.maximal_zero_conf,
+                create_response_json,
+                server_lockup_tx_id: None,
+                user_lockup_tx_id: None,
+                claim_tx_id: None,
+                refund_tx_id: None,
+                created_at: utils::now(),
+                state: PaymentState::Created,
+                claim_private_key: claim_keypair.display_secret().to_string(),
+                refund_private_key: refund_keypair.display_secret().to_string(),
+                key_index: Some(key_index),
+                fee_rate_sat_per_vbyte: req.fee_rate_sat_per_vbyte,
+            })
+            .map_err(|_| PaymentError::PersistError)?;
+        self.status_stream.track_swap_id(&swap_id)?;
+
+        Ok(ReceiveOnchainResponse {
+            id: swap_id,
+            address,
+            bip21,
+        })
+    }
+
+    /// Receives L-BTC directly to a wallet address, without a swap.
+    ///
+    /// This is a separate method from [LiquidSdk::receive_onchain], which receives BTC onchain
+    /// through a chain swap. The returned address is tracked, so that the payments it receives
+    /// show up in [LiquidSdk::list_payments] with the request label.
+    pub async fn receive_liquid(
+        &self,
+        req: &ReceiveLiquidRequest,
+    ) -> Result<ReceiveLiquidResponse, PaymentError> {
+        self.ensure_is_started().await?;
+
+        if let Some(amount_sat) = req.amount_sat {
+            ensure_sdk!(amount_sat > 0, PaymentError::AmountOutOfRange);
+        }
+
+        let address = self.onchain_wallet.next_unused_address().await?;
+        self.persister
+            .insert_or_update_liquid_receive_request(&LiquidReceiveRequest {
+                address: address.to_string(),
+                script_pubkey: address.script_pubkey().as_bytes().to_lower_hex_string(),
+                amount_sat: req.amount_sat,
+                label: req.label.clone(),
+                created_at: utils::now(),
+                tx_id: None,
+            })?;
+
+        let bip21 = LiquidAddressData {
+            address: address.to_string(),
+            network: self.config.network,
+            amount_sat: req.amount_sat,
+            asset_id: None,
+            label: req.label.clone(),
+            message: None,
+        }
+        .to_uri();
+
+        Ok(ReceiveLiquidResponse {
+            address: address.to_string(),
+            bip21,
+        })
+    }
+
+    fn validate_liquid_address(&self, address: &str) -> Result<(), PaymentError> {
+        let address = lwk_wollet::elements::Address::from_str(address).map_err(|err| {
+            PaymentError::Generic {
+                err: format!("Invalid L-BTC address: {err}"),
+            }
+        })?;
+        ensure_sdk!(
+            address.params == self.config.elements_network()?.address_params(),
+            PaymentError::Generic {
+                err: "L-BTC address cannot be used on the current network".to_string()
+            }
+        );
+        Ok(())
+    }
+
+    /// Fetches a swap that can be refunded via [LiquidSdk::refund]: either a [Refundable] Incoming
+    /// Chain Swap, or a Send Swap with a lockup tx and no refund tx
+    fn get_refundable_swap(&self, swap_id: &str) -> Result<Swap, PaymentError> {
+        let swap = self
+            .persister
+            .fetch_swap_by_id(swap_id)
+            .map_err(|_| PaymentError::PersistError)?
+            .ok_or(PaymentError::Generic {
+                err: format!("Swap {swap_id} not found"),
+            })?;
+        match &swap {
+            Swap::Chain(ChainSwap {
+                state: Refundable, ..
+            }) => Ok(swap),
+            Swap::Send(SendSwap {
+                state: Pending,
+                lockup_tx_id: Some(_),
+                refund_tx_id: None,
+                ..
+            }) => Ok(swap),
+            _ => Err(PaymentError::Generic {
+                err: format!("Swap {swap_id} is not refundable"),
+            }),
+        }
+    }
+
+    /// Validates the refund address and estimates the refund tx of a refundable swap, returning
+    /// its vsize, fee and fee rate in sat/vbyte
+    fn estimate_refund(
+        &self,
+        swap: &Swap,
+        refund_address: &str,
+        fee_rate: &OnchainFeeRate,
+    ) -> Result<(u32, u64, f64), PaymentError> {
+        let (tx_vsize, sat_per_vbyte) = match swap {
+            Swap::Chain(chain_swap) => {
+                self.validate_bitcoin_address(refund_address)?;
+                let sat_per_vbyte =
+                    Self::resolve_fee_rate(fee_rate, BITCOIN_MIN_FEE_RATE_SAT_PER_VBYTE, || {
+                        self.fee_estimator.recommended_bitcoin_fees()
+                    })?;
+                let tx_vsize = self
+                    .swapper
+                    .get_chain_swap_refund_tx_vsize(chain_swap, refund_address)?;
+                (tx_vsize, sat_per_vbyte)
+            }
+            Swap::Send(send_swap) => {
+                self.validate_liquid_address(refund_address)?;
+                let sat_per_vbyte =
+                    Self::resolve_fee_rate(fee_rate, LIQUID_MIN_FEE_RATE_SAT_PER_VBYTE, || {
+                        self.fee_estimator.recommended_liquid_fees()
+                    })?;
+                let tx_vsize = self
+                    .swapper
+                    .get_send_swap_refund_tx_vsize(send_swap, refund_address)?;
+                (tx_vsize, sat_per_vbyte)
+            }
+            Swap::Receive(_) => {
+                return Err(PaymentError::Generic {
+                    err: "Receive Swaps cannot be refunded".to_string(),
+                })
+            }
+        };
+        let tx_fee_sat = (tx_vsize as f64 * sat_per_vbyte).ceil() as u64;
+        Ok((tx_vsize, tx_fee_sat, sat_per_vbyte))
+    }
+
+    /// The amount locked up by the payer of a refundable swap
+    fn get_refundable_amount_sat(swap: &Swap) -> Result<u64, PaymentError> {
+        match swap {
+            Swap::Chain(chain_swap) => Ok(chain_swap.payer_amount_sat),
+            Swap::Send(send_swap) => Ok(send_swap.get_boltz_create_response()?.expected_amount),
+            Swap::Receive(_) => Err(PaymentError::Generic {
+                err: "Receive Swaps cannot be refunded".to_string(),
+            }),
+        }
+    }
+
+    /// Lists the swaps whose funds can be refunded, see [LiquidSdk::refund]
+    pub async fn list_refundables(&self) -> LiquidSdkResult<Vec<RefundableSwap>> {
+        self.ensure_is_started().await?;
+
+        let refundable_chain_swaps = self
+            .persister
+            .list_refundable_chain_swaps()?
+            .into_iter()
+            .map(|swap| RefundableSwap {
+                swap_id: swap.id,
+                swap_address: swap.lockup_address,
+                timestamp: swap.created_at,
+                amount_sat: swap.payer_amount_sat,
+                payment_type: PaymentType::Receive,
+            });
+        let refundable_send_swaps = self
+            .persister
+            .list_refundable_send_swaps()?
+            .into_iter()
+            .map(|swap| {
+                let create_response = swap.get_boltz_create_response()?;
+                Ok(RefundableSwap {
+                    swap_id: swap.id,
+                    swap_address: create_response.address,
+                    timestamp: swap.created_at,
+                    amount_sat: create_response.expected_amount,
+                    payment_type: PaymentType::Send,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(refundable_chain_swaps
+            .chain(refundable_send_swaps)
+            .collect())
+    }
+
+    /// Estimates the fees and the refunded amount of a refund, see [LiquidSdk::refund]
+    pub async fn prepare_refund(
+        &self,
+        req: &PrepareRefundRequest,
+    ) -> Result<PrepareRefundResponse, PaymentError> {
+        self.ensure_is_started().await?;
+
+        let swap = self.get_refundable_swap(&req.swap_id)?;
+        let (tx_vsize, tx_fee_sat, fee_rate_sat_per_vbyte) =
+            self.estimate_refund(&swap, &req.refund_address, &req.fee_rate)?;
+        let amount_sat = Self::get_refundable_amount_sat(&swap)?;
+        ensure_sdk!(amount_sat > tx_fee_sat, PaymentError::AmountOutOfRange);
+
+        Ok(PrepareRefundResponse {
+            tx_vsize,
+            tx_fee_sat,
+            refund_amount_sat: amount_sat - tx_fee_sat,
+            fee_rate_sat_per_vbyte,
+        })
+    }
+
+    /// Refunds the funds locked up by the payer of a failed swap to the given address.
+    ///
+    /// The refund is first attempted cooperatively with the swapper. If that fails, a
+    /// non-cooperative refund is broadcast, which is only accepted once the lockup timelock has
+    /// expired.
+    pub async fn refund(&self, req: &RefundRequest) -> Result<RefundResponse, PaymentError> {
+        self.ensure_is_started().await?;
+
+        let swap = self.get_refundable_swap(&req.swap_id)?;
+        let fee_rate = OnchainFeeRate::SatPerVbyte {
+            sat_per_vbyte: req.fee_rate_sat_per_vbyte,
+        };
+        let (_, tx_fee_sat, _) = self.estimate_refund(&swap, &req.refund_address, &fee_rate)?;
+        let broadcast_fees_sat = Amount::from_sat(tx_fee_sat);
+
+        let refund_tx_id = match swap {
+            Swap::Chain(chain_swap) => {
+                self.chain_swap_state_handler
+                    .refund_incoming_swap(&chain_swap, &req.refund_address, broadcast_fees_sat)
+                    .await?
+            }
+            Swap::Send(send_swap) => {
+                let refund_tx_id = self
+                    .try_refund(&send_swap, &req.refund_address, broadcast_fees_sat)
+                    .await?;
+                info!(
+                    "Broadcast refund tx for Send Swap {}. Tx id: {refund_tx_id}",
+                    send_swap.id
+                );
+                // The refund address may not belong to this wallet, so the swap is resolved once
+                // the refund tx is broadcast
+                self.send_swap_state_handler
+                    .update_swap_info(&send_swap.id, Failed, None, None, Some(&refund_tx_id))
+                    .await?;
+                refund_tx_id
+            }
+            Swap::Receive(_) => {
+                return Err(PaymentError::Generic {
+                    err: "Receive Swaps cannot be refunded".to_string(),
+                })
+            }
+        };
+        Ok(RefundResponse { refund_tx_id })
+    }
+
     /// This method fetches the chain tx data (onchain and mempool) using LWK. For every wallet tx,
     /// it inserts or updates a corresponding entry in our Payments table.
     async fn sync_payments_with_chain_data(&self, with_scan: bool) -> Result<()> {
         let payments_before_sync: HashMap<String, Payment> = self
-            .list_payments()
+            .list_payments(&ListPaymentsRequest::default())
             .await?
             .into_iter()
             .filter_map(|payment| {
@@ -968,22 +2721,42 @@ impl LiquidSdk {
             self.persister.list_pending_receive_swaps_by_claim_tx_id()?;
         let pending_send_swaps_by_refund_tx_id =
             self.persister.list_pending_send_swaps_by_refund_tx_id()?;
-
+        let pending_chain_swaps_by_claim_tx_id =
+            self.persister.list_pending_chain_swaps_by_claim_tx_id()?;
+        let pending_chain_swaps_by_refund_tx_id =
+            self.persister.list_pending_chain_swaps_by_refund_tx_id()?;
+        let unpaid_liquid_receive_requests_by_script_pubkey = self
+            .persister
+            .list_unpaid_liquid_receive_requests_by_script_pubkey()?;
+
+        let lbtc_asset_id = self.config.lbtc_asset_id()?;
         for tx in self.onchain_wallet.transactions().await? {
             let tx_id = tx.txid.to_string();
             let is_tx_confirmed = tx.height.is_some();
-            let amount_sat = tx.balance.values().sum::<i64>();
+            let amount_sat = tx.balance.get(&lbtc_asset_id).copied().unwrap_or_default();
+
+            // If the tx moves an issued asset, the payment direction follows that asset. The L-BTC
+            // amount is then only the fee (Send) or nothing (Receive).
+            let issued_asset = tx
+                .balance
+                .iter()
+                .find(|(asset_id, amount)| **asset_id != lbtc_asset_id && **amount != 0);
+            let payment_type_amount = issued_asset.map_or(amount_sat, |(_, amount)| *amount);
+            let payment_type = match payment_type_amount >= 0 {
+                true => PaymentType::Receive,
+                false => PaymentType::Send,
+            };
 
             self.persister.insert_or_update_payment(PaymentTxData {
                 tx_id: tx_id.clone(),
                 timestamp: tx.timestamp,
                 amount_sat: amount_sat.unsigned_abs(),
                 fees_sat: tx.fee,
-                payment_type: match amount_sat >= 0 {
-                    true => PaymentType::Receive,
-                    false => PaymentType::Send,
-                },
+                payment_type,
                 is_confirmed: is_tx_confirmed,
+                asset_id: issued_asset.map(|(asset_id, _)| asset_id.to_string()),
+                asset_amount: issued_asset.map(|(_, amount)| amount.unsigned_abs()),
+                fee_rate_sat_per_vbyte: None,
             })?;
 
             if let Some(swap) = pending_receive_swaps_by_claim_tx_id.get(&tx_id) {
@@ -998,11 +2771,42 @@ impl LiquidSdk {
                         .update_swap_info(&swap.id, Failed, None, None, None)
                         .await?;
                 }
+            } else if let Some(swap) = pending_chain_swaps_by_claim_tx_id.get(&tx_id) {
+                // Only the L-BTC claim of Incoming swaps is a wallet tx
+                if is_tx_confirmed {
+                    self.chain_swap_state_handler
+                        .update_swap_info(&swap.id, Complete, None, None, None, None)
+                        .await?;
+                }
+            } else if let Some(swap) = pending_chain_swaps_by_refund_tx_id.get(&tx_id) {
+                // Only the L-BTC refund of Outgoing swaps is a wallet tx
+                if is_tx_confirmed {
+                    self.chain_swap_state_handler
+                        .update_swap_info(&swap.id, Failed, None, None, None, None)
+                        .await?;
+                }
             } else {
                 // Payments that are not directly associated with a swap (e.g. direct onchain payments using MRH)
 
                 match payments_before_sync.get(&tx_id) {
                     None => {
+                        if payment_type == PaymentType::Receive {
+                            // Tie the new incoming tx to the Liquid receive request it pays, if any
+                            for output in tx.outputs.iter().flatten() {
+                                let script_pubkey =
+                                    output.script_pubkey.as_bytes().to_lower_hex_string();
+                                if let Some(request) =
+                                    unpaid_liquid_receive_requests_by_script_pubkey
+                                        .get(&script_pubkey)
+                                {
+                                    self.persister.set_liquid_receive_request_tx_id(
+                                        &request.address,
+                                        &tx_id,
+                                    )?;
+                                }
+                            }
+                        }
+
                         // A completely new payment brought in by this sync, in mempool or confirmed
                         // Covers events:
                         // - onchain Receive Pending and Complete
@@ -1024,18 +2828,88 @@ impl LiquidSdk {
     }
 
     /// Lists the SDK payments. The payments are determined based on onchain transactions and swaps.
-    pub async fn list_payments(&self) -> Result<Vec<Payment>, PaymentError> {
+    ///
+    /// The payments can be filtered and paginated with the [ListPaymentsRequest].
+    pub async fn list_payments(
+        &self,
+        req: &ListPaymentsRequest,
+    ) -> Result<Vec<Payment>, PaymentError> {
+        self.ensure_is_started().await?;
+
+        Ok(self.persister.get_payments(req)?)
+    }
+
+    /// Looks up a payment by its tx id, swap id, invoice or payment hash.
+    ///
+    /// Unlike [LiquidSdk::list_payments], this also finds the Receive Swaps whose invoice was not
+    /// paid yet.
+    pub async fn get_payment(
+        &self,
+        req: &GetPaymentRequest,
+    ) -> Result<Option<Payment>, PaymentError> {
+        self.ensure_is_started().await?;
+
+        // Invoices are matched by payment hash, which does not depend on their encoding
+        let req = match req {
+            GetPaymentRequest::Bolt11 { bolt11 } => GetPaymentRequest::PaymentHash {
+                payment_hash: Self::parse_invoice(bolt11)?.payment_hash,
+            },
+            req => req.clone(),
+        };
+        Ok(self.persister.get_payment_by_request(&req)?)
+    }
+
+    /// Sets the user metadata of a payment, replacing any it already has.
+    ///
+    /// The payment is identified by its swap id or by one of its tx ids. The metadata of a swap
+    /// is always stored under its swap id, so it is kept across all its txs.
+    pub async fn set_payment_metadata(
+        &self,
+        req: &SetPaymentMetadataRequest,
+    ) -> Result<(), PaymentError> {
         self.ensure_is_started().await?;
 
-        let mut payments: Vec<Payment> = self.persister.get_payments()?;
-        payments.sort_by_key(|p| p.timestamp);
-        Ok(payments)
+        let swap_id_req = GetPaymentRequest::SwapId {
+            swap_id: req.id.clone(),
+        };
+        let tx_id_req = GetPaymentRequest::TxId {
+            tx_id: req.id.clone(),
+        };
+        let payment =
+            match self.persister.get_payment_by_request(&swap_id_req)? {
+                Some(payment) => payment,
+                None => self.persister.get_payment_by_request(&tx_id_req)?.ok_or(
+                    PaymentError::Generic {
+                        err: format!("Payment {} not found", req.id),
+                    },
+                )?,
+            };
+        // Payments are joined with their metadata by swap id, else by tx id
+        let metadata_id = payment.swap_id.unwrap_or(req.id.clone());
+
+        Ok(self
+            .persister
+            .insert_or_update_payment_metadata(&metadata_id, &req.metadata)?)
+    }
+
+    /// Exports the payments matching the request filter to a CSV or JSON file, for accounting.
+    ///
+    /// See [PaymentExportFormat] for the exported columns.
+    pub async fn export_payments(
+        &self,
+        req: &ExportPaymentsRequest,
+    ) -> Result<ExportPaymentsResponse, PaymentError> {
+        let payments = self.list_payments(&req.filter).await?;
+        export::export_payments(&req.path, &payments, req.format)?;
+        Ok(ExportPaymentsResponse {
+            payments_count: payments.len() as u32,
+        })
     }
 
     /// Empties all Liquid Wallet caches for this network type.
     pub fn empty_wallet_cache(&self) -> Result<()> {
         let mut path = PathBuf::from(self.config.working_dir.clone());
-        path.push(Into::<ElementsNetwork>::into(self.config.network).as_str());
+        path.push(self.config.elements_network()?.as_str());
         path.push("enc_cache");
 
         fs::remove_dir_all(&path)?;
@@ -1044,6 +2918,15 @@ impl LiquidSdk {
         Ok(())
     }
 
+    /// Lists the Liquid Electrum servers of the [Config], with the one in use and their health.
+    ///
+    /// If [Config::esplora_url] is set, the servers are only used to build swap claim and refund txs.
+    pub async fn list_electrum_servers(&self) -> LiquidSdkResult<Vec<ElectrumServerStatus>> {
+        self.ensure_is_started().await?;
+
+        Ok(self.electrum_servers.status())
+    }
+
     /// Synchronize the DB with mempool and onchain data
     pub async fn sync(&self) -> LiquidSdkResult<()> {
         self.ensure_is_started().await?;
@@ -1065,21 +2948,37 @@ impl LiquidSdk {
         self.persister.backup(backup_path)
     }
 
+    /// Restores the database from a backup.
+    ///
+    /// The swap key index is recovered in the background on the next start, so the SDK has to be
+    /// reconnected before new swaps can be created.
     pub fn restore(&self, req: RestoreRequest) -> Result<()> {
         let backup_path = req
             .backup_path
             .map(PathBuf::from)
             .unwrap_or(self.persister.get_default_backup_path());
-        self.persister.restore_from_backup(backup_path)
+        self.persister.restore_from_backup(backup_path)?;
+        // Swaps created after the backup may have used later swap keys
+        self.persister.delete_swap_key_index()
     }
 
     pub fn default_config(network: Network) -> Config {
         match network {
             Network::Mainnet => Config::mainnet(),
             Network::Testnet => Config::testnet(),
+            Network::Regtest => Config::regtest(),
         }
     }
 
+    /// Classifies and decodes a user input: a BOLT11 invoice, a Liquid or BTC address, a
+    /// BIP21 URI, an LNURL or a Lightning Address.
+    ///
+    /// Returns [PaymentError::NetworkMismatch] if the input is meant for a different network
+    /// than the configured one.
+    pub fn parse(&self, input: &str) -> Result<InputType, PaymentError> {
+        input_parser::parse(input, self.config.network)
+    }
+
     pub fn parse_invoice(input: &str) -> Result<LNInvoice, PaymentError> {
         let input = input
             .strip_prefix("lightning:")
@@ -1190,26 +3089,23 @@ impl ReconnectHandler for SwapperReconnectHandler {
 #[cfg(test)]
 mod tests {
     use anyhow::Result;
-    use tempdir::TempDir;
+    use boltz_client::swaps::boltzv2::ReversePair;
+    use lwk_wollet::hashes::{sha256, Hash};
 
+    use crate::error::PaymentError;
     use crate::model::*;
-    use crate::sdk::LiquidSdk;
-
-    const TEST_MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
-
-    fn create_temp_dir() -> Result<(TempDir, String)> {
-        let data_dir = TempDir::new(&uuid::Uuid::new_v4().to_string())?;
-        let data_dir_str = data_dir
-            .as_ref()
-            .to_path_buf()
-            .to_str()
-            .expect("Expecting valid temporary path")
-            .to_owned();
-        Ok((data_dir, data_dir_str))
-    }
+    use crate::sdk::{LiquidSdk, SWAP_KEY_RECOVERY_GAP_LIMIT};
+    use crate::test_utils::chain_swap::new_chain_swap;
+    use crate::test_utils::persist::{create_temp_dir, new_send_swap};
+    use crate::test_utils::sdk::{new_test_sdk, new_test_sdk_with_config};
+    use crate::test_utils::swapper::{new_reverse_pair, new_submarine_pair, MOCK_REFUND_TX_VSIZE};
+    use crate::test_utils::{
+        new_test_invoice, TEST_BITCOIN_ADDRESS, TEST_LIQUID_ADDRESS, TEST_MNEMONIC,
+    };
+    use crate::utils;
 
     async fn list_pending(sdk: &LiquidSdk) -> Result<Vec<Payment>> {
-        let payments = sdk.list_payments().await?;
+        let payments = sdk.list_payments(&ListPaymentsRequest::default()).await?;
 
         Ok(payments
             .iter()
@@ -1230,8 +3126,14 @@ mod tests {
         .await?;
 
         let invoice = "lntb10u1pnqwkjrpp5j8ucv9mgww0ajk95yfpvuq0gg5825s207clrzl5thvtuzfn68h0sdqqcqzzsxqr23srzjqv8clnrfs9keq3zlg589jvzpw87cqh6rjks0f9g2t9tvuvcqgcl45f6pqqqqqfcqqyqqqqlgqqqqqqgq2qsp5jnuprlxrargr6hgnnahl28nvutj3gkmxmmssu8ztfhmmey3gq2ss9qyyssq9ejvcp6frwklf73xvskzdcuhnnw8dmxag6v44pffwqrxznsly4nqedem3p3zhn6u4ln7k79vk6zv55jjljhnac4gnvr677fyhfgn07qp4x6wrq".to_string();
-        sdk.prepare_send_payment(&PrepareSendRequest { invoice })
-            .await?;
+        sdk.prepare_send_payment(&PrepareSendRequest {
+            invoice,
+            metadata: None,
+            max_fee_sat: None,
+            max_fee_percent: None,
+            fee_rate: None,
+        })
+        .await?;
         assert!(!list_pending(&sdk).await?.is_empty());
 
         Ok(())
@@ -1250,7 +3152,15 @@ mod tests {
 
         let prepare_response = sdk
             .prepare_receive_payment(&PrepareReceiveRequest {
-                payer_amount_sat: 1_000,
+                payer_amount_sat: Some(1_000),
+                receiver_amount_sat: None,
+                description: Some("Test receive".to_string()),
+                description_hash: None,
+                invoice_expiry_secs: None,
+                metadata: None,
+                max_fee_sat: None,
+                max_fee_percent: None,
+                fee_rate: None,
             })
             .await?;
         sdk.receive_payment(&prepare_response).await?;
@@ -1263,4 +3173,880 @@ mod tests {
     fn reverse_submarine_swap_recovery() -> Result<()> {
         Ok(())
     }
+
+    #[test]
+    fn reverse_payer_amount_sat() -> Result<()> {
+        fn reverse_pair_with_fees(percentage: f64, lockup_fees_sat: u64) -> Result<ReversePair> {
+            Ok(serde_json::from_value(serde_json::json!({
+                "hash": "reverse-pair-hash",
+                "rate": 1.0,
+                "limits": { "maximal": 25_000_000, "minimal": 1_000 },
+                "fees": {
+                    "percentage": percentage,
+                    "minerFees": { "lockup": lockup_fees_sat, "claim": 14 },
+                },
+            }))?)
+        }
+
+        let receiver_amounts_sat = [
+            1, 2, 99, 100, 101, 399, 400, 401, 999, 1_000, 1_001, 49_875, 50_000, 123_457,
+            1_000_000, 24_999_999, 25_000_000,
+        ];
+        for percentage in [0.0, 0.1, 0.25, 0.5, 1.0, 2.5, 33.3] {
+            for (lockup_fees_sat, claim_fees_sat) in [(0, 0), (26, 14), (26, 50), (1_000, 1)] {
+                let reverse_pair = reverse_pair_with_fees(percentage, lockup_fees_sat)?;
+                let receiver_amount_of = |payer_amount_sat: u64| {
+                    let fee_breakdown = LiquidSdk::reverse_fee_breakdown(
+                        &reverse_pair,
+                        payer_amount_sat,
+                        claim_fees_sat,
+                    );
+                    payer_amount_sat.saturating_sub(fee_breakdown.total_fees_sat)
+                };
+
+                for receiver_amount_sat in receiver_amounts_sat {
+                    let payer_amount_sat = LiquidSdk::reverse_payer_amount_sat(
+                        &reverse_pair,
+                        receiver_amount_sat,
+                        claim_fees_sat,
+                    )?;
+                    let case = format!(
+                        "{receiver_amount_sat} sat at {percentage}% with {lockup_fees_sat} + \
+                         {claim_fees_sat} sat of miner fees"
+                    );
+                    assert_eq!(
+                        receiver_amount_of(payer_amount_sat),
+                        receiver_amount_sat,
+                        "{case}"
+                    );
+                    // It is the smallest invoice amount resulting in the requested amount
+                    assert!(
+                        receiver_amount_of(payer_amount_sat - 1) < receiver_amount_sat,
+                        "{case}"
+                    );
+                }
+
+                assert!(matches!(
+                    LiquidSdk::reverse_payer_amount_sat(&reverse_pair, 0, claim_fees_sat),
+                    Err(PaymentError::AmountOutOfRange)
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn validate_invoice_description() {
+        let description_hash = crate::test_utils::mock_hash("description");
+        let cases = [
+            (None, None, true),
+            (Some("Coffee".to_string()), None, true),
+            (Some("a".repeat(639)), None, true),
+            (Some("a".repeat(640)), None, false),
+            // The limit is in bytes, not in characters
+            (Some("é".repeat(319)), None, true),
+            (Some("é".repeat(320)), None, false),
+            (None, Some(description_hash.clone()), true),
+            (None, Some(description_hash[1..].to_string()), false),
+            (None, Some("not a hash".to_string()), false),
+            (Some("Coffee".to_string()), Some(description_hash), false),
+        ];
+
+        for (description, description_hash, is_valid) in cases {
+            let res = LiquidSdk::validate_invoice_description(&description, &description_hash);
+            match is_valid {
+                true => assert!(
+                    res.is_ok(),
+                    "Expecting {description:?} / {description_hash:?} to be valid"
+                ),
+                false => assert!(
+                    matches!(res, Err(PaymentError::ReceiveError { .. })),
+                    "Expecting {description:?} / {description_hash:?} to be rejected"
+                ),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn ensure_fees_within_limits() -> Result<()> {
+        let test_sdk = new_test_sdk().await?;
+        // Without any limit, all fees are accepted
+        assert!(test_sdk
+            .sdk
+            .ensure_fees_within_limits(50_000, 10_000, None, None)
+            .is_ok());
+
+        let mut config = Config::testnet();
+        config.max_fee_sat = Some(500);
+        config.max_fee_percent = Some(1.0);
+        let test_sdk = new_test_sdk_with_config(config).await?;
+        let sdk = &test_sdk.sdk;
+
+        // (fees, invoice amount, request max fee sat, request max fee percent, expected limit)
+        let cases = [
+            // The lowest of the sat and percentage limits of the config applies
+            (500, 100_000, None, None, Ok(())),
+            (501, 100_000, None, None, Err(500)),
+            (100, 10_000, None, None, Ok(())),
+            (101, 10_000, None, None, Err(100)),
+            // The limits of the request override the ones of the config
+            (900, 100_000, Some(1_000), None, Ok(())),
+            (1_001, 100_000, Some(1_000), Some(5.0), Err(1_000)),
+            (900, 10_000, Some(1_000), Some(10.0), Ok(())),
+            (11, 10_000, None, Some(0.1), Err(10)),
+            (0, 10_000, Some(0), Some(0.0), Ok(())),
+            (1, 10_000, Some(0), None, Err(0)),
+        ];
+        for (fees_sat, amount_sat, max_fee_sat, max_fee_percent, expected) in cases {
+            let res =
+                sdk.ensure_fees_within_limits(fees_sat, amount_sat, max_fee_sat, max_fee_percent);
+            match expected {
+                Ok(()) => assert!(res.is_ok(), "Expecting {fees_sat} sat to be accepted"),
+                Err(expected_limit_sat) => assert!(
+                    matches!(
+                        res,
+                        Err(PaymentError::FeeLimitExceeded { fee_sat, limit_sat })
+                            if fee_sat == fees_sat && limit_sat == expected_limit_sat
+                    ),
+                    "Expecting {fees_sat} sat to exceed {expected_limit_sat} sat"
+                ),
+            }
+        }
+
+        for max_fee_percent in [-1.0, f64::NAN] {
+            assert!(matches!(
+                sdk.ensure_fees_within_limits(0, 10_000, None, Some(max_fee_percent)),
+                Err(PaymentError::Generic { .. })
+            ));
+        }
+        let mut config = Config::testnet();
+        config.max_fee_percent = Some(-0.5);
+        let test_sdk = new_test_sdk_with_config(config).await?;
+        assert!(matches!(
+            test_sdk
+                .sdk
+                .ensure_fees_within_limits(0, 10_000, None, None),
+            Err(PaymentError::Generic { .. })
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn fetch_lightning_limits() -> Result<()> {
+        let test_sdk = new_test_sdk().await?;
+        let sdk = &test_sdk.sdk;
+
+        let limits = sdk.fetch_lightning_limits().await?;
+        assert_eq!(
+            (limits.send.min_sat, limits.send.max_sat),
+            (1_000, 25_000_000)
+        );
+        assert_eq!(limits.send.service_fee_percentage, 0.1);
+        assert_eq!(limits.send.miner_fees_sat, 19);
+        assert_eq!(
+            (limits.receive.min_sat, limits.receive.max_sat),
+            (1_000, 25_000_000)
+        );
+        assert_eq!(limits.receive.service_fee_percentage, 0.25);
+        assert_eq!(limits.receive.miner_fees_sat, 26 + 14);
+
+        *test_sdk.swapper.reverse_pair.lock().unwrap() = None;
+        assert!(matches!(
+            sdk.fetch_lightning_limits().await,
+            Err(PaymentError::PairsNotFound)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn fee_breakdown() {
+        fn sum_of_components(fee_breakdown: &FeeBreakdown) -> u64 {
+            fee_breakdown.service_fee_sat
+                + fee_breakdown.swapper_miner_fees_sat
+                + fee_breakdown.network_fee_sat
+        }
+
+        let lbtc_pair = new_submarine_pair();
+        let reverse_pair = new_reverse_pair();
+        for amount_sat in [1_000, 50_000, 1_234_567, 25_000_000] {
+            let fee_breakdown = LiquidSdk::submarine_fee_breakdown(&lbtc_pair, amount_sat, 26);
+            assert_eq!(
+                sum_of_components(&fee_breakdown),
+                fee_breakdown.total_fees_sat
+            );
+            assert_eq!(
+                fee_breakdown.total_fees_sat,
+                lbtc_pair.fees.total(amount_sat) + 26
+            );
+
+            let fee_breakdown = LiquidSdk::reverse_fee_breakdown(&reverse_pair, amount_sat, 20);
+            assert_eq!(
+                sum_of_components(&fee_breakdown),
+                fee_breakdown.total_fees_sat
+            );
+            assert_eq!(
+                fee_breakdown.total_fees_sat,
+                reverse_pair.fees.total(amount_sat) - reverse_pair.fees.claim_estimate() + 20
+            );
+        }
+    }
+
+    #[test]
+    fn liquid_claim_fees_sat() {
+        // The swapper claim estimate is priced at the minimum Liquid fee rate
+        assert_eq!(LiquidSdk::liquid_claim_fees_sat(20, None), 20);
+        assert_eq!(LiquidSdk::liquid_claim_fees_sat(20, Some(0.1)), 20);
+        assert_eq!(LiquidSdk::liquid_claim_fees_sat(20, Some(0.25)), 50);
+    }
+
+    #[tokio::test]
+    async fn pay_onchain() -> Result<()> {
+        let test_sdk = new_test_sdk().await?;
+        let sdk = &test_sdk.sdk;
+
+        // User lockup: (50_000 + 300 claim + 400 server) / (1 - 0.1%) = 50_751, plus a 100 sat
+        // lockup tx fee at the wallet default fee rate
+        let prepare_res = sdk
+            .prepare_pay_onchain(&PreparePayOnchainRequest {
+                receiver_amount_sat: 50_000,
+                fee_rate: None,
+            })
+            .await?;
+        assert_eq!(prepare_res.fees_sat, 851);
+
+        // Only BTC addresses on the SDK network are accepted
+        for address in [
+            TEST_LIQUID_ADDRESS,
+            "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq",
+        ] {
+            let res = sdk
+                .pay_onchain(&PayOnchainRequest {
+                    address: address.to_string(),
+                    prepare_res: prepare_res.clone(),
+                })
+                .await;
+            assert!(res.is_err(), "Expected {address} to be rejected");
+        }
+
+        // Fees that do not match the current pair are rejected
+        let mut outdated_prepare_res = prepare_res.clone();
+        outdated_prepare_res.fees_sat -= 1;
+        let res = sdk
+            .pay_onchain(&PayOnchainRequest {
+                address: TEST_BITCOIN_ADDRESS.to_string(),
+                prepare_res: outdated_prepare_res,
+            })
+            .await;
+        assert!(matches!(res, Err(PaymentError::InvalidOrExpiredFees)));
+        assert!(test_sdk
+            .swapper
+            .broadcast_tx_hexes
+            .lock()
+            .unwrap()
+            .is_empty());
+
+        let payment = sdk
+            .pay_onchain(&PayOnchainRequest {
+                address: TEST_BITCOIN_ADDRESS.to_string(),
+                prepare_res,
+            })
+            .await?
+            .payment;
+        assert_eq!(test_sdk.swapper.broadcast_tx_hexes.lock().unwrap().len(), 1);
+        assert_eq!(payment.status, PaymentState::Pending);
+        assert_eq!(payment.payment_type, PaymentType::Send);
+
+        let swap_id = payment.swap_id.expect("Expecting a swap ID");
+        let swap = test_sdk
+            .persister
+            .fetch_chain_swap_by_id(&swap_id)?
+            .expect("Expecting a persisted swap");
+        assert_eq!(swap.direction, Direction::Outgoing);
+        assert_eq!(swap.state, PaymentState::Pending);
+        assert_eq!(swap.claim_address.as_deref(), Some(TEST_BITCOIN_ADDRESS));
+        assert_eq!(swap.user_lockup_tx_id, payment.tx_id);
+        assert_eq!(swap.payer_amount_sat, 50_851);
+        assert_eq!(swap.receiver_amount_sat, 50_000);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn receive_onchain() -> Result<()> {
+        let test_sdk = new_test_sdk().await?;
+        let sdk = &test_sdk.sdk;
+
+        // Below the pair minimum of 25_000 sat
+        let res = sdk
+            .prepare_receive_onchain(&PrepareReceiveOnchainRequest {
+                payer_amount_sat: 10_000,
+                fee_rate: None,
+            })
+            .await;
+        assert!(matches!(res, Err(PaymentError::AmountOutOfRange)));
+
+        // 50 sat service fee (0.1%), 400 sat server fee and the 20 sat claim estimate
+        let prepare_res = sdk
+            .prepare_receive_onchain(&PrepareReceiveOnchainRequest {
+                payer_amount_sat: 50_000,
+                fee_rate: None,
+            })
+            .await?;
+        assert_eq!(prepare_res.fees_sat, 470);
+
+        // The claim fee scales with the claim fee rate, from the 0.1 sat/vbyte of the estimate
+        let custom_rate_prepare_res = sdk
+            .prepare_receive_onchain(&PrepareReceiveOnchainRequest {
+                payer_amount_sat: 50_000,
+                fee_rate: Some(OnchainFeeRate::SatPerVbyte { sat_per_vbyte: 0.2 }),
+            })
+            .await?;
+        assert_eq!(custom_rate_prepare_res.fees_sat, 490);
+
+        let mut outdated_prepare_res = prepare_res.clone();
+        outdated_prepare_res.fees_sat += 1;
+        let res = sdk.receive_onchain(&outdated_prepare_res).await;
+        assert!(matches!(res, Err(PaymentError::InvalidOrExpiredFees)));
+
+        let receive_res = sdk.receive_onchain(&prepare_res).await?;
+        assert_eq!(receive_res.address, TEST_BITCOIN_ADDRESS);
+        assert_eq!(
+            receive_res.bip21,
+            format!("bitcoin:{TEST_BITCOIN_ADDRESS}?amount=0.0005")
+        );
+
+        let swap = test_sdk
+            .persister
+            .fetch_chain_swap_by_id(&receive_res.id)?
+            .expect("Expecting a persisted swap");
+        assert_eq!(swap.direction, Direction::Incoming);
+        assert_eq!(swap.state, PaymentState::Created);
+        assert_eq!(swap.payer_amount_sat, 50_000);
+        assert_eq!(swap.receiver_amount_sat, 49_530);
+        assert_eq!(swap.claim_fees_sat, 20);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn refund_chain_swap() -> Result<()> {
+        let test_sdk = new_test_sdk().await?;
+        let sdk = &test_sdk.sdk;
+        test_sdk.persister.insert_chain_swap(&new_chain_swap(
+            "refundable",
+            Direction::Incoming,
+            PaymentState::Refundable,
+        ))?;
+        test_sdk.persister.insert_chain_swap(&new_chain_swap(
+            "pending",
+            Direction::Incoming,
+            PaymentState::Pending,
+        ))?;
+
+        let refundables = sdk.list_refundables().await?;
+        assert_eq!(
+            refundables
+                .iter()
+                .map(|r| (r.swap_id.as_str(), r.amount_sat))
+                .collect::<Vec<_>>(),
+            vec![("refundable", 50_000)]
+        );
+
+        let prepare_req = |swap_id: &str, refund_address: &str| PrepareRefundRequest {
+            swap_id: swap_id.to_string(),
+            refund_address: refund_address.to_string(),
+            fee_rate: OnchainFeeRate::SatPerVbyte { sat_per_vbyte: 5.0 },
+        };
+        assert!(sdk
+            .prepare_refund(&prepare_req("pending", TEST_BITCOIN_ADDRESS))
+            .await
+            .is_err());
+        // The BTC lockup can only be refunded to a BTC address
+        assert!(sdk
+            .prepare_refund(&prepare_req("refundable", TEST_LIQUID_ADDRESS))
+            .await
+            .is_err());
+
+        let prepare_res = sdk
+            .prepare_refund(&prepare_req("refundable", TEST_BITCOIN_ADDRESS))
+            .await?;
+        assert_eq!(prepare_res.tx_vsize, MOCK_REFUND_TX_VSIZE);
+        assert_eq!(prepare_res.tx_fee_sat, 750);
+        assert_eq!(prepare_res.refund_amount_sat, 49_250);
+        assert_eq!(prepare_res.fee_rate_sat_per_vbyte, 5.0);
+
+        // Fee priorities resolve to the recommended BTC fees
+        let priority_prepare_res = sdk
+            .prepare_refund(&PrepareRefundRequest {
+                fee_rate: OnchainFeeRate::Priority {
+                    priority: FeePriority::Fastest,
+                },
+                ..prepare_req("refundable", TEST_BITCOIN_ADDRESS)
+            })
+            .await?;
+        assert_eq!(priority_prepare_res.tx_fee_sat, 3_000);
+
+        let refund_res = sdk
+            .refund(&RefundRequest {
+                swap_id: "refundable".to_string(),
+                refund_address: TEST_BITCOIN_ADDRESS.to_string(),
+                fee_rate_sat_per_vbyte: prepare_res.fee_rate_sat_per_vbyte,
+            })
+            .await?;
+        let refunds = test_sdk.swapper.refunds.lock().unwrap().clone();
+        assert_eq!(refunds.len(), 1);
+        assert_eq!(refunds[0].broadcast_fees_sat, 750);
+        assert_eq!(refunds[0].output_address, TEST_BITCOIN_ADDRESS);
+
+        let swap = test_sdk
+            .persister
+            .fetch_chain_swap_by_id("refundable")?
+            .expect("Expecting a persisted swap");
+        assert_eq!(swap.state, PaymentState::Failed);
+        assert_eq!(swap.refund_tx_id, Some(refund_res.refund_tx_id));
+        assert!(sdk.list_refundables().await?.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn swap_key_index_recovery() -> Result<()> {
+        let test_sdk = new_test_sdk().await?;
+        let (sdk, persister, swapper) = (&test_sdk.sdk, &test_sdk.persister, &test_sdk.swapper);
+
+        // The database was lost
+        persister.delete_swap_key_index()?;
+        assert!(persister.next_swap_key_index().is_err());
+
+        // The swapper knows a swap created with the refund key of index 3
+        let refund_public_key = sdk
+            .onchain_wallet
+            .derive_swap_refund_keypair(3)?
+            .public_key();
+        swapper
+            .swap_ids_by_public_key
+            .lock()
+            .unwrap()
+            .insert(refund_public_key, vec!["send-3".to_string()]);
+        sdk.recover_swap_key_index().await?;
+        assert_eq!(persister.next_swap_key_index()?, 4);
+
+        // Swaps past the gap limit are not found, but the persisted ones are never reused
+        persister.delete_swap_key_index()?;
+        let claim_public_key = sdk
+            .onchain_wallet
+            .derive_swap_claim_keypair(3 + SWAP_KEY_RECOVERY_GAP_LIMIT + 1)?
+            .public_key();
+        swapper
+            .swap_ids_by_public_key
+            .lock()
+            .unwrap()
+            .insert(claim_public_key, vec!["receive-24".to_string()]);
+        let mut swap = new_chain_swap("chain-30", Direction::Incoming, PaymentState::Created);
+        swap.key_index = Some(30);
+        persister.insert_chain_swap(&swap)?;
+        sdk.recover_swap_key_index().await?;
+        assert_eq!(persister.next_swap_key_index()?, 31);
+
+        // Once persisted, the index is not recovered again
+        sdk.recover_swap_key_index().await?;
+        assert_eq!(persister.next_swap_key_index()?, 32);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn send_onchain() -> Result<()> {
+        let test_sdk = new_test_sdk().await?;
+        let sdk = &test_sdk.sdk;
+
+        let prepare_res = sdk
+            .prepare_send_onchain(&PrepareSendOnchainRequest {
+                destination: format!("liquidtestnet:{TEST_LIQUID_ADDRESS}?amount=0.0001"),
+                amount_sat: None,
+                send_all: false,
+                asset_id: None,
+                fee_rate: None,
+            })
+            .await?;
+        assert_eq!(prepare_res.address, TEST_LIQUID_ADDRESS);
+        assert_eq!(prepare_res.receiver_amount_sat, 10_000);
+
+        // The address of the prepared payment is checked again
+        for address in [
+            TEST_BITCOIN_ADDRESS,
+            "lq1pqvzxvqhrf54dd4sny4cag7497pe38252qefk46t92frs7us8r80ja9ha8r5me09nn22m4tmdqp5p4wafq3s59cql3v9n45t5trwtxrmxfsyxjnstkctj",
+        ] {
+            let res = sdk
+                .send_onchain(&PrepareSendOnchainResponse {
+                    address: address.to_string(),
+                    ..prepare_res.clone()
+                })
+                .await;
+            assert!(res.is_err(), "Expected {address} to be rejected");
+        }
+        assert!(test_sdk
+            .swapper
+            .broadcast_tx_hexes
+            .lock()
+            .unwrap()
+            .is_empty());
+
+        let payment = sdk.send_onchain(&prepare_res).await?.payment;
+        assert_eq!(test_sdk.swapper.broadcast_tx_hexes.lock().unwrap().len(), 1);
+        assert_eq!(payment.payment_type, PaymentType::Send);
+        assert_eq!(payment.status, PaymentState::Pending);
+        assert_eq!(payment.amount_sat, 10_000 + prepare_res.fees_sat);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn receive_liquid() -> Result<()> {
+        let test_sdk = new_test_sdk().await?;
+        let sdk = &test_sdk.sdk;
+
+        let res = sdk
+            .receive_liquid(&ReceiveLiquidRequest {
+                amount_sat: Some(0),
+                label: None,
+            })
+            .await;
+        assert!(matches!(res, Err(PaymentError::AmountOutOfRange)));
+
+        let receive_res = sdk
+            .receive_liquid(&ReceiveLiquidRequest {
+                amount_sat: Some(10_000),
+                label: Some("Coffee & cake".to_string()),
+            })
+            .await?;
+        assert_eq!(receive_res.address, TEST_LIQUID_ADDRESS);
+        assert_eq!(
+            receive_res.bip21,
+            format!("liquidtestnet:{TEST_LIQUID_ADDRESS}?amount=0.0001&label=Coffee%20%26%20cake")
+        );
+        let requests = test_sdk
+            .persister
+            .list_unpaid_liquid_receive_requests_by_script_pubkey()?;
+        assert_eq!(requests.len(), 1);
+        let request = requests.values().next().unwrap();
+        assert_eq!(request.address, TEST_LIQUID_ADDRESS);
+        assert_eq!(request.amount_sat, Some(10_000));
+        assert_eq!(request.label.as_deref(), Some("Coffee & cake"));
+        assert!(request.tx_id.is_none());
+
+        // The wallet hands out the same address until it is used, so the request is replaced
+        let receive_res = sdk
+            .receive_liquid(&ReceiveLiquidRequest {
+                amount_sat: None,
+                label: None,
+            })
+            .await?;
+        assert_eq!(
+            receive_res.bip21,
+            format!("liquidtestnet:{TEST_LIQUID_ADDRESS}")
+        );
+        let requests = test_sdk
+            .persister
+            .list_unpaid_liquid_receive_requests_by_script_pubkey()?;
+        assert_eq!(requests.len(), 1);
+        let request = requests.values().next().unwrap();
+        assert_eq!(request.amount_sat, None);
+        assert_eq!(request.label, None);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn set_payment_metadata() -> Result<()> {
+        let test_sdk = new_test_sdk().await?;
+        let sdk = &test_sdk.sdk;
+        let mut send_swap = new_send_swap("send", PaymentState::Pending);
+        send_swap.lockup_tx_id = Some("send-lockup".to_string());
+        test_sdk.persister.insert_send_swap(&send_swap)?;
+        test_sdk.persister.insert_or_update_payment(PaymentTxData {
+            tx_id: "send-lockup".to_string(),
+            timestamp: None,
+            amount_sat: 50_100,
+            fees_sat: 100,
+            payment_type: PaymentType::Send,
+            is_confirmed: false,
+            asset_id: None,
+            asset_amount: None,
+            fee_rate_sat_per_vbyte: None,
+        })?;
+
+        // Set by the tx ID of the swap, the metadata is stored under the swap ID
+        let metadata = PaymentMetadata {
+            label: Some("Rent".to_string()),
+            note: None,
+            external_id: None,
+        };
+        sdk.set_payment_metadata(&SetPaymentMetadataRequest {
+            id: "send-lockup".to_string(),
+            metadata: metadata.clone(),
+        })
+        .await?;
+        for req in [
+            GetPaymentRequest::SwapId {
+                swap_id: "send".to_string(),
+            },
+            GetPaymentRequest::TxId {
+                tx_id: "send-lockup".to_string(),
+            },
+        ] {
+            let payment = sdk.get_payment(&req).await?.expect("Expecting a payment");
+            assert_eq!(payment.metadata, Some(metadata.clone()));
+        }
+
+        assert!(sdk
+            .set_payment_metadata(&SetPaymentMetadataRequest {
+                id: "unknown".to_string(),
+                metadata,
+            })
+            .await
+            .is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn send_payment_fee_quote() -> Result<()> {
+        let mut config = Config::testnet();
+        config.payment_timeout_sec = 0;
+        let test_sdk = new_test_sdk_with_config(config).await?;
+        let sdk = &test_sdk.sdk;
+
+        let invoice = new_test_invoice(
+            50_000_000,
+            sha256::Hash::hash(b"send fee quote"),
+            "Send fee quote",
+            None,
+            None,
+        );
+        let prepared = sdk
+            .prepare_send_payment(&PrepareSendRequest {
+                invoice,
+                metadata: None,
+                max_fee_sat: None,
+                max_fee_percent: None,
+                fee_rate: None,
+            })
+            .await?;
+
+        let mut expired = prepared.clone();
+        expired.expires_at = utils::now() - 1;
+        let mut fees_mismatch = prepared.clone();
+        fees_mismatch.fees_sat -= 1;
+        let mut components_mismatch = prepared.clone();
+        components_mismatch.fees_sat += 1;
+        components_mismatch.fee_breakdown.total_fees_sat += 1;
+        let mut lower_service_fee = prepared.clone();
+        lower_service_fee.fees_sat -= 1;
+        lower_service_fee.fee_breakdown.service_fee_sat -= 1;
+        lower_service_fee.fee_breakdown.total_fees_sat -= 1;
+        let mut pair_hash_mismatch = prepared.clone();
+        pair_hash_mismatch.pair_hash = "other-pair-hash".to_string();
+        for req in [
+            expired,
+            fees_mismatch,
+            components_mismatch,
+            lower_service_fee,
+            pair_hash_mismatch,
+        ] {
+            assert!(matches!(
+                sdk.send_payment(&req).await,
+                Err(PaymentError::InvalidOrExpiredFees)
+            ));
+        }
+
+        // The quote is bound to the pair it was made from
+        let mut updated_pair = new_submarine_pair();
+        updated_pair.hash = "updated-pair-hash".to_string();
+        *test_sdk.swapper.submarine_pair.lock().unwrap() = Some(updated_pair);
+        assert!(matches!(
+            sdk.send_payment(&prepared).await,
+            Err(PaymentError::InvalidOrExpiredFees)
+        ));
+        *test_sdk.swapper.submarine_pair.lock().unwrap() = Some(new_submarine_pair());
+
+        // The lockup tx fee is re-estimated when sending, and checked against the fee limits
+        *test_sdk.wallet.tx_vsize.lock().unwrap() *= 2;
+        let mut at_limit = prepared.clone();
+        at_limit.max_fee_sat = Some(prepared.fees_sat);
+        assert!(matches!(
+            sdk.send_payment(&at_limit).await,
+            Err(PaymentError::FeeLimitExceeded { .. })
+        ));
+
+        // Within the limits, the swap is created with the re-estimated lockup tx fee
+        assert!(matches!(
+            sdk.send_payment(&prepared).await,
+            Err(PaymentError::PaymentTimeout)
+        ));
+        let swap = test_sdk
+            .persister
+            .fetch_send_swap_by_invoice(&prepared.invoice)?
+            .expect("Expecting a send swap");
+        let fee_breakdown = swap.fee_breakdown.expect("Expecting a fee breakdown");
+        let network_fee_sat = 2 * prepared.fee_breakdown.network_fee_sat;
+        assert_eq!(fee_breakdown.network_fee_sat, network_fee_sat);
+        assert_eq!(
+            fee_breakdown.service_fee_sat,
+            prepared.fee_breakdown.service_fee_sat
+        );
+        assert_eq!(
+            fee_breakdown.swapper_miner_fees_sat,
+            prepared.fee_breakdown.swapper_miner_fees_sat
+        );
+        assert_eq!(
+            fee_breakdown.total_fees_sat,
+            prepared.fees_sat + prepared.fee_breakdown.network_fee_sat
+        );
+        assert_eq!(
+            swap.payer_amount_sat,
+            swap.receiver_amount_sat + fee_breakdown.total_fees_sat
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn receive_payment_fee_quote() -> Result<()> {
+        let test_sdk = new_test_sdk().await?;
+        let sdk = &test_sdk.sdk;
+
+        let prepared = sdk
+            .prepare_receive_payment(&PrepareReceiveRequest {
+                payer_amount_sat: Some(50_000),
+                receiver_amount_sat: None,
+                description: Some("Receive fee quote".to_string()),
+                description_hash: None,
+                invoice_expiry_secs: None,
+                metadata: None,
+                max_fee_sat: None,
+                max_fee_percent: None,
+                fee_rate: None,
+            })
+            .await?;
+
+        let mut expired = prepared.clone();
+        expired.expires_at = utils::now() - 1;
+        let mut receiver_amount_mismatch = prepared.clone();
+        receiver_amount_mismatch.receiver_amount_sat += 1;
+        let mut components_mismatch = prepared.clone();
+        components_mismatch.fees_sat += 1;
+        components_mismatch.receiver_amount_sat -= 1;
+        components_mismatch.fee_breakdown.total_fees_sat += 1;
+        // A lower claim fee than the one quoted for the fee rate, consistent in every field
+        let mut lower_claim_fee = prepared.clone();
+        lower_claim_fee.fees_sat -= 10;
+        lower_claim_fee.receiver_amount_sat += 10;
+        lower_claim_fee.fee_breakdown.network_fee_sat -= 10;
+        lower_claim_fee.fee_breakdown.total_fees_sat -= 10;
+        let mut pair_hash_mismatch = prepared.clone();
+        pair_hash_mismatch.pair_hash = "other-pair-hash".to_string();
+        for req in [
+            expired,
+            receiver_amount_mismatch,
+            components_mismatch,
+            lower_claim_fee,
+            pair_hash_mismatch,
+        ] {
+            assert!(matches!(
+                sdk.receive_payment(&req).await,
+                Err(PaymentError::InvalidOrExpiredFees)
+            ));
+        }
+
+        let mut updated_pair = new_reverse_pair();
+        updated_pair.hash = "updated-pair-hash".to_string();
+        *test_sdk.swapper.reverse_pair.lock().unwrap() = Some(updated_pair);
+        assert!(matches!(
+            sdk.receive_payment(&prepared).await,
+            Err(PaymentError::InvalidOrExpiredFees)
+        ));
+        *test_sdk.swapper.reverse_pair.lock().unwrap() = Some(new_reverse_pair());
+
+        let res = sdk.receive_payment(&prepared).await?;
+        let swap = test_sdk
+            .persister
+            .fetch_receive_swap(&res.id)?
+            .expect("Expecting a receive swap");
+        assert_eq!(swap.claim_fees_sat, prepared.fee_breakdown.network_fee_sat);
+        assert_eq!(swap.receiver_amount_sat, prepared.receiver_amount_sat);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn receive_payment_custom_fee_rate() -> Result<()> {
+        let test_sdk = new_test_sdk().await?;
+        let sdk = &test_sdk.sdk;
+        let prepare = |fee_rate| async move {
+            sdk.prepare_receive_payment(&PrepareReceiveRequest {
+                payer_amount_sat: Some(50_000),
+                receiver_amount_sat: None,
+                description: None,
+                description_hash: None,
+                invoice_expiry_secs: None,
+                metadata: None,
+                max_fee_sat: None,
+                max_fee_percent: None,
+                fee_rate,
+            })
+            .await
+        };
+
+        let default_prepared = prepare(None).await?;
+        let prepared = prepare(Some(OnchainFeeRate::SatPerVbyte { sat_per_vbyte: 0.2 })).await?;
+        // The claim fee scales with the claim fee rate, from the 0.1 sat/vbyte of the estimate
+        assert_eq!(
+            prepared.fee_breakdown.network_fee_sat,
+            2 * default_prepared.fee_breakdown.network_fee_sat
+        );
+        assert_eq!(
+            prepared.receiver_amount_sat + prepared.fee_breakdown.network_fee_sat,
+            default_prepared.receiver_amount_sat + default_prepared.fee_breakdown.network_fee_sat
+        );
+
+        // The MRH of the invoice only has the swapper fees taken off
+        let res = sdk.receive_payment(&prepared).await?;
+        let swap = test_sdk
+            .persister
+            .fetch_receive_swap(&res.id)?
+            .expect("Expecting a receive swap");
+        assert_eq!(swap.claim_fees_sat, prepared.fee_breakdown.network_fee_sat);
+        assert_eq!(swap.receiver_amount_sat, prepared.receiver_amount_sat);
+        assert_eq!(swap.fee_rate_sat_per_vbyte, Some(0.2));
+        let (_, mrh_amount_btc) = test_sdk
+            .swapper
+            .mrh
+            .lock()
+            .unwrap()
+            .get(&swap.invoice)
+            .cloned()
+            .expect("Expecting an MRH");
+        assert_eq!(
+            (mrh_amount_btc * 100_000_000.0).round() as u64,
+            default_prepared.receiver_amount_sat
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn watched_scripts() -> Result<()> {
+        use lwk_wollet::elements::Script;
+
+        let test_sdk = new_test_sdk().await?;
+        let wallet_scripts = vec![Script::from(vec![0x51]), Script::from(vec![0x52])];
+        *test_sdk.wallet.watched_scripts.lock().unwrap() = wallet_scripts.clone();
+        assert_eq!(test_sdk.sdk.watched_scripts().await?, wallet_scripts);
+
+        // An ongoing swap whose script cannot be derived is not watched, without failing
+        test_sdk
+            .persister
+            .insert_send_swap(&new_send_swap("send", PaymentState::Pending))?;
+        assert_eq!(test_sdk.sdk.watched_scripts().await?, wallet_scripts);
+
+        Ok(())
+    }
 }
@@ -11,9 +11,9 @@ use lwk_wollet::elements::Transaction;
 use lwk_wollet::hashes::{sha256, Hash};
 use tokio::sync::broadcast;
 
-use crate::model::PaymentState::{Complete, Created, Failed, Pending, TimedOut};
+use crate::chain::ChainService;
+use crate::model::PaymentState::{Complete, Created, Failed, Pending, Refundable, TimedOut};
 use crate::model::{Config, SendSwap};
-use crate::sdk::ChainService;
 use crate::swapper::Swapper;
 use crate::wallet::OnchainWallet;
 use crate::{ensure_sdk, get_invoice_amount};
@@ -73,7 +73,9 @@ impl SendSwapStateHandler {
                 match (swap.state, swap.lockup_tx_id.clone()) {
                     (PaymentState::Created, None) | (PaymentState::TimedOut, None) => {
                         let create_response = swap.get_boltz_create_response()?;
-                        let lockup_tx = self.lockup_funds(id, &create_response).await?;
+                        let lockup_tx = self
+                            .lockup_funds(id, &create_response, swap.fee_rate_sat_per_vbyte)
+                            .await?;
                         let lockup_tx_id = lockup_tx.txid().to_string();
                         let lockup_tx_fees_sat: u64 = lockup_tx.all_fees().values().sum();
 
@@ -86,6 +88,9 @@ impl SendSwapStateHandler {
                             fees_sat: lockup_tx_fees_sat,
                             payment_type: PaymentType::Send,
                             is_confirmed: false,
+                            asset_id: None,
+                            asset_amount: None,
+                            fee_rate_sat_per_vbyte: swap.fee_rate_sat_per_vbyte,
                         })?;
 
                         self.update_swap_info(id, Pending, None, Some(&lockup_tx_id), None)
@@ -185,6 +190,7 @@ impl SendSwapStateHandler {
         &self,
         swap_id: &str,
         create_response: &CreateSubmarineResponse,
+        fee_rate_sat_per_vbyte: Option<f64>,
     ) -> Result<Transaction, PaymentError> {
         debug!(
             "Initiated Send Swap: send {} sats to liquid address {}",
@@ -194,7 +200,7 @@ impl SendSwapStateHandler {
         let lockup_tx = self
             .onchain_wallet
             .build_tx(
-                None,
+                fee_rate_sat_per_vbyte,
                 &create_response.address,
                 create_response.expected_amount,
             )
@@ -274,12 +280,7 @@ impl SendSwapStateHandler {
         debug!("Found Send Swap swap_script_pk: {swap_script_pk:?}");
 
         // Get tx history of the swap script (lockup address)
-        let history: Vec<_> = self
-            .chain_service
-            .get_scripts_history(&[&swap_script_pk])?
-            .into_iter()
-            .flatten()
-            .collect();
+        let history = self.chain_service.get_script_history(&swap_script_pk)?;
 
         // We expect at most 2 txs: lockup and maybe the claim
         ensure_sdk!(
@@ -293,8 +294,7 @@ impl SendSwapStateHandler {
             None => Err(PaymentError::Generic {
                 err: format!("Send Swap {id} has no claim tx"),
             }),
-            Some(claim_tx_entry) => {
-                let claim_tx_id = claim_tx_entry.txid;
+            Some(&claim_tx_id) => {
                 debug!("Send Swap {id} has claim tx {claim_tx_id}");
 
                 let claim_tx = self
@@ -344,7 +344,7 @@ impl SendSwapStateHandler {
 
         let fee = self
             .onchain_wallet
-            .build_tx(None, &output_address, amount_sat)
+            .build_tx(swap.fee_rate_sat_per_vbyte, &output_address, amount_sat)
             .await?
             .all_fees()
             .values()
@@ -373,7 +373,7 @@ impl SendSwapStateHandler {
             &swap.id
         );
 
-        let current_height = self.onchain_wallet.tip().await.height();
+        let current_height = self.chain_service.tip()?;
         let output_address = self.onchain_wallet.next_unused_address().await?.to_string();
         let refund_tx_id = self.swapper.refund_send_swap_non_cooperative(
             swap,
@@ -399,12 +399,12 @@ impl SendSwapStateHandler {
             }),
 
             (Created | Pending, Pending) => Ok(()),
-            (Complete | Failed | TimedOut, Pending) => Err(PaymentError::Generic {
+            (Complete | Failed | TimedOut | Refundable, Pending) => Err(PaymentError::Generic {
                 err: format!("Cannot transition from {from_state:?} to Pending state"),
             }),
 
             (Created | Pending, Complete) => Ok(()),
-            (Complete | Failed | TimedOut, Complete) => Err(PaymentError::Generic {
+            (Complete | Failed | TimedOut | Refundable, Complete) => Err(PaymentError::Generic {
                 err: format!("Cannot transition from {from_state:?} to Complete state"),
             }),
 
@@ -413,6 +413,10 @@ impl SendSwapStateHandler {
                 err: format!("Cannot transition from {from_state:?} to TimedOut state"),
             }),
 
+            (_, Refundable) => Err(PaymentError::Generic {
+                err: format!("Cannot transition from {from_state:?} to Refundable state"),
+            }),
+
             (_, Failed) => Ok(()),
         }
     }
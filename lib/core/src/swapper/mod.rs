@@ -1,7 +1,8 @@
 mod boltz_status_stream;
 
 use std::str::FromStr;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 use anyhow::Result;
 use async_trait::async_trait;
@@ -9,21 +10,30 @@ use boltz_client::error::Error;
 use boltz_client::network::electrum::ElectrumConfig;
 use boltz_client::network::Chain;
 use boltz_client::swaps::boltzv2::{
-    self, BoltzApiClientV2, ClaimTxResponse, CreateReverseRequest, CreateReverseResponse,
-    CreateSubmarineRequest, CreateSubmarineResponse, ReversePair, SubmarinePair,
+    self, BoltzApiClientV2, ChainPair, ClaimTxResponse, CreateChainRequest, CreateChainResponse,
+    CreateReverseRequest, CreateReverseResponse, CreateSubmarineRequest, CreateSubmarineResponse,
+    ReversePair, SubmarinePair,
 };
 use boltz_client::util::secrets::Preimage;
-use boltz_client::{Amount, Bolt11Invoice, LBtcSwapTxV2};
+use boltz_client::{Amount, Bolt11Invoice, BtcSwapTxV2, LBtcSwapTxV2};
 use boltz_status_stream::BoltzStatusStream;
 use log::{debug, info};
-use lwk_wollet::elements::LockTime;
+use lwk_wollet::elements::{encode, LockTime, Transaction};
+use lwk_wollet::secp256k1::PublicKey;
+use serde::Deserialize;
 use serde_json::Value;
 use tokio::sync::{broadcast, watch};
 
+use crate::chain::ChainService;
+use crate::electrum::ElectrumServers;
 use crate::error::PaymentError;
-use crate::model::{Config, Network, ReceiveSwap, SendSwap};
+use crate::model::{ChainSwap, Config, Direction, Network, ReceiveSwap, SendSwap};
 use crate::utils;
 
+/// How long fetched pairs are reused, so that consecutive calls (e.g. preparing and then making
+/// a payment) do not each fetch them
+const PAIRS_CACHE_TTL: Duration = Duration::from_secs(30);
+
 #[async_trait]
 pub trait ReconnectHandler: Send + Sync {
     async fn on_stream_reconnect(&self);
@@ -41,6 +51,48 @@ pub trait SwapperStatusStream: Send + Sync {
 }
 
 pub trait Swapper: Send + Sync {
+    /// Create a new chain swap
+    fn create_chain_swap(
+        &self,
+        req: CreateChainRequest,
+    ) -> Result<CreateChainResponse, PaymentError>;
+
+    /// Get a chain pair information
+    fn get_chain_pairs(&self, direction: Direction) -> Result<Option<ChainPair>, PaymentError>;
+
+    /// Claim chain swap. Here the local swapper is the one that claims.
+    ///
+    /// For Incoming swaps the L-BTC server lockup is claimed, for Outgoing swaps the BTC one.
+    fn claim_chain_swap(
+        &self,
+        swap: &ChainSwap,
+        claim_address: String,
+    ) -> Result<String, PaymentError>;
+
+    /// Refund a chain swap cooperatively
+    fn refund_chain_swap_cooperative(
+        &self,
+        swap: &ChainSwap,
+        output_address: &str,
+        broadcast_fees_sat: Amount,
+    ) -> Result<String, PaymentError>;
+
+    /// Refund a chain swap non-cooperatively. The refund tx is rejected by the chain until the
+    /// lockup timelock has expired.
+    fn refund_chain_swap_non_cooperative(
+        &self,
+        swap: &ChainSwap,
+        broadcast_fees_sat: Amount,
+        output_address: &str,
+    ) -> Result<String, PaymentError>;
+
+    /// Get the vsize of a chain swap refund tx, used to estimate its fees
+    fn get_chain_swap_refund_tx_vsize(
+        &self,
+        swap: &ChainSwap,
+        output_address: &str,
+    ) -> Result<u32, PaymentError>;
+
     /// Create a new send swap
     fn create_send_swap(
         &self,
@@ -67,6 +119,13 @@ pub trait Swapper: Send + Sync {
         current_height: u32,
     ) -> Result<String, PaymentError>;
 
+    /// Get the vsize of a send swap refund tx, used to estimate its fees
+    fn get_send_swap_refund_tx_vsize(
+        &self,
+        swap: &SendSwap,
+        output_address: &str,
+    ) -> Result<u32, PaymentError>;
+
     /// Get claim tx details which includes the preimage as a proof of payment.
     /// It is used to validate the preimage before claiming which is the reason why we need to separate
     /// the claim into two steps.
@@ -104,29 +163,182 @@ pub trait Swapper: Send + Sync {
 
     /// Look for a valid Magic Routing Hint. If found, validate it and extract the BIP21 info (amount, address).
     fn check_for_mrh(&self, invoice: &str) -> Result<Option<(String, f64)>, PaymentError>;
+
+    /// List the IDs of the swaps created with the given claim or refund public key
+    fn list_swap_ids_by_public_key(
+        &self,
+        public_key: &PublicKey,
+    ) -> Result<Vec<String>, PaymentError>;
+}
+
+/// A swap returned by the swapper when restoring the swaps of a public key
+#[derive(Deserialize)]
+struct RestorableSwap {
+    id: String,
 }
 
 pub struct BoltzSwapper {
     client: BoltzApiClientV2,
     config: Config,
-    electrum_config: ElectrumConfig,
+    electrum_servers: Arc<ElectrumServers>,
+    liquid_chain_service: Arc<dyn ChainService>,
+    bitcoin_electrum_config: ElectrumConfig,
+    submarine_pairs_cache: Mutex<Option<(Instant, SubmarinePair)>>,
+    reverse_pairs_cache: Mutex<Option<(Instant, ReversePair)>>,
 }
 
 impl BoltzSwapper {
-    pub fn new(config: Config) -> BoltzSwapper {
+    pub(crate) fn new(
+        config: Config,
+        electrum_servers: Arc<ElectrumServers>,
+        liquid_chain_service: Arc<dyn ChainService>,
+    ) -> BoltzSwapper {
+        let bitcoin_electrum_server = config.bitcoin_electrum_server();
         BoltzSwapper {
             client: BoltzApiClientV2::new(&config.boltz_url),
             config: config.clone(),
-            electrum_config: ElectrumConfig::new(
-                config.network.into(),
-                &config.electrum_url,
-                true,
-                true,
+            electrum_servers,
+            liquid_chain_service,
+            bitcoin_electrum_config: ElectrumConfig::new(
+                config.network.as_bitcoin_chain(),
+                &bitcoin_electrum_server.url,
+                bitcoin_electrum_server.tls,
+                bitcoin_electrum_server.validate_domain,
                 100,
             ),
+            submarine_pairs_cache: Mutex::new(None),
+            reverse_pairs_cache: Mutex::new(None),
         }
     }
 
+    /// Runs a request against the Liquid Electrum servers, failing over to the next one on
+    /// connection errors
+    fn with_liquid_electrum<T>(
+        &self,
+        mut request: impl FnMut(&ElectrumConfig) -> Result<T, Error>,
+    ) -> Result<T, PaymentError> {
+        self.electrum_servers.with_failover(|_, server| {
+            Ok(request(&ElectrumConfig::new(
+                self.config.network.into(),
+                &server.url,
+                server.tls,
+                server.validate_domain,
+                100,
+            ))?)
+        })
+    }
+
+    /// Whether Send Swap refunds are broadcast by the swapper. On Testnet their lowball fees may
+    /// be rejected by public nodes, unless an Esplora backend is configured to broadcast them.
+    fn refunds_via_swapper(&self) -> bool {
+        self.config.network == Network::Testnet && self.config.esplora_url.is_none()
+    }
+
+    /// Broadcasts a Liquid swap tx, returning its id. Claims paying the fee estimated by the
+    /// swapper are below the relay fee of public nodes, so the swapper broadcasts them, as well as
+    /// the refunds of [BoltzSwapper::refunds_via_swapper]. Any other tx goes through the
+    /// [ChainService].
+    fn broadcast_liquid_tx(
+        &self,
+        tx: &Transaction,
+        via_swapper: bool,
+    ) -> Result<String, PaymentError> {
+        match via_swapper {
+            true => {
+                self.broadcast_tx(self.config.network.into(), &encode::serialize_hex(tx))?;
+                Ok(tx.txid().to_string())
+            }
+            false => Ok(self.liquid_chain_service.broadcast(tx)?.to_string()),
+        }
+    }
+
+    /// Returns the cached pairs if they are recent enough, else fetches and caches them. Missing
+    /// pairs are not cached, so they are fetched again on the next call.
+    fn get_cached_pairs<T: Clone>(
+        cache: &Mutex<Option<(Instant, T)>>,
+        fetch: impl FnOnce() -> Result<Option<T>, PaymentError>,
+    ) -> Result<Option<T>, PaymentError> {
+        let mut cache = cache.lock().map_err(|_| PaymentError::Generic {
+            err: "Pairs cache is poisoned".to_string(),
+        })?;
+        if let Some((fetched_at, pairs)) = cache.as_ref() {
+            if fetched_at.elapsed() < PAIRS_CACHE_TTL {
+                return Ok(Some(pairs.clone()));
+            }
+        }
+
+        let pairs = fetch()?;
+        *cache = pairs.clone().map(|pairs| (Instant::now(), pairs));
+        Ok(pairs)
+    }
+
+    fn new_chain_refund_tx_lbtc(
+        &self,
+        swap: &ChainSwap,
+        output_address: &str,
+    ) -> Result<LBtcSwapTxV2, PaymentError> {
+        let swap_script = swap.get_lockup_swap_script_lbtc()?;
+
+        self.with_liquid_electrum(|electrum_config| {
+            LBtcSwapTxV2::new_refund(
+                swap_script.clone(),
+                &output_address.to_string(),
+                electrum_config,
+                self.config.boltz_url.clone(),
+                swap.id.to_string(),
+            )
+        })
+    }
+
+    fn new_chain_refund_tx_btc(
+        &self,
+        swap: &ChainSwap,
+        output_address: &str,
+    ) -> Result<BtcSwapTxV2, PaymentError> {
+        Ok(BtcSwapTxV2::new_refund(
+            swap.get_lockup_swap_script_btc()?,
+            &output_address.to_string(),
+            &self.bitcoin_electrum_config,
+            self.config.boltz_url.clone(),
+            swap.id.to_string(),
+        )?)
+    }
+
+    fn refund_chain_swap(
+        &self,
+        swap: &ChainSwap,
+        output_address: &str,
+        broadcast_fees_sat: Amount,
+        is_cooperative: bool,
+    ) -> Result<String, PaymentError> {
+        let refund_keypair = swap.get_refund_keypair()?;
+        let refund_tx_id = match swap.direction {
+            Direction::Incoming => {
+                let refund_tx = self.new_chain_refund_tx_btc(swap, output_address)?;
+                let cooperative = is_cooperative.then_some((&self.client, swap.id.clone()));
+                let tx = refund_tx.sign_refund(&refund_keypair, broadcast_fees_sat, cooperative)?;
+                refund_tx
+                    .broadcast(&tx, &self.bitcoin_electrum_config)?
+                    .to_string()
+            }
+            Direction::Outgoing => {
+                let refund_tx = self.new_chain_refund_tx_lbtc(swap, output_address)?;
+                let cooperative = is_cooperative.then_some((&self.client, &swap.id));
+                let tx = refund_tx.sign_refund(&refund_keypair, broadcast_fees_sat, cooperative)?;
+                self.broadcast_liquid_tx(&tx, false)?
+            }
+        };
+        info!(
+            "Successfully broadcast {} refund for Chain Swap {}, tx: {refund_tx_id}",
+            match is_cooperative {
+                true => "cooperative",
+                false => "non-cooperative",
+            },
+            swap.id
+        );
+        Ok(refund_tx_id)
+    }
+
     fn new_refund_tx(
         &self,
         swap: &SendSwap,
@@ -134,13 +346,15 @@ impl BoltzSwapper {
     ) -> Result<LBtcSwapTxV2, PaymentError> {
         let swap_script = swap.get_swap_script()?;
 
-        Ok(LBtcSwapTxV2::new_refund(
-            swap_script.clone(),
-            output_address,
-            &self.electrum_config,
-            self.config.boltz_url.clone(),
-            swap.id.to_string(),
-        )?)
+        self.with_liquid_electrum(|electrum_config| {
+            LBtcSwapTxV2::new_refund(
+                swap_script.clone(),
+                output_address,
+                electrum_config,
+                self.config.boltz_url.clone(),
+                swap.id.to_string(),
+            )
+        })
     }
 
     fn validate_send_swap_preimage(
@@ -168,6 +382,123 @@ impl BoltzSwapper {
 }
 
 impl Swapper for BoltzSwapper {
+    /// Create a new chain swap
+    fn create_chain_swap(
+        &self,
+        req: CreateChainRequest,
+    ) -> Result<CreateChainResponse, PaymentError> {
+        Ok(self.client.post_chain_req(req)?)
+    }
+
+    /// Get a chain pair information
+    fn get_chain_pairs(&self, direction: Direction) -> Result<Option<ChainPair>, PaymentError> {
+        let pairs = self.client.get_chain_pairs()?;
+        let pair = match direction {
+            Direction::Incoming => pairs.get_btc_to_lbtc_pair(),
+            Direction::Outgoing => pairs.get_lbtc_to_btc_pair(),
+        };
+        Ok(pair)
+    }
+
+    /// Claim chain swap. Here the local swapper is the one that claims.
+    ///
+    /// For Incoming swaps the L-BTC server lockup is claimed, for Outgoing swaps the BTC one.
+    fn claim_chain_swap(
+        &self,
+        swap: &ChainSwap,
+        claim_address: String,
+    ) -> Result<String, PaymentError> {
+        let swap_id = &swap.id;
+        let claim_keypair = swap.get_claim_keypair()?;
+        let preimage = Preimage::from_str(&swap.preimage)?;
+
+        // A cooperative chain swap claim requires signing the server claim of the user lockup in
+        // the same step, so the claim is done via the script path.
+        let claim_tx_id = match swap.direction {
+            Direction::Incoming => {
+                let swap_script = swap.get_claim_swap_script_lbtc()?;
+                let claim_tx_wrapper = self.with_liquid_electrum(|electrum_config| {
+                    LBtcSwapTxV2::new_claim(
+                        swap_script.clone(),
+                        claim_address.clone(),
+                        electrum_config,
+                        self.config.boltz_url.clone(),
+                        swap_id.clone(),
+                    )
+                })?;
+                let claim_fees_sat = Amount::from_sat(swap.claim_fees_sat);
+                let claim_tx =
+                    claim_tx_wrapper.sign_claim(&claim_keypair, &preimage, claim_fees_sat, None)?;
+                self.broadcast_liquid_tx(&claim_tx, swap.fee_rate_sat_per_vbyte.is_none())?
+            }
+            Direction::Outgoing => {
+                let claim_tx_wrapper = BtcSwapTxV2::new_claim(
+                    swap.get_claim_swap_script_btc()?,
+                    claim_address,
+                    &self.bitcoin_electrum_config,
+                    self.config.boltz_url.clone(),
+                    swap_id.clone(),
+                )?;
+                let claim_fees_sat = Amount::from_sat(swap.claim_fees_sat);
+                let claim_tx =
+                    claim_tx_wrapper.sign_claim(&claim_keypair, &preimage, claim_fees_sat, None)?;
+                claim_tx_wrapper
+                    .broadcast(&claim_tx, &self.bitcoin_electrum_config)?
+                    .to_string()
+            }
+        };
+        info!("Successfully broadcast claim tx {claim_tx_id} for Chain Swap {swap_id}");
+        Ok(claim_tx_id)
+    }
+
+    /// Refund a chain swap cooperatively
+    fn refund_chain_swap_cooperative(
+        &self,
+        swap: &ChainSwap,
+        output_address: &str,
+        broadcast_fees_sat: Amount,
+    ) -> Result<String, PaymentError> {
+        info!("Initiating cooperative refund for Chain Swap {}", &swap.id);
+        self.refund_chain_swap(swap, output_address, broadcast_fees_sat, true)
+    }
+
+    /// Refund a chain swap non-cooperatively. The refund tx is rejected by the chain until the
+    /// lockup timelock has expired.
+    fn refund_chain_swap_non_cooperative(
+        &self,
+        swap: &ChainSwap,
+        broadcast_fees_sat: Amount,
+        output_address: &str,
+    ) -> Result<String, PaymentError> {
+        info!(
+            "Initiating non-cooperative refund for Chain Swap {}",
+            &swap.id
+        );
+        self.refund_chain_swap(swap, output_address, broadcast_fees_sat, false)
+    }
+
+    /// Get the vsize of a chain swap refund tx, used to estimate its fees
+    fn get_chain_swap_refund_tx_vsize(
+        &self,
+        swap: &ChainSwap,
+        output_address: &str,
+    ) -> Result<u32, PaymentError> {
+        // The tx is signed with a placeholder fee, as the fee does not change the tx size
+        let refund_keypair = swap.get_refund_keypair()?;
+        let placeholder_fees_sat = Amount::from_sat(1);
+        let vsize = match swap.direction {
+            Direction::Incoming => self
+                .new_chain_refund_tx_btc(swap, output_address)?
+                .sign_refund(&refund_keypair, placeholder_fees_sat, None)?
+                .vsize(),
+            Direction::Outgoing => self
+                .new_chain_refund_tx_lbtc(swap, output_address)?
+                .sign_refund(&refund_keypair, placeholder_fees_sat, None)?
+                .vsize(),
+        };
+        Ok(vsize as u32)
+    }
+
     /// Create a new send swap
     fn create_send_swap(
         &self,
@@ -178,7 +509,9 @@ impl Swapper for BoltzSwapper {
 
     /// Get a submarine pair information
     fn get_submarine_pairs(&self) -> Result<Option<SubmarinePair>, PaymentError> {
-        Ok(self.client.get_submarine_pairs()?.get_lbtc_to_btc_pair())
+        Self::get_cached_pairs(&self.submarine_pairs_cache, || {
+            Ok(self.client.get_submarine_pairs()?.get_lbtc_to_btc_pair())
+        })
     }
 
     /// Refund a cooperatively send swap  
@@ -199,11 +532,7 @@ impl Swapper for BoltzSwapper {
             broadcast_fees_sat,
             cooperative,
         )?;
-        let is_lowball = match self.config.network {
-            Network::Mainnet => None,
-            Network::Testnet => Some((&self.client, boltz_client::network::Chain::LiquidTestnet)),
-        };
-        let refund_tx_id = refund_tx.broadcast(&tx, &self.electrum_config, is_lowball)?;
+        let refund_tx_id = self.broadcast_liquid_tx(&tx, self.refunds_via_swapper())?;
         info!(
             "Successfully broadcast cooperative refund for Send Swap {}",
             &swap.id
@@ -243,11 +572,7 @@ impl Swapper for BoltzSwapper {
             broadcast_fees_sat,
             None,
         )?;
-        let is_lowball = match self.config.network {
-            Network::Mainnet => None,
-            Network::Testnet => Some((&self.client, boltz_client::network::Chain::LiquidTestnet)),
-        };
-        let refund_tx_id = refund_tx.broadcast(&tx, &self.electrum_config, is_lowball)?;
+        let refund_tx_id = self.broadcast_liquid_tx(&tx, self.refunds_via_swapper())?;
         info!(
             "Successfully broadcast non-cooperative refund for swap-in {}",
             swap.id
@@ -255,6 +580,20 @@ impl Swapper for BoltzSwapper {
         Ok(refund_tx_id)
     }
 
+    /// Get the vsize of a send swap refund tx, used to estimate its fees
+    fn get_send_swap_refund_tx_vsize(
+        &self,
+        swap: &SendSwap,
+        output_address: &str,
+    ) -> Result<u32, PaymentError> {
+        // The tx is signed with a placeholder fee, as the fee does not change the tx size
+        let vsize = self
+            .new_refund_tx(swap, &output_address.into())?
+            .sign_refund(&swap.get_refund_keypair()?, Amount::from_sat(1), None)?
+            .vsize();
+        Ok(vsize as u32)
+    }
+
     /// Get claim tx details which includes the preimage as a proof of payment.
     /// It is used to validate the preimage before claiming which is the reason why we need to separate
     /// the claim into two steps.
@@ -298,7 +637,9 @@ impl Swapper for BoltzSwapper {
 
     // Get a reverse pair information
     fn get_reverse_swap_pairs(&self) -> Result<Option<ReversePair>, PaymentError> {
-        Ok(self.client.get_reverse_pairs()?.get_btc_to_lbtc_pair())
+        Self::get_cached_pairs(&self.reverse_pairs_cache, || {
+            Ok(self.client.get_reverse_pairs()?.get_btc_to_lbtc_pair())
+        })
     }
 
     /// Claim receive swap. Here the local swapper is the one that claims.
@@ -309,29 +650,33 @@ impl Swapper for BoltzSwapper {
     ) -> Result<String, PaymentError> {
         let swap_script = swap.get_swap_script()?;
         let swap_id = &swap.id;
-        let claim_tx_wrapper = LBtcSwapTxV2::new_claim(
-            swap_script,
-            claim_address,
-            &self.electrum_config,
-            self.config.boltz_url.clone(),
-            swap.id.clone(),
-        )?;
+        let claim_tx_wrapper = self.with_liquid_electrum(|electrum_config| {
+            LBtcSwapTxV2::new_claim(
+                swap_script.clone(),
+                claim_address.clone(),
+                electrum_config,
+                self.config.boltz_url.clone(),
+                swap.id.clone(),
+            )
+        })?;
+
+        let claim_keypair = swap.get_claim_keypair()?;
+        let preimage = Preimage::from_str(&swap.preimage)?;
+        // The claim fee quoted for the swap, at its fee rate if one was chosen
+        let claim_fees_sat = Amount::from_sat(swap.claim_fees_sat);
 
         let cooperative = Some((&self.client, swap.id.clone()));
         let claim_tx = claim_tx_wrapper.sign_claim(
-            &swap.get_claim_keypair()?,
-            &Preimage::from_str(&swap.preimage)?,
-            Amount::from_sat(swap.claim_fees_sat),
+            &claim_keypair,
+            &preimage,
+            claim_fees_sat,
             // Enable cooperative claim (Some) or not (None)
             cooperative,
             // None
         )?;
 
-        let claim_tx_id = claim_tx_wrapper.broadcast(
-            &claim_tx,
-            &self.electrum_config,
-            Some((&self.client, self.config.network.into())),
-        )?;
+        let claim_tx_id =
+            self.broadcast_liquid_tx(&claim_tx, swap.fee_rate_sat_per_vbyte.is_none())?;
         info!("Successfully broadcast claim tx {claim_tx_id} for Receive Swap {swap_id}");
         debug!("Claim Tx {:?}", claim_tx);
         Ok(claim_tx_id)
@@ -353,4 +698,85 @@ impl Swapper for BoltzSwapper {
         )
         .map_err(Into::into)
     }
+
+    fn list_swap_ids_by_public_key(
+        &self,
+        public_key: &PublicKey,
+    ) -> Result<Vec<String>, PaymentError> {
+        // The Boltz client has no call to restore swaps, so its errors are used for this request
+        let url = format!("{}/swap/restore", self.config.boltz_url);
+        let body = serde_json::json!({ "publicKey": public_key.to_string() }).to_string();
+        let restore = || -> Result<Vec<RestorableSwap>, boltz_client::error::Error> {
+            let response = ureq::post(&url)
+                .set("Content-Type", "application/json")
+                .send_string(&body)?
+                .into_string()?;
+            Ok(serde_json::from_str(&response)?)
+        };
+        Ok(restore()?.into_iter().map(|swap| swap.id).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+    use std::time::Instant;
+
+    use anyhow::Result;
+
+    use crate::error::PaymentError;
+    use crate::swapper::{BoltzSwapper, PAIRS_CACHE_TTL};
+
+    #[test]
+    fn get_cached_pairs() -> Result<()> {
+        let cache: Mutex<Option<(Instant, u32)>> = Mutex::new(None);
+        let fetch_count = Mutex::new(0);
+        let fetch = |pairs: Option<u32>| {
+            let fetch_count = &fetch_count;
+            move || -> Result<Option<u32>, PaymentError> {
+                *fetch_count.lock().unwrap() += 1;
+                Ok(pairs)
+            }
+        };
+
+        // Missing pairs are not cached
+        assert_eq!(BoltzSwapper::get_cached_pairs(&cache, fetch(None))?, None);
+        assert!(cache.lock().unwrap().is_none());
+        assert_eq!(
+            BoltzSwapper::get_cached_pairs(&cache, fetch(Some(1)))?,
+            Some(1)
+        );
+        assert_eq!(*fetch_count.lock().unwrap(), 2);
+
+        // Recent pairs are reused
+        assert_eq!(
+            BoltzSwapper::get_cached_pairs(&cache, fetch(Some(2)))?,
+            Some(1)
+        );
+        assert_eq!(*fetch_count.lock().unwrap(), 2);
+
+        // Expired pairs are fetched again
+        let expired_at = Instant::now()
+            .checked_sub(PAIRS_CACHE_TTL)
+            .expect("Expecting a valid instant");
+        cache.lock().unwrap().as_mut().unwrap().0 = expired_at;
+        assert_eq!(
+            BoltzSwapper::get_cached_pairs(&cache, fetch(Some(3)))?,
+            Some(3)
+        );
+        assert_eq!(*fetch_count.lock().unwrap(), 3);
+
+        // Fetch errors are returned, and the expired pairs are not reused
+        cache.lock().unwrap().as_mut().unwrap().0 = expired_at;
+        assert!(
+            BoltzSwapper::get_cached_pairs(&cache, || -> Result<Option<u32>, PaymentError> {
+                Err(PaymentError::PairsNotFound)
+            })
+            .is_err()
+        );
+        assert_eq!(BoltzSwapper::get_cached_pairs(&cache, fetch(None))?, None);
+        assert!(cache.lock().unwrap().is_none());
+
+        Ok(())
+    }
 }
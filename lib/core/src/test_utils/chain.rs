@@ -0,0 +1,137 @@
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+
+use anyhow::Result;
+use lwk_wollet::elements::{Script, Transaction, Txid};
+use lwk_wollet::Wollet;
+
+use crate::chain::{ChainChanges, ChainService};
+use crate::error::PaymentError;
+
+/// [ChainService] serving the txs and script histories set by the test
+#[derive(Default)]
+pub(crate) struct MockChainService {
+    pub(crate) tip: Mutex<u32>,
+    pub(crate) transactions: Mutex<HashMap<Txid, Transaction>>,
+    pub(crate) script_histories: Mutex<HashMap<Script, Vec<Txid>>>,
+    pub(crate) broadcast_txs: Mutex<Vec<Transaction>>,
+    /// Returned by [ChainService::poll_changes], `None` if subscriptions are not supported
+    pub(crate) changes: Mutex<Option<ChainChanges>>,
+    pub(crate) polled_scripts: Mutex<Vec<Script>>,
+    pub(crate) full_scans: Mutex<u32>,
+}
+
+impl MockChainService {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl ChainService for MockChainService {
+    fn tip(&self) -> Result<u32, PaymentError> {
+        Ok(*self.tip.lock().unwrap())
+    }
+
+    fn broadcast(&self, tx: &Transaction) -> Result<Txid, PaymentError> {
+        self.broadcast_txs.lock().unwrap().push(tx.clone());
+        Ok(tx.txid())
+    }
+
+    fn get_transactions(&self, txids: &[Txid]) -> Result<Vec<Transaction>, PaymentError> {
+        let transactions = self.transactions.lock().unwrap();
+        Ok(txids
+            .iter()
+            .filter_map(|txid| transactions.get(txid).cloned())
+            .collect())
+    }
+
+    fn get_script_history(&self, script: &Script) -> Result<Vec<Txid>, PaymentError> {
+        Ok(self
+            .script_histories
+            .lock()
+            .unwrap()
+            .get(script)
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    fn full_scan(&self, _wallet: &mut Wollet) -> Result<(), PaymentError> {
+        *self.full_scans.lock().unwrap() += 1;
+        Ok(())
+    }
+
+    fn poll_changes(&self, scripts: &[Script]) -> Result<Option<ChainChanges>, PaymentError> {
+        *self.polled_scripts.lock().unwrap() = scripts.to_vec();
+        Ok(*self.changes.lock().unwrap())
+    }
+}
+
+/// A local stand-in Esplora server, answering each request path with a canned body, or with a
+/// 404 if there is none. A path ending with `*` matches every path with that prefix.
+pub(crate) struct EsploraStandIn {
+    pub(crate) url: String,
+    /// The path and body of each request received, in order
+    pub(crate) requests: Arc<Mutex<Vec<(String, String)>>>,
+}
+
+impl EsploraStandIn {
+    pub(crate) fn start(responses: Vec<(String, Vec<u8>)>) -> Result<Self> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let url = format!("http://{}", listener.local_addr()?);
+        let requests = Arc::new(Mutex::new(vec![]));
+        let received = requests.clone();
+        std::thread::spawn(move || {
+            for mut stream in listener.incoming().flatten() {
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                let path = request_line
+                    .split_whitespace()
+                    .nth(1)
+                    .unwrap_or_default()
+                    .to_string();
+
+                let mut content_length = 0;
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    if header.trim().is_empty() {
+                        break;
+                    }
+                    if let Some((name, value)) = header.split_once(':') {
+                        if name.eq_ignore_ascii_case("content-length") {
+                            content_length = value.trim().parse().unwrap();
+                        }
+                    }
+                }
+                let mut body = vec![0; content_length];
+                reader.read_exact(&mut body).unwrap();
+                received
+                    .lock()
+                    .unwrap()
+                    .push((path.clone(), String::from_utf8_lossy(&body).to_string()));
+
+                let response =
+                    responses
+                        .iter()
+                        .find(|(pattern, _)| match pattern.strip_suffix('*') {
+                            Some(prefix) => path.starts_with(prefix),
+                            None => *pattern == path,
+                        });
+                let (status, body) = match response {
+                    Some((_, body)) => ("200 OK", body.as_slice()),
+                    None => ("404 Not Found", &[][..]),
+                };
+                let headers = format!(
+                    "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                    body.len()
+                );
+                stream.write_all(headers.as_bytes()).unwrap();
+                stream.write_all(body).unwrap();
+            }
+        });
+        Ok(Self { url, requests })
+    }
+}
@@ -0,0 +1,66 @@
+use std::sync::Arc;
+
+use crate::chain_swap::ChainSwapStateHandler;
+use crate::model::{ChainSwap, Config, Direction, PaymentState};
+use crate::persist::chain::InternalCreateChainResponse;
+use crate::persist::Persister;
+use crate::test_utils::chain::MockChainService;
+use crate::test_utils::swapper::{new_create_chain_response, MockSwapper};
+use crate::test_utils::wallet::MockWallet;
+use crate::test_utils::{mock_hash, TEST_BITCOIN_ADDRESS, TEST_LIQUID_ADDRESS};
+use crate::utils;
+
+/// A chain swap of 50k sat in the given state, with no txs yet
+pub(crate) fn new_chain_swap(id: &str, direction: Direction, state: PaymentState) -> ChainSwap {
+    let payer_amount_sat = 50_000;
+    let receiver_amount_sat = 49_000;
+    let create_response = new_create_chain_response(id, direction, payer_amount_sat as u32);
+    let create_response_json = serde_json::to_string(
+        &InternalCreateChainResponse::try_convert_from_boltz(&create_response, id)
+            .expect("Expecting a valid create response"),
+    )
+    .expect("Expecting a serializable create response");
+    let (lockup_address, claim_address) = match direction {
+        Direction::Incoming => (TEST_BITCOIN_ADDRESS, None),
+        Direction::Outgoing => (TEST_LIQUID_ADDRESS, Some(TEST_BITCOIN_ADDRESS.to_string())),
+    };
+
+    ChainSwap {
+        id: id.to_string(),
+        direction,
+        claim_address,
+        lockup_address: lockup_address.to_string(),
+        timeout_block_height: 1_000,
+        preimage: mock_hash(&format!("preimage {id}")),
+        payer_amount_sat,
+        receiver_amount_sat,
+        claim_fees_sat: 20,
+        accept_zero_conf: true,
+        create_response_json,
+        server_lockup_tx_id: None,
+        user_lockup_tx_id: None,
+        claim_tx_id: None,
+        refund_tx_id: None,
+        created_at: utils::now(),
+        state,
+        claim_private_key: mock_hash(&format!("claim key {id}")),
+        refund_private_key: mock_hash(&format!("refund key {id}")),
+        key_index: Some(0),
+        fee_rate_sat_per_vbyte: None,
+    }
+}
+
+/// A testnet [ChainSwapStateHandler] on a mocked wallet
+pub(crate) fn new_chain_swap_state_handler(
+    persister: Arc<Persister>,
+    swapper: Arc<MockSwapper>,
+    chain_service: Arc<MockChainService>,
+) -> ChainSwapStateHandler {
+    ChainSwapStateHandler::new(
+        Config::testnet(),
+        Arc::new(MockWallet::new()),
+        persister,
+        swapper,
+        chain_service,
+    )
+}
@@ -0,0 +1,42 @@
+use std::sync::Mutex;
+
+use crate::error::PaymentError;
+use crate::fee_estimator::FeeEstimator;
+use crate::model::RecommendedFees;
+
+/// [FeeEstimator] returning the fee rates set by the test
+pub(crate) struct MockFeeEstimator {
+    pub(crate) liquid_fees: Mutex<RecommendedFees>,
+    pub(crate) bitcoin_fees: Mutex<RecommendedFees>,
+}
+
+impl MockFeeEstimator {
+    pub(crate) fn new() -> Self {
+        Self {
+            liquid_fees: Mutex::new(RecommendedFees {
+                fastest_fee: 0.5,
+                half_hour_fee: 0.25,
+                hour_fee: 0.2,
+                economy_fee: 0.1,
+                minimum_fee: 0.1,
+            }),
+            bitcoin_fees: Mutex::new(RecommendedFees {
+                fastest_fee: 20.0,
+                half_hour_fee: 10.0,
+                hour_fee: 5.0,
+                economy_fee: 2.0,
+                minimum_fee: 1.0,
+            }),
+        }
+    }
+}
+
+impl FeeEstimator for MockFeeEstimator {
+    fn recommended_liquid_fees(&self) -> Result<RecommendedFees, PaymentError> {
+        Ok(self.liquid_fees.lock().unwrap().clone())
+    }
+
+    fn recommended_bitcoin_fees(&self) -> Result<RecommendedFees, PaymentError> {
+        Ok(self.bitcoin_fees.lock().unwrap().clone())
+    }
+}
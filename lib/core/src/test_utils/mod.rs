@@ -0,0 +1,58 @@
+//! Mocks and builders shared by the unit tests
+
+pub(crate) mod chain;
+pub(crate) mod chain_swap;
+pub(crate) mod fee_estimator;
+pub(crate) mod persist;
+pub(crate) mod rest_client;
+pub(crate) mod sdk;
+pub(crate) mod status_stream;
+pub(crate) mod swapper;
+pub(crate) mod wallet;
+
+use std::time::Duration;
+
+use boltz_client::lightning_invoice::{Currency, InvoiceBuilder, PaymentSecret};
+use lwk_wollet::hashes::{sha256, Hash};
+
+pub(crate) const TEST_MNEMONIC: &str =
+    "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+/// A Liquid testnet address, used wherever the tests need a valid one
+pub(crate) const TEST_LIQUID_ADDRESS: &str = "tlq1pq0wqu32e2xacxeyps22x8gjre4qk3u6r70pj4r62hzczxeyz8x3yxucrpn79zy28plc4x37aaf33kwt6dz2nn6gtkya6h02mwpzy4eh69zzexq7cf5y5";
+
+/// A Bitcoin testnet address, used wherever the tests need a valid one
+pub(crate) const TEST_BITCOIN_ADDRESS: &str = "tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx";
+
+/// A deterministic, hex-encoded 32 bytes value derived from a label, e.g. to use as a tx ID
+pub(crate) fn mock_hash(label: &str) -> String {
+    sha256::Hash::hash(label.as_bytes()).to_string()
+}
+
+/// A testnet invoice, committing to `description_hash` if set, else to `description`
+pub(crate) fn new_test_invoice(
+    amount_msat: u64,
+    payment_hash: sha256::Hash,
+    description: &str,
+    description_hash: Option<sha256::Hash>,
+    expiry_secs: Option<u64>,
+) -> String {
+    let builder = InvoiceBuilder::new(Currency::BitcoinTestnet);
+    let builder = match description_hash {
+        Some(description_hash) => builder.description_hash(description_hash),
+        None => builder.description(description.to_string()),
+    };
+    let secp = boltz_client::Secp256k1::new();
+    let node_secret_key = lwk_wollet::secp256k1::SecretKey::from_slice(&[42; 32])
+        .expect("Expecting a valid secret key");
+    builder
+        .payment_hash(payment_hash)
+        .payment_secret(PaymentSecret([42; 32]))
+        .amount_milli_satoshis(amount_msat)
+        .current_timestamp()
+        .min_final_cltv_expiry_delta(144)
+        .expiry_time(Duration::from_secs(expiry_secs.unwrap_or(3600)))
+        .build_signed(|hash| secp.sign_ecdsa_recoverable(hash, &node_secret_key))
+        .expect("Expecting a valid invoice")
+        .to_string()
+}
@@ -0,0 +1,82 @@
+use anyhow::Result;
+use lwk_wollet::hashes::{sha256, Hash};
+use tempdir::TempDir;
+
+use crate::model::{Network, PaymentState, ReceiveSwap, SendSwap};
+use crate::persist::Persister;
+use crate::test_utils::{mock_hash, new_test_invoice};
+use crate::utils;
+
+pub(crate) fn create_temp_dir() -> Result<(TempDir, String)> {
+    let data_dir = TempDir::new(&uuid::Uuid::new_v4().to_string())?;
+    let data_dir_str = data_dir
+        .as_ref()
+        .to_path_buf()
+        .to_str()
+        .expect("Expecting valid temporary path")
+        .to_owned();
+    Ok((data_dir, data_dir_str))
+}
+
+/// A testnet [Persister] in a temporary dir, which is deleted when the returned [TempDir] is
+/// dropped
+pub(crate) fn new_persister() -> Result<(TempDir, Persister)> {
+    let (data_dir, data_dir_str) = create_temp_dir()?;
+    let persister = Persister::new(&data_dir_str, Network::Testnet)?;
+    persister.init()?;
+    Ok((data_dir, persister))
+}
+
+/// A Send Swap paying a 50k sat invoice in the given state, with no txs yet
+pub(crate) fn new_send_swap(id: &str, state: PaymentState) -> SendSwap {
+    SendSwap {
+        id: id.to_string(),
+        invoice: new_test_invoice(
+            50_000_000,
+            sha256::Hash::hash(format!("preimage {id}").as_bytes()),
+            "",
+            None,
+            None,
+        ),
+        preimage: None,
+        payer_amount_sat: 50_100,
+        receiver_amount_sat: 50_000,
+        create_response_json: "{}".to_string(),
+        lockup_tx_id: None,
+        refund_tx_id: None,
+        created_at: utils::now(),
+        state,
+        refund_private_key: mock_hash(&format!("refund key {id}")),
+        key_index: Some(0),
+        fee_breakdown: None,
+        fee_rate_sat_per_vbyte: None,
+    }
+}
+
+/// A Receive Swap of a 50k sat invoice in the given state, with no txs yet
+pub(crate) fn new_receive_swap(id: &str, state: PaymentState) -> ReceiveSwap {
+    let preimage = mock_hash(&format!("preimage {id}"));
+    ReceiveSwap {
+        id: id.to_string(),
+        invoice: new_test_invoice(
+            50_000_000,
+            sha256::Hash::hash(preimage.as_bytes()),
+            "Coffee",
+            None,
+            None,
+        ),
+        preimage,
+        create_response_json: "{}".to_string(),
+        claim_private_key: mock_hash(&format!("claim key {id}")),
+        payer_amount_sat: 50_000,
+        receiver_amount_sat: 49_800,
+        claim_fees_sat: 14,
+        claim_tx_id: None,
+        created_at: utils::now(),
+        state,
+        key_index: Some(0),
+        description: Some("Coffee".to_string()),
+        fee_breakdown: None,
+        fee_rate_sat_per_vbyte: None,
+    }
+}
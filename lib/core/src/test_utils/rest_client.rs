@@ -0,0 +1,40 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use crate::error::PaymentError;
+use crate::lnurl::RestClient;
+
+/// [RestClient] returning the responses set by the test, keyed by URL without query params
+pub(crate) struct MockRestClient {
+    pub(crate) responses: Mutex<HashMap<String, (u16, String)>>,
+    /// The full URLs of the requests made, in order
+    pub(crate) requested_urls: Mutex<Vec<String>>,
+}
+
+impl MockRestClient {
+    pub(crate) fn new() -> Self {
+        Self {
+            responses: Mutex::new(HashMap::new()),
+            requested_urls: Mutex::new(vec![]),
+        }
+    }
+
+    pub(crate) fn add_response(&self, url: &str, body: serde_json::Value) {
+        self.responses
+            .lock()
+            .unwrap()
+            .insert(url.to_string(), (200, body.to_string()));
+    }
+}
+
+impl RestClient for MockRestClient {
+    fn get(&self, url: &str) -> Result<(u16, String), PaymentError> {
+        self.requested_urls.lock().unwrap().push(url.to_string());
+        let base_url = url.split_once('?').map_or(url, |(base_url, _)| base_url);
+        self.responses.lock().unwrap().get(base_url).cloned().ok_or(
+            PaymentError::LnUrlServiceUnreachable {
+                err: format!("No mock response for {base_url}"),
+            },
+        )
+    }
+}
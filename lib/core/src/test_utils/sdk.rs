@@ -0,0 +1,65 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use tempdir::TempDir;
+
+use crate::electrum::ElectrumServers;
+use crate::model::Config;
+use crate::persist::Persister;
+use crate::sdk::LiquidSdk;
+use crate::test_utils::chain::MockChainService;
+use crate::test_utils::fee_estimator::MockFeeEstimator;
+use crate::test_utils::persist::create_temp_dir;
+use crate::test_utils::rest_client::MockRestClient;
+use crate::test_utils::swapper::MockSwapper;
+use crate::test_utils::wallet::MockWallet;
+
+/// A testnet SDK instance on mocked components, marked as started without running the
+/// background tasks
+pub(crate) struct TestSdk {
+    pub(crate) sdk: Arc<LiquidSdk>,
+    pub(crate) persister: Arc<Persister>,
+    pub(crate) swapper: Arc<MockSwapper>,
+    pub(crate) rest_client: Arc<MockRestClient>,
+    pub(crate) wallet: Arc<MockWallet>,
+    _data_dir: TempDir,
+}
+
+pub(crate) async fn new_test_sdk() -> Result<TestSdk> {
+    new_test_sdk_with_config(Config::testnet()).await
+}
+
+/// A [TestSdk] with the given testnet config, whose working dir is replaced by a temporary one
+pub(crate) async fn new_test_sdk_with_config(mut config: Config) -> Result<TestSdk> {
+    let (data_dir, data_dir_str) = create_temp_dir()?;
+    config.working_dir = data_dir_str;
+
+    let persister = Arc::new(Persister::new(&config.working_dir, config.network)?);
+    persister.init()?;
+    persister.set_min_next_swap_key_index(0)?;
+    let swapper = Arc::new(MockSwapper::new());
+    let rest_client = Arc::new(MockRestClient::new());
+    let wallet = Arc::new(MockWallet::new());
+    let electrum_servers = Arc::new(ElectrumServers::new(config.liquid_electrum_servers()));
+
+    let sdk = LiquidSdk::new_with_components(
+        config,
+        persister.clone(),
+        swapper.clone(),
+        wallet.clone(),
+        Arc::new(MockChainService::new()),
+        Arc::new(MockFeeEstimator::new()),
+        rest_client.clone(),
+        electrum_servers,
+    );
+    sdk.mark_as_started().await;
+
+    Ok(TestSdk {
+        sdk,
+        persister,
+        swapper,
+        rest_client,
+        wallet,
+        _data_dir: data_dir,
+    })
+}
@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use boltz_client::swaps::boltzv2;
+use serde_json::json;
+use tokio::sync::{broadcast, watch};
+
+use crate::swapper::{ReconnectHandler, SwapperStatusStream};
+
+/// [SwapperStatusStream] which never receives updates
+pub(crate) struct MockStatusStream {
+    update_notifier: broadcast::Sender<boltzv2::Update>,
+}
+
+impl MockStatusStream {
+    pub(crate) fn new() -> Self {
+        let (update_notifier, _) = broadcast::channel::<boltzv2::Update>(30);
+        Self { update_notifier }
+    }
+}
+
+#[async_trait]
+impl SwapperStatusStream for MockStatusStream {
+    async fn start(
+        self: Arc<Self>,
+        _callback: Box<dyn ReconnectHandler>,
+        _shutdown: watch::Receiver<()>,
+    ) {
+    }
+
+    fn track_swap_id(&self, _swap_id: &str) -> Result<()> {
+        Ok(())
+    }
+
+    fn subscribe_swap_updates(&self) -> broadcast::Receiver<boltzv2::Update> {
+        self.update_notifier.subscribe()
+    }
+}
+
+/// A swap status update, as sent by Boltz. Lockup updates also carry the lockup tx.
+pub(crate) fn new_swap_update(
+    swap_id: &str,
+    status: &str,
+    transaction_id: Option<&str>,
+) -> boltzv2::Update {
+    let update = match transaction_id {
+        Some(transaction_id) => json!({
+            "id": swap_id,
+            "status": status,
+            "transaction": { "id": transaction_id, "hex": "" },
+        }),
+        None => json!({ "id": swap_id, "status": status }),
+    };
+    serde_json::from_value(update).expect("Expecting a valid swap update")
+}
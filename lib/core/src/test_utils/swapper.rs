@@ -0,0 +1,387 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::Mutex;
+
+use boltz_client::network::Chain;
+use boltz_client::swaps::boltzv2::{
+    ChainPair, ClaimTxResponse, CreateChainRequest, CreateChainResponse, CreateReverseRequest,
+    CreateReverseResponse, CreateSubmarineRequest, CreateSubmarineResponse, ReversePair,
+    SubmarinePair,
+};
+use boltz_client::{Amount, Bolt11Invoice};
+use lwk_wollet::secp256k1::PublicKey;
+use serde_json::{json, Value};
+
+use crate::error::PaymentError;
+use crate::model::{ChainSwap, Direction, ReceiveSwap, SendSwap};
+use crate::swapper::{Swapper, SwapperStatusStream};
+use crate::test_utils::status_stream::MockStatusStream;
+use crate::test_utils::{mock_hash, new_test_invoice, TEST_BITCOIN_ADDRESS, TEST_LIQUID_ADDRESS};
+use crate::utils;
+
+/// The vsize the refund txs of [MockSwapper] are estimated at
+pub(crate) const MOCK_REFUND_TX_VSIZE: u32 = 150;
+/// The public key of the swapper in the swaps created by [MockSwapper]
+const MOCK_SERVER_PUBLIC_KEY: &str =
+    "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+
+/// A refund broadcast by [MockSwapper]
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct MockRefund {
+    pub(crate) swap_id: String,
+    pub(crate) output_address: String,
+    pub(crate) broadcast_fees_sat: u64,
+    pub(crate) is_cooperative: bool,
+}
+
+/// [Swapper] serving the pairs set by the test and recording the claims, refunds and
+/// broadcasts instead of performing them
+pub(crate) struct MockSwapper {
+    pub(crate) submarine_pair: Mutex<Option<SubmarinePair>>,
+    pub(crate) reverse_pair: Mutex<Option<ReversePair>>,
+    pub(crate) incoming_chain_pair: Mutex<Option<ChainPair>>,
+    pub(crate) outgoing_chain_pair: Mutex<Option<ChainPair>>,
+    /// Makes the cooperative refunds fail, so that the non-cooperative ones are attempted
+    pub(crate) fail_cooperative_refunds: Mutex<bool>,
+    pub(crate) claimed_swap_ids: Mutex<Vec<String>>,
+    pub(crate) refunds: Mutex<Vec<MockRefund>>,
+    pub(crate) broadcast_tx_hexes: Mutex<Vec<String>>,
+    /// The MRH address and amount in BTC of the invoices, see [Swapper::check_for_mrh]
+    pub(crate) mrh: Mutex<HashMap<String, (String, f64)>>,
+    /// The IDs of the swaps created with a public key, see [Swapper::list_swap_ids_by_public_key]
+    pub(crate) swap_ids_by_public_key: Mutex<HashMap<PublicKey, Vec<String>>>,
+}
+
+impl MockSwapper {
+    pub(crate) fn new() -> Self {
+        Self {
+            submarine_pair: Mutex::new(Some(new_submarine_pair())),
+            reverse_pair: Mutex::new(Some(new_reverse_pair())),
+            incoming_chain_pair: Mutex::new(Some(new_chain_pair(20))),
+            outgoing_chain_pair: Mutex::new(Some(new_chain_pair(300))),
+            fail_cooperative_refunds: Mutex::new(false),
+            claimed_swap_ids: Mutex::new(vec![]),
+            refunds: Mutex::new(vec![]),
+            broadcast_tx_hexes: Mutex::new(vec![]),
+            mrh: Mutex::new(HashMap::new()),
+            swap_ids_by_public_key: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn claim(&self, swap_id: &str) -> String {
+        self.claimed_swap_ids
+            .lock()
+            .unwrap()
+            .push(swap_id.to_string());
+        mock_hash(&format!("claim {swap_id}"))
+    }
+
+    fn refund(
+        &self,
+        swap_id: &str,
+        output_address: &str,
+        broadcast_fees_sat: Amount,
+        is_cooperative: bool,
+    ) -> Result<String, PaymentError> {
+        if is_cooperative && *self.fail_cooperative_refunds.lock().unwrap() {
+            return Err(PaymentError::Generic {
+                err: "Cooperative refund rejected".to_string(),
+            });
+        }
+        self.refunds.lock().unwrap().push(MockRefund {
+            swap_id: swap_id.to_string(),
+            output_address: output_address.to_string(),
+            broadcast_fees_sat: broadcast_fees_sat.to_sat(),
+            is_cooperative,
+        });
+        Ok(mock_hash(&format!("refund {swap_id}")))
+    }
+}
+
+/// A swap tree in the format of the Boltz API. The leaves are not valid scripts.
+fn mock_swap_tree() -> Value {
+    json!({
+        "claimLeaf": { "output": "00", "version": 196 },
+        "refundLeaf": { "output": "51", "version": 196 },
+    })
+}
+
+fn mock_chain_swap_details(lockup_address: &str, amount: u32) -> Value {
+    json!({
+        "swapTree": mock_swap_tree(),
+        "lockupAddress": lockup_address,
+        "serverPublicKey": MOCK_SERVER_PUBLIC_KEY,
+        "timeoutBlockHeight": 1_000,
+        "amount": amount,
+    })
+}
+
+/// The Boltz response to the creation of a chain swap, locking up and claiming `amount` sat
+pub(crate) fn new_create_chain_response(
+    swap_id: &str,
+    direction: Direction,
+    amount: u32,
+) -> CreateChainResponse {
+    let (lockup_address, claim_address) = match direction {
+        Direction::Incoming => (TEST_BITCOIN_ADDRESS, TEST_LIQUID_ADDRESS),
+        Direction::Outgoing => (TEST_LIQUID_ADDRESS, TEST_BITCOIN_ADDRESS),
+    };
+    serde_json::from_value(json!({
+        "id": swap_id,
+        "claimDetails": mock_chain_swap_details(claim_address, amount),
+        "lockupDetails": mock_chain_swap_details(lockup_address, amount),
+    }))
+    .expect("Expecting a valid chain swap")
+}
+
+/// A submarine pair with a 0.1% service fee
+pub(crate) fn new_submarine_pair() -> SubmarinePair {
+    serde_json::from_value(json!({
+        "hash": "submarine-pair-hash",
+        "rate": 1.0,
+        "limits": { "maximal": 25_000_000, "minimal": 1_000, "maximalZeroConf": 100_000 },
+        "fees": { "percentage": 0.1, "minerFees": 19 },
+    }))
+    .expect("Expecting a valid submarine pair")
+}
+
+/// A reverse pair with a 0.25% service fee
+pub(crate) fn new_reverse_pair() -> ReversePair {
+    serde_json::from_value(json!({
+        "hash": "reverse-pair-hash",
+        "rate": 1.0,
+        "limits": { "maximal": 25_000_000, "minimal": 1_000 },
+        "fees": { "percentage": 0.25, "minerFees": { "lockup": 26, "claim": 14 } },
+    }))
+    .expect("Expecting a valid reverse pair")
+}
+
+/// A chain pair with a 0.1% service fee and the given estimate of the user claim fee
+pub(crate) fn new_chain_pair(claim_estimate_sat: u64) -> ChainPair {
+    serde_json::from_value(json!({
+        "hash": format!("chain-pair-hash-{claim_estimate_sat}"),
+        "rate": 1.0,
+        "limits": { "maximal": 25_000_000, "minimal": 25_000, "maximalZeroConf": 1_000_000 },
+        "fees": {
+            "percentage": 0.1,
+            "minerFees": {
+                "server": 400,
+                "user": { "claim": claim_estimate_sat, "lockup": 50 },
+            },
+        },
+    }))
+    .expect("Expecting a valid chain pair")
+}
+
+impl Swapper for MockSwapper {
+    fn create_chain_swap(
+        &self,
+        req: CreateChainRequest,
+    ) -> Result<CreateChainResponse, PaymentError> {
+        let swap_id = format!("chain-{}", &req.preimage_hash.to_string()[..8]);
+        let direction = match req.from.as_str() {
+            "BTC" => Direction::Incoming,
+            _ => Direction::Outgoing,
+        };
+        Ok(new_create_chain_response(
+            &swap_id,
+            direction,
+            req.user_lock_amount.unwrap_or_default(),
+        ))
+    }
+
+    fn get_chain_pairs(&self, direction: Direction) -> Result<Option<ChainPair>, PaymentError> {
+        Ok(match direction {
+            Direction::Incoming => self.incoming_chain_pair.lock().unwrap().clone(),
+            Direction::Outgoing => self.outgoing_chain_pair.lock().unwrap().clone(),
+        })
+    }
+
+    fn claim_chain_swap(
+        &self,
+        swap: &ChainSwap,
+        _claim_address: String,
+    ) -> Result<String, PaymentError> {
+        Ok(self.claim(&swap.id))
+    }
+
+    fn refund_chain_swap_cooperative(
+        &self,
+        swap: &ChainSwap,
+        output_address: &str,
+        broadcast_fees_sat: Amount,
+    ) -> Result<String, PaymentError> {
+        self.refund(&swap.id, output_address, broadcast_fees_sat, true)
+    }
+
+    fn refund_chain_swap_non_cooperative(
+        &self,
+        swap: &ChainSwap,
+        broadcast_fees_sat: Amount,
+        output_address: &str,
+    ) -> Result<String, PaymentError> {
+        self.refund(&swap.id, output_address, broadcast_fees_sat, false)
+    }
+
+    fn get_chain_swap_refund_tx_vsize(
+        &self,
+        _swap: &ChainSwap,
+        _output_address: &str,
+    ) -> Result<u32, PaymentError> {
+        Ok(MOCK_REFUND_TX_VSIZE)
+    }
+
+    fn create_send_swap(
+        &self,
+        req: CreateSubmarineRequest,
+    ) -> Result<CreateSubmarineResponse, PaymentError> {
+        let invoice = Bolt11Invoice::from_str(&req.invoice)
+            .map_err(|e| PaymentError::InvalidInvoice { err: e.to_string() })?;
+        let amount_sat = invoice.amount_milli_satoshis().unwrap_or_default() / 1000;
+        serde_json::from_value(json!({
+            "id": format!("send-{}", &invoice.payment_hash().to_string()[..8]),
+            "acceptZeroConf": false,
+            "address": TEST_LIQUID_ADDRESS,
+            "bip21": format!("liquidtestnet:{TEST_LIQUID_ADDRESS}"),
+            "claimPublicKey": MOCK_SERVER_PUBLIC_KEY,
+            "expectedAmount": amount_sat + new_submarine_pair().fees.total(amount_sat),
+            "swapTree": mock_swap_tree(),
+        }))
+        .map_err(|e| PaymentError::Generic {
+            err: format!("Invalid mock send swap: {e:?}"),
+        })
+    }
+
+    fn get_submarine_pairs(&self) -> Result<Option<SubmarinePair>, PaymentError> {
+        Ok(self.submarine_pair.lock().unwrap().clone())
+    }
+
+    fn refund_send_swap_cooperative(
+        &self,
+        swap: &SendSwap,
+        output_address: &str,
+        broadcast_fees_sat: Amount,
+    ) -> Result<String, PaymentError> {
+        self.refund(&swap.id, output_address, broadcast_fees_sat, true)
+    }
+
+    fn refund_send_swap_non_cooperative(
+        &self,
+        swap: &SendSwap,
+        broadcast_fees_sat: Amount,
+        output_address: &str,
+        _current_height: u32,
+    ) -> Result<String, PaymentError> {
+        self.refund(&swap.id, output_address, broadcast_fees_sat, false)
+    }
+
+    fn get_send_swap_refund_tx_vsize(
+        &self,
+        _swap: &SendSwap,
+        _output_address: &str,
+    ) -> Result<u32, PaymentError> {
+        Ok(MOCK_REFUND_TX_VSIZE)
+    }
+
+    fn get_claim_tx_details(&self, swap: &SendSwap) -> Result<ClaimTxResponse, PaymentError> {
+        Err(PaymentError::Generic {
+            err: format!("No claim tx details for Send Swap {}", swap.id),
+        })
+    }
+
+    fn claim_send_swap_cooperative(
+        &self,
+        swap: &SendSwap,
+        _claim_tx_response: ClaimTxResponse,
+        _output_address: &str,
+    ) -> Result<(), PaymentError> {
+        self.claim(&swap.id);
+        Ok(())
+    }
+
+    /// Creates a Receive Swap whose invoice has an MRH to the requested address, for the
+    /// invoice amount minus the swapper fees
+    fn create_receive_swap(
+        &self,
+        req: CreateReverseRequest,
+    ) -> Result<CreateReverseResponse, PaymentError> {
+        let invoice_amount_sat = req.invoice_amount as u64;
+        let description_hash = req
+            .description_hash
+            .as_deref()
+            .map(lwk_wollet::hashes::sha256::Hash::from_str)
+            .transpose()
+            .map_err(|e| PaymentError::Generic {
+                err: format!("Invalid description hash: {e:?}"),
+            })?;
+        let invoice = new_test_invoice(
+            invoice_amount_sat * 1000,
+            req.preimage_hash,
+            req.description.as_deref().unwrap_or_default(),
+            description_hash,
+            req.invoice_expiry,
+        );
+        if let Some(address) = req.address {
+            let fees_sat = new_reverse_pair().fees.total(invoice_amount_sat);
+            let amount_btc = (invoice_amount_sat - fees_sat) as f64 / 100_000_000.0;
+            self.mrh
+                .lock()
+                .unwrap()
+                .insert(invoice.clone(), (address, amount_btc));
+        }
+        serde_json::from_value(json!({
+            "id": format!("receive-{}", &req.preimage_hash.to_string()[..8]),
+            "invoice": invoice,
+            "swapTree": mock_swap_tree(),
+            "lockupAddress": TEST_LIQUID_ADDRESS,
+            "refundPublicKey": MOCK_SERVER_PUBLIC_KEY,
+            "timeoutBlockHeight": 1_000,
+            "onchainAmount": invoice_amount_sat,
+        }))
+        .map_err(|e| PaymentError::Generic {
+            err: format!("Invalid mock receive swap: {e:?}"),
+        })
+    }
+
+    fn get_reverse_swap_pairs(&self) -> Result<Option<ReversePair>, PaymentError> {
+        Ok(self.reverse_pair.lock().unwrap().clone())
+    }
+
+    fn claim_receive_swap(
+        &self,
+        swap: &ReceiveSwap,
+        _claim_address: String,
+    ) -> Result<String, PaymentError> {
+        Ok(self.claim(&swap.id))
+    }
+
+    fn broadcast_tx(&self, _chain: Chain, tx_hex: &str) -> Result<Value, PaymentError> {
+        let tx_id = utils::deserialize_tx_hex(tx_hex)?.txid();
+        self.broadcast_tx_hexes
+            .lock()
+            .unwrap()
+            .push(tx_hex.to_string());
+        Ok(json!(tx_id.to_string()))
+    }
+
+    fn create_status_stream(&self) -> Box<dyn SwapperStatusStream> {
+        Box::new(MockStatusStream::new())
+    }
+
+    fn check_for_mrh(&self, invoice: &str) -> Result<Option<(String, f64)>, PaymentError> {
+        Ok(self.mrh.lock().unwrap().get(invoice).cloned())
+    }
+
+    fn list_swap_ids_by_public_key(
+        &self,
+        public_key: &PublicKey,
+    ) -> Result<Vec<String>, PaymentError> {
+        Ok(self
+            .swap_ids_by_public_key
+            .lock()
+            .unwrap()
+            .get(public_key)
+            .cloned()
+            .unwrap_or_default())
+    }
+}
@@ -0,0 +1,182 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use boltz_client::util::secrets::Preimage;
+use boltz_client::Keypair;
+use lwk_wollet::elements::{
+    confidential, Address, AssetId, LockTime, Script, Transaction, TxOut, TxOutWitness,
+};
+use lwk_wollet::hashes::{sha256, Hash};
+use lwk_wollet::{ElementsNetwork, WalletTx};
+
+use crate::error::PaymentError;
+use crate::test_utils::{mock_hash, TEST_LIQUID_ADDRESS};
+use crate::wallet::OnchainWallet;
+
+/// The default vsize of the txs built by [MockWallet], whose fee is this vsize times the fee rate
+pub(crate) const MOCK_TX_VSIZE: u64 = 1_000;
+
+/// [OnchainWallet] building unsigned txs without inputs, paying the recipient and the fees
+pub(crate) struct MockWallet {
+    pub(crate) balance_sat: Mutex<u64>,
+    pub(crate) watched_scripts: Mutex<Vec<Script>>,
+    /// The vsize of the txs built, e.g. to change the fee of a tx between two estimates
+    pub(crate) tx_vsize: Mutex<u64>,
+    lbtc_asset_id: AssetId,
+}
+
+impl MockWallet {
+    pub(crate) fn new() -> Self {
+        Self {
+            balance_sat: Mutex::new(0),
+            watched_scripts: Mutex::new(vec![]),
+            tx_vsize: Mutex::new(MOCK_TX_VSIZE),
+            lbtc_asset_id: ElementsNetwork::LiquidTestnet.policy_asset(),
+        }
+    }
+
+    pub(crate) fn fees_sat(&self, fee_rate_sat_per_vbyte: Option<f64>) -> u64 {
+        let tx_vsize = *self.tx_vsize.lock().unwrap();
+        (fee_rate_sat_per_vbyte.unwrap_or(0.1) * tx_vsize as f64).ceil() as u64
+    }
+
+    fn build_mock_tx(
+        recipient_address: &str,
+        amount: u64,
+        asset_id: AssetId,
+        fees_sat: u64,
+        lbtc_asset_id: AssetId,
+    ) -> Transaction {
+        let script_pubkey = Address::from_str(recipient_address)
+            .map(|address| address.script_pubkey())
+            .unwrap_or_else(|_| Script::new());
+        Transaction {
+            version: 2,
+            lock_time: LockTime::ZERO,
+            input: vec![],
+            output: vec![
+                TxOut {
+                    asset: confidential::Asset::Explicit(asset_id),
+                    value: confidential::Value::Explicit(amount),
+                    nonce: confidential::Nonce::Null,
+                    script_pubkey,
+                    witness: TxOutWitness::default(),
+                },
+                TxOut::new_fee(fees_sat, lbtc_asset_id),
+            ],
+        }
+    }
+
+    fn derive_keypair(key_index: u32, child: u32) -> Result<Keypair, PaymentError> {
+        let secret = sha256::Hash::hash(format!("{key_index}/{child}").as_bytes());
+        Keypair::from_seckey_slice(&boltz_client::Secp256k1::new(), &secret.to_byte_array())
+            .map_err(|e| PaymentError::Generic {
+                err: format!("Invalid mock swap key: {e:?}"),
+            })
+    }
+}
+
+#[async_trait]
+impl OnchainWallet for MockWallet {
+    async fn transactions(&self) -> Result<Vec<WalletTx>, PaymentError> {
+        Ok(vec![])
+    }
+
+    async fn build_tx(
+        &self,
+        fee_rate_sat_per_vbyte: Option<f64>,
+        recipient_address: &str,
+        amount_sat: u64,
+    ) -> Result<Transaction, PaymentError> {
+        Ok(Self::build_mock_tx(
+            recipient_address,
+            amount_sat,
+            self.lbtc_asset_id,
+            self.fees_sat(fee_rate_sat_per_vbyte),
+            self.lbtc_asset_id,
+        ))
+    }
+
+    async fn build_drain_tx(
+        &self,
+        fee_rate_sat_per_vbyte: Option<f64>,
+        recipient_address: &str,
+    ) -> Result<Transaction, PaymentError> {
+        let fees_sat = self.fees_sat(fee_rate_sat_per_vbyte);
+        let amount_sat = self
+            .balance_sat
+            .lock()
+            .unwrap()
+            .checked_sub(fees_sat)
+            .ok_or(PaymentError::InsufficientFunds)?;
+        Ok(Self::build_mock_tx(
+            recipient_address,
+            amount_sat,
+            self.lbtc_asset_id,
+            fees_sat,
+            self.lbtc_asset_id,
+        ))
+    }
+
+    async fn build_asset_tx(
+        &self,
+        fee_rate_sat_per_vbyte: Option<f64>,
+        recipient_address: &str,
+        amount: u64,
+        asset_id: &str,
+    ) -> Result<Transaction, PaymentError> {
+        let asset_id = AssetId::from_str(asset_id).map_err(|e| PaymentError::Generic {
+            err: format!("Invalid asset ID {asset_id}: {e:?}"),
+        })?;
+        Ok(Self::build_mock_tx(
+            recipient_address,
+            amount,
+            asset_id,
+            self.fees_sat(fee_rate_sat_per_vbyte),
+            self.lbtc_asset_id,
+        ))
+    }
+
+    async fn lbtc_balance_sat(&self) -> Result<u64, PaymentError> {
+        Ok(*self.balance_sat.lock().unwrap())
+    }
+
+    async fn asset_balances(&self) -> Result<HashMap<String, u64>, PaymentError> {
+        Ok(HashMap::from([(
+            self.lbtc_asset_id.to_string(),
+            *self.balance_sat.lock().unwrap(),
+        )]))
+    }
+
+    async fn next_unused_address(&self) -> Result<Address, PaymentError> {
+        Address::from_str(TEST_LIQUID_ADDRESS).map_err(|e| PaymentError::Generic {
+            err: format!("Invalid mock address: {e:?}"),
+        })
+    }
+
+    fn pubkey(&self) -> String {
+        mock_hash("pubkey")
+    }
+
+    async fn full_scan(&self) -> Result<(), PaymentError> {
+        Ok(())
+    }
+
+    async fn watched_scripts(&self) -> Result<Vec<Script>, PaymentError> {
+        Ok(self.watched_scripts.lock().unwrap().clone())
+    }
+
+    fn derive_swap_claim_keypair(&self, key_index: u32) -> Result<Keypair, PaymentError> {
+        Self::derive_keypair(key_index, 0)
+    }
+
+    fn derive_swap_refund_keypair(&self, key_index: u32) -> Result<Keypair, PaymentError> {
+        Self::derive_keypair(key_index, 1)
+    }
+
+    fn derive_swap_preimage(&self, key_index: u32) -> Result<Preimage, PaymentError> {
+        Ok(Preimage::from_str(&mock_hash(&format!("{key_index}/2")))?)
+    }
+}
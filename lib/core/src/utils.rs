@@ -23,19 +23,42 @@ pub(crate) fn json_to_pubkey(json: &str) -> Result<boltz_client::PublicKey, Paym
     })
 }
 
-pub(crate) fn generate_keypair() -> boltz_client::Keypair {
-    let secp = boltz_client::Secp256k1::new();
-    let mut rng = bip39::rand::rngs::OsRng;
-    let secret_key = lwk_wollet::secp256k1::SecretKey::new(&mut rng);
-    boltz_client::Keypair::from_secret_key(&secp, &secret_key)
-}
-
 pub(crate) fn decode_keypair(secret_key: &str) -> Result<boltz_client::Keypair, lwk_wollet::Error> {
     let secp = boltz_client::Secp256k1::new();
     let secret_key = lwk_wollet::secp256k1::SecretKey::from_str(secret_key)?;
     Ok(boltz_client::Keypair::from_secret_key(&secp, &secret_key))
 }
 
+/// Parses a decimal BTC amount, like the BIP21 `amount` param, into satoshi
+pub(crate) fn parse_btc_amount_sat(amount: &str) -> Option<u64> {
+    let (whole, fraction) = amount.split_once('.').unwrap_or((amount, ""));
+    if fraction.len() > 8 || !(whole.chars().chain(fraction.chars())).all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let whole_sat = match whole {
+        "" => 0,
+        whole => whole.parse::<u64>().ok()?.checked_mul(100_000_000)?,
+    };
+    let fraction_sat = match fraction {
+        "" => 0,
+        fraction => format!("{fraction:0<8}").parse::<u64>().ok()?,
+    };
+    whole_sat.checked_add(fraction_sat)
+}
+
+/// Formats an amount in satoshi as a decimal BTC amount, without trailing zeros
+pub(crate) fn format_btc_amount(amount_sat: u64) -> String {
+    let amount = format!(
+        "{}.{:08}",
+        amount_sat / 100_000_000,
+        amount_sat % 100_000_000
+    );
+    amount
+        .trim_end_matches('0')
+        .trim_end_matches('.')
+        .to_string()
+}
+
 pub(crate) fn is_locktime_expired(current_locktime: LockTime, expiry_locktime: LockTime) -> bool {
     match (current_locktime, expiry_locktime) {
         (Blocks(n), Blocks(lock_time)) => n >= lock_time,
@@ -44,6 +67,13 @@ pub(crate) fn is_locktime_expired(current_locktime: LockTime, expiry_locktime: L
     }
 }
 
+/// The hex-encoded payment hash of a BOLT11 invoice, if it can be parsed
+pub(crate) fn get_invoice_payment_hash(invoice: &str) -> Option<String> {
+    boltz_client::Bolt11Invoice::from_str(invoice)
+        .ok()
+        .map(|invoice| invoice.payment_hash().to_string())
+}
+
 pub(crate) fn deserialize_tx_hex(tx_hex: &str) -> Result<Transaction> {
     Ok(deserialize(&Vec::<u8>::from_hex(tx_hex).map_err(
         |err| anyhow!("Could not deserialize transaction: {err:?}"),
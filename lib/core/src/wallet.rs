@@ -1,72 +1,178 @@
-use std::{str::FromStr, sync::Arc};
+use std::{collections::HashMap, str::FromStr, sync::Arc};
 
 use anyhow::{anyhow, Result};
 use async_trait::async_trait;
-use boltz_client::ElementsAddress;
+use boltz_client::util::secrets::Preimage;
+use boltz_client::{ElementsAddress, Keypair, ToHex};
 use lwk_common::Signer;
 use lwk_common::{singlesig_desc, Singlesig};
 use lwk_signer::{AnySigner, SwSigner};
+use lwk_wollet::bitcoin::bip32::{ChildNumber, DerivationPath, Xpriv};
+use lwk_wollet::hashes::{sha256, Hash};
+use lwk_wollet::secp256k1::PublicKey;
 use lwk_wollet::{
-    elements::{Address, Transaction},
-    ElectrumClient, ElectrumUrl, ElementsNetwork, FsPersister, Tip, WalletTx, Wollet,
-    WolletDescriptor,
+    elements::{Address, AssetId, Script, Transaction},
+    FsPersister, WalletTx, Wollet, WolletDescriptor,
 };
 use tokio::sync::Mutex;
 
 use crate::{
+    chain::ChainService,
     error::PaymentError,
     model::{Config, Network},
 };
 
+/// SDK-specific BIP32 path under which the swap keys are derived. Each swap has its own key index,
+/// and the keys of a swap are derived at `{SWAP_KEYS_BIP32_PATH}/{key_index}/{child}`.
+const SWAP_KEYS_BIP32_PATH: &str = "m/44'/1776'/1'";
+const SWAP_CLAIM_KEY_CHILD: u32 = 0;
+const SWAP_REFUND_KEY_CHILD: u32 = 1;
+const SWAP_PREIMAGE_CHILD: u32 = 2;
+/// Number of addresses after the next unused one of each chain whose scripts are watched
+const WATCHED_ADDRESSES_LOOKAHEAD: u32 = 20;
+
+/// Whether the input witnesses of a tx reveal the swap keys of a key index: either a swap script
+/// with one of the public keys, spent through the script path, or the preimage, spent in a claim
+pub(crate) fn reveals_swap_keys(
+    tx: &Transaction,
+    public_keys: &[PublicKey],
+    preimage_hash: &sha256::Hash,
+) -> bool {
+    let x_only_public_keys: Vec<[u8; 32]> = public_keys
+        .iter()
+        .map(|public_key| public_key.x_only_public_key().0.serialize())
+        .collect();
+    tx.input
+        .iter()
+        .flat_map(|input| &input.witness.script_witness)
+        .any(|item| {
+            sha256::Hash::hash(item) == *preimage_hash
+                || x_only_public_keys
+                    .iter()
+                    .any(|key| item.windows(key.len()).any(|window| window == key))
+        })
+}
+
 #[async_trait]
 pub trait OnchainWallet: Send + Sync {
     /// List all transactions in the wallet
     async fn transactions(&self) -> Result<Vec<WalletTx>, PaymentError>;
 
-    /// Build a transaction to send funds to a recipient
+    /// Build a transaction to send funds to a recipient.
+    ///
+    /// The fee rate is in sat/vbyte. If not set, the LWK default of 0.1 sat/vbyte is used.
     async fn build_tx(
         &self,
-        fee_rate: Option<f32>,
+        fee_rate_sat_per_vbyte: Option<f64>,
         recipient_address: &str,
         amount_sat: u64,
     ) -> Result<Transaction, PaymentError>;
 
+    /// Build a transaction sending the whole L-BTC balance of the wallet, minus fees, to a recipient
+    async fn build_drain_tx(
+        &self,
+        fee_rate_sat_per_vbyte: Option<f64>,
+        recipient_address: &str,
+    ) -> Result<Transaction, PaymentError>;
+
+    /// Build a transaction to send an issued asset to a recipient, paying the fees in L-BTC
+    async fn build_asset_tx(
+        &self,
+        fee_rate_sat_per_vbyte: Option<f64>,
+        recipient_address: &str,
+        amount: u64,
+        asset_id: &str,
+    ) -> Result<Transaction, PaymentError>;
+
+    /// Get the L-BTC balance of the wallet
+    async fn lbtc_balance_sat(&self) -> Result<u64, PaymentError>;
+
+    /// Get the balances of all assets in the wallet, indexed by asset ID
+    async fn asset_balances(&self) -> Result<HashMap<String, u64>, PaymentError>;
+
     /// Get the next unused address in the wallet
     async fn next_unused_address(&self) -> Result<Address, PaymentError>;
 
-    /// Get the current tip of the blockchain the wallet is aware of
-    async fn tip(&self) -> Tip;
-
     /// Get the public key of the wallet
     fn pubkey(&self) -> String;
 
     /// Perform a full scan of the wallet
     async fn full_scan(&self) -> Result<(), PaymentError>;
+
+    /// Get the scripts of the wallet addresses to watch for txs, of both the receive and the
+    /// change chains: the used ones, the next unused one and a lookahead
+    async fn watched_scripts(&self) -> Result<Vec<Script>, PaymentError>;
+
+    /// Derive the claim keypair of the swap with the given key index
+    fn derive_swap_claim_keypair(&self, key_index: u32) -> Result<Keypair, PaymentError>;
+
+    /// Derive the refund keypair of the swap with the given key index
+    fn derive_swap_refund_keypair(&self, key_index: u32) -> Result<Keypair, PaymentError>;
+
+    /// Derive the preimage of the swap with the given key index
+    fn derive_swap_preimage(&self, key_index: u32) -> Result<Preimage, PaymentError>;
 }
 
 pub(crate) struct LiquidOnchainWallet {
     wallet: Arc<Mutex<Wollet>>,
     lwk_signer: SwSigner,
+    /// Extended private key at [SWAP_KEYS_BIP32_PATH]
+    swap_keys_xprv: Xpriv,
     config: Config,
+    chain_service: Arc<dyn ChainService>,
 }
 
 impl LiquidOnchainWallet {
-    pub(crate) fn new(mnemonic: String, config: Config) -> Result<Self> {
+    pub(crate) fn new(
+        mnemonic: String,
+        config: Config,
+        chain_service: Arc<dyn ChainService>,
+    ) -> Result<Self> {
         let is_mainnet = config.network == Network::Mainnet;
         let lwk_signer = SwSigner::new(&mnemonic, is_mainnet)?;
         let descriptor = LiquidOnchainWallet::get_descriptor(&lwk_signer, config.network)?;
-        let elements_network: ElementsNetwork = config.network.into();
+        let elements_network = config.elements_network()?;
 
         let lwk_persister =
             FsPersister::new(config.working_dir.clone(), elements_network, &descriptor)?;
         let wollet = Wollet::new(elements_network, lwk_persister, descriptor)?;
+        let swap_keys_xprv = LiquidOnchainWallet::get_swap_keys_xprv(&mnemonic, config.network)?;
         Ok(Self {
             wallet: Arc::new(Mutex::new(wollet)),
             lwk_signer,
+            swap_keys_xprv,
             config,
+            chain_service,
         })
     }
 
+    fn get_swap_keys_xprv(mnemonic: &str, network: Network) -> Result<Xpriv> {
+        let seed = bip39::Mnemonic::from_str(mnemonic)?.to_seed("");
+        let secp = boltz_client::Secp256k1::new();
+        let path = DerivationPath::from_str(SWAP_KEYS_BIP32_PATH)?;
+        Ok(Xpriv::new_master(network.into(), &seed)?.derive_priv(&secp, &path)?)
+    }
+
+    fn derive_swap_keypair(&self, key_index: u32, child: u32) -> Result<Keypair, PaymentError> {
+        let secp = boltz_client::Secp256k1::new();
+        let path = [
+            ChildNumber::from_normal_idx(key_index),
+            ChildNumber::from_normal_idx(child),
+        ]
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| PaymentError::Generic {
+            err: format!("Invalid swap key index {key_index}: {e:?}"),
+        })?;
+        let xprv =
+            self.swap_keys_xprv
+                .derive_priv(&secp, &path)
+                .map_err(|e| PaymentError::Generic {
+                    err: format!("Failed to derive swap key {key_index}/{child}: {e:?}"),
+                })?;
+        Ok(Keypair::from_secret_key(&secp, &xprv.private_key))
+    }
+
     fn get_descriptor(
         signer: &SwSigner,
         network: Network,
@@ -81,6 +187,11 @@ impl LiquidOnchainWallet {
         .map_err(|e| anyhow!("Invalid descriptor: {e}"))?;
         Ok(descriptor_str.parse()?)
     }
+
+    /// LWK expects fee rates in sat/kvbyte
+    fn lwk_fee_rate(fee_rate_sat_per_vbyte: Option<f64>) -> Option<f32> {
+        fee_rate_sat_per_vbyte.map(|fee_rate| (fee_rate * 1000.0) as f32)
+    }
 }
 
 #[async_trait]
@@ -96,12 +207,12 @@ impl OnchainWallet for LiquidOnchainWallet {
     /// Build a transaction to send funds to a recipient
     async fn build_tx(
         &self,
-        fee_rate: Option<f32>,
+        fee_rate_sat_per_vbyte: Option<f64>,
         recipient_address: &str,
         amount_sat: u64,
     ) -> Result<Transaction, PaymentError> {
         let lwk_wollet = self.wallet.lock().await;
-        let mut pset = lwk_wollet::TxBuilder::new(self.config.network.into())
+        let mut pset = lwk_wollet::TxBuilder::new(self.config.elements_network()?)
             .add_lbtc_recipient(
                 &ElementsAddress::from_str(recipient_address).map_err(|e| {
                     PaymentError::Generic {
@@ -112,23 +223,91 @@ impl OnchainWallet for LiquidOnchainWallet {
                 })?,
                 amount_sat,
             )?
-            .fee_rate(fee_rate)
+            .fee_rate(Self::lwk_fee_rate(fee_rate_sat_per_vbyte))
+            .finish(&lwk_wollet)?;
+        let signer = AnySigner::Software(self.lwk_signer.clone());
+        signer.sign(&mut pset)?;
+        Ok(lwk_wollet.finalize(&mut pset)?)
+    }
+
+    /// Build a transaction sending the whole L-BTC balance of the wallet, minus fees, to a recipient
+    async fn build_drain_tx(
+        &self,
+        fee_rate_sat_per_vbyte: Option<f64>,
+        recipient_address: &str,
+    ) -> Result<Transaction, PaymentError> {
+        let lwk_wollet = self.wallet.lock().await;
+        let mut pset = lwk_wollet::TxBuilder::new(self.config.elements_network()?)
+            .drain_lbtc_wallet()
+            .drain_lbtc_to(ElementsAddress::from_str(recipient_address).map_err(|e| {
+                PaymentError::Generic {
+                    err: format!(
+                        "Recipient address {recipient_address} is not a valid ElementsAddress: {e:?}"
+                    ),
+                }
+            })?)
+            .fee_rate(Self::lwk_fee_rate(fee_rate_sat_per_vbyte))
             .finish(&lwk_wollet)?;
         let signer = AnySigner::Software(self.lwk_signer.clone());
         signer.sign(&mut pset)?;
         Ok(lwk_wollet.finalize(&mut pset)?)
     }
 
+    /// Build a transaction to send an issued asset to a recipient, paying the fees in L-BTC
+    async fn build_asset_tx(
+        &self,
+        fee_rate_sat_per_vbyte: Option<f64>,
+        recipient_address: &str,
+        amount: u64,
+        asset_id: &str,
+    ) -> Result<Transaction, PaymentError> {
+        let lwk_wollet = self.wallet.lock().await;
+        let mut pset = lwk_wollet::TxBuilder::new(self.config.elements_network()?)
+            .add_recipient(
+                &ElementsAddress::from_str(recipient_address).map_err(|e| {
+                    PaymentError::Generic {
+                        err: format!(
+                            "Recipient address {recipient_address} is not a valid ElementsAddress: {e:?}"
+                        ),
+                    }
+                })?,
+                amount,
+                AssetId::from_str(asset_id).map_err(|e| PaymentError::Generic {
+                    err: format!("Invalid asset ID {asset_id}: {e:?}"),
+                })?,
+            )?
+            .fee_rate(Self::lwk_fee_rate(fee_rate_sat_per_vbyte))
+            .finish(&lwk_wollet)?;
+        let signer = AnySigner::Software(self.lwk_signer.clone());
+        signer.sign(&mut pset)?;
+        Ok(lwk_wollet.finalize(&mut pset)?)
+    }
+
+    /// Get the L-BTC balance of the wallet
+    async fn lbtc_balance_sat(&self) -> Result<u64, PaymentError> {
+        let lwk_wollet = self.wallet.lock().await;
+        let balance = lwk_wollet.balance()?;
+        Ok(balance
+            .get(&lwk_wollet.policy_asset())
+            .copied()
+            .unwrap_or_default())
+    }
+
+    /// Get the balances of all assets in the wallet, indexed by asset ID
+    async fn asset_balances(&self) -> Result<HashMap<String, u64>, PaymentError> {
+        let lwk_wollet = self.wallet.lock().await;
+        Ok(lwk_wollet
+            .balance()?
+            .into_iter()
+            .map(|(asset_id, balance)| (asset_id.to_string(), balance))
+            .collect())
+    }
+
     /// Get the next unused address in the wallet
     async fn next_unused_address(&self) -> Result<Address, PaymentError> {
         Ok(self.wallet.lock().await.address(None)?.address().clone())
     }
 
-    /// Get the current tip of the blockchain the wallet is aware of
-    async fn tip(&self) -> Tip {
-        self.wallet.lock().await.tip()
-    }
-
     /// Get the public key of the wallet
     fn pubkey(&self) -> String {
         self.lwk_signer.xpub().public_key.to_string()
@@ -136,10 +315,166 @@ impl OnchainWallet for LiquidOnchainWallet {
 
     /// Perform a full scan of the wallet
     async fn full_scan(&self) -> Result<(), PaymentError> {
-        let mut wallet = self.wallet.lock().await;
-        let mut electrum_client =
-            ElectrumClient::new(&ElectrumUrl::new(&self.config.electrum_url, true, true))?;
-        lwk_wollet::full_scan_with_electrum_client(&mut wallet, &mut electrum_client)?;
+        // The chain clients block on their requests, so the scan runs on a blocking thread
+        let wallet = self.wallet.clone();
+        let chain_service = self.chain_service.clone();
+        tokio::task::spawn_blocking(move || chain_service.full_scan(&mut wallet.blocking_lock()))
+            .await
+            .map_err(|e| PaymentError::Generic {
+                err: format!("Wallet scan failed to complete: {e:?}"),
+            })?
+    }
+
+    async fn watched_scripts(&self) -> Result<Vec<Script>, PaymentError> {
+        let wallet = self.wallet.lock().await;
+        let next_unused_index = wallet.address(None)?.index();
+        let next_unused_change_index = wallet.change(None)?.index();
+        let receive_scripts = (0..=next_unused_index + WATCHED_ADDRESSES_LOOKAHEAD)
+            .map(|index| Ok(wallet.address(Some(index))?.address().script_pubkey()));
+        let change_scripts = (0..=next_unused_change_index + WATCHED_ADDRESSES_LOOKAHEAD)
+            .map(|index| Ok(wallet.change(Some(index))?.address().script_pubkey()));
+        receive_scripts.chain(change_scripts).collect()
+    }
+
+    fn derive_swap_claim_keypair(&self, key_index: u32) -> Result<Keypair, PaymentError> {
+        self.derive_swap_keypair(key_index, SWAP_CLAIM_KEY_CHILD)
+    }
+
+    fn derive_swap_refund_keypair(&self, key_index: u32) -> Result<Keypair, PaymentError> {
+        self.derive_swap_keypair(key_index, SWAP_REFUND_KEY_CHILD)
+    }
+
+    /// The preimage is the SHA256 hash of a dedicated derived private key, so that revealing it
+    /// does not reveal any swap key
+    fn derive_swap_preimage(&self, key_index: u32) -> Result<Preimage, PaymentError> {
+        let keypair = self.derive_swap_keypair(key_index, SWAP_PREIMAGE_CHILD)?;
+        let preimage_bytes = sha256::Hash::hash(&keypair.secret_bytes());
+        Ok(Preimage::from_str(
+            &preimage_bytes.to_byte_array().to_hex(),
+        )?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use anyhow::Result;
+    use lwk_wollet::elements::hex::FromHex;
+    use lwk_wollet::elements::{LockTime, Transaction, TxIn, TxInWitness};
+    use tempdir::TempDir;
+
+    use crate::model::Config;
+    use crate::test_utils::chain::MockChainService;
+    use crate::test_utils::persist::create_temp_dir;
+    use crate::test_utils::TEST_MNEMONIC;
+
+    use super::{reveals_swap_keys, LiquidOnchainWallet, OnchainWallet};
+
+    fn new_test_wallet() -> Result<(TempDir, LiquidOnchainWallet)> {
+        let (data_dir, data_dir_str) = create_temp_dir()?;
+        let mut config = Config::testnet();
+        config.working_dir = data_dir_str;
+        let wallet = LiquidOnchainWallet::new(
+            TEST_MNEMONIC.to_string(),
+            config,
+            Arc::new(MockChainService::new()),
+        )?;
+        Ok((data_dir, wallet))
+    }
+
+    #[test]
+    fn swap_key_derivation() -> Result<()> {
+        let (_data_dir, wallet) = new_test_wallet()?;
+
+        // Derived at m/44'/1776'/1'/{key_index}/{0,1,2}
+        for (key_index, claim_public_key, refund_public_key, preimage) in [
+            (
+                0,
+                "036f93809d7c712210ef4250facad0543ccfa45563c85826e16661feae128a98c8",
+                "02b7d79ae3c18898a3aa166ddb28c767d1b726718c4169f4125f6e6545c690176e",
+                "4ce849d4e5bf44a3c72935c91b72bab5554e831679a4a4857be084667a05e56a",
+            ),
+            (
+                1,
+                "02ce307286e13ea0439e4fb7dd8c250988561a605f3e8848c23b7465539a66b2ab",
+                "033f77ce465f12fcb04de535d07b8f44b0832929812a8190db85adde7d042b5d3a",
+                "06b6b1860e92839e9ce4523fe650a7bb1dfb49ae8029ee4a9f57e5b1874450fa",
+            ),
+            (
+                7,
+                "0293d243b66e4af4fa93cd94b4e5650ff0d2f8a479cec864e32d1d2f8e19a073d1",
+                "029e9254d07131a5cd04e9071554b45b45c984837093fdae2d0af5dc3b2c2eb595",
+                "3df2bb90243ca6c7e50a41527103c4a30bee856123345934e56e18f4f6f05eca",
+            ),
+        ] {
+            assert_eq!(
+                wallet
+                    .derive_swap_claim_keypair(key_index)?
+                    .public_key()
+                    .to_string(),
+                claim_public_key
+            );
+            assert_eq!(
+                wallet
+                    .derive_swap_refund_keypair(key_index)?
+                    .public_key()
+                    .to_string(),
+                refund_public_key
+            );
+            assert_eq!(
+                wallet.derive_swap_preimage(key_index)?.to_string(),
+                Some(preimage.to_string())
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn swap_keys_in_witness() -> Result<()> {
+        let (_data_dir, wallet) = new_test_wallet()?;
+        let public_keys = [
+            wallet.derive_swap_claim_keypair(0)?.public_key(),
+            wallet.derive_swap_refund_keypair(0)?.public_key(),
+        ];
+        let preimage = wallet.derive_swap_preimage(0)?;
+        let preimage_bytes = Vec::<u8>::from_hex(&preimage.to_string().unwrap())?;
+        let refund_x_only_public_key = public_keys[1].x_only_public_key().0.serialize();
+        let new_tx = |script_witness: Vec<Vec<u8>>| Transaction {
+            version: 2,
+            lock_time: LockTime::ZERO,
+            input: vec![TxIn {
+                witness: TxInWitness {
+                    script_witness,
+                    ..Default::default()
+                },
+                ..Default::default()
+            }],
+            output: vec![],
+        };
+
+        // A key path spend reveals nothing
+        let signature = vec![1; 64];
+        assert!(!reveals_swap_keys(
+            &new_tx(vec![signature.clone()]),
+            &public_keys,
+            &preimage.sha256
+        ));
+
+        // A claim reveals the preimage
+        assert!(reveals_swap_keys(
+            &new_tx(vec![signature.clone(), preimage_bytes]),
+            &public_keys,
+            &preimage.sha256
+        ));
+
+        // A script path refund reveals the refund leaf, with the refund public key
+        let refund_leaf = [[0x20].as_slice(), &refund_x_only_public_key, &[0xad]].concat();
+        assert!(reveals_swap_keys(
+            &new_tx(vec![signature, refund_leaf]),
+            &public_keys,
+            &preimage.sha256
+        ));
         Ok(())
     }
 }
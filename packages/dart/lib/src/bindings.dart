@@ -33,20 +33,60 @@ abstract class BindingLiquidSdk implements RustOpaqueInterface {
 
   void emptyWalletCache();
 
+  Future<ExportPaymentsResponse> exportPayments({required ExportPaymentsRequest req});
+
+  Future<LightningPaymentLimitsResponse> fetchLightningLimits();
+
+  Future<RecommendedFeesResponse> fetchRecommendedFees();
+
   Future<GetInfoResponse> getInfo();
 
-  Future<List<Payment>> listPayments();
+  Future<Payment?> getPayment({required GetPaymentRequest req});
+
+  Future<List<ElectrumServerStatus>> listElectrumServers();
+
+  Future<List<Payment>> listPayments({required ListPaymentsRequest req});
+
+  Future<List<RefundableSwap>> listRefundables();
+
+  Future<LnUrlPayResult> lnurlPay({required PrepareLnUrlPayResponse req});
+
+  Future<LnUrlWithdrawResult> lnurlWithdraw({required LnUrlWithdrawRequest req});
+
+  InputType parse({required String input});
+
+  Future<SendPaymentResponse> payOnchain({required PayOnchainRequest req});
+
+  Future<PrepareLnUrlPayResponse> prepareLnurlPay({required PrepareLnUrlPayRequest req});
+
+  Future<PreparePayOnchainResponse> preparePayOnchain({required PreparePayOnchainRequest req});
+
+  Future<PrepareReceiveOnchainResponse> prepareReceiveOnchain({required PrepareReceiveOnchainRequest req});
 
   Future<PrepareReceiveResponse> prepareReceivePayment({required PrepareReceiveRequest req});
 
+  Future<PrepareRefundResponse> prepareRefund({required PrepareRefundRequest req});
+
+  Future<PrepareSendOnchainResponse> prepareSendOnchain({required PrepareSendOnchainRequest req});
+
   Future<PrepareSendResponse> prepareSendPayment({required PrepareSendRequest req});
 
+  Future<ReceiveLiquidResponse> receiveLiquid({required ReceiveLiquidRequest req});
+
+  Future<ReceiveOnchainResponse> receiveOnchain({required PrepareReceiveOnchainResponse req});
+
   Future<ReceivePaymentResponse> receivePayment({required PrepareReceiveResponse req});
 
+  Future<RefundResponse> refund({required RefundRequest req});
+
   void restore({required RestoreRequest req});
 
+  Future<SendPaymentResponse> sendOnchain({required PrepareSendOnchainResponse req});
+
   Future<SendPaymentResponse> sendPayment({required PrepareSendResponse req});
 
+  Future<void> setPaymentMetadata({required SetPaymentMetadataRequest req});
+
   Future<void> sync();
 }
 
@@ -27,10 +27,29 @@ sealed class PaymentError with _$PaymentError implements FrbException {
   const factory PaymentError.alreadyPaid() = PaymentError_AlreadyPaid;
   const factory PaymentError.paymentInProgress() = PaymentError_PaymentInProgress;
   const factory PaymentError.amountOutOfRange() = PaymentError_AmountOutOfRange;
+  const factory PaymentError.unrecognizedInput({
+    required String err,
+  }) = PaymentError_UnrecognizedInput;
+  const factory PaymentError.networkMismatch({
+    required String err,
+  }) = PaymentError_NetworkMismatch;
+  const factory PaymentError.lnUrlError({
+    required String err,
+  }) = PaymentError_LnUrlError;
+  const factory PaymentError.lnUrlServiceUnreachable({
+    required String err,
+  }) = PaymentError_LnUrlServiceUnreachable;
+  const factory PaymentError.lnUrlServiceError({
+    required String err,
+  }) = PaymentError_LnUrlServiceError;
   const factory PaymentError.generic({
     required String err,
   }) = PaymentError_Generic;
   const factory PaymentError.invalidOrExpiredFees() = PaymentError_InvalidOrExpiredFees;
+  const factory PaymentError.feeLimitExceeded({
+    required BigInt feeSat,
+    required BigInt limitSat,
+  }) = PaymentError_FeeLimitExceeded;
   const factory PaymentError.insufficientFunds() = PaymentError_InsufficientFunds;
   const factory PaymentError.invalidInvoice({
     required String err,
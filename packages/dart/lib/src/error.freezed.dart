@@ -371,6 +371,374 @@ abstract class PaymentError_AmountOutOfRange extends PaymentError {
   const PaymentError_AmountOutOfRange._() : super._();
 }
 
+/// @nodoc
+abstract class _$$PaymentError_UnrecognizedInputImplCopyWith<$Res> {
+  factory _$$PaymentError_UnrecognizedInputImplCopyWith(_$PaymentError_UnrecognizedInputImpl value,
+          $Res Function(_$PaymentError_UnrecognizedInputImpl) then) =
+      __$$PaymentError_UnrecognizedInputImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String err});
+}
+
+/// @nodoc
+class __$$PaymentError_UnrecognizedInputImplCopyWithImpl<$Res>
+    extends _$PaymentErrorCopyWithImpl<$Res, _$PaymentError_UnrecognizedInputImpl>
+    implements _$$PaymentError_UnrecognizedInputImplCopyWith<$Res> {
+  __$$PaymentError_UnrecognizedInputImplCopyWithImpl(
+      _$PaymentError_UnrecognizedInputImpl _value, $Res Function(_$PaymentError_UnrecognizedInputImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? err = null,
+  }) {
+    return _then(_$PaymentError_UnrecognizedInputImpl(
+      err: null == err
+          ? _value.err
+          : err // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$PaymentError_UnrecognizedInputImpl extends PaymentError_UnrecognizedInput {
+  const _$PaymentError_UnrecognizedInputImpl({required this.err}) : super._();
+
+  @override
+  final String err;
+
+  @override
+  String toString() {
+    return 'PaymentError.unrecognizedInput(err: $err)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$PaymentError_UnrecognizedInputImpl &&
+            (identical(other.err, err) || other.err == err));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, err);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$PaymentError_UnrecognizedInputImplCopyWith<_$PaymentError_UnrecognizedInputImpl> get copyWith =>
+      __$$PaymentError_UnrecognizedInputImplCopyWithImpl<_$PaymentError_UnrecognizedInputImpl>(
+          this, _$identity);
+}
+
+abstract class PaymentError_UnrecognizedInput extends PaymentError {
+  const factory PaymentError_UnrecognizedInput({required final String err}) =
+      _$PaymentError_UnrecognizedInputImpl;
+  const PaymentError_UnrecognizedInput._() : super._();
+
+  String get err;
+  @JsonKey(ignore: true)
+  _$$PaymentError_UnrecognizedInputImplCopyWith<_$PaymentError_UnrecognizedInputImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$PaymentError_NetworkMismatchImplCopyWith<$Res> {
+  factory _$$PaymentError_NetworkMismatchImplCopyWith(
+          _$PaymentError_NetworkMismatchImpl value, $Res Function(_$PaymentError_NetworkMismatchImpl) then) =
+      __$$PaymentError_NetworkMismatchImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String err});
+}
+
+/// @nodoc
+class __$$PaymentError_NetworkMismatchImplCopyWithImpl<$Res>
+    extends _$PaymentErrorCopyWithImpl<$Res, _$PaymentError_NetworkMismatchImpl>
+    implements _$$PaymentError_NetworkMismatchImplCopyWith<$Res> {
+  __$$PaymentError_NetworkMismatchImplCopyWithImpl(
+      _$PaymentError_NetworkMismatchImpl _value, $Res Function(_$PaymentError_NetworkMismatchImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? err = null,
+  }) {
+    return _then(_$PaymentError_NetworkMismatchImpl(
+      err: null == err
+          ? _value.err
+          : err // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$PaymentError_NetworkMismatchImpl extends PaymentError_NetworkMismatch {
+  const _$PaymentError_NetworkMismatchImpl({required this.err}) : super._();
+
+  @override
+  final String err;
+
+  @override
+  String toString() {
+    return 'PaymentError.networkMismatch(err: $err)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$PaymentError_NetworkMismatchImpl &&
+            (identical(other.err, err) || other.err == err));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, err);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$PaymentError_NetworkMismatchImplCopyWith<_$PaymentError_NetworkMismatchImpl> get copyWith =>
+      __$$PaymentError_NetworkMismatchImplCopyWithImpl<_$PaymentError_NetworkMismatchImpl>(this, _$identity);
+}
+
+abstract class PaymentError_NetworkMismatch extends PaymentError {
+  const factory PaymentError_NetworkMismatch({required final String err}) =
+      _$PaymentError_NetworkMismatchImpl;
+  const PaymentError_NetworkMismatch._() : super._();
+
+  String get err;
+  @JsonKey(ignore: true)
+  _$$PaymentError_NetworkMismatchImplCopyWith<_$PaymentError_NetworkMismatchImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$PaymentError_LnUrlErrorImplCopyWith<$Res> {
+  factory _$$PaymentError_LnUrlErrorImplCopyWith(
+          _$PaymentError_LnUrlErrorImpl value, $Res Function(_$PaymentError_LnUrlErrorImpl) then) =
+      __$$PaymentError_LnUrlErrorImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String err});
+}
+
+/// @nodoc
+class __$$PaymentError_LnUrlErrorImplCopyWithImpl<$Res>
+    extends _$PaymentErrorCopyWithImpl<$Res, _$PaymentError_LnUrlErrorImpl>
+    implements _$$PaymentError_LnUrlErrorImplCopyWith<$Res> {
+  __$$PaymentError_LnUrlErrorImplCopyWithImpl(
+      _$PaymentError_LnUrlErrorImpl _value, $Res Function(_$PaymentError_LnUrlErrorImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? err = null,
+  }) {
+    return _then(_$PaymentError_LnUrlErrorImpl(
+      err: null == err
+          ? _value.err
+          : err // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$PaymentError_LnUrlErrorImpl extends PaymentError_LnUrlError {
+  const _$PaymentError_LnUrlErrorImpl({required this.err}) : super._();
+
+  @override
+  final String err;
+
+  @override
+  String toString() {
+    return 'PaymentError.lnUrlError(err: $err)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$PaymentError_LnUrlErrorImpl &&
+            (identical(other.err, err) || other.err == err));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, err);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$PaymentError_LnUrlErrorImplCopyWith<_$PaymentError_LnUrlErrorImpl> get copyWith =>
+      __$$PaymentError_LnUrlErrorImplCopyWithImpl<_$PaymentError_LnUrlErrorImpl>(this, _$identity);
+}
+
+abstract class PaymentError_LnUrlError extends PaymentError {
+  const factory PaymentError_LnUrlError({required final String err}) = _$PaymentError_LnUrlErrorImpl;
+  const PaymentError_LnUrlError._() : super._();
+
+  String get err;
+  @JsonKey(ignore: true)
+  _$$PaymentError_LnUrlErrorImplCopyWith<_$PaymentError_LnUrlErrorImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$PaymentError_LnUrlServiceUnreachableImplCopyWith<$Res> {
+  factory _$$PaymentError_LnUrlServiceUnreachableImplCopyWith(
+          _$PaymentError_LnUrlServiceUnreachableImpl value,
+          $Res Function(_$PaymentError_LnUrlServiceUnreachableImpl) then) =
+      __$$PaymentError_LnUrlServiceUnreachableImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String err});
+}
+
+/// @nodoc
+class __$$PaymentError_LnUrlServiceUnreachableImplCopyWithImpl<$Res>
+    extends _$PaymentErrorCopyWithImpl<$Res, _$PaymentError_LnUrlServiceUnreachableImpl>
+    implements _$$PaymentError_LnUrlServiceUnreachableImplCopyWith<$Res> {
+  __$$PaymentError_LnUrlServiceUnreachableImplCopyWithImpl(_$PaymentError_LnUrlServiceUnreachableImpl _value,
+      $Res Function(_$PaymentError_LnUrlServiceUnreachableImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? err = null,
+  }) {
+    return _then(_$PaymentError_LnUrlServiceUnreachableImpl(
+      err: null == err
+          ? _value.err
+          : err // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$PaymentError_LnUrlServiceUnreachableImpl extends PaymentError_LnUrlServiceUnreachable {
+  const _$PaymentError_LnUrlServiceUnreachableImpl({required this.err}) : super._();
+
+  @override
+  final String err;
+
+  @override
+  String toString() {
+    return 'PaymentError.lnUrlServiceUnreachable(err: $err)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$PaymentError_LnUrlServiceUnreachableImpl &&
+            (identical(other.err, err) || other.err == err));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, err);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$PaymentError_LnUrlServiceUnreachableImplCopyWith<_$PaymentError_LnUrlServiceUnreachableImpl>
+      get copyWith => __$$PaymentError_LnUrlServiceUnreachableImplCopyWithImpl<
+          _$PaymentError_LnUrlServiceUnreachableImpl>(this, _$identity);
+}
+
+abstract class PaymentError_LnUrlServiceUnreachable extends PaymentError {
+  const factory PaymentError_LnUrlServiceUnreachable({required final String err}) =
+      _$PaymentError_LnUrlServiceUnreachableImpl;
+  const PaymentError_LnUrlServiceUnreachable._() : super._();
+
+  String get err;
+  @JsonKey(ignore: true)
+  _$$PaymentError_LnUrlServiceUnreachableImplCopyWith<_$PaymentError_LnUrlServiceUnreachableImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$PaymentError_LnUrlServiceErrorImplCopyWith<$Res> {
+  factory _$$PaymentError_LnUrlServiceErrorImplCopyWith(_$PaymentError_LnUrlServiceErrorImpl value,
+          $Res Function(_$PaymentError_LnUrlServiceErrorImpl) then) =
+      __$$PaymentError_LnUrlServiceErrorImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String err});
+}
+
+/// @nodoc
+class __$$PaymentError_LnUrlServiceErrorImplCopyWithImpl<$Res>
+    extends _$PaymentErrorCopyWithImpl<$Res, _$PaymentError_LnUrlServiceErrorImpl>
+    implements _$$PaymentError_LnUrlServiceErrorImplCopyWith<$Res> {
+  __$$PaymentError_LnUrlServiceErrorImplCopyWithImpl(
+      _$PaymentError_LnUrlServiceErrorImpl _value, $Res Function(_$PaymentError_LnUrlServiceErrorImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? err = null,
+  }) {
+    return _then(_$PaymentError_LnUrlServiceErrorImpl(
+      err: null == err
+          ? _value.err
+          : err // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$PaymentError_LnUrlServiceErrorImpl extends PaymentError_LnUrlServiceError {
+  const _$PaymentError_LnUrlServiceErrorImpl({required this.err}) : super._();
+
+  @override
+  final String err;
+
+  @override
+  String toString() {
+    return 'PaymentError.lnUrlServiceError(err: $err)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$PaymentError_LnUrlServiceErrorImpl &&
+            (identical(other.err, err) || other.err == err));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, err);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$PaymentError_LnUrlServiceErrorImplCopyWith<_$PaymentError_LnUrlServiceErrorImpl> get copyWith =>
+      __$$PaymentError_LnUrlServiceErrorImplCopyWithImpl<_$PaymentError_LnUrlServiceErrorImpl>(
+          this, _$identity);
+}
+
+abstract class PaymentError_LnUrlServiceError extends PaymentError {
+  const factory PaymentError_LnUrlServiceError({required final String err}) =
+      _$PaymentError_LnUrlServiceErrorImpl;
+  const PaymentError_LnUrlServiceError._() : super._();
+
+  String get err;
+  @JsonKey(ignore: true)
+  _$$PaymentError_LnUrlServiceErrorImplCopyWith<_$PaymentError_LnUrlServiceErrorImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
 /// @nodoc
 abstract class _$$PaymentError_GenericImplCopyWith<$Res> {
   factory _$$PaymentError_GenericImplCopyWith(
@@ -484,6 +852,89 @@ abstract class PaymentError_InvalidOrExpiredFees extends PaymentError {
   const PaymentError_InvalidOrExpiredFees._() : super._();
 }
 
+/// @nodoc
+abstract class _$$PaymentError_FeeLimitExceededImplCopyWith<$Res> {
+  factory _$$PaymentError_FeeLimitExceededImplCopyWith(_$PaymentError_FeeLimitExceededImpl value,
+          $Res Function(_$PaymentError_FeeLimitExceededImpl) then) =
+      __$$PaymentError_FeeLimitExceededImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({BigInt feeSat, BigInt limitSat});
+}
+
+/// @nodoc
+class __$$PaymentError_FeeLimitExceededImplCopyWithImpl<$Res>
+    extends _$PaymentErrorCopyWithImpl<$Res, _$PaymentError_FeeLimitExceededImpl>
+    implements _$$PaymentError_FeeLimitExceededImplCopyWith<$Res> {
+  __$$PaymentError_FeeLimitExceededImplCopyWithImpl(
+      _$PaymentError_FeeLimitExceededImpl _value, $Res Function(_$PaymentError_FeeLimitExceededImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? feeSat = null,
+    Object? limitSat = null,
+  }) {
+    return _then(_$PaymentError_FeeLimitExceededImpl(
+      feeSat: null == feeSat
+          ? _value.feeSat
+          : feeSat // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+      limitSat: null == limitSat
+          ? _value.limitSat
+          : limitSat // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$PaymentError_FeeLimitExceededImpl extends PaymentError_FeeLimitExceeded {
+  const _$PaymentError_FeeLimitExceededImpl({required this.feeSat, required this.limitSat}) : super._();
+
+  @override
+  final BigInt feeSat;
+  @override
+  final BigInt limitSat;
+
+  @override
+  String toString() {
+    return 'PaymentError.feeLimitExceeded(feeSat: $feeSat, limitSat: $limitSat)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$PaymentError_FeeLimitExceededImpl &&
+            (identical(other.feeSat, feeSat) || other.feeSat == feeSat) &&
+            (identical(other.limitSat, limitSat) || other.limitSat == limitSat));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, feeSat, limitSat);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$PaymentError_FeeLimitExceededImplCopyWith<_$PaymentError_FeeLimitExceededImpl> get copyWith =>
+      __$$PaymentError_FeeLimitExceededImplCopyWithImpl<_$PaymentError_FeeLimitExceededImpl>(
+          this, _$identity);
+}
+
+abstract class PaymentError_FeeLimitExceeded extends PaymentError {
+  const factory PaymentError_FeeLimitExceeded(
+      {required final BigInt feeSat, required final BigInt limitSat}) = _$PaymentError_FeeLimitExceededImpl;
+  const PaymentError_FeeLimitExceeded._() : super._();
+
+  BigInt get feeSat;
+  BigInt get limitSat;
+  @JsonKey(ignore: true)
+  _$$PaymentError_FeeLimitExceededImplCopyWith<_$PaymentError_FeeLimitExceededImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
 /// @nodoc
 abstract class _$$PaymentError_InsufficientFundsImplCopyWith<$Res> {
   factory _$$PaymentError_InsufficientFundsImplCopyWith(_$PaymentError_InsufficientFundsImpl value,
@@ -54,7 +54,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.0.0-dev.38';
 
   @override
-  int get rustContentHash => 308302012;
+  int get rustContentHash => -837504212;
 
   static const kDefaultExternalLibraryLoaderConfig = ExternalLibraryLoaderConfig(
     stem: 'breez_liquid_sdk',
@@ -72,24 +72,83 @@ abstract class RustLibApi extends BaseApi {
 
   void crateBindingsBindingLiquidSdkEmptyWalletCache({required BindingLiquidSdk that});
 
+  Future<ExportPaymentsResponse> crateBindingsBindingLiquidSdkExportPayments(
+      {required BindingLiquidSdk that, required ExportPaymentsRequest req});
+
+  Future<LightningPaymentLimitsResponse> crateBindingsBindingLiquidSdkFetchLightningLimits(
+      {required BindingLiquidSdk that});
+
+  Future<RecommendedFeesResponse> crateBindingsBindingLiquidSdkFetchRecommendedFees(
+      {required BindingLiquidSdk that});
+
   Future<GetInfoResponse> crateBindingsBindingLiquidSdkGetInfo({required BindingLiquidSdk that});
 
-  Future<List<Payment>> crateBindingsBindingLiquidSdkListPayments({required BindingLiquidSdk that});
+  Future<Payment?> crateBindingsBindingLiquidSdkGetPayment(
+      {required BindingLiquidSdk that, required GetPaymentRequest req});
+
+  Future<List<ElectrumServerStatus>> crateBindingsBindingLiquidSdkListElectrumServers(
+      {required BindingLiquidSdk that});
+
+  Future<List<Payment>> crateBindingsBindingLiquidSdkListPayments(
+      {required BindingLiquidSdk that, required ListPaymentsRequest req});
+
+  Future<List<RefundableSwap>> crateBindingsBindingLiquidSdkListRefundables({required BindingLiquidSdk that});
+
+  Future<LnUrlPayResult> crateBindingsBindingLiquidSdkLnurlPay(
+      {required BindingLiquidSdk that, required PrepareLnUrlPayResponse req});
+
+  Future<LnUrlWithdrawResult> crateBindingsBindingLiquidSdkLnurlWithdraw(
+      {required BindingLiquidSdk that, required LnUrlWithdrawRequest req});
+
+  InputType crateBindingsBindingLiquidSdkParse({required BindingLiquidSdk that, required String input});
+
+  Future<SendPaymentResponse> crateBindingsBindingLiquidSdkPayOnchain(
+      {required BindingLiquidSdk that, required PayOnchainRequest req});
+
+  Future<PrepareLnUrlPayResponse> crateBindingsBindingLiquidSdkPrepareLnurlPay(
+      {required BindingLiquidSdk that, required PrepareLnUrlPayRequest req});
+
+  Future<PreparePayOnchainResponse> crateBindingsBindingLiquidSdkPreparePayOnchain(
+      {required BindingLiquidSdk that, required PreparePayOnchainRequest req});
+
+  Future<PrepareReceiveOnchainResponse> crateBindingsBindingLiquidSdkPrepareReceiveOnchain(
+      {required BindingLiquidSdk that, required PrepareReceiveOnchainRequest req});
 
   Future<PrepareReceiveResponse> crateBindingsBindingLiquidSdkPrepareReceivePayment(
       {required BindingLiquidSdk that, required PrepareReceiveRequest req});
 
+  Future<PrepareRefundResponse> crateBindingsBindingLiquidSdkPrepareRefund(
+      {required BindingLiquidSdk that, required PrepareRefundRequest req});
+
+  Future<PrepareSendOnchainResponse> crateBindingsBindingLiquidSdkPrepareSendOnchain(
+      {required BindingLiquidSdk that, required PrepareSendOnchainRequest req});
+
   Future<PrepareSendResponse> crateBindingsBindingLiquidSdkPrepareSendPayment(
       {required BindingLiquidSdk that, required PrepareSendRequest req});
 
+  Future<ReceiveLiquidResponse> crateBindingsBindingLiquidSdkReceiveLiquid(
+      {required BindingLiquidSdk that, required ReceiveLiquidRequest req});
+
+  Future<ReceiveOnchainResponse> crateBindingsBindingLiquidSdkReceiveOnchain(
+      {required BindingLiquidSdk that, required PrepareReceiveOnchainResponse req});
+
   Future<ReceivePaymentResponse> crateBindingsBindingLiquidSdkReceivePayment(
       {required BindingLiquidSdk that, required PrepareReceiveResponse req});
 
+  Future<RefundResponse> crateBindingsBindingLiquidSdkRefund(
+      {required BindingLiquidSdk that, required RefundRequest req});
+
   void crateBindingsBindingLiquidSdkRestore({required BindingLiquidSdk that, required RestoreRequest req});
 
+  Future<SendPaymentResponse> crateBindingsBindingLiquidSdkSendOnchain(
+      {required BindingLiquidSdk that, required PrepareSendOnchainResponse req});
+
   Future<SendPaymentResponse> crateBindingsBindingLiquidSdkSendPayment(
       {required BindingLiquidSdk that, required PrepareSendResponse req});
 
+  Future<void> crateBindingsBindingLiquidSdkSetPaymentMetadata(
+      {required BindingLiquidSdk that, required SetPaymentMetadataRequest req});
+
   Future<void> crateBindingsBindingLiquidSdkSync({required BindingLiquidSdk that});
 
   Future<void> crateBindingsBindingEventListenerOnEvent(
@@ -219,1797 +278,4975 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       );
 
   @override
-  Future<GetInfoResponse> crateBindingsBindingLiquidSdkGetInfo({required BindingLiquidSdk that}) {
+  Future<ExportPaymentsResponse> crateBindingsBindingLiquidSdkExportPayments(
+      {required BindingLiquidSdk that, required ExportPaymentsRequest req}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         var arg0 =
             cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
                 that);
-        return wire.wire__crate__bindings__BindingLiquidSdk_get_info(port_, arg0);
+        var arg1 = cst_encode_box_autoadd_export_payments_request(req);
+        return wire.wire__crate__bindings__BindingLiquidSdk_export_payments(port_, arg0, arg1);
       },
       codec: DcoCodec(
-        decodeSuccessData: dco_decode_get_info_response,
-        decodeErrorData: dco_decode_liquid_sdk_error,
+        decodeSuccessData: dco_decode_export_payments_response,
+        decodeErrorData: dco_decode_payment_error,
       ),
-      constMeta: kCrateBindingsBindingLiquidSdkGetInfoConstMeta,
-      argValues: [that],
+      constMeta: kCrateBindingsBindingLiquidSdkExportPaymentsConstMeta,
+      argValues: [that, req],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateBindingsBindingLiquidSdkGetInfoConstMeta => const TaskConstMeta(
-        debugName: "BindingLiquidSdk_get_info",
-        argNames: ["that"],
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkExportPaymentsConstMeta => const TaskConstMeta(
+        debugName: "BindingLiquidSdk_export_payments",
+        argNames: ["that", "req"],
       );
 
   @override
-  Future<List<Payment>> crateBindingsBindingLiquidSdkListPayments({required BindingLiquidSdk that}) {
+  Future<LightningPaymentLimitsResponse> crateBindingsBindingLiquidSdkFetchLightningLimits(
+      {required BindingLiquidSdk that}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         var arg0 =
             cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
                 that);
-        return wire.wire__crate__bindings__BindingLiquidSdk_list_payments(port_, arg0);
+        return wire.wire__crate__bindings__BindingLiquidSdk_fetch_lightning_limits(port_, arg0);
       },
       codec: DcoCodec(
-        decodeSuccessData: dco_decode_list_payment,
+        decodeSuccessData: dco_decode_lightning_payment_limits_response,
         decodeErrorData: dco_decode_payment_error,
       ),
-      constMeta: kCrateBindingsBindingLiquidSdkListPaymentsConstMeta,
+      constMeta: kCrateBindingsBindingLiquidSdkFetchLightningLimitsConstMeta,
       argValues: [that],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateBindingsBindingLiquidSdkListPaymentsConstMeta => const TaskConstMeta(
-        debugName: "BindingLiquidSdk_list_payments",
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkFetchLightningLimitsConstMeta => const TaskConstMeta(
+        debugName: "BindingLiquidSdk_fetch_lightning_limits",
         argNames: ["that"],
       );
 
   @override
-  Future<PrepareReceiveResponse> crateBindingsBindingLiquidSdkPrepareReceivePayment(
-      {required BindingLiquidSdk that, required PrepareReceiveRequest req}) {
+  Future<RecommendedFeesResponse> crateBindingsBindingLiquidSdkFetchRecommendedFees(
+      {required BindingLiquidSdk that}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         var arg0 =
             cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
                 that);
-        var arg1 = cst_encode_box_autoadd_prepare_receive_request(req);
-        return wire.wire__crate__bindings__BindingLiquidSdk_prepare_receive_payment(port_, arg0, arg1);
+        return wire.wire__crate__bindings__BindingLiquidSdk_fetch_recommended_fees(port_, arg0);
       },
       codec: DcoCodec(
-        decodeSuccessData: dco_decode_prepare_receive_response,
+        decodeSuccessData: dco_decode_recommended_fees_response,
         decodeErrorData: dco_decode_payment_error,
       ),
-      constMeta: kCrateBindingsBindingLiquidSdkPrepareReceivePaymentConstMeta,
-      argValues: [that, req],
+      constMeta: kCrateBindingsBindingLiquidSdkFetchRecommendedFeesConstMeta,
+      argValues: [that],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateBindingsBindingLiquidSdkPrepareReceivePaymentConstMeta => const TaskConstMeta(
-        debugName: "BindingLiquidSdk_prepare_receive_payment",
-        argNames: ["that", "req"],
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkFetchRecommendedFeesConstMeta => const TaskConstMeta(
+        debugName: "BindingLiquidSdk_fetch_recommended_fees",
+        argNames: ["that"],
       );
 
   @override
-  Future<PrepareSendResponse> crateBindingsBindingLiquidSdkPrepareSendPayment(
-      {required BindingLiquidSdk that, required PrepareSendRequest req}) {
+  Future<GetInfoResponse> crateBindingsBindingLiquidSdkGetInfo({required BindingLiquidSdk that}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         var arg0 =
             cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
                 that);
-        var arg1 = cst_encode_box_autoadd_prepare_send_request(req);
-        return wire.wire__crate__bindings__BindingLiquidSdk_prepare_send_payment(port_, arg0, arg1);
+        return wire.wire__crate__bindings__BindingLiquidSdk_get_info(port_, arg0);
       },
       codec: DcoCodec(
-        decodeSuccessData: dco_decode_prepare_send_response,
-        decodeErrorData: dco_decode_payment_error,
+        decodeSuccessData: dco_decode_get_info_response,
+        decodeErrorData: dco_decode_liquid_sdk_error,
       ),
-      constMeta: kCrateBindingsBindingLiquidSdkPrepareSendPaymentConstMeta,
-      argValues: [that, req],
+      constMeta: kCrateBindingsBindingLiquidSdkGetInfoConstMeta,
+      argValues: [that],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateBindingsBindingLiquidSdkPrepareSendPaymentConstMeta => const TaskConstMeta(
-        debugName: "BindingLiquidSdk_prepare_send_payment",
-        argNames: ["that", "req"],
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkGetInfoConstMeta => const TaskConstMeta(
+        debugName: "BindingLiquidSdk_get_info",
+        argNames: ["that"],
       );
 
   @override
-  Future<ReceivePaymentResponse> crateBindingsBindingLiquidSdkReceivePayment(
-      {required BindingLiquidSdk that, required PrepareReceiveResponse req}) {
+  Future<Payment?> crateBindingsBindingLiquidSdkGetPayment(
+      {required BindingLiquidSdk that, required GetPaymentRequest req}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         var arg0 =
             cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
                 that);
-        var arg1 = cst_encode_box_autoadd_prepare_receive_response(req);
-        return wire.wire__crate__bindings__BindingLiquidSdk_receive_payment(port_, arg0, arg1);
+        var arg1 = cst_encode_box_autoadd_get_payment_request(req);
+        return wire.wire__crate__bindings__BindingLiquidSdk_get_payment(port_, arg0, arg1);
       },
       codec: DcoCodec(
-        decodeSuccessData: dco_decode_receive_payment_response,
+        decodeSuccessData: dco_decode_opt_box_autoadd_payment,
         decodeErrorData: dco_decode_payment_error,
       ),
-      constMeta: kCrateBindingsBindingLiquidSdkReceivePaymentConstMeta,
+      constMeta: kCrateBindingsBindingLiquidSdkGetPaymentConstMeta,
       argValues: [that, req],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateBindingsBindingLiquidSdkReceivePaymentConstMeta => const TaskConstMeta(
-        debugName: "BindingLiquidSdk_receive_payment",
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkGetPaymentConstMeta => const TaskConstMeta(
+        debugName: "BindingLiquidSdk_get_payment",
         argNames: ["that", "req"],
       );
 
   @override
-  void crateBindingsBindingLiquidSdkRestore({required BindingLiquidSdk that, required RestoreRequest req}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
+  Future<List<ElectrumServerStatus>> crateBindingsBindingLiquidSdkListElectrumServers(
+      {required BindingLiquidSdk that}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
         var arg0 =
             cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
                 that);
-        var arg1 = cst_encode_box_autoadd_restore_request(req);
-        return wire.wire__crate__bindings__BindingLiquidSdk_restore(arg0, arg1);
+        return wire.wire__crate__bindings__BindingLiquidSdk_list_electrum_servers(port_, arg0);
       },
       codec: DcoCodec(
-        decodeSuccessData: dco_decode_unit,
+        decodeSuccessData: dco_decode_list_electrum_server_status,
         decodeErrorData: dco_decode_liquid_sdk_error,
       ),
-      constMeta: kCrateBindingsBindingLiquidSdkRestoreConstMeta,
-      argValues: [that, req],
+      constMeta: kCrateBindingsBindingLiquidSdkListElectrumServersConstMeta,
+      argValues: [that],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateBindingsBindingLiquidSdkRestoreConstMeta => const TaskConstMeta(
-        debugName: "BindingLiquidSdk_restore",
-        argNames: ["that", "req"],
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkListElectrumServersConstMeta => const TaskConstMeta(
+        debugName: "BindingLiquidSdk_list_electrum_servers",
+        argNames: ["that"],
       );
 
   @override
-  Future<SendPaymentResponse> crateBindingsBindingLiquidSdkSendPayment(
-      {required BindingLiquidSdk that, required PrepareSendResponse req}) {
+  Future<List<Payment>> crateBindingsBindingLiquidSdkListPayments(
+      {required BindingLiquidSdk that, required ListPaymentsRequest req}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         var arg0 =
             cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
                 that);
-        var arg1 = cst_encode_box_autoadd_prepare_send_response(req);
-        return wire.wire__crate__bindings__BindingLiquidSdk_send_payment(port_, arg0, arg1);
+        var arg1 = cst_encode_box_autoadd_list_payments_request(req);
+        return wire.wire__crate__bindings__BindingLiquidSdk_list_payments(port_, arg0, arg1);
       },
       codec: DcoCodec(
-        decodeSuccessData: dco_decode_send_payment_response,
+        decodeSuccessData: dco_decode_list_payment,
         decodeErrorData: dco_decode_payment_error,
       ),
-      constMeta: kCrateBindingsBindingLiquidSdkSendPaymentConstMeta,
+      constMeta: kCrateBindingsBindingLiquidSdkListPaymentsConstMeta,
       argValues: [that, req],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateBindingsBindingLiquidSdkSendPaymentConstMeta => const TaskConstMeta(
-        debugName: "BindingLiquidSdk_send_payment",
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkListPaymentsConstMeta => const TaskConstMeta(
+        debugName: "BindingLiquidSdk_list_payments",
         argNames: ["that", "req"],
       );
 
   @override
-  Future<void> crateBindingsBindingLiquidSdkSync({required BindingLiquidSdk that}) {
+  Future<List<RefundableSwap>> crateBindingsBindingLiquidSdkListRefundables(
+      {required BindingLiquidSdk that}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         var arg0 =
             cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
                 that);
-        return wire.wire__crate__bindings__BindingLiquidSdk_sync(port_, arg0);
+        return wire.wire__crate__bindings__BindingLiquidSdk_list_refundables(port_, arg0);
       },
       codec: DcoCodec(
-        decodeSuccessData: dco_decode_unit,
+        decodeSuccessData: dco_decode_list_refundable_swap,
         decodeErrorData: dco_decode_liquid_sdk_error,
       ),
-      constMeta: kCrateBindingsBindingLiquidSdkSyncConstMeta,
+      constMeta: kCrateBindingsBindingLiquidSdkListRefundablesConstMeta,
       argValues: [that],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateBindingsBindingLiquidSdkSyncConstMeta => const TaskConstMeta(
-        debugName: "BindingLiquidSdk_sync",
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkListRefundablesConstMeta => const TaskConstMeta(
+        debugName: "BindingLiquidSdk_list_refundables",
         argNames: ["that"],
       );
 
   @override
-  Future<void> crateBindingsBindingEventListenerOnEvent(
-      {required BindingEventListener that, required LiquidSdkEvent e}) {
+  Future<LnUrlPayResult> crateBindingsBindingLiquidSdkLnurlPay(
+      {required BindingLiquidSdk that, required PrepareLnUrlPayResponse req}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
-        var arg0 = cst_encode_box_autoadd_binding_event_listener(that);
-        var arg1 = cst_encode_box_autoadd_liquid_sdk_event(e);
-        return wire.wire__crate__bindings__binding_event_listener_on_event(port_, arg0, arg1);
+        var arg0 =
+            cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that);
+        var arg1 = cst_encode_box_autoadd_prepare_ln_url_pay_response(req);
+        return wire.wire__crate__bindings__BindingLiquidSdk_lnurl_pay(port_, arg0, arg1);
       },
       codec: DcoCodec(
-        decodeSuccessData: dco_decode_unit,
-        decodeErrorData: null,
+        decodeSuccessData: dco_decode_ln_url_pay_result,
+        decodeErrorData: dco_decode_payment_error,
       ),
-      constMeta: kCrateBindingsBindingEventListenerOnEventConstMeta,
-      argValues: [that, e],
+      constMeta: kCrateBindingsBindingLiquidSdkLnurlPayConstMeta,
+      argValues: [that, req],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateBindingsBindingEventListenerOnEventConstMeta => const TaskConstMeta(
-        debugName: "binding_event_listener_on_event",
-        argNames: ["that", "e"],
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkLnurlPayConstMeta => const TaskConstMeta(
+        debugName: "BindingLiquidSdk_lnurl_pay",
+        argNames: ["that", "req"],
       );
 
   @override
-  Stream<LogEntry> crateBindingsBreezLogStream() {
-    final s = RustStreamSink<LogEntry>();
-    unawaited(handler.executeNormal(NormalTask(
+  Future<LnUrlWithdrawResult> crateBindingsBindingLiquidSdkLnurlWithdraw(
+      {required BindingLiquidSdk that, required LnUrlWithdrawRequest req}) {
+    return handler.executeNormal(NormalTask(
       callFfi: (port_) {
-        var arg0 = cst_encode_StreamSink_log_entry_Dco(s);
-        return wire.wire__crate__bindings__breez_log_stream(port_, arg0);
+        var arg0 =
+            cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that);
+        var arg1 = cst_encode_box_autoadd_ln_url_withdraw_request(req);
+        return wire.wire__crate__bindings__BindingLiquidSdk_lnurl_withdraw(port_, arg0, arg1);
       },
       codec: DcoCodec(
-        decodeSuccessData: dco_decode_unit,
-        decodeErrorData: dco_decode_AnyhowException,
+        decodeSuccessData: dco_decode_ln_url_withdraw_result,
+        decodeErrorData: dco_decode_payment_error,
       ),
-      constMeta: kCrateBindingsBreezLogStreamConstMeta,
-      argValues: [s],
+      constMeta: kCrateBindingsBindingLiquidSdkLnurlWithdrawConstMeta,
+      argValues: [that, req],
       apiImpl: this,
-    )));
-    return s.stream;
+    ));
   }
 
-  TaskConstMeta get kCrateBindingsBreezLogStreamConstMeta => const TaskConstMeta(
-        debugName: "breez_log_stream",
-        argNames: ["s"],
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkLnurlWithdrawConstMeta => const TaskConstMeta(
+        debugName: "BindingLiquidSdk_lnurl_withdraw",
+        argNames: ["that", "req"],
       );
 
   @override
-  Future<BindingLiquidSdk> crateBindingsConnect({required ConnectRequest req}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        var arg0 = cst_encode_box_autoadd_connect_request(req);
-        return wire.wire__crate__bindings__connect(port_, arg0);
+  InputType crateBindingsBindingLiquidSdkParse({required BindingLiquidSdk that, required String input}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 =
+            cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that);
+        var arg1 = cst_encode_String(input);
+        return wire.wire__crate__bindings__BindingLiquidSdk_parse(arg0, arg1);
       },
       codec: DcoCodec(
-        decodeSuccessData:
-            dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk,
-        decodeErrorData: dco_decode_liquid_sdk_error,
+        decodeSuccessData: dco_decode_input_type,
+        decodeErrorData: dco_decode_payment_error,
       ),
-      constMeta: kCrateBindingsConnectConstMeta,
-      argValues: [req],
+      constMeta: kCrateBindingsBindingLiquidSdkParseConstMeta,
+      argValues: [that, input],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateBindingsConnectConstMeta => const TaskConstMeta(
-        debugName: "connect",
-        argNames: ["req"],
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkParseConstMeta => const TaskConstMeta(
+        debugName: "BindingLiquidSdk_parse",
+        argNames: ["that", "input"],
       );
 
   @override
-  Config crateBindingsDefaultConfig({required Network network}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_network(network);
-        return wire.wire__crate__bindings__default_config(arg0);
+  Future<SendPaymentResponse> crateBindingsBindingLiquidSdkPayOnchain(
+      {required BindingLiquidSdk that, required PayOnchainRequest req}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 =
+            cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that);
+        var arg1 = cst_encode_box_autoadd_pay_onchain_request(req);
+        return wire.wire__crate__bindings__BindingLiquidSdk_pay_onchain(port_, arg0, arg1);
       },
       codec: DcoCodec(
-        decodeSuccessData: dco_decode_config,
-        decodeErrorData: null,
+        decodeSuccessData: dco_decode_send_payment_response,
+        decodeErrorData: dco_decode_payment_error,
       ),
-      constMeta: kCrateBindingsDefaultConfigConstMeta,
-      argValues: [network],
+      constMeta: kCrateBindingsBindingLiquidSdkPayOnchainConstMeta,
+      argValues: [that, req],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateBindingsDefaultConfigConstMeta => const TaskConstMeta(
-        debugName: "default_config",
-        argNames: ["network"],
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkPayOnchainConstMeta => const TaskConstMeta(
+        debugName: "BindingLiquidSdk_pay_onchain",
+        argNames: ["that", "req"],
       );
 
   @override
-  LNInvoice crateBindingsParseInvoice({required String input}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        var arg0 = cst_encode_String(input);
-        return wire.wire__crate__bindings__parse_invoice(arg0);
+  Future<PrepareLnUrlPayResponse> crateBindingsBindingLiquidSdkPrepareLnurlPay(
+      {required BindingLiquidSdk that, required PrepareLnUrlPayRequest req}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 =
+            cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that);
+        var arg1 = cst_encode_box_autoadd_prepare_ln_url_pay_request(req);
+        return wire.wire__crate__bindings__BindingLiquidSdk_prepare_lnurl_pay(port_, arg0, arg1);
       },
       codec: DcoCodec(
-        decodeSuccessData: dco_decode_ln_invoice,
+        decodeSuccessData: dco_decode_prepare_ln_url_pay_response,
         decodeErrorData: dco_decode_payment_error,
       ),
-      constMeta: kCrateBindingsParseInvoiceConstMeta,
-      argValues: [input],
+      constMeta: kCrateBindingsBindingLiquidSdkPrepareLnurlPayConstMeta,
+      argValues: [that, req],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateBindingsParseInvoiceConstMeta => const TaskConstMeta(
-        debugName: "parse_invoice",
-        argNames: ["input"],
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkPrepareLnurlPayConstMeta => const TaskConstMeta(
+        debugName: "BindingLiquidSdk_prepare_lnurl_pay",
+        argNames: ["that", "req"],
       );
 
-  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_BindingLiquidSdk => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk;
+  @override
+  Future<PreparePayOnchainResponse> crateBindingsBindingLiquidSdkPreparePayOnchain(
+      {required BindingLiquidSdk that, required PreparePayOnchainRequest req}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 =
+            cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that);
+        var arg1 = cst_encode_box_autoadd_prepare_pay_onchain_request(req);
+        return wire.wire__crate__bindings__BindingLiquidSdk_prepare_pay_onchain(port_, arg0, arg1);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_prepare_pay_onchain_response,
+        decodeErrorData: dco_decode_payment_error,
+      ),
+      constMeta: kCrateBindingsBindingLiquidSdkPreparePayOnchainConstMeta,
+      argValues: [that, req],
+      apiImpl: this,
+    ));
+  }
 
-  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_BindingLiquidSdk => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk;
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkPreparePayOnchainConstMeta => const TaskConstMeta(
+        debugName: "BindingLiquidSdk_prepare_pay_onchain",
+        argNames: ["that", "req"],
+      );
 
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AnyhowException(raw as String);
+  @override
+  Future<PrepareReceiveOnchainResponse> crateBindingsBindingLiquidSdkPrepareReceiveOnchain(
+      {required BindingLiquidSdk that, required PrepareReceiveOnchainRequest req}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 =
+            cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that);
+        var arg1 = cst_encode_box_autoadd_prepare_receive_onchain_request(req);
+        return wire.wire__crate__bindings__BindingLiquidSdk_prepare_receive_onchain(port_, arg0, arg1);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_prepare_receive_onchain_response,
+        decodeErrorData: dco_decode_payment_error,
+      ),
+      constMeta: kCrateBindingsBindingLiquidSdkPrepareReceiveOnchainConstMeta,
+      argValues: [that, req],
+      apiImpl: this,
+    ));
   }
 
-  @protected
-  BindingLiquidSdk
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return BindingLiquidSdkImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkPrepareReceiveOnchainConstMeta => const TaskConstMeta(
+        debugName: "BindingLiquidSdk_prepare_receive_onchain",
+        argNames: ["that", "req"],
+      );
 
-  @protected
-  BindingLiquidSdk
-      dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+  @override
+  Future<PrepareReceiveResponse> crateBindingsBindingLiquidSdkPrepareReceivePayment(
+      {required BindingLiquidSdk that, required PrepareReceiveRequest req}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 =
+            cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that);
+        var arg1 = cst_encode_box_autoadd_prepare_receive_request(req);
+        return wire.wire__crate__bindings__BindingLiquidSdk_prepare_receive_payment(port_, arg0, arg1);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_prepare_receive_response,
+        decodeErrorData: dco_decode_payment_error,
+      ),
+      constMeta: kCrateBindingsBindingLiquidSdkPrepareReceivePaymentConstMeta,
+      argValues: [that, req],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkPrepareReceivePaymentConstMeta => const TaskConstMeta(
+        debugName: "BindingLiquidSdk_prepare_receive_payment",
+        argNames: ["that", "req"],
+      );
+
+  @override
+  Future<PrepareRefundResponse> crateBindingsBindingLiquidSdkPrepareRefund(
+      {required BindingLiquidSdk that, required PrepareRefundRequest req}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 =
+            cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that);
+        var arg1 = cst_encode_box_autoadd_prepare_refund_request(req);
+        return wire.wire__crate__bindings__BindingLiquidSdk_prepare_refund(port_, arg0, arg1);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_prepare_refund_response,
+        decodeErrorData: dco_decode_payment_error,
+      ),
+      constMeta: kCrateBindingsBindingLiquidSdkPrepareRefundConstMeta,
+      argValues: [that, req],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkPrepareRefundConstMeta => const TaskConstMeta(
+        debugName: "BindingLiquidSdk_prepare_refund",
+        argNames: ["that", "req"],
+      );
+
+  @override
+  Future<PrepareSendOnchainResponse> crateBindingsBindingLiquidSdkPrepareSendOnchain(
+      {required BindingLiquidSdk that, required PrepareSendOnchainRequest req}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 =
+            cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that);
+        var arg1 = cst_encode_box_autoadd_prepare_send_onchain_request(req);
+        return wire.wire__crate__bindings__BindingLiquidSdk_prepare_send_onchain(port_, arg0, arg1);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_prepare_send_onchain_response,
+        decodeErrorData: dco_decode_payment_error,
+      ),
+      constMeta: kCrateBindingsBindingLiquidSdkPrepareSendOnchainConstMeta,
+      argValues: [that, req],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkPrepareSendOnchainConstMeta => const TaskConstMeta(
+        debugName: "BindingLiquidSdk_prepare_send_onchain",
+        argNames: ["that", "req"],
+      );
+
+  @override
+  Future<PrepareSendResponse> crateBindingsBindingLiquidSdkPrepareSendPayment(
+      {required BindingLiquidSdk that, required PrepareSendRequest req}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 =
+            cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that);
+        var arg1 = cst_encode_box_autoadd_prepare_send_request(req);
+        return wire.wire__crate__bindings__BindingLiquidSdk_prepare_send_payment(port_, arg0, arg1);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_prepare_send_response,
+        decodeErrorData: dco_decode_payment_error,
+      ),
+      constMeta: kCrateBindingsBindingLiquidSdkPrepareSendPaymentConstMeta,
+      argValues: [that, req],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkPrepareSendPaymentConstMeta => const TaskConstMeta(
+        debugName: "BindingLiquidSdk_prepare_send_payment",
+        argNames: ["that", "req"],
+      );
+
+  @override
+  Future<ReceiveLiquidResponse> crateBindingsBindingLiquidSdkReceiveLiquid(
+      {required BindingLiquidSdk that, required ReceiveLiquidRequest req}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 =
+            cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that);
+        var arg1 = cst_encode_box_autoadd_receive_liquid_request(req);
+        return wire.wire__crate__bindings__BindingLiquidSdk_receive_liquid(port_, arg0, arg1);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_receive_liquid_response,
+        decodeErrorData: dco_decode_payment_error,
+      ),
+      constMeta: kCrateBindingsBindingLiquidSdkReceiveLiquidConstMeta,
+      argValues: [that, req],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkReceiveLiquidConstMeta => const TaskConstMeta(
+        debugName: "BindingLiquidSdk_receive_liquid",
+        argNames: ["that", "req"],
+      );
+
+  @override
+  Future<ReceiveOnchainResponse> crateBindingsBindingLiquidSdkReceiveOnchain(
+      {required BindingLiquidSdk that, required PrepareReceiveOnchainResponse req}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 =
+            cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that);
+        var arg1 = cst_encode_box_autoadd_prepare_receive_onchain_response(req);
+        return wire.wire__crate__bindings__BindingLiquidSdk_receive_onchain(port_, arg0, arg1);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_receive_onchain_response,
+        decodeErrorData: dco_decode_payment_error,
+      ),
+      constMeta: kCrateBindingsBindingLiquidSdkReceiveOnchainConstMeta,
+      argValues: [that, req],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkReceiveOnchainConstMeta => const TaskConstMeta(
+        debugName: "BindingLiquidSdk_receive_onchain",
+        argNames: ["that", "req"],
+      );
+
+  @override
+  Future<ReceivePaymentResponse> crateBindingsBindingLiquidSdkReceivePayment(
+      {required BindingLiquidSdk that, required PrepareReceiveResponse req}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 =
+            cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that);
+        var arg1 = cst_encode_box_autoadd_prepare_receive_response(req);
+        return wire.wire__crate__bindings__BindingLiquidSdk_receive_payment(port_, arg0, arg1);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_receive_payment_response,
+        decodeErrorData: dco_decode_payment_error,
+      ),
+      constMeta: kCrateBindingsBindingLiquidSdkReceivePaymentConstMeta,
+      argValues: [that, req],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkReceivePaymentConstMeta => const TaskConstMeta(
+        debugName: "BindingLiquidSdk_receive_payment",
+        argNames: ["that", "req"],
+      );
+
+  @override
+  Future<RefundResponse> crateBindingsBindingLiquidSdkRefund(
+      {required BindingLiquidSdk that, required RefundRequest req}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 =
+            cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that);
+        var arg1 = cst_encode_box_autoadd_refund_request(req);
+        return wire.wire__crate__bindings__BindingLiquidSdk_refund(port_, arg0, arg1);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_refund_response,
+        decodeErrorData: dco_decode_payment_error,
+      ),
+      constMeta: kCrateBindingsBindingLiquidSdkRefundConstMeta,
+      argValues: [that, req],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkRefundConstMeta => const TaskConstMeta(
+        debugName: "BindingLiquidSdk_refund",
+        argNames: ["that", "req"],
+      );
+
+  @override
+  void crateBindingsBindingLiquidSdkRestore({required BindingLiquidSdk that, required RestoreRequest req}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 =
+            cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that);
+        var arg1 = cst_encode_box_autoadd_restore_request(req);
+        return wire.wire__crate__bindings__BindingLiquidSdk_restore(arg0, arg1);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_unit,
+        decodeErrorData: dco_decode_liquid_sdk_error,
+      ),
+      constMeta: kCrateBindingsBindingLiquidSdkRestoreConstMeta,
+      argValues: [that, req],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkRestoreConstMeta => const TaskConstMeta(
+        debugName: "BindingLiquidSdk_restore",
+        argNames: ["that", "req"],
+      );
+
+  @override
+  Future<SendPaymentResponse> crateBindingsBindingLiquidSdkSendOnchain(
+      {required BindingLiquidSdk that, required PrepareSendOnchainResponse req}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 =
+            cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that);
+        var arg1 = cst_encode_box_autoadd_prepare_send_onchain_response(req);
+        return wire.wire__crate__bindings__BindingLiquidSdk_send_onchain(port_, arg0, arg1);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_send_payment_response,
+        decodeErrorData: dco_decode_payment_error,
+      ),
+      constMeta: kCrateBindingsBindingLiquidSdkSendOnchainConstMeta,
+      argValues: [that, req],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkSendOnchainConstMeta => const TaskConstMeta(
+        debugName: "BindingLiquidSdk_send_onchain",
+        argNames: ["that", "req"],
+      );
+
+  @override
+  Future<SendPaymentResponse> crateBindingsBindingLiquidSdkSendPayment(
+      {required BindingLiquidSdk that, required PrepareSendResponse req}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 =
+            cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that);
+        var arg1 = cst_encode_box_autoadd_prepare_send_response(req);
+        return wire.wire__crate__bindings__BindingLiquidSdk_send_payment(port_, arg0, arg1);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_send_payment_response,
+        decodeErrorData: dco_decode_payment_error,
+      ),
+      constMeta: kCrateBindingsBindingLiquidSdkSendPaymentConstMeta,
+      argValues: [that, req],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkSendPaymentConstMeta => const TaskConstMeta(
+        debugName: "BindingLiquidSdk_send_payment",
+        argNames: ["that", "req"],
+      );
+
+  @override
+  Future<void> crateBindingsBindingLiquidSdkSetPaymentMetadata(
+      {required BindingLiquidSdk that, required SetPaymentMetadataRequest req}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 =
+            cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that);
+        var arg1 = cst_encode_box_autoadd_set_payment_metadata_request(req);
+        return wire.wire__crate__bindings__BindingLiquidSdk_set_payment_metadata(port_, arg0, arg1);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_unit,
+        decodeErrorData: dco_decode_payment_error,
+      ),
+      constMeta: kCrateBindingsBindingLiquidSdkSetPaymentMetadataConstMeta,
+      argValues: [that, req],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkSetPaymentMetadataConstMeta => const TaskConstMeta(
+        debugName: "BindingLiquidSdk_set_payment_metadata",
+        argNames: ["that", "req"],
+      );
+
+  @override
+  Future<void> crateBindingsBindingLiquidSdkSync({required BindingLiquidSdk that}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 =
+            cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+                that);
+        return wire.wire__crate__bindings__BindingLiquidSdk_sync(port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_unit,
+        decodeErrorData: dco_decode_liquid_sdk_error,
+      ),
+      constMeta: kCrateBindingsBindingLiquidSdkSyncConstMeta,
+      argValues: [that],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBindingsBindingLiquidSdkSyncConstMeta => const TaskConstMeta(
+        debugName: "BindingLiquidSdk_sync",
+        argNames: ["that"],
+      );
+
+  @override
+  Future<void> crateBindingsBindingEventListenerOnEvent(
+      {required BindingEventListener that, required LiquidSdkEvent e}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_box_autoadd_binding_event_listener(that);
+        var arg1 = cst_encode_box_autoadd_liquid_sdk_event(e);
+        return wire.wire__crate__bindings__binding_event_listener_on_event(port_, arg0, arg1);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_unit,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateBindingsBindingEventListenerOnEventConstMeta,
+      argValues: [that, e],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBindingsBindingEventListenerOnEventConstMeta => const TaskConstMeta(
+        debugName: "binding_event_listener_on_event",
+        argNames: ["that", "e"],
+      );
+
+  @override
+  Stream<LogEntry> crateBindingsBreezLogStream() {
+    final s = RustStreamSink<LogEntry>();
+    unawaited(handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_StreamSink_log_entry_Dco(s);
+        return wire.wire__crate__bindings__breez_log_stream(port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_unit,
+        decodeErrorData: dco_decode_AnyhowException,
+      ),
+      constMeta: kCrateBindingsBreezLogStreamConstMeta,
+      argValues: [s],
+      apiImpl: this,
+    )));
+    return s.stream;
+  }
+
+  TaskConstMeta get kCrateBindingsBreezLogStreamConstMeta => const TaskConstMeta(
+        debugName: "breez_log_stream",
+        argNames: ["s"],
+      );
+
+  @override
+  Future<BindingLiquidSdk> crateBindingsConnect({required ConnectRequest req}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        var arg0 = cst_encode_box_autoadd_connect_request(req);
+        return wire.wire__crate__bindings__connect(port_, arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData:
+            dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk,
+        decodeErrorData: dco_decode_liquid_sdk_error,
+      ),
+      constMeta: kCrateBindingsConnectConstMeta,
+      argValues: [req],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBindingsConnectConstMeta => const TaskConstMeta(
+        debugName: "connect",
+        argNames: ["req"],
+      );
+
+  @override
+  Config crateBindingsDefaultConfig({required Network network}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 = cst_encode_network(network);
+        return wire.wire__crate__bindings__default_config(arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_config,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateBindingsDefaultConfigConstMeta,
+      argValues: [network],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBindingsDefaultConfigConstMeta => const TaskConstMeta(
+        debugName: "default_config",
+        argNames: ["network"],
+      );
+
+  @override
+  LNInvoice crateBindingsParseInvoice({required String input}) {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        var arg0 = cst_encode_String(input);
+        return wire.wire__crate__bindings__parse_invoice(arg0);
+      },
+      codec: DcoCodec(
+        decodeSuccessData: dco_decode_ln_invoice,
+        decodeErrorData: dco_decode_payment_error,
+      ),
+      constMeta: kCrateBindingsParseInvoiceConstMeta,
+      argValues: [input],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateBindingsParseInvoiceConstMeta => const TaskConstMeta(
+        debugName: "parse_invoice",
+        argNames: ["input"],
+      );
+
+  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_BindingLiquidSdk => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk;
+
+  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_BindingLiquidSdk => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk;
+
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnyhowException(raw as String);
+  }
+
+  @protected
+  BindingLiquidSdk
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+          dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return BindingLiquidSdkImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  BindingLiquidSdk
+      dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
           dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return BindingLiquidSdkImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    return BindingLiquidSdkImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  BindingLiquidSdk dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return BindingLiquidSdkImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  RustStreamSink<LiquidSdkEvent> dco_decode_StreamSink_liquid_sdk_event_Dco(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<LogEntry> dco_decode_StreamSink_log_entry_Dco(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  String dco_decode_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as String;
+  }
+
+  @protected
+  AssetBalance dco_decode_asset_balance(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return AssetBalance(
+      assetId: dco_decode_String(arr[0]),
+      balance: dco_decode_u_64(arr[1]),
+      assetMetadata: dco_decode_opt_box_autoadd_asset_metadata(arr[2]),
+    );
+  }
+
+  @protected
+  AssetMetadata dco_decode_asset_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return AssetMetadata(
+      assetId: dco_decode_String(arr[0]),
+      ticker: dco_decode_String(arr[1]),
+      name: dco_decode_String(arr[2]),
+      precision: dco_decode_u_8(arr[3]),
+    );
+  }
+
+  @protected
+  BackupRequest dco_decode_backup_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return BackupRequest(
+      backupPath: dco_decode_opt_String(arr[0]),
+    );
+  }
+
+  @protected
+  BindingEventListener dco_decode_binding_event_listener(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return BindingEventListener(
+      stream: dco_decode_StreamSink_liquid_sdk_event_Dco(arr[0]),
+    );
+  }
+
+  @protected
+  BitcoinAddressData dco_decode_bitcoin_address_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return BitcoinAddressData(
+      address: dco_decode_String(arr[0]),
+      network: dco_decode_network(arr[1]),
+      amountSat: dco_decode_opt_box_autoadd_u_64(arr[2]),
+      label: dco_decode_opt_String(arr[3]),
+      message: dco_decode_opt_String(arr[4]),
+    );
+  }
+
+  @protected
+  bool dco_decode_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  AssetMetadata dco_decode_box_autoadd_asset_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_asset_metadata(raw);
+  }
+
+  @protected
+  BackupRequest dco_decode_box_autoadd_backup_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_backup_request(raw);
+  }
+
+  @protected
+  BindingEventListener dco_decode_box_autoadd_binding_event_listener(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_binding_event_listener(raw);
+  }
+
+  @protected
+  BitcoinAddressData dco_decode_box_autoadd_bitcoin_address_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_bitcoin_address_data(raw);
+  }
+
+  @protected
+  bool dco_decode_box_autoadd_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  ConnectRequest dco_decode_box_autoadd_connect_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_connect_request(raw);
+  }
+
+  @protected
+  ExportPaymentsRequest dco_decode_box_autoadd_export_payments_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_export_payments_request(raw);
+  }
+
+  @protected
+  double dco_decode_box_autoadd_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  FeeBreakdown dco_decode_box_autoadd_fee_breakdown(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_fee_breakdown(raw);
+  }
+
+  @protected
+  GetPaymentRequest dco_decode_box_autoadd_get_payment_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_get_payment_request(raw);
+  }
+
+  @protected
+  LiquidAddressData dco_decode_box_autoadd_liquid_address_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_liquid_address_data(raw);
+  }
+
+  @protected
+  LiquidSdkEvent dco_decode_box_autoadd_liquid_sdk_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_liquid_sdk_event(raw);
+  }
+
+  @protected
+  ListPaymentsRequest dco_decode_box_autoadd_list_payments_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_list_payments_request(raw);
+  }
+
+  @protected
+  LNInvoice dco_decode_box_autoadd_ln_invoice(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_ln_invoice(raw);
+  }
+
+  @protected
+  LnUrlWithdrawRequest dco_decode_box_autoadd_ln_url_withdraw_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_ln_url_withdraw_request(raw);
+  }
+
+  @protected
+  OnchainFeeRate dco_decode_box_autoadd_onchain_fee_rate(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_onchain_fee_rate(raw);
+  }
+
+  @protected
+  PayOnchainRequest dco_decode_box_autoadd_pay_onchain_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_pay_onchain_request(raw);
+  }
+
+  @protected
+  Payment dco_decode_box_autoadd_payment(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_payment(raw);
+  }
+
+  @protected
+  PaymentMetadata dco_decode_box_autoadd_payment_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_payment_metadata(raw);
+  }
+
+  @protected
+  PrepareLnUrlPayRequest dco_decode_box_autoadd_prepare_ln_url_pay_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_prepare_ln_url_pay_request(raw);
+  }
+
+  @protected
+  PrepareLnUrlPayResponse dco_decode_box_autoadd_prepare_ln_url_pay_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_prepare_ln_url_pay_response(raw);
+  }
+
+  @protected
+  PreparePayOnchainRequest dco_decode_box_autoadd_prepare_pay_onchain_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_prepare_pay_onchain_request(raw);
+  }
+
+  @protected
+  PrepareReceiveOnchainRequest dco_decode_box_autoadd_prepare_receive_onchain_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_prepare_receive_onchain_request(raw);
+  }
+
+  @protected
+  PrepareReceiveOnchainResponse dco_decode_box_autoadd_prepare_receive_onchain_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_prepare_receive_onchain_response(raw);
+  }
+
+  @protected
+  PrepareReceiveRequest dco_decode_box_autoadd_prepare_receive_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_prepare_receive_request(raw);
+  }
+
+  @protected
+  PrepareReceiveResponse dco_decode_box_autoadd_prepare_receive_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_prepare_receive_response(raw);
+  }
+
+  @protected
+  PrepareRefundRequest dco_decode_box_autoadd_prepare_refund_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_prepare_refund_request(raw);
+  }
+
+  @protected
+  PrepareSendOnchainRequest dco_decode_box_autoadd_prepare_send_onchain_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_prepare_send_onchain_request(raw);
+  }
+
+  @protected
+  PrepareSendOnchainResponse dco_decode_box_autoadd_prepare_send_onchain_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_prepare_send_onchain_response(raw);
+  }
+
+  @protected
+  PrepareSendRequest dco_decode_box_autoadd_prepare_send_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_prepare_send_request(raw);
+  }
+
+  @protected
+  PrepareSendResponse dco_decode_box_autoadd_prepare_send_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_prepare_send_response(raw);
+  }
+
+  @protected
+  ReceiveLiquidRequest dco_decode_box_autoadd_receive_liquid_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_receive_liquid_request(raw);
+  }
+
+  @protected
+  RefundRequest dco_decode_box_autoadd_refund_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_refund_request(raw);
+  }
+
+  @protected
+  RestoreRequest dco_decode_box_autoadd_restore_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_restore_request(raw);
+  }
+
+  @protected
+  SetPaymentMetadataRequest dco_decode_box_autoadd_set_payment_metadata_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_set_payment_metadata_request(raw);
+  }
+
+  @protected
+  SuccessAction dco_decode_box_autoadd_success_action(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_success_action(raw);
+  }
+
+  @protected
+  SuccessActionProcessed dco_decode_box_autoadd_success_action_processed(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_success_action_processed(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_u_64(raw);
+  }
+
+  @protected
+  Config dco_decode_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 15) throw Exception('unexpected arr length: expect 15 but see ${arr.length}');
+    return Config(
+      boltzUrl: dco_decode_String(arr[0]),
+      electrumServers: dco_decode_list_electrum_server(arr[1]),
+      electrumUrl: dco_decode_opt_String(arr[2]),
+      bitcoinElectrumUrl: dco_decode_String(arr[3]),
+      esploraUrl: dco_decode_opt_String(arr[4]),
+      workingDir: dco_decode_String(arr[5]),
+      network: dco_decode_network(arr[6]),
+      paymentTimeoutSec: dco_decode_u_64(arr[7]),
+      syncFallbackIntervalSec: dco_decode_u_64(arr[8]),
+      zeroConfMinFeeRate: dco_decode_f_32(arr[9]),
+      zeroConfMaxAmountSat: dco_decode_opt_box_autoadd_u_64(arr[10]),
+      assetMetadata: dco_decode_opt_list_asset_metadata(arr[11]),
+      maxFeeSat: dco_decode_opt_box_autoadd_u_64(arr[12]),
+      maxFeePercent: dco_decode_opt_box_autoadd_f_64(arr[13]),
+      regtestPolicyAsset: dco_decode_opt_String(arr[14]),
+    );
+  }
+
+  @protected
+  ConnectRequest dco_decode_connect_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ConnectRequest(
+      mnemonic: dco_decode_String(arr[0]),
+      config: dco_decode_config(arr[1]),
+    );
+  }
+
+  @protected
+  ElectrumServer dco_decode_electrum_server(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ElectrumServer(
+      url: dco_decode_String(arr[0]),
+      tls: dco_decode_bool(arr[1]),
+      validateDomain: dco_decode_bool(arr[2]),
+    );
+  }
+
+  @protected
+  ElectrumServerStatus dco_decode_electrum_server_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ElectrumServerStatus(
+      url: dco_decode_String(arr[0]),
+      inUse: dco_decode_bool(arr[1]),
+      healthy: dco_decode_bool(arr[2]),
+      consecutiveFailures: dco_decode_u_32(arr[3]),
+      lastError: dco_decode_opt_String(arr[4]),
+    );
+  }
+
+  @protected
+  ExportPaymentsRequest dco_decode_export_payments_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ExportPaymentsRequest(
+      format: dco_decode_payment_export_format(arr[0]),
+      filter: dco_decode_list_payments_request(arr[1]),
+      path: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  ExportPaymentsResponse dco_decode_export_payments_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return ExportPaymentsResponse(
+      paymentsCount: dco_decode_u_32(arr[0]),
+    );
+  }
+
+  @protected
+  double dco_decode_f_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  double dco_decode_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  FeeBreakdown dco_decode_fee_breakdown(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return FeeBreakdown(
+      serviceFeePercentage: dco_decode_f_64(arr[0]),
+      serviceFeeSat: dco_decode_u_64(arr[1]),
+      swapperMinerFeesSat: dco_decode_u_64(arr[2]),
+      networkFeeSat: dco_decode_u_64(arr[3]),
+      totalFeesSat: dco_decode_u_64(arr[4]),
+    );
+  }
+
+  @protected
+  FeePriority dco_decode_fee_priority(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return FeePriority.values[raw as int];
+  }
+
+  @protected
+  GetInfoResponse dco_decode_get_info_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return GetInfoResponse(
+      balanceSat: dco_decode_u_64(arr[0]),
+      pendingSendSat: dco_decode_u_64(arr[1]),
+      pendingReceiveSat: dco_decode_u_64(arr[2]),
+      pubkey: dco_decode_String(arr[3]),
+      assetBalances: dco_decode_list_asset_balance(arr[4]),
+    );
+  }
+
+  @protected
+  GetPaymentRequest dco_decode_get_payment_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return GetPaymentRequest_TxId(
+          txId: dco_decode_String(raw[1]),
+        );
+      case 1:
+        return GetPaymentRequest_SwapId(
+          swapId: dco_decode_String(raw[1]),
+        );
+      case 2:
+        return GetPaymentRequest_Bolt11(
+          bolt11: dco_decode_String(raw[1]),
+        );
+      case 3:
+        return GetPaymentRequest_PaymentHash(
+          paymentHash: dco_decode_String(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  int dco_decode_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  InputType dco_decode_input_type(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return InputType_Bolt11(
+          invoice: dco_decode_box_autoadd_ln_invoice(raw[1]),
+        );
+      case 1:
+        return InputType_LiquidAddress(
+          address: dco_decode_box_autoadd_liquid_address_data(raw[1]),
+        );
+      case 2:
+        return InputType_BitcoinAddress(
+          address: dco_decode_box_autoadd_bitcoin_address_data(raw[1]),
+        );
+      case 3:
+        return InputType_LnUrl(
+          url: dco_decode_String(raw[1]),
+        );
+      case 4:
+        return InputType_LightningAddress(
+          address: dco_decode_String(raw[1]),
+          url: dco_decode_String(raw[2]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  LightningLimits dco_decode_lightning_limits(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return LightningLimits(
+      minSat: dco_decode_u_64(arr[0]),
+      maxSat: dco_decode_u_64(arr[1]),
+      serviceFeePercentage: dco_decode_f_64(arr[2]),
+      minerFeesSat: dco_decode_u_64(arr[3]),
+    );
+  }
+
+  @protected
+  LightningPaymentLimitsResponse dco_decode_lightning_payment_limits_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return LightningPaymentLimitsResponse(
+      send: dco_decode_lightning_limits(arr[0]),
+      receive: dco_decode_lightning_limits(arr[1]),
+    );
+  }
+
+  @protected
+  LiquidAddressData dco_decode_liquid_address_data(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return LiquidAddressData(
+      address: dco_decode_String(arr[0]),
+      network: dco_decode_network(arr[1]),
+      amountSat: dco_decode_opt_box_autoadd_u_64(arr[2]),
+      assetId: dco_decode_opt_String(arr[3]),
+      label: dco_decode_opt_String(arr[4]),
+      message: dco_decode_opt_String(arr[5]),
+    );
+  }
+
+  @protected
+  LiquidSdkError dco_decode_liquid_sdk_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return LiquidSdkError_AlreadyStarted();
+      case 1:
+        return LiquidSdkError_Generic(
+          err: dco_decode_String(raw[1]),
+        );
+      case 2:
+        return LiquidSdkError_NotStarted();
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  LiquidSdkEvent dco_decode_liquid_sdk_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return LiquidSdkEvent_PaymentFailed(
+          details: dco_decode_box_autoadd_payment(raw[1]),
+        );
+      case 1:
+        return LiquidSdkEvent_PaymentPending(
+          details: dco_decode_box_autoadd_payment(raw[1]),
+        );
+      case 2:
+        return LiquidSdkEvent_PaymentRefunded(
+          details: dco_decode_box_autoadd_payment(raw[1]),
+        );
+      case 3:
+        return LiquidSdkEvent_PaymentRefundable(
+          details: dco_decode_box_autoadd_payment(raw[1]),
+        );
+      case 4:
+        return LiquidSdkEvent_PaymentRefundPending(
+          details: dco_decode_box_autoadd_payment(raw[1]),
+        );
+      case 5:
+        return LiquidSdkEvent_PaymentSucceeded(
+          details: dco_decode_box_autoadd_payment(raw[1]),
+        );
+      case 6:
+        return LiquidSdkEvent_PaymentWaitingConfirmation(
+          details: dco_decode_box_autoadd_payment(raw[1]),
+        );
+      case 7:
+        return LiquidSdkEvent_Synced();
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  List<AssetBalance> dco_decode_list_asset_balance(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_asset_balance).toList();
+  }
+
+  @protected
+  List<AssetMetadata> dco_decode_list_asset_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_asset_metadata).toList();
+  }
+
+  @protected
+  List<ElectrumServer> dco_decode_list_electrum_server(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_electrum_server).toList();
+  }
+
+  @protected
+  List<ElectrumServerStatus> dco_decode_list_electrum_server_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_electrum_server_status).toList();
+  }
+
+  @protected
+  List<Payment> dco_decode_list_payment(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_payment).toList();
+  }
+
+  @protected
+  List<PaymentState> dco_decode_list_payment_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_payment_state).toList();
+  }
+
+  @protected
+  List<PaymentType> dco_decode_list_payment_type(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_payment_type).toList();
+  }
+
+  @protected
+  ListPaymentsRequest dco_decode_list_payments_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return ListPaymentsRequest(
+      paymentTypes: dco_decode_opt_list_payment_type(arr[0]),
+      states: dco_decode_opt_list_payment_state(arr[1]),
+      fromTimestamp: dco_decode_opt_box_autoadd_u_32(arr[2]),
+      toTimestamp: dco_decode_opt_box_autoadd_u_32(arr[3]),
+      offset: dco_decode_opt_box_autoadd_u_32(arr[4]),
+      limit: dco_decode_opt_box_autoadd_u_32(arr[5]),
+      sortDescending: dco_decode_opt_box_autoadd_bool(arr[6]),
+      metadataSearch: dco_decode_opt_String(arr[7]),
+    );
+  }
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint8List;
+  }
+
+  @protected
+  List<RefundableSwap> dco_decode_list_refundable_swap(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_refundable_swap).toList();
+  }
+
+  @protected
+  List<RouteHint> dco_decode_list_route_hint(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_route_hint).toList();
+  }
+
+  @protected
+  List<RouteHintHop> dco_decode_list_route_hint_hop(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_route_hint_hop).toList();
+  }
+
+  @protected
+  LNInvoice dco_decode_ln_invoice(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 12) throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
+    return LNInvoice(
+      bolt11: dco_decode_String(arr[0]),
+      network: dco_decode_network(arr[1]),
+      payeePubkey: dco_decode_String(arr[2]),
+      paymentHash: dco_decode_String(arr[3]),
+      description: dco_decode_opt_String(arr[4]),
+      descriptionHash: dco_decode_opt_String(arr[5]),
+      amountMsat: dco_decode_opt_box_autoadd_u_64(arr[6]),
+      timestamp: dco_decode_u_64(arr[7]),
+      expiry: dco_decode_u_64(arr[8]),
+      routingHints: dco_decode_list_route_hint(arr[9]),
+      paymentSecret: dco_decode_list_prim_u_8_strict(arr[10]),
+      minFinalCltvExpiryDelta: dco_decode_u_64(arr[11]),
+    );
+  }
+
+  @protected
+  LnUrlPayResult dco_decode_ln_url_pay_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return LnUrlPayResult(
+      payment: dco_decode_payment(arr[0]),
+      successAction: dco_decode_opt_box_autoadd_success_action_processed(arr[1]),
+    );
+  }
+
+  @protected
+  LnUrlWithdrawRequest dco_decode_ln_url_withdraw_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return LnUrlWithdrawRequest(
+      lnurl: dco_decode_String(arr[0]),
+      amountSat: dco_decode_u_64(arr[1]),
+    );
+  }
+
+  @protected
+  LnUrlWithdrawResult dco_decode_ln_url_withdraw_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return LnUrlWithdrawResult(
+      receivePaymentResponse: dco_decode_receive_payment_response(arr[0]),
+    );
+  }
+
+  @protected
+  LogEntry dco_decode_log_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return LogEntry(
+      line: dco_decode_String(arr[0]),
+      level: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  Network dco_decode_network(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Network.values[raw as int];
+  }
+
+  @protected
+  OnchainFeeRate dco_decode_onchain_fee_rate(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return OnchainFeeRate_SatPerVbyte(
+          satPerVbyte: dco_decode_f_64(raw[1]),
+        );
+      case 1:
+        return OnchainFeeRate_Priority(
+          priority: dco_decode_fee_priority(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_String(raw);
+  }
+
+  @protected
+  AssetMetadata? dco_decode_opt_box_autoadd_asset_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_asset_metadata(raw);
+  }
+
+  @protected
+  bool? dco_decode_opt_box_autoadd_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_bool(raw);
+  }
+
+  @protected
+  double? dco_decode_opt_box_autoadd_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_f_64(raw);
+  }
+
+  @protected
+  FeeBreakdown? dco_decode_opt_box_autoadd_fee_breakdown(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_fee_breakdown(raw);
+  }
+
+  @protected
+  OnchainFeeRate? dco_decode_opt_box_autoadd_onchain_fee_rate(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_onchain_fee_rate(raw);
+  }
+
+  @protected
+  Payment? dco_decode_opt_box_autoadd_payment(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_payment(raw);
+  }
+
+  @protected
+  PaymentMetadata? dco_decode_opt_box_autoadd_payment_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_payment_metadata(raw);
+  }
+
+  @protected
+  SuccessAction? dco_decode_opt_box_autoadd_success_action(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_success_action(raw);
+  }
+
+  @protected
+  SuccessActionProcessed? dco_decode_opt_box_autoadd_success_action_processed(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_success_action_processed(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  }
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
+  }
+
+  @protected
+  List<AssetMetadata>? dco_decode_opt_list_asset_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_asset_metadata(raw);
+  }
+
+  @protected
+  List<PaymentState>? dco_decode_opt_list_payment_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_payment_state(raw);
+  }
+
+  @protected
+  List<PaymentType>? dco_decode_opt_list_payment_type(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_payment_type(raw);
+  }
+
+  @protected
+  PayOnchainRequest dco_decode_pay_onchain_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return PayOnchainRequest(
+      address: dco_decode_String(arr[0]),
+      prepareRes: dco_decode_prepare_pay_onchain_response(arr[1]),
+    );
+  }
+
+  @protected
+  Payment dco_decode_payment(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 24) throw Exception('unexpected arr length: expect 24 but see ${arr.length}');
+    return Payment(
+      txId: dco_decode_opt_String(arr[0]),
+      swapId: dco_decode_opt_String(arr[1]),
+      swapType: dco_decode_payment_swap_type(arr[2]),
+      timestamp: dco_decode_u_32(arr[3]),
+      amountSat: dco_decode_u_64(arr[4]),
+      feesSat: dco_decode_u_64(arr[5]),
+      networkFeesSat: dco_decode_opt_box_autoadd_u_64(arr[6]),
+      feeBreakdown: dco_decode_opt_box_autoadd_fee_breakdown(arr[7]),
+      feeRateSatPerVbyte: dco_decode_opt_box_autoadd_f_64(arr[8]),
+      preimage: dco_decode_opt_String(arr[9]),
+      bolt11: dco_decode_opt_String(arr[10]),
+      paymentHash: dco_decode_opt_String(arr[11]),
+      payeePubkey: dco_decode_opt_String(arr[12]),
+      lockupTxId: dco_decode_opt_String(arr[13]),
+      claimTxId: dco_decode_opt_String(arr[14]),
+      refundTxId: dco_decode_opt_String(arr[15]),
+      refundTxAmountSat: dco_decode_opt_box_autoadd_u_64(arr[16]),
+      paymentType: dco_decode_payment_type(arr[17]),
+      status: dco_decode_payment_state(arr[18]),
+      label: dco_decode_opt_String(arr[19]),
+      description: dco_decode_opt_String(arr[20]),
+      metadata: dco_decode_opt_box_autoadd_payment_metadata(arr[21]),
+      assetId: dco_decode_opt_String(arr[22]),
+      assetAmount: dco_decode_opt_box_autoadd_u_64(arr[23]),
+    );
+  }
+
+  @protected
+  PaymentError dco_decode_payment_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return PaymentError_AlreadyClaimed();
+      case 1:
+        return PaymentError_AlreadyPaid();
+      case 2:
+        return PaymentError_PaymentInProgress();
+      case 3:
+        return PaymentError_AmountOutOfRange();
+      case 4:
+        return PaymentError_UnrecognizedInput(
+          err: dco_decode_String(raw[1]),
+        );
+      case 5:
+        return PaymentError_NetworkMismatch(
+          err: dco_decode_String(raw[1]),
+        );
+      case 6:
+        return PaymentError_LnUrlError(
+          err: dco_decode_String(raw[1]),
+        );
+      case 7:
+        return PaymentError_LnUrlServiceUnreachable(
+          err: dco_decode_String(raw[1]),
+        );
+      case 8:
+        return PaymentError_LnUrlServiceError(
+          err: dco_decode_String(raw[1]),
+        );
+      case 9:
+        return PaymentError_Generic(
+          err: dco_decode_String(raw[1]),
+        );
+      case 10:
+        return PaymentError_InvalidOrExpiredFees();
+      case 11:
+        return PaymentError_FeeLimitExceeded(
+          feeSat: dco_decode_u_64(raw[1]),
+          limitSat: dco_decode_u_64(raw[2]),
+        );
+      case 12:
+        return PaymentError_InsufficientFunds();
+      case 13:
+        return PaymentError_InvalidInvoice(
+          err: dco_decode_String(raw[1]),
+        );
+      case 14:
+        return PaymentError_InvalidPreimage();
+      case 15:
+        return PaymentError_LwkError(
+          err: dco_decode_String(raw[1]),
+        );
+      case 16:
+        return PaymentError_PairsNotFound();
+      case 17:
+        return PaymentError_PaymentTimeout();
+      case 18:
+        return PaymentError_PersistError();
+      case 19:
+        return PaymentError_ReceiveError(
+          err: dco_decode_String(raw[1]),
+        );
+      case 20:
+        return PaymentError_Refunded(
+          err: dco_decode_String(raw[1]),
+          refundTxId: dco_decode_String(raw[2]),
+        );
+      case 21:
+        return PaymentError_SelfTransferNotSupported();
+      case 22:
+        return PaymentError_SendError(
+          err: dco_decode_String(raw[1]),
+        );
+      case 23:
+        return PaymentError_SignerError(
+          err: dco_decode_String(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  PaymentExportFormat dco_decode_payment_export_format(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return PaymentExportFormat.values[raw as int];
+  }
+
+  @protected
+  PaymentMetadata dco_decode_payment_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return PaymentMetadata(
+      label: dco_decode_opt_String(arr[0]),
+      note: dco_decode_opt_String(arr[1]),
+      externalId: dco_decode_opt_String(arr[2]),
+    );
+  }
+
+  @protected
+  PaymentState dco_decode_payment_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return PaymentState.values[raw as int];
+  }
+
+  @protected
+  PaymentSwapType dco_decode_payment_swap_type(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return PaymentSwapType.values[raw as int];
+  }
+
+  @protected
+  PaymentType dco_decode_payment_type(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return PaymentType.values[raw as int];
+  }
+
+  @protected
+  PrepareLnUrlPayRequest dco_decode_prepare_ln_url_pay_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return PrepareLnUrlPayRequest(
+      lnurl: dco_decode_String(arr[0]),
+      amountSat: dco_decode_u_64(arr[1]),
+      comment: dco_decode_opt_String(arr[2]),
+    );
+  }
+
+  @protected
+  PrepareLnUrlPayResponse dco_decode_prepare_ln_url_pay_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return PrepareLnUrlPayResponse(
+      prepareSendResponse: dco_decode_prepare_send_response(arr[0]),
+      successAction: dco_decode_opt_box_autoadd_success_action(arr[1]),
+    );
+  }
+
+  @protected
+  PreparePayOnchainRequest dco_decode_prepare_pay_onchain_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return PreparePayOnchainRequest(
+      receiverAmountSat: dco_decode_u_64(arr[0]),
+      feeRate: dco_decode_opt_box_autoadd_onchain_fee_rate(arr[1]),
+    );
+  }
+
+  @protected
+  PreparePayOnchainResponse dco_decode_prepare_pay_onchain_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return PreparePayOnchainResponse(
+      receiverAmountSat: dco_decode_u_64(arr[0]),
+      feesSat: dco_decode_u_64(arr[1]),
+      feeRateSatPerVbyte: dco_decode_opt_box_autoadd_f_64(arr[2]),
+    );
+  }
+
+  @protected
+  PrepareReceiveOnchainRequest dco_decode_prepare_receive_onchain_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return PrepareReceiveOnchainRequest(
+      payerAmountSat: dco_decode_u_64(arr[0]),
+      feeRate: dco_decode_opt_box_autoadd_onchain_fee_rate(arr[1]),
+    );
+  }
+
+  @protected
+  PrepareReceiveOnchainResponse dco_decode_prepare_receive_onchain_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return PrepareReceiveOnchainResponse(
+      payerAmountSat: dco_decode_u_64(arr[0]),
+      feesSat: dco_decode_u_64(arr[1]),
+      feeRateSatPerVbyte: dco_decode_opt_box_autoadd_f_64(arr[2]),
+    );
+  }
+
+  @protected
+  PrepareReceiveRequest dco_decode_prepare_receive_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9) throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return PrepareReceiveRequest(
+      payerAmountSat: dco_decode_opt_box_autoadd_u_64(arr[0]),
+      receiverAmountSat: dco_decode_opt_box_autoadd_u_64(arr[1]),
+      description: dco_decode_opt_String(arr[2]),
+      descriptionHash: dco_decode_opt_String(arr[3]),
+      invoiceExpirySecs: dco_decode_opt_box_autoadd_u_64(arr[4]),
+      metadata: dco_decode_opt_box_autoadd_payment_metadata(arr[5]),
+      maxFeeSat: dco_decode_opt_box_autoadd_u_64(arr[6]),
+      maxFeePercent: dco_decode_opt_box_autoadd_f_64(arr[7]),
+      feeRate: dco_decode_opt_box_autoadd_onchain_fee_rate(arr[8]),
+    );
+  }
+
+  @protected
+  PrepareReceiveResponse dco_decode_prepare_receive_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 11) throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+    return PrepareReceiveResponse(
+      payerAmountSat: dco_decode_u_64(arr[0]),
+      receiverAmountSat: dco_decode_u_64(arr[1]),
+      feesSat: dco_decode_u_64(arr[2]),
+      description: dco_decode_opt_String(arr[3]),
+      descriptionHash: dco_decode_opt_String(arr[4]),
+      invoiceExpirySecs: dco_decode_opt_box_autoadd_u_64(arr[5]),
+      metadata: dco_decode_opt_box_autoadd_payment_metadata(arr[6]),
+      feeBreakdown: dco_decode_fee_breakdown(arr[7]),
+      pairHash: dco_decode_String(arr[8]),
+      expiresAt: dco_decode_u_32(arr[9]),
+      feeRateSatPerVbyte: dco_decode_opt_box_autoadd_f_64(arr[10]),
+    );
+  }
+
+  @protected
+  PrepareRefundRequest dco_decode_prepare_refund_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return PrepareRefundRequest(
+      swapId: dco_decode_String(arr[0]),
+      refundAddress: dco_decode_String(arr[1]),
+      feeRate: dco_decode_onchain_fee_rate(arr[2]),
+    );
+  }
+
+  @protected
+  PrepareRefundResponse dco_decode_prepare_refund_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return PrepareRefundResponse(
+      txVsize: dco_decode_u_32(arr[0]),
+      txFeeSat: dco_decode_u_64(arr[1]),
+      refundAmountSat: dco_decode_u_64(arr[2]),
+      feeRateSatPerVbyte: dco_decode_f_64(arr[3]),
+    );
+  }
+
+  @protected
+  PrepareSendOnchainRequest dco_decode_prepare_send_onchain_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return PrepareSendOnchainRequest(
+      destination: dco_decode_String(arr[0]),
+      amountSat: dco_decode_opt_box_autoadd_u_64(arr[1]),
+      sendAll: dco_decode_bool(arr[2]),
+      assetId: dco_decode_opt_String(arr[3]),
+      feeRate: dco_decode_opt_box_autoadd_onchain_fee_rate(arr[4]),
+    );
+  }
+
+  @protected
+  PrepareSendOnchainResponse dco_decode_prepare_send_onchain_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return PrepareSendOnchainResponse(
+      address: dco_decode_String(arr[0]),
+      receiverAmountSat: dco_decode_u_64(arr[1]),
+      feesSat: dco_decode_u_64(arr[2]),
+      sendAll: dco_decode_bool(arr[3]),
+      assetId: dco_decode_opt_String(arr[4]),
+      feeRateSatPerVbyte: dco_decode_opt_box_autoadd_f_64(arr[5]),
+    );
+  }
+
+  @protected
+  PrepareSendRequest dco_decode_prepare_send_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return PrepareSendRequest(
+      invoice: dco_decode_String(arr[0]),
+      metadata: dco_decode_opt_box_autoadd_payment_metadata(arr[1]),
+      maxFeeSat: dco_decode_opt_box_autoadd_u_64(arr[2]),
+      maxFeePercent: dco_decode_opt_box_autoadd_f_64(arr[3]),
+      feeRate: dco_decode_opt_box_autoadd_onchain_fee_rate(arr[4]),
+    );
+  }
+
+  @protected
+  PrepareSendResponse dco_decode_prepare_send_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9) throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return PrepareSendResponse(
+      invoice: dco_decode_String(arr[0]),
+      feesSat: dco_decode_u_64(arr[1]),
+      metadata: dco_decode_opt_box_autoadd_payment_metadata(arr[2]),
+      feeBreakdown: dco_decode_fee_breakdown(arr[3]),
+      maxFeeSat: dco_decode_opt_box_autoadd_u_64(arr[4]),
+      maxFeePercent: dco_decode_opt_box_autoadd_f_64(arr[5]),
+      pairHash: dco_decode_String(arr[6]),
+      expiresAt: dco_decode_u_32(arr[7]),
+      feeRateSatPerVbyte: dco_decode_opt_box_autoadd_f_64(arr[8]),
+    );
+  }
+
+  @protected
+  ReceiveLiquidRequest dco_decode_receive_liquid_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ReceiveLiquidRequest(
+      amountSat: dco_decode_opt_box_autoadd_u_64(arr[0]),
+      label: dco_decode_opt_String(arr[1]),
+    );
+  }
+
+  @protected
+  ReceiveLiquidResponse dco_decode_receive_liquid_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ReceiveLiquidResponse(
+      address: dco_decode_String(arr[0]),
+      bip21: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  ReceiveOnchainResponse dco_decode_receive_onchain_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ReceiveOnchainResponse(
+      id: dco_decode_String(arr[0]),
+      address: dco_decode_String(arr[1]),
+      bip21: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  ReceivePaymentResponse dco_decode_receive_payment_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ReceivePaymentResponse(
+      id: dco_decode_String(arr[0]),
+      invoice: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  RecommendedFees dco_decode_recommended_fees(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return RecommendedFees(
+      fastestFee: dco_decode_f_64(arr[0]),
+      halfHourFee: dco_decode_f_64(arr[1]),
+      hourFee: dco_decode_f_64(arr[2]),
+      economyFee: dco_decode_f_64(arr[3]),
+      minimumFee: dco_decode_f_64(arr[4]),
+    );
+  }
+
+  @protected
+  RecommendedFeesResponse dco_decode_recommended_fees_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return RecommendedFeesResponse(
+      liquid: dco_decode_recommended_fees(arr[0]),
+      bitcoin: dco_decode_recommended_fees(arr[1]),
+    );
+  }
+
+  @protected
+  RefundRequest dco_decode_refund_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return RefundRequest(
+      swapId: dco_decode_String(arr[0]),
+      refundAddress: dco_decode_String(arr[1]),
+      feeRateSatPerVbyte: dco_decode_f_64(arr[2]),
+    );
+  }
+
+  @protected
+  RefundResponse dco_decode_refund_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return RefundResponse(
+      refundTxId: dco_decode_String(arr[0]),
+    );
+  }
+
+  @protected
+  RefundableSwap dco_decode_refundable_swap(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return RefundableSwap(
+      swapId: dco_decode_String(arr[0]),
+      swapAddress: dco_decode_String(arr[1]),
+      timestamp: dco_decode_u_32(arr[2]),
+      amountSat: dco_decode_u_64(arr[3]),
+      paymentType: dco_decode_payment_type(arr[4]),
+    );
+  }
+
+  @protected
+  RestoreRequest dco_decode_restore_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return RestoreRequest(
+      backupPath: dco_decode_opt_String(arr[0]),
+    );
+  }
+
+  @protected
+  RouteHint dco_decode_route_hint(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return RouteHint(
+      hops: dco_decode_list_route_hint_hop(arr[0]),
+    );
+  }
+
+  @protected
+  RouteHintHop dco_decode_route_hint_hop(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return RouteHintHop(
+      srcNodeId: dco_decode_String(arr[0]),
+      shortChannelId: dco_decode_u_64(arr[1]),
+      feesBaseMsat: dco_decode_u_32(arr[2]),
+      feesProportionalMillionths: dco_decode_u_32(arr[3]),
+      cltvExpiryDelta: dco_decode_u_64(arr[4]),
+      htlcMinimumMsat: dco_decode_opt_box_autoadd_u_64(arr[5]),
+      htlcMaximumMsat: dco_decode_opt_box_autoadd_u_64(arr[6]),
+    );
+  }
+
+  @protected
+  SendPaymentResponse dco_decode_send_payment_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return SendPaymentResponse(
+      payment: dco_decode_payment(arr[0]),
+    );
+  }
+
+  @protected
+  SetPaymentMetadataRequest dco_decode_set_payment_metadata_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return SetPaymentMetadataRequest(
+      id: dco_decode_String(arr[0]),
+      metadata: dco_decode_payment_metadata(arr[1]),
+    );
+  }
+
+  @protected
+  SuccessAction dco_decode_success_action(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return SuccessAction_Message(
+          message: dco_decode_String(raw[1]),
+        );
+      case 1:
+        return SuccessAction_Url(
+          description: dco_decode_String(raw[1]),
+          url: dco_decode_String(raw[2]),
+        );
+      case 2:
+        return SuccessAction_Aes(
+          description: dco_decode_String(raw[1]),
+          ciphertext: dco_decode_String(raw[2]),
+          iv: dco_decode_String(raw[3]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  SuccessActionProcessed dco_decode_success_action_processed(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return SuccessActionProcessed_Message(
+          message: dco_decode_String(raw[1]),
+        );
+      case 1:
+        return SuccessActionProcessed_Url(
+          description: dco_decode_String(raw[1]),
+          url: dco_decode_String(raw[2]),
+        );
+      case 2:
+        return SuccessActionProcessed_Aes(
+          description: dco_decode_String(raw[1]),
+          plaintext: dco_decode_String(raw[2]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  int dco_decode_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  void dco_decode_unit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return;
+  }
+
+  @protected
+  BigInt dco_decode_usize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_String(deserializer);
+    return AnyhowException(inner);
+  }
+
+  @protected
+  BindingLiquidSdk
+      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return BindingLiquidSdkImpl.frbInternalSseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  BindingLiquidSdk
+      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+          SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return BindingLiquidSdkImpl.frbInternalSseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  BindingLiquidSdk sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return BindingLiquidSdkImpl.frbInternalSseDecode(
+        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  RustStreamSink<LiquidSdkEvent> sse_decode_StreamSink_liquid_sdk_event_Dco(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<LogEntry> sse_decode_StreamSink_log_entry_Dco(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_prim_u_8_strict(deserializer);
+    return utf8.decoder.convert(inner);
+  }
+
+  @protected
+  AssetBalance sse_decode_asset_balance(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_assetId = sse_decode_String(deserializer);
+    var var_balance = sse_decode_u_64(deserializer);
+    var var_assetMetadata = sse_decode_opt_box_autoadd_asset_metadata(deserializer);
+    return AssetBalance(assetId: var_assetId, balance: var_balance, assetMetadata: var_assetMetadata);
+  }
+
+  @protected
+  AssetMetadata sse_decode_asset_metadata(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_assetId = sse_decode_String(deserializer);
+    var var_ticker = sse_decode_String(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_precision = sse_decode_u_8(deserializer);
+    return AssetMetadata(assetId: var_assetId, ticker: var_ticker, name: var_name, precision: var_precision);
+  }
+
+  @protected
+  BackupRequest sse_decode_backup_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_backupPath = sse_decode_opt_String(deserializer);
+    return BackupRequest(backupPath: var_backupPath);
+  }
+
+  @protected
+  BindingEventListener sse_decode_binding_event_listener(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_stream = sse_decode_StreamSink_liquid_sdk_event_Dco(deserializer);
+    return BindingEventListener(stream: var_stream);
+  }
+
+  @protected
+  BitcoinAddressData sse_decode_bitcoin_address_data(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_address = sse_decode_String(deserializer);
+    var var_network = sse_decode_network(deserializer);
+    var var_amountSat = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_label = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_opt_String(deserializer);
+    return BitcoinAddressData(
+        address: var_address,
+        network: var_network,
+        amountSat: var_amountSat,
+        label: var_label,
+        message: var_message);
+  }
+
+  @protected
+  AssetMetadata sse_decode_box_autoadd_asset_metadata(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_asset_metadata(deserializer));
+  }
+
+  @protected
+  BackupRequest sse_decode_box_autoadd_backup_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_backup_request(deserializer));
+  }
+
+  @protected
+  BindingEventListener sse_decode_box_autoadd_binding_event_listener(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_binding_event_listener(deserializer));
+  }
+
+  @protected
+  BitcoinAddressData sse_decode_box_autoadd_bitcoin_address_data(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_bitcoin_address_data(deserializer));
+  }
+
+  @protected
+  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_bool(deserializer));
+  }
+
+  @protected
+  ConnectRequest sse_decode_box_autoadd_connect_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_connect_request(deserializer));
+  }
+
+  @protected
+  ExportPaymentsRequest sse_decode_box_autoadd_export_payments_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_export_payments_request(deserializer));
+  }
+
+  @protected
+  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_f_64(deserializer));
+  }
+
+  @protected
+  FeeBreakdown sse_decode_box_autoadd_fee_breakdown(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_fee_breakdown(deserializer));
+  }
+
+  @protected
+  GetPaymentRequest sse_decode_box_autoadd_get_payment_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_get_payment_request(deserializer));
+  }
+
+  @protected
+  LiquidAddressData sse_decode_box_autoadd_liquid_address_data(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_liquid_address_data(deserializer));
+  }
+
+  @protected
+  LiquidSdkEvent sse_decode_box_autoadd_liquid_sdk_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_liquid_sdk_event(deserializer));
+  }
+
+  @protected
+  ListPaymentsRequest sse_decode_box_autoadd_list_payments_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_list_payments_request(deserializer));
+  }
+
+  @protected
+  LNInvoice sse_decode_box_autoadd_ln_invoice(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_ln_invoice(deserializer));
+  }
+
+  @protected
+  LnUrlWithdrawRequest sse_decode_box_autoadd_ln_url_withdraw_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_ln_url_withdraw_request(deserializer));
+  }
+
+  @protected
+  OnchainFeeRate sse_decode_box_autoadd_onchain_fee_rate(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_onchain_fee_rate(deserializer));
+  }
+
+  @protected
+  PayOnchainRequest sse_decode_box_autoadd_pay_onchain_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_pay_onchain_request(deserializer));
+  }
+
+  @protected
+  Payment sse_decode_box_autoadd_payment(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_payment(deserializer));
+  }
+
+  @protected
+  PaymentMetadata sse_decode_box_autoadd_payment_metadata(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_payment_metadata(deserializer));
+  }
+
+  @protected
+  PrepareLnUrlPayRequest sse_decode_box_autoadd_prepare_ln_url_pay_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_prepare_ln_url_pay_request(deserializer));
+  }
+
+  @protected
+  PrepareLnUrlPayResponse sse_decode_box_autoadd_prepare_ln_url_pay_response(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_prepare_ln_url_pay_response(deserializer));
+  }
+
+  @protected
+  PreparePayOnchainRequest sse_decode_box_autoadd_prepare_pay_onchain_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_prepare_pay_onchain_request(deserializer));
+  }
+
+  @protected
+  PrepareReceiveOnchainRequest sse_decode_box_autoadd_prepare_receive_onchain_request(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_prepare_receive_onchain_request(deserializer));
+  }
+
+  @protected
+  PrepareReceiveOnchainResponse sse_decode_box_autoadd_prepare_receive_onchain_response(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_prepare_receive_onchain_response(deserializer));
+  }
+
+  @protected
+  PrepareReceiveRequest sse_decode_box_autoadd_prepare_receive_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_prepare_receive_request(deserializer));
+  }
+
+  @protected
+  PrepareReceiveResponse sse_decode_box_autoadd_prepare_receive_response(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_prepare_receive_response(deserializer));
+  }
+
+  @protected
+  PrepareRefundRequest sse_decode_box_autoadd_prepare_refund_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_prepare_refund_request(deserializer));
+  }
+
+  @protected
+  PrepareSendOnchainRequest sse_decode_box_autoadd_prepare_send_onchain_request(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_prepare_send_onchain_request(deserializer));
+  }
+
+  @protected
+  PrepareSendOnchainResponse sse_decode_box_autoadd_prepare_send_onchain_response(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_prepare_send_onchain_response(deserializer));
+  }
+
+  @protected
+  PrepareSendRequest sse_decode_box_autoadd_prepare_send_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_prepare_send_request(deserializer));
+  }
+
+  @protected
+  PrepareSendResponse sse_decode_box_autoadd_prepare_send_response(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_prepare_send_response(deserializer));
+  }
+
+  @protected
+  ReceiveLiquidRequest sse_decode_box_autoadd_receive_liquid_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_receive_liquid_request(deserializer));
+  }
+
+  @protected
+  RefundRequest sse_decode_box_autoadd_refund_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_refund_request(deserializer));
+  }
+
+  @protected
+  RestoreRequest sse_decode_box_autoadd_restore_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_restore_request(deserializer));
+  }
+
+  @protected
+  SetPaymentMetadataRequest sse_decode_box_autoadd_set_payment_metadata_request(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_set_payment_metadata_request(deserializer));
+  }
+
+  @protected
+  SuccessAction sse_decode_box_autoadd_success_action(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_success_action(deserializer));
+  }
+
+  @protected
+  SuccessActionProcessed sse_decode_box_autoadd_success_action_processed(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_success_action_processed(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_32(deserializer));
+  }
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_64(deserializer));
+  }
+
+  @protected
+  Config sse_decode_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_boltzUrl = sse_decode_String(deserializer);
+    var var_electrumServers = sse_decode_list_electrum_server(deserializer);
+    var var_electrumUrl = sse_decode_opt_String(deserializer);
+    var var_bitcoinElectrumUrl = sse_decode_String(deserializer);
+    var var_esploraUrl = sse_decode_opt_String(deserializer);
+    var var_workingDir = sse_decode_String(deserializer);
+    var var_network = sse_decode_network(deserializer);
+    var var_paymentTimeoutSec = sse_decode_u_64(deserializer);
+    var var_syncFallbackIntervalSec = sse_decode_u_64(deserializer);
+    var var_zeroConfMinFeeRate = sse_decode_f_32(deserializer);
+    var var_zeroConfMaxAmountSat = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_assetMetadata = sse_decode_opt_list_asset_metadata(deserializer);
+    var var_maxFeeSat = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_maxFeePercent = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_regtestPolicyAsset = sse_decode_opt_String(deserializer);
+    return Config(
+        boltzUrl: var_boltzUrl,
+        electrumServers: var_electrumServers,
+        electrumUrl: var_electrumUrl,
+        bitcoinElectrumUrl: var_bitcoinElectrumUrl,
+        esploraUrl: var_esploraUrl,
+        workingDir: var_workingDir,
+        network: var_network,
+        paymentTimeoutSec: var_paymentTimeoutSec,
+        syncFallbackIntervalSec: var_syncFallbackIntervalSec,
+        zeroConfMinFeeRate: var_zeroConfMinFeeRate,
+        zeroConfMaxAmountSat: var_zeroConfMaxAmountSat,
+        assetMetadata: var_assetMetadata,
+        maxFeeSat: var_maxFeeSat,
+        maxFeePercent: var_maxFeePercent,
+        regtestPolicyAsset: var_regtestPolicyAsset);
+  }
+
+  @protected
+  ConnectRequest sse_decode_connect_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_mnemonic = sse_decode_String(deserializer);
+    var var_config = sse_decode_config(deserializer);
+    return ConnectRequest(mnemonic: var_mnemonic, config: var_config);
+  }
+
+  @protected
+  ElectrumServer sse_decode_electrum_server(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_url = sse_decode_String(deserializer);
+    var var_tls = sse_decode_bool(deserializer);
+    var var_validateDomain = sse_decode_bool(deserializer);
+    return ElectrumServer(url: var_url, tls: var_tls, validateDomain: var_validateDomain);
+  }
+
+  @protected
+  ElectrumServerStatus sse_decode_electrum_server_status(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_url = sse_decode_String(deserializer);
+    var var_inUse = sse_decode_bool(deserializer);
+    var var_healthy = sse_decode_bool(deserializer);
+    var var_consecutiveFailures = sse_decode_u_32(deserializer);
+    var var_lastError = sse_decode_opt_String(deserializer);
+    return ElectrumServerStatus(
+        url: var_url,
+        inUse: var_inUse,
+        healthy: var_healthy,
+        consecutiveFailures: var_consecutiveFailures,
+        lastError: var_lastError);
+  }
+
+  @protected
+  ExportPaymentsRequest sse_decode_export_payments_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_format = sse_decode_payment_export_format(deserializer);
+    var var_filter = sse_decode_list_payments_request(deserializer);
+    var var_path = sse_decode_String(deserializer);
+    return ExportPaymentsRequest(format: var_format, filter: var_filter, path: var_path);
+  }
+
+  @protected
+  ExportPaymentsResponse sse_decode_export_payments_response(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_paymentsCount = sse_decode_u_32(deserializer);
+    return ExportPaymentsResponse(paymentsCount: var_paymentsCount);
+  }
+
+  @protected
+  double sse_decode_f_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getFloat32();
+  }
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getFloat64();
+  }
+
+  @protected
+  FeeBreakdown sse_decode_fee_breakdown(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_serviceFeePercentage = sse_decode_f_64(deserializer);
+    var var_serviceFeeSat = sse_decode_u_64(deserializer);
+    var var_swapperMinerFeesSat = sse_decode_u_64(deserializer);
+    var var_networkFeeSat = sse_decode_u_64(deserializer);
+    var var_totalFeesSat = sse_decode_u_64(deserializer);
+    return FeeBreakdown(
+        serviceFeePercentage: var_serviceFeePercentage,
+        serviceFeeSat: var_serviceFeeSat,
+        swapperMinerFeesSat: var_swapperMinerFeesSat,
+        networkFeeSat: var_networkFeeSat,
+        totalFeesSat: var_totalFeesSat);
+  }
+
+  @protected
+  FeePriority sse_decode_fee_priority(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return FeePriority.values[inner];
+  }
+
+  @protected
+  GetInfoResponse sse_decode_get_info_response(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_balanceSat = sse_decode_u_64(deserializer);
+    var var_pendingSendSat = sse_decode_u_64(deserializer);
+    var var_pendingReceiveSat = sse_decode_u_64(deserializer);
+    var var_pubkey = sse_decode_String(deserializer);
+    var var_assetBalances = sse_decode_list_asset_balance(deserializer);
+    return GetInfoResponse(
+        balanceSat: var_balanceSat,
+        pendingSendSat: var_pendingSendSat,
+        pendingReceiveSat: var_pendingReceiveSat,
+        pubkey: var_pubkey,
+        assetBalances: var_assetBalances);
+  }
+
+  @protected
+  GetPaymentRequest sse_decode_get_payment_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_txId = sse_decode_String(deserializer);
+        return GetPaymentRequest_TxId(txId: var_txId);
+      case 1:
+        var var_swapId = sse_decode_String(deserializer);
+        return GetPaymentRequest_SwapId(swapId: var_swapId);
+      case 2:
+        var var_bolt11 = sse_decode_String(deserializer);
+        return GetPaymentRequest_Bolt11(bolt11: var_bolt11);
+      case 3:
+        var var_paymentHash = sse_decode_String(deserializer);
+        return GetPaymentRequest_PaymentHash(paymentHash: var_paymentHash);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt32();
+  }
+
+  @protected
+  InputType sse_decode_input_type(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_invoice = sse_decode_box_autoadd_ln_invoice(deserializer);
+        return InputType_Bolt11(invoice: var_invoice);
+      case 1:
+        var var_address = sse_decode_box_autoadd_liquid_address_data(deserializer);
+        return InputType_LiquidAddress(address: var_address);
+      case 2:
+        var var_address = sse_decode_box_autoadd_bitcoin_address_data(deserializer);
+        return InputType_BitcoinAddress(address: var_address);
+      case 3:
+        var var_url = sse_decode_String(deserializer);
+        return InputType_LnUrl(url: var_url);
+      case 4:
+        var var_address = sse_decode_String(deserializer);
+        var var_url = sse_decode_String(deserializer);
+        return InputType_LightningAddress(address: var_address, url: var_url);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  LightningLimits sse_decode_lightning_limits(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_minSat = sse_decode_u_64(deserializer);
+    var var_maxSat = sse_decode_u_64(deserializer);
+    var var_serviceFeePercentage = sse_decode_f_64(deserializer);
+    var var_minerFeesSat = sse_decode_u_64(deserializer);
+    return LightningLimits(
+        minSat: var_minSat,
+        maxSat: var_maxSat,
+        serviceFeePercentage: var_serviceFeePercentage,
+        minerFeesSat: var_minerFeesSat);
+  }
+
+  @protected
+  LightningPaymentLimitsResponse sse_decode_lightning_payment_limits_response(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_send = sse_decode_lightning_limits(deserializer);
+    var var_receive = sse_decode_lightning_limits(deserializer);
+    return LightningPaymentLimitsResponse(send: var_send, receive: var_receive);
+  }
+
+  @protected
+  LiquidAddressData sse_decode_liquid_address_data(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_address = sse_decode_String(deserializer);
+    var var_network = sse_decode_network(deserializer);
+    var var_amountSat = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_assetId = sse_decode_opt_String(deserializer);
+    var var_label = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_opt_String(deserializer);
+    return LiquidAddressData(
+        address: var_address,
+        network: var_network,
+        amountSat: var_amountSat,
+        assetId: var_assetId,
+        label: var_label,
+        message: var_message);
+  }
+
+  @protected
+  LiquidSdkError sse_decode_liquid_sdk_error(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        return LiquidSdkError_AlreadyStarted();
+      case 1:
+        var var_err = sse_decode_String(deserializer);
+        return LiquidSdkError_Generic(err: var_err);
+      case 2:
+        return LiquidSdkError_NotStarted();
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  LiquidSdkEvent sse_decode_liquid_sdk_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_details = sse_decode_box_autoadd_payment(deserializer);
+        return LiquidSdkEvent_PaymentFailed(details: var_details);
+      case 1:
+        var var_details = sse_decode_box_autoadd_payment(deserializer);
+        return LiquidSdkEvent_PaymentPending(details: var_details);
+      case 2:
+        var var_details = sse_decode_box_autoadd_payment(deserializer);
+        return LiquidSdkEvent_PaymentRefunded(details: var_details);
+      case 3:
+        var var_details = sse_decode_box_autoadd_payment(deserializer);
+        return LiquidSdkEvent_PaymentRefundable(details: var_details);
+      case 4:
+        var var_details = sse_decode_box_autoadd_payment(deserializer);
+        return LiquidSdkEvent_PaymentRefundPending(details: var_details);
+      case 5:
+        var var_details = sse_decode_box_autoadd_payment(deserializer);
+        return LiquidSdkEvent_PaymentSucceeded(details: var_details);
+      case 6:
+        var var_details = sse_decode_box_autoadd_payment(deserializer);
+        return LiquidSdkEvent_PaymentWaitingConfirmation(details: var_details);
+      case 7:
+        return LiquidSdkEvent_Synced();
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  List<AssetBalance> sse_decode_list_asset_balance(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <AssetBalance>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_asset_balance(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<AssetMetadata> sse_decode_list_asset_metadata(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <AssetMetadata>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_asset_metadata(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ElectrumServer> sse_decode_list_electrum_server(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ElectrumServer>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_electrum_server(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ElectrumServerStatus> sse_decode_list_electrum_server_status(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ElectrumServerStatus>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_electrum_server_status(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<Payment> sse_decode_list_payment(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Payment>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_payment(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PaymentState> sse_decode_list_payment_state(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PaymentState>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_payment_state(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PaymentType> sse_decode_list_payment_type(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PaymentType>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_payment_type(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  ListPaymentsRequest sse_decode_list_payments_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_paymentTypes = sse_decode_opt_list_payment_type(deserializer);
+    var var_states = sse_decode_opt_list_payment_state(deserializer);
+    var var_fromTimestamp = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_toTimestamp = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_offset = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_limit = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_sortDescending = sse_decode_opt_box_autoadd_bool(deserializer);
+    var var_metadataSearch = sse_decode_opt_String(deserializer);
+    return ListPaymentsRequest(
+        paymentTypes: var_paymentTypes,
+        states: var_states,
+        fromTimestamp: var_fromTimestamp,
+        toTimestamp: var_toTimestamp,
+        offset: var_offset,
+        limit: var_limit,
+        sortDescending: var_sortDescending,
+        metadataSearch: var_metadataSearch);
+  }
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  List<RefundableSwap> sse_decode_list_refundable_swap(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <RefundableSwap>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_refundable_swap(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<RouteHint> sse_decode_list_route_hint(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <RouteHint>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_route_hint(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<RouteHintHop> sse_decode_list_route_hint_hop(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <RouteHintHop>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_route_hint_hop(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  LNInvoice sse_decode_ln_invoice(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_bolt11 = sse_decode_String(deserializer);
+    var var_network = sse_decode_network(deserializer);
+    var var_payeePubkey = sse_decode_String(deserializer);
+    var var_paymentHash = sse_decode_String(deserializer);
+    var var_description = sse_decode_opt_String(deserializer);
+    var var_descriptionHash = sse_decode_opt_String(deserializer);
+    var var_amountMsat = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_timestamp = sse_decode_u_64(deserializer);
+    var var_expiry = sse_decode_u_64(deserializer);
+    var var_routingHints = sse_decode_list_route_hint(deserializer);
+    var var_paymentSecret = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_minFinalCltvExpiryDelta = sse_decode_u_64(deserializer);
+    return LNInvoice(
+        bolt11: var_bolt11,
+        network: var_network,
+        payeePubkey: var_payeePubkey,
+        paymentHash: var_paymentHash,
+        description: var_description,
+        descriptionHash: var_descriptionHash,
+        amountMsat: var_amountMsat,
+        timestamp: var_timestamp,
+        expiry: var_expiry,
+        routingHints: var_routingHints,
+        paymentSecret: var_paymentSecret,
+        minFinalCltvExpiryDelta: var_minFinalCltvExpiryDelta);
+  }
+
+  @protected
+  LnUrlPayResult sse_decode_ln_url_pay_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_payment = sse_decode_payment(deserializer);
+    var var_successAction = sse_decode_opt_box_autoadd_success_action_processed(deserializer);
+    return LnUrlPayResult(payment: var_payment, successAction: var_successAction);
+  }
+
+  @protected
+  LnUrlWithdrawRequest sse_decode_ln_url_withdraw_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_lnurl = sse_decode_String(deserializer);
+    var var_amountSat = sse_decode_u_64(deserializer);
+    return LnUrlWithdrawRequest(lnurl: var_lnurl, amountSat: var_amountSat);
+  }
+
+  @protected
+  LnUrlWithdrawResult sse_decode_ln_url_withdraw_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_receivePaymentResponse = sse_decode_receive_payment_response(deserializer);
+    return LnUrlWithdrawResult(receivePaymentResponse: var_receivePaymentResponse);
+  }
+
+  @protected
+  LogEntry sse_decode_log_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_line = sse_decode_String(deserializer);
+    var var_level = sse_decode_String(deserializer);
+    return LogEntry(line: var_line, level: var_level);
+  }
+
+  @protected
+  Network sse_decode_network(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return Network.values[inner];
+  }
+
+  @protected
+  OnchainFeeRate sse_decode_onchain_fee_rate(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_satPerVbyte = sse_decode_f_64(deserializer);
+        return OnchainFeeRate_SatPerVbyte(satPerVbyte: var_satPerVbyte);
+      case 1:
+        var var_priority = sse_decode_fee_priority(deserializer);
+        return OnchainFeeRate_Priority(priority: var_priority);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  AssetMetadata? sse_decode_opt_box_autoadd_asset_metadata(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_asset_metadata(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_bool(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_f_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  FeeBreakdown? sse_decode_opt_box_autoadd_fee_breakdown(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_fee_breakdown(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  OnchainFeeRate? sse_decode_opt_box_autoadd_onchain_fee_rate(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_onchain_fee_rate(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  Payment? sse_decode_opt_box_autoadd_payment(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_payment(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PaymentMetadata? sse_decode_opt_box_autoadd_payment_metadata(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_payment_metadata(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  SuccessAction? sse_decode_opt_box_autoadd_success_action(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_success_action(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  SuccessActionProcessed? sse_decode_opt_box_autoadd_success_action_processed(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_success_action_processed(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  List<AssetMetadata>? sse_decode_opt_list_asset_metadata(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_asset_metadata(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  List<PaymentState>? sse_decode_opt_list_payment_state(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_payment_state(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  List<PaymentType>? sse_decode_opt_list_payment_type(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_payment_type(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PayOnchainRequest sse_decode_pay_onchain_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_address = sse_decode_String(deserializer);
+    var var_prepareRes = sse_decode_prepare_pay_onchain_response(deserializer);
+    return PayOnchainRequest(address: var_address, prepareRes: var_prepareRes);
+  }
+
+  @protected
+  Payment sse_decode_payment(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_txId = sse_decode_opt_String(deserializer);
+    var var_swapId = sse_decode_opt_String(deserializer);
+    var var_swapType = sse_decode_payment_swap_type(deserializer);
+    var var_timestamp = sse_decode_u_32(deserializer);
+    var var_amountSat = sse_decode_u_64(deserializer);
+    var var_feesSat = sse_decode_u_64(deserializer);
+    var var_networkFeesSat = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_feeBreakdown = sse_decode_opt_box_autoadd_fee_breakdown(deserializer);
+    var var_feeRateSatPerVbyte = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_preimage = sse_decode_opt_String(deserializer);
+    var var_bolt11 = sse_decode_opt_String(deserializer);
+    var var_paymentHash = sse_decode_opt_String(deserializer);
+    var var_payeePubkey = sse_decode_opt_String(deserializer);
+    var var_lockupTxId = sse_decode_opt_String(deserializer);
+    var var_claimTxId = sse_decode_opt_String(deserializer);
+    var var_refundTxId = sse_decode_opt_String(deserializer);
+    var var_refundTxAmountSat = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_paymentType = sse_decode_payment_type(deserializer);
+    var var_status = sse_decode_payment_state(deserializer);
+    var var_label = sse_decode_opt_String(deserializer);
+    var var_description = sse_decode_opt_String(deserializer);
+    var var_metadata = sse_decode_opt_box_autoadd_payment_metadata(deserializer);
+    var var_assetId = sse_decode_opt_String(deserializer);
+    var var_assetAmount = sse_decode_opt_box_autoadd_u_64(deserializer);
+    return Payment(
+        txId: var_txId,
+        swapId: var_swapId,
+        swapType: var_swapType,
+        timestamp: var_timestamp,
+        amountSat: var_amountSat,
+        feesSat: var_feesSat,
+        networkFeesSat: var_networkFeesSat,
+        feeBreakdown: var_feeBreakdown,
+        feeRateSatPerVbyte: var_feeRateSatPerVbyte,
+        preimage: var_preimage,
+        bolt11: var_bolt11,
+        paymentHash: var_paymentHash,
+        payeePubkey: var_payeePubkey,
+        lockupTxId: var_lockupTxId,
+        claimTxId: var_claimTxId,
+        refundTxId: var_refundTxId,
+        refundTxAmountSat: var_refundTxAmountSat,
+        paymentType: var_paymentType,
+        status: var_status,
+        label: var_label,
+        description: var_description,
+        metadata: var_metadata,
+        assetId: var_assetId,
+        assetAmount: var_assetAmount);
+  }
+
+  @protected
+  PaymentError sse_decode_payment_error(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        return PaymentError_AlreadyClaimed();
+      case 1:
+        return PaymentError_AlreadyPaid();
+      case 2:
+        return PaymentError_PaymentInProgress();
+      case 3:
+        return PaymentError_AmountOutOfRange();
+      case 4:
+        var var_err = sse_decode_String(deserializer);
+        return PaymentError_UnrecognizedInput(err: var_err);
+      case 5:
+        var var_err = sse_decode_String(deserializer);
+        return PaymentError_NetworkMismatch(err: var_err);
+      case 6:
+        var var_err = sse_decode_String(deserializer);
+        return PaymentError_LnUrlError(err: var_err);
+      case 7:
+        var var_err = sse_decode_String(deserializer);
+        return PaymentError_LnUrlServiceUnreachable(err: var_err);
+      case 8:
+        var var_err = sse_decode_String(deserializer);
+        return PaymentError_LnUrlServiceError(err: var_err);
+      case 9:
+        var var_err = sse_decode_String(deserializer);
+        return PaymentError_Generic(err: var_err);
+      case 10:
+        return PaymentError_InvalidOrExpiredFees();
+      case 11:
+        var var_feeSat = sse_decode_u_64(deserializer);
+        var var_limitSat = sse_decode_u_64(deserializer);
+        return PaymentError_FeeLimitExceeded(feeSat: var_feeSat, limitSat: var_limitSat);
+      case 12:
+        return PaymentError_InsufficientFunds();
+      case 13:
+        var var_err = sse_decode_String(deserializer);
+        return PaymentError_InvalidInvoice(err: var_err);
+      case 14:
+        return PaymentError_InvalidPreimage();
+      case 15:
+        var var_err = sse_decode_String(deserializer);
+        return PaymentError_LwkError(err: var_err);
+      case 16:
+        return PaymentError_PairsNotFound();
+      case 17:
+        return PaymentError_PaymentTimeout();
+      case 18:
+        return PaymentError_PersistError();
+      case 19:
+        var var_err = sse_decode_String(deserializer);
+        return PaymentError_ReceiveError(err: var_err);
+      case 20:
+        var var_err = sse_decode_String(deserializer);
+        var var_refundTxId = sse_decode_String(deserializer);
+        return PaymentError_Refunded(err: var_err, refundTxId: var_refundTxId);
+      case 21:
+        return PaymentError_SelfTransferNotSupported();
+      case 22:
+        var var_err = sse_decode_String(deserializer);
+        return PaymentError_SendError(err: var_err);
+      case 23:
+        var var_err = sse_decode_String(deserializer);
+        return PaymentError_SignerError(err: var_err);
+      default:
+        throw UnimplementedError('');
+    }
   }
 
   @protected
-  BindingLiquidSdk dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
-      dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return BindingLiquidSdkImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  PaymentExportFormat sse_decode_payment_export_format(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return PaymentExportFormat.values[inner];
   }
 
   @protected
-  RustStreamSink<LiquidSdkEvent> dco_decode_StreamSink_liquid_sdk_event_Dco(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError();
+  PaymentMetadata sse_decode_payment_metadata(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_label = sse_decode_opt_String(deserializer);
+    var var_note = sse_decode_opt_String(deserializer);
+    var var_externalId = sse_decode_opt_String(deserializer);
+    return PaymentMetadata(label: var_label, note: var_note, externalId: var_externalId);
   }
 
   @protected
-  RustStreamSink<LogEntry> dco_decode_StreamSink_log_entry_Dco(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError();
+  PaymentState sse_decode_payment_state(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return PaymentState.values[inner];
   }
 
   @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
+  PaymentSwapType sse_decode_payment_swap_type(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return PaymentSwapType.values[inner];
   }
 
   @protected
-  BackupRequest dco_decode_backup_request(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
-    return BackupRequest(
-      backupPath: dco_decode_opt_String(arr[0]),
-    );
+  PaymentType sse_decode_payment_type(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return PaymentType.values[inner];
   }
 
   @protected
-  BindingEventListener dco_decode_binding_event_listener(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
-    return BindingEventListener(
-      stream: dco_decode_StreamSink_liquid_sdk_event_Dco(arr[0]),
-    );
+  PrepareLnUrlPayRequest sse_decode_prepare_ln_url_pay_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_lnurl = sse_decode_String(deserializer);
+    var var_amountSat = sse_decode_u_64(deserializer);
+    var var_comment = sse_decode_opt_String(deserializer);
+    return PrepareLnUrlPayRequest(lnurl: var_lnurl, amountSat: var_amountSat, comment: var_comment);
   }
 
   @protected
-  BackupRequest dco_decode_box_autoadd_backup_request(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_backup_request(raw);
+  PrepareLnUrlPayResponse sse_decode_prepare_ln_url_pay_response(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_prepareSendResponse = sse_decode_prepare_send_response(deserializer);
+    var var_successAction = sse_decode_opt_box_autoadd_success_action(deserializer);
+    return PrepareLnUrlPayResponse(
+        prepareSendResponse: var_prepareSendResponse, successAction: var_successAction);
   }
 
   @protected
-  BindingEventListener dco_decode_box_autoadd_binding_event_listener(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_binding_event_listener(raw);
+  PreparePayOnchainRequest sse_decode_prepare_pay_onchain_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_receiverAmountSat = sse_decode_u_64(deserializer);
+    var var_feeRate = sse_decode_opt_box_autoadd_onchain_fee_rate(deserializer);
+    return PreparePayOnchainRequest(receiverAmountSat: var_receiverAmountSat, feeRate: var_feeRate);
   }
 
   @protected
-  ConnectRequest dco_decode_box_autoadd_connect_request(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_connect_request(raw);
+  PreparePayOnchainResponse sse_decode_prepare_pay_onchain_response(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_receiverAmountSat = sse_decode_u_64(deserializer);
+    var var_feesSat = sse_decode_u_64(deserializer);
+    var var_feeRateSatPerVbyte = sse_decode_opt_box_autoadd_f_64(deserializer);
+    return PreparePayOnchainResponse(
+        receiverAmountSat: var_receiverAmountSat,
+        feesSat: var_feesSat,
+        feeRateSatPerVbyte: var_feeRateSatPerVbyte);
   }
 
   @protected
-  LiquidSdkEvent dco_decode_box_autoadd_liquid_sdk_event(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_liquid_sdk_event(raw);
+  PrepareReceiveOnchainRequest sse_decode_prepare_receive_onchain_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_payerAmountSat = sse_decode_u_64(deserializer);
+    var var_feeRate = sse_decode_opt_box_autoadd_onchain_fee_rate(deserializer);
+    return PrepareReceiveOnchainRequest(payerAmountSat: var_payerAmountSat, feeRate: var_feeRate);
   }
 
   @protected
-  Payment dco_decode_box_autoadd_payment(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_payment(raw);
+  PrepareReceiveOnchainResponse sse_decode_prepare_receive_onchain_response(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_payerAmountSat = sse_decode_u_64(deserializer);
+    var var_feesSat = sse_decode_u_64(deserializer);
+    var var_feeRateSatPerVbyte = sse_decode_opt_box_autoadd_f_64(deserializer);
+    return PrepareReceiveOnchainResponse(
+        payerAmountSat: var_payerAmountSat, feesSat: var_feesSat, feeRateSatPerVbyte: var_feeRateSatPerVbyte);
   }
 
   @protected
-  PrepareReceiveRequest dco_decode_box_autoadd_prepare_receive_request(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_prepare_receive_request(raw);
+  PrepareReceiveRequest sse_decode_prepare_receive_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_payerAmountSat = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_receiverAmountSat = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_description = sse_decode_opt_String(deserializer);
+    var var_descriptionHash = sse_decode_opt_String(deserializer);
+    var var_invoiceExpirySecs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_metadata = sse_decode_opt_box_autoadd_payment_metadata(deserializer);
+    var var_maxFeeSat = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_maxFeePercent = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_feeRate = sse_decode_opt_box_autoadd_onchain_fee_rate(deserializer);
+    return PrepareReceiveRequest(
+        payerAmountSat: var_payerAmountSat,
+        receiverAmountSat: var_receiverAmountSat,
+        description: var_description,
+        descriptionHash: var_descriptionHash,
+        invoiceExpirySecs: var_invoiceExpirySecs,
+        metadata: var_metadata,
+        maxFeeSat: var_maxFeeSat,
+        maxFeePercent: var_maxFeePercent,
+        feeRate: var_feeRate);
   }
 
   @protected
-  PrepareReceiveResponse dco_decode_box_autoadd_prepare_receive_response(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_prepare_receive_response(raw);
+  PrepareReceiveResponse sse_decode_prepare_receive_response(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_payerAmountSat = sse_decode_u_64(deserializer);
+    var var_receiverAmountSat = sse_decode_u_64(deserializer);
+    var var_feesSat = sse_decode_u_64(deserializer);
+    var var_description = sse_decode_opt_String(deserializer);
+    var var_descriptionHash = sse_decode_opt_String(deserializer);
+    var var_invoiceExpirySecs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_metadata = sse_decode_opt_box_autoadd_payment_metadata(deserializer);
+    var var_feeBreakdown = sse_decode_fee_breakdown(deserializer);
+    var var_pairHash = sse_decode_String(deserializer);
+    var var_expiresAt = sse_decode_u_32(deserializer);
+    var var_feeRateSatPerVbyte = sse_decode_opt_box_autoadd_f_64(deserializer);
+    return PrepareReceiveResponse(
+        payerAmountSat: var_payerAmountSat,
+        receiverAmountSat: var_receiverAmountSat,
+        feesSat: var_feesSat,
+        description: var_description,
+        descriptionHash: var_descriptionHash,
+        invoiceExpirySecs: var_invoiceExpirySecs,
+        metadata: var_metadata,
+        feeBreakdown: var_feeBreakdown,
+        pairHash: var_pairHash,
+        expiresAt: var_expiresAt,
+        feeRateSatPerVbyte: var_feeRateSatPerVbyte);
   }
 
   @protected
-  PrepareSendRequest dco_decode_box_autoadd_prepare_send_request(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_prepare_send_request(raw);
+  PrepareRefundRequest sse_decode_prepare_refund_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_swapId = sse_decode_String(deserializer);
+    var var_refundAddress = sse_decode_String(deserializer);
+    var var_feeRate = sse_decode_onchain_fee_rate(deserializer);
+    return PrepareRefundRequest(swapId: var_swapId, refundAddress: var_refundAddress, feeRate: var_feeRate);
   }
 
   @protected
-  PrepareSendResponse dco_decode_box_autoadd_prepare_send_response(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_prepare_send_response(raw);
+  PrepareRefundResponse sse_decode_prepare_refund_response(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_txVsize = sse_decode_u_32(deserializer);
+    var var_txFeeSat = sse_decode_u_64(deserializer);
+    var var_refundAmountSat = sse_decode_u_64(deserializer);
+    var var_feeRateSatPerVbyte = sse_decode_f_64(deserializer);
+    return PrepareRefundResponse(
+        txVsize: var_txVsize,
+        txFeeSat: var_txFeeSat,
+        refundAmountSat: var_refundAmountSat,
+        feeRateSatPerVbyte: var_feeRateSatPerVbyte);
   }
 
   @protected
-  RestoreRequest dco_decode_box_autoadd_restore_request(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_restore_request(raw);
+  PrepareSendOnchainRequest sse_decode_prepare_send_onchain_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_destination = sse_decode_String(deserializer);
+    var var_amountSat = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_sendAll = sse_decode_bool(deserializer);
+    var var_assetId = sse_decode_opt_String(deserializer);
+    var var_feeRate = sse_decode_opt_box_autoadd_onchain_fee_rate(deserializer);
+    return PrepareSendOnchainRequest(
+        destination: var_destination,
+        amountSat: var_amountSat,
+        sendAll: var_sendAll,
+        assetId: var_assetId,
+        feeRate: var_feeRate);
   }
 
   @protected
-  BigInt dco_decode_box_autoadd_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_u_64(raw);
+  PrepareSendOnchainResponse sse_decode_prepare_send_onchain_response(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_address = sse_decode_String(deserializer);
+    var var_receiverAmountSat = sse_decode_u_64(deserializer);
+    var var_feesSat = sse_decode_u_64(deserializer);
+    var var_sendAll = sse_decode_bool(deserializer);
+    var var_assetId = sse_decode_opt_String(deserializer);
+    var var_feeRateSatPerVbyte = sse_decode_opt_box_autoadd_f_64(deserializer);
+    return PrepareSendOnchainResponse(
+        address: var_address,
+        receiverAmountSat: var_receiverAmountSat,
+        feesSat: var_feesSat,
+        sendAll: var_sendAll,
+        assetId: var_assetId,
+        feeRateSatPerVbyte: var_feeRateSatPerVbyte);
   }
 
   @protected
-  Config dco_decode_config(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
-    return Config(
-      boltzUrl: dco_decode_String(arr[0]),
-      electrumUrl: dco_decode_String(arr[1]),
-      workingDir: dco_decode_String(arr[2]),
-      network: dco_decode_network(arr[3]),
-      paymentTimeoutSec: dco_decode_u_64(arr[4]),
-      zeroConfMinFeeRate: dco_decode_f_32(arr[5]),
-      zeroConfMaxAmountSat: dco_decode_opt_box_autoadd_u_64(arr[6]),
-    );
+  PrepareSendRequest sse_decode_prepare_send_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_invoice = sse_decode_String(deserializer);
+    var var_metadata = sse_decode_opt_box_autoadd_payment_metadata(deserializer);
+    var var_maxFeeSat = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_maxFeePercent = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_feeRate = sse_decode_opt_box_autoadd_onchain_fee_rate(deserializer);
+    return PrepareSendRequest(
+        invoice: var_invoice,
+        metadata: var_metadata,
+        maxFeeSat: var_maxFeeSat,
+        maxFeePercent: var_maxFeePercent,
+        feeRate: var_feeRate);
   }
 
   @protected
-  ConnectRequest dco_decode_connect_request(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return ConnectRequest(
-      mnemonic: dco_decode_String(arr[0]),
-      config: dco_decode_config(arr[1]),
-    );
+  PrepareSendResponse sse_decode_prepare_send_response(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_invoice = sse_decode_String(deserializer);
+    var var_feesSat = sse_decode_u_64(deserializer);
+    var var_metadata = sse_decode_opt_box_autoadd_payment_metadata(deserializer);
+    var var_feeBreakdown = sse_decode_fee_breakdown(deserializer);
+    var var_maxFeeSat = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_maxFeePercent = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_pairHash = sse_decode_String(deserializer);
+    var var_expiresAt = sse_decode_u_32(deserializer);
+    var var_feeRateSatPerVbyte = sse_decode_opt_box_autoadd_f_64(deserializer);
+    return PrepareSendResponse(
+        invoice: var_invoice,
+        feesSat: var_feesSat,
+        metadata: var_metadata,
+        feeBreakdown: var_feeBreakdown,
+        maxFeeSat: var_maxFeeSat,
+        maxFeePercent: var_maxFeePercent,
+        pairHash: var_pairHash,
+        expiresAt: var_expiresAt,
+        feeRateSatPerVbyte: var_feeRateSatPerVbyte);
   }
 
   @protected
-  double dco_decode_f_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
+  ReceiveLiquidRequest sse_decode_receive_liquid_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_amountSat = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_label = sse_decode_opt_String(deserializer);
+    return ReceiveLiquidRequest(amountSat: var_amountSat, label: var_label);
   }
 
   @protected
-  GetInfoResponse dco_decode_get_info_response(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return GetInfoResponse(
-      balanceSat: dco_decode_u_64(arr[0]),
-      pendingSendSat: dco_decode_u_64(arr[1]),
-      pendingReceiveSat: dco_decode_u_64(arr[2]),
-      pubkey: dco_decode_String(arr[3]),
-    );
+  ReceiveLiquidResponse sse_decode_receive_liquid_response(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_address = sse_decode_String(deserializer);
+    var var_bip21 = sse_decode_String(deserializer);
+    return ReceiveLiquidResponse(address: var_address, bip21: var_bip21);
   }
 
   @protected
-  int dco_decode_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  ReceiveOnchainResponse sse_decode_receive_onchain_response(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_address = sse_decode_String(deserializer);
+    var var_bip21 = sse_decode_String(deserializer);
+    return ReceiveOnchainResponse(id: var_id, address: var_address, bip21: var_bip21);
   }
 
   @protected
-  LiquidSdkError dco_decode_liquid_sdk_error(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    switch (raw[0]) {
-      case 0:
-        return LiquidSdkError_AlreadyStarted();
-      case 1:
-        return LiquidSdkError_Generic(
-          err: dco_decode_String(raw[1]),
-        );
-      case 2:
-        return LiquidSdkError_NotStarted();
-      default:
-        throw Exception("unreachable");
-    }
+  ReceivePaymentResponse sse_decode_receive_payment_response(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_invoice = sse_decode_String(deserializer);
+    return ReceivePaymentResponse(id: var_id, invoice: var_invoice);
   }
 
   @protected
-  LiquidSdkEvent dco_decode_liquid_sdk_event(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    switch (raw[0]) {
-      case 0:
-        return LiquidSdkEvent_PaymentFailed(
-          details: dco_decode_box_autoadd_payment(raw[1]),
-        );
-      case 1:
-        return LiquidSdkEvent_PaymentPending(
-          details: dco_decode_box_autoadd_payment(raw[1]),
-        );
-      case 2:
-        return LiquidSdkEvent_PaymentRefunded(
-          details: dco_decode_box_autoadd_payment(raw[1]),
-        );
-      case 3:
-        return LiquidSdkEvent_PaymentRefundPending(
-          details: dco_decode_box_autoadd_payment(raw[1]),
-        );
-      case 4:
-        return LiquidSdkEvent_PaymentSucceeded(
-          details: dco_decode_box_autoadd_payment(raw[1]),
-        );
-      case 5:
-        return LiquidSdkEvent_PaymentWaitingConfirmation(
-          details: dco_decode_box_autoadd_payment(raw[1]),
-        );
-      case 6:
-        return LiquidSdkEvent_Synced();
-      default:
-        throw Exception("unreachable");
-    }
+  RecommendedFees sse_decode_recommended_fees(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_fastestFee = sse_decode_f_64(deserializer);
+    var var_halfHourFee = sse_decode_f_64(deserializer);
+    var var_hourFee = sse_decode_f_64(deserializer);
+    var var_economyFee = sse_decode_f_64(deserializer);
+    var var_minimumFee = sse_decode_f_64(deserializer);
+    return RecommendedFees(
+        fastestFee: var_fastestFee,
+        halfHourFee: var_halfHourFee,
+        hourFee: var_hourFee,
+        economyFee: var_economyFee,
+        minimumFee: var_minimumFee);
+  }
+
+  @protected
+  RecommendedFeesResponse sse_decode_recommended_fees_response(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_liquid = sse_decode_recommended_fees(deserializer);
+    var var_bitcoin = sse_decode_recommended_fees(deserializer);
+    return RecommendedFeesResponse(liquid: var_liquid, bitcoin: var_bitcoin);
+  }
+
+  @protected
+  RefundRequest sse_decode_refund_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_swapId = sse_decode_String(deserializer);
+    var var_refundAddress = sse_decode_String(deserializer);
+    var var_feeRateSatPerVbyte = sse_decode_f_64(deserializer);
+    return RefundRequest(
+        swapId: var_swapId, refundAddress: var_refundAddress, feeRateSatPerVbyte: var_feeRateSatPerVbyte);
+  }
+
+  @protected
+  RefundResponse sse_decode_refund_response(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_refundTxId = sse_decode_String(deserializer);
+    return RefundResponse(refundTxId: var_refundTxId);
+  }
+
+  @protected
+  RefundableSwap sse_decode_refundable_swap(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_swapId = sse_decode_String(deserializer);
+    var var_swapAddress = sse_decode_String(deserializer);
+    var var_timestamp = sse_decode_u_32(deserializer);
+    var var_amountSat = sse_decode_u_64(deserializer);
+    var var_paymentType = sse_decode_payment_type(deserializer);
+    return RefundableSwap(
+        swapId: var_swapId,
+        swapAddress: var_swapAddress,
+        timestamp: var_timestamp,
+        amountSat: var_amountSat,
+        paymentType: var_paymentType);
+  }
+
+  @protected
+  RestoreRequest sse_decode_restore_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_backupPath = sse_decode_opt_String(deserializer);
+    return RestoreRequest(backupPath: var_backupPath);
+  }
+
+  @protected
+  RouteHint sse_decode_route_hint(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_hops = sse_decode_list_route_hint_hop(deserializer);
+    return RouteHint(hops: var_hops);
   }
 
   @protected
-  List<Payment> dco_decode_list_payment(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_payment).toList();
+  RouteHintHop sse_decode_route_hint_hop(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_srcNodeId = sse_decode_String(deserializer);
+    var var_shortChannelId = sse_decode_u_64(deserializer);
+    var var_feesBaseMsat = sse_decode_u_32(deserializer);
+    var var_feesProportionalMillionths = sse_decode_u_32(deserializer);
+    var var_cltvExpiryDelta = sse_decode_u_64(deserializer);
+    var var_htlcMinimumMsat = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_htlcMaximumMsat = sse_decode_opt_box_autoadd_u_64(deserializer);
+    return RouteHintHop(
+        srcNodeId: var_srcNodeId,
+        shortChannelId: var_shortChannelId,
+        feesBaseMsat: var_feesBaseMsat,
+        feesProportionalMillionths: var_feesProportionalMillionths,
+        cltvExpiryDelta: var_cltvExpiryDelta,
+        htlcMinimumMsat: var_htlcMinimumMsat,
+        htlcMaximumMsat: var_htlcMaximumMsat);
   }
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
+  SendPaymentResponse sse_decode_send_payment_response(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_payment = sse_decode_payment(deserializer);
+    return SendPaymentResponse(payment: var_payment);
   }
 
   @protected
-  List<RouteHint> dco_decode_list_route_hint(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_route_hint).toList();
+  SetPaymentMetadataRequest sse_decode_set_payment_metadata_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_metadata = sse_decode_payment_metadata(deserializer);
+    return SetPaymentMetadataRequest(id: var_id, metadata: var_metadata);
   }
 
   @protected
-  List<RouteHintHop> dco_decode_list_route_hint_hop(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_route_hint_hop).toList();
+  SuccessAction sse_decode_success_action(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_message = sse_decode_String(deserializer);
+        return SuccessAction_Message(message: var_message);
+      case 1:
+        var var_description = sse_decode_String(deserializer);
+        var var_url = sse_decode_String(deserializer);
+        return SuccessAction_Url(description: var_description, url: var_url);
+      case 2:
+        var var_description = sse_decode_String(deserializer);
+        var var_ciphertext = sse_decode_String(deserializer);
+        var var_iv = sse_decode_String(deserializer);
+        return SuccessAction_Aes(description: var_description, ciphertext: var_ciphertext, iv: var_iv);
+      default:
+        throw UnimplementedError('');
+    }
   }
 
   @protected
-  LNInvoice dco_decode_ln_invoice(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 12) throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
-    return LNInvoice(
-      bolt11: dco_decode_String(arr[0]),
-      network: dco_decode_network(arr[1]),
-      payeePubkey: dco_decode_String(arr[2]),
-      paymentHash: dco_decode_String(arr[3]),
-      description: dco_decode_opt_String(arr[4]),
-      descriptionHash: dco_decode_opt_String(arr[5]),
-      amountMsat: dco_decode_opt_box_autoadd_u_64(arr[6]),
-      timestamp: dco_decode_u_64(arr[7]),
-      expiry: dco_decode_u_64(arr[8]),
-      routingHints: dco_decode_list_route_hint(arr[9]),
-      paymentSecret: dco_decode_list_prim_u_8_strict(arr[10]),
-      minFinalCltvExpiryDelta: dco_decode_u_64(arr[11]),
-    );
+  SuccessActionProcessed sse_decode_success_action_processed(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_message = sse_decode_String(deserializer);
+        return SuccessActionProcessed_Message(message: var_message);
+      case 1:
+        var var_description = sse_decode_String(deserializer);
+        var var_url = sse_decode_String(deserializer);
+        return SuccessActionProcessed_Url(description: var_description, url: var_url);
+      case 2:
+        var var_description = sse_decode_String(deserializer);
+        var var_plaintext = sse_decode_String(deserializer);
+        return SuccessActionProcessed_Aes(description: var_description, plaintext: var_plaintext);
+      default:
+        throw UnimplementedError('');
+    }
   }
 
   @protected
-  LogEntry dco_decode_log_entry(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return LogEntry(
-      line: dco_decode_String(arr[0]),
-      level: dco_decode_String(arr[1]),
-    );
+  int sse_decode_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint32();
   }
 
   @protected
-  Network dco_decode_network(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Network.values[raw as int];
+  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
   }
 
   @protected
-  String? dco_decode_opt_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
+  int sse_decode_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8();
   }
 
   @protected
-  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
+  void sse_decode_unit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
   }
 
   @protected
-  Payment dco_decode_payment(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
-    return Payment(
-      txId: dco_decode_opt_String(arr[0]),
-      swapId: dco_decode_opt_String(arr[1]),
-      timestamp: dco_decode_u_32(arr[2]),
-      amountSat: dco_decode_u_64(arr[3]),
-      feesSat: dco_decode_u_64(arr[4]),
-      preimage: dco_decode_opt_String(arr[5]),
-      refundTxId: dco_decode_opt_String(arr[6]),
-      refundTxAmountSat: dco_decode_opt_box_autoadd_u_64(arr[7]),
-      paymentType: dco_decode_payment_type(arr[8]),
-      status: dco_decode_payment_state(arr[9]),
-    );
+  BigInt sse_decode_usize(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
   }
 
   @protected
-  PaymentError dco_decode_payment_error(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    switch (raw[0]) {
-      case 0:
-        return PaymentError_AlreadyClaimed();
-      case 1:
-        return PaymentError_AlreadyPaid();
-      case 2:
-        return PaymentError_PaymentInProgress();
-      case 3:
-        return PaymentError_AmountOutOfRange();
-      case 4:
-        return PaymentError_Generic(
-          err: dco_decode_String(raw[1]),
-        );
-      case 5:
-        return PaymentError_InvalidOrExpiredFees();
-      case 6:
-        return PaymentError_InsufficientFunds();
-      case 7:
-        return PaymentError_InvalidInvoice(
-          err: dco_decode_String(raw[1]),
-        );
-      case 8:
-        return PaymentError_InvalidPreimage();
-      case 9:
-        return PaymentError_LwkError(
-          err: dco_decode_String(raw[1]),
-        );
-      case 10:
-        return PaymentError_PairsNotFound();
-      case 11:
-        return PaymentError_PaymentTimeout();
-      case 12:
-        return PaymentError_PersistError();
-      case 13:
-        return PaymentError_ReceiveError(
-          err: dco_decode_String(raw[1]),
-        );
-      case 14:
-        return PaymentError_Refunded(
-          err: dco_decode_String(raw[1]),
-          refundTxId: dco_decode_String(raw[2]),
-        );
-      case 15:
-        return PaymentError_SelfTransferNotSupported();
-      case 16:
-        return PaymentError_SendError(
-          err: dco_decode_String(raw[1]),
-        );
-      case 17:
-        return PaymentError_SignerError(
-          err: dco_decode_String(raw[1]),
-        );
-      default:
-        throw Exception("unreachable");
-    }
+  bool sse_decode_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8() != 0;
   }
 
   @protected
-  PaymentState dco_decode_payment_state(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return PaymentState.values[raw as int];
+  int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+      BindingLiquidSdk raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+// ignore: invalid_use_of_internal_member
+    return (raw as BindingLiquidSdkImpl).frbInternalCstEncode(move: true);
   }
 
   @protected
-  PaymentType dco_decode_payment_type(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return PaymentType.values[raw as int];
+  int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+      BindingLiquidSdk raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+// ignore: invalid_use_of_internal_member
+    return (raw as BindingLiquidSdkImpl).frbInternalCstEncode(move: false);
   }
 
   @protected
-  PrepareReceiveRequest dco_decode_prepare_receive_request(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
-    return PrepareReceiveRequest(
-      payerAmountSat: dco_decode_u_64(arr[0]),
-    );
+  int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+      BindingLiquidSdk raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+// ignore: invalid_use_of_internal_member
+    return (raw as BindingLiquidSdkImpl).frbInternalCstEncode();
   }
 
   @protected
-  PrepareReceiveResponse dco_decode_prepare_receive_response(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return PrepareReceiveResponse(
-      payerAmountSat: dco_decode_u_64(arr[0]),
-      feesSat: dco_decode_u_64(arr[1]),
-    );
+  bool cst_encode_bool(bool raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw;
   }
 
   @protected
-  PrepareSendRequest dco_decode_prepare_send_request(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
-    return PrepareSendRequest(
-      invoice: dco_decode_String(arr[0]),
-    );
+  double cst_encode_f_32(double raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw;
   }
 
   @protected
-  PrepareSendResponse dco_decode_prepare_send_response(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return PrepareSendResponse(
-      invoice: dco_decode_String(arr[0]),
-      feesSat: dco_decode_u_64(arr[1]),
-    );
+  double cst_encode_f_64(double raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw;
   }
 
   @protected
-  ReceivePaymentResponse dco_decode_receive_payment_response(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return ReceivePaymentResponse(
-      id: dco_decode_String(arr[0]),
-      invoice: dco_decode_String(arr[1]),
-    );
+  int cst_encode_fee_priority(FeePriority raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_32(raw.index);
   }
 
   @protected
-  RestoreRequest dco_decode_restore_request(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
-    return RestoreRequest(
-      backupPath: dco_decode_opt_String(arr[0]),
-    );
+  int cst_encode_i_32(int raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw;
   }
 
   @protected
-  RouteHint dco_decode_route_hint(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
-    return RouteHint(
-      hops: dco_decode_list_route_hint_hop(arr[0]),
-    );
+  int cst_encode_network(Network raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_32(raw.index);
   }
 
   @protected
-  RouteHintHop dco_decode_route_hint_hop(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
-    return RouteHintHop(
-      srcNodeId: dco_decode_String(arr[0]),
-      shortChannelId: dco_decode_u_64(arr[1]),
-      feesBaseMsat: dco_decode_u_32(arr[2]),
-      feesProportionalMillionths: dco_decode_u_32(arr[3]),
-      cltvExpiryDelta: dco_decode_u_64(arr[4]),
-      htlcMinimumMsat: dco_decode_opt_box_autoadd_u_64(arr[5]),
-      htlcMaximumMsat: dco_decode_opt_box_autoadd_u_64(arr[6]),
-    );
+  int cst_encode_payment_export_format(PaymentExportFormat raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_32(raw.index);
   }
 
   @protected
-  SendPaymentResponse dco_decode_send_payment_response(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
-    return SendPaymentResponse(
-      payment: dco_decode_payment(arr[0]),
-    );
+  int cst_encode_payment_state(PaymentState raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_32(raw.index);
   }
 
   @protected
-  int dco_decode_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  int cst_encode_payment_swap_type(PaymentSwapType raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_32(raw.index);
   }
 
   @protected
-  BigInt dco_decode_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
+  int cst_encode_payment_type(PaymentType raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return cst_encode_i_32(raw.index);
   }
 
   @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  int cst_encode_u_32(int raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw;
   }
 
   @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
+  int cst_encode_u_8(int raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw;
   }
 
   @protected
-  BigInt dco_decode_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
+  void cst_encode_unit(void raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw;
   }
 
   @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+  void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_String(deserializer);
-    return AnyhowException(inner);
+    sse_encode_String(self.message, serializer);
   }
 
   @protected
-  BindingLiquidSdk
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
-          SseDeserializer deserializer) {
+  void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+      BindingLiquidSdk self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return BindingLiquidSdkImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+    sse_encode_usize((self as BindingLiquidSdkImpl).frbInternalSseEncode(move: true), serializer);
   }
 
   @protected
-  BindingLiquidSdk
-      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
-          SseDeserializer deserializer) {
+  void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+      BindingLiquidSdk self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return BindingLiquidSdkImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+    sse_encode_usize((self as BindingLiquidSdkImpl).frbInternalSseEncode(move: false), serializer);
   }
 
   @protected
-  BindingLiquidSdk sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
-      SseDeserializer deserializer) {
+  void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+      BindingLiquidSdk self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return BindingLiquidSdkImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+    sse_encode_usize((self as BindingLiquidSdkImpl).frbInternalSseEncode(move: null), serializer);
   }
 
   @protected
-  RustStreamSink<LiquidSdkEvent> sse_decode_StreamSink_liquid_sdk_event_Dco(SseDeserializer deserializer) {
+  void sse_encode_StreamSink_liquid_sdk_event_Dco(
+      RustStreamSink<LiquidSdkEvent> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    throw UnimplementedError('Unreachable ()');
+    sse_encode_String(
+        self.setupAndSerialize(
+            codec: DcoCodec(decodeSuccessData: dco_decode_liquid_sdk_event, decodeErrorData: null)),
+        serializer);
   }
 
   @protected
-  RustStreamSink<LogEntry> sse_decode_StreamSink_log_entry_Dco(SseDeserializer deserializer) {
+  void sse_encode_StreamSink_log_entry_Dco(RustStreamSink<LogEntry> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    throw UnimplementedError('Unreachable ()');
+    sse_encode_String(
+        self.setupAndSerialize(
+            codec: DcoCodec(decodeSuccessData: dco_decode_log_entry, decodeErrorData: null)),
+        serializer);
   }
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer) {
+  void sse_encode_String(String self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
+    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
   }
 
   @protected
-  BackupRequest sse_decode_backup_request(SseDeserializer deserializer) {
+  void sse_encode_asset_balance(AssetBalance self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_backupPath = sse_decode_opt_String(deserializer);
-    return BackupRequest(backupPath: var_backupPath);
+    sse_encode_String(self.assetId, serializer);
+    sse_encode_u_64(self.balance, serializer);
+    sse_encode_opt_box_autoadd_asset_metadata(self.assetMetadata, serializer);
   }
 
   @protected
-  BindingEventListener sse_decode_binding_event_listener(SseDeserializer deserializer) {
+  void sse_encode_asset_metadata(AssetMetadata self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_stream = sse_decode_StreamSink_liquid_sdk_event_Dco(deserializer);
-    return BindingEventListener(stream: var_stream);
+    sse_encode_String(self.assetId, serializer);
+    sse_encode_String(self.ticker, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_u_8(self.precision, serializer);
   }
 
   @protected
-  BackupRequest sse_decode_box_autoadd_backup_request(SseDeserializer deserializer) {
+  void sse_encode_backup_request(BackupRequest self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_backup_request(deserializer));
+    sse_encode_opt_String(self.backupPath, serializer);
   }
 
   @protected
-  BindingEventListener sse_decode_box_autoadd_binding_event_listener(SseDeserializer deserializer) {
+  void sse_encode_binding_event_listener(BindingEventListener self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_binding_event_listener(deserializer));
+    sse_encode_StreamSink_liquid_sdk_event_Dco(self.stream, serializer);
   }
 
   @protected
-  ConnectRequest sse_decode_box_autoadd_connect_request(SseDeserializer deserializer) {
+  void sse_encode_bitcoin_address_data(BitcoinAddressData self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_connect_request(deserializer));
+    sse_encode_String(self.address, serializer);
+    sse_encode_network(self.network, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.amountSat, serializer);
+    sse_encode_opt_String(self.label, serializer);
+    sse_encode_opt_String(self.message, serializer);
   }
 
   @protected
-  LiquidSdkEvent sse_decode_box_autoadd_liquid_sdk_event(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_asset_metadata(AssetMetadata self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_liquid_sdk_event(deserializer));
+    sse_encode_asset_metadata(self, serializer);
   }
 
   @protected
-  Payment sse_decode_box_autoadd_payment(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_backup_request(BackupRequest self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_payment(deserializer));
+    sse_encode_backup_request(self, serializer);
   }
 
   @protected
-  PrepareReceiveRequest sse_decode_box_autoadd_prepare_receive_request(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_binding_event_listener(BindingEventListener self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_prepare_receive_request(deserializer));
+    sse_encode_binding_event_listener(self, serializer);
   }
 
   @protected
-  PrepareReceiveResponse sse_decode_box_autoadd_prepare_receive_response(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_bitcoin_address_data(BitcoinAddressData self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_prepare_receive_response(deserializer));
+    sse_encode_bitcoin_address_data(self, serializer);
   }
 
   @protected
-  PrepareSendRequest sse_decode_box_autoadd_prepare_send_request(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_prepare_send_request(deserializer));
+    sse_encode_bool(self, serializer);
   }
 
   @protected
-  PrepareSendResponse sse_decode_box_autoadd_prepare_send_response(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_connect_request(ConnectRequest self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_prepare_send_response(deserializer));
+    sse_encode_connect_request(self, serializer);
   }
 
   @protected
-  RestoreRequest sse_decode_box_autoadd_restore_request(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_export_payments_request(ExportPaymentsRequest self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_restore_request(deserializer));
+    sse_encode_export_payments_request(self, serializer);
   }
 
   @protected
-  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_u_64(deserializer));
+    sse_encode_f_64(self, serializer);
   }
 
   @protected
-  Config sse_decode_config(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_fee_breakdown(FeeBreakdown self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_boltzUrl = sse_decode_String(deserializer);
-    var var_electrumUrl = sse_decode_String(deserializer);
-    var var_workingDir = sse_decode_String(deserializer);
-    var var_network = sse_decode_network(deserializer);
-    var var_paymentTimeoutSec = sse_decode_u_64(deserializer);
-    var var_zeroConfMinFeeRate = sse_decode_f_32(deserializer);
-    var var_zeroConfMaxAmountSat = sse_decode_opt_box_autoadd_u_64(deserializer);
-    return Config(
-        boltzUrl: var_boltzUrl,
-        electrumUrl: var_electrumUrl,
-        workingDir: var_workingDir,
-        network: var_network,
-        paymentTimeoutSec: var_paymentTimeoutSec,
-        zeroConfMinFeeRate: var_zeroConfMinFeeRate,
-        zeroConfMaxAmountSat: var_zeroConfMaxAmountSat);
+    sse_encode_fee_breakdown(self, serializer);
   }
 
   @protected
-  ConnectRequest sse_decode_connect_request(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_get_payment_request(GetPaymentRequest self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_mnemonic = sse_decode_String(deserializer);
-    var var_config = sse_decode_config(deserializer);
-    return ConnectRequest(mnemonic: var_mnemonic, config: var_config);
+    sse_encode_get_payment_request(self, serializer);
   }
 
   @protected
-  double sse_decode_f_32(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_liquid_address_data(LiquidAddressData self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getFloat32();
+    sse_encode_liquid_address_data(self, serializer);
   }
 
   @protected
-  GetInfoResponse sse_decode_get_info_response(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_liquid_sdk_event(LiquidSdkEvent self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_balanceSat = sse_decode_u_64(deserializer);
-    var var_pendingSendSat = sse_decode_u_64(deserializer);
-    var var_pendingReceiveSat = sse_decode_u_64(deserializer);
-    var var_pubkey = sse_decode_String(deserializer);
-    return GetInfoResponse(
-        balanceSat: var_balanceSat,
-        pendingSendSat: var_pendingSendSat,
-        pendingReceiveSat: var_pendingReceiveSat,
-        pubkey: var_pubkey);
+    sse_encode_liquid_sdk_event(self, serializer);
   }
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_list_payments_request(ListPaymentsRequest self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
+    sse_encode_list_payments_request(self, serializer);
   }
 
   @protected
-  LiquidSdkError sse_decode_liquid_sdk_error(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_ln_invoice(LNInvoice self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var tag_ = sse_decode_i_32(deserializer);
-    switch (tag_) {
-      case 0:
-        return LiquidSdkError_AlreadyStarted();
-      case 1:
-        var var_err = sse_decode_String(deserializer);
-        return LiquidSdkError_Generic(err: var_err);
-      case 2:
-        return LiquidSdkError_NotStarted();
-      default:
-        throw UnimplementedError('');
-    }
+    sse_encode_ln_invoice(self, serializer);
   }
 
   @protected
-  LiquidSdkEvent sse_decode_liquid_sdk_event(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_ln_url_withdraw_request(LnUrlWithdrawRequest self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var tag_ = sse_decode_i_32(deserializer);
-    switch (tag_) {
-      case 0:
-        var var_details = sse_decode_box_autoadd_payment(deserializer);
-        return LiquidSdkEvent_PaymentFailed(details: var_details);
-      case 1:
-        var var_details = sse_decode_box_autoadd_payment(deserializer);
-        return LiquidSdkEvent_PaymentPending(details: var_details);
-      case 2:
-        var var_details = sse_decode_box_autoadd_payment(deserializer);
-        return LiquidSdkEvent_PaymentRefunded(details: var_details);
-      case 3:
-        var var_details = sse_decode_box_autoadd_payment(deserializer);
-        return LiquidSdkEvent_PaymentRefundPending(details: var_details);
-      case 4:
-        var var_details = sse_decode_box_autoadd_payment(deserializer);
-        return LiquidSdkEvent_PaymentSucceeded(details: var_details);
-      case 5:
-        var var_details = sse_decode_box_autoadd_payment(deserializer);
-        return LiquidSdkEvent_PaymentWaitingConfirmation(details: var_details);
-      case 6:
-        return LiquidSdkEvent_Synced();
-      default:
-        throw UnimplementedError('');
-    }
+    sse_encode_ln_url_withdraw_request(self, serializer);
   }
 
   @protected
-  List<Payment> sse_decode_list_payment(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_onchain_fee_rate(OnchainFeeRate self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <Payment>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_payment(deserializer));
-    }
-    return ans_;
+    sse_encode_onchain_fee_rate(self, serializer);
   }
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_pay_onchain_request(PayOnchainRequest self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    sse_encode_pay_onchain_request(self, serializer);
   }
 
   @protected
-  List<RouteHint> sse_decode_list_route_hint(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_payment(Payment self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <RouteHint>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_route_hint(deserializer));
-    }
-    return ans_;
+    sse_encode_payment(self, serializer);
   }
 
   @protected
-  List<RouteHintHop> sse_decode_list_route_hint_hop(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_payment_metadata(PaymentMetadata self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_payment_metadata(self, serializer);
+  }
 
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <RouteHintHop>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_route_hint_hop(deserializer));
-    }
-    return ans_;
+  @protected
+  void sse_encode_box_autoadd_prepare_ln_url_pay_request(
+      PrepareLnUrlPayRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_prepare_ln_url_pay_request(self, serializer);
   }
 
   @protected
-  LNInvoice sse_decode_ln_invoice(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_prepare_ln_url_pay_response(
+      PrepareLnUrlPayResponse self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_bolt11 = sse_decode_String(deserializer);
-    var var_network = sse_decode_network(deserializer);
-    var var_payeePubkey = sse_decode_String(deserializer);
-    var var_paymentHash = sse_decode_String(deserializer);
-    var var_description = sse_decode_opt_String(deserializer);
-    var var_descriptionHash = sse_decode_opt_String(deserializer);
-    var var_amountMsat = sse_decode_opt_box_autoadd_u_64(deserializer);
-    var var_timestamp = sse_decode_u_64(deserializer);
-    var var_expiry = sse_decode_u_64(deserializer);
-    var var_routingHints = sse_decode_list_route_hint(deserializer);
-    var var_paymentSecret = sse_decode_list_prim_u_8_strict(deserializer);
-    var var_minFinalCltvExpiryDelta = sse_decode_u_64(deserializer);
-    return LNInvoice(
-        bolt11: var_bolt11,
-        network: var_network,
-        payeePubkey: var_payeePubkey,
-        paymentHash: var_paymentHash,
-        description: var_description,
-        descriptionHash: var_descriptionHash,
-        amountMsat: var_amountMsat,
-        timestamp: var_timestamp,
-        expiry: var_expiry,
-        routingHints: var_routingHints,
-        paymentSecret: var_paymentSecret,
-        minFinalCltvExpiryDelta: var_minFinalCltvExpiryDelta);
+    sse_encode_prepare_ln_url_pay_response(self, serializer);
   }
 
   @protected
-  LogEntry sse_decode_log_entry(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_prepare_pay_onchain_request(
+      PreparePayOnchainRequest self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_line = sse_decode_String(deserializer);
-    var var_level = sse_decode_String(deserializer);
-    return LogEntry(line: var_line, level: var_level);
+    sse_encode_prepare_pay_onchain_request(self, serializer);
   }
 
   @protected
-  Network sse_decode_network(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_prepare_receive_onchain_request(
+      PrepareReceiveOnchainRequest self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return Network.values[inner];
+    sse_encode_prepare_receive_onchain_request(self, serializer);
   }
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_prepare_receive_onchain_response(
+      PrepareReceiveOnchainResponse self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
-    }
+    sse_encode_prepare_receive_onchain_response(self, serializer);
   }
 
   @protected
-  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_prepare_receive_request(PrepareReceiveRequest self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_u_64(deserializer));
-    } else {
-      return null;
-    }
+    sse_encode_prepare_receive_request(self, serializer);
   }
 
   @protected
-  Payment sse_decode_payment(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_prepare_receive_response(
+      PrepareReceiveResponse self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_txId = sse_decode_opt_String(deserializer);
-    var var_swapId = sse_decode_opt_String(deserializer);
-    var var_timestamp = sse_decode_u_32(deserializer);
-    var var_amountSat = sse_decode_u_64(deserializer);
-    var var_feesSat = sse_decode_u_64(deserializer);
-    var var_preimage = sse_decode_opt_String(deserializer);
-    var var_refundTxId = sse_decode_opt_String(deserializer);
-    var var_refundTxAmountSat = sse_decode_opt_box_autoadd_u_64(deserializer);
-    var var_paymentType = sse_decode_payment_type(deserializer);
-    var var_status = sse_decode_payment_state(deserializer);
-    return Payment(
-        txId: var_txId,
-        swapId: var_swapId,
-        timestamp: var_timestamp,
-        amountSat: var_amountSat,
-        feesSat: var_feesSat,
-        preimage: var_preimage,
-        refundTxId: var_refundTxId,
-        refundTxAmountSat: var_refundTxAmountSat,
-        paymentType: var_paymentType,
-        status: var_status);
+    sse_encode_prepare_receive_response(self, serializer);
   }
 
   @protected
-  PaymentError sse_decode_payment_error(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_prepare_refund_request(PrepareRefundRequest self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_prepare_refund_request(self, serializer);
+  }
 
-    var tag_ = sse_decode_i_32(deserializer);
-    switch (tag_) {
-      case 0:
-        return PaymentError_AlreadyClaimed();
-      case 1:
-        return PaymentError_AlreadyPaid();
-      case 2:
-        return PaymentError_PaymentInProgress();
-      case 3:
-        return PaymentError_AmountOutOfRange();
-      case 4:
-        var var_err = sse_decode_String(deserializer);
-        return PaymentError_Generic(err: var_err);
-      case 5:
-        return PaymentError_InvalidOrExpiredFees();
-      case 6:
-        return PaymentError_InsufficientFunds();
-      case 7:
-        var var_err = sse_decode_String(deserializer);
-        return PaymentError_InvalidInvoice(err: var_err);
-      case 8:
-        return PaymentError_InvalidPreimage();
-      case 9:
-        var var_err = sse_decode_String(deserializer);
-        return PaymentError_LwkError(err: var_err);
-      case 10:
-        return PaymentError_PairsNotFound();
-      case 11:
-        return PaymentError_PaymentTimeout();
-      case 12:
-        return PaymentError_PersistError();
-      case 13:
-        var var_err = sse_decode_String(deserializer);
-        return PaymentError_ReceiveError(err: var_err);
-      case 14:
-        var var_err = sse_decode_String(deserializer);
-        var var_refundTxId = sse_decode_String(deserializer);
-        return PaymentError_Refunded(err: var_err, refundTxId: var_refundTxId);
-      case 15:
-        return PaymentError_SelfTransferNotSupported();
-      case 16:
-        var var_err = sse_decode_String(deserializer);
-        return PaymentError_SendError(err: var_err);
-      case 17:
-        var var_err = sse_decode_String(deserializer);
-        return PaymentError_SignerError(err: var_err);
-      default:
-        throw UnimplementedError('');
-    }
+  @protected
+  void sse_encode_box_autoadd_prepare_send_onchain_request(
+      PrepareSendOnchainRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_prepare_send_onchain_request(self, serializer);
   }
 
   @protected
-  PaymentState sse_decode_payment_state(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_prepare_send_onchain_response(
+      PrepareSendOnchainResponse self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return PaymentState.values[inner];
+    sse_encode_prepare_send_onchain_response(self, serializer);
   }
 
   @protected
-  PaymentType sse_decode_payment_type(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_prepare_send_request(PrepareSendRequest self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return PaymentType.values[inner];
+    sse_encode_prepare_send_request(self, serializer);
   }
 
   @protected
-  PrepareReceiveRequest sse_decode_prepare_receive_request(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_prepare_send_response(PrepareSendResponse self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_payerAmountSat = sse_decode_u_64(deserializer);
-    return PrepareReceiveRequest(payerAmountSat: var_payerAmountSat);
+    sse_encode_prepare_send_response(self, serializer);
   }
 
   @protected
-  PrepareReceiveResponse sse_decode_prepare_receive_response(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_receive_liquid_request(ReceiveLiquidRequest self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_payerAmountSat = sse_decode_u_64(deserializer);
-    var var_feesSat = sse_decode_u_64(deserializer);
-    return PrepareReceiveResponse(payerAmountSat: var_payerAmountSat, feesSat: var_feesSat);
+    sse_encode_receive_liquid_request(self, serializer);
   }
 
   @protected
-  PrepareSendRequest sse_decode_prepare_send_request(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_refund_request(RefundRequest self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_invoice = sse_decode_String(deserializer);
-    return PrepareSendRequest(invoice: var_invoice);
+    sse_encode_refund_request(self, serializer);
   }
 
   @protected
-  PrepareSendResponse sse_decode_prepare_send_response(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_restore_request(RestoreRequest self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_invoice = sse_decode_String(deserializer);
-    var var_feesSat = sse_decode_u_64(deserializer);
-    return PrepareSendResponse(invoice: var_invoice, feesSat: var_feesSat);
+    sse_encode_restore_request(self, serializer);
   }
 
   @protected
-  ReceivePaymentResponse sse_decode_receive_payment_response(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_set_payment_metadata_request(
+      SetPaymentMetadataRequest self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_String(deserializer);
-    var var_invoice = sse_decode_String(deserializer);
-    return ReceivePaymentResponse(id: var_id, invoice: var_invoice);
+    sse_encode_set_payment_metadata_request(self, serializer);
   }
 
   @protected
-  RestoreRequest sse_decode_restore_request(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_success_action(SuccessAction self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_backupPath = sse_decode_opt_String(deserializer);
-    return RestoreRequest(backupPath: var_backupPath);
+    sse_encode_success_action(self, serializer);
   }
 
   @protected
-  RouteHint sse_decode_route_hint(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_success_action_processed(
+      SuccessActionProcessed self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_hops = sse_decode_list_route_hint_hop(deserializer);
-    return RouteHint(hops: var_hops);
+    sse_encode_success_action_processed(self, serializer);
   }
 
   @protected
-  RouteHintHop sse_decode_route_hint_hop(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_srcNodeId = sse_decode_String(deserializer);
-    var var_shortChannelId = sse_decode_u_64(deserializer);
-    var var_feesBaseMsat = sse_decode_u_32(deserializer);
-    var var_feesProportionalMillionths = sse_decode_u_32(deserializer);
-    var var_cltvExpiryDelta = sse_decode_u_64(deserializer);
-    var var_htlcMinimumMsat = sse_decode_opt_box_autoadd_u_64(deserializer);
-    var var_htlcMaximumMsat = sse_decode_opt_box_autoadd_u_64(deserializer);
-    return RouteHintHop(
-        srcNodeId: var_srcNodeId,
-        shortChannelId: var_shortChannelId,
-        feesBaseMsat: var_feesBaseMsat,
-        feesProportionalMillionths: var_feesProportionalMillionths,
-        cltvExpiryDelta: var_cltvExpiryDelta,
-        htlcMinimumMsat: var_htlcMinimumMsat,
-        htlcMaximumMsat: var_htlcMaximumMsat);
+    sse_encode_u_32(self, serializer);
   }
 
   @protected
-  SendPaymentResponse sse_decode_send_payment_response(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_payment = sse_decode_payment(deserializer);
-    return SendPaymentResponse(payment: var_payment);
+    sse_encode_u_64(self, serializer);
   }
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer) {
+  void sse_encode_config(Config self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint32();
+    sse_encode_String(self.boltzUrl, serializer);
+    sse_encode_list_electrum_server(self.electrumServers, serializer);
+    sse_encode_opt_String(self.electrumUrl, serializer);
+    sse_encode_String(self.bitcoinElectrumUrl, serializer);
+    sse_encode_opt_String(self.esploraUrl, serializer);
+    sse_encode_String(self.workingDir, serializer);
+    sse_encode_network(self.network, serializer);
+    sse_encode_u_64(self.paymentTimeoutSec, serializer);
+    sse_encode_u_64(self.syncFallbackIntervalSec, serializer);
+    sse_encode_f_32(self.zeroConfMinFeeRate, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.zeroConfMaxAmountSat, serializer);
+    sse_encode_opt_list_asset_metadata(self.assetMetadata, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.maxFeeSat, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.maxFeePercent, serializer);
+    sse_encode_opt_String(self.regtestPolicyAsset, serializer);
   }
 
   @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+  void sse_encode_connect_request(ConnectRequest self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
+    sse_encode_String(self.mnemonic, serializer);
+    sse_encode_config(self.config, serializer);
   }
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
+  void sse_encode_electrum_server(ElectrumServer self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
+    sse_encode_String(self.url, serializer);
+    sse_encode_bool(self.tls, serializer);
+    sse_encode_bool(self.validateDomain, serializer);
   }
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
+  void sse_encode_electrum_server_status(ElectrumServerStatus self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.url, serializer);
+    sse_encode_bool(self.inUse, serializer);
+    sse_encode_bool(self.healthy, serializer);
+    sse_encode_u_32(self.consecutiveFailures, serializer);
+    sse_encode_opt_String(self.lastError, serializer);
   }
 
   @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer) {
+  void sse_encode_export_payments_request(ExportPaymentsRequest self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
+    sse_encode_payment_export_format(self.format, serializer);
+    sse_encode_list_payments_request(self.filter, serializer);
+    sse_encode_String(self.path, serializer);
   }
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
+  void sse_encode_export_payments_response(ExportPaymentsResponse self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
+    sse_encode_u_32(self.paymentsCount, serializer);
   }
 
   @protected
-  int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
-      BindingLiquidSdk raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
-    return (raw as BindingLiquidSdkImpl).frbInternalCstEncode(move: true);
+  void sse_encode_f_32(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putFloat32(self);
   }
 
   @protected
-  int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
-      BindingLiquidSdk raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
-    return (raw as BindingLiquidSdkImpl).frbInternalCstEncode(move: false);
+  void sse_encode_f_64(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putFloat64(self);
   }
 
   @protected
-  int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
-      BindingLiquidSdk raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-// ignore: invalid_use_of_internal_member
-    return (raw as BindingLiquidSdkImpl).frbInternalCstEncode();
+  void sse_encode_fee_breakdown(FeeBreakdown self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_64(self.serviceFeePercentage, serializer);
+    sse_encode_u_64(self.serviceFeeSat, serializer);
+    sse_encode_u_64(self.swapperMinerFeesSat, serializer);
+    sse_encode_u_64(self.networkFeeSat, serializer);
+    sse_encode_u_64(self.totalFeesSat, serializer);
   }
 
   @protected
-  double cst_encode_f_32(double raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw;
+  void sse_encode_fee_priority(FeePriority self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  int cst_encode_i_32(int raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw;
+  void sse_encode_get_info_response(GetInfoResponse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.balanceSat, serializer);
+    sse_encode_u_64(self.pendingSendSat, serializer);
+    sse_encode_u_64(self.pendingReceiveSat, serializer);
+    sse_encode_String(self.pubkey, serializer);
+    sse_encode_list_asset_balance(self.assetBalances, serializer);
   }
 
   @protected
-  int cst_encode_network(Network raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return cst_encode_i_32(raw.index);
+  void sse_encode_get_payment_request(GetPaymentRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case GetPaymentRequest_TxId(txId: final txId):
+        sse_encode_i_32(0, serializer);
+        sse_encode_String(txId, serializer);
+      case GetPaymentRequest_SwapId(swapId: final swapId):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(swapId, serializer);
+      case GetPaymentRequest_Bolt11(bolt11: final bolt11):
+        sse_encode_i_32(2, serializer);
+        sse_encode_String(bolt11, serializer);
+      case GetPaymentRequest_PaymentHash(paymentHash: final paymentHash):
+        sse_encode_i_32(3, serializer);
+        sse_encode_String(paymentHash, serializer);
+      default:
+        throw UnimplementedError('');
+    }
   }
 
   @protected
-  int cst_encode_payment_state(PaymentState raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return cst_encode_i_32(raw.index);
+  void sse_encode_i_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putInt32(self);
   }
 
   @protected
-  int cst_encode_payment_type(PaymentType raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return cst_encode_i_32(raw.index);
+  void sse_encode_input_type(InputType self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case InputType_Bolt11(invoice: final invoice):
+        sse_encode_i_32(0, serializer);
+        sse_encode_box_autoadd_ln_invoice(invoice, serializer);
+      case InputType_LiquidAddress(address: final address):
+        sse_encode_i_32(1, serializer);
+        sse_encode_box_autoadd_liquid_address_data(address, serializer);
+      case InputType_BitcoinAddress(address: final address):
+        sse_encode_i_32(2, serializer);
+        sse_encode_box_autoadd_bitcoin_address_data(address, serializer);
+      case InputType_LnUrl(url: final url):
+        sse_encode_i_32(3, serializer);
+        sse_encode_String(url, serializer);
+      case InputType_LightningAddress(address: final address, url: final url):
+        sse_encode_i_32(4, serializer);
+        sse_encode_String(address, serializer);
+        sse_encode_String(url, serializer);
+      default:
+        throw UnimplementedError('');
+    }
   }
 
   @protected
-  int cst_encode_u_32(int raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw;
+  void sse_encode_lightning_limits(LightningLimits self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.minSat, serializer);
+    sse_encode_u_64(self.maxSat, serializer);
+    sse_encode_f_64(self.serviceFeePercentage, serializer);
+    sse_encode_u_64(self.minerFeesSat, serializer);
   }
 
   @protected
-  int cst_encode_u_8(int raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw;
+  void sse_encode_lightning_payment_limits_response(
+      LightningPaymentLimitsResponse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_lightning_limits(self.send, serializer);
+    sse_encode_lightning_limits(self.receive, serializer);
   }
 
   @protected
-  void cst_encode_unit(void raw) {
-    // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw;
+  void sse_encode_liquid_address_data(LiquidAddressData self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.address, serializer);
+    sse_encode_network(self.network, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.amountSat, serializer);
+    sse_encode_opt_String(self.assetId, serializer);
+    sse_encode_opt_String(self.label, serializer);
+    sse_encode_opt_String(self.message, serializer);
   }
 
   @protected
-  void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer) {
+  void sse_encode_liquid_sdk_error(LiquidSdkError self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.message, serializer);
+    switch (self) {
+      case LiquidSdkError_AlreadyStarted():
+        sse_encode_i_32(0, serializer);
+      case LiquidSdkError_Generic(err: final err):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(err, serializer);
+      case LiquidSdkError_NotStarted():
+        sse_encode_i_32(2, serializer);
+      default:
+        throw UnimplementedError('');
+    }
   }
 
   @protected
-  void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
-      BindingLiquidSdk self, SseSerializer serializer) {
+  void sse_encode_liquid_sdk_event(LiquidSdkEvent self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize((self as BindingLiquidSdkImpl).frbInternalSseEncode(move: true), serializer);
+    switch (self) {
+      case LiquidSdkEvent_PaymentFailed(details: final details):
+        sse_encode_i_32(0, serializer);
+        sse_encode_box_autoadd_payment(details, serializer);
+      case LiquidSdkEvent_PaymentPending(details: final details):
+        sse_encode_i_32(1, serializer);
+        sse_encode_box_autoadd_payment(details, serializer);
+      case LiquidSdkEvent_PaymentRefunded(details: final details):
+        sse_encode_i_32(2, serializer);
+        sse_encode_box_autoadd_payment(details, serializer);
+      case LiquidSdkEvent_PaymentRefundable(details: final details):
+        sse_encode_i_32(3, serializer);
+        sse_encode_box_autoadd_payment(details, serializer);
+      case LiquidSdkEvent_PaymentRefundPending(details: final details):
+        sse_encode_i_32(4, serializer);
+        sse_encode_box_autoadd_payment(details, serializer);
+      case LiquidSdkEvent_PaymentSucceeded(details: final details):
+        sse_encode_i_32(5, serializer);
+        sse_encode_box_autoadd_payment(details, serializer);
+      case LiquidSdkEvent_PaymentWaitingConfirmation(details: final details):
+        sse_encode_i_32(6, serializer);
+        sse_encode_box_autoadd_payment(details, serializer);
+      case LiquidSdkEvent_Synced():
+        sse_encode_i_32(7, serializer);
+      default:
+        throw UnimplementedError('');
+    }
   }
 
   @protected
-  void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
-      BindingLiquidSdk self, SseSerializer serializer) {
+  void sse_encode_list_asset_balance(List<AssetBalance> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize((self as BindingLiquidSdkImpl).frbInternalSseEncode(move: false), serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_asset_balance(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
-      BindingLiquidSdk self, SseSerializer serializer) {
+  void sse_encode_list_asset_metadata(List<AssetMetadata> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize((self as BindingLiquidSdkImpl).frbInternalSseEncode(move: null), serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_asset_metadata(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_StreamSink_liquid_sdk_event_Dco(
-      RustStreamSink<LiquidSdkEvent> self, SseSerializer serializer) {
+  void sse_encode_list_electrum_server(List<ElectrumServer> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(
-        self.setupAndSerialize(
-            codec: DcoCodec(decodeSuccessData: dco_decode_liquid_sdk_event, decodeErrorData: null)),
-        serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_electrum_server(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_StreamSink_log_entry_Dco(RustStreamSink<LogEntry> self, SseSerializer serializer) {
+  void sse_encode_list_electrum_server_status(List<ElectrumServerStatus> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(
-        self.setupAndSerialize(
-            codec: DcoCodec(decodeSuccessData: dco_decode_log_entry, decodeErrorData: null)),
-        serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_electrum_server_status(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
+  void sse_encode_list_payment(List<Payment> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_payment(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_backup_request(BackupRequest self, SseSerializer serializer) {
+  void sse_encode_list_payment_state(List<PaymentState> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_opt_String(self.backupPath, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_payment_state(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_binding_event_listener(BindingEventListener self, SseSerializer serializer) {
+  void sse_encode_list_payment_type(List<PaymentType> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_StreamSink_liquid_sdk_event_Dco(self.stream, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_payment_type(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_backup_request(BackupRequest self, SseSerializer serializer) {
+  void sse_encode_list_payments_request(ListPaymentsRequest self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_backup_request(self, serializer);
+    sse_encode_opt_list_payment_type(self.paymentTypes, serializer);
+    sse_encode_opt_list_payment_state(self.states, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.fromTimestamp, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.toTimestamp, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.offset, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.limit, serializer);
+    sse_encode_opt_box_autoadd_bool(self.sortDescending, serializer);
+    sse_encode_opt_String(self.metadataSearch, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_binding_event_listener(BindingEventListener self, SseSerializer serializer) {
+  void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_binding_event_listener(self, serializer);
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(self);
   }
 
   @protected
-  void sse_encode_box_autoadd_connect_request(ConnectRequest self, SseSerializer serializer) {
+  void sse_encode_list_refundable_swap(List<RefundableSwap> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_connect_request(self, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_refundable_swap(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_liquid_sdk_event(LiquidSdkEvent self, SseSerializer serializer) {
+  void sse_encode_list_route_hint(List<RouteHint> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_liquid_sdk_event(self, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_route_hint(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_payment(Payment self, SseSerializer serializer) {
+  void sse_encode_list_route_hint_hop(List<RouteHintHop> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_payment(self, serializer);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_route_hint_hop(item, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_prepare_receive_request(PrepareReceiveRequest self, SseSerializer serializer) {
+  void sse_encode_ln_invoice(LNInvoice self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_prepare_receive_request(self, serializer);
+    sse_encode_String(self.bolt11, serializer);
+    sse_encode_network(self.network, serializer);
+    sse_encode_String(self.payeePubkey, serializer);
+    sse_encode_String(self.paymentHash, serializer);
+    sse_encode_opt_String(self.description, serializer);
+    sse_encode_opt_String(self.descriptionHash, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.amountMsat, serializer);
+    sse_encode_u_64(self.timestamp, serializer);
+    sse_encode_u_64(self.expiry, serializer);
+    sse_encode_list_route_hint(self.routingHints, serializer);
+    sse_encode_list_prim_u_8_strict(self.paymentSecret, serializer);
+    sse_encode_u_64(self.minFinalCltvExpiryDelta, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_prepare_receive_response(
-      PrepareReceiveResponse self, SseSerializer serializer) {
+  void sse_encode_ln_url_pay_result(LnUrlPayResult self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_prepare_receive_response(self, serializer);
+    sse_encode_payment(self.payment, serializer);
+    sse_encode_opt_box_autoadd_success_action_processed(self.successAction, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_prepare_send_request(PrepareSendRequest self, SseSerializer serializer) {
+  void sse_encode_ln_url_withdraw_request(LnUrlWithdrawRequest self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_prepare_send_request(self, serializer);
+    sse_encode_String(self.lnurl, serializer);
+    sse_encode_u_64(self.amountSat, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_prepare_send_response(PrepareSendResponse self, SseSerializer serializer) {
+  void sse_encode_ln_url_withdraw_result(LnUrlWithdrawResult self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_prepare_send_response(self, serializer);
+    sse_encode_receive_payment_response(self.receivePaymentResponse, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_restore_request(RestoreRequest self, SseSerializer serializer) {
+  void sse_encode_log_entry(LogEntry self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_restore_request(self, serializer);
+    sse_encode_String(self.line, serializer);
+    sse_encode_String(self.level, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer) {
+  void sse_encode_network(Network self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_64(self, serializer);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_config(Config self, SseSerializer serializer) {
+  void sse_encode_onchain_fee_rate(OnchainFeeRate self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.boltzUrl, serializer);
-    sse_encode_String(self.electrumUrl, serializer);
-    sse_encode_String(self.workingDir, serializer);
-    sse_encode_network(self.network, serializer);
-    sse_encode_u_64(self.paymentTimeoutSec, serializer);
-    sse_encode_f_32(self.zeroConfMinFeeRate, serializer);
-    sse_encode_opt_box_autoadd_u_64(self.zeroConfMaxAmountSat, serializer);
+    switch (self) {
+      case OnchainFeeRate_SatPerVbyte(satPerVbyte: final satPerVbyte):
+        sse_encode_i_32(0, serializer);
+        sse_encode_f_64(satPerVbyte, serializer);
+      case OnchainFeeRate_Priority(priority: final priority):
+        sse_encode_i_32(1, serializer);
+        sse_encode_fee_priority(priority, serializer);
+      default:
+        throw UnimplementedError('');
+    }
   }
 
   @protected
-  void sse_encode_connect_request(ConnectRequest self, SseSerializer serializer) {
+  void sse_encode_opt_String(String? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.mnemonic, serializer);
-    sse_encode_config(self.config, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_String(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_f_32(double self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_asset_metadata(AssetMetadata? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putFloat32(self);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_asset_metadata(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_get_info_response(GetInfoResponse self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_64(self.balanceSat, serializer);
-    sse_encode_u_64(self.pendingSendSat, serializer);
-    sse_encode_u_64(self.pendingReceiveSat, serializer);
-    sse_encode_String(self.pubkey, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_bool(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_f_64(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_liquid_sdk_error(LiquidSdkError self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_fee_breakdown(FeeBreakdown? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    switch (self) {
-      case LiquidSdkError_AlreadyStarted():
-        sse_encode_i_32(0, serializer);
-      case LiquidSdkError_Generic(err: final err):
-        sse_encode_i_32(1, serializer);
-        sse_encode_String(err, serializer);
-      case LiquidSdkError_NotStarted():
-        sse_encode_i_32(2, serializer);
-      default:
-        throw UnimplementedError('');
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_fee_breakdown(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_liquid_sdk_event(LiquidSdkEvent self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_onchain_fee_rate(OnchainFeeRate? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    switch (self) {
-      case LiquidSdkEvent_PaymentFailed(details: final details):
-        sse_encode_i_32(0, serializer);
-        sse_encode_box_autoadd_payment(details, serializer);
-      case LiquidSdkEvent_PaymentPending(details: final details):
-        sse_encode_i_32(1, serializer);
-        sse_encode_box_autoadd_payment(details, serializer);
-      case LiquidSdkEvent_PaymentRefunded(details: final details):
-        sse_encode_i_32(2, serializer);
-        sse_encode_box_autoadd_payment(details, serializer);
-      case LiquidSdkEvent_PaymentRefundPending(details: final details):
-        sse_encode_i_32(3, serializer);
-        sse_encode_box_autoadd_payment(details, serializer);
-      case LiquidSdkEvent_PaymentSucceeded(details: final details):
-        sse_encode_i_32(4, serializer);
-        sse_encode_box_autoadd_payment(details, serializer);
-      case LiquidSdkEvent_PaymentWaitingConfirmation(details: final details):
-        sse_encode_i_32(5, serializer);
-        sse_encode_box_autoadd_payment(details, serializer);
-      case LiquidSdkEvent_Synced():
-        sse_encode_i_32(6, serializer);
-      default:
-        throw UnimplementedError('');
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_onchain_fee_rate(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_payment(List<Payment> self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_payment(Payment? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_payment(item, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_payment(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_payment_metadata(PaymentMetadata? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_payment_metadata(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_list_route_hint(List<RouteHint> self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_success_action(SuccessAction? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_route_hint(item, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_success_action(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_route_hint_hop(List<RouteHintHop> self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_success_action_processed(
+      SuccessActionProcessed? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_route_hint_hop(item, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_success_action_processed(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_ln_invoice(LNInvoice self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.bolt11, serializer);
-    sse_encode_network(self.network, serializer);
-    sse_encode_String(self.payeePubkey, serializer);
-    sse_encode_String(self.paymentHash, serializer);
-    sse_encode_opt_String(self.description, serializer);
-    sse_encode_opt_String(self.descriptionHash, serializer);
-    sse_encode_opt_box_autoadd_u_64(self.amountMsat, serializer);
-    sse_encode_u_64(self.timestamp, serializer);
-    sse_encode_u_64(self.expiry, serializer);
-    sse_encode_list_route_hint(self.routingHints, serializer);
-    sse_encode_list_prim_u_8_strict(self.paymentSecret, serializer);
-    sse_encode_u_64(self.minFinalCltvExpiryDelta, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_32(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_log_entry(LogEntry self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.line, serializer);
-    sse_encode_String(self.level, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_64(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_network(Network self, SseSerializer serializer) {
+  void sse_encode_opt_list_asset_metadata(List<AssetMetadata>? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_list_asset_metadata(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer) {
+  void sse_encode_opt_list_payment_state(List<PaymentState>? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     sse_encode_bool(self != null, serializer);
     if (self != null) {
-      sse_encode_String(self, serializer);
+      sse_encode_list_payment_state(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer) {
+  void sse_encode_opt_list_payment_type(List<PaymentType>? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     sse_encode_bool(self != null, serializer);
     if (self != null) {
-      sse_encode_box_autoadd_u_64(self, serializer);
+      sse_encode_list_payment_type(self, serializer);
     }
   }
 
+  @protected
+  void sse_encode_pay_onchain_request(PayOnchainRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.address, serializer);
+    sse_encode_prepare_pay_onchain_response(self.prepareRes, serializer);
+  }
+
   @protected
   void sse_encode_payment(Payment self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_opt_String(self.txId, serializer);
     sse_encode_opt_String(self.swapId, serializer);
+    sse_encode_payment_swap_type(self.swapType, serializer);
     sse_encode_u_32(self.timestamp, serializer);
     sse_encode_u_64(self.amountSat, serializer);
     sse_encode_u_64(self.feesSat, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.networkFeesSat, serializer);
+    sse_encode_opt_box_autoadd_fee_breakdown(self.feeBreakdown, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.feeRateSatPerVbyte, serializer);
     sse_encode_opt_String(self.preimage, serializer);
+    sse_encode_opt_String(self.bolt11, serializer);
+    sse_encode_opt_String(self.paymentHash, serializer);
+    sse_encode_opt_String(self.payeePubkey, serializer);
+    sse_encode_opt_String(self.lockupTxId, serializer);
+    sse_encode_opt_String(self.claimTxId, serializer);
     sse_encode_opt_String(self.refundTxId, serializer);
     sse_encode_opt_box_autoadd_u_64(self.refundTxAmountSat, serializer);
     sse_encode_payment_type(self.paymentType, serializer);
     sse_encode_payment_state(self.status, serializer);
+    sse_encode_opt_String(self.label, serializer);
+    sse_encode_opt_String(self.description, serializer);
+    sse_encode_opt_box_autoadd_payment_metadata(self.metadata, serializer);
+    sse_encode_opt_String(self.assetId, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.assetAmount, serializer);
   }
 
   @protected
@@ -2024,53 +5261,92 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_i_32(2, serializer);
       case PaymentError_AmountOutOfRange():
         sse_encode_i_32(3, serializer);
-      case PaymentError_Generic(err: final err):
+      case PaymentError_UnrecognizedInput(err: final err):
         sse_encode_i_32(4, serializer);
         sse_encode_String(err, serializer);
-      case PaymentError_InvalidOrExpiredFees():
+      case PaymentError_NetworkMismatch(err: final err):
         sse_encode_i_32(5, serializer);
-      case PaymentError_InsufficientFunds():
+        sse_encode_String(err, serializer);
+      case PaymentError_LnUrlError(err: final err):
         sse_encode_i_32(6, serializer);
-      case PaymentError_InvalidInvoice(err: final err):
+        sse_encode_String(err, serializer);
+      case PaymentError_LnUrlServiceUnreachable(err: final err):
         sse_encode_i_32(7, serializer);
         sse_encode_String(err, serializer);
-      case PaymentError_InvalidPreimage():
+      case PaymentError_LnUrlServiceError(err: final err):
         sse_encode_i_32(8, serializer);
-      case PaymentError_LwkError(err: final err):
+        sse_encode_String(err, serializer);
+      case PaymentError_Generic(err: final err):
         sse_encode_i_32(9, serializer);
         sse_encode_String(err, serializer);
-      case PaymentError_PairsNotFound():
+      case PaymentError_InvalidOrExpiredFees():
         sse_encode_i_32(10, serializer);
-      case PaymentError_PaymentTimeout():
+      case PaymentError_FeeLimitExceeded(feeSat: final feeSat, limitSat: final limitSat):
         sse_encode_i_32(11, serializer);
-      case PaymentError_PersistError():
+        sse_encode_u_64(feeSat, serializer);
+        sse_encode_u_64(limitSat, serializer);
+      case PaymentError_InsufficientFunds():
         sse_encode_i_32(12, serializer);
-      case PaymentError_ReceiveError(err: final err):
+      case PaymentError_InvalidInvoice(err: final err):
         sse_encode_i_32(13, serializer);
         sse_encode_String(err, serializer);
-      case PaymentError_Refunded(err: final err, refundTxId: final refundTxId):
+      case PaymentError_InvalidPreimage():
         sse_encode_i_32(14, serializer);
+      case PaymentError_LwkError(err: final err):
+        sse_encode_i_32(15, serializer);
+        sse_encode_String(err, serializer);
+      case PaymentError_PairsNotFound():
+        sse_encode_i_32(16, serializer);
+      case PaymentError_PaymentTimeout():
+        sse_encode_i_32(17, serializer);
+      case PaymentError_PersistError():
+        sse_encode_i_32(18, serializer);
+      case PaymentError_ReceiveError(err: final err):
+        sse_encode_i_32(19, serializer);
+        sse_encode_String(err, serializer);
+      case PaymentError_Refunded(err: final err, refundTxId: final refundTxId):
+        sse_encode_i_32(20, serializer);
         sse_encode_String(err, serializer);
         sse_encode_String(refundTxId, serializer);
       case PaymentError_SelfTransferNotSupported():
-        sse_encode_i_32(15, serializer);
+        sse_encode_i_32(21, serializer);
       case PaymentError_SendError(err: final err):
-        sse_encode_i_32(16, serializer);
+        sse_encode_i_32(22, serializer);
         sse_encode_String(err, serializer);
       case PaymentError_SignerError(err: final err):
-        sse_encode_i_32(17, serializer);
+        sse_encode_i_32(23, serializer);
         sse_encode_String(err, serializer);
       default:
         throw UnimplementedError('');
     }
   }
 
+  @protected
+  void sse_encode_payment_export_format(PaymentExportFormat self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_payment_metadata(PaymentMetadata self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.label, serializer);
+    sse_encode_opt_String(self.note, serializer);
+    sse_encode_opt_String(self.externalId, serializer);
+  }
+
   @protected
   void sse_encode_payment_state(PaymentState self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.index, serializer);
   }
 
+  @protected
+  void sse_encode_payment_swap_type(PaymentSwapType self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
   @protected
   void sse_encode_payment_type(PaymentType self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2078,22 +5354,128 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  void sse_encode_prepare_receive_request(PrepareReceiveRequest self, SseSerializer serializer) {
+  void sse_encode_prepare_ln_url_pay_request(PrepareLnUrlPayRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.lnurl, serializer);
+    sse_encode_u_64(self.amountSat, serializer);
+    sse_encode_opt_String(self.comment, serializer);
+  }
+
+  @protected
+  void sse_encode_prepare_ln_url_pay_response(PrepareLnUrlPayResponse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_prepare_send_response(self.prepareSendResponse, serializer);
+    sse_encode_opt_box_autoadd_success_action(self.successAction, serializer);
+  }
+
+  @protected
+  void sse_encode_prepare_pay_onchain_request(PreparePayOnchainRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.receiverAmountSat, serializer);
+    sse_encode_opt_box_autoadd_onchain_fee_rate(self.feeRate, serializer);
+  }
+
+  @protected
+  void sse_encode_prepare_pay_onchain_response(PreparePayOnchainResponse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.receiverAmountSat, serializer);
+    sse_encode_u_64(self.feesSat, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.feeRateSatPerVbyte, serializer);
+  }
+
+  @protected
+  void sse_encode_prepare_receive_onchain_request(
+      PrepareReceiveOnchainRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.payerAmountSat, serializer);
+    sse_encode_opt_box_autoadd_onchain_fee_rate(self.feeRate, serializer);
+  }
+
+  @protected
+  void sse_encode_prepare_receive_onchain_response(
+      PrepareReceiveOnchainResponse self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_u_64(self.payerAmountSat, serializer);
+    sse_encode_u_64(self.feesSat, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.feeRateSatPerVbyte, serializer);
+  }
+
+  @protected
+  void sse_encode_prepare_receive_request(PrepareReceiveRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_box_autoadd_u_64(self.payerAmountSat, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.receiverAmountSat, serializer);
+    sse_encode_opt_String(self.description, serializer);
+    sse_encode_opt_String(self.descriptionHash, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.invoiceExpirySecs, serializer);
+    sse_encode_opt_box_autoadd_payment_metadata(self.metadata, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.maxFeeSat, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.maxFeePercent, serializer);
+    sse_encode_opt_box_autoadd_onchain_fee_rate(self.feeRate, serializer);
   }
 
   @protected
   void sse_encode_prepare_receive_response(PrepareReceiveResponse self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_u_64(self.payerAmountSat, serializer);
+    sse_encode_u_64(self.receiverAmountSat, serializer);
+    sse_encode_u_64(self.feesSat, serializer);
+    sse_encode_opt_String(self.description, serializer);
+    sse_encode_opt_String(self.descriptionHash, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.invoiceExpirySecs, serializer);
+    sse_encode_opt_box_autoadd_payment_metadata(self.metadata, serializer);
+    sse_encode_fee_breakdown(self.feeBreakdown, serializer);
+    sse_encode_String(self.pairHash, serializer);
+    sse_encode_u_32(self.expiresAt, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.feeRateSatPerVbyte, serializer);
+  }
+
+  @protected
+  void sse_encode_prepare_refund_request(PrepareRefundRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.swapId, serializer);
+    sse_encode_String(self.refundAddress, serializer);
+    sse_encode_onchain_fee_rate(self.feeRate, serializer);
+  }
+
+  @protected
+  void sse_encode_prepare_refund_response(PrepareRefundResponse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.txVsize, serializer);
+    sse_encode_u_64(self.txFeeSat, serializer);
+    sse_encode_u_64(self.refundAmountSat, serializer);
+    sse_encode_f_64(self.feeRateSatPerVbyte, serializer);
+  }
+
+  @protected
+  void sse_encode_prepare_send_onchain_request(PrepareSendOnchainRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.destination, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.amountSat, serializer);
+    sse_encode_bool(self.sendAll, serializer);
+    sse_encode_opt_String(self.assetId, serializer);
+    sse_encode_opt_box_autoadd_onchain_fee_rate(self.feeRate, serializer);
+  }
+
+  @protected
+  void sse_encode_prepare_send_onchain_response(PrepareSendOnchainResponse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.address, serializer);
+    sse_encode_u_64(self.receiverAmountSat, serializer);
     sse_encode_u_64(self.feesSat, serializer);
+    sse_encode_bool(self.sendAll, serializer);
+    sse_encode_opt_String(self.assetId, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.feeRateSatPerVbyte, serializer);
   }
 
   @protected
   void sse_encode_prepare_send_request(PrepareSendRequest self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_String(self.invoice, serializer);
+    sse_encode_opt_box_autoadd_payment_metadata(self.metadata, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.maxFeeSat, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.maxFeePercent, serializer);
+    sse_encode_opt_box_autoadd_onchain_fee_rate(self.feeRate, serializer);
   }
 
   @protected
@@ -2101,6 +5483,35 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_String(self.invoice, serializer);
     sse_encode_u_64(self.feesSat, serializer);
+    sse_encode_opt_box_autoadd_payment_metadata(self.metadata, serializer);
+    sse_encode_fee_breakdown(self.feeBreakdown, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.maxFeeSat, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.maxFeePercent, serializer);
+    sse_encode_String(self.pairHash, serializer);
+    sse_encode_u_32(self.expiresAt, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.feeRateSatPerVbyte, serializer);
+  }
+
+  @protected
+  void sse_encode_receive_liquid_request(ReceiveLiquidRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_box_autoadd_u_64(self.amountSat, serializer);
+    sse_encode_opt_String(self.label, serializer);
+  }
+
+  @protected
+  void sse_encode_receive_liquid_response(ReceiveLiquidResponse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.address, serializer);
+    sse_encode_String(self.bip21, serializer);
+  }
+
+  @protected
+  void sse_encode_receive_onchain_response(ReceiveOnchainResponse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.address, serializer);
+    sse_encode_String(self.bip21, serializer);
   }
 
   @protected
@@ -2110,6 +5521,47 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.invoice, serializer);
   }
 
+  @protected
+  void sse_encode_recommended_fees(RecommendedFees self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_64(self.fastestFee, serializer);
+    sse_encode_f_64(self.halfHourFee, serializer);
+    sse_encode_f_64(self.hourFee, serializer);
+    sse_encode_f_64(self.economyFee, serializer);
+    sse_encode_f_64(self.minimumFee, serializer);
+  }
+
+  @protected
+  void sse_encode_recommended_fees_response(RecommendedFeesResponse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_recommended_fees(self.liquid, serializer);
+    sse_encode_recommended_fees(self.bitcoin, serializer);
+  }
+
+  @protected
+  void sse_encode_refund_request(RefundRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.swapId, serializer);
+    sse_encode_String(self.refundAddress, serializer);
+    sse_encode_f_64(self.feeRateSatPerVbyte, serializer);
+  }
+
+  @protected
+  void sse_encode_refund_response(RefundResponse self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.refundTxId, serializer);
+  }
+
+  @protected
+  void sse_encode_refundable_swap(RefundableSwap self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.swapId, serializer);
+    sse_encode_String(self.swapAddress, serializer);
+    sse_encode_u_32(self.timestamp, serializer);
+    sse_encode_u_64(self.amountSat, serializer);
+    sse_encode_payment_type(self.paymentType, serializer);
+  }
+
   @protected
   void sse_encode_restore_request(RestoreRequest self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2140,6 +5592,54 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_payment(self.payment, serializer);
   }
 
+  @protected
+  void sse_encode_set_payment_metadata_request(SetPaymentMetadataRequest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_payment_metadata(self.metadata, serializer);
+  }
+
+  @protected
+  void sse_encode_success_action(SuccessAction self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case SuccessAction_Message(message: final message):
+        sse_encode_i_32(0, serializer);
+        sse_encode_String(message, serializer);
+      case SuccessAction_Url(description: final description, url: final url):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(description, serializer);
+        sse_encode_String(url, serializer);
+      case SuccessAction_Aes(description: final description, ciphertext: final ciphertext, iv: final iv):
+        sse_encode_i_32(2, serializer);
+        sse_encode_String(description, serializer);
+        sse_encode_String(ciphertext, serializer);
+        sse_encode_String(iv, serializer);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  void sse_encode_success_action_processed(SuccessActionProcessed self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case SuccessActionProcessed_Message(message: final message):
+        sse_encode_i_32(0, serializer);
+        sse_encode_String(message, serializer);
+      case SuccessActionProcessed_Url(description: final description, url: final url):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(description, serializer);
+        sse_encode_String(url, serializer);
+      case SuccessActionProcessed_Aes(description: final description, plaintext: final plaintext):
+        sse_encode_i_32(2, serializer);
+        sse_encode_String(description, serializer);
+        sse_encode_String(plaintext, serializer);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
   @protected
   void sse_encode_u_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2208,29 +5708,96 @@ class BindingLiquidSdkImpl extends RustOpaque implements BindingLiquidSdk {
         that: this,
       );
 
+  Future<ExportPaymentsResponse> exportPayments({required ExportPaymentsRequest req}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkExportPayments(that: this, req: req);
+
+  Future<LightningPaymentLimitsResponse> fetchLightningLimits() =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkFetchLightningLimits(
+        that: this,
+      );
+
+  Future<RecommendedFeesResponse> fetchRecommendedFees() =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkFetchRecommendedFees(
+        that: this,
+      );
+
   Future<GetInfoResponse> getInfo() => RustLib.instance.api.crateBindingsBindingLiquidSdkGetInfo(
         that: this,
       );
 
-  Future<List<Payment>> listPayments() => RustLib.instance.api.crateBindingsBindingLiquidSdkListPayments(
+  Future<Payment?> getPayment({required GetPaymentRequest req}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkGetPayment(that: this, req: req);
+
+  Future<List<ElectrumServerStatus>> listElectrumServers() =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkListElectrumServers(
+        that: this,
+      );
+
+  Future<List<Payment>> listPayments({required ListPaymentsRequest req}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkListPayments(that: this, req: req);
+
+  Future<List<RefundableSwap>> listRefundables() =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkListRefundables(
         that: this,
       );
 
+  Future<LnUrlPayResult> lnurlPay({required PrepareLnUrlPayResponse req}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkLnurlPay(that: this, req: req);
+
+  Future<LnUrlWithdrawResult> lnurlWithdraw({required LnUrlWithdrawRequest req}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkLnurlWithdraw(that: this, req: req);
+
+  InputType parse({required String input}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkParse(that: this, input: input);
+
+  Future<SendPaymentResponse> payOnchain({required PayOnchainRequest req}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkPayOnchain(that: this, req: req);
+
+  Future<PrepareLnUrlPayResponse> prepareLnurlPay({required PrepareLnUrlPayRequest req}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkPrepareLnurlPay(that: this, req: req);
+
+  Future<PreparePayOnchainResponse> preparePayOnchain({required PreparePayOnchainRequest req}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkPreparePayOnchain(that: this, req: req);
+
+  Future<PrepareReceiveOnchainResponse> prepareReceiveOnchain({required PrepareReceiveOnchainRequest req}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkPrepareReceiveOnchain(that: this, req: req);
+
   Future<PrepareReceiveResponse> prepareReceivePayment({required PrepareReceiveRequest req}) =>
       RustLib.instance.api.crateBindingsBindingLiquidSdkPrepareReceivePayment(that: this, req: req);
 
+  Future<PrepareRefundResponse> prepareRefund({required PrepareRefundRequest req}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkPrepareRefund(that: this, req: req);
+
+  Future<PrepareSendOnchainResponse> prepareSendOnchain({required PrepareSendOnchainRequest req}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkPrepareSendOnchain(that: this, req: req);
+
   Future<PrepareSendResponse> prepareSendPayment({required PrepareSendRequest req}) =>
       RustLib.instance.api.crateBindingsBindingLiquidSdkPrepareSendPayment(that: this, req: req);
 
+  Future<ReceiveLiquidResponse> receiveLiquid({required ReceiveLiquidRequest req}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkReceiveLiquid(that: this, req: req);
+
+  Future<ReceiveOnchainResponse> receiveOnchain({required PrepareReceiveOnchainResponse req}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkReceiveOnchain(that: this, req: req);
+
   Future<ReceivePaymentResponse> receivePayment({required PrepareReceiveResponse req}) =>
       RustLib.instance.api.crateBindingsBindingLiquidSdkReceivePayment(that: this, req: req);
 
+  Future<RefundResponse> refund({required RefundRequest req}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkRefund(that: this, req: req);
+
   void restore({required RestoreRequest req}) =>
       RustLib.instance.api.crateBindingsBindingLiquidSdkRestore(that: this, req: req);
 
+  Future<SendPaymentResponse> sendOnchain({required PrepareSendOnchainResponse req}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkSendOnchain(that: this, req: req);
+
   Future<SendPaymentResponse> sendPayment({required PrepareSendResponse req}) =>
       RustLib.instance.api.crateBindingsBindingLiquidSdkSendPayment(that: this, req: req);
 
+  Future<void> setPaymentMetadata({required SetPaymentMetadataRequest req}) =>
+      RustLib.instance.api.crateBindingsBindingLiquidSdkSetPaymentMetadata(that: this, req: req);
+
   Future<void> sync() => RustLib.instance.api.crateBindingsBindingLiquidSdkSync(
         that: this,
       );
@@ -49,42 +49,138 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   String dco_decode_String(dynamic raw);
 
+  @protected
+  AssetBalance dco_decode_asset_balance(dynamic raw);
+
+  @protected
+  AssetMetadata dco_decode_asset_metadata(dynamic raw);
+
   @protected
   BackupRequest dco_decode_backup_request(dynamic raw);
 
   @protected
   BindingEventListener dco_decode_binding_event_listener(dynamic raw);
 
+  @protected
+  BitcoinAddressData dco_decode_bitcoin_address_data(dynamic raw);
+
+  @protected
+  bool dco_decode_bool(dynamic raw);
+
+  @protected
+  AssetMetadata dco_decode_box_autoadd_asset_metadata(dynamic raw);
+
   @protected
   BackupRequest dco_decode_box_autoadd_backup_request(dynamic raw);
 
   @protected
   BindingEventListener dco_decode_box_autoadd_binding_event_listener(dynamic raw);
 
+  @protected
+  BitcoinAddressData dco_decode_box_autoadd_bitcoin_address_data(dynamic raw);
+
+  @protected
+  bool dco_decode_box_autoadd_bool(dynamic raw);
+
   @protected
   ConnectRequest dco_decode_box_autoadd_connect_request(dynamic raw);
 
+  @protected
+  ExportPaymentsRequest dco_decode_box_autoadd_export_payments_request(dynamic raw);
+
+  @protected
+  double dco_decode_box_autoadd_f_64(dynamic raw);
+
+  @protected
+  FeeBreakdown dco_decode_box_autoadd_fee_breakdown(dynamic raw);
+
+  @protected
+  GetPaymentRequest dco_decode_box_autoadd_get_payment_request(dynamic raw);
+
+  @protected
+  LiquidAddressData dco_decode_box_autoadd_liquid_address_data(dynamic raw);
+
   @protected
   LiquidSdkEvent dco_decode_box_autoadd_liquid_sdk_event(dynamic raw);
 
+  @protected
+  ListPaymentsRequest dco_decode_box_autoadd_list_payments_request(dynamic raw);
+
+  @protected
+  LNInvoice dco_decode_box_autoadd_ln_invoice(dynamic raw);
+
+  @protected
+  LnUrlWithdrawRequest dco_decode_box_autoadd_ln_url_withdraw_request(dynamic raw);
+
+  @protected
+  OnchainFeeRate dco_decode_box_autoadd_onchain_fee_rate(dynamic raw);
+
+  @protected
+  PayOnchainRequest dco_decode_box_autoadd_pay_onchain_request(dynamic raw);
+
   @protected
   Payment dco_decode_box_autoadd_payment(dynamic raw);
 
+  @protected
+  PaymentMetadata dco_decode_box_autoadd_payment_metadata(dynamic raw);
+
+  @protected
+  PrepareLnUrlPayRequest dco_decode_box_autoadd_prepare_ln_url_pay_request(dynamic raw);
+
+  @protected
+  PrepareLnUrlPayResponse dco_decode_box_autoadd_prepare_ln_url_pay_response(dynamic raw);
+
+  @protected
+  PreparePayOnchainRequest dco_decode_box_autoadd_prepare_pay_onchain_request(dynamic raw);
+
+  @protected
+  PrepareReceiveOnchainRequest dco_decode_box_autoadd_prepare_receive_onchain_request(dynamic raw);
+
+  @protected
+  PrepareReceiveOnchainResponse dco_decode_box_autoadd_prepare_receive_onchain_response(dynamic raw);
+
   @protected
   PrepareReceiveRequest dco_decode_box_autoadd_prepare_receive_request(dynamic raw);
 
   @protected
   PrepareReceiveResponse dco_decode_box_autoadd_prepare_receive_response(dynamic raw);
 
+  @protected
+  PrepareRefundRequest dco_decode_box_autoadd_prepare_refund_request(dynamic raw);
+
+  @protected
+  PrepareSendOnchainRequest dco_decode_box_autoadd_prepare_send_onchain_request(dynamic raw);
+
+  @protected
+  PrepareSendOnchainResponse dco_decode_box_autoadd_prepare_send_onchain_response(dynamic raw);
+
   @protected
   PrepareSendRequest dco_decode_box_autoadd_prepare_send_request(dynamic raw);
 
   @protected
   PrepareSendResponse dco_decode_box_autoadd_prepare_send_response(dynamic raw);
 
+  @protected
+  ReceiveLiquidRequest dco_decode_box_autoadd_receive_liquid_request(dynamic raw);
+
+  @protected
+  RefundRequest dco_decode_box_autoadd_refund_request(dynamic raw);
+
   @protected
   RestoreRequest dco_decode_box_autoadd_restore_request(dynamic raw);
 
+  @protected
+  SetPaymentMetadataRequest dco_decode_box_autoadd_set_payment_metadata_request(dynamic raw);
+
+  @protected
+  SuccessAction dco_decode_box_autoadd_success_action(dynamic raw);
+
+  @protected
+  SuccessActionProcessed dco_decode_box_autoadd_success_action_processed(dynamic raw);
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw);
+
   @protected
   BigInt dco_decode_box_autoadd_u_64(dynamic raw);
 
@@ -94,27 +190,87 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ConnectRequest dco_decode_connect_request(dynamic raw);
 
+  @protected
+  ElectrumServer dco_decode_electrum_server(dynamic raw);
+
+  @protected
+  ElectrumServerStatus dco_decode_electrum_server_status(dynamic raw);
+
+  @protected
+  ExportPaymentsRequest dco_decode_export_payments_request(dynamic raw);
+
+  @protected
+  ExportPaymentsResponse dco_decode_export_payments_response(dynamic raw);
+
   @protected
   double dco_decode_f_32(dynamic raw);
 
+  @protected
+  double dco_decode_f_64(dynamic raw);
+
+  @protected
+  FeeBreakdown dco_decode_fee_breakdown(dynamic raw);
+
+  @protected
+  FeePriority dco_decode_fee_priority(dynamic raw);
+
   @protected
   GetInfoResponse dco_decode_get_info_response(dynamic raw);
 
+  @protected
+  GetPaymentRequest dco_decode_get_payment_request(dynamic raw);
+
   @protected
   int dco_decode_i_32(dynamic raw);
 
+  @protected
+  InputType dco_decode_input_type(dynamic raw);
+
+  @protected
+  LightningLimits dco_decode_lightning_limits(dynamic raw);
+
+  @protected
+  LightningPaymentLimitsResponse dco_decode_lightning_payment_limits_response(dynamic raw);
+
+  @protected
+  LiquidAddressData dco_decode_liquid_address_data(dynamic raw);
+
   @protected
   LiquidSdkError dco_decode_liquid_sdk_error(dynamic raw);
 
   @protected
   LiquidSdkEvent dco_decode_liquid_sdk_event(dynamic raw);
 
+  @protected
+  List<AssetBalance> dco_decode_list_asset_balance(dynamic raw);
+
+  @protected
+  List<AssetMetadata> dco_decode_list_asset_metadata(dynamic raw);
+
+  @protected
+  List<ElectrumServer> dco_decode_list_electrum_server(dynamic raw);
+
+  @protected
+  List<ElectrumServerStatus> dco_decode_list_electrum_server_status(dynamic raw);
+
   @protected
   List<Payment> dco_decode_list_payment(dynamic raw);
 
+  @protected
+  List<PaymentState> dco_decode_list_payment_state(dynamic raw);
+
+  @protected
+  List<PaymentType> dco_decode_list_payment_type(dynamic raw);
+
+  @protected
+  ListPaymentsRequest dco_decode_list_payments_request(dynamic raw);
+
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
+  @protected
+  List<RefundableSwap> dco_decode_list_refundable_swap(dynamic raw);
+
   @protected
   List<RouteHint> dco_decode_list_route_hint(dynamic raw);
 
@@ -124,45 +280,162 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   LNInvoice dco_decode_ln_invoice(dynamic raw);
 
+  @protected
+  LnUrlPayResult dco_decode_ln_url_pay_result(dynamic raw);
+
+  @protected
+  LnUrlWithdrawRequest dco_decode_ln_url_withdraw_request(dynamic raw);
+
+  @protected
+  LnUrlWithdrawResult dco_decode_ln_url_withdraw_result(dynamic raw);
+
   @protected
   LogEntry dco_decode_log_entry(dynamic raw);
 
   @protected
   Network dco_decode_network(dynamic raw);
 
+  @protected
+  OnchainFeeRate dco_decode_onchain_fee_rate(dynamic raw);
+
   @protected
   String? dco_decode_opt_String(dynamic raw);
 
+  @protected
+  AssetMetadata? dco_decode_opt_box_autoadd_asset_metadata(dynamic raw);
+
+  @protected
+  bool? dco_decode_opt_box_autoadd_bool(dynamic raw);
+
+  @protected
+  double? dco_decode_opt_box_autoadd_f_64(dynamic raw);
+
+  @protected
+  FeeBreakdown? dco_decode_opt_box_autoadd_fee_breakdown(dynamic raw);
+
+  @protected
+  OnchainFeeRate? dco_decode_opt_box_autoadd_onchain_fee_rate(dynamic raw);
+
+  @protected
+  Payment? dco_decode_opt_box_autoadd_payment(dynamic raw);
+
+  @protected
+  PaymentMetadata? dco_decode_opt_box_autoadd_payment_metadata(dynamic raw);
+
+  @protected
+  SuccessAction? dco_decode_opt_box_autoadd_success_action(dynamic raw);
+
+  @protected
+  SuccessActionProcessed? dco_decode_opt_box_autoadd_success_action_processed(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
   @protected
   BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
 
+  @protected
+  List<AssetMetadata>? dco_decode_opt_list_asset_metadata(dynamic raw);
+
+  @protected
+  List<PaymentState>? dco_decode_opt_list_payment_state(dynamic raw);
+
+  @protected
+  List<PaymentType>? dco_decode_opt_list_payment_type(dynamic raw);
+
+  @protected
+  PayOnchainRequest dco_decode_pay_onchain_request(dynamic raw);
+
   @protected
   Payment dco_decode_payment(dynamic raw);
 
   @protected
   PaymentError dco_decode_payment_error(dynamic raw);
 
+  @protected
+  PaymentExportFormat dco_decode_payment_export_format(dynamic raw);
+
+  @protected
+  PaymentMetadata dco_decode_payment_metadata(dynamic raw);
+
   @protected
   PaymentState dco_decode_payment_state(dynamic raw);
 
+  @protected
+  PaymentSwapType dco_decode_payment_swap_type(dynamic raw);
+
   @protected
   PaymentType dco_decode_payment_type(dynamic raw);
 
+  @protected
+  PrepareLnUrlPayRequest dco_decode_prepare_ln_url_pay_request(dynamic raw);
+
+  @protected
+  PrepareLnUrlPayResponse dco_decode_prepare_ln_url_pay_response(dynamic raw);
+
+  @protected
+  PreparePayOnchainRequest dco_decode_prepare_pay_onchain_request(dynamic raw);
+
+  @protected
+  PreparePayOnchainResponse dco_decode_prepare_pay_onchain_response(dynamic raw);
+
+  @protected
+  PrepareReceiveOnchainRequest dco_decode_prepare_receive_onchain_request(dynamic raw);
+
+  @protected
+  PrepareReceiveOnchainResponse dco_decode_prepare_receive_onchain_response(dynamic raw);
+
   @protected
   PrepareReceiveRequest dco_decode_prepare_receive_request(dynamic raw);
 
   @protected
   PrepareReceiveResponse dco_decode_prepare_receive_response(dynamic raw);
 
+  @protected
+  PrepareRefundRequest dco_decode_prepare_refund_request(dynamic raw);
+
+  @protected
+  PrepareRefundResponse dco_decode_prepare_refund_response(dynamic raw);
+
+  @protected
+  PrepareSendOnchainRequest dco_decode_prepare_send_onchain_request(dynamic raw);
+
+  @protected
+  PrepareSendOnchainResponse dco_decode_prepare_send_onchain_response(dynamic raw);
+
   @protected
   PrepareSendRequest dco_decode_prepare_send_request(dynamic raw);
 
   @protected
   PrepareSendResponse dco_decode_prepare_send_response(dynamic raw);
 
+  @protected
+  ReceiveLiquidRequest dco_decode_receive_liquid_request(dynamic raw);
+
+  @protected
+  ReceiveLiquidResponse dco_decode_receive_liquid_response(dynamic raw);
+
+  @protected
+  ReceiveOnchainResponse dco_decode_receive_onchain_response(dynamic raw);
+
   @protected
   ReceivePaymentResponse dco_decode_receive_payment_response(dynamic raw);
 
+  @protected
+  RecommendedFees dco_decode_recommended_fees(dynamic raw);
+
+  @protected
+  RecommendedFeesResponse dco_decode_recommended_fees_response(dynamic raw);
+
+  @protected
+  RefundRequest dco_decode_refund_request(dynamic raw);
+
+  @protected
+  RefundResponse dco_decode_refund_response(dynamic raw);
+
+  @protected
+  RefundableSwap dco_decode_refundable_swap(dynamic raw);
+
   @protected
   RestoreRequest dco_decode_restore_request(dynamic raw);
 
@@ -175,6 +448,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   SendPaymentResponse dco_decode_send_payment_response(dynamic raw);
 
+  @protected
+  SetPaymentMetadataRequest dco_decode_set_payment_metadata_request(dynamic raw);
+
+  @protected
+  SuccessAction dco_decode_success_action(dynamic raw);
+
+  @protected
+  SuccessActionProcessed dco_decode_success_action_processed(dynamic raw);
+
   @protected
   int dco_decode_u_32(dynamic raw);
 
@@ -216,12 +498,24 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   String sse_decode_String(SseDeserializer deserializer);
 
+  @protected
+  AssetBalance sse_decode_asset_balance(SseDeserializer deserializer);
+
+  @protected
+  AssetMetadata sse_decode_asset_metadata(SseDeserializer deserializer);
+
   @protected
   BackupRequest sse_decode_backup_request(SseDeserializer deserializer);
 
   @protected
   BindingEventListener sse_decode_binding_event_listener(SseDeserializer deserializer);
 
+  @protected
+  BitcoinAddressData sse_decode_bitcoin_address_data(SseDeserializer deserializer);
+
+  @protected
+  AssetMetadata sse_decode_box_autoadd_asset_metadata(SseDeserializer deserializer);
+
   @protected
   BackupRequest sse_decode_box_autoadd_backup_request(SseDeserializer deserializer);
 
@@ -229,139 +523,409 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   BindingEventListener sse_decode_box_autoadd_binding_event_listener(SseDeserializer deserializer);
 
   @protected
-  ConnectRequest sse_decode_box_autoadd_connect_request(SseDeserializer deserializer);
+  BitcoinAddressData sse_decode_box_autoadd_bitcoin_address_data(SseDeserializer deserializer);
 
   @protected
-  LiquidSdkEvent sse_decode_box_autoadd_liquid_sdk_event(SseDeserializer deserializer);
+  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer);
 
   @protected
-  Payment sse_decode_box_autoadd_payment(SseDeserializer deserializer);
+  ConnectRequest sse_decode_box_autoadd_connect_request(SseDeserializer deserializer);
 
   @protected
-  PrepareReceiveRequest sse_decode_box_autoadd_prepare_receive_request(SseDeserializer deserializer);
+  ExportPaymentsRequest sse_decode_box_autoadd_export_payments_request(SseDeserializer deserializer);
 
   @protected
-  PrepareReceiveResponse sse_decode_box_autoadd_prepare_receive_response(SseDeserializer deserializer);
+  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer);
 
   @protected
-  PrepareSendRequest sse_decode_box_autoadd_prepare_send_request(SseDeserializer deserializer);
+  FeeBreakdown sse_decode_box_autoadd_fee_breakdown(SseDeserializer deserializer);
 
   @protected
-  PrepareSendResponse sse_decode_box_autoadd_prepare_send_response(SseDeserializer deserializer);
+  GetPaymentRequest sse_decode_box_autoadd_get_payment_request(SseDeserializer deserializer);
 
   @protected
-  RestoreRequest sse_decode_box_autoadd_restore_request(SseDeserializer deserializer);
+  LiquidAddressData sse_decode_box_autoadd_liquid_address_data(SseDeserializer deserializer);
 
   @protected
-  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+  LiquidSdkEvent sse_decode_box_autoadd_liquid_sdk_event(SseDeserializer deserializer);
 
   @protected
-  Config sse_decode_config(SseDeserializer deserializer);
+  ListPaymentsRequest sse_decode_box_autoadd_list_payments_request(SseDeserializer deserializer);
 
   @protected
-  ConnectRequest sse_decode_connect_request(SseDeserializer deserializer);
+  LNInvoice sse_decode_box_autoadd_ln_invoice(SseDeserializer deserializer);
 
   @protected
-  double sse_decode_f_32(SseDeserializer deserializer);
+  LnUrlWithdrawRequest sse_decode_box_autoadd_ln_url_withdraw_request(SseDeserializer deserializer);
 
   @protected
-  GetInfoResponse sse_decode_get_info_response(SseDeserializer deserializer);
+  OnchainFeeRate sse_decode_box_autoadd_onchain_fee_rate(SseDeserializer deserializer);
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+  PayOnchainRequest sse_decode_box_autoadd_pay_onchain_request(SseDeserializer deserializer);
 
   @protected
-  LiquidSdkError sse_decode_liquid_sdk_error(SseDeserializer deserializer);
+  Payment sse_decode_box_autoadd_payment(SseDeserializer deserializer);
 
   @protected
-  LiquidSdkEvent sse_decode_liquid_sdk_event(SseDeserializer deserializer);
+  PaymentMetadata sse_decode_box_autoadd_payment_metadata(SseDeserializer deserializer);
 
   @protected
-  List<Payment> sse_decode_list_payment(SseDeserializer deserializer);
+  PrepareLnUrlPayRequest sse_decode_box_autoadd_prepare_ln_url_pay_request(SseDeserializer deserializer);
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+  PrepareLnUrlPayResponse sse_decode_box_autoadd_prepare_ln_url_pay_response(SseDeserializer deserializer);
 
   @protected
-  List<RouteHint> sse_decode_list_route_hint(SseDeserializer deserializer);
+  PreparePayOnchainRequest sse_decode_box_autoadd_prepare_pay_onchain_request(SseDeserializer deserializer);
 
   @protected
-  List<RouteHintHop> sse_decode_list_route_hint_hop(SseDeserializer deserializer);
+  PrepareReceiveOnchainRequest sse_decode_box_autoadd_prepare_receive_onchain_request(
+      SseDeserializer deserializer);
 
   @protected
-  LNInvoice sse_decode_ln_invoice(SseDeserializer deserializer);
+  PrepareReceiveOnchainResponse sse_decode_box_autoadd_prepare_receive_onchain_response(
+      SseDeserializer deserializer);
 
   @protected
-  LogEntry sse_decode_log_entry(SseDeserializer deserializer);
+  PrepareReceiveRequest sse_decode_box_autoadd_prepare_receive_request(SseDeserializer deserializer);
 
   @protected
-  Network sse_decode_network(SseDeserializer deserializer);
+  PrepareReceiveResponse sse_decode_box_autoadd_prepare_receive_response(SseDeserializer deserializer);
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer);
+  PrepareRefundRequest sse_decode_box_autoadd_prepare_refund_request(SseDeserializer deserializer);
 
   @protected
-  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+  PrepareSendOnchainRequest sse_decode_box_autoadd_prepare_send_onchain_request(SseDeserializer deserializer);
 
   @protected
-  Payment sse_decode_payment(SseDeserializer deserializer);
+  PrepareSendOnchainResponse sse_decode_box_autoadd_prepare_send_onchain_response(
+      SseDeserializer deserializer);
 
   @protected
-  PaymentError sse_decode_payment_error(SseDeserializer deserializer);
+  PrepareSendRequest sse_decode_box_autoadd_prepare_send_request(SseDeserializer deserializer);
 
   @protected
-  PaymentState sse_decode_payment_state(SseDeserializer deserializer);
+  PrepareSendResponse sse_decode_box_autoadd_prepare_send_response(SseDeserializer deserializer);
 
   @protected
-  PaymentType sse_decode_payment_type(SseDeserializer deserializer);
+  ReceiveLiquidRequest sse_decode_box_autoadd_receive_liquid_request(SseDeserializer deserializer);
 
   @protected
-  PrepareReceiveRequest sse_decode_prepare_receive_request(SseDeserializer deserializer);
+  RefundRequest sse_decode_box_autoadd_refund_request(SseDeserializer deserializer);
 
   @protected
-  PrepareReceiveResponse sse_decode_prepare_receive_response(SseDeserializer deserializer);
+  RestoreRequest sse_decode_box_autoadd_restore_request(SseDeserializer deserializer);
 
   @protected
-  PrepareSendRequest sse_decode_prepare_send_request(SseDeserializer deserializer);
+  SetPaymentMetadataRequest sse_decode_box_autoadd_set_payment_metadata_request(SseDeserializer deserializer);
 
   @protected
-  PrepareSendResponse sse_decode_prepare_send_response(SseDeserializer deserializer);
+  SuccessAction sse_decode_box_autoadd_success_action(SseDeserializer deserializer);
 
   @protected
-  ReceivePaymentResponse sse_decode_receive_payment_response(SseDeserializer deserializer);
+  SuccessActionProcessed sse_decode_box_autoadd_success_action_processed(SseDeserializer deserializer);
 
   @protected
-  RestoreRequest sse_decode_restore_request(SseDeserializer deserializer);
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
 
   @protected
-  RouteHint sse_decode_route_hint(SseDeserializer deserializer);
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
 
   @protected
-  RouteHintHop sse_decode_route_hint_hop(SseDeserializer deserializer);
+  Config sse_decode_config(SseDeserializer deserializer);
 
   @protected
-  SendPaymentResponse sse_decode_send_payment_response(SseDeserializer deserializer);
+  ConnectRequest sse_decode_connect_request(SseDeserializer deserializer);
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer);
+  ElectrumServer sse_decode_electrum_server(SseDeserializer deserializer);
 
   @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer);
+  ElectrumServerStatus sse_decode_electrum_server_status(SseDeserializer deserializer);
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+  ExportPaymentsRequest sse_decode_export_payments_request(SseDeserializer deserializer);
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer);
+  ExportPaymentsResponse sse_decode_export_payments_response(SseDeserializer deserializer);
 
   @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer);
+  double sse_decode_f_32(SseDeserializer deserializer);
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+  double sse_decode_f_64(SseDeserializer deserializer);
 
   @protected
-  ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_AnyhowException(AnyhowException raw) {
+  FeeBreakdown sse_decode_fee_breakdown(SseDeserializer deserializer);
+
+  @protected
+  FeePriority sse_decode_fee_priority(SseDeserializer deserializer);
+
+  @protected
+  GetInfoResponse sse_decode_get_info_response(SseDeserializer deserializer);
+
+  @protected
+  GetPaymentRequest sse_decode_get_payment_request(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer);
+
+  @protected
+  InputType sse_decode_input_type(SseDeserializer deserializer);
+
+  @protected
+  LightningLimits sse_decode_lightning_limits(SseDeserializer deserializer);
+
+  @protected
+  LightningPaymentLimitsResponse sse_decode_lightning_payment_limits_response(SseDeserializer deserializer);
+
+  @protected
+  LiquidAddressData sse_decode_liquid_address_data(SseDeserializer deserializer);
+
+  @protected
+  LiquidSdkError sse_decode_liquid_sdk_error(SseDeserializer deserializer);
+
+  @protected
+  LiquidSdkEvent sse_decode_liquid_sdk_event(SseDeserializer deserializer);
+
+  @protected
+  List<AssetBalance> sse_decode_list_asset_balance(SseDeserializer deserializer);
+
+  @protected
+  List<AssetMetadata> sse_decode_list_asset_metadata(SseDeserializer deserializer);
+
+  @protected
+  List<ElectrumServer> sse_decode_list_electrum_server(SseDeserializer deserializer);
+
+  @protected
+  List<ElectrumServerStatus> sse_decode_list_electrum_server_status(SseDeserializer deserializer);
+
+  @protected
+  List<Payment> sse_decode_list_payment(SseDeserializer deserializer);
+
+  @protected
+  List<PaymentState> sse_decode_list_payment_state(SseDeserializer deserializer);
+
+  @protected
+  List<PaymentType> sse_decode_list_payment_type(SseDeserializer deserializer);
+
+  @protected
+  ListPaymentsRequest sse_decode_list_payments_request(SseDeserializer deserializer);
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+  @protected
+  List<RefundableSwap> sse_decode_list_refundable_swap(SseDeserializer deserializer);
+
+  @protected
+  List<RouteHint> sse_decode_list_route_hint(SseDeserializer deserializer);
+
+  @protected
+  List<RouteHintHop> sse_decode_list_route_hint_hop(SseDeserializer deserializer);
+
+  @protected
+  LNInvoice sse_decode_ln_invoice(SseDeserializer deserializer);
+
+  @protected
+  LnUrlPayResult sse_decode_ln_url_pay_result(SseDeserializer deserializer);
+
+  @protected
+  LnUrlWithdrawRequest sse_decode_ln_url_withdraw_request(SseDeserializer deserializer);
+
+  @protected
+  LnUrlWithdrawResult sse_decode_ln_url_withdraw_result(SseDeserializer deserializer);
+
+  @protected
+  LogEntry sse_decode_log_entry(SseDeserializer deserializer);
+
+  @protected
+  Network sse_decode_network(SseDeserializer deserializer);
+
+  @protected
+  OnchainFeeRate sse_decode_onchain_fee_rate(SseDeserializer deserializer);
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer);
+
+  @protected
+  AssetMetadata? sse_decode_opt_box_autoadd_asset_metadata(SseDeserializer deserializer);
+
+  @protected
+  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer);
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer);
+
+  @protected
+  FeeBreakdown? sse_decode_opt_box_autoadd_fee_breakdown(SseDeserializer deserializer);
+
+  @protected
+  OnchainFeeRate? sse_decode_opt_box_autoadd_onchain_fee_rate(SseDeserializer deserializer);
+
+  @protected
+  Payment? sse_decode_opt_box_autoadd_payment(SseDeserializer deserializer);
+
+  @protected
+  PaymentMetadata? sse_decode_opt_box_autoadd_payment_metadata(SseDeserializer deserializer);
+
+  @protected
+  SuccessAction? sse_decode_opt_box_autoadd_success_action(SseDeserializer deserializer);
+
+  @protected
+  SuccessActionProcessed? sse_decode_opt_box_autoadd_success_action_processed(SseDeserializer deserializer);
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  List<AssetMetadata>? sse_decode_opt_list_asset_metadata(SseDeserializer deserializer);
+
+  @protected
+  List<PaymentState>? sse_decode_opt_list_payment_state(SseDeserializer deserializer);
+
+  @protected
+  List<PaymentType>? sse_decode_opt_list_payment_type(SseDeserializer deserializer);
+
+  @protected
+  PayOnchainRequest sse_decode_pay_onchain_request(SseDeserializer deserializer);
+
+  @protected
+  Payment sse_decode_payment(SseDeserializer deserializer);
+
+  @protected
+  PaymentError sse_decode_payment_error(SseDeserializer deserializer);
+
+  @protected
+  PaymentExportFormat sse_decode_payment_export_format(SseDeserializer deserializer);
+
+  @protected
+  PaymentMetadata sse_decode_payment_metadata(SseDeserializer deserializer);
+
+  @protected
+  PaymentState sse_decode_payment_state(SseDeserializer deserializer);
+
+  @protected
+  PaymentSwapType sse_decode_payment_swap_type(SseDeserializer deserializer);
+
+  @protected
+  PaymentType sse_decode_payment_type(SseDeserializer deserializer);
+
+  @protected
+  PrepareLnUrlPayRequest sse_decode_prepare_ln_url_pay_request(SseDeserializer deserializer);
+
+  @protected
+  PrepareLnUrlPayResponse sse_decode_prepare_ln_url_pay_response(SseDeserializer deserializer);
+
+  @protected
+  PreparePayOnchainRequest sse_decode_prepare_pay_onchain_request(SseDeserializer deserializer);
+
+  @protected
+  PreparePayOnchainResponse sse_decode_prepare_pay_onchain_response(SseDeserializer deserializer);
+
+  @protected
+  PrepareReceiveOnchainRequest sse_decode_prepare_receive_onchain_request(SseDeserializer deserializer);
+
+  @protected
+  PrepareReceiveOnchainResponse sse_decode_prepare_receive_onchain_response(SseDeserializer deserializer);
+
+  @protected
+  PrepareReceiveRequest sse_decode_prepare_receive_request(SseDeserializer deserializer);
+
+  @protected
+  PrepareReceiveResponse sse_decode_prepare_receive_response(SseDeserializer deserializer);
+
+  @protected
+  PrepareRefundRequest sse_decode_prepare_refund_request(SseDeserializer deserializer);
+
+  @protected
+  PrepareRefundResponse sse_decode_prepare_refund_response(SseDeserializer deserializer);
+
+  @protected
+  PrepareSendOnchainRequest sse_decode_prepare_send_onchain_request(SseDeserializer deserializer);
+
+  @protected
+  PrepareSendOnchainResponse sse_decode_prepare_send_onchain_response(SseDeserializer deserializer);
+
+  @protected
+  PrepareSendRequest sse_decode_prepare_send_request(SseDeserializer deserializer);
+
+  @protected
+  PrepareSendResponse sse_decode_prepare_send_response(SseDeserializer deserializer);
+
+  @protected
+  ReceiveLiquidRequest sse_decode_receive_liquid_request(SseDeserializer deserializer);
+
+  @protected
+  ReceiveLiquidResponse sse_decode_receive_liquid_response(SseDeserializer deserializer);
+
+  @protected
+  ReceiveOnchainResponse sse_decode_receive_onchain_response(SseDeserializer deserializer);
+
+  @protected
+  ReceivePaymentResponse sse_decode_receive_payment_response(SseDeserializer deserializer);
+
+  @protected
+  RecommendedFees sse_decode_recommended_fees(SseDeserializer deserializer);
+
+  @protected
+  RecommendedFeesResponse sse_decode_recommended_fees_response(SseDeserializer deserializer);
+
+  @protected
+  RefundRequest sse_decode_refund_request(SseDeserializer deserializer);
+
+  @protected
+  RefundResponse sse_decode_refund_response(SseDeserializer deserializer);
+
+  @protected
+  RefundableSwap sse_decode_refundable_swap(SseDeserializer deserializer);
+
+  @protected
+  RestoreRequest sse_decode_restore_request(SseDeserializer deserializer);
+
+  @protected
+  RouteHint sse_decode_route_hint(SseDeserializer deserializer);
+
+  @protected
+  RouteHintHop sse_decode_route_hint_hop(SseDeserializer deserializer);
+
+  @protected
+  SendPaymentResponse sse_decode_send_payment_response(SseDeserializer deserializer);
+
+  @protected
+  SetPaymentMetadataRequest sse_decode_set_payment_metadata_request(SseDeserializer deserializer);
+
+  @protected
+  SuccessAction sse_decode_success_action(SseDeserializer deserializer);
+
+  @protected
+  SuccessActionProcessed sse_decode_success_action_processed(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer);
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_usize(SseDeserializer deserializer);
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer);
+
+  @protected
+  ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_AnyhowException(AnyhowException raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
     throw UnimplementedError();
   }
@@ -388,6 +952,14 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     return cst_encode_list_prim_u_8_strict(utf8.encoder.convert(raw));
   }
 
+  @protected
+  ffi.Pointer<wire_cst_asset_metadata> cst_encode_box_autoadd_asset_metadata(AssetMetadata raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_asset_metadata();
+    cst_api_fill_to_wire_asset_metadata(raw, ptr.ref);
+    return ptr;
+  }
+
   @protected
   ffi.Pointer<wire_cst_backup_request> cst_encode_box_autoadd_backup_request(BackupRequest raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
@@ -406,651 +978,1970 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   }
 
   @protected
-  ffi.Pointer<wire_cst_connect_request> cst_encode_box_autoadd_connect_request(ConnectRequest raw) {
+  ffi.Pointer<wire_cst_bitcoin_address_data> cst_encode_box_autoadd_bitcoin_address_data(
+      BitcoinAddressData raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    final ptr = wire.cst_new_box_autoadd_connect_request();
-    cst_api_fill_to_wire_connect_request(raw, ptr.ref);
+    final ptr = wire.cst_new_box_autoadd_bitcoin_address_data();
+    cst_api_fill_to_wire_bitcoin_address_data(raw, ptr.ref);
     return ptr;
   }
 
   @protected
-  ffi.Pointer<wire_cst_liquid_sdk_event> cst_encode_box_autoadd_liquid_sdk_event(LiquidSdkEvent raw) {
+  ffi.Pointer<ffi.Bool> cst_encode_box_autoadd_bool(bool raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    final ptr = wire.cst_new_box_autoadd_liquid_sdk_event();
-    cst_api_fill_to_wire_liquid_sdk_event(raw, ptr.ref);
-    return ptr;
+    return wire.cst_new_box_autoadd_bool(cst_encode_bool(raw));
   }
 
   @protected
-  ffi.Pointer<wire_cst_payment> cst_encode_box_autoadd_payment(Payment raw) {
+  ffi.Pointer<wire_cst_connect_request> cst_encode_box_autoadd_connect_request(ConnectRequest raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    final ptr = wire.cst_new_box_autoadd_payment();
-    cst_api_fill_to_wire_payment(raw, ptr.ref);
+    final ptr = wire.cst_new_box_autoadd_connect_request();
+    cst_api_fill_to_wire_connect_request(raw, ptr.ref);
     return ptr;
   }
 
   @protected
-  ffi.Pointer<wire_cst_prepare_receive_request> cst_encode_box_autoadd_prepare_receive_request(
-      PrepareReceiveRequest raw) {
+  ffi.Pointer<wire_cst_export_payments_request> cst_encode_box_autoadd_export_payments_request(
+      ExportPaymentsRequest raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    final ptr = wire.cst_new_box_autoadd_prepare_receive_request();
-    cst_api_fill_to_wire_prepare_receive_request(raw, ptr.ref);
+    final ptr = wire.cst_new_box_autoadd_export_payments_request();
+    cst_api_fill_to_wire_export_payments_request(raw, ptr.ref);
     return ptr;
   }
 
   @protected
-  ffi.Pointer<wire_cst_prepare_receive_response> cst_encode_box_autoadd_prepare_receive_response(
-      PrepareReceiveResponse raw) {
+  ffi.Pointer<ffi.Double> cst_encode_box_autoadd_f_64(double raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    final ptr = wire.cst_new_box_autoadd_prepare_receive_response();
-    cst_api_fill_to_wire_prepare_receive_response(raw, ptr.ref);
-    return ptr;
+    return wire.cst_new_box_autoadd_f_64(cst_encode_f_64(raw));
   }
 
   @protected
-  ffi.Pointer<wire_cst_prepare_send_request> cst_encode_box_autoadd_prepare_send_request(
-      PrepareSendRequest raw) {
+  ffi.Pointer<wire_cst_fee_breakdown> cst_encode_box_autoadd_fee_breakdown(FeeBreakdown raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    final ptr = wire.cst_new_box_autoadd_prepare_send_request();
-    cst_api_fill_to_wire_prepare_send_request(raw, ptr.ref);
+    final ptr = wire.cst_new_box_autoadd_fee_breakdown();
+    cst_api_fill_to_wire_fee_breakdown(raw, ptr.ref);
     return ptr;
   }
 
   @protected
-  ffi.Pointer<wire_cst_prepare_send_response> cst_encode_box_autoadd_prepare_send_response(
-      PrepareSendResponse raw) {
+  ffi.Pointer<wire_cst_get_payment_request> cst_encode_box_autoadd_get_payment_request(
+      GetPaymentRequest raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    final ptr = wire.cst_new_box_autoadd_prepare_send_response();
-    cst_api_fill_to_wire_prepare_send_response(raw, ptr.ref);
+    final ptr = wire.cst_new_box_autoadd_get_payment_request();
+    cst_api_fill_to_wire_get_payment_request(raw, ptr.ref);
     return ptr;
   }
 
   @protected
-  ffi.Pointer<wire_cst_restore_request> cst_encode_box_autoadd_restore_request(RestoreRequest raw) {
+  ffi.Pointer<wire_cst_liquid_address_data> cst_encode_box_autoadd_liquid_address_data(
+      LiquidAddressData raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    final ptr = wire.cst_new_box_autoadd_restore_request();
-    cst_api_fill_to_wire_restore_request(raw, ptr.ref);
+    final ptr = wire.cst_new_box_autoadd_liquid_address_data();
+    cst_api_fill_to_wire_liquid_address_data(raw, ptr.ref);
     return ptr;
   }
 
   @protected
-  ffi.Pointer<ffi.Uint64> cst_encode_box_autoadd_u_64(BigInt raw) {
+  ffi.Pointer<wire_cst_liquid_sdk_event> cst_encode_box_autoadd_liquid_sdk_event(LiquidSdkEvent raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    return wire.cst_new_box_autoadd_u_64(cst_encode_u_64(raw));
+    final ptr = wire.cst_new_box_autoadd_liquid_sdk_event();
+    cst_api_fill_to_wire_liquid_sdk_event(raw, ptr.ref);
+    return ptr;
   }
 
   @protected
-  ffi.Pointer<wire_cst_list_payment> cst_encode_list_payment(List<Payment> raw) {
+  ffi.Pointer<wire_cst_list_payments_request> cst_encode_box_autoadd_list_payments_request(
+      ListPaymentsRequest raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    final ans = wire.cst_new_list_payment(raw.length);
-    for (var i = 0; i < raw.length; ++i) {
-      cst_api_fill_to_wire_payment(raw[i], ans.ref.ptr[i]);
-    }
-    return ans;
+    final ptr = wire.cst_new_box_autoadd_list_payments_request();
+    cst_api_fill_to_wire_list_payments_request(raw, ptr.ref);
+    return ptr;
   }
 
   @protected
-  ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_list_prim_u_8_strict(Uint8List raw) {
+  ffi.Pointer<wire_cst_ln_invoice> cst_encode_box_autoadd_ln_invoice(LNInvoice raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    final ans = wire.cst_new_list_prim_u_8_strict(raw.length);
-    ans.ref.ptr.asTypedList(raw.length).setAll(0, raw);
-    return ans;
+    final ptr = wire.cst_new_box_autoadd_ln_invoice();
+    cst_api_fill_to_wire_ln_invoice(raw, ptr.ref);
+    return ptr;
   }
 
   @protected
-  ffi.Pointer<wire_cst_list_route_hint> cst_encode_list_route_hint(List<RouteHint> raw) {
+  ffi.Pointer<wire_cst_ln_url_withdraw_request> cst_encode_box_autoadd_ln_url_withdraw_request(
+      LnUrlWithdrawRequest raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    final ans = wire.cst_new_list_route_hint(raw.length);
-    for (var i = 0; i < raw.length; ++i) {
-      cst_api_fill_to_wire_route_hint(raw[i], ans.ref.ptr[i]);
-    }
-    return ans;
+    final ptr = wire.cst_new_box_autoadd_ln_url_withdraw_request();
+    cst_api_fill_to_wire_ln_url_withdraw_request(raw, ptr.ref);
+    return ptr;
   }
 
   @protected
-  ffi.Pointer<wire_cst_list_route_hint_hop> cst_encode_list_route_hint_hop(List<RouteHintHop> raw) {
+  ffi.Pointer<wire_cst_onchain_fee_rate> cst_encode_box_autoadd_onchain_fee_rate(OnchainFeeRate raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    final ans = wire.cst_new_list_route_hint_hop(raw.length);
-    for (var i = 0; i < raw.length; ++i) {
-      cst_api_fill_to_wire_route_hint_hop(raw[i], ans.ref.ptr[i]);
-    }
-    return ans;
+    final ptr = wire.cst_new_box_autoadd_onchain_fee_rate();
+    cst_api_fill_to_wire_onchain_fee_rate(raw, ptr.ref);
+    return ptr;
   }
 
   @protected
-  ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_opt_String(String? raw) {
+  ffi.Pointer<wire_cst_pay_onchain_request> cst_encode_box_autoadd_pay_onchain_request(
+      PayOnchainRequest raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null ? ffi.nullptr : cst_encode_String(raw);
+    final ptr = wire.cst_new_box_autoadd_pay_onchain_request();
+    cst_api_fill_to_wire_pay_onchain_request(raw, ptr.ref);
+    return ptr;
   }
 
   @protected
-  ffi.Pointer<ffi.Uint64> cst_encode_opt_box_autoadd_u_64(BigInt? raw) {
+  ffi.Pointer<wire_cst_payment> cst_encode_box_autoadd_payment(Payment raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_u_64(raw);
+    final ptr = wire.cst_new_box_autoadd_payment();
+    cst_api_fill_to_wire_payment(raw, ptr.ref);
+    return ptr;
   }
 
   @protected
-  int cst_encode_u_64(BigInt raw) {
+  ffi.Pointer<wire_cst_payment_metadata> cst_encode_box_autoadd_payment_metadata(PaymentMetadata raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw.toSigned(64).toInt();
+    final ptr = wire.cst_new_box_autoadd_payment_metadata();
+    cst_api_fill_to_wire_payment_metadata(raw, ptr.ref);
+    return ptr;
   }
 
   @protected
-  int cst_encode_usize(BigInt raw) {
+  ffi.Pointer<wire_cst_prepare_ln_url_pay_request> cst_encode_box_autoadd_prepare_ln_url_pay_request(
+      PrepareLnUrlPayRequest raw) {
     // Codec=Cst (C-struct based), see doc to use other codecs
-    return raw.toSigned(64).toInt();
+    final ptr = wire.cst_new_box_autoadd_prepare_ln_url_pay_request();
+    cst_api_fill_to_wire_prepare_ln_url_pay_request(raw, ptr.ref);
+    return ptr;
   }
 
   @protected
-  void cst_api_fill_to_wire_backup_request(BackupRequest apiObj, wire_cst_backup_request wireObj) {
-    wireObj.backup_path = cst_encode_opt_String(apiObj.backupPath);
+  ffi.Pointer<wire_cst_prepare_ln_url_pay_response> cst_encode_box_autoadd_prepare_ln_url_pay_response(
+      PrepareLnUrlPayResponse raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_prepare_ln_url_pay_response();
+    cst_api_fill_to_wire_prepare_ln_url_pay_response(raw, ptr.ref);
+    return ptr;
   }
 
   @protected
-  void cst_api_fill_to_wire_binding_event_listener(
-      BindingEventListener apiObj, wire_cst_binding_event_listener wireObj) {
-    wireObj.stream = cst_encode_StreamSink_liquid_sdk_event_Dco(apiObj.stream);
+  ffi.Pointer<wire_cst_prepare_pay_onchain_request> cst_encode_box_autoadd_prepare_pay_onchain_request(
+      PreparePayOnchainRequest raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_prepare_pay_onchain_request();
+    cst_api_fill_to_wire_prepare_pay_onchain_request(raw, ptr.ref);
+    return ptr;
   }
 
   @protected
-  void cst_api_fill_to_wire_box_autoadd_backup_request(
-      BackupRequest apiObj, ffi.Pointer<wire_cst_backup_request> wireObj) {
-    cst_api_fill_to_wire_backup_request(apiObj, wireObj.ref);
+  ffi.Pointer<wire_cst_prepare_receive_onchain_request>
+      cst_encode_box_autoadd_prepare_receive_onchain_request(PrepareReceiveOnchainRequest raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_prepare_receive_onchain_request();
+    cst_api_fill_to_wire_prepare_receive_onchain_request(raw, ptr.ref);
+    return ptr;
   }
 
   @protected
-  void cst_api_fill_to_wire_box_autoadd_binding_event_listener(
-      BindingEventListener apiObj, ffi.Pointer<wire_cst_binding_event_listener> wireObj) {
-    cst_api_fill_to_wire_binding_event_listener(apiObj, wireObj.ref);
+  ffi.Pointer<wire_cst_prepare_receive_onchain_response>
+      cst_encode_box_autoadd_prepare_receive_onchain_response(PrepareReceiveOnchainResponse raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_prepare_receive_onchain_response();
+    cst_api_fill_to_wire_prepare_receive_onchain_response(raw, ptr.ref);
+    return ptr;
   }
 
   @protected
-  void cst_api_fill_to_wire_box_autoadd_connect_request(
-      ConnectRequest apiObj, ffi.Pointer<wire_cst_connect_request> wireObj) {
-    cst_api_fill_to_wire_connect_request(apiObj, wireObj.ref);
+  ffi.Pointer<wire_cst_prepare_receive_request> cst_encode_box_autoadd_prepare_receive_request(
+      PrepareReceiveRequest raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_prepare_receive_request();
+    cst_api_fill_to_wire_prepare_receive_request(raw, ptr.ref);
+    return ptr;
   }
 
   @protected
-  void cst_api_fill_to_wire_box_autoadd_liquid_sdk_event(
-      LiquidSdkEvent apiObj, ffi.Pointer<wire_cst_liquid_sdk_event> wireObj) {
-    cst_api_fill_to_wire_liquid_sdk_event(apiObj, wireObj.ref);
+  ffi.Pointer<wire_cst_prepare_receive_response> cst_encode_box_autoadd_prepare_receive_response(
+      PrepareReceiveResponse raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_prepare_receive_response();
+    cst_api_fill_to_wire_prepare_receive_response(raw, ptr.ref);
+    return ptr;
   }
 
   @protected
-  void cst_api_fill_to_wire_box_autoadd_payment(Payment apiObj, ffi.Pointer<wire_cst_payment> wireObj) {
-    cst_api_fill_to_wire_payment(apiObj, wireObj.ref);
+  ffi.Pointer<wire_cst_prepare_refund_request> cst_encode_box_autoadd_prepare_refund_request(
+      PrepareRefundRequest raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_prepare_refund_request();
+    cst_api_fill_to_wire_prepare_refund_request(raw, ptr.ref);
+    return ptr;
   }
 
   @protected
-  void cst_api_fill_to_wire_box_autoadd_prepare_receive_request(
-      PrepareReceiveRequest apiObj, ffi.Pointer<wire_cst_prepare_receive_request> wireObj) {
-    cst_api_fill_to_wire_prepare_receive_request(apiObj, wireObj.ref);
+  ffi.Pointer<wire_cst_prepare_send_onchain_request> cst_encode_box_autoadd_prepare_send_onchain_request(
+      PrepareSendOnchainRequest raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_prepare_send_onchain_request();
+    cst_api_fill_to_wire_prepare_send_onchain_request(raw, ptr.ref);
+    return ptr;
   }
 
   @protected
-  void cst_api_fill_to_wire_box_autoadd_prepare_receive_response(
-      PrepareReceiveResponse apiObj, ffi.Pointer<wire_cst_prepare_receive_response> wireObj) {
-    cst_api_fill_to_wire_prepare_receive_response(apiObj, wireObj.ref);
+  ffi.Pointer<wire_cst_prepare_send_onchain_response> cst_encode_box_autoadd_prepare_send_onchain_response(
+      PrepareSendOnchainResponse raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_prepare_send_onchain_response();
+    cst_api_fill_to_wire_prepare_send_onchain_response(raw, ptr.ref);
+    return ptr;
   }
 
   @protected
-  void cst_api_fill_to_wire_box_autoadd_prepare_send_request(
-      PrepareSendRequest apiObj, ffi.Pointer<wire_cst_prepare_send_request> wireObj) {
-    cst_api_fill_to_wire_prepare_send_request(apiObj, wireObj.ref);
+  ffi.Pointer<wire_cst_prepare_send_request> cst_encode_box_autoadd_prepare_send_request(
+      PrepareSendRequest raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_prepare_send_request();
+    cst_api_fill_to_wire_prepare_send_request(raw, ptr.ref);
+    return ptr;
   }
 
   @protected
-  void cst_api_fill_to_wire_box_autoadd_prepare_send_response(
-      PrepareSendResponse apiObj, ffi.Pointer<wire_cst_prepare_send_response> wireObj) {
-    cst_api_fill_to_wire_prepare_send_response(apiObj, wireObj.ref);
+  ffi.Pointer<wire_cst_prepare_send_response> cst_encode_box_autoadd_prepare_send_response(
+      PrepareSendResponse raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_prepare_send_response();
+    cst_api_fill_to_wire_prepare_send_response(raw, ptr.ref);
+    return ptr;
   }
 
   @protected
-  void cst_api_fill_to_wire_box_autoadd_restore_request(
-      RestoreRequest apiObj, ffi.Pointer<wire_cst_restore_request> wireObj) {
-    cst_api_fill_to_wire_restore_request(apiObj, wireObj.ref);
+  ffi.Pointer<wire_cst_receive_liquid_request> cst_encode_box_autoadd_receive_liquid_request(
+      ReceiveLiquidRequest raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_receive_liquid_request();
+    cst_api_fill_to_wire_receive_liquid_request(raw, ptr.ref);
+    return ptr;
   }
 
   @protected
-  void cst_api_fill_to_wire_config(Config apiObj, wire_cst_config wireObj) {
-    wireObj.boltz_url = cst_encode_String(apiObj.boltzUrl);
-    wireObj.electrum_url = cst_encode_String(apiObj.electrumUrl);
-    wireObj.working_dir = cst_encode_String(apiObj.workingDir);
-    wireObj.network = cst_encode_network(apiObj.network);
-    wireObj.payment_timeout_sec = cst_encode_u_64(apiObj.paymentTimeoutSec);
-    wireObj.zero_conf_min_fee_rate = cst_encode_f_32(apiObj.zeroConfMinFeeRate);
-    wireObj.zero_conf_max_amount_sat = cst_encode_opt_box_autoadd_u_64(apiObj.zeroConfMaxAmountSat);
+  ffi.Pointer<wire_cst_refund_request> cst_encode_box_autoadd_refund_request(RefundRequest raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_refund_request();
+    cst_api_fill_to_wire_refund_request(raw, ptr.ref);
+    return ptr;
   }
 
   @protected
-  void cst_api_fill_to_wire_connect_request(ConnectRequest apiObj, wire_cst_connect_request wireObj) {
-    wireObj.mnemonic = cst_encode_String(apiObj.mnemonic);
-    cst_api_fill_to_wire_config(apiObj.config, wireObj.config);
+  ffi.Pointer<wire_cst_restore_request> cst_encode_box_autoadd_restore_request(RestoreRequest raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_restore_request();
+    cst_api_fill_to_wire_restore_request(raw, ptr.ref);
+    return ptr;
   }
 
   @protected
-  void cst_api_fill_to_wire_get_info_response(GetInfoResponse apiObj, wire_cst_get_info_response wireObj) {
-    wireObj.balance_sat = cst_encode_u_64(apiObj.balanceSat);
-    wireObj.pending_send_sat = cst_encode_u_64(apiObj.pendingSendSat);
-    wireObj.pending_receive_sat = cst_encode_u_64(apiObj.pendingReceiveSat);
-    wireObj.pubkey = cst_encode_String(apiObj.pubkey);
+  ffi.Pointer<wire_cst_set_payment_metadata_request> cst_encode_box_autoadd_set_payment_metadata_request(
+      SetPaymentMetadataRequest raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_set_payment_metadata_request();
+    cst_api_fill_to_wire_set_payment_metadata_request(raw, ptr.ref);
+    return ptr;
   }
 
   @protected
-  void cst_api_fill_to_wire_liquid_sdk_error(LiquidSdkError apiObj, wire_cst_liquid_sdk_error wireObj) {
-    if (apiObj is LiquidSdkError_AlreadyStarted) {
-      wireObj.tag = 0;
-      return;
-    }
-    if (apiObj is LiquidSdkError_Generic) {
-      var pre_err = cst_encode_String(apiObj.err);
-      wireObj.tag = 1;
-      wireObj.kind.Generic.err = pre_err;
-      return;
-    }
-    if (apiObj is LiquidSdkError_NotStarted) {
-      wireObj.tag = 2;
-      return;
-    }
+  ffi.Pointer<wire_cst_success_action> cst_encode_box_autoadd_success_action(SuccessAction raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_success_action();
+    cst_api_fill_to_wire_success_action(raw, ptr.ref);
+    return ptr;
   }
 
   @protected
-  void cst_api_fill_to_wire_liquid_sdk_event(LiquidSdkEvent apiObj, wire_cst_liquid_sdk_event wireObj) {
-    if (apiObj is LiquidSdkEvent_PaymentFailed) {
-      var pre_details = cst_encode_box_autoadd_payment(apiObj.details);
-      wireObj.tag = 0;
-      wireObj.kind.PaymentFailed.details = pre_details;
-      return;
-    }
-    if (apiObj is LiquidSdkEvent_PaymentPending) {
-      var pre_details = cst_encode_box_autoadd_payment(apiObj.details);
-      wireObj.tag = 1;
-      wireObj.kind.PaymentPending.details = pre_details;
-      return;
-    }
-    if (apiObj is LiquidSdkEvent_PaymentRefunded) {
-      var pre_details = cst_encode_box_autoadd_payment(apiObj.details);
-      wireObj.tag = 2;
-      wireObj.kind.PaymentRefunded.details = pre_details;
-      return;
-    }
-    if (apiObj is LiquidSdkEvent_PaymentRefundPending) {
-      var pre_details = cst_encode_box_autoadd_payment(apiObj.details);
-      wireObj.tag = 3;
-      wireObj.kind.PaymentRefundPending.details = pre_details;
-      return;
-    }
-    if (apiObj is LiquidSdkEvent_PaymentSucceeded) {
-      var pre_details = cst_encode_box_autoadd_payment(apiObj.details);
-      wireObj.tag = 4;
-      wireObj.kind.PaymentSucceeded.details = pre_details;
-      return;
-    }
-    if (apiObj is LiquidSdkEvent_PaymentWaitingConfirmation) {
-      var pre_details = cst_encode_box_autoadd_payment(apiObj.details);
-      wireObj.tag = 5;
-      wireObj.kind.PaymentWaitingConfirmation.details = pre_details;
-      return;
-    }
-    if (apiObj is LiquidSdkEvent_Synced) {
-      wireObj.tag = 6;
-      return;
-    }
+  ffi.Pointer<wire_cst_success_action_processed> cst_encode_box_autoadd_success_action_processed(
+      SuccessActionProcessed raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ptr = wire.cst_new_box_autoadd_success_action_processed();
+    cst_api_fill_to_wire_success_action_processed(raw, ptr.ref);
+    return ptr;
   }
 
   @protected
-  void cst_api_fill_to_wire_ln_invoice(LNInvoice apiObj, wire_cst_ln_invoice wireObj) {
-    wireObj.bolt11 = cst_encode_String(apiObj.bolt11);
-    wireObj.network = cst_encode_network(apiObj.network);
-    wireObj.payee_pubkey = cst_encode_String(apiObj.payeePubkey);
-    wireObj.payment_hash = cst_encode_String(apiObj.paymentHash);
-    wireObj.description = cst_encode_opt_String(apiObj.description);
-    wireObj.description_hash = cst_encode_opt_String(apiObj.descriptionHash);
-    wireObj.amount_msat = cst_encode_opt_box_autoadd_u_64(apiObj.amountMsat);
-    wireObj.timestamp = cst_encode_u_64(apiObj.timestamp);
-    wireObj.expiry = cst_encode_u_64(apiObj.expiry);
-    wireObj.routing_hints = cst_encode_list_route_hint(apiObj.routingHints);
-    wireObj.payment_secret = cst_encode_list_prim_u_8_strict(apiObj.paymentSecret);
-    wireObj.min_final_cltv_expiry_delta = cst_encode_u_64(apiObj.minFinalCltvExpiryDelta);
+  ffi.Pointer<ffi.Uint32> cst_encode_box_autoadd_u_32(int raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return wire.cst_new_box_autoadd_u_32(cst_encode_u_32(raw));
   }
 
   @protected
-  void cst_api_fill_to_wire_log_entry(LogEntry apiObj, wire_cst_log_entry wireObj) {
-    wireObj.line = cst_encode_String(apiObj.line);
-    wireObj.level = cst_encode_String(apiObj.level);
+  ffi.Pointer<ffi.Uint64> cst_encode_box_autoadd_u_64(BigInt raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return wire.cst_new_box_autoadd_u_64(cst_encode_u_64(raw));
   }
 
   @protected
-  void cst_api_fill_to_wire_payment(Payment apiObj, wire_cst_payment wireObj) {
-    wireObj.tx_id = cst_encode_opt_String(apiObj.txId);
-    wireObj.swap_id = cst_encode_opt_String(apiObj.swapId);
-    wireObj.timestamp = cst_encode_u_32(apiObj.timestamp);
-    wireObj.amount_sat = cst_encode_u_64(apiObj.amountSat);
-    wireObj.fees_sat = cst_encode_u_64(apiObj.feesSat);
-    wireObj.preimage = cst_encode_opt_String(apiObj.preimage);
-    wireObj.refund_tx_id = cst_encode_opt_String(apiObj.refundTxId);
-    wireObj.refund_tx_amount_sat = cst_encode_opt_box_autoadd_u_64(apiObj.refundTxAmountSat);
-    wireObj.payment_type = cst_encode_payment_type(apiObj.paymentType);
-    wireObj.status = cst_encode_payment_state(apiObj.status);
+  ffi.Pointer<wire_cst_list_asset_balance> cst_encode_list_asset_balance(List<AssetBalance> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_asset_balance(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_asset_balance(raw[i], ans.ref.ptr[i]);
+    }
+    return ans;
   }
 
   @protected
-  void cst_api_fill_to_wire_payment_error(PaymentError apiObj, wire_cst_payment_error wireObj) {
-    if (apiObj is PaymentError_AlreadyClaimed) {
-      wireObj.tag = 0;
-      return;
-    }
-    if (apiObj is PaymentError_AlreadyPaid) {
-      wireObj.tag = 1;
-      return;
-    }
-    if (apiObj is PaymentError_PaymentInProgress) {
-      wireObj.tag = 2;
-      return;
+  ffi.Pointer<wire_cst_list_asset_metadata> cst_encode_list_asset_metadata(List<AssetMetadata> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_asset_metadata(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_asset_metadata(raw[i], ans.ref.ptr[i]);
     }
-    if (apiObj is PaymentError_AmountOutOfRange) {
-      wireObj.tag = 3;
-      return;
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_electrum_server> cst_encode_list_electrum_server(List<ElectrumServer> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_electrum_server(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_electrum_server(raw[i], ans.ref.ptr[i]);
     }
-    if (apiObj is PaymentError_Generic) {
-      var pre_err = cst_encode_String(apiObj.err);
-      wireObj.tag = 4;
-      wireObj.kind.Generic.err = pre_err;
-      return;
-    }
-    if (apiObj is PaymentError_InvalidOrExpiredFees) {
-      wireObj.tag = 5;
-      return;
-    }
-    if (apiObj is PaymentError_InsufficientFunds) {
-      wireObj.tag = 6;
-      return;
-    }
-    if (apiObj is PaymentError_InvalidInvoice) {
-      var pre_err = cst_encode_String(apiObj.err);
-      wireObj.tag = 7;
-      wireObj.kind.InvalidInvoice.err = pre_err;
-      return;
-    }
-    if (apiObj is PaymentError_InvalidPreimage) {
-      wireObj.tag = 8;
-      return;
-    }
-    if (apiObj is PaymentError_LwkError) {
-      var pre_err = cst_encode_String(apiObj.err);
-      wireObj.tag = 9;
-      wireObj.kind.LwkError.err = pre_err;
-      return;
-    }
-    if (apiObj is PaymentError_PairsNotFound) {
-      wireObj.tag = 10;
-      return;
-    }
-    if (apiObj is PaymentError_PaymentTimeout) {
-      wireObj.tag = 11;
-      return;
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_electrum_server_status> cst_encode_list_electrum_server_status(
+      List<ElectrumServerStatus> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_electrum_server_status(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_electrum_server_status(raw[i], ans.ref.ptr[i]);
     }
-    if (apiObj is PaymentError_PersistError) {
-      wireObj.tag = 12;
-      return;
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_payment> cst_encode_list_payment(List<Payment> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_payment(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_payment(raw[i], ans.ref.ptr[i]);
     }
-    if (apiObj is PaymentError_ReceiveError) {
-      var pre_err = cst_encode_String(apiObj.err);
-      wireObj.tag = 13;
-      wireObj.kind.ReceiveError.err = pre_err;
-      return;
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_payment_state> cst_encode_list_payment_state(List<PaymentState> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_payment_state(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      ans.ref.ptr[i] = cst_encode_payment_state(raw[i]);
     }
-    if (apiObj is PaymentError_Refunded) {
-      var pre_err = cst_encode_String(apiObj.err);
-      var pre_refund_tx_id = cst_encode_String(apiObj.refundTxId);
-      wireObj.tag = 14;
-      wireObj.kind.Refunded.err = pre_err;
-      wireObj.kind.Refunded.refund_tx_id = pre_refund_tx_id;
-      return;
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_payment_type> cst_encode_list_payment_type(List<PaymentType> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_payment_type(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      ans.ref.ptr[i] = cst_encode_payment_type(raw[i]);
     }
-    if (apiObj is PaymentError_SelfTransferNotSupported) {
-      wireObj.tag = 15;
-      return;
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_list_prim_u_8_strict(Uint8List raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_prim_u_8_strict(raw.length);
+    ans.ref.ptr.asTypedList(raw.length).setAll(0, raw);
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_refundable_swap> cst_encode_list_refundable_swap(List<RefundableSwap> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_refundable_swap(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_refundable_swap(raw[i], ans.ref.ptr[i]);
     }
-    if (apiObj is PaymentError_SendError) {
-      var pre_err = cst_encode_String(apiObj.err);
-      wireObj.tag = 16;
-      wireObj.kind.SendError.err = pre_err;
-      return;
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_route_hint> cst_encode_list_route_hint(List<RouteHint> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_route_hint(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_route_hint(raw[i], ans.ref.ptr[i]);
     }
-    if (apiObj is PaymentError_SignerError) {
-      var pre_err = cst_encode_String(apiObj.err);
-      wireObj.tag = 17;
-      wireObj.kind.SignerError.err = pre_err;
-      return;
+    return ans;
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_route_hint_hop> cst_encode_list_route_hint_hop(List<RouteHintHop> raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    final ans = wire.cst_new_list_route_hint_hop(raw.length);
+    for (var i = 0; i < raw.length; ++i) {
+      cst_api_fill_to_wire_route_hint_hop(raw[i], ans.ref.ptr[i]);
     }
+    return ans;
   }
 
   @protected
-  void cst_api_fill_to_wire_prepare_receive_request(
-      PrepareReceiveRequest apiObj, wire_cst_prepare_receive_request wireObj) {
-    wireObj.payer_amount_sat = cst_encode_u_64(apiObj.payerAmountSat);
+  ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_encode_opt_String(String? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_String(raw);
   }
 
   @protected
-  void cst_api_fill_to_wire_prepare_receive_response(
-      PrepareReceiveResponse apiObj, wire_cst_prepare_receive_response wireObj) {
-    wireObj.payer_amount_sat = cst_encode_u_64(apiObj.payerAmountSat);
-    wireObj.fees_sat = cst_encode_u_64(apiObj.feesSat);
+  ffi.Pointer<wire_cst_asset_metadata> cst_encode_opt_box_autoadd_asset_metadata(AssetMetadata? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_asset_metadata(raw);
   }
 
   @protected
-  void cst_api_fill_to_wire_prepare_send_request(
-      PrepareSendRequest apiObj, wire_cst_prepare_send_request wireObj) {
-    wireObj.invoice = cst_encode_String(apiObj.invoice);
+  ffi.Pointer<ffi.Bool> cst_encode_opt_box_autoadd_bool(bool? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_bool(raw);
   }
 
   @protected
-  void cst_api_fill_to_wire_prepare_send_response(
-      PrepareSendResponse apiObj, wire_cst_prepare_send_response wireObj) {
-    wireObj.invoice = cst_encode_String(apiObj.invoice);
-    wireObj.fees_sat = cst_encode_u_64(apiObj.feesSat);
+  ffi.Pointer<ffi.Double> cst_encode_opt_box_autoadd_f_64(double? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_f_64(raw);
   }
 
   @protected
-  void cst_api_fill_to_wire_receive_payment_response(
-      ReceivePaymentResponse apiObj, wire_cst_receive_payment_response wireObj) {
-    wireObj.id = cst_encode_String(apiObj.id);
-    wireObj.invoice = cst_encode_String(apiObj.invoice);
+  ffi.Pointer<wire_cst_fee_breakdown> cst_encode_opt_box_autoadd_fee_breakdown(FeeBreakdown? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_fee_breakdown(raw);
   }
 
   @protected
-  void cst_api_fill_to_wire_restore_request(RestoreRequest apiObj, wire_cst_restore_request wireObj) {
+  ffi.Pointer<wire_cst_onchain_fee_rate> cst_encode_opt_box_autoadd_onchain_fee_rate(OnchainFeeRate? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_onchain_fee_rate(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_payment> cst_encode_opt_box_autoadd_payment(Payment? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_payment(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_payment_metadata> cst_encode_opt_box_autoadd_payment_metadata(PaymentMetadata? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_payment_metadata(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_success_action> cst_encode_opt_box_autoadd_success_action(SuccessAction? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_success_action(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_success_action_processed> cst_encode_opt_box_autoadd_success_action_processed(
+      SuccessActionProcessed? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_success_action_processed(raw);
+  }
+
+  @protected
+  ffi.Pointer<ffi.Uint32> cst_encode_opt_box_autoadd_u_32(int? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_u_32(raw);
+  }
+
+  @protected
+  ffi.Pointer<ffi.Uint64> cst_encode_opt_box_autoadd_u_64(BigInt? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_box_autoadd_u_64(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_asset_metadata> cst_encode_opt_list_asset_metadata(List<AssetMetadata>? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_list_asset_metadata(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_payment_state> cst_encode_opt_list_payment_state(List<PaymentState>? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_list_payment_state(raw);
+  }
+
+  @protected
+  ffi.Pointer<wire_cst_list_payment_type> cst_encode_opt_list_payment_type(List<PaymentType>? raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw == null ? ffi.nullptr : cst_encode_list_payment_type(raw);
+  }
+
+  @protected
+  int cst_encode_u_64(BigInt raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw.toSigned(64).toInt();
+  }
+
+  @protected
+  int cst_encode_usize(BigInt raw) {
+    // Codec=Cst (C-struct based), see doc to use other codecs
+    return raw.toSigned(64).toInt();
+  }
+
+  @protected
+  void cst_api_fill_to_wire_asset_balance(AssetBalance apiObj, wire_cst_asset_balance wireObj) {
+    wireObj.asset_id = cst_encode_String(apiObj.assetId);
+    wireObj.balance = cst_encode_u_64(apiObj.balance);
+    wireObj.asset_metadata = cst_encode_opt_box_autoadd_asset_metadata(apiObj.assetMetadata);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_asset_metadata(AssetMetadata apiObj, wire_cst_asset_metadata wireObj) {
+    wireObj.asset_id = cst_encode_String(apiObj.assetId);
+    wireObj.ticker = cst_encode_String(apiObj.ticker);
+    wireObj.name = cst_encode_String(apiObj.name);
+    wireObj.precision = cst_encode_u_8(apiObj.precision);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_backup_request(BackupRequest apiObj, wire_cst_backup_request wireObj) {
     wireObj.backup_path = cst_encode_opt_String(apiObj.backupPath);
   }
 
   @protected
-  void cst_api_fill_to_wire_route_hint(RouteHint apiObj, wire_cst_route_hint wireObj) {
-    wireObj.hops = cst_encode_list_route_hint_hop(apiObj.hops);
+  void cst_api_fill_to_wire_binding_event_listener(
+      BindingEventListener apiObj, wire_cst_binding_event_listener wireObj) {
+    wireObj.stream = cst_encode_StreamSink_liquid_sdk_event_Dco(apiObj.stream);
   }
 
   @protected
-  void cst_api_fill_to_wire_route_hint_hop(RouteHintHop apiObj, wire_cst_route_hint_hop wireObj) {
-    wireObj.src_node_id = cst_encode_String(apiObj.srcNodeId);
-    wireObj.short_channel_id = cst_encode_u_64(apiObj.shortChannelId);
-    wireObj.fees_base_msat = cst_encode_u_32(apiObj.feesBaseMsat);
-    wireObj.fees_proportional_millionths = cst_encode_u_32(apiObj.feesProportionalMillionths);
-    wireObj.cltv_expiry_delta = cst_encode_u_64(apiObj.cltvExpiryDelta);
-    wireObj.htlc_minimum_msat = cst_encode_opt_box_autoadd_u_64(apiObj.htlcMinimumMsat);
-    wireObj.htlc_maximum_msat = cst_encode_opt_box_autoadd_u_64(apiObj.htlcMaximumMsat);
+  void cst_api_fill_to_wire_bitcoin_address_data(
+      BitcoinAddressData apiObj, wire_cst_bitcoin_address_data wireObj) {
+    wireObj.address = cst_encode_String(apiObj.address);
+    wireObj.network = cst_encode_network(apiObj.network);
+    wireObj.amount_sat = cst_encode_opt_box_autoadd_u_64(apiObj.amountSat);
+    wireObj.label = cst_encode_opt_String(apiObj.label);
+    wireObj.message = cst_encode_opt_String(apiObj.message);
   }
 
   @protected
-  void cst_api_fill_to_wire_send_payment_response(
-      SendPaymentResponse apiObj, wire_cst_send_payment_response wireObj) {
-    cst_api_fill_to_wire_payment(apiObj.payment, wireObj.payment);
+  void cst_api_fill_to_wire_box_autoadd_asset_metadata(
+      AssetMetadata apiObj, ffi.Pointer<wire_cst_asset_metadata> wireObj) {
+    cst_api_fill_to_wire_asset_metadata(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_backup_request(
+      BackupRequest apiObj, ffi.Pointer<wire_cst_backup_request> wireObj) {
+    cst_api_fill_to_wire_backup_request(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_binding_event_listener(
+      BindingEventListener apiObj, ffi.Pointer<wire_cst_binding_event_listener> wireObj) {
+    cst_api_fill_to_wire_binding_event_listener(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_bitcoin_address_data(
+      BitcoinAddressData apiObj, ffi.Pointer<wire_cst_bitcoin_address_data> wireObj) {
+    cst_api_fill_to_wire_bitcoin_address_data(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_connect_request(
+      ConnectRequest apiObj, ffi.Pointer<wire_cst_connect_request> wireObj) {
+    cst_api_fill_to_wire_connect_request(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_export_payments_request(
+      ExportPaymentsRequest apiObj, ffi.Pointer<wire_cst_export_payments_request> wireObj) {
+    cst_api_fill_to_wire_export_payments_request(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_fee_breakdown(
+      FeeBreakdown apiObj, ffi.Pointer<wire_cst_fee_breakdown> wireObj) {
+    cst_api_fill_to_wire_fee_breakdown(apiObj, wireObj.ref);
   }
 
   @protected
-  int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
-      BindingLiquidSdk raw);
+  void cst_api_fill_to_wire_box_autoadd_get_payment_request(
+      GetPaymentRequest apiObj, ffi.Pointer<wire_cst_get_payment_request> wireObj) {
+    cst_api_fill_to_wire_get_payment_request(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_liquid_address_data(
+      LiquidAddressData apiObj, ffi.Pointer<wire_cst_liquid_address_data> wireObj) {
+    cst_api_fill_to_wire_liquid_address_data(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_liquid_sdk_event(
+      LiquidSdkEvent apiObj, ffi.Pointer<wire_cst_liquid_sdk_event> wireObj) {
+    cst_api_fill_to_wire_liquid_sdk_event(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_list_payments_request(
+      ListPaymentsRequest apiObj, ffi.Pointer<wire_cst_list_payments_request> wireObj) {
+    cst_api_fill_to_wire_list_payments_request(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_ln_invoice(
+      LNInvoice apiObj, ffi.Pointer<wire_cst_ln_invoice> wireObj) {
+    cst_api_fill_to_wire_ln_invoice(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_ln_url_withdraw_request(
+      LnUrlWithdrawRequest apiObj, ffi.Pointer<wire_cst_ln_url_withdraw_request> wireObj) {
+    cst_api_fill_to_wire_ln_url_withdraw_request(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_onchain_fee_rate(
+      OnchainFeeRate apiObj, ffi.Pointer<wire_cst_onchain_fee_rate> wireObj) {
+    cst_api_fill_to_wire_onchain_fee_rate(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_pay_onchain_request(
+      PayOnchainRequest apiObj, ffi.Pointer<wire_cst_pay_onchain_request> wireObj) {
+    cst_api_fill_to_wire_pay_onchain_request(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_payment(Payment apiObj, ffi.Pointer<wire_cst_payment> wireObj) {
+    cst_api_fill_to_wire_payment(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_payment_metadata(
+      PaymentMetadata apiObj, ffi.Pointer<wire_cst_payment_metadata> wireObj) {
+    cst_api_fill_to_wire_payment_metadata(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_prepare_ln_url_pay_request(
+      PrepareLnUrlPayRequest apiObj, ffi.Pointer<wire_cst_prepare_ln_url_pay_request> wireObj) {
+    cst_api_fill_to_wire_prepare_ln_url_pay_request(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_prepare_ln_url_pay_response(
+      PrepareLnUrlPayResponse apiObj, ffi.Pointer<wire_cst_prepare_ln_url_pay_response> wireObj) {
+    cst_api_fill_to_wire_prepare_ln_url_pay_response(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_prepare_pay_onchain_request(
+      PreparePayOnchainRequest apiObj, ffi.Pointer<wire_cst_prepare_pay_onchain_request> wireObj) {
+    cst_api_fill_to_wire_prepare_pay_onchain_request(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_prepare_receive_onchain_request(
+      PrepareReceiveOnchainRequest apiObj, ffi.Pointer<wire_cst_prepare_receive_onchain_request> wireObj) {
+    cst_api_fill_to_wire_prepare_receive_onchain_request(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_prepare_receive_onchain_response(
+      PrepareReceiveOnchainResponse apiObj, ffi.Pointer<wire_cst_prepare_receive_onchain_response> wireObj) {
+    cst_api_fill_to_wire_prepare_receive_onchain_response(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_prepare_receive_request(
+      PrepareReceiveRequest apiObj, ffi.Pointer<wire_cst_prepare_receive_request> wireObj) {
+    cst_api_fill_to_wire_prepare_receive_request(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_prepare_receive_response(
+      PrepareReceiveResponse apiObj, ffi.Pointer<wire_cst_prepare_receive_response> wireObj) {
+    cst_api_fill_to_wire_prepare_receive_response(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_prepare_refund_request(
+      PrepareRefundRequest apiObj, ffi.Pointer<wire_cst_prepare_refund_request> wireObj) {
+    cst_api_fill_to_wire_prepare_refund_request(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_prepare_send_onchain_request(
+      PrepareSendOnchainRequest apiObj, ffi.Pointer<wire_cst_prepare_send_onchain_request> wireObj) {
+    cst_api_fill_to_wire_prepare_send_onchain_request(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_prepare_send_onchain_response(
+      PrepareSendOnchainResponse apiObj, ffi.Pointer<wire_cst_prepare_send_onchain_response> wireObj) {
+    cst_api_fill_to_wire_prepare_send_onchain_response(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_prepare_send_request(
+      PrepareSendRequest apiObj, ffi.Pointer<wire_cst_prepare_send_request> wireObj) {
+    cst_api_fill_to_wire_prepare_send_request(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_prepare_send_response(
+      PrepareSendResponse apiObj, ffi.Pointer<wire_cst_prepare_send_response> wireObj) {
+    cst_api_fill_to_wire_prepare_send_response(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_receive_liquid_request(
+      ReceiveLiquidRequest apiObj, ffi.Pointer<wire_cst_receive_liquid_request> wireObj) {
+    cst_api_fill_to_wire_receive_liquid_request(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_refund_request(
+      RefundRequest apiObj, ffi.Pointer<wire_cst_refund_request> wireObj) {
+    cst_api_fill_to_wire_refund_request(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_restore_request(
+      RestoreRequest apiObj, ffi.Pointer<wire_cst_restore_request> wireObj) {
+    cst_api_fill_to_wire_restore_request(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_set_payment_metadata_request(
+      SetPaymentMetadataRequest apiObj, ffi.Pointer<wire_cst_set_payment_metadata_request> wireObj) {
+    cst_api_fill_to_wire_set_payment_metadata_request(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_success_action(
+      SuccessAction apiObj, ffi.Pointer<wire_cst_success_action> wireObj) {
+    cst_api_fill_to_wire_success_action(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_box_autoadd_success_action_processed(
+      SuccessActionProcessed apiObj, ffi.Pointer<wire_cst_success_action_processed> wireObj) {
+    cst_api_fill_to_wire_success_action_processed(apiObj, wireObj.ref);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_config(Config apiObj, wire_cst_config wireObj) {
+    wireObj.boltz_url = cst_encode_String(apiObj.boltzUrl);
+    wireObj.electrum_servers = cst_encode_list_electrum_server(apiObj.electrumServers);
+    wireObj.electrum_url = cst_encode_opt_String(apiObj.electrumUrl);
+    wireObj.bitcoin_electrum_url = cst_encode_String(apiObj.bitcoinElectrumUrl);
+    wireObj.esplora_url = cst_encode_opt_String(apiObj.esploraUrl);
+    wireObj.working_dir = cst_encode_String(apiObj.workingDir);
+    wireObj.network = cst_encode_network(apiObj.network);
+    wireObj.payment_timeout_sec = cst_encode_u_64(apiObj.paymentTimeoutSec);
+    wireObj.sync_fallback_interval_sec = cst_encode_u_64(apiObj.syncFallbackIntervalSec);
+    wireObj.zero_conf_min_fee_rate = cst_encode_f_32(apiObj.zeroConfMinFeeRate);
+    wireObj.zero_conf_max_amount_sat = cst_encode_opt_box_autoadd_u_64(apiObj.zeroConfMaxAmountSat);
+    wireObj.asset_metadata = cst_encode_opt_list_asset_metadata(apiObj.assetMetadata);
+    wireObj.max_fee_sat = cst_encode_opt_box_autoadd_u_64(apiObj.maxFeeSat);
+    wireObj.max_fee_percent = cst_encode_opt_box_autoadd_f_64(apiObj.maxFeePercent);
+    wireObj.regtest_policy_asset = cst_encode_opt_String(apiObj.regtestPolicyAsset);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_connect_request(ConnectRequest apiObj, wire_cst_connect_request wireObj) {
+    wireObj.mnemonic = cst_encode_String(apiObj.mnemonic);
+    cst_api_fill_to_wire_config(apiObj.config, wireObj.config);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_electrum_server(ElectrumServer apiObj, wire_cst_electrum_server wireObj) {
+    wireObj.url = cst_encode_String(apiObj.url);
+    wireObj.tls = cst_encode_bool(apiObj.tls);
+    wireObj.validate_domain = cst_encode_bool(apiObj.validateDomain);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_electrum_server_status(
+      ElectrumServerStatus apiObj, wire_cst_electrum_server_status wireObj) {
+    wireObj.url = cst_encode_String(apiObj.url);
+    wireObj.in_use = cst_encode_bool(apiObj.inUse);
+    wireObj.healthy = cst_encode_bool(apiObj.healthy);
+    wireObj.consecutive_failures = cst_encode_u_32(apiObj.consecutiveFailures);
+    wireObj.last_error = cst_encode_opt_String(apiObj.lastError);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_export_payments_request(
+      ExportPaymentsRequest apiObj, wire_cst_export_payments_request wireObj) {
+    wireObj.format = cst_encode_payment_export_format(apiObj.format);
+    cst_api_fill_to_wire_list_payments_request(apiObj.filter, wireObj.filter);
+    wireObj.path = cst_encode_String(apiObj.path);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_export_payments_response(
+      ExportPaymentsResponse apiObj, wire_cst_export_payments_response wireObj) {
+    wireObj.payments_count = cst_encode_u_32(apiObj.paymentsCount);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_fee_breakdown(FeeBreakdown apiObj, wire_cst_fee_breakdown wireObj) {
+    wireObj.service_fee_percentage = cst_encode_f_64(apiObj.serviceFeePercentage);
+    wireObj.service_fee_sat = cst_encode_u_64(apiObj.serviceFeeSat);
+    wireObj.swapper_miner_fees_sat = cst_encode_u_64(apiObj.swapperMinerFeesSat);
+    wireObj.network_fee_sat = cst_encode_u_64(apiObj.networkFeeSat);
+    wireObj.total_fees_sat = cst_encode_u_64(apiObj.totalFeesSat);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_get_info_response(GetInfoResponse apiObj, wire_cst_get_info_response wireObj) {
+    wireObj.balance_sat = cst_encode_u_64(apiObj.balanceSat);
+    wireObj.pending_send_sat = cst_encode_u_64(apiObj.pendingSendSat);
+    wireObj.pending_receive_sat = cst_encode_u_64(apiObj.pendingReceiveSat);
+    wireObj.pubkey = cst_encode_String(apiObj.pubkey);
+    wireObj.asset_balances = cst_encode_list_asset_balance(apiObj.assetBalances);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_get_payment_request(
+      GetPaymentRequest apiObj, wire_cst_get_payment_request wireObj) {
+    if (apiObj is GetPaymentRequest_TxId) {
+      var pre_tx_id = cst_encode_String(apiObj.txId);
+      wireObj.tag = 0;
+      wireObj.kind.TxId.tx_id = pre_tx_id;
+      return;
+    }
+    if (apiObj is GetPaymentRequest_SwapId) {
+      var pre_swap_id = cst_encode_String(apiObj.swapId);
+      wireObj.tag = 1;
+      wireObj.kind.SwapId.swap_id = pre_swap_id;
+      return;
+    }
+    if (apiObj is GetPaymentRequest_Bolt11) {
+      var pre_bolt11 = cst_encode_String(apiObj.bolt11);
+      wireObj.tag = 2;
+      wireObj.kind.Bolt11.bolt11 = pre_bolt11;
+      return;
+    }
+    if (apiObj is GetPaymentRequest_PaymentHash) {
+      var pre_payment_hash = cst_encode_String(apiObj.paymentHash);
+      wireObj.tag = 3;
+      wireObj.kind.PaymentHash.payment_hash = pre_payment_hash;
+      return;
+    }
+  }
+
+  @protected
+  void cst_api_fill_to_wire_input_type(InputType apiObj, wire_cst_input_type wireObj) {
+    if (apiObj is InputType_Bolt11) {
+      var pre_invoice = cst_encode_box_autoadd_ln_invoice(apiObj.invoice);
+      wireObj.tag = 0;
+      wireObj.kind.Bolt11.invoice = pre_invoice;
+      return;
+    }
+    if (apiObj is InputType_LiquidAddress) {
+      var pre_address = cst_encode_box_autoadd_liquid_address_data(apiObj.address);
+      wireObj.tag = 1;
+      wireObj.kind.LiquidAddress.address = pre_address;
+      return;
+    }
+    if (apiObj is InputType_BitcoinAddress) {
+      var pre_address = cst_encode_box_autoadd_bitcoin_address_data(apiObj.address);
+      wireObj.tag = 2;
+      wireObj.kind.BitcoinAddress.address = pre_address;
+      return;
+    }
+    if (apiObj is InputType_LnUrl) {
+      var pre_url = cst_encode_String(apiObj.url);
+      wireObj.tag = 3;
+      wireObj.kind.LnUrl.url = pre_url;
+      return;
+    }
+    if (apiObj is InputType_LightningAddress) {
+      var pre_address = cst_encode_String(apiObj.address);
+      var pre_url = cst_encode_String(apiObj.url);
+      wireObj.tag = 4;
+      wireObj.kind.LightningAddress.address = pre_address;
+      wireObj.kind.LightningAddress.url = pre_url;
+      return;
+    }
+  }
+
+  @protected
+  void cst_api_fill_to_wire_lightning_limits(LightningLimits apiObj, wire_cst_lightning_limits wireObj) {
+    wireObj.min_sat = cst_encode_u_64(apiObj.minSat);
+    wireObj.max_sat = cst_encode_u_64(apiObj.maxSat);
+    wireObj.service_fee_percentage = cst_encode_f_64(apiObj.serviceFeePercentage);
+    wireObj.miner_fees_sat = cst_encode_u_64(apiObj.minerFeesSat);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_lightning_payment_limits_response(
+      LightningPaymentLimitsResponse apiObj, wire_cst_lightning_payment_limits_response wireObj) {
+    cst_api_fill_to_wire_lightning_limits(apiObj.send, wireObj.send);
+    cst_api_fill_to_wire_lightning_limits(apiObj.receive, wireObj.receive);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_liquid_address_data(
+      LiquidAddressData apiObj, wire_cst_liquid_address_data wireObj) {
+    wireObj.address = cst_encode_String(apiObj.address);
+    wireObj.network = cst_encode_network(apiObj.network);
+    wireObj.amount_sat = cst_encode_opt_box_autoadd_u_64(apiObj.amountSat);
+    wireObj.asset_id = cst_encode_opt_String(apiObj.assetId);
+    wireObj.label = cst_encode_opt_String(apiObj.label);
+    wireObj.message = cst_encode_opt_String(apiObj.message);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_liquid_sdk_error(LiquidSdkError apiObj, wire_cst_liquid_sdk_error wireObj) {
+    if (apiObj is LiquidSdkError_AlreadyStarted) {
+      wireObj.tag = 0;
+      return;
+    }
+    if (apiObj is LiquidSdkError_Generic) {
+      var pre_err = cst_encode_String(apiObj.err);
+      wireObj.tag = 1;
+      wireObj.kind.Generic.err = pre_err;
+      return;
+    }
+    if (apiObj is LiquidSdkError_NotStarted) {
+      wireObj.tag = 2;
+      return;
+    }
+  }
+
+  @protected
+  void cst_api_fill_to_wire_liquid_sdk_event(LiquidSdkEvent apiObj, wire_cst_liquid_sdk_event wireObj) {
+    if (apiObj is LiquidSdkEvent_PaymentFailed) {
+      var pre_details = cst_encode_box_autoadd_payment(apiObj.details);
+      wireObj.tag = 0;
+      wireObj.kind.PaymentFailed.details = pre_details;
+      return;
+    }
+    if (apiObj is LiquidSdkEvent_PaymentPending) {
+      var pre_details = cst_encode_box_autoadd_payment(apiObj.details);
+      wireObj.tag = 1;
+      wireObj.kind.PaymentPending.details = pre_details;
+      return;
+    }
+    if (apiObj is LiquidSdkEvent_PaymentRefunded) {
+      var pre_details = cst_encode_box_autoadd_payment(apiObj.details);
+      wireObj.tag = 2;
+      wireObj.kind.PaymentRefunded.details = pre_details;
+      return;
+    }
+    if (apiObj is LiquidSdkEvent_PaymentRefundable) {
+      var pre_details = cst_encode_box_autoadd_payment(apiObj.details);
+      wireObj.tag = 3;
+      wireObj.kind.PaymentRefundable.details = pre_details;
+      return;
+    }
+    if (apiObj is LiquidSdkEvent_PaymentRefundPending) {
+      var pre_details = cst_encode_box_autoadd_payment(apiObj.details);
+      wireObj.tag = 4;
+      wireObj.kind.PaymentRefundPending.details = pre_details;
+      return;
+    }
+    if (apiObj is LiquidSdkEvent_PaymentSucceeded) {
+      var pre_details = cst_encode_box_autoadd_payment(apiObj.details);
+      wireObj.tag = 5;
+      wireObj.kind.PaymentSucceeded.details = pre_details;
+      return;
+    }
+    if (apiObj is LiquidSdkEvent_PaymentWaitingConfirmation) {
+      var pre_details = cst_encode_box_autoadd_payment(apiObj.details);
+      wireObj.tag = 6;
+      wireObj.kind.PaymentWaitingConfirmation.details = pre_details;
+      return;
+    }
+    if (apiObj is LiquidSdkEvent_Synced) {
+      wireObj.tag = 7;
+      return;
+    }
+  }
+
+  @protected
+  void cst_api_fill_to_wire_list_payments_request(
+      ListPaymentsRequest apiObj, wire_cst_list_payments_request wireObj) {
+    wireObj.payment_types = cst_encode_opt_list_payment_type(apiObj.paymentTypes);
+    wireObj.states = cst_encode_opt_list_payment_state(apiObj.states);
+    wireObj.from_timestamp = cst_encode_opt_box_autoadd_u_32(apiObj.fromTimestamp);
+    wireObj.to_timestamp = cst_encode_opt_box_autoadd_u_32(apiObj.toTimestamp);
+    wireObj.offset = cst_encode_opt_box_autoadd_u_32(apiObj.offset);
+    wireObj.limit = cst_encode_opt_box_autoadd_u_32(apiObj.limit);
+    wireObj.sort_descending = cst_encode_opt_box_autoadd_bool(apiObj.sortDescending);
+    wireObj.metadata_search = cst_encode_opt_String(apiObj.metadataSearch);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_ln_invoice(LNInvoice apiObj, wire_cst_ln_invoice wireObj) {
+    wireObj.bolt11 = cst_encode_String(apiObj.bolt11);
+    wireObj.network = cst_encode_network(apiObj.network);
+    wireObj.payee_pubkey = cst_encode_String(apiObj.payeePubkey);
+    wireObj.payment_hash = cst_encode_String(apiObj.paymentHash);
+    wireObj.description = cst_encode_opt_String(apiObj.description);
+    wireObj.description_hash = cst_encode_opt_String(apiObj.descriptionHash);
+    wireObj.amount_msat = cst_encode_opt_box_autoadd_u_64(apiObj.amountMsat);
+    wireObj.timestamp = cst_encode_u_64(apiObj.timestamp);
+    wireObj.expiry = cst_encode_u_64(apiObj.expiry);
+    wireObj.routing_hints = cst_encode_list_route_hint(apiObj.routingHints);
+    wireObj.payment_secret = cst_encode_list_prim_u_8_strict(apiObj.paymentSecret);
+    wireObj.min_final_cltv_expiry_delta = cst_encode_u_64(apiObj.minFinalCltvExpiryDelta);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_ln_url_pay_result(LnUrlPayResult apiObj, wire_cst_ln_url_pay_result wireObj) {
+    cst_api_fill_to_wire_payment(apiObj.payment, wireObj.payment);
+    wireObj.success_action = cst_encode_opt_box_autoadd_success_action_processed(apiObj.successAction);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_ln_url_withdraw_request(
+      LnUrlWithdrawRequest apiObj, wire_cst_ln_url_withdraw_request wireObj) {
+    wireObj.lnurl = cst_encode_String(apiObj.lnurl);
+    wireObj.amount_sat = cst_encode_u_64(apiObj.amountSat);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_ln_url_withdraw_result(
+      LnUrlWithdrawResult apiObj, wire_cst_ln_url_withdraw_result wireObj) {
+    cst_api_fill_to_wire_receive_payment_response(
+        apiObj.receivePaymentResponse, wireObj.receive_payment_response);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_log_entry(LogEntry apiObj, wire_cst_log_entry wireObj) {
+    wireObj.line = cst_encode_String(apiObj.line);
+    wireObj.level = cst_encode_String(apiObj.level);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_onchain_fee_rate(OnchainFeeRate apiObj, wire_cst_onchain_fee_rate wireObj) {
+    if (apiObj is OnchainFeeRate_SatPerVbyte) {
+      var pre_sat_per_vbyte = cst_encode_f_64(apiObj.satPerVbyte);
+      wireObj.tag = 0;
+      wireObj.kind.SatPerVbyte.sat_per_vbyte = pre_sat_per_vbyte;
+      return;
+    }
+    if (apiObj is OnchainFeeRate_Priority) {
+      var pre_priority = cst_encode_fee_priority(apiObj.priority);
+      wireObj.tag = 1;
+      wireObj.kind.Priority.priority = pre_priority;
+      return;
+    }
+  }
+
+  @protected
+  void cst_api_fill_to_wire_pay_onchain_request(
+      PayOnchainRequest apiObj, wire_cst_pay_onchain_request wireObj) {
+    wireObj.address = cst_encode_String(apiObj.address);
+    cst_api_fill_to_wire_prepare_pay_onchain_response(apiObj.prepareRes, wireObj.prepare_res);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_payment(Payment apiObj, wire_cst_payment wireObj) {
+    wireObj.tx_id = cst_encode_opt_String(apiObj.txId);
+    wireObj.swap_id = cst_encode_opt_String(apiObj.swapId);
+    wireObj.swap_type = cst_encode_payment_swap_type(apiObj.swapType);
+    wireObj.timestamp = cst_encode_u_32(apiObj.timestamp);
+    wireObj.amount_sat = cst_encode_u_64(apiObj.amountSat);
+    wireObj.fees_sat = cst_encode_u_64(apiObj.feesSat);
+    wireObj.network_fees_sat = cst_encode_opt_box_autoadd_u_64(apiObj.networkFeesSat);
+    wireObj.fee_breakdown = cst_encode_opt_box_autoadd_fee_breakdown(apiObj.feeBreakdown);
+    wireObj.fee_rate_sat_per_vbyte = cst_encode_opt_box_autoadd_f_64(apiObj.feeRateSatPerVbyte);
+    wireObj.preimage = cst_encode_opt_String(apiObj.preimage);
+    wireObj.bolt11 = cst_encode_opt_String(apiObj.bolt11);
+    wireObj.payment_hash = cst_encode_opt_String(apiObj.paymentHash);
+    wireObj.payee_pubkey = cst_encode_opt_String(apiObj.payeePubkey);
+    wireObj.lockup_tx_id = cst_encode_opt_String(apiObj.lockupTxId);
+    wireObj.claim_tx_id = cst_encode_opt_String(apiObj.claimTxId);
+    wireObj.refund_tx_id = cst_encode_opt_String(apiObj.refundTxId);
+    wireObj.refund_tx_amount_sat = cst_encode_opt_box_autoadd_u_64(apiObj.refundTxAmountSat);
+    wireObj.payment_type = cst_encode_payment_type(apiObj.paymentType);
+    wireObj.status = cst_encode_payment_state(apiObj.status);
+    wireObj.label = cst_encode_opt_String(apiObj.label);
+    wireObj.description = cst_encode_opt_String(apiObj.description);
+    wireObj.metadata = cst_encode_opt_box_autoadd_payment_metadata(apiObj.metadata);
+    wireObj.asset_id = cst_encode_opt_String(apiObj.assetId);
+    wireObj.asset_amount = cst_encode_opt_box_autoadd_u_64(apiObj.assetAmount);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_payment_error(PaymentError apiObj, wire_cst_payment_error wireObj) {
+    if (apiObj is PaymentError_AlreadyClaimed) {
+      wireObj.tag = 0;
+      return;
+    }
+    if (apiObj is PaymentError_AlreadyPaid) {
+      wireObj.tag = 1;
+      return;
+    }
+    if (apiObj is PaymentError_PaymentInProgress) {
+      wireObj.tag = 2;
+      return;
+    }
+    if (apiObj is PaymentError_AmountOutOfRange) {
+      wireObj.tag = 3;
+      return;
+    }
+    if (apiObj is PaymentError_UnrecognizedInput) {
+      var pre_err = cst_encode_String(apiObj.err);
+      wireObj.tag = 4;
+      wireObj.kind.UnrecognizedInput.err = pre_err;
+      return;
+    }
+    if (apiObj is PaymentError_NetworkMismatch) {
+      var pre_err = cst_encode_String(apiObj.err);
+      wireObj.tag = 5;
+      wireObj.kind.NetworkMismatch.err = pre_err;
+      return;
+    }
+    if (apiObj is PaymentError_LnUrlError) {
+      var pre_err = cst_encode_String(apiObj.err);
+      wireObj.tag = 6;
+      wireObj.kind.LnUrlError.err = pre_err;
+      return;
+    }
+    if (apiObj is PaymentError_LnUrlServiceUnreachable) {
+      var pre_err = cst_encode_String(apiObj.err);
+      wireObj.tag = 7;
+      wireObj.kind.LnUrlServiceUnreachable.err = pre_err;
+      return;
+    }
+    if (apiObj is PaymentError_LnUrlServiceError) {
+      var pre_err = cst_encode_String(apiObj.err);
+      wireObj.tag = 8;
+      wireObj.kind.LnUrlServiceError.err = pre_err;
+      return;
+    }
+    if (apiObj is PaymentError_Generic) {
+      var pre_err = cst_encode_String(apiObj.err);
+      wireObj.tag = 9;
+      wireObj.kind.Generic.err = pre_err;
+      return;
+    }
+    if (apiObj is PaymentError_InvalidOrExpiredFees) {
+      wireObj.tag = 10;
+      return;
+    }
+    if (apiObj is PaymentError_FeeLimitExceeded) {
+      var pre_fee_sat = cst_encode_u_64(apiObj.feeSat);
+      var pre_limit_sat = cst_encode_u_64(apiObj.limitSat);
+      wireObj.tag = 11;
+      wireObj.kind.FeeLimitExceeded.fee_sat = pre_fee_sat;
+      wireObj.kind.FeeLimitExceeded.limit_sat = pre_limit_sat;
+      return;
+    }
+    if (apiObj is PaymentError_InsufficientFunds) {
+      wireObj.tag = 12;
+      return;
+    }
+    if (apiObj is PaymentError_InvalidInvoice) {
+      var pre_err = cst_encode_String(apiObj.err);
+      wireObj.tag = 13;
+      wireObj.kind.InvalidInvoice.err = pre_err;
+      return;
+    }
+    if (apiObj is PaymentError_InvalidPreimage) {
+      wireObj.tag = 14;
+      return;
+    }
+    if (apiObj is PaymentError_LwkError) {
+      var pre_err = cst_encode_String(apiObj.err);
+      wireObj.tag = 15;
+      wireObj.kind.LwkError.err = pre_err;
+      return;
+    }
+    if (apiObj is PaymentError_PairsNotFound) {
+      wireObj.tag = 16;
+      return;
+    }
+    if (apiObj is PaymentError_PaymentTimeout) {
+      wireObj.tag = 17;
+      return;
+    }
+    if (apiObj is PaymentError_PersistError) {
+      wireObj.tag = 18;
+      return;
+    }
+    if (apiObj is PaymentError_ReceiveError) {
+      var pre_err = cst_encode_String(apiObj.err);
+      wireObj.tag = 19;
+      wireObj.kind.ReceiveError.err = pre_err;
+      return;
+    }
+    if (apiObj is PaymentError_Refunded) {
+      var pre_err = cst_encode_String(apiObj.err);
+      var pre_refund_tx_id = cst_encode_String(apiObj.refundTxId);
+      wireObj.tag = 20;
+      wireObj.kind.Refunded.err = pre_err;
+      wireObj.kind.Refunded.refund_tx_id = pre_refund_tx_id;
+      return;
+    }
+    if (apiObj is PaymentError_SelfTransferNotSupported) {
+      wireObj.tag = 21;
+      return;
+    }
+    if (apiObj is PaymentError_SendError) {
+      var pre_err = cst_encode_String(apiObj.err);
+      wireObj.tag = 22;
+      wireObj.kind.SendError.err = pre_err;
+      return;
+    }
+    if (apiObj is PaymentError_SignerError) {
+      var pre_err = cst_encode_String(apiObj.err);
+      wireObj.tag = 23;
+      wireObj.kind.SignerError.err = pre_err;
+      return;
+    }
+  }
+
+  @protected
+  void cst_api_fill_to_wire_payment_metadata(PaymentMetadata apiObj, wire_cst_payment_metadata wireObj) {
+    wireObj.label = cst_encode_opt_String(apiObj.label);
+    wireObj.note = cst_encode_opt_String(apiObj.note);
+    wireObj.external_id = cst_encode_opt_String(apiObj.externalId);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_prepare_ln_url_pay_request(
+      PrepareLnUrlPayRequest apiObj, wire_cst_prepare_ln_url_pay_request wireObj) {
+    wireObj.lnurl = cst_encode_String(apiObj.lnurl);
+    wireObj.amount_sat = cst_encode_u_64(apiObj.amountSat);
+    wireObj.comment = cst_encode_opt_String(apiObj.comment);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_prepare_ln_url_pay_response(
+      PrepareLnUrlPayResponse apiObj, wire_cst_prepare_ln_url_pay_response wireObj) {
+    cst_api_fill_to_wire_prepare_send_response(apiObj.prepareSendResponse, wireObj.prepare_send_response);
+    wireObj.success_action = cst_encode_opt_box_autoadd_success_action(apiObj.successAction);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_prepare_pay_onchain_request(
+      PreparePayOnchainRequest apiObj, wire_cst_prepare_pay_onchain_request wireObj) {
+    wireObj.receiver_amount_sat = cst_encode_u_64(apiObj.receiverAmountSat);
+    wireObj.fee_rate = cst_encode_opt_box_autoadd_onchain_fee_rate(apiObj.feeRate);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_prepare_pay_onchain_response(
+      PreparePayOnchainResponse apiObj, wire_cst_prepare_pay_onchain_response wireObj) {
+    wireObj.receiver_amount_sat = cst_encode_u_64(apiObj.receiverAmountSat);
+    wireObj.fees_sat = cst_encode_u_64(apiObj.feesSat);
+    wireObj.fee_rate_sat_per_vbyte = cst_encode_opt_box_autoadd_f_64(apiObj.feeRateSatPerVbyte);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_prepare_receive_onchain_request(
+      PrepareReceiveOnchainRequest apiObj, wire_cst_prepare_receive_onchain_request wireObj) {
+    wireObj.payer_amount_sat = cst_encode_u_64(apiObj.payerAmountSat);
+    wireObj.fee_rate = cst_encode_opt_box_autoadd_onchain_fee_rate(apiObj.feeRate);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_prepare_receive_onchain_response(
+      PrepareReceiveOnchainResponse apiObj, wire_cst_prepare_receive_onchain_response wireObj) {
+    wireObj.payer_amount_sat = cst_encode_u_64(apiObj.payerAmountSat);
+    wireObj.fees_sat = cst_encode_u_64(apiObj.feesSat);
+    wireObj.fee_rate_sat_per_vbyte = cst_encode_opt_box_autoadd_f_64(apiObj.feeRateSatPerVbyte);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_prepare_receive_request(
+      PrepareReceiveRequest apiObj, wire_cst_prepare_receive_request wireObj) {
+    wireObj.payer_amount_sat = cst_encode_opt_box_autoadd_u_64(apiObj.payerAmountSat);
+    wireObj.receiver_amount_sat = cst_encode_opt_box_autoadd_u_64(apiObj.receiverAmountSat);
+    wireObj.description = cst_encode_opt_String(apiObj.description);
+    wireObj.description_hash = cst_encode_opt_String(apiObj.descriptionHash);
+    wireObj.invoice_expiry_secs = cst_encode_opt_box_autoadd_u_64(apiObj.invoiceExpirySecs);
+    wireObj.metadata = cst_encode_opt_box_autoadd_payment_metadata(apiObj.metadata);
+    wireObj.max_fee_sat = cst_encode_opt_box_autoadd_u_64(apiObj.maxFeeSat);
+    wireObj.max_fee_percent = cst_encode_opt_box_autoadd_f_64(apiObj.maxFeePercent);
+    wireObj.fee_rate = cst_encode_opt_box_autoadd_onchain_fee_rate(apiObj.feeRate);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_prepare_receive_response(
+      PrepareReceiveResponse apiObj, wire_cst_prepare_receive_response wireObj) {
+    wireObj.payer_amount_sat = cst_encode_u_64(apiObj.payerAmountSat);
+    wireObj.receiver_amount_sat = cst_encode_u_64(apiObj.receiverAmountSat);
+    wireObj.fees_sat = cst_encode_u_64(apiObj.feesSat);
+    wireObj.description = cst_encode_opt_String(apiObj.description);
+    wireObj.description_hash = cst_encode_opt_String(apiObj.descriptionHash);
+    wireObj.invoice_expiry_secs = cst_encode_opt_box_autoadd_u_64(apiObj.invoiceExpirySecs);
+    wireObj.metadata = cst_encode_opt_box_autoadd_payment_metadata(apiObj.metadata);
+    cst_api_fill_to_wire_fee_breakdown(apiObj.feeBreakdown, wireObj.fee_breakdown);
+    wireObj.pair_hash = cst_encode_String(apiObj.pairHash);
+    wireObj.expires_at = cst_encode_u_32(apiObj.expiresAt);
+    wireObj.fee_rate_sat_per_vbyte = cst_encode_opt_box_autoadd_f_64(apiObj.feeRateSatPerVbyte);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_prepare_refund_request(
+      PrepareRefundRequest apiObj, wire_cst_prepare_refund_request wireObj) {
+    wireObj.swap_id = cst_encode_String(apiObj.swapId);
+    wireObj.refund_address = cst_encode_String(apiObj.refundAddress);
+    cst_api_fill_to_wire_onchain_fee_rate(apiObj.feeRate, wireObj.fee_rate);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_prepare_refund_response(
+      PrepareRefundResponse apiObj, wire_cst_prepare_refund_response wireObj) {
+    wireObj.tx_vsize = cst_encode_u_32(apiObj.txVsize);
+    wireObj.tx_fee_sat = cst_encode_u_64(apiObj.txFeeSat);
+    wireObj.refund_amount_sat = cst_encode_u_64(apiObj.refundAmountSat);
+    wireObj.fee_rate_sat_per_vbyte = cst_encode_f_64(apiObj.feeRateSatPerVbyte);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_prepare_send_onchain_request(
+      PrepareSendOnchainRequest apiObj, wire_cst_prepare_send_onchain_request wireObj) {
+    wireObj.destination = cst_encode_String(apiObj.destination);
+    wireObj.amount_sat = cst_encode_opt_box_autoadd_u_64(apiObj.amountSat);
+    wireObj.send_all = cst_encode_bool(apiObj.sendAll);
+    wireObj.asset_id = cst_encode_opt_String(apiObj.assetId);
+    wireObj.fee_rate = cst_encode_opt_box_autoadd_onchain_fee_rate(apiObj.feeRate);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_prepare_send_onchain_response(
+      PrepareSendOnchainResponse apiObj, wire_cst_prepare_send_onchain_response wireObj) {
+    wireObj.address = cst_encode_String(apiObj.address);
+    wireObj.receiver_amount_sat = cst_encode_u_64(apiObj.receiverAmountSat);
+    wireObj.fees_sat = cst_encode_u_64(apiObj.feesSat);
+    wireObj.send_all = cst_encode_bool(apiObj.sendAll);
+    wireObj.asset_id = cst_encode_opt_String(apiObj.assetId);
+    wireObj.fee_rate_sat_per_vbyte = cst_encode_opt_box_autoadd_f_64(apiObj.feeRateSatPerVbyte);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_prepare_send_request(
+      PrepareSendRequest apiObj, wire_cst_prepare_send_request wireObj) {
+    wireObj.invoice = cst_encode_String(apiObj.invoice);
+    wireObj.metadata = cst_encode_opt_box_autoadd_payment_metadata(apiObj.metadata);
+    wireObj.max_fee_sat = cst_encode_opt_box_autoadd_u_64(apiObj.maxFeeSat);
+    wireObj.max_fee_percent = cst_encode_opt_box_autoadd_f_64(apiObj.maxFeePercent);
+    wireObj.fee_rate = cst_encode_opt_box_autoadd_onchain_fee_rate(apiObj.feeRate);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_prepare_send_response(
+      PrepareSendResponse apiObj, wire_cst_prepare_send_response wireObj) {
+    wireObj.invoice = cst_encode_String(apiObj.invoice);
+    wireObj.fees_sat = cst_encode_u_64(apiObj.feesSat);
+    wireObj.metadata = cst_encode_opt_box_autoadd_payment_metadata(apiObj.metadata);
+    cst_api_fill_to_wire_fee_breakdown(apiObj.feeBreakdown, wireObj.fee_breakdown);
+    wireObj.max_fee_sat = cst_encode_opt_box_autoadd_u_64(apiObj.maxFeeSat);
+    wireObj.max_fee_percent = cst_encode_opt_box_autoadd_f_64(apiObj.maxFeePercent);
+    wireObj.pair_hash = cst_encode_String(apiObj.pairHash);
+    wireObj.expires_at = cst_encode_u_32(apiObj.expiresAt);
+    wireObj.fee_rate_sat_per_vbyte = cst_encode_opt_box_autoadd_f_64(apiObj.feeRateSatPerVbyte);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_receive_liquid_request(
+      ReceiveLiquidRequest apiObj, wire_cst_receive_liquid_request wireObj) {
+    wireObj.amount_sat = cst_encode_opt_box_autoadd_u_64(apiObj.amountSat);
+    wireObj.label = cst_encode_opt_String(apiObj.label);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_receive_liquid_response(
+      ReceiveLiquidResponse apiObj, wire_cst_receive_liquid_response wireObj) {
+    wireObj.address = cst_encode_String(apiObj.address);
+    wireObj.bip21 = cst_encode_String(apiObj.bip21);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_receive_onchain_response(
+      ReceiveOnchainResponse apiObj, wire_cst_receive_onchain_response wireObj) {
+    wireObj.id = cst_encode_String(apiObj.id);
+    wireObj.address = cst_encode_String(apiObj.address);
+    wireObj.bip21 = cst_encode_String(apiObj.bip21);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_receive_payment_response(
+      ReceivePaymentResponse apiObj, wire_cst_receive_payment_response wireObj) {
+    wireObj.id = cst_encode_String(apiObj.id);
+    wireObj.invoice = cst_encode_String(apiObj.invoice);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_recommended_fees(RecommendedFees apiObj, wire_cst_recommended_fees wireObj) {
+    wireObj.fastest_fee = cst_encode_f_64(apiObj.fastestFee);
+    wireObj.half_hour_fee = cst_encode_f_64(apiObj.halfHourFee);
+    wireObj.hour_fee = cst_encode_f_64(apiObj.hourFee);
+    wireObj.economy_fee = cst_encode_f_64(apiObj.economyFee);
+    wireObj.minimum_fee = cst_encode_f_64(apiObj.minimumFee);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_recommended_fees_response(
+      RecommendedFeesResponse apiObj, wire_cst_recommended_fees_response wireObj) {
+    cst_api_fill_to_wire_recommended_fees(apiObj.liquid, wireObj.liquid);
+    cst_api_fill_to_wire_recommended_fees(apiObj.bitcoin, wireObj.bitcoin);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_refund_request(RefundRequest apiObj, wire_cst_refund_request wireObj) {
+    wireObj.swap_id = cst_encode_String(apiObj.swapId);
+    wireObj.refund_address = cst_encode_String(apiObj.refundAddress);
+    wireObj.fee_rate_sat_per_vbyte = cst_encode_f_64(apiObj.feeRateSatPerVbyte);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_refund_response(RefundResponse apiObj, wire_cst_refund_response wireObj) {
+    wireObj.refund_tx_id = cst_encode_String(apiObj.refundTxId);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_refundable_swap(RefundableSwap apiObj, wire_cst_refundable_swap wireObj) {
+    wireObj.swap_id = cst_encode_String(apiObj.swapId);
+    wireObj.swap_address = cst_encode_String(apiObj.swapAddress);
+    wireObj.timestamp = cst_encode_u_32(apiObj.timestamp);
+    wireObj.amount_sat = cst_encode_u_64(apiObj.amountSat);
+    wireObj.payment_type = cst_encode_payment_type(apiObj.paymentType);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_restore_request(RestoreRequest apiObj, wire_cst_restore_request wireObj) {
+    wireObj.backup_path = cst_encode_opt_String(apiObj.backupPath);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_route_hint(RouteHint apiObj, wire_cst_route_hint wireObj) {
+    wireObj.hops = cst_encode_list_route_hint_hop(apiObj.hops);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_route_hint_hop(RouteHintHop apiObj, wire_cst_route_hint_hop wireObj) {
+    wireObj.src_node_id = cst_encode_String(apiObj.srcNodeId);
+    wireObj.short_channel_id = cst_encode_u_64(apiObj.shortChannelId);
+    wireObj.fees_base_msat = cst_encode_u_32(apiObj.feesBaseMsat);
+    wireObj.fees_proportional_millionths = cst_encode_u_32(apiObj.feesProportionalMillionths);
+    wireObj.cltv_expiry_delta = cst_encode_u_64(apiObj.cltvExpiryDelta);
+    wireObj.htlc_minimum_msat = cst_encode_opt_box_autoadd_u_64(apiObj.htlcMinimumMsat);
+    wireObj.htlc_maximum_msat = cst_encode_opt_box_autoadd_u_64(apiObj.htlcMaximumMsat);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_send_payment_response(
+      SendPaymentResponse apiObj, wire_cst_send_payment_response wireObj) {
+    cst_api_fill_to_wire_payment(apiObj.payment, wireObj.payment);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_set_payment_metadata_request(
+      SetPaymentMetadataRequest apiObj, wire_cst_set_payment_metadata_request wireObj) {
+    wireObj.id = cst_encode_String(apiObj.id);
+    cst_api_fill_to_wire_payment_metadata(apiObj.metadata, wireObj.metadata);
+  }
+
+  @protected
+  void cst_api_fill_to_wire_success_action(SuccessAction apiObj, wire_cst_success_action wireObj) {
+    if (apiObj is SuccessAction_Message) {
+      var pre_message = cst_encode_String(apiObj.message);
+      wireObj.tag = 0;
+      wireObj.kind.Message.message = pre_message;
+      return;
+    }
+    if (apiObj is SuccessAction_Url) {
+      var pre_description = cst_encode_String(apiObj.description);
+      var pre_url = cst_encode_String(apiObj.url);
+      wireObj.tag = 1;
+      wireObj.kind.Url.description = pre_description;
+      wireObj.kind.Url.url = pre_url;
+      return;
+    }
+    if (apiObj is SuccessAction_Aes) {
+      var pre_description = cst_encode_String(apiObj.description);
+      var pre_ciphertext = cst_encode_String(apiObj.ciphertext);
+      var pre_iv = cst_encode_String(apiObj.iv);
+      wireObj.tag = 2;
+      wireObj.kind.Aes.description = pre_description;
+      wireObj.kind.Aes.ciphertext = pre_ciphertext;
+      wireObj.kind.Aes.iv = pre_iv;
+      return;
+    }
+  }
+
+  @protected
+  void cst_api_fill_to_wire_success_action_processed(
+      SuccessActionProcessed apiObj, wire_cst_success_action_processed wireObj) {
+    if (apiObj is SuccessActionProcessed_Message) {
+      var pre_message = cst_encode_String(apiObj.message);
+      wireObj.tag = 0;
+      wireObj.kind.Message.message = pre_message;
+      return;
+    }
+    if (apiObj is SuccessActionProcessed_Url) {
+      var pre_description = cst_encode_String(apiObj.description);
+      var pre_url = cst_encode_String(apiObj.url);
+      wireObj.tag = 1;
+      wireObj.kind.Url.description = pre_description;
+      wireObj.kind.Url.url = pre_url;
+      return;
+    }
+    if (apiObj is SuccessActionProcessed_Aes) {
+      var pre_description = cst_encode_String(apiObj.description);
+      var pre_plaintext = cst_encode_String(apiObj.plaintext);
+      wireObj.tag = 2;
+      wireObj.kind.Aes.description = pre_description;
+      wireObj.kind.Aes.plaintext = pre_plaintext;
+      return;
+    }
+  }
+
+  @protected
+  int cst_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+      BindingLiquidSdk raw);
+
+  @protected
+  int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+      BindingLiquidSdk raw);
+
+  @protected
+  int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+      BindingLiquidSdk raw);
+
+  @protected
+  bool cst_encode_bool(bool raw);
+
+  @protected
+  double cst_encode_f_32(double raw);
+
+  @protected
+  double cst_encode_f_64(double raw);
+
+  @protected
+  int cst_encode_fee_priority(FeePriority raw);
+
+  @protected
+  int cst_encode_i_32(int raw);
+
+  @protected
+  int cst_encode_network(Network raw);
+
+  @protected
+  int cst_encode_payment_export_format(PaymentExportFormat raw);
+
+  @protected
+  int cst_encode_payment_state(PaymentState raw);
+
+  @protected
+  int cst_encode_payment_swap_type(PaymentSwapType raw);
+
+  @protected
+  int cst_encode_payment_type(PaymentType raw);
+
+  @protected
+  int cst_encode_u_32(int raw);
+
+  @protected
+  int cst_encode_u_8(int raw);
+
+  @protected
+  void cst_encode_unit(void raw);
+
+  @protected
+  void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+      BindingLiquidSdk self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+      BindingLiquidSdk self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
+      BindingLiquidSdk self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_StreamSink_liquid_sdk_event_Dco(
+      RustStreamSink<LiquidSdkEvent> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_StreamSink_log_entry_Dco(RustStreamSink<LogEntry> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_asset_balance(AssetBalance self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_asset_metadata(AssetMetadata self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_backup_request(BackupRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_binding_event_listener(BindingEventListener self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_bitcoin_address_data(BitcoinAddressData self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_asset_metadata(AssetMetadata self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_backup_request(BackupRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_binding_event_listener(BindingEventListener self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_bitcoin_address_data(BitcoinAddressData self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_connect_request(ConnectRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_export_payments_request(ExportPaymentsRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_fee_breakdown(FeeBreakdown self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_get_payment_request(GetPaymentRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_liquid_address_data(LiquidAddressData self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_liquid_sdk_event(LiquidSdkEvent self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_list_payments_request(ListPaymentsRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_ln_invoice(LNInvoice self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_ln_url_withdraw_request(LnUrlWithdrawRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_onchain_fee_rate(OnchainFeeRate self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_pay_onchain_request(PayOnchainRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_payment(Payment self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_payment_metadata(PaymentMetadata self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_prepare_ln_url_pay_request(
+      PrepareLnUrlPayRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_prepare_ln_url_pay_response(
+      PrepareLnUrlPayResponse self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_prepare_pay_onchain_request(
+      PreparePayOnchainRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_prepare_receive_onchain_request(
+      PrepareReceiveOnchainRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_prepare_receive_onchain_response(
+      PrepareReceiveOnchainResponse self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_prepare_receive_request(PrepareReceiveRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_prepare_receive_response(PrepareReceiveResponse self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_prepare_refund_request(PrepareRefundRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_prepare_send_onchain_request(
+      PrepareSendOnchainRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_prepare_send_onchain_response(
+      PrepareSendOnchainResponse self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_prepare_send_request(PrepareSendRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_prepare_send_response(PrepareSendResponse self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_receive_liquid_request(ReceiveLiquidRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_refund_request(RefundRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_restore_request(RestoreRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_set_payment_metadata_request(
+      SetPaymentMetadataRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_success_action(SuccessAction self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_success_action_processed(SuccessActionProcessed self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_config(Config self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_connect_request(ConnectRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_electrum_server(ElectrumServer self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_electrum_server_status(ElectrumServerStatus self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_export_payments_request(ExportPaymentsRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_export_payments_response(ExportPaymentsResponse self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_f_32(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_f_64(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_fee_breakdown(FeeBreakdown self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_fee_priority(FeePriority self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_get_info_response(GetInfoResponse self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_get_payment_request(GetPaymentRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_i_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_input_type(InputType self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_lightning_limits(LightningLimits self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_lightning_payment_limits_response(
+      LightningPaymentLimitsResponse self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_liquid_address_data(LiquidAddressData self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_liquid_sdk_error(LiquidSdkError self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_liquid_sdk_event(LiquidSdkEvent self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_asset_balance(List<AssetBalance> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_asset_metadata(List<AssetMetadata> self, SseSerializer serializer);
 
   @protected
-  int cst_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
-      BindingLiquidSdk raw);
+  void sse_encode_list_electrum_server(List<ElectrumServer> self, SseSerializer serializer);
 
   @protected
-  int cst_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
-      BindingLiquidSdk raw);
+  void sse_encode_list_electrum_server_status(List<ElectrumServerStatus> self, SseSerializer serializer);
 
   @protected
-  double cst_encode_f_32(double raw);
+  void sse_encode_list_payment(List<Payment> self, SseSerializer serializer);
 
   @protected
-  int cst_encode_i_32(int raw);
+  void sse_encode_list_payment_state(List<PaymentState> self, SseSerializer serializer);
 
   @protected
-  int cst_encode_network(Network raw);
+  void sse_encode_list_payment_type(List<PaymentType> self, SseSerializer serializer);
 
   @protected
-  int cst_encode_payment_state(PaymentState raw);
+  void sse_encode_list_payments_request(ListPaymentsRequest self, SseSerializer serializer);
 
   @protected
-  int cst_encode_payment_type(PaymentType raw);
+  void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer);
 
   @protected
-  int cst_encode_u_32(int raw);
+  void sse_encode_list_refundable_swap(List<RefundableSwap> self, SseSerializer serializer);
 
   @protected
-  int cst_encode_u_8(int raw);
+  void sse_encode_list_route_hint(List<RouteHint> self, SseSerializer serializer);
 
   @protected
-  void cst_encode_unit(void raw);
+  void sse_encode_list_route_hint_hop(List<RouteHintHop> self, SseSerializer serializer);
 
   @protected
-  void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer);
+  void sse_encode_ln_invoice(LNInvoice self, SseSerializer serializer);
 
   @protected
-  void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
-      BindingLiquidSdk self, SseSerializer serializer);
+  void sse_encode_ln_url_pay_result(LnUrlPayResult self, SseSerializer serializer);
 
   @protected
-  void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
-      BindingLiquidSdk self, SseSerializer serializer);
+  void sse_encode_ln_url_withdraw_request(LnUrlWithdrawRequest self, SseSerializer serializer);
 
   @protected
-  void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdk(
-      BindingLiquidSdk self, SseSerializer serializer);
+  void sse_encode_ln_url_withdraw_result(LnUrlWithdrawResult self, SseSerializer serializer);
 
   @protected
-  void sse_encode_StreamSink_liquid_sdk_event_Dco(
-      RustStreamSink<LiquidSdkEvent> self, SseSerializer serializer);
+  void sse_encode_log_entry(LogEntry self, SseSerializer serializer);
 
   @protected
-  void sse_encode_StreamSink_log_entry_Dco(RustStreamSink<LogEntry> self, SseSerializer serializer);
+  void sse_encode_network(Network self, SseSerializer serializer);
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer);
+  void sse_encode_onchain_fee_rate(OnchainFeeRate self, SseSerializer serializer);
 
   @protected
-  void sse_encode_backup_request(BackupRequest self, SseSerializer serializer);
+  void sse_encode_opt_String(String? self, SseSerializer serializer);
 
   @protected
-  void sse_encode_binding_event_listener(BindingEventListener self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_asset_metadata(AssetMetadata? self, SseSerializer serializer);
 
   @protected
-  void sse_encode_box_autoadd_backup_request(BackupRequest self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer);
 
   @protected
-  void sse_encode_box_autoadd_binding_event_listener(BindingEventListener self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer);
 
   @protected
-  void sse_encode_box_autoadd_connect_request(ConnectRequest self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_fee_breakdown(FeeBreakdown? self, SseSerializer serializer);
 
   @protected
-  void sse_encode_box_autoadd_liquid_sdk_event(LiquidSdkEvent self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_onchain_fee_rate(OnchainFeeRate? self, SseSerializer serializer);
 
   @protected
-  void sse_encode_box_autoadd_payment(Payment self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_payment(Payment? self, SseSerializer serializer);
 
   @protected
-  void sse_encode_box_autoadd_prepare_receive_request(PrepareReceiveRequest self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_payment_metadata(PaymentMetadata? self, SseSerializer serializer);
 
   @protected
-  void sse_encode_box_autoadd_prepare_receive_response(PrepareReceiveResponse self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_success_action(SuccessAction? self, SseSerializer serializer);
 
   @protected
-  void sse_encode_box_autoadd_prepare_send_request(PrepareSendRequest self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_success_action_processed(
+      SuccessActionProcessed? self, SseSerializer serializer);
 
   @protected
-  void sse_encode_box_autoadd_prepare_send_response(PrepareSendResponse self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
 
   @protected
-  void sse_encode_box_autoadd_restore_request(RestoreRequest self, SseSerializer serializer);
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
 
   @protected
-  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+  void sse_encode_opt_list_asset_metadata(List<AssetMetadata>? self, SseSerializer serializer);
 
   @protected
-  void sse_encode_config(Config self, SseSerializer serializer);
+  void sse_encode_opt_list_payment_state(List<PaymentState>? self, SseSerializer serializer);
 
   @protected
-  void sse_encode_connect_request(ConnectRequest self, SseSerializer serializer);
+  void sse_encode_opt_list_payment_type(List<PaymentType>? self, SseSerializer serializer);
 
   @protected
-  void sse_encode_f_32(double self, SseSerializer serializer);
+  void sse_encode_pay_onchain_request(PayOnchainRequest self, SseSerializer serializer);
 
   @protected
-  void sse_encode_get_info_response(GetInfoResponse self, SseSerializer serializer);
+  void sse_encode_payment(Payment self, SseSerializer serializer);
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
+  void sse_encode_payment_error(PaymentError self, SseSerializer serializer);
 
   @protected
-  void sse_encode_liquid_sdk_error(LiquidSdkError self, SseSerializer serializer);
+  void sse_encode_payment_export_format(PaymentExportFormat self, SseSerializer serializer);
 
   @protected
-  void sse_encode_liquid_sdk_event(LiquidSdkEvent self, SseSerializer serializer);
+  void sse_encode_payment_metadata(PaymentMetadata self, SseSerializer serializer);
 
   @protected
-  void sse_encode_list_payment(List<Payment> self, SseSerializer serializer);
+  void sse_encode_payment_state(PaymentState self, SseSerializer serializer);
 
   @protected
-  void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer);
+  void sse_encode_payment_swap_type(PaymentSwapType self, SseSerializer serializer);
 
   @protected
-  void sse_encode_list_route_hint(List<RouteHint> self, SseSerializer serializer);
+  void sse_encode_payment_type(PaymentType self, SseSerializer serializer);
 
   @protected
-  void sse_encode_list_route_hint_hop(List<RouteHintHop> self, SseSerializer serializer);
+  void sse_encode_prepare_ln_url_pay_request(PrepareLnUrlPayRequest self, SseSerializer serializer);
 
   @protected
-  void sse_encode_ln_invoice(LNInvoice self, SseSerializer serializer);
+  void sse_encode_prepare_ln_url_pay_response(PrepareLnUrlPayResponse self, SseSerializer serializer);
 
   @protected
-  void sse_encode_log_entry(LogEntry self, SseSerializer serializer);
+  void sse_encode_prepare_pay_onchain_request(PreparePayOnchainRequest self, SseSerializer serializer);
 
   @protected
-  void sse_encode_network(Network self, SseSerializer serializer);
+  void sse_encode_prepare_pay_onchain_response(PreparePayOnchainResponse self, SseSerializer serializer);
 
   @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer);
+  void sse_encode_prepare_receive_onchain_request(
+      PrepareReceiveOnchainRequest self, SseSerializer serializer);
 
   @protected
-  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+  void sse_encode_prepare_receive_onchain_response(
+      PrepareReceiveOnchainResponse self, SseSerializer serializer);
 
   @protected
-  void sse_encode_payment(Payment self, SseSerializer serializer);
+  void sse_encode_prepare_receive_request(PrepareReceiveRequest self, SseSerializer serializer);
 
   @protected
-  void sse_encode_payment_error(PaymentError self, SseSerializer serializer);
+  void sse_encode_prepare_receive_response(PrepareReceiveResponse self, SseSerializer serializer);
 
   @protected
-  void sse_encode_payment_state(PaymentState self, SseSerializer serializer);
+  void sse_encode_prepare_refund_request(PrepareRefundRequest self, SseSerializer serializer);
 
   @protected
-  void sse_encode_payment_type(PaymentType self, SseSerializer serializer);
+  void sse_encode_prepare_refund_response(PrepareRefundResponse self, SseSerializer serializer);
 
   @protected
-  void sse_encode_prepare_receive_request(PrepareReceiveRequest self, SseSerializer serializer);
+  void sse_encode_prepare_send_onchain_request(PrepareSendOnchainRequest self, SseSerializer serializer);
 
   @protected
-  void sse_encode_prepare_receive_response(PrepareReceiveResponse self, SseSerializer serializer);
+  void sse_encode_prepare_send_onchain_response(PrepareSendOnchainResponse self, SseSerializer serializer);
 
   @protected
   void sse_encode_prepare_send_request(PrepareSendRequest self, SseSerializer serializer);
@@ -1058,9 +2949,33 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_prepare_send_response(PrepareSendResponse self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_receive_liquid_request(ReceiveLiquidRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_receive_liquid_response(ReceiveLiquidResponse self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_receive_onchain_response(ReceiveOnchainResponse self, SseSerializer serializer);
+
   @protected
   void sse_encode_receive_payment_response(ReceivePaymentResponse self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_recommended_fees(RecommendedFees self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_recommended_fees_response(RecommendedFeesResponse self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_refund_request(RefundRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_refund_response(RefundResponse self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_refundable_swap(RefundableSwap self, SseSerializer serializer);
+
   @protected
   void sse_encode_restore_request(RestoreRequest self, SseSerializer serializer);
 
@@ -1073,6 +2988,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_send_payment_response(SendPaymentResponse self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_set_payment_metadata_request(SetPaymentMetadataRequest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_success_action(SuccessAction self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_success_action_processed(SuccessActionProcessed self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_32(int self, SseSerializer serializer);
 
@@ -1147,86 +3071,335 @@ class RustLibWire implements BaseWire {
       _wire__crate__bindings__BindingLiquidSdk_add_event_listenerPtr
           .asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__BindingLiquidSdk_backup(
+  WireSyncRust2DartDco wire__crate__bindings__BindingLiquidSdk_backup(
+    int that,
+    ffi.Pointer<wire_cst_backup_request> req,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_backup(
+      that,
+      req,
+    );
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_backupPtr = _lookup<
+          ffi
+          .NativeFunction<WireSyncRust2DartDco Function(ffi.UintPtr, ffi.Pointer<wire_cst_backup_request>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_backup');
+  late final _wire__crate__bindings__BindingLiquidSdk_backup =
+      _wire__crate__bindings__BindingLiquidSdk_backupPtr
+          .asFunction<WireSyncRust2DartDco Function(int, ffi.Pointer<wire_cst_backup_request>)>();
+
+  void wire__crate__bindings__BindingLiquidSdk_disconnect(
+    int port_,
+    int that,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_disconnect(
+      port_,
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_disconnectPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.UintPtr)>>(
+          'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_disconnect');
+  late final _wire__crate__bindings__BindingLiquidSdk_disconnect =
+      _wire__crate__bindings__BindingLiquidSdk_disconnectPtr.asFunction<void Function(int, int)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__BindingLiquidSdk_empty_wallet_cache(
+    int that,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_empty_wallet_cache(
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_empty_wallet_cachePtr =
+      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.UintPtr)>>(
+          'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_empty_wallet_cache');
+  late final _wire__crate__bindings__BindingLiquidSdk_empty_wallet_cache =
+      _wire__crate__bindings__BindingLiquidSdk_empty_wallet_cachePtr
+          .asFunction<WireSyncRust2DartDco Function(int)>();
+
+  void wire__crate__bindings__BindingLiquidSdk_export_payments(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_export_payments_request> req,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_export_payments(
+      port_,
+      that,
+      req,
+    );
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_export_paymentsPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_export_payments_request>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_export_payments');
+  late final _wire__crate__bindings__BindingLiquidSdk_export_payments =
+      _wire__crate__bindings__BindingLiquidSdk_export_paymentsPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_export_payments_request>)>();
+
+  void wire__crate__bindings__BindingLiquidSdk_fetch_lightning_limits(
+    int port_,
+    int that,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_fetch_lightning_limits(
+      port_,
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_fetch_lightning_limitsPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.UintPtr)>>(
+          'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fetch_lightning_limits');
+  late final _wire__crate__bindings__BindingLiquidSdk_fetch_lightning_limits =
+      _wire__crate__bindings__BindingLiquidSdk_fetch_lightning_limitsPtr
+          .asFunction<void Function(int, int)>();
+
+  void wire__crate__bindings__BindingLiquidSdk_fetch_recommended_fees(
+    int port_,
+    int that,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_fetch_recommended_fees(
+      port_,
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_fetch_recommended_feesPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.UintPtr)>>(
+          'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fetch_recommended_fees');
+  late final _wire__crate__bindings__BindingLiquidSdk_fetch_recommended_fees =
+      _wire__crate__bindings__BindingLiquidSdk_fetch_recommended_feesPtr
+          .asFunction<void Function(int, int)>();
+
+  void wire__crate__bindings__BindingLiquidSdk_get_info(
+    int port_,
+    int that,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_get_info(
+      port_,
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_get_infoPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.UintPtr)>>(
+          'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_info');
+  late final _wire__crate__bindings__BindingLiquidSdk_get_info =
+      _wire__crate__bindings__BindingLiquidSdk_get_infoPtr.asFunction<void Function(int, int)>();
+
+  void wire__crate__bindings__BindingLiquidSdk_get_payment(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_get_payment_request> req,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_get_payment(
+      port_,
+      that,
+      req,
+    );
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_get_paymentPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_get_payment_request>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_payment');
+  late final _wire__crate__bindings__BindingLiquidSdk_get_payment =
+      _wire__crate__bindings__BindingLiquidSdk_get_paymentPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_get_payment_request>)>();
+
+  void wire__crate__bindings__BindingLiquidSdk_list_electrum_servers(
+    int port_,
+    int that,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_list_electrum_servers(
+      port_,
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_list_electrum_serversPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.UintPtr)>>(
+          'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_electrum_servers');
+  late final _wire__crate__bindings__BindingLiquidSdk_list_electrum_servers =
+      _wire__crate__bindings__BindingLiquidSdk_list_electrum_serversPtr.asFunction<void Function(int, int)>();
+
+  void wire__crate__bindings__BindingLiquidSdk_list_payments(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_list_payments_request> req,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_list_payments(
+      port_,
+      that,
+      req,
+    );
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_list_paymentsPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_list_payments_request>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_payments');
+  late final _wire__crate__bindings__BindingLiquidSdk_list_payments =
+      _wire__crate__bindings__BindingLiquidSdk_list_paymentsPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_payments_request>)>();
+
+  void wire__crate__bindings__BindingLiquidSdk_list_refundables(
+    int port_,
+    int that,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_list_refundables(
+      port_,
+      that,
+    );
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_list_refundablesPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.UintPtr)>>(
+          'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_refundables');
+  late final _wire__crate__bindings__BindingLiquidSdk_list_refundables =
+      _wire__crate__bindings__BindingLiquidSdk_list_refundablesPtr.asFunction<void Function(int, int)>();
+
+  void wire__crate__bindings__BindingLiquidSdk_lnurl_pay(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_prepare_ln_url_pay_response> req,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_lnurl_pay(
+      port_,
+      that,
+      req,
+    );
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_lnurl_payPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_prepare_ln_url_pay_response>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_lnurl_pay');
+  late final _wire__crate__bindings__BindingLiquidSdk_lnurl_pay =
+      _wire__crate__bindings__BindingLiquidSdk_lnurl_payPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_prepare_ln_url_pay_response>)>();
+
+  void wire__crate__bindings__BindingLiquidSdk_lnurl_withdraw(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_ln_url_withdraw_request> req,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_lnurl_withdraw(
+      port_,
+      that,
+      req,
+    );
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_lnurl_withdrawPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_ln_url_withdraw_request>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_lnurl_withdraw');
+  late final _wire__crate__bindings__BindingLiquidSdk_lnurl_withdraw =
+      _wire__crate__bindings__BindingLiquidSdk_lnurl_withdrawPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_ln_url_withdraw_request>)>();
+
+  WireSyncRust2DartDco wire__crate__bindings__BindingLiquidSdk_parse(
     int that,
-    ffi.Pointer<wire_cst_backup_request> req,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> input,
   ) {
-    return _wire__crate__bindings__BindingLiquidSdk_backup(
+    return _wire__crate__bindings__BindingLiquidSdk_parse(
       that,
-      req,
+      input,
     );
   }
 
-  late final _wire__crate__bindings__BindingLiquidSdk_backupPtr = _lookup<
-          ffi
-          .NativeFunction<WireSyncRust2DartDco Function(ffi.UintPtr, ffi.Pointer<wire_cst_backup_request>)>>(
-      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_backup');
-  late final _wire__crate__bindings__BindingLiquidSdk_backup =
-      _wire__crate__bindings__BindingLiquidSdk_backupPtr
-          .asFunction<WireSyncRust2DartDco Function(int, ffi.Pointer<wire_cst_backup_request>)>();
+  late final _wire__crate__bindings__BindingLiquidSdk_parsePtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(ffi.UintPtr, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_parse');
+  late final _wire__crate__bindings__BindingLiquidSdk_parse =
+      _wire__crate__bindings__BindingLiquidSdk_parsePtr
+          .asFunction<WireSyncRust2DartDco Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
 
-  void wire__crate__bindings__BindingLiquidSdk_disconnect(
+  void wire__crate__bindings__BindingLiquidSdk_pay_onchain(
     int port_,
     int that,
+    ffi.Pointer<wire_cst_pay_onchain_request> req,
   ) {
-    return _wire__crate__bindings__BindingLiquidSdk_disconnect(
+    return _wire__crate__bindings__BindingLiquidSdk_pay_onchain(
       port_,
       that,
+      req,
     );
   }
 
-  late final _wire__crate__bindings__BindingLiquidSdk_disconnectPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.UintPtr)>>(
-          'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_disconnect');
-  late final _wire__crate__bindings__BindingLiquidSdk_disconnect =
-      _wire__crate__bindings__BindingLiquidSdk_disconnectPtr.asFunction<void Function(int, int)>();
+  late final _wire__crate__bindings__BindingLiquidSdk_pay_onchainPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_pay_onchain_request>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_pay_onchain');
+  late final _wire__crate__bindings__BindingLiquidSdk_pay_onchain =
+      _wire__crate__bindings__BindingLiquidSdk_pay_onchainPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_pay_onchain_request>)>();
 
-  WireSyncRust2DartDco wire__crate__bindings__BindingLiquidSdk_empty_wallet_cache(
+  void wire__crate__bindings__BindingLiquidSdk_prepare_lnurl_pay(
+    int port_,
     int that,
+    ffi.Pointer<wire_cst_prepare_ln_url_pay_request> req,
   ) {
-    return _wire__crate__bindings__BindingLiquidSdk_empty_wallet_cache(
+    return _wire__crate__bindings__BindingLiquidSdk_prepare_lnurl_pay(
+      port_,
       that,
+      req,
     );
   }
 
-  late final _wire__crate__bindings__BindingLiquidSdk_empty_wallet_cachePtr =
-      _lookup<ffi.NativeFunction<WireSyncRust2DartDco Function(ffi.UintPtr)>>(
-          'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_empty_wallet_cache');
-  late final _wire__crate__bindings__BindingLiquidSdk_empty_wallet_cache =
-      _wire__crate__bindings__BindingLiquidSdk_empty_wallet_cachePtr
-          .asFunction<WireSyncRust2DartDco Function(int)>();
+  late final _wire__crate__bindings__BindingLiquidSdk_prepare_lnurl_payPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_prepare_ln_url_pay_request>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_lnurl_pay');
+  late final _wire__crate__bindings__BindingLiquidSdk_prepare_lnurl_pay =
+      _wire__crate__bindings__BindingLiquidSdk_prepare_lnurl_payPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_prepare_ln_url_pay_request>)>();
 
-  void wire__crate__bindings__BindingLiquidSdk_get_info(
+  void wire__crate__bindings__BindingLiquidSdk_prepare_pay_onchain(
     int port_,
     int that,
+    ffi.Pointer<wire_cst_prepare_pay_onchain_request> req,
   ) {
-    return _wire__crate__bindings__BindingLiquidSdk_get_info(
+    return _wire__crate__bindings__BindingLiquidSdk_prepare_pay_onchain(
       port_,
       that,
+      req,
     );
   }
 
-  late final _wire__crate__bindings__BindingLiquidSdk_get_infoPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.UintPtr)>>(
-          'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_info');
-  late final _wire__crate__bindings__BindingLiquidSdk_get_info =
-      _wire__crate__bindings__BindingLiquidSdk_get_infoPtr.asFunction<void Function(int, int)>();
+  late final _wire__crate__bindings__BindingLiquidSdk_prepare_pay_onchainPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_prepare_pay_onchain_request>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_pay_onchain');
+  late final _wire__crate__bindings__BindingLiquidSdk_prepare_pay_onchain =
+      _wire__crate__bindings__BindingLiquidSdk_prepare_pay_onchainPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_prepare_pay_onchain_request>)>();
 
-  void wire__crate__bindings__BindingLiquidSdk_list_payments(
+  void wire__crate__bindings__BindingLiquidSdk_prepare_receive_onchain(
     int port_,
     int that,
+    ffi.Pointer<wire_cst_prepare_receive_onchain_request> req,
   ) {
-    return _wire__crate__bindings__BindingLiquidSdk_list_payments(
+    return _wire__crate__bindings__BindingLiquidSdk_prepare_receive_onchain(
       port_,
       that,
+      req,
     );
   }
 
-  late final _wire__crate__bindings__BindingLiquidSdk_list_paymentsPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.UintPtr)>>(
-          'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_payments');
-  late final _wire__crate__bindings__BindingLiquidSdk_list_payments =
-      _wire__crate__bindings__BindingLiquidSdk_list_paymentsPtr.asFunction<void Function(int, int)>();
+  late final _wire__crate__bindings__BindingLiquidSdk_prepare_receive_onchainPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(
+                  ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_prepare_receive_onchain_request>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_receive_onchain');
+  late final _wire__crate__bindings__BindingLiquidSdk_prepare_receive_onchain =
+      _wire__crate__bindings__BindingLiquidSdk_prepare_receive_onchainPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_prepare_receive_onchain_request>)>();
 
   void wire__crate__bindings__BindingLiquidSdk_prepare_receive_payment(
     int port_,
@@ -1248,6 +3421,46 @@ class RustLibWire implements BaseWire {
       _wire__crate__bindings__BindingLiquidSdk_prepare_receive_paymentPtr
           .asFunction<void Function(int, int, ffi.Pointer<wire_cst_prepare_receive_request>)>();
 
+  void wire__crate__bindings__BindingLiquidSdk_prepare_refund(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_prepare_refund_request> req,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_prepare_refund(
+      port_,
+      that,
+      req,
+    );
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_prepare_refundPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_prepare_refund_request>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_refund');
+  late final _wire__crate__bindings__BindingLiquidSdk_prepare_refund =
+      _wire__crate__bindings__BindingLiquidSdk_prepare_refundPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_prepare_refund_request>)>();
+
+  void wire__crate__bindings__BindingLiquidSdk_prepare_send_onchain(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_prepare_send_onchain_request> req,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_prepare_send_onchain(
+      port_,
+      that,
+      req,
+    );
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_prepare_send_onchainPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_prepare_send_onchain_request>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_send_onchain');
+  late final _wire__crate__bindings__BindingLiquidSdk_prepare_send_onchain =
+      _wire__crate__bindings__BindingLiquidSdk_prepare_send_onchainPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_prepare_send_onchain_request>)>();
+
   void wire__crate__bindings__BindingLiquidSdk_prepare_send_payment(
     int port_,
     int that,
@@ -1268,6 +3481,47 @@ class RustLibWire implements BaseWire {
       _wire__crate__bindings__BindingLiquidSdk_prepare_send_paymentPtr
           .asFunction<void Function(int, int, ffi.Pointer<wire_cst_prepare_send_request>)>();
 
+  void wire__crate__bindings__BindingLiquidSdk_receive_liquid(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_receive_liquid_request> req,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_receive_liquid(
+      port_,
+      that,
+      req,
+    );
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_receive_liquidPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_receive_liquid_request>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_receive_liquid');
+  late final _wire__crate__bindings__BindingLiquidSdk_receive_liquid =
+      _wire__crate__bindings__BindingLiquidSdk_receive_liquidPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_receive_liquid_request>)>();
+
+  void wire__crate__bindings__BindingLiquidSdk_receive_onchain(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_prepare_receive_onchain_response> req,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_receive_onchain(
+      port_,
+      that,
+      req,
+    );
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_receive_onchainPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(
+                  ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_prepare_receive_onchain_response>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_receive_onchain');
+  late final _wire__crate__bindings__BindingLiquidSdk_receive_onchain =
+      _wire__crate__bindings__BindingLiquidSdk_receive_onchainPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_prepare_receive_onchain_response>)>();
+
   void wire__crate__bindings__BindingLiquidSdk_receive_payment(
     int port_,
     int that,
@@ -1288,6 +3542,26 @@ class RustLibWire implements BaseWire {
       _wire__crate__bindings__BindingLiquidSdk_receive_paymentPtr
           .asFunction<void Function(int, int, ffi.Pointer<wire_cst_prepare_receive_response>)>();
 
+  void wire__crate__bindings__BindingLiquidSdk_refund(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_refund_request> req,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_refund(
+      port_,
+      that,
+      req,
+    );
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_refundPtr = _lookup<
+          ffi
+          .NativeFunction<ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_refund_request>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_refund');
+  late final _wire__crate__bindings__BindingLiquidSdk_refund =
+      _wire__crate__bindings__BindingLiquidSdk_refundPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_refund_request>)>();
+
   WireSyncRust2DartDco wire__crate__bindings__BindingLiquidSdk_restore(
     int that,
     ffi.Pointer<wire_cst_restore_request> req,
@@ -1306,6 +3580,27 @@ class RustLibWire implements BaseWire {
       _wire__crate__bindings__BindingLiquidSdk_restorePtr
           .asFunction<WireSyncRust2DartDco Function(int, ffi.Pointer<wire_cst_restore_request>)>();
 
+  void wire__crate__bindings__BindingLiquidSdk_send_onchain(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_prepare_send_onchain_response> req,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_send_onchain(
+      port_,
+      that,
+      req,
+    );
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_send_onchainPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(
+                  ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_prepare_send_onchain_response>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_send_onchain');
+  late final _wire__crate__bindings__BindingLiquidSdk_send_onchain =
+      _wire__crate__bindings__BindingLiquidSdk_send_onchainPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_prepare_send_onchain_response>)>();
+
   void wire__crate__bindings__BindingLiquidSdk_send_payment(
     int port_,
     int that,
@@ -1326,6 +3621,26 @@ class RustLibWire implements BaseWire {
       _wire__crate__bindings__BindingLiquidSdk_send_paymentPtr
           .asFunction<void Function(int, int, ffi.Pointer<wire_cst_prepare_send_response>)>();
 
+  void wire__crate__bindings__BindingLiquidSdk_set_payment_metadata(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_set_payment_metadata_request> req,
+  ) {
+    return _wire__crate__bindings__BindingLiquidSdk_set_payment_metadata(
+      port_,
+      that,
+      req,
+    );
+  }
+
+  late final _wire__crate__bindings__BindingLiquidSdk_set_payment_metadataPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_set_payment_metadata_request>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_set_payment_metadata');
+  late final _wire__crate__bindings__BindingLiquidSdk_set_payment_metadata =
+      _wire__crate__bindings__BindingLiquidSdk_set_payment_metadataPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_set_payment_metadata_request>)>();
+
   void wire__crate__bindings__BindingLiquidSdk_sync(
     int port_,
     int that,
@@ -1456,6 +3771,16 @@ class RustLibWire implements BaseWire {
       _rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdkPtr
           .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 
+  ffi.Pointer<wire_cst_asset_metadata> cst_new_box_autoadd_asset_metadata() {
+    return _cst_new_box_autoadd_asset_metadata();
+  }
+
+  late final _cst_new_box_autoadd_asset_metadataPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_asset_metadata> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_asset_metadata');
+  late final _cst_new_box_autoadd_asset_metadata =
+      _cst_new_box_autoadd_asset_metadataPtr.asFunction<ffi.Pointer<wire_cst_asset_metadata> Function()>();
+
   ffi.Pointer<wire_cst_backup_request> cst_new_box_autoadd_backup_request() {
     return _cst_new_box_autoadd_backup_request();
   }
@@ -1476,6 +3801,30 @@ class RustLibWire implements BaseWire {
   late final _cst_new_box_autoadd_binding_event_listener = _cst_new_box_autoadd_binding_event_listenerPtr
       .asFunction<ffi.Pointer<wire_cst_binding_event_listener> Function()>();
 
+  ffi.Pointer<wire_cst_bitcoin_address_data> cst_new_box_autoadd_bitcoin_address_data() {
+    return _cst_new_box_autoadd_bitcoin_address_data();
+  }
+
+  late final _cst_new_box_autoadd_bitcoin_address_dataPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_bitcoin_address_data> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_bitcoin_address_data');
+  late final _cst_new_box_autoadd_bitcoin_address_data = _cst_new_box_autoadd_bitcoin_address_dataPtr
+      .asFunction<ffi.Pointer<wire_cst_bitcoin_address_data> Function()>();
+
+  ffi.Pointer<ffi.Bool> cst_new_box_autoadd_bool(
+    bool value,
+  ) {
+    return _cst_new_box_autoadd_bool(
+      value,
+    );
+  }
+
+  late final _cst_new_box_autoadd_boolPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Bool> Function(ffi.Bool)>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_bool');
+  late final _cst_new_box_autoadd_bool =
+      _cst_new_box_autoadd_boolPtr.asFunction<ffi.Pointer<ffi.Bool> Function(bool)>();
+
   ffi.Pointer<wire_cst_connect_request> cst_new_box_autoadd_connect_request() {
     return _cst_new_box_autoadd_connect_request();
   }
@@ -1486,6 +3835,60 @@ class RustLibWire implements BaseWire {
   late final _cst_new_box_autoadd_connect_request =
       _cst_new_box_autoadd_connect_requestPtr.asFunction<ffi.Pointer<wire_cst_connect_request> Function()>();
 
+  ffi.Pointer<wire_cst_export_payments_request> cst_new_box_autoadd_export_payments_request() {
+    return _cst_new_box_autoadd_export_payments_request();
+  }
+
+  late final _cst_new_box_autoadd_export_payments_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_export_payments_request> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_export_payments_request');
+  late final _cst_new_box_autoadd_export_payments_request = _cst_new_box_autoadd_export_payments_requestPtr
+      .asFunction<ffi.Pointer<wire_cst_export_payments_request> Function()>();
+
+  ffi.Pointer<ffi.Double> cst_new_box_autoadd_f_64(
+    double value,
+  ) {
+    return _cst_new_box_autoadd_f_64(
+      value,
+    );
+  }
+
+  late final _cst_new_box_autoadd_f_64Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Double> Function(ffi.Double)>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_f_64');
+  late final _cst_new_box_autoadd_f_64 =
+      _cst_new_box_autoadd_f_64Ptr.asFunction<ffi.Pointer<ffi.Double> Function(double)>();
+
+  ffi.Pointer<wire_cst_fee_breakdown> cst_new_box_autoadd_fee_breakdown() {
+    return _cst_new_box_autoadd_fee_breakdown();
+  }
+
+  late final _cst_new_box_autoadd_fee_breakdownPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_fee_breakdown> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_fee_breakdown');
+  late final _cst_new_box_autoadd_fee_breakdown =
+      _cst_new_box_autoadd_fee_breakdownPtr.asFunction<ffi.Pointer<wire_cst_fee_breakdown> Function()>();
+
+  ffi.Pointer<wire_cst_get_payment_request> cst_new_box_autoadd_get_payment_request() {
+    return _cst_new_box_autoadd_get_payment_request();
+  }
+
+  late final _cst_new_box_autoadd_get_payment_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_get_payment_request> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_get_payment_request');
+  late final _cst_new_box_autoadd_get_payment_request = _cst_new_box_autoadd_get_payment_requestPtr
+      .asFunction<ffi.Pointer<wire_cst_get_payment_request> Function()>();
+
+  ffi.Pointer<wire_cst_liquid_address_data> cst_new_box_autoadd_liquid_address_data() {
+    return _cst_new_box_autoadd_liquid_address_data();
+  }
+
+  late final _cst_new_box_autoadd_liquid_address_dataPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_liquid_address_data> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_liquid_address_data');
+  late final _cst_new_box_autoadd_liquid_address_data = _cst_new_box_autoadd_liquid_address_dataPtr
+      .asFunction<ffi.Pointer<wire_cst_liquid_address_data> Function()>();
+
   ffi.Pointer<wire_cst_liquid_sdk_event> cst_new_box_autoadd_liquid_sdk_event() {
     return _cst_new_box_autoadd_liquid_sdk_event();
   }
@@ -1496,6 +3899,56 @@ class RustLibWire implements BaseWire {
   late final _cst_new_box_autoadd_liquid_sdk_event = _cst_new_box_autoadd_liquid_sdk_eventPtr
       .asFunction<ffi.Pointer<wire_cst_liquid_sdk_event> Function()>();
 
+  ffi.Pointer<wire_cst_list_payments_request> cst_new_box_autoadd_list_payments_request() {
+    return _cst_new_box_autoadd_list_payments_request();
+  }
+
+  late final _cst_new_box_autoadd_list_payments_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_payments_request> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_list_payments_request');
+  late final _cst_new_box_autoadd_list_payments_request = _cst_new_box_autoadd_list_payments_requestPtr
+      .asFunction<ffi.Pointer<wire_cst_list_payments_request> Function()>();
+
+  ffi.Pointer<wire_cst_ln_invoice> cst_new_box_autoadd_ln_invoice() {
+    return _cst_new_box_autoadd_ln_invoice();
+  }
+
+  late final _cst_new_box_autoadd_ln_invoicePtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_ln_invoice> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_ln_invoice');
+  late final _cst_new_box_autoadd_ln_invoice =
+      _cst_new_box_autoadd_ln_invoicePtr.asFunction<ffi.Pointer<wire_cst_ln_invoice> Function()>();
+
+  ffi.Pointer<wire_cst_ln_url_withdraw_request> cst_new_box_autoadd_ln_url_withdraw_request() {
+    return _cst_new_box_autoadd_ln_url_withdraw_request();
+  }
+
+  late final _cst_new_box_autoadd_ln_url_withdraw_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_ln_url_withdraw_request> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_ln_url_withdraw_request');
+  late final _cst_new_box_autoadd_ln_url_withdraw_request = _cst_new_box_autoadd_ln_url_withdraw_requestPtr
+      .asFunction<ffi.Pointer<wire_cst_ln_url_withdraw_request> Function()>();
+
+  ffi.Pointer<wire_cst_onchain_fee_rate> cst_new_box_autoadd_onchain_fee_rate() {
+    return _cst_new_box_autoadd_onchain_fee_rate();
+  }
+
+  late final _cst_new_box_autoadd_onchain_fee_ratePtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_onchain_fee_rate> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_onchain_fee_rate');
+  late final _cst_new_box_autoadd_onchain_fee_rate = _cst_new_box_autoadd_onchain_fee_ratePtr
+      .asFunction<ffi.Pointer<wire_cst_onchain_fee_rate> Function()>();
+
+  ffi.Pointer<wire_cst_pay_onchain_request> cst_new_box_autoadd_pay_onchain_request() {
+    return _cst_new_box_autoadd_pay_onchain_request();
+  }
+
+  late final _cst_new_box_autoadd_pay_onchain_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_pay_onchain_request> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_pay_onchain_request');
+  late final _cst_new_box_autoadd_pay_onchain_request = _cst_new_box_autoadd_pay_onchain_requestPtr
+      .asFunction<ffi.Pointer<wire_cst_pay_onchain_request> Function()>();
+
   ffi.Pointer<wire_cst_payment> cst_new_box_autoadd_payment() {
     return _cst_new_box_autoadd_payment();
   }
@@ -1506,6 +3959,73 @@ class RustLibWire implements BaseWire {
   late final _cst_new_box_autoadd_payment =
       _cst_new_box_autoadd_paymentPtr.asFunction<ffi.Pointer<wire_cst_payment> Function()>();
 
+  ffi.Pointer<wire_cst_payment_metadata> cst_new_box_autoadd_payment_metadata() {
+    return _cst_new_box_autoadd_payment_metadata();
+  }
+
+  late final _cst_new_box_autoadd_payment_metadataPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_payment_metadata> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_payment_metadata');
+  late final _cst_new_box_autoadd_payment_metadata = _cst_new_box_autoadd_payment_metadataPtr
+      .asFunction<ffi.Pointer<wire_cst_payment_metadata> Function()>();
+
+  ffi.Pointer<wire_cst_prepare_ln_url_pay_request> cst_new_box_autoadd_prepare_ln_url_pay_request() {
+    return _cst_new_box_autoadd_prepare_ln_url_pay_request();
+  }
+
+  late final _cst_new_box_autoadd_prepare_ln_url_pay_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_prepare_ln_url_pay_request> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_prepare_ln_url_pay_request');
+  late final _cst_new_box_autoadd_prepare_ln_url_pay_request =
+      _cst_new_box_autoadd_prepare_ln_url_pay_requestPtr
+          .asFunction<ffi.Pointer<wire_cst_prepare_ln_url_pay_request> Function()>();
+
+  ffi.Pointer<wire_cst_prepare_ln_url_pay_response> cst_new_box_autoadd_prepare_ln_url_pay_response() {
+    return _cst_new_box_autoadd_prepare_ln_url_pay_response();
+  }
+
+  late final _cst_new_box_autoadd_prepare_ln_url_pay_responsePtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_prepare_ln_url_pay_response> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_prepare_ln_url_pay_response');
+  late final _cst_new_box_autoadd_prepare_ln_url_pay_response =
+      _cst_new_box_autoadd_prepare_ln_url_pay_responsePtr
+          .asFunction<ffi.Pointer<wire_cst_prepare_ln_url_pay_response> Function()>();
+
+  ffi.Pointer<wire_cst_prepare_pay_onchain_request> cst_new_box_autoadd_prepare_pay_onchain_request() {
+    return _cst_new_box_autoadd_prepare_pay_onchain_request();
+  }
+
+  late final _cst_new_box_autoadd_prepare_pay_onchain_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_prepare_pay_onchain_request> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_prepare_pay_onchain_request');
+  late final _cst_new_box_autoadd_prepare_pay_onchain_request =
+      _cst_new_box_autoadd_prepare_pay_onchain_requestPtr
+          .asFunction<ffi.Pointer<wire_cst_prepare_pay_onchain_request> Function()>();
+
+  ffi.Pointer<wire_cst_prepare_receive_onchain_request>
+      cst_new_box_autoadd_prepare_receive_onchain_request() {
+    return _cst_new_box_autoadd_prepare_receive_onchain_request();
+  }
+
+  late final _cst_new_box_autoadd_prepare_receive_onchain_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_prepare_receive_onchain_request> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_prepare_receive_onchain_request');
+  late final _cst_new_box_autoadd_prepare_receive_onchain_request =
+      _cst_new_box_autoadd_prepare_receive_onchain_requestPtr
+          .asFunction<ffi.Pointer<wire_cst_prepare_receive_onchain_request> Function()>();
+
+  ffi.Pointer<wire_cst_prepare_receive_onchain_response>
+      cst_new_box_autoadd_prepare_receive_onchain_response() {
+    return _cst_new_box_autoadd_prepare_receive_onchain_response();
+  }
+
+  late final _cst_new_box_autoadd_prepare_receive_onchain_responsePtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_prepare_receive_onchain_response> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_prepare_receive_onchain_response');
+  late final _cst_new_box_autoadd_prepare_receive_onchain_response =
+      _cst_new_box_autoadd_prepare_receive_onchain_responsePtr
+          .asFunction<ffi.Pointer<wire_cst_prepare_receive_onchain_response> Function()>();
+
   ffi.Pointer<wire_cst_prepare_receive_request> cst_new_box_autoadd_prepare_receive_request() {
     return _cst_new_box_autoadd_prepare_receive_request();
   }
@@ -1526,6 +4046,38 @@ class RustLibWire implements BaseWire {
   late final _cst_new_box_autoadd_prepare_receive_response = _cst_new_box_autoadd_prepare_receive_responsePtr
       .asFunction<ffi.Pointer<wire_cst_prepare_receive_response> Function()>();
 
+  ffi.Pointer<wire_cst_prepare_refund_request> cst_new_box_autoadd_prepare_refund_request() {
+    return _cst_new_box_autoadd_prepare_refund_request();
+  }
+
+  late final _cst_new_box_autoadd_prepare_refund_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_prepare_refund_request> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_prepare_refund_request');
+  late final _cst_new_box_autoadd_prepare_refund_request = _cst_new_box_autoadd_prepare_refund_requestPtr
+      .asFunction<ffi.Pointer<wire_cst_prepare_refund_request> Function()>();
+
+  ffi.Pointer<wire_cst_prepare_send_onchain_request> cst_new_box_autoadd_prepare_send_onchain_request() {
+    return _cst_new_box_autoadd_prepare_send_onchain_request();
+  }
+
+  late final _cst_new_box_autoadd_prepare_send_onchain_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_prepare_send_onchain_request> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_onchain_request');
+  late final _cst_new_box_autoadd_prepare_send_onchain_request =
+      _cst_new_box_autoadd_prepare_send_onchain_requestPtr
+          .asFunction<ffi.Pointer<wire_cst_prepare_send_onchain_request> Function()>();
+
+  ffi.Pointer<wire_cst_prepare_send_onchain_response> cst_new_box_autoadd_prepare_send_onchain_response() {
+    return _cst_new_box_autoadd_prepare_send_onchain_response();
+  }
+
+  late final _cst_new_box_autoadd_prepare_send_onchain_responsePtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_prepare_send_onchain_response> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_onchain_response');
+  late final _cst_new_box_autoadd_prepare_send_onchain_response =
+      _cst_new_box_autoadd_prepare_send_onchain_responsePtr
+          .asFunction<ffi.Pointer<wire_cst_prepare_send_onchain_response> Function()>();
+
   ffi.Pointer<wire_cst_prepare_send_request> cst_new_box_autoadd_prepare_send_request() {
     return _cst_new_box_autoadd_prepare_send_request();
   }
@@ -1546,6 +4098,26 @@ class RustLibWire implements BaseWire {
   late final _cst_new_box_autoadd_prepare_send_response = _cst_new_box_autoadd_prepare_send_responsePtr
       .asFunction<ffi.Pointer<wire_cst_prepare_send_response> Function()>();
 
+  ffi.Pointer<wire_cst_receive_liquid_request> cst_new_box_autoadd_receive_liquid_request() {
+    return _cst_new_box_autoadd_receive_liquid_request();
+  }
+
+  late final _cst_new_box_autoadd_receive_liquid_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_receive_liquid_request> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_receive_liquid_request');
+  late final _cst_new_box_autoadd_receive_liquid_request = _cst_new_box_autoadd_receive_liquid_requestPtr
+      .asFunction<ffi.Pointer<wire_cst_receive_liquid_request> Function()>();
+
+  ffi.Pointer<wire_cst_refund_request> cst_new_box_autoadd_refund_request() {
+    return _cst_new_box_autoadd_refund_request();
+  }
+
+  late final _cst_new_box_autoadd_refund_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_refund_request> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_refund_request');
+  late final _cst_new_box_autoadd_refund_request =
+      _cst_new_box_autoadd_refund_requestPtr.asFunction<ffi.Pointer<wire_cst_refund_request> Function()>();
+
   ffi.Pointer<wire_cst_restore_request> cst_new_box_autoadd_restore_request() {
     return _cst_new_box_autoadd_restore_request();
   }
@@ -1556,6 +4128,51 @@ class RustLibWire implements BaseWire {
   late final _cst_new_box_autoadd_restore_request =
       _cst_new_box_autoadd_restore_requestPtr.asFunction<ffi.Pointer<wire_cst_restore_request> Function()>();
 
+  ffi.Pointer<wire_cst_set_payment_metadata_request> cst_new_box_autoadd_set_payment_metadata_request() {
+    return _cst_new_box_autoadd_set_payment_metadata_request();
+  }
+
+  late final _cst_new_box_autoadd_set_payment_metadata_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_set_payment_metadata_request> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_set_payment_metadata_request');
+  late final _cst_new_box_autoadd_set_payment_metadata_request =
+      _cst_new_box_autoadd_set_payment_metadata_requestPtr
+          .asFunction<ffi.Pointer<wire_cst_set_payment_metadata_request> Function()>();
+
+  ffi.Pointer<wire_cst_success_action> cst_new_box_autoadd_success_action() {
+    return _cst_new_box_autoadd_success_action();
+  }
+
+  late final _cst_new_box_autoadd_success_actionPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_success_action> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_success_action');
+  late final _cst_new_box_autoadd_success_action =
+      _cst_new_box_autoadd_success_actionPtr.asFunction<ffi.Pointer<wire_cst_success_action> Function()>();
+
+  ffi.Pointer<wire_cst_success_action_processed> cst_new_box_autoadd_success_action_processed() {
+    return _cst_new_box_autoadd_success_action_processed();
+  }
+
+  late final _cst_new_box_autoadd_success_action_processedPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_success_action_processed> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_success_action_processed');
+  late final _cst_new_box_autoadd_success_action_processed = _cst_new_box_autoadd_success_action_processedPtr
+      .asFunction<ffi.Pointer<wire_cst_success_action_processed> Function()>();
+
+  ffi.Pointer<ffi.Uint32> cst_new_box_autoadd_u_32(
+    int value,
+  ) {
+    return _cst_new_box_autoadd_u_32(
+      value,
+    );
+  }
+
+  late final _cst_new_box_autoadd_u_32Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Uint32> Function(ffi.Uint32)>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_u_32');
+  late final _cst_new_box_autoadd_u_32 =
+      _cst_new_box_autoadd_u_32Ptr.asFunction<ffi.Pointer<ffi.Uint32> Function(int)>();
+
   ffi.Pointer<ffi.Uint64> cst_new_box_autoadd_u_64(
     int value,
   ) {
@@ -1564,11 +4181,67 @@ class RustLibWire implements BaseWire {
     );
   }
 
-  late final _cst_new_box_autoadd_u_64Ptr =
-      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Uint64> Function(ffi.Uint64)>>(
-          'frbgen_breez_liquid_cst_new_box_autoadd_u_64');
-  late final _cst_new_box_autoadd_u_64 =
-      _cst_new_box_autoadd_u_64Ptr.asFunction<ffi.Pointer<ffi.Uint64> Function(int)>();
+  late final _cst_new_box_autoadd_u_64Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Uint64> Function(ffi.Uint64)>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_u_64');
+  late final _cst_new_box_autoadd_u_64 =
+      _cst_new_box_autoadd_u_64Ptr.asFunction<ffi.Pointer<ffi.Uint64> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_asset_balance> cst_new_list_asset_balance(
+    int len,
+  ) {
+    return _cst_new_list_asset_balance(
+      len,
+    );
+  }
+
+  late final _cst_new_list_asset_balancePtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_asset_balance> Function(ffi.Int32)>>(
+          'frbgen_breez_liquid_cst_new_list_asset_balance');
+  late final _cst_new_list_asset_balance =
+      _cst_new_list_asset_balancePtr.asFunction<ffi.Pointer<wire_cst_list_asset_balance> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_asset_metadata> cst_new_list_asset_metadata(
+    int len,
+  ) {
+    return _cst_new_list_asset_metadata(
+      len,
+    );
+  }
+
+  late final _cst_new_list_asset_metadataPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_asset_metadata> Function(ffi.Int32)>>(
+          'frbgen_breez_liquid_cst_new_list_asset_metadata');
+  late final _cst_new_list_asset_metadata =
+      _cst_new_list_asset_metadataPtr.asFunction<ffi.Pointer<wire_cst_list_asset_metadata> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_electrum_server> cst_new_list_electrum_server(
+    int len,
+  ) {
+    return _cst_new_list_electrum_server(
+      len,
+    );
+  }
+
+  late final _cst_new_list_electrum_serverPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_electrum_server> Function(ffi.Int32)>>(
+          'frbgen_breez_liquid_cst_new_list_electrum_server');
+  late final _cst_new_list_electrum_server =
+      _cst_new_list_electrum_serverPtr.asFunction<ffi.Pointer<wire_cst_list_electrum_server> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_electrum_server_status> cst_new_list_electrum_server_status(
+    int len,
+  ) {
+    return _cst_new_list_electrum_server_status(
+      len,
+    );
+  }
+
+  late final _cst_new_list_electrum_server_statusPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_electrum_server_status> Function(ffi.Int32)>>(
+          'frbgen_breez_liquid_cst_new_list_electrum_server_status');
+  late final _cst_new_list_electrum_server_status = _cst_new_list_electrum_server_statusPtr
+      .asFunction<ffi.Pointer<wire_cst_list_electrum_server_status> Function(int)>();
 
   ffi.Pointer<wire_cst_list_payment> cst_new_list_payment(
     int len,
@@ -1584,6 +4257,34 @@ class RustLibWire implements BaseWire {
   late final _cst_new_list_payment =
       _cst_new_list_paymentPtr.asFunction<ffi.Pointer<wire_cst_list_payment> Function(int)>();
 
+  ffi.Pointer<wire_cst_list_payment_state> cst_new_list_payment_state(
+    int len,
+  ) {
+    return _cst_new_list_payment_state(
+      len,
+    );
+  }
+
+  late final _cst_new_list_payment_statePtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_payment_state> Function(ffi.Int32)>>(
+          'frbgen_breez_liquid_cst_new_list_payment_state');
+  late final _cst_new_list_payment_state =
+      _cst_new_list_payment_statePtr.asFunction<ffi.Pointer<wire_cst_list_payment_state> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_payment_type> cst_new_list_payment_type(
+    int len,
+  ) {
+    return _cst_new_list_payment_type(
+      len,
+    );
+  }
+
+  late final _cst_new_list_payment_typePtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_payment_type> Function(ffi.Int32)>>(
+          'frbgen_breez_liquid_cst_new_list_payment_type');
+  late final _cst_new_list_payment_type =
+      _cst_new_list_payment_typePtr.asFunction<ffi.Pointer<wire_cst_list_payment_type> Function(int)>();
+
   ffi.Pointer<wire_cst_list_prim_u_8_strict> cst_new_list_prim_u_8_strict(
     int len,
   ) {
@@ -1598,6 +4299,20 @@ class RustLibWire implements BaseWire {
   late final _cst_new_list_prim_u_8_strict =
       _cst_new_list_prim_u_8_strictPtr.asFunction<ffi.Pointer<wire_cst_list_prim_u_8_strict> Function(int)>();
 
+  ffi.Pointer<wire_cst_list_refundable_swap> cst_new_list_refundable_swap(
+    int len,
+  ) {
+    return _cst_new_list_refundable_swap(
+      len,
+    );
+  }
+
+  late final _cst_new_list_refundable_swapPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_refundable_swap> Function(ffi.Int32)>>(
+          'frbgen_breez_liquid_cst_new_list_refundable_swap');
+  late final _cst_new_list_refundable_swap =
+      _cst_new_list_refundable_swapPtr.asFunction<ffi.Pointer<wire_cst_list_refundable_swap> Function(int)>();
+
   ffi.Pointer<wire_cst_list_route_hint> cst_new_list_route_hint(
     int len,
   ) {
@@ -1654,32 +4369,366 @@ final class wire_cst_backup_request extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> backup_path;
 }
 
-final class wire_cst_prepare_receive_request extends ffi.Struct {
+final class wire_cst_list_payment_type extends ffi.Struct {
+  external ffi.Pointer<ffi.Int32> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_list_payment_state extends ffi.Struct {
+  external ffi.Pointer<ffi.Int32> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_list_payments_request extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_payment_type> payment_types;
+
+  external ffi.Pointer<wire_cst_list_payment_state> states;
+
+  external ffi.Pointer<ffi.Uint32> from_timestamp;
+
+  external ffi.Pointer<ffi.Uint32> to_timestamp;
+
+  external ffi.Pointer<ffi.Uint32> offset;
+
+  external ffi.Pointer<ffi.Uint32> limit;
+
+  external ffi.Pointer<ffi.Bool> sort_descending;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> metadata_search;
+}
+
+final class wire_cst_export_payments_request extends ffi.Struct {
+  @ffi.Int32()
+  external int format;
+
+  external wire_cst_list_payments_request filter;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> path;
+}
+
+final class wire_cst_GetPaymentRequest_TxId extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> tx_id;
+}
+
+final class wire_cst_GetPaymentRequest_SwapId extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_id;
+}
+
+final class wire_cst_GetPaymentRequest_Bolt11 extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> bolt11;
+}
+
+final class wire_cst_GetPaymentRequest_PaymentHash extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> payment_hash;
+}
+
+final class GetPaymentRequestKind extends ffi.Union {
+  external wire_cst_GetPaymentRequest_TxId TxId;
+
+  external wire_cst_GetPaymentRequest_SwapId SwapId;
+
+  external wire_cst_GetPaymentRequest_Bolt11 Bolt11;
+
+  external wire_cst_GetPaymentRequest_PaymentHash PaymentHash;
+}
+
+final class wire_cst_get_payment_request extends ffi.Struct {
+  @ffi.Int32()
+  external int tag;
+
+  external GetPaymentRequestKind kind;
+}
+
+final class wire_cst_payment_metadata extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> label;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> note;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> external_id;
+}
+
+final class wire_cst_fee_breakdown extends ffi.Struct {
+  @ffi.Double()
+  external double service_fee_percentage;
+
+  @ffi.Uint64()
+  external int service_fee_sat;
+
+  @ffi.Uint64()
+  external int swapper_miner_fees_sat;
+
+  @ffi.Uint64()
+  external int network_fee_sat;
+
+  @ffi.Uint64()
+  external int total_fees_sat;
+}
+
+final class wire_cst_prepare_send_response extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> invoice;
+
+  @ffi.Uint64()
+  external int fees_sat;
+
+  external ffi.Pointer<wire_cst_payment_metadata> metadata;
+
+  external wire_cst_fee_breakdown fee_breakdown;
+
+  external ffi.Pointer<ffi.Uint64> max_fee_sat;
+
+  external ffi.Pointer<ffi.Double> max_fee_percent;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> pair_hash;
+
+  @ffi.Uint32()
+  external int expires_at;
+
+  external ffi.Pointer<ffi.Double> fee_rate_sat_per_vbyte;
+}
+
+final class wire_cst_SuccessAction_Message extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> message;
+}
+
+final class wire_cst_SuccessAction_Url extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> description;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> url;
+}
+
+final class wire_cst_SuccessAction_Aes extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> description;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> ciphertext;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> iv;
+}
+
+final class SuccessActionKind extends ffi.Union {
+  external wire_cst_SuccessAction_Message Message;
+
+  external wire_cst_SuccessAction_Url Url;
+
+  external wire_cst_SuccessAction_Aes Aes;
+}
+
+final class wire_cst_success_action extends ffi.Struct {
+  @ffi.Int32()
+  external int tag;
+
+  external SuccessActionKind kind;
+}
+
+final class wire_cst_prepare_ln_url_pay_response extends ffi.Struct {
+  external wire_cst_prepare_send_response prepare_send_response;
+
+  external ffi.Pointer<wire_cst_success_action> success_action;
+}
+
+final class wire_cst_ln_url_withdraw_request extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> lnurl;
+
+  @ffi.Uint64()
+  external int amount_sat;
+}
+
+final class wire_cst_prepare_pay_onchain_response extends ffi.Struct {
+  @ffi.Uint64()
+  external int receiver_amount_sat;
+
+  @ffi.Uint64()
+  external int fees_sat;
+
+  external ffi.Pointer<ffi.Double> fee_rate_sat_per_vbyte;
+}
+
+final class wire_cst_pay_onchain_request extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> address;
+
+  external wire_cst_prepare_pay_onchain_response prepare_res;
+}
+
+final class wire_cst_prepare_ln_url_pay_request extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> lnurl;
+
+  @ffi.Uint64()
+  external int amount_sat;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> comment;
+}
+
+final class wire_cst_OnchainFeeRate_SatPerVbyte extends ffi.Struct {
+  @ffi.Double()
+  external double sat_per_vbyte;
+}
+
+final class wire_cst_OnchainFeeRate_Priority extends ffi.Struct {
+  @ffi.Int32()
+  external int priority;
+}
+
+final class OnchainFeeRateKind extends ffi.Union {
+  external wire_cst_OnchainFeeRate_SatPerVbyte SatPerVbyte;
+
+  external wire_cst_OnchainFeeRate_Priority Priority;
+}
+
+final class wire_cst_onchain_fee_rate extends ffi.Struct {
+  @ffi.Int32()
+  external int tag;
+
+  external OnchainFeeRateKind kind;
+}
+
+final class wire_cst_prepare_pay_onchain_request extends ffi.Struct {
+  @ffi.Uint64()
+  external int receiver_amount_sat;
+
+  external ffi.Pointer<wire_cst_onchain_fee_rate> fee_rate;
+}
+
+final class wire_cst_prepare_receive_onchain_request extends ffi.Struct {
   @ffi.Uint64()
   external int payer_amount_sat;
+
+  external ffi.Pointer<wire_cst_onchain_fee_rate> fee_rate;
+}
+
+final class wire_cst_prepare_receive_request extends ffi.Struct {
+  external ffi.Pointer<ffi.Uint64> payer_amount_sat;
+
+  external ffi.Pointer<ffi.Uint64> receiver_amount_sat;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> description;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> description_hash;
+
+  external ffi.Pointer<ffi.Uint64> invoice_expiry_secs;
+
+  external ffi.Pointer<wire_cst_payment_metadata> metadata;
+
+  external ffi.Pointer<ffi.Uint64> max_fee_sat;
+
+  external ffi.Pointer<ffi.Double> max_fee_percent;
+
+  external ffi.Pointer<wire_cst_onchain_fee_rate> fee_rate;
+}
+
+final class wire_cst_prepare_refund_request extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> refund_address;
+
+  external wire_cst_onchain_fee_rate fee_rate;
+}
+
+final class wire_cst_prepare_send_onchain_request extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> destination;
+
+  external ffi.Pointer<ffi.Uint64> amount_sat;
+
+  @ffi.Bool()
+  external bool send_all;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> asset_id;
+
+  external ffi.Pointer<wire_cst_onchain_fee_rate> fee_rate;
 }
 
 final class wire_cst_prepare_send_request extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> invoice;
+
+  external ffi.Pointer<wire_cst_payment_metadata> metadata;
+
+  external ffi.Pointer<ffi.Uint64> max_fee_sat;
+
+  external ffi.Pointer<ffi.Double> max_fee_percent;
+
+  external ffi.Pointer<wire_cst_onchain_fee_rate> fee_rate;
+}
+
+final class wire_cst_receive_liquid_request extends ffi.Struct {
+  external ffi.Pointer<ffi.Uint64> amount_sat;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> label;
+}
+
+final class wire_cst_prepare_receive_onchain_response extends ffi.Struct {
+  @ffi.Uint64()
+  external int payer_amount_sat;
+
+  @ffi.Uint64()
+  external int fees_sat;
+
+  external ffi.Pointer<ffi.Double> fee_rate_sat_per_vbyte;
 }
 
 final class wire_cst_prepare_receive_response extends ffi.Struct {
   @ffi.Uint64()
   external int payer_amount_sat;
 
+  @ffi.Uint64()
+  external int receiver_amount_sat;
+
   @ffi.Uint64()
   external int fees_sat;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> description;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> description_hash;
+
+  external ffi.Pointer<ffi.Uint64> invoice_expiry_secs;
+
+  external ffi.Pointer<wire_cst_payment_metadata> metadata;
+
+  external wire_cst_fee_breakdown fee_breakdown;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> pair_hash;
+
+  @ffi.Uint32()
+  external int expires_at;
+
+  external ffi.Pointer<ffi.Double> fee_rate_sat_per_vbyte;
+}
+
+final class wire_cst_refund_request extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> refund_address;
+
+  @ffi.Double()
+  external double fee_rate_sat_per_vbyte;
 }
 
 final class wire_cst_restore_request extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> backup_path;
 }
 
-final class wire_cst_prepare_send_response extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> invoice;
+final class wire_cst_prepare_send_onchain_response extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> address;
+
+  @ffi.Uint64()
+  external int receiver_amount_sat;
 
   @ffi.Uint64()
   external int fees_sat;
+
+  @ffi.Bool()
+  external bool send_all;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> asset_id;
+
+  external ffi.Pointer<ffi.Double> fee_rate_sat_per_vbyte;
+}
+
+final class wire_cst_set_payment_metadata_request extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> id;
+
+  external wire_cst_payment_metadata metadata;
 }
 
 final class wire_cst_binding_event_listener extends ffi.Struct {
@@ -1691,6 +4740,9 @@ final class wire_cst_payment extends ffi.Struct {
 
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_id;
 
+  @ffi.Int32()
+  external int swap_type;
+
   @ffi.Uint32()
   external int timestamp;
 
@@ -1700,8 +4752,24 @@ final class wire_cst_payment extends ffi.Struct {
   @ffi.Uint64()
   external int fees_sat;
 
+  external ffi.Pointer<ffi.Uint64> network_fees_sat;
+
+  external ffi.Pointer<wire_cst_fee_breakdown> fee_breakdown;
+
+  external ffi.Pointer<ffi.Double> fee_rate_sat_per_vbyte;
+
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> preimage;
 
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> bolt11;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> payment_hash;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> payee_pubkey;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> lockup_tx_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> claim_tx_id;
+
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> refund_tx_id;
 
   external ffi.Pointer<ffi.Uint64> refund_tx_amount_sat;
@@ -1711,6 +4779,16 @@ final class wire_cst_payment extends ffi.Struct {
 
   @ffi.Int32()
   external int status;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> label;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> description;
+
+  external ffi.Pointer<wire_cst_payment_metadata> metadata;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> asset_id;
+
+  external ffi.Pointer<ffi.Uint64> asset_amount;
 }
 
 final class wire_cst_LiquidSdkEvent_PaymentFailed extends ffi.Struct {
@@ -1725,6 +4803,10 @@ final class wire_cst_LiquidSdkEvent_PaymentRefunded extends ffi.Struct {
   external ffi.Pointer<wire_cst_payment> details;
 }
 
+final class wire_cst_LiquidSdkEvent_PaymentRefundable extends ffi.Struct {
+  external ffi.Pointer<wire_cst_payment> details;
+}
+
 final class wire_cst_LiquidSdkEvent_PaymentRefundPending extends ffi.Struct {
   external ffi.Pointer<wire_cst_payment> details;
 }
@@ -1744,6 +4826,8 @@ final class LiquidSdkEventKind extends ffi.Union {
 
   external wire_cst_LiquidSdkEvent_PaymentRefunded PaymentRefunded;
 
+  external wire_cst_LiquidSdkEvent_PaymentRefundable PaymentRefundable;
+
   external wire_cst_LiquidSdkEvent_PaymentRefundPending PaymentRefundPending;
 
   external wire_cst_LiquidSdkEvent_PaymentSucceeded PaymentSucceeded;
@@ -1758,11 +4842,52 @@ final class wire_cst_liquid_sdk_event extends ffi.Struct {
   external LiquidSdkEventKind kind;
 }
 
+final class wire_cst_electrum_server extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> url;
+
+  @ffi.Bool()
+  external bool tls;
+
+  @ffi.Bool()
+  external bool validate_domain;
+}
+
+final class wire_cst_list_electrum_server extends ffi.Struct {
+  external ffi.Pointer<wire_cst_electrum_server> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_asset_metadata extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> asset_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> ticker;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> name;
+
+  @ffi.Uint8()
+  external int precision;
+}
+
+final class wire_cst_list_asset_metadata extends ffi.Struct {
+  external ffi.Pointer<wire_cst_asset_metadata> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
 final class wire_cst_config extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> boltz_url;
 
+  external ffi.Pointer<wire_cst_list_electrum_server> electrum_servers;
+
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> electrum_url;
 
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> bitcoin_electrum_url;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> esplora_url;
+
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> working_dir;
 
   @ffi.Int32()
@@ -1771,10 +4896,21 @@ final class wire_cst_config extends ffi.Struct {
   @ffi.Uint64()
   external int payment_timeout_sec;
 
+  @ffi.Uint64()
+  external int sync_fallback_interval_sec;
+
   @ffi.Float()
   external double zero_conf_min_fee_rate;
 
   external ffi.Pointer<ffi.Uint64> zero_conf_max_amount_sat;
+
+  external ffi.Pointer<wire_cst_list_asset_metadata> asset_metadata;
+
+  external ffi.Pointer<ffi.Uint64> max_fee_sat;
+
+  external ffi.Pointer<ffi.Double> max_fee_percent;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> regtest_policy_asset;
 }
 
 final class wire_cst_connect_request extends ffi.Struct {
@@ -1783,11 +4919,32 @@ final class wire_cst_connect_request extends ffi.Struct {
   external wire_cst_config config;
 }
 
-final class wire_cst_list_payment extends ffi.Struct {
-  external ffi.Pointer<wire_cst_payment> ptr;
+final class wire_cst_bitcoin_address_data extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> address;
 
   @ffi.Int32()
-  external int len;
+  external int network;
+
+  external ffi.Pointer<ffi.Uint64> amount_sat;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> label;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> message;
+}
+
+final class wire_cst_liquid_address_data extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> address;
+
+  @ffi.Int32()
+  external int network;
+
+  external ffi.Pointer<ffi.Uint64> amount_sat;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> asset_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> label;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> message;
 }
 
 final class wire_cst_route_hint_hop extends ffi.Struct {
@@ -1828,6 +4985,139 @@ final class wire_cst_list_route_hint extends ffi.Struct {
   external int len;
 }
 
+final class wire_cst_ln_invoice extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> bolt11;
+
+  @ffi.Int32()
+  external int network;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> payee_pubkey;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> payment_hash;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> description;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> description_hash;
+
+  external ffi.Pointer<ffi.Uint64> amount_msat;
+
+  @ffi.Uint64()
+  external int timestamp;
+
+  @ffi.Uint64()
+  external int expiry;
+
+  external ffi.Pointer<wire_cst_list_route_hint> routing_hints;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> payment_secret;
+
+  @ffi.Uint64()
+  external int min_final_cltv_expiry_delta;
+}
+
+final class wire_cst_SuccessActionProcessed_Message extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> message;
+}
+
+final class wire_cst_SuccessActionProcessed_Url extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> description;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> url;
+}
+
+final class wire_cst_SuccessActionProcessed_Aes extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> description;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> plaintext;
+}
+
+final class SuccessActionProcessedKind extends ffi.Union {
+  external wire_cst_SuccessActionProcessed_Message Message;
+
+  external wire_cst_SuccessActionProcessed_Url Url;
+
+  external wire_cst_SuccessActionProcessed_Aes Aes;
+}
+
+final class wire_cst_success_action_processed extends ffi.Struct {
+  @ffi.Int32()
+  external int tag;
+
+  external SuccessActionProcessedKind kind;
+}
+
+final class wire_cst_asset_balance extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> asset_id;
+
+  @ffi.Uint64()
+  external int balance;
+
+  external ffi.Pointer<wire_cst_asset_metadata> asset_metadata;
+}
+
+final class wire_cst_list_asset_balance extends ffi.Struct {
+  external ffi.Pointer<wire_cst_asset_balance> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_electrum_server_status extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> url;
+
+  @ffi.Bool()
+  external bool in_use;
+
+  @ffi.Bool()
+  external bool healthy;
+
+  @ffi.Uint32()
+  external int consecutive_failures;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> last_error;
+}
+
+final class wire_cst_list_electrum_server_status extends ffi.Struct {
+  external ffi.Pointer<wire_cst_electrum_server_status> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_list_payment extends ffi.Struct {
+  external ffi.Pointer<wire_cst_payment> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_refundable_swap extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_address;
+
+  @ffi.Uint32()
+  external int timestamp;
+
+  @ffi.Uint64()
+  external int amount_sat;
+
+  @ffi.Int32()
+  external int payment_type;
+}
+
+final class wire_cst_list_refundable_swap extends ffi.Struct {
+  external ffi.Pointer<wire_cst_refundable_swap> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_export_payments_response extends ffi.Struct {
+  @ffi.Uint32()
+  external int payments_count;
+}
+
 final class wire_cst_get_info_response extends ffi.Struct {
   @ffi.Uint64()
   external int balance_sat;
@@ -1839,6 +5129,69 @@ final class wire_cst_get_info_response extends ffi.Struct {
   external int pending_receive_sat;
 
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> pubkey;
+
+  external ffi.Pointer<wire_cst_list_asset_balance> asset_balances;
+}
+
+final class wire_cst_InputType_Bolt11 extends ffi.Struct {
+  external ffi.Pointer<wire_cst_ln_invoice> invoice;
+}
+
+final class wire_cst_InputType_LiquidAddress extends ffi.Struct {
+  external ffi.Pointer<wire_cst_liquid_address_data> address;
+}
+
+final class wire_cst_InputType_BitcoinAddress extends ffi.Struct {
+  external ffi.Pointer<wire_cst_bitcoin_address_data> address;
+}
+
+final class wire_cst_InputType_LnUrl extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> url;
+}
+
+final class wire_cst_InputType_LightningAddress extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> address;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> url;
+}
+
+final class InputTypeKind extends ffi.Union {
+  external wire_cst_InputType_Bolt11 Bolt11;
+
+  external wire_cst_InputType_LiquidAddress LiquidAddress;
+
+  external wire_cst_InputType_BitcoinAddress BitcoinAddress;
+
+  external wire_cst_InputType_LnUrl LnUrl;
+
+  external wire_cst_InputType_LightningAddress LightningAddress;
+}
+
+final class wire_cst_input_type extends ffi.Struct {
+  @ffi.Int32()
+  external int tag;
+
+  external InputTypeKind kind;
+}
+
+final class wire_cst_lightning_limits extends ffi.Struct {
+  @ffi.Uint64()
+  external int min_sat;
+
+  @ffi.Uint64()
+  external int max_sat;
+
+  @ffi.Double()
+  external double service_fee_percentage;
+
+  @ffi.Uint64()
+  external int miner_fees_sat;
+}
+
+final class wire_cst_lightning_payment_limits_response extends ffi.Struct {
+  external wire_cst_lightning_limits send;
+
+  external wire_cst_lightning_limits receive;
 }
 
 final class wire_cst_LiquidSdkError_Generic extends ffi.Struct {
@@ -1856,46 +5209,60 @@ final class wire_cst_liquid_sdk_error extends ffi.Struct {
   external LiquidSdkErrorKind kind;
 }
 
-final class wire_cst_ln_invoice extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> bolt11;
-
-  @ffi.Int32()
-  external int network;
-
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> payee_pubkey;
+final class wire_cst_ln_url_pay_result extends ffi.Struct {
+  external wire_cst_payment payment;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> payment_hash;
+  external ffi.Pointer<wire_cst_success_action_processed> success_action;
+}
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> description;
+final class wire_cst_receive_payment_response extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> id;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> description_hash;
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> invoice;
+}
 
-  external ffi.Pointer<ffi.Uint64> amount_msat;
+final class wire_cst_ln_url_withdraw_result extends ffi.Struct {
+  external wire_cst_receive_payment_response receive_payment_response;
+}
 
-  @ffi.Uint64()
-  external int timestamp;
+final class wire_cst_log_entry extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> line;
 
-  @ffi.Uint64()
-  external int expiry;
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> level;
+}
 
-  external ffi.Pointer<wire_cst_list_route_hint> routing_hints;
+final class wire_cst_PaymentError_UnrecognizedInput extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+}
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> payment_secret;
+final class wire_cst_PaymentError_NetworkMismatch extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+}
 
-  @ffi.Uint64()
-  external int min_final_cltv_expiry_delta;
+final class wire_cst_PaymentError_LnUrlError extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
 }
 
-final class wire_cst_log_entry extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> line;
+final class wire_cst_PaymentError_LnUrlServiceUnreachable extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+}
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> level;
+final class wire_cst_PaymentError_LnUrlServiceError extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
 }
 
 final class wire_cst_PaymentError_Generic extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
 }
 
+final class wire_cst_PaymentError_FeeLimitExceeded extends ffi.Struct {
+  @ffi.Uint64()
+  external int fee_sat;
+
+  @ffi.Uint64()
+  external int limit_sat;
+}
+
 final class wire_cst_PaymentError_InvalidInvoice extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
 }
@@ -1923,8 +5290,20 @@ final class wire_cst_PaymentError_SignerError extends ffi.Struct {
 }
 
 final class PaymentErrorKind extends ffi.Union {
+  external wire_cst_PaymentError_UnrecognizedInput UnrecognizedInput;
+
+  external wire_cst_PaymentError_NetworkMismatch NetworkMismatch;
+
+  external wire_cst_PaymentError_LnUrlError LnUrlError;
+
+  external wire_cst_PaymentError_LnUrlServiceUnreachable LnUrlServiceUnreachable;
+
+  external wire_cst_PaymentError_LnUrlServiceError LnUrlServiceError;
+
   external wire_cst_PaymentError_Generic Generic;
 
+  external wire_cst_PaymentError_FeeLimitExceeded FeeLimitExceeded;
+
   external wire_cst_PaymentError_InvalidInvoice InvalidInvoice;
 
   external wire_cst_PaymentError_LwkError LwkError;
@@ -1945,10 +5324,59 @@ final class wire_cst_payment_error extends ffi.Struct {
   external PaymentErrorKind kind;
 }
 
-final class wire_cst_receive_payment_response extends ffi.Struct {
+final class wire_cst_prepare_refund_response extends ffi.Struct {
+  @ffi.Uint32()
+  external int tx_vsize;
+
+  @ffi.Uint64()
+  external int tx_fee_sat;
+
+  @ffi.Uint64()
+  external int refund_amount_sat;
+
+  @ffi.Double()
+  external double fee_rate_sat_per_vbyte;
+}
+
+final class wire_cst_receive_liquid_response extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> address;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> bip21;
+}
+
+final class wire_cst_receive_onchain_response extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> id;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> invoice;
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> address;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> bip21;
+}
+
+final class wire_cst_recommended_fees extends ffi.Struct {
+  @ffi.Double()
+  external double fastest_fee;
+
+  @ffi.Double()
+  external double half_hour_fee;
+
+  @ffi.Double()
+  external double hour_fee;
+
+  @ffi.Double()
+  external double economy_fee;
+
+  @ffi.Double()
+  external double minimum_fee;
+}
+
+final class wire_cst_recommended_fees_response extends ffi.Struct {
+  external wire_cst_recommended_fees liquid;
+
+  external wire_cst_recommended_fees bitcoin;
+}
+
+final class wire_cst_refund_response extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> refund_tx_id;
 }
 
 final class wire_cst_send_payment_response extends ffi.Struct {
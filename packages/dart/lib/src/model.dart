@@ -8,6 +8,68 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
 part 'model.freezed.dart';
 
+/// The balance of a single asset held by the wallet
+class AssetBalance {
+  /// The asset ID, in hex
+  final String assetId;
+
+  /// Balance in the asset's base units. For L-BTC, this is in satoshi.
+  final BigInt balance;
+
+  /// Metadata of the asset, if it's known
+  final AssetMetadata? assetMetadata;
+
+  const AssetBalance({
+    required this.assetId,
+    required this.balance,
+    this.assetMetadata,
+  });
+
+  @override
+  int get hashCode => assetId.hashCode ^ balance.hashCode ^ assetMetadata.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AssetBalance &&
+          runtimeType == other.runtimeType &&
+          assetId == other.assetId &&
+          balance == other.balance &&
+          assetMetadata == other.assetMetadata;
+}
+
+/// Display metadata of a Liquid asset
+class AssetMetadata {
+  /// The asset ID, in hex
+  final String assetId;
+  final String ticker;
+  final String name;
+
+  /// Number of decimals of the asset: an amount in base units is divided by `10^precision`
+  /// to get the amount to display
+  final int precision;
+
+  const AssetMetadata({
+    required this.assetId,
+    required this.ticker,
+    required this.name,
+    required this.precision,
+  });
+
+  @override
+  int get hashCode => assetId.hashCode ^ ticker.hashCode ^ name.hashCode ^ precision.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AssetMetadata &&
+          runtimeType == other.runtimeType &&
+          assetId == other.assetId &&
+          ticker == other.ticker &&
+          name == other.name &&
+          precision == other.precision;
+}
+
 class BackupRequest {
   /// Path to the backup.
   ///
@@ -28,10 +90,57 @@ class BackupRequest {
       other is BackupRequest && runtimeType == other.runtimeType && backupPath == other.backupPath;
 }
 
+/// A BTC address, optionally with the parameters of a BIP21 URI
+class BitcoinAddressData {
+  final String address;
+  final Network network;
+  final BigInt? amountSat;
+  final String? label;
+  final String? message;
+
+  const BitcoinAddressData({
+    required this.address,
+    required this.network,
+    this.amountSat,
+    this.label,
+    this.message,
+  });
+
+  @override
+  int get hashCode =>
+      address.hashCode ^ network.hashCode ^ amountSat.hashCode ^ label.hashCode ^ message.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BitcoinAddressData &&
+          runtimeType == other.runtimeType &&
+          address == other.address &&
+          network == other.network &&
+          amountSat == other.amountSat &&
+          label == other.label &&
+          message == other.message;
+}
+
 /// Configuration for the Liquid SDK
 class Config {
   final String boltzUrl;
-  final String electrumUrl;
+
+  /// Liquid Electrum servers, used for wallet sync and L-BTC swap txs. They are tried in
+  /// order, failing over to the next one when a request fails.
+  final List<ElectrumServer> electrumServers;
+
+  /// Deprecated, use [Config::electrum_servers] instead. If set, this Liquid Electrum server
+  /// is tried first, before [Config::electrum_servers].
+  final String? electrumUrl;
+
+  /// Bitcoin Electrum server, used for the BTC side of chain swaps
+  final String bitcoinElectrumUrl;
+
+  /// Liquid Esplora REST API, like `https://blockstream.info/liquid/api`. If set, it is used
+  /// instead of [Config::electrum_servers] for wallet sync, broadcasts, chain queries and fee
+  /// estimation.
+  final String? esploraUrl;
 
   /// Directory in which all SDK files (DB, log, cache) are stored.
   ///
@@ -42,6 +151,12 @@ class Config {
   /// Send payment timeout. See [crate::sdk::LiquidSdk::send_payment]
   final BigInt paymentTimeoutSec;
 
+  /// Interval of the background wallet sync, in seconds. With Electrum, the wallet is also
+  /// synced whenever a tx pays to or spends from one of its addresses or an ongoing swap
+  /// script, so this is only a fallback. Esplora has no such notifications, so a shorter
+  /// interval may be needed.
+  final BigInt syncFallbackIntervalSec;
+
   /// Zero-conf minimum accepted fee-rate in sat/vbyte
   final double zeroConfMinFeeRate;
 
@@ -49,25 +164,57 @@ class Config {
   /// Defaults to [crate::receive_swap::DEFAULT_ZERO_CONF_MAX_SAT]
   final BigInt? zeroConfMaxAmountSat;
 
+  /// Custom asset metadata, extending the built-in registry of [Config::default_asset_metadata].
+  /// Entries override the built-in ones with the same asset ID.
+  final List<AssetMetadata>? assetMetadata;
+
+  /// Maximum fees of a Lightning payment (Send or Receive), in satoshi. Can be overridden per
+  /// payment.
+  final BigInt? maxFeeSat;
+
+  /// Maximum fees of a Lightning payment (Send or Receive), as a percentage of the invoice
+  /// amount. Can be overridden per payment. Must be a non-negative number.
+  final double? maxFeePercent;
+
+  /// Policy asset (L-BTC) of the Regtest network, in hex. Defaults to the one of a default
+  /// `elementsd` Regtest chain. Ignored on the other networks.
+  final String? regtestPolicyAsset;
+
   const Config({
     required this.boltzUrl,
-    required this.electrumUrl,
+    required this.electrumServers,
+    this.electrumUrl,
+    required this.bitcoinElectrumUrl,
+    this.esploraUrl,
     required this.workingDir,
     required this.network,
     required this.paymentTimeoutSec,
+    required this.syncFallbackIntervalSec,
     required this.zeroConfMinFeeRate,
     this.zeroConfMaxAmountSat,
+    this.assetMetadata,
+    this.maxFeeSat,
+    this.maxFeePercent,
+    this.regtestPolicyAsset,
   });
 
   @override
   int get hashCode =>
       boltzUrl.hashCode ^
+      electrumServers.hashCode ^
       electrumUrl.hashCode ^
+      bitcoinElectrumUrl.hashCode ^
+      esploraUrl.hashCode ^
       workingDir.hashCode ^
       network.hashCode ^
       paymentTimeoutSec.hashCode ^
+      syncFallbackIntervalSec.hashCode ^
       zeroConfMinFeeRate.hashCode ^
-      zeroConfMaxAmountSat.hashCode;
+      zeroConfMaxAmountSat.hashCode ^
+      assetMetadata.hashCode ^
+      maxFeeSat.hashCode ^
+      maxFeePercent.hashCode ^
+      regtestPolicyAsset.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -75,12 +222,20 @@ class Config {
       other is Config &&
           runtimeType == other.runtimeType &&
           boltzUrl == other.boltzUrl &&
+          electrumServers == other.electrumServers &&
           electrumUrl == other.electrumUrl &&
+          bitcoinElectrumUrl == other.bitcoinElectrumUrl &&
+          esploraUrl == other.esploraUrl &&
           workingDir == other.workingDir &&
           network == other.network &&
           paymentTimeoutSec == other.paymentTimeoutSec &&
+          syncFallbackIntervalSec == other.syncFallbackIntervalSec &&
           zeroConfMinFeeRate == other.zeroConfMinFeeRate &&
-          zeroConfMaxAmountSat == other.zeroConfMaxAmountSat;
+          zeroConfMaxAmountSat == other.zeroConfMaxAmountSat &&
+          assetMetadata == other.assetMetadata &&
+          maxFeeSat == other.maxFeeSat &&
+          maxFeePercent == other.maxFeePercent &&
+          regtestPolicyAsset == other.regtestPolicyAsset;
 }
 
 class ConnectRequest {
@@ -104,6 +259,190 @@ class ConnectRequest {
           config == other.config;
 }
 
+/// A Liquid Electrum server, see [Config::electrum_servers]
+class ElectrumServer {
+  /// Host and port of the server, like `blockstream.info:995`
+  final String url;
+
+  /// Whether to connect over TLS
+  final bool tls;
+
+  /// Whether to check that the TLS certificate matches the host. Only disable it for servers
+  /// with self-signed certificates.
+  final bool validateDomain;
+
+  const ElectrumServer({
+    required this.url,
+    required this.tls,
+    required this.validateDomain,
+  });
+
+  @override
+  int get hashCode => url.hashCode ^ tls.hashCode ^ validateDomain.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ElectrumServer &&
+          runtimeType == other.runtimeType &&
+          url == other.url &&
+          tls == other.tls &&
+          validateDomain == other.validateDomain;
+}
+
+/// The health of an [ElectrumServer], as returned by
+/// [crate::sdk::LiquidSdk::list_electrum_servers]
+class ElectrumServerStatus {
+  final String url;
+
+  /// Whether the last successful request went to this server
+  final bool inUse;
+
+  /// Whether the server is tried before the failed ones. A failed server becomes healthy
+  /// again after a cooldown or a successful request.
+  final bool healthy;
+
+  /// Number of failed requests since the last successful one
+  final int consecutiveFailures;
+
+  /// Error of the last failed request, cleared by a successful one
+  final String? lastError;
+
+  const ElectrumServerStatus({
+    required this.url,
+    required this.inUse,
+    required this.healthy,
+    required this.consecutiveFailures,
+    this.lastError,
+  });
+
+  @override
+  int get hashCode =>
+      url.hashCode ^ inUse.hashCode ^ healthy.hashCode ^ consecutiveFailures.hashCode ^ lastError.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ElectrumServerStatus &&
+          runtimeType == other.runtimeType &&
+          url == other.url &&
+          inUse == other.inUse &&
+          healthy == other.healthy &&
+          consecutiveFailures == other.consecutiveFailures &&
+          lastError == other.lastError;
+}
+
+class ExportPaymentsRequest {
+  final PaymentExportFormat format;
+
+  /// The payments to export
+  final ListPaymentsRequest filter;
+
+  /// The file to write, which is replaced if it exists
+  final String path;
+
+  const ExportPaymentsRequest({
+    required this.format,
+    required this.filter,
+    required this.path,
+  });
+
+  @override
+  int get hashCode => format.hashCode ^ filter.hashCode ^ path.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ExportPaymentsRequest &&
+          runtimeType == other.runtimeType &&
+          format == other.format &&
+          filter == other.filter &&
+          path == other.path;
+}
+
+class ExportPaymentsResponse {
+  final int paymentsCount;
+
+  const ExportPaymentsResponse({
+    required this.paymentsCount,
+  });
+
+  @override
+  int get hashCode => paymentsCount.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ExportPaymentsResponse &&
+          runtimeType == other.runtimeType &&
+          paymentsCount == other.paymentsCount;
+}
+
+/// The fees of a Send or Receive Swap, as quoted by the swapper when the swap is prepared
+class FeeBreakdown {
+  /// The swapper service fee, as a percentage of the invoice amount
+  final double serviceFeePercentage;
+  final BigInt serviceFeeSat;
+
+  /// The miner fees of the swapper txs, charged by the swapper: the claim tx of a Send Swap,
+  /// or the lockup tx of a Receive Swap
+  final BigInt swapperMinerFeesSat;
+
+  /// The Liquid network fee of the wallet tx: the lockup tx of a Send Swap, or the claim tx of
+  /// a Receive Swap (estimated by the swapper). For a payment without swap, this is the fee of
+  /// the onchain tx, and the other fees are zero.
+  final BigInt networkFeeSat;
+
+  /// The sum of all the fees above
+  final BigInt totalFeesSat;
+
+  const FeeBreakdown({
+    required this.serviceFeePercentage,
+    required this.serviceFeeSat,
+    required this.swapperMinerFeesSat,
+    required this.networkFeeSat,
+    required this.totalFeesSat,
+  });
+
+  @override
+  int get hashCode =>
+      serviceFeePercentage.hashCode ^
+      serviceFeeSat.hashCode ^
+      swapperMinerFeesSat.hashCode ^
+      networkFeeSat.hashCode ^
+      totalFeesSat.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FeeBreakdown &&
+          runtimeType == other.runtimeType &&
+          serviceFeePercentage == other.serviceFeePercentage &&
+          serviceFeeSat == other.serviceFeeSat &&
+          swapperMinerFeesSat == other.swapperMinerFeesSat &&
+          networkFeeSat == other.networkFeeSat &&
+          totalFeesSat == other.totalFeesSat;
+}
+
+/// How fast an onchain tx should confirm, see [RecommendedFees]
+enum FeePriority {
+  /// Next block
+  fastest,
+
+  /// Within 3 blocks
+  halfHour,
+
+  /// Within 6 blocks
+  hour,
+
+  /// Within a day
+  economy,
+
+  /// The lowest fee rate relayed by the network
+  minimum,
+  ;
+}
+
 class GetInfoResponse {
   /// Usable balance. This is the confirmed onchain balance minus `pending_send_sat`.
   final BigInt balanceSat;
@@ -115,16 +454,24 @@ class GetInfoResponse {
   final BigInt pendingReceiveSat;
   final String pubkey;
 
+  /// Onchain balances of all assets in the wallet, including L-BTC
+  final List<AssetBalance> assetBalances;
+
   const GetInfoResponse({
     required this.balanceSat,
     required this.pendingSendSat,
     required this.pendingReceiveSat,
     required this.pubkey,
+    required this.assetBalances,
   });
 
   @override
   int get hashCode =>
-      balanceSat.hashCode ^ pendingSendSat.hashCode ^ pendingReceiveSat.hashCode ^ pubkey.hashCode;
+      balanceSat.hashCode ^
+      pendingSendSat.hashCode ^
+      pendingReceiveSat.hashCode ^
+      pubkey.hashCode ^
+      assetBalances.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -134,7 +481,156 @@ class GetInfoResponse {
           balanceSat == other.balanceSat &&
           pendingSendSat == other.pendingSendSat &&
           pendingReceiveSat == other.pendingReceiveSat &&
-          pubkey == other.pubkey;
+          pubkey == other.pubkey &&
+          assetBalances == other.assetBalances;
+}
+
+@freezed
+sealed class GetPaymentRequest with _$GetPaymentRequest {
+  const GetPaymentRequest._();
+
+  const factory GetPaymentRequest.txId({
+    required String txId,
+  }) = GetPaymentRequest_TxId;
+  const factory GetPaymentRequest.swapId({
+    required String swapId,
+  }) = GetPaymentRequest_SwapId;
+  /// The invoice of a Send or Receive Swap
+  const factory GetPaymentRequest.bolt11({
+    required String bolt11,
+  }) = GetPaymentRequest_Bolt11;
+  /// The payment hash of the invoice of a Send or Receive Swap
+  const factory GetPaymentRequest.paymentHash({
+    required String paymentHash,
+  }) = GetPaymentRequest_PaymentHash;
+}
+
+@freezed
+sealed class InputType with _$InputType {
+  const InputType._();
+
+  /// A BOLT11 invoice, either on its own or from the `lightning` param of a bitcoin BIP21 URI
+  const factory InputType.bolt11({
+    required LNInvoice invoice,
+  }) = InputType_Bolt11;
+  /// A Liquid address, or a `liquidnetwork:`/`liquidtestnet:` BIP21 URI
+  const factory InputType.liquidAddress({
+    required LiquidAddressData address,
+  }) = InputType_LiquidAddress;
+  /// A BTC address, or a `bitcoin:` BIP21 URI without a `lightning` param
+  const factory InputType.bitcoinAddress({
+    required BitcoinAddressData address,
+  }) = InputType_BitcoinAddress;
+  /// A bech32-encoded LNURL or a LUD-17 URL (`lnurlp://`, `lnurlw://`), decoded to the
+  /// endpoint URL
+  const factory InputType.lnUrl({
+    required String url,
+  }) = InputType_LnUrl;
+  /// A Lightning Address (`user@domain`), with the LNURL-pay endpoint URL it resolves to
+  const factory InputType.lightningAddress({
+    required String address,
+    required String url,
+  }) = InputType_LightningAddress;
+}
+
+class LightningLimits {
+  /// Minimum invoice amount
+  final BigInt minSat;
+
+  /// Maximum invoice amount
+  final BigInt maxSat;
+
+  /// The swapper service fee, as a percentage of the invoice amount
+  final double serviceFeePercentage;
+
+  /// The fixed miner fees, on top of the service fee: for Send, the swapper claim tx fee (the
+  /// fee of the wallet lockup tx comes on top), for Receive, the swapper lockup tx fee and the
+  /// estimated claim tx fee
+  final BigInt minerFeesSat;
+
+  const LightningLimits({
+    required this.minSat,
+    required this.maxSat,
+    required this.serviceFeePercentage,
+    required this.minerFeesSat,
+  });
+
+  @override
+  int get hashCode =>
+      minSat.hashCode ^ maxSat.hashCode ^ serviceFeePercentage.hashCode ^ minerFeesSat.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LightningLimits &&
+          runtimeType == other.runtimeType &&
+          minSat == other.minSat &&
+          maxSat == other.maxSat &&
+          serviceFeePercentage == other.serviceFeePercentage &&
+          minerFeesSat == other.minerFeesSat;
+}
+
+/// The current limits and fees of Lightning payments, see
+/// [LiquidSdk::fetch_lightning_limits](crate::sdk::LiquidSdk::fetch_lightning_limits)
+class LightningPaymentLimitsResponse {
+  final LightningLimits send;
+  final LightningLimits receive;
+
+  const LightningPaymentLimitsResponse({
+    required this.send,
+    required this.receive,
+  });
+
+  @override
+  int get hashCode => send.hashCode ^ receive.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LightningPaymentLimitsResponse &&
+          runtimeType == other.runtimeType &&
+          send == other.send &&
+          receive == other.receive;
+}
+
+/// A Liquid address, optionally with the parameters of a BIP21 URI
+class LiquidAddressData {
+  final String address;
+  final Network network;
+  final BigInt? amountSat;
+  final String? assetId;
+  final String? label;
+  final String? message;
+
+  const LiquidAddressData({
+    required this.address,
+    required this.network,
+    this.amountSat,
+    this.assetId,
+    this.label,
+    this.message,
+  });
+
+  @override
+  int get hashCode =>
+      address.hashCode ^
+      network.hashCode ^
+      amountSat.hashCode ^
+      assetId.hashCode ^
+      label.hashCode ^
+      message.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LiquidAddressData &&
+          runtimeType == other.runtimeType &&
+          address == other.address &&
+          network == other.network &&
+          amountSat == other.amountSat &&
+          assetId == other.assetId &&
+          label == other.label &&
+          message == other.message;
 }
 
 @freezed
@@ -150,6 +646,9 @@ sealed class LiquidSdkEvent with _$LiquidSdkEvent {
   const factory LiquidSdkEvent.paymentRefunded({
     required Payment details,
   }) = LiquidSdkEvent_PaymentRefunded;
+  const factory LiquidSdkEvent.paymentRefundable({
+    required Payment details,
+  }) = LiquidSdkEvent_PaymentRefundable;
   const factory LiquidSdkEvent.paymentRefundPending({
     required Payment details,
   }) = LiquidSdkEvent_PaymentRefundPending;
@@ -162,6 +661,70 @@ sealed class LiquidSdkEvent with _$LiquidSdkEvent {
   const factory LiquidSdkEvent.synced() = LiquidSdkEvent_Synced;
 }
 
+/// Filters and pagination for [LiquidSdk::list_payments](crate::sdk::LiquidSdk::list_payments).
+/// By default, all payments are listed, sorted by ascending timestamp.
+class ListPaymentsRequest {
+  /// Only list payments of these types
+  final List<PaymentType>? paymentTypes;
+
+  /// Only list payments in these states
+  final List<PaymentState>? states;
+
+  /// Epoch time, in seconds. Only list payments with a timestamp from this time on.
+  final int? fromTimestamp;
+
+  /// Epoch time, in seconds. Only list payments with a timestamp before this time.
+  final int? toTimestamp;
+
+  /// Number of payments to skip
+  final int? offset;
+
+  /// Max number of payments to list
+  final int? limit;
+
+  /// Sort by descending timestamp, newest first
+  final bool? sortDescending;
+
+  /// Only list payments whose metadata label, note or external ID contains this text
+  final String? metadataSearch;
+
+  const ListPaymentsRequest({
+    this.paymentTypes,
+    this.states,
+    this.fromTimestamp,
+    this.toTimestamp,
+    this.offset,
+    this.limit,
+    this.sortDescending,
+    this.metadataSearch,
+  });
+
+  @override
+  int get hashCode =>
+      paymentTypes.hashCode ^
+      states.hashCode ^
+      fromTimestamp.hashCode ^
+      toTimestamp.hashCode ^
+      offset.hashCode ^
+      limit.hashCode ^
+      sortDescending.hashCode ^
+      metadataSearch.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ListPaymentsRequest &&
+          runtimeType == other.runtimeType &&
+          paymentTypes == other.paymentTypes &&
+          states == other.states &&
+          fromTimestamp == other.fromTimestamp &&
+          toTimestamp == other.toTimestamp &&
+          offset == other.offset &&
+          limit == other.limit &&
+          sortDescending == other.sortDescending &&
+          metadataSearch == other.metadataSearch;
+}
+
 /// Wrapper for a BOLT11 LN invoice
 class LNInvoice {
   final String bolt11;
@@ -226,6 +789,71 @@ class LNInvoice {
           minFinalCltvExpiryDelta == other.minFinalCltvExpiryDelta;
 }
 
+class LnUrlPayResult {
+  final Payment payment;
+  final SuccessActionProcessed? successAction;
+
+  const LnUrlPayResult({
+    required this.payment,
+    this.successAction,
+  });
+
+  @override
+  int get hashCode => payment.hashCode ^ successAction.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LnUrlPayResult &&
+          runtimeType == other.runtimeType &&
+          payment == other.payment &&
+          successAction == other.successAction;
+}
+
+class LnUrlWithdrawRequest {
+  /// An LNURL-withdraw (bech32 or LUD-17 URL)
+  final String lnurl;
+
+  /// Amount to withdraw. It is the amount of the invoice, so swap fees are deducted from it.
+  final BigInt amountSat;
+
+  const LnUrlWithdrawRequest({
+    required this.lnurl,
+    required this.amountSat,
+  });
+
+  @override
+  int get hashCode => lnurl.hashCode ^ amountSat.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LnUrlWithdrawRequest &&
+          runtimeType == other.runtimeType &&
+          lnurl == other.lnurl &&
+          amountSat == other.amountSat;
+}
+
+class LnUrlWithdrawResult {
+  /// The Receive Swap whose invoice was submitted to the LNURL service. Its `id` is the
+  /// swap id of the resulting [Payment].
+  final ReceivePaymentResponse receivePaymentResponse;
+
+  const LnUrlWithdrawResult({
+    required this.receivePaymentResponse,
+  });
+
+  @override
+  int get hashCode => receivePaymentResponse.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LnUrlWithdrawResult &&
+          runtimeType == other.runtimeType &&
+          receivePaymentResponse == other.receivePaymentResponse;
+}
+
 /// Internal SDK log entry used in the Uniffi and Dart bindings
 class LogEntry {
   final String line;
@@ -251,9 +879,46 @@ enum Network {
 
   /// Testnet Bitcoin and Liquid chains
   testnet,
+
+  /// Local Regtest Bitcoin and Liquid chains, see [Config::regtest]
+  regtest,
   ;
 }
 
+@freezed
+sealed class OnchainFeeRate with _$OnchainFeeRate {
+  const OnchainFeeRate._();
+
+  const factory OnchainFeeRate.satPerVbyte({
+    required double satPerVbyte,
+  }) = OnchainFeeRate_SatPerVbyte;
+  const factory OnchainFeeRate.priority({
+    required FeePriority priority,
+  }) = OnchainFeeRate_Priority;
+}
+
+class PayOnchainRequest {
+  /// The BTC address to send to
+  final String address;
+  final PreparePayOnchainResponse prepareRes;
+
+  const PayOnchainRequest({
+    required this.address,
+    required this.prepareRes,
+  });
+
+  @override
+  int get hashCode => address.hashCode ^ prepareRes.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PayOnchainRequest &&
+          runtimeType == other.runtimeType &&
+          address == other.address &&
+          prepareRes == other.prepareRes;
+}
+
 /// Represents an SDK payment.
 ///
 /// By default, this is an onchain tx. It may represent a swap, if swap metadata is available.
@@ -263,6 +928,9 @@ class Payment {
   /// The swap ID, if any swap is associated with this payment
   final String? swapId;
 
+  /// The kind of swap used for this payment, or [PaymentSwapType::Direct] if none
+  final PaymentSwapType swapType;
+
   /// Composite timestamp that can be used for sorting or displaying the payment.
   ///
   /// If this payment has an associated swap, it is the swap creation time. Otherwise, the point
@@ -290,13 +958,44 @@ class Payment {
   /// - for Receive payments, this is zero
   final BigInt feesSat;
 
+  /// The part of `fees_sat` paid as Liquid network fee for the wallet's own tx: the lockup tx
+  /// of a Send or outgoing Chain Swap, the claim tx of a Receive or incoming Chain Swap, or the
+  /// tx of a direct Send. The rest of `fees_sat` goes to the swapper.
+  ///
+  /// It is `None` while the tx of a swap is not known yet.
+  final BigInt? networkFeesSat;
+
+  /// For a Send or Receive Swap, this is the breakdown of `fees_sat` as quoted when the swap was
+  /// prepared. It is `None` for swaps created by older SDK versions.
+  final FeeBreakdown? feeBreakdown;
+
+  /// The fee rate, in sat/vbyte, chosen for the wallet's own tx (see `network_fees_sat`). It is
+  /// `None` if the wallet default or the swapper estimate was used.
+  final double? feeRateSatPerVbyte;
+
   /// In case of a Send swap, this is the preimage of the paid invoice (proof of payment).
   final String? preimage;
 
-  /// For a Send swap which was refunded, this is the refund tx id
+  /// For a Send or Receive Swap, this is the BOLT11 invoice that was paid or received
+  final String? bolt11;
+
+  /// For a Send or Receive Swap, this is the payment hash of the invoice
+  final String? paymentHash;
+
+  /// For a Send or Receive Swap, this is the pubkey of the invoice payee
+  final String? payeePubkey;
+
+  /// For a swap, this is the tx locking the funds in the swap: the wallet lockup tx of a Send
+  /// or Chain Swap, or the swapper lockup tx of a Receive Swap
+  final String? lockupTxId;
+
+  /// For a Receive or Chain Swap, this is the tx claiming the funds into the wallet
+  final String? claimTxId;
+
+  /// For a Send or Chain swap which was refunded, this is the refund tx id
   final String? refundTxId;
 
-  /// For a Send swap which was refunded, this is the refund amount
+  /// For a Send or Chain swap which was refunded, this is the refund amount
   final BigInt? refundTxAmountSat;
   final PaymentType paymentType;
 
@@ -307,31 +1006,79 @@ class Payment {
   /// If the tx has an associated swap, this is determined by the swap status (pending or complete).
   final PaymentState status;
 
-  const Payment({
-    this.txId,
-    this.swapId,
+  /// For a Receive paying a [LiquidSdk::receive_liquid](crate::sdk::LiquidSdk::receive_liquid)
+  /// request, this is the label of the request
+  final String? label;
+
+  /// For a Send or Receive Swap, this is the description of the invoice, if it has one
+  final String? description;
+
+  /// The user metadata of the payment, if any was set
+  final PaymentMetadata? metadata;
+
+  /// For a payment of an issued asset (e.g. USDt), this is the asset ID. It is `None` for L-BTC
+  /// payments.
+  ///
+  /// In this case `amount_sat` only covers the L-BTC part of the tx: the fees for a Send,
+  /// usually zero for a Receive.
+  final String? assetId;
+
+  /// For a payment of an issued asset, this is the amount in the asset's base units
+  final BigInt? assetAmount;
+
+  const Payment({
+    this.txId,
+    this.swapId,
+    required this.swapType,
     required this.timestamp,
     required this.amountSat,
     required this.feesSat,
+    this.networkFeesSat,
+    this.feeBreakdown,
+    this.feeRateSatPerVbyte,
     this.preimage,
+    this.bolt11,
+    this.paymentHash,
+    this.payeePubkey,
+    this.lockupTxId,
+    this.claimTxId,
     this.refundTxId,
     this.refundTxAmountSat,
     required this.paymentType,
     required this.status,
+    this.label,
+    this.description,
+    this.metadata,
+    this.assetId,
+    this.assetAmount,
   });
 
   @override
   int get hashCode =>
       txId.hashCode ^
       swapId.hashCode ^
+      swapType.hashCode ^
       timestamp.hashCode ^
       amountSat.hashCode ^
       feesSat.hashCode ^
+      networkFeesSat.hashCode ^
+      feeBreakdown.hashCode ^
+      feeRateSatPerVbyte.hashCode ^
       preimage.hashCode ^
+      bolt11.hashCode ^
+      paymentHash.hashCode ^
+      payeePubkey.hashCode ^
+      lockupTxId.hashCode ^
+      claimTxId.hashCode ^
       refundTxId.hashCode ^
       refundTxAmountSat.hashCode ^
       paymentType.hashCode ^
-      status.hashCode;
+      status.hashCode ^
+      label.hashCode ^
+      description.hashCode ^
+      metadata.hashCode ^
+      assetId.hashCode ^
+      assetAmount.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -340,14 +1087,77 @@ class Payment {
           runtimeType == other.runtimeType &&
           txId == other.txId &&
           swapId == other.swapId &&
+          swapType == other.swapType &&
           timestamp == other.timestamp &&
           amountSat == other.amountSat &&
           feesSat == other.feesSat &&
+          networkFeesSat == other.networkFeesSat &&
+          feeBreakdown == other.feeBreakdown &&
+          feeRateSatPerVbyte == other.feeRateSatPerVbyte &&
           preimage == other.preimage &&
+          bolt11 == other.bolt11 &&
+          paymentHash == other.paymentHash &&
+          payeePubkey == other.payeePubkey &&
+          lockupTxId == other.lockupTxId &&
+          claimTxId == other.claimTxId &&
           refundTxId == other.refundTxId &&
           refundTxAmountSat == other.refundTxAmountSat &&
           paymentType == other.paymentType &&
-          status == other.status;
+          status == other.status &&
+          label == other.label &&
+          description == other.description &&
+          metadata == other.metadata &&
+          assetId == other.assetId &&
+          assetAmount == other.assetAmount;
+}
+
+/// The file format of [LiquidSdk::export_payments](crate::sdk::LiquidSdk::export_payments).
+///
+/// Both formats have one entry per payment, with the same columns. The columns only change with
+/// a new format version, which is the `version` column of a CSV row and the top-level `version`
+/// of a JSON export.
+///
+/// Version 1 columns: `timestamp`, `date` (RFC 3339, UTC), `direction`, `status`, `swap_type`,
+/// `amount_sat`, `fees_sat`, `swap_fees_sat`, `network_fees_sat`, `asset_id`, `asset_amount`,
+/// `tx_id`, `swap_id`, `lockup_tx_id`, `claim_tx_id`, `bolt11`, `payment_hash`, `preimage`,
+/// `description`, `refund_tx_id`, `refund_tx_amount_sat`, `label`, `metadata_label`,
+/// `metadata_note`, `metadata_external_id`.
+///
+/// The `direction` is `send` or `receive`. The `status` is one of `created`, `pending`,
+/// `complete`, `failed`, `timed_out` and `refundable`. The `swap_type` is one of `submarine`,
+/// `reverse`, `chain` and `direct`.
+enum PaymentExportFormat {
+  csv,
+  json,
+  ;
+}
+
+/// User data attached to a payment, see
+/// [LiquidSdk::set_payment_metadata](crate::sdk::LiquidSdk::set_payment_metadata)
+class PaymentMetadata {
+  final String? label;
+  final String? note;
+
+  /// An ID from an external system, like an order ID
+  final String? externalId;
+
+  const PaymentMetadata({
+    this.label,
+    this.note,
+    this.externalId,
+  });
+
+  @override
+  int get hashCode => label.hashCode ^ note.hashCode ^ externalId.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PaymentMetadata &&
+          runtimeType == other.runtimeType &&
+          label == other.label &&
+          note == other.note &&
+          externalId == other.externalId;
 }
 
 enum PaymentState {
@@ -369,6 +1179,13 @@ enum PaymentState {
   ///
   /// When the refund tx is broadcast, `refund_tx_id` is set in the swap.
   ///
+  /// ## Chain Swaps
+  ///
+  /// Covers the cases when
+  /// - the user lockup tx was broadcast or seen in the mempool or
+  /// - our claim tx is broadcast or
+  /// - an Outgoing swap refund tx was broadcast
+  ///
   /// ## No swap data available
   ///
   /// If no associated swap is found, this indicates the underlying tx is not confirmed yet.
@@ -382,6 +1199,11 @@ enum PaymentState {
   ///
   /// This is the status when the claim tx is broadcast and we see it in the mempool.
   ///
+  /// ## Chain Swaps
+  ///
+  /// For Incoming swaps, this is the status when the claim tx is confirmed. For Outgoing swaps,
+  /// this is the status when the swapper claimed the user lockup, after our BTC claim.
+  ///
   /// ## No swap data available
   ///
   /// If no associated swap is found, this indicates the underlying tx is confirmed.
@@ -394,6 +1216,11 @@ enum PaymentState {
   /// ## Send Swaps
   ///
   /// This is the status when a swap refund was initiated and the refund tx is confirmed.
+  ///
+  /// ## Chain Swaps
+  ///
+  /// This is the status when the swap failed before any funds were locked up, when an Outgoing
+  /// swap refund tx is confirmed or when an Incoming swap refund tx is broadcast.
   failed,
 
   /// ## Send Swaps
@@ -401,6 +1228,29 @@ enum PaymentState {
   /// This covers the case when the swap state is still Created and the swap fails to reach the
   /// Pending state in time. The TimedOut state indicates the lockup tx should never be broadcast.
   timedOut,
+
+  /// ## Incoming Chain Swaps
+  ///
+  /// This covers the case when the swap failed after the payer locked up BTC. The funds have to
+  /// be refunded manually to a BTC address. See [crate::sdk::LiquidSdk::refund].
+  refundable,
+  ;
+}
+
+/// How a payment was made
+enum PaymentSwapType {
+  /// A Send Swap, paying a Lightning invoice
+  submarine,
+
+  /// A Receive Swap, receiving a Lightning payment
+  reverse,
+
+  /// A Chain Swap, sending or receiving BTC onchain
+  chain,
+
+  /// No swap: a Liquid tx sent or received directly, including invoices paid via their
+  /// magic routing hint (MRH)
+  direct,
   ;
 }
 
@@ -410,35 +1260,284 @@ enum PaymentType {
   ;
 }
 
-class PrepareReceiveRequest {
+class PrepareLnUrlPayRequest {
+  /// An LNURL-pay (bech32 or LUD-17 URL) or a Lightning Address
+  final String lnurl;
+  final BigInt amountSat;
+
+  /// Comment for the payee, if the LNURL service allows it
+  final String? comment;
+
+  const PrepareLnUrlPayRequest({
+    required this.lnurl,
+    required this.amountSat,
+    this.comment,
+  });
+
+  @override
+  int get hashCode => lnurl.hashCode ^ amountSat.hashCode ^ comment.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PrepareLnUrlPayRequest &&
+          runtimeType == other.runtimeType &&
+          lnurl == other.lnurl &&
+          amountSat == other.amountSat &&
+          comment == other.comment;
+}
+
+class PrepareLnUrlPayResponse {
+  /// The prepared payment of the invoice returned by the LNURL service
+  final PrepareSendResponse prepareSendResponse;
+
+  /// The success action to process once the payment succeeds
+  final SuccessAction? successAction;
+
+  const PrepareLnUrlPayResponse({
+    required this.prepareSendResponse,
+    this.successAction,
+  });
+
+  @override
+  int get hashCode => prepareSendResponse.hashCode ^ successAction.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PrepareLnUrlPayResponse &&
+          runtimeType == other.runtimeType &&
+          prepareSendResponse == other.prepareSendResponse &&
+          successAction == other.successAction;
+}
+
+class PreparePayOnchainRequest {
+  /// Amount the BTC destination address should receive
+  final BigInt receiverAmountSat;
+
+  /// Fee rate of the L-BTC lockup tx. If not set, the wallet default is used.
+  final OnchainFeeRate? feeRate;
+
+  const PreparePayOnchainRequest({
+    required this.receiverAmountSat,
+    this.feeRate,
+  });
+
+  @override
+  int get hashCode => receiverAmountSat.hashCode ^ feeRate.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PreparePayOnchainRequest &&
+          runtimeType == other.runtimeType &&
+          receiverAmountSat == other.receiverAmountSat &&
+          feeRate == other.feeRate;
+}
+
+class PreparePayOnchainResponse {
+  final BigInt receiverAmountSat;
+
+  /// Total fees: swap service fee, swapper miner fees, BTC claim fee and L-BTC lockup fee
+  final BigInt feesSat;
+
+  /// The fee rate of the L-BTC lockup tx in sat/vbyte, or `None` for the wallet default
+  final double? feeRateSatPerVbyte;
+
+  const PreparePayOnchainResponse({
+    required this.receiverAmountSat,
+    required this.feesSat,
+    this.feeRateSatPerVbyte,
+  });
+
+  @override
+  int get hashCode => receiverAmountSat.hashCode ^ feesSat.hashCode ^ feeRateSatPerVbyte.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PreparePayOnchainResponse &&
+          runtimeType == other.runtimeType &&
+          receiverAmountSat == other.receiverAmountSat &&
+          feesSat == other.feesSat &&
+          feeRateSatPerVbyte == other.feeRateSatPerVbyte;
+}
+
+class PrepareReceiveOnchainRequest {
+  /// Amount of BTC the payer will send to the swap lockup address
   final BigInt payerAmountSat;
 
-  const PrepareReceiveRequest({
+  /// Fee rate of the L-BTC claim tx. If not set, the claim fee estimated by the swapper is
+  /// used.
+  final OnchainFeeRate? feeRate;
+
+  const PrepareReceiveOnchainRequest({
     required this.payerAmountSat,
+    this.feeRate,
   });
 
   @override
-  int get hashCode => payerAmountSat.hashCode;
+  int get hashCode => payerAmountSat.hashCode ^ feeRate.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PrepareReceiveOnchainRequest &&
+          runtimeType == other.runtimeType &&
+          payerAmountSat == other.payerAmountSat &&
+          feeRate == other.feeRate;
+}
+
+class PrepareReceiveOnchainResponse {
+  final BigInt payerAmountSat;
+
+  /// Total fees: swap service fee, swapper miner fees and L-BTC claim fee
+  final BigInt feesSat;
+
+  /// The fee rate of the L-BTC claim tx in sat/vbyte, or `None` for the swapper estimate
+  final double? feeRateSatPerVbyte;
+
+  const PrepareReceiveOnchainResponse({
+    required this.payerAmountSat,
+    required this.feesSat,
+    this.feeRateSatPerVbyte,
+  });
+
+  @override
+  int get hashCode => payerAmountSat.hashCode ^ feesSat.hashCode ^ feeRateSatPerVbyte.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PrepareReceiveOnchainResponse &&
+          runtimeType == other.runtimeType &&
+          payerAmountSat == other.payerAmountSat &&
+          feesSat == other.feesSat &&
+          feeRateSatPerVbyte == other.feeRateSatPerVbyte;
+}
+
+class PrepareReceiveRequest {
+  /// The invoice amount. Exactly one of `payer_amount_sat` and `receiver_amount_sat` must be
+  /// set.
+  final BigInt? payerAmountSat;
+
+  /// The amount to receive after fees. The invoice amount is derived from it.
+  final BigInt? receiverAmountSat;
+
+  /// Description of the invoice. Cannot be set together with `description_hash`.
+  final String? description;
+
+  /// Hex-encoded SHA256 hash of the invoice description, as needed for example by LNURL-pay
+  /// services. Cannot be set together with `description`.
+  final String? descriptionHash;
+
+  /// Invoice expiry in seconds. If not set, the swapper default is used.
+  final BigInt? invoiceExpirySecs;
+
+  /// Stored with the Receive Swap once it is created
+  final PaymentMetadata? metadata;
+
+  /// Overrides the `max_fee_sat` of the [Config]
+  final BigInt? maxFeeSat;
+
+  /// Overrides the `max_fee_percent` of the [Config]
+  final double? maxFeePercent;
+
+  /// Fee rate of the claim tx. If not set, the claim fee estimated by the swapper is used.
+  final OnchainFeeRate? feeRate;
+
+  const PrepareReceiveRequest({
+    this.payerAmountSat,
+    this.receiverAmountSat,
+    this.description,
+    this.descriptionHash,
+    this.invoiceExpirySecs,
+    this.metadata,
+    this.maxFeeSat,
+    this.maxFeePercent,
+    this.feeRate,
+  });
+
+  @override
+  int get hashCode =>
+      payerAmountSat.hashCode ^
+      receiverAmountSat.hashCode ^
+      description.hashCode ^
+      descriptionHash.hashCode ^
+      invoiceExpirySecs.hashCode ^
+      metadata.hashCode ^
+      maxFeeSat.hashCode ^
+      maxFeePercent.hashCode ^
+      feeRate.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
       other is PrepareReceiveRequest &&
           runtimeType == other.runtimeType &&
-          payerAmountSat == other.payerAmountSat;
+          payerAmountSat == other.payerAmountSat &&
+          receiverAmountSat == other.receiverAmountSat &&
+          description == other.description &&
+          descriptionHash == other.descriptionHash &&
+          invoiceExpirySecs == other.invoiceExpirySecs &&
+          metadata == other.metadata &&
+          maxFeeSat == other.maxFeeSat &&
+          maxFeePercent == other.maxFeePercent &&
+          feeRate == other.feeRate;
 }
 
 class PrepareReceiveResponse {
+  /// The invoice amount
   final BigInt payerAmountSat;
+
+  /// The amount received after fees, `payer_amount_sat` minus `fees_sat`
+  final BigInt receiverAmountSat;
   final BigInt feesSat;
+  final String? description;
+  final String? descriptionHash;
+  final BigInt? invoiceExpirySecs;
+  final PaymentMetadata? metadata;
+
+  /// The parts of `fees_sat`
+  final FeeBreakdown feeBreakdown;
+
+  /// The hash of the swapper pair the fees were quoted from
+  final String pairHash;
+
+  /// Epoch time, in seconds, until which the quoted fees are honored. Afterwards, receiving
+  /// fails with [PaymentError::InvalidOrExpiredFees] and the payment must be prepared again.
+  final int expiresAt;
+
+  /// The fee rate of the claim tx in sat/vbyte, or `None` for the swapper estimate
+  final double? feeRateSatPerVbyte;
 
   const PrepareReceiveResponse({
     required this.payerAmountSat,
+    required this.receiverAmountSat,
     required this.feesSat,
+    this.description,
+    this.descriptionHash,
+    this.invoiceExpirySecs,
+    this.metadata,
+    required this.feeBreakdown,
+    required this.pairHash,
+    required this.expiresAt,
+    this.feeRateSatPerVbyte,
   });
 
   @override
-  int get hashCode => payerAmountSat.hashCode ^ feesSat.hashCode;
+  int get hashCode =>
+      payerAmountSat.hashCode ^
+      receiverAmountSat.hashCode ^
+      feesSat.hashCode ^
+      description.hashCode ^
+      descriptionHash.hashCode ^
+      invoiceExpirySecs.hashCode ^
+      metadata.hashCode ^
+      feeBreakdown.hashCode ^
+      pairHash.hashCode ^
+      expiresAt.hashCode ^
+      feeRateSatPerVbyte.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -446,36 +1545,254 @@ class PrepareReceiveResponse {
       other is PrepareReceiveResponse &&
           runtimeType == other.runtimeType &&
           payerAmountSat == other.payerAmountSat &&
-          feesSat == other.feesSat;
+          receiverAmountSat == other.receiverAmountSat &&
+          feesSat == other.feesSat &&
+          description == other.description &&
+          descriptionHash == other.descriptionHash &&
+          invoiceExpirySecs == other.invoiceExpirySecs &&
+          metadata == other.metadata &&
+          feeBreakdown == other.feeBreakdown &&
+          pairHash == other.pairHash &&
+          expiresAt == other.expiresAt &&
+          feeRateSatPerVbyte == other.feeRateSatPerVbyte;
+}
+
+class PrepareRefundRequest {
+  final String swapId;
+
+  /// The address the refunded funds are sent to
+  final String refundAddress;
+
+  /// Fee rate of the refund tx, on the chain of the refund address
+  final OnchainFeeRate feeRate;
+
+  const PrepareRefundRequest({
+    required this.swapId,
+    required this.refundAddress,
+    required this.feeRate,
+  });
+
+  @override
+  int get hashCode => swapId.hashCode ^ refundAddress.hashCode ^ feeRate.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PrepareRefundRequest &&
+          runtimeType == other.runtimeType &&
+          swapId == other.swapId &&
+          refundAddress == other.refundAddress &&
+          feeRate == other.feeRate;
+}
+
+class PrepareRefundResponse {
+  final int txVsize;
+  final BigInt txFeeSat;
+
+  /// Amount the refund address will receive
+  final BigInt refundAmountSat;
+
+  /// The fee rate of the refund tx, in sat/vbyte
+  final double feeRateSatPerVbyte;
+
+  const PrepareRefundResponse({
+    required this.txVsize,
+    required this.txFeeSat,
+    required this.refundAmountSat,
+    required this.feeRateSatPerVbyte,
+  });
+
+  @override
+  int get hashCode =>
+      txVsize.hashCode ^ txFeeSat.hashCode ^ refundAmountSat.hashCode ^ feeRateSatPerVbyte.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PrepareRefundResponse &&
+          runtimeType == other.runtimeType &&
+          txVsize == other.txVsize &&
+          txFeeSat == other.txFeeSat &&
+          refundAmountSat == other.refundAmountSat &&
+          feeRateSatPerVbyte == other.feeRateSatPerVbyte;
+}
+
+class PrepareSendOnchainRequest {
+  /// A Liquid address, or a BIP21 URI like `liquidnetwork:<address>?amount=<amount>`
+  final String destination;
+
+  /// Amount to send. If not set, the amount of the BIP21 URI is used.
+  final BigInt? amountSat;
+
+  /// If set, the whole wallet balance is sent, minus fees, and `amount_sat` is ignored
+  final bool sendAll;
+
+  /// The issued asset to send. If not set, the asset of the BIP21 URI is used, else L-BTC.
+  ///
+  /// For an issued asset, `amount_sat` is in the asset's base units.
+  final String? assetId;
+
+  /// Fee rate of the tx. If not set, the wallet default is used.
+  final OnchainFeeRate? feeRate;
+
+  const PrepareSendOnchainRequest({
+    required this.destination,
+    this.amountSat,
+    required this.sendAll,
+    this.assetId,
+    this.feeRate,
+  });
+
+  @override
+  int get hashCode =>
+      destination.hashCode ^ amountSat.hashCode ^ sendAll.hashCode ^ assetId.hashCode ^ feeRate.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PrepareSendOnchainRequest &&
+          runtimeType == other.runtimeType &&
+          destination == other.destination &&
+          amountSat == other.amountSat &&
+          sendAll == other.sendAll &&
+          assetId == other.assetId &&
+          feeRate == other.feeRate;
+}
+
+class PrepareSendOnchainResponse {
+  /// The Liquid address to send to
+  final String address;
+
+  /// Amount that will be received. For an issued asset, this is in the asset's base units.
+  final BigInt receiverAmountSat;
+
+  /// The L-BTC tx fees
+  final BigInt feesSat;
+  final bool sendAll;
+
+  /// The issued asset to send, or `None` for L-BTC
+  final String? assetId;
+
+  /// The fee rate of the tx in sat/vbyte, or `None` for the wallet default
+  final double? feeRateSatPerVbyte;
+
+  const PrepareSendOnchainResponse({
+    required this.address,
+    required this.receiverAmountSat,
+    required this.feesSat,
+    required this.sendAll,
+    this.assetId,
+    this.feeRateSatPerVbyte,
+  });
+
+  @override
+  int get hashCode =>
+      address.hashCode ^
+      receiverAmountSat.hashCode ^
+      feesSat.hashCode ^
+      sendAll.hashCode ^
+      assetId.hashCode ^
+      feeRateSatPerVbyte.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PrepareSendOnchainResponse &&
+          runtimeType == other.runtimeType &&
+          address == other.address &&
+          receiverAmountSat == other.receiverAmountSat &&
+          feesSat == other.feesSat &&
+          sendAll == other.sendAll &&
+          assetId == other.assetId &&
+          feeRateSatPerVbyte == other.feeRateSatPerVbyte;
 }
 
 class PrepareSendRequest {
   final String invoice;
 
+  /// Stored with the payment once it is sent
+  final PaymentMetadata? metadata;
+
+  /// Overrides the `max_fee_sat` of the [Config]
+  final BigInt? maxFeeSat;
+
+  /// Overrides the `max_fee_percent` of the [Config]
+  final double? maxFeePercent;
+
+  /// Fee rate of the lockup tx, or of the onchain tx if the invoice has an MRH. If not set, the
+  /// wallet default is used.
+  final OnchainFeeRate? feeRate;
+
   const PrepareSendRequest({
     required this.invoice,
+    this.metadata,
+    this.maxFeeSat,
+    this.maxFeePercent,
+    this.feeRate,
   });
 
   @override
-  int get hashCode => invoice.hashCode;
+  int get hashCode =>
+      invoice.hashCode ^ metadata.hashCode ^ maxFeeSat.hashCode ^ maxFeePercent.hashCode ^ feeRate.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is PrepareSendRequest && runtimeType == other.runtimeType && invoice == other.invoice;
+      other is PrepareSendRequest &&
+          runtimeType == other.runtimeType &&
+          invoice == other.invoice &&
+          metadata == other.metadata &&
+          maxFeeSat == other.maxFeeSat &&
+          maxFeePercent == other.maxFeePercent &&
+          feeRate == other.feeRate;
 }
 
 class PrepareSendResponse {
   final String invoice;
   final BigInt feesSat;
+  final PaymentMetadata? metadata;
+
+  /// The parts of `fees_sat`
+  final FeeBreakdown feeBreakdown;
+
+  /// The fee limits of the [PrepareSendRequest], checked again when sending
+  final BigInt? maxFeeSat;
+  final double? maxFeePercent;
+
+  /// The hash of the swapper pair the fees were quoted from
+  final String pairHash;
+
+  /// Epoch time, in seconds, until which the quoted swapper fees are honored. Afterwards,
+  /// sending fails with [PaymentError::InvalidOrExpiredFees] and the payment must be prepared
+  /// again.
+  final int expiresAt;
+
+  /// The fee rate of the wallet tx in sat/vbyte, or `None` for the wallet default
+  final double? feeRateSatPerVbyte;
 
   const PrepareSendResponse({
     required this.invoice,
     required this.feesSat,
+    this.metadata,
+    required this.feeBreakdown,
+    this.maxFeeSat,
+    this.maxFeePercent,
+    required this.pairHash,
+    required this.expiresAt,
+    this.feeRateSatPerVbyte,
   });
 
   @override
-  int get hashCode => invoice.hashCode ^ feesSat.hashCode;
+  int get hashCode =>
+      invoice.hashCode ^
+      feesSat.hashCode ^
+      metadata.hashCode ^
+      feeBreakdown.hashCode ^
+      maxFeeSat.hashCode ^
+      maxFeePercent.hashCode ^
+      pairHash.hashCode ^
+      expiresAt.hashCode ^
+      feeRateSatPerVbyte.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -483,7 +1800,91 @@ class PrepareSendResponse {
       other is PrepareSendResponse &&
           runtimeType == other.runtimeType &&
           invoice == other.invoice &&
-          feesSat == other.feesSat;
+          feesSat == other.feesSat &&
+          metadata == other.metadata &&
+          feeBreakdown == other.feeBreakdown &&
+          maxFeeSat == other.maxFeeSat &&
+          maxFeePercent == other.maxFeePercent &&
+          pairHash == other.pairHash &&
+          expiresAt == other.expiresAt &&
+          feeRateSatPerVbyte == other.feeRateSatPerVbyte;
+}
+
+class ReceiveLiquidRequest {
+  /// Amount the payer should send. If not set, the payer chooses the amount.
+  final BigInt? amountSat;
+
+  /// Label shown on the payment once received, and added to the BIP21 URI
+  final String? label;
+
+  const ReceiveLiquidRequest({
+    this.amountSat,
+    this.label,
+  });
+
+  @override
+  int get hashCode => amountSat.hashCode ^ label.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ReceiveLiquidRequest &&
+          runtimeType == other.runtimeType &&
+          amountSat == other.amountSat &&
+          label == other.label;
+}
+
+class ReceiveLiquidResponse {
+  /// The wallet L-BTC address the payer has to send funds to
+  final String address;
+
+  /// BIP21 URI for the address, including the amount and label
+  final String bip21;
+
+  const ReceiveLiquidResponse({
+    required this.address,
+    required this.bip21,
+  });
+
+  @override
+  int get hashCode => address.hashCode ^ bip21.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ReceiveLiquidResponse &&
+          runtimeType == other.runtimeType &&
+          address == other.address &&
+          bip21 == other.bip21;
+}
+
+class ReceiveOnchainResponse {
+  /// The swap ID
+  final String id;
+
+  /// The BTC lockup address the payer has to send funds to
+  final String address;
+
+  /// BIP21 URI for the lockup address, including the amount
+  final String bip21;
+
+  const ReceiveOnchainResponse({
+    required this.id,
+    required this.address,
+    required this.bip21,
+  });
+
+  @override
+  int get hashCode => id.hashCode ^ address.hashCode ^ bip21.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ReceiveOnchainResponse &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          address == other.address &&
+          bip21 == other.bip21;
 }
 
 class ReceivePaymentResponse {
@@ -507,6 +1908,151 @@ class ReceivePaymentResponse {
           invoice == other.invoice;
 }
 
+/// Fee rates of a chain, in sat/vbyte, estimated by its Electrum server. The estimates are never
+/// lower than `minimum_fee`.
+class RecommendedFees {
+  final double fastestFee;
+  final double halfHourFee;
+  final double hourFee;
+  final double economyFee;
+  final double minimumFee;
+
+  const RecommendedFees({
+    required this.fastestFee,
+    required this.halfHourFee,
+    required this.hourFee,
+    required this.economyFee,
+    required this.minimumFee,
+  });
+
+  @override
+  int get hashCode =>
+      fastestFee.hashCode ^
+      halfHourFee.hashCode ^
+      hourFee.hashCode ^
+      economyFee.hashCode ^
+      minimumFee.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RecommendedFees &&
+          runtimeType == other.runtimeType &&
+          fastestFee == other.fastestFee &&
+          halfHourFee == other.halfHourFee &&
+          hourFee == other.hourFee &&
+          economyFee == other.economyFee &&
+          minimumFee == other.minimumFee;
+}
+
+/// See [LiquidSdk::fetch_recommended_fees](crate::sdk::LiquidSdk::fetch_recommended_fees)
+class RecommendedFeesResponse {
+  /// For Liquid txs: lockups, claims, refunds of Send Swaps and direct sends
+  final RecommendedFees liquid;
+
+  /// For Bitcoin txs: refunds of Incoming Chain Swaps
+  final RecommendedFees bitcoin;
+
+  const RecommendedFeesResponse({
+    required this.liquid,
+    required this.bitcoin,
+  });
+
+  @override
+  int get hashCode => liquid.hashCode ^ bitcoin.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RecommendedFeesResponse &&
+          runtimeType == other.runtimeType &&
+          liquid == other.liquid &&
+          bitcoin == other.bitcoin;
+}
+
+class RefundRequest {
+  final String swapId;
+  final String refundAddress;
+
+  /// The fee rate of the refund tx, in sat/vbyte, as resolved by [crate::sdk::LiquidSdk::prepare_refund]
+  final double feeRateSatPerVbyte;
+
+  const RefundRequest({
+    required this.swapId,
+    required this.refundAddress,
+    required this.feeRateSatPerVbyte,
+  });
+
+  @override
+  int get hashCode => swapId.hashCode ^ refundAddress.hashCode ^ feeRateSatPerVbyte.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RefundRequest &&
+          runtimeType == other.runtimeType &&
+          swapId == other.swapId &&
+          refundAddress == other.refundAddress &&
+          feeRateSatPerVbyte == other.feeRateSatPerVbyte;
+}
+
+class RefundResponse {
+  final String refundTxId;
+
+  const RefundResponse({
+    required this.refundTxId,
+  });
+
+  @override
+  int get hashCode => refundTxId.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RefundResponse && runtimeType == other.runtimeType && refundTxId == other.refundTxId;
+}
+
+/// A swap whose lockup can be refunded
+class RefundableSwap {
+  final String swapId;
+
+  /// The lockup address of the swap
+  final String swapAddress;
+
+  /// Swap creation timestamp
+  final int timestamp;
+
+  /// Amount locked up by the payer
+  final BigInt amountSat;
+
+  /// [PaymentType::Send] swaps are refunded to an L-BTC address, [PaymentType::Receive] swaps
+  /// to a BTC address
+  final PaymentType paymentType;
+
+  const RefundableSwap({
+    required this.swapId,
+    required this.swapAddress,
+    required this.timestamp,
+    required this.amountSat,
+    required this.paymentType,
+  });
+
+  @override
+  int get hashCode =>
+      swapId.hashCode ^ swapAddress.hashCode ^ timestamp.hashCode ^ amountSat.hashCode ^ paymentType.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RefundableSwap &&
+          runtimeType == other.runtimeType &&
+          swapId == other.swapId &&
+          swapAddress == other.swapAddress &&
+          timestamp == other.timestamp &&
+          amountSat == other.amountSat &&
+          paymentType == other.paymentType;
+}
+
 class RestoreRequest {
   final String? backupPath;
 
@@ -609,3 +2155,63 @@ class SendPaymentResponse {
       identical(this, other) ||
       other is SendPaymentResponse && runtimeType == other.runtimeType && payment == other.payment;
 }
+
+class SetPaymentMetadataRequest {
+  /// The swap ID or one of the tx IDs of the payment
+  final String id;
+
+  /// Replaces any metadata the payment already has
+  final PaymentMetadata metadata;
+
+  const SetPaymentMetadataRequest({
+    required this.id,
+    required this.metadata,
+  });
+
+  @override
+  int get hashCode => id.hashCode ^ metadata.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SetPaymentMetadataRequest &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          metadata == other.metadata;
+}
+
+@freezed
+sealed class SuccessAction with _$SuccessAction {
+  const SuccessAction._();
+
+  const factory SuccessAction.message({
+    required String message,
+  }) = SuccessAction_Message;
+  const factory SuccessAction.url({
+    required String description,
+    required String url,
+  }) = SuccessAction_Url;
+  /// A message encrypted with the payment preimage
+  const factory SuccessAction.aes({
+    required String description,
+    required String ciphertext,
+    required String iv,
+  }) = SuccessAction_Aes;
+}
+
+@freezed
+sealed class SuccessActionProcessed with _$SuccessActionProcessed {
+  const SuccessActionProcessed._();
+
+  const factory SuccessActionProcessed.message({
+    required String message,
+  }) = SuccessActionProcessed_Message;
+  const factory SuccessActionProcessed.url({
+    required String description,
+    required String url,
+  }) = SuccessActionProcessed_Url;
+  const factory SuccessActionProcessed.aes({
+    required String description,
+    required String plaintext,
+  }) = SuccessActionProcessed_Aes;
+}
@@ -15,18 +15,18 @@ final _privateConstructorUsedError = UnsupportedError(
     'It seems like you constructed your class using `MyClass._()`. This constructor is only meant to be used by freezed and you are not supposed to need it nor use it.\nPlease check the documentation here for more information: https://github.com/rrousselGit/freezed#adding-getters-and-methods-to-our-models');
 
 /// @nodoc
-mixin _$LiquidSdkEvent {}
+mixin _$GetPaymentRequest {}
 
 /// @nodoc
-abstract class $LiquidSdkEventCopyWith<$Res> {
-  factory $LiquidSdkEventCopyWith(LiquidSdkEvent value, $Res Function(LiquidSdkEvent) then) =
-      _$LiquidSdkEventCopyWithImpl<$Res, LiquidSdkEvent>;
+abstract class $GetPaymentRequestCopyWith<$Res> {
+  factory $GetPaymentRequestCopyWith(GetPaymentRequest value, $Res Function(GetPaymentRequest) then) =
+      _$GetPaymentRequestCopyWithImpl<$Res, GetPaymentRequest>;
 }
 
 /// @nodoc
-class _$LiquidSdkEventCopyWithImpl<$Res, $Val extends LiquidSdkEvent>
-    implements $LiquidSdkEventCopyWith<$Res> {
-  _$LiquidSdkEventCopyWithImpl(this._value, this._then);
+class _$GetPaymentRequestCopyWithImpl<$Res, $Val extends GetPaymentRequest>
+    implements $GetPaymentRequestCopyWith<$Res> {
+  _$GetPaymentRequestCopyWithImpl(this._value, this._then);
 
   // ignore: unused_field
   final $Val _value;
@@ -35,488 +35,1958 @@ class _$LiquidSdkEventCopyWithImpl<$Res, $Val extends LiquidSdkEvent>
 }
 
 /// @nodoc
-abstract class _$$LiquidSdkEvent_PaymentFailedImplCopyWith<$Res> {
-  factory _$$LiquidSdkEvent_PaymentFailedImplCopyWith(
-          _$LiquidSdkEvent_PaymentFailedImpl value, $Res Function(_$LiquidSdkEvent_PaymentFailedImpl) then) =
-      __$$LiquidSdkEvent_PaymentFailedImplCopyWithImpl<$Res>;
+abstract class _$$GetPaymentRequest_TxIdImplCopyWith<$Res> {
+  factory _$$GetPaymentRequest_TxIdImplCopyWith(
+          _$GetPaymentRequest_TxIdImpl value, $Res Function(_$GetPaymentRequest_TxIdImpl) then) =
+      __$$GetPaymentRequest_TxIdImplCopyWithImpl<$Res>;
   @useResult
-  $Res call({Payment details});
+  $Res call({String txId});
 }
 
 /// @nodoc
-class __$$LiquidSdkEvent_PaymentFailedImplCopyWithImpl<$Res>
-    extends _$LiquidSdkEventCopyWithImpl<$Res, _$LiquidSdkEvent_PaymentFailedImpl>
-    implements _$$LiquidSdkEvent_PaymentFailedImplCopyWith<$Res> {
-  __$$LiquidSdkEvent_PaymentFailedImplCopyWithImpl(
-      _$LiquidSdkEvent_PaymentFailedImpl _value, $Res Function(_$LiquidSdkEvent_PaymentFailedImpl) _then)
+class __$$GetPaymentRequest_TxIdImplCopyWithImpl<$Res>
+    extends _$GetPaymentRequestCopyWithImpl<$Res, _$GetPaymentRequest_TxIdImpl>
+    implements _$$GetPaymentRequest_TxIdImplCopyWith<$Res> {
+  __$$GetPaymentRequest_TxIdImplCopyWithImpl(
+      _$GetPaymentRequest_TxIdImpl _value, $Res Function(_$GetPaymentRequest_TxIdImpl) _then)
       : super(_value, _then);
 
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? details = null,
+    Object? txId = null,
   }) {
-    return _then(_$LiquidSdkEvent_PaymentFailedImpl(
-      details: null == details
-          ? _value.details
-          : details // ignore: cast_nullable_to_non_nullable
-              as Payment,
+    return _then(_$GetPaymentRequest_TxIdImpl(
+      txId: null == txId
+          ? _value.txId
+          : txId // ignore: cast_nullable_to_non_nullable
+              as String,
     ));
   }
 }
 
 /// @nodoc
 
-class _$LiquidSdkEvent_PaymentFailedImpl extends LiquidSdkEvent_PaymentFailed {
-  const _$LiquidSdkEvent_PaymentFailedImpl({required this.details}) : super._();
+class _$GetPaymentRequest_TxIdImpl extends GetPaymentRequest_TxId {
+  const _$GetPaymentRequest_TxIdImpl({required this.txId}) : super._();
 
   @override
-  final Payment details;
+  final String txId;
 
   @override
   String toString() {
-    return 'LiquidSdkEvent.paymentFailed(details: $details)';
+    return 'GetPaymentRequest.txId(txId: $txId)';
   }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$LiquidSdkEvent_PaymentFailedImpl &&
-            (identical(other.details, details) || other.details == details));
+            other is _$GetPaymentRequest_TxIdImpl &&
+            (identical(other.txId, txId) || other.txId == txId));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, details);
+  int get hashCode => Object.hash(runtimeType, txId);
 
   @JsonKey(ignore: true)
   @override
   @pragma('vm:prefer-inline')
-  _$$LiquidSdkEvent_PaymentFailedImplCopyWith<_$LiquidSdkEvent_PaymentFailedImpl> get copyWith =>
-      __$$LiquidSdkEvent_PaymentFailedImplCopyWithImpl<_$LiquidSdkEvent_PaymentFailedImpl>(this, _$identity);
+  _$$GetPaymentRequest_TxIdImplCopyWith<_$GetPaymentRequest_TxIdImpl> get copyWith =>
+      __$$GetPaymentRequest_TxIdImplCopyWithImpl<_$GetPaymentRequest_TxIdImpl>(this, _$identity);
 }
 
-abstract class LiquidSdkEvent_PaymentFailed extends LiquidSdkEvent {
-  const factory LiquidSdkEvent_PaymentFailed({required final Payment details}) =
-      _$LiquidSdkEvent_PaymentFailedImpl;
-  const LiquidSdkEvent_PaymentFailed._() : super._();
+abstract class GetPaymentRequest_TxId extends GetPaymentRequest {
+  const factory GetPaymentRequest_TxId({required final String txId}) = _$GetPaymentRequest_TxIdImpl;
+  const GetPaymentRequest_TxId._() : super._();
 
-  Payment get details;
+  String get txId;
   @JsonKey(ignore: true)
-  _$$LiquidSdkEvent_PaymentFailedImplCopyWith<_$LiquidSdkEvent_PaymentFailedImpl> get copyWith =>
+  _$$GetPaymentRequest_TxIdImplCopyWith<_$GetPaymentRequest_TxIdImpl> get copyWith =>
       throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class _$$LiquidSdkEvent_PaymentPendingImplCopyWith<$Res> {
-  factory _$$LiquidSdkEvent_PaymentPendingImplCopyWith(_$LiquidSdkEvent_PaymentPendingImpl value,
-          $Res Function(_$LiquidSdkEvent_PaymentPendingImpl) then) =
-      __$$LiquidSdkEvent_PaymentPendingImplCopyWithImpl<$Res>;
+abstract class _$$GetPaymentRequest_SwapIdImplCopyWith<$Res> {
+  factory _$$GetPaymentRequest_SwapIdImplCopyWith(
+          _$GetPaymentRequest_SwapIdImpl value, $Res Function(_$GetPaymentRequest_SwapIdImpl) then) =
+      __$$GetPaymentRequest_SwapIdImplCopyWithImpl<$Res>;
   @useResult
-  $Res call({Payment details});
+  $Res call({String swapId});
 }
 
 /// @nodoc
-class __$$LiquidSdkEvent_PaymentPendingImplCopyWithImpl<$Res>
-    extends _$LiquidSdkEventCopyWithImpl<$Res, _$LiquidSdkEvent_PaymentPendingImpl>
-    implements _$$LiquidSdkEvent_PaymentPendingImplCopyWith<$Res> {
-  __$$LiquidSdkEvent_PaymentPendingImplCopyWithImpl(
-      _$LiquidSdkEvent_PaymentPendingImpl _value, $Res Function(_$LiquidSdkEvent_PaymentPendingImpl) _then)
+class __$$GetPaymentRequest_SwapIdImplCopyWithImpl<$Res>
+    extends _$GetPaymentRequestCopyWithImpl<$Res, _$GetPaymentRequest_SwapIdImpl>
+    implements _$$GetPaymentRequest_SwapIdImplCopyWith<$Res> {
+  __$$GetPaymentRequest_SwapIdImplCopyWithImpl(
+      _$GetPaymentRequest_SwapIdImpl _value, $Res Function(_$GetPaymentRequest_SwapIdImpl) _then)
       : super(_value, _then);
 
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? details = null,
+    Object? swapId = null,
   }) {
-    return _then(_$LiquidSdkEvent_PaymentPendingImpl(
-      details: null == details
-          ? _value.details
-          : details // ignore: cast_nullable_to_non_nullable
-              as Payment,
+    return _then(_$GetPaymentRequest_SwapIdImpl(
+      swapId: null == swapId
+          ? _value.swapId
+          : swapId // ignore: cast_nullable_to_non_nullable
+              as String,
     ));
   }
 }
 
 /// @nodoc
 
-class _$LiquidSdkEvent_PaymentPendingImpl extends LiquidSdkEvent_PaymentPending {
-  const _$LiquidSdkEvent_PaymentPendingImpl({required this.details}) : super._();
+class _$GetPaymentRequest_SwapIdImpl extends GetPaymentRequest_SwapId {
+  const _$GetPaymentRequest_SwapIdImpl({required this.swapId}) : super._();
 
   @override
-  final Payment details;
+  final String swapId;
 
   @override
   String toString() {
-    return 'LiquidSdkEvent.paymentPending(details: $details)';
+    return 'GetPaymentRequest.swapId(swapId: $swapId)';
   }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$LiquidSdkEvent_PaymentPendingImpl &&
-            (identical(other.details, details) || other.details == details));
+            other is _$GetPaymentRequest_SwapIdImpl &&
+            (identical(other.swapId, swapId) || other.swapId == swapId));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, details);
+  int get hashCode => Object.hash(runtimeType, swapId);
 
   @JsonKey(ignore: true)
   @override
   @pragma('vm:prefer-inline')
-  _$$LiquidSdkEvent_PaymentPendingImplCopyWith<_$LiquidSdkEvent_PaymentPendingImpl> get copyWith =>
-      __$$LiquidSdkEvent_PaymentPendingImplCopyWithImpl<_$LiquidSdkEvent_PaymentPendingImpl>(
-          this, _$identity);
+  _$$GetPaymentRequest_SwapIdImplCopyWith<_$GetPaymentRequest_SwapIdImpl> get copyWith =>
+      __$$GetPaymentRequest_SwapIdImplCopyWithImpl<_$GetPaymentRequest_SwapIdImpl>(this, _$identity);
 }
 
-abstract class LiquidSdkEvent_PaymentPending extends LiquidSdkEvent {
-  const factory LiquidSdkEvent_PaymentPending({required final Payment details}) =
-      _$LiquidSdkEvent_PaymentPendingImpl;
-  const LiquidSdkEvent_PaymentPending._() : super._();
+abstract class GetPaymentRequest_SwapId extends GetPaymentRequest {
+  const factory GetPaymentRequest_SwapId({required final String swapId}) = _$GetPaymentRequest_SwapIdImpl;
+  const GetPaymentRequest_SwapId._() : super._();
 
-  Payment get details;
+  String get swapId;
   @JsonKey(ignore: true)
-  _$$LiquidSdkEvent_PaymentPendingImplCopyWith<_$LiquidSdkEvent_PaymentPendingImpl> get copyWith =>
+  _$$GetPaymentRequest_SwapIdImplCopyWith<_$GetPaymentRequest_SwapIdImpl> get copyWith =>
       throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class _$$LiquidSdkEvent_PaymentRefundedImplCopyWith<$Res> {
-  factory _$$LiquidSdkEvent_PaymentRefundedImplCopyWith(_$LiquidSdkEvent_PaymentRefundedImpl value,
-          $Res Function(_$LiquidSdkEvent_PaymentRefundedImpl) then) =
-      __$$LiquidSdkEvent_PaymentRefundedImplCopyWithImpl<$Res>;
+abstract class _$$GetPaymentRequest_Bolt11ImplCopyWith<$Res> {
+  factory _$$GetPaymentRequest_Bolt11ImplCopyWith(
+          _$GetPaymentRequest_Bolt11Impl value, $Res Function(_$GetPaymentRequest_Bolt11Impl) then) =
+      __$$GetPaymentRequest_Bolt11ImplCopyWithImpl<$Res>;
   @useResult
-  $Res call({Payment details});
+  $Res call({String bolt11});
 }
 
 /// @nodoc
-class __$$LiquidSdkEvent_PaymentRefundedImplCopyWithImpl<$Res>
-    extends _$LiquidSdkEventCopyWithImpl<$Res, _$LiquidSdkEvent_PaymentRefundedImpl>
-    implements _$$LiquidSdkEvent_PaymentRefundedImplCopyWith<$Res> {
-  __$$LiquidSdkEvent_PaymentRefundedImplCopyWithImpl(
-      _$LiquidSdkEvent_PaymentRefundedImpl _value, $Res Function(_$LiquidSdkEvent_PaymentRefundedImpl) _then)
+class __$$GetPaymentRequest_Bolt11ImplCopyWithImpl<$Res>
+    extends _$GetPaymentRequestCopyWithImpl<$Res, _$GetPaymentRequest_Bolt11Impl>
+    implements _$$GetPaymentRequest_Bolt11ImplCopyWith<$Res> {
+  __$$GetPaymentRequest_Bolt11ImplCopyWithImpl(
+      _$GetPaymentRequest_Bolt11Impl _value, $Res Function(_$GetPaymentRequest_Bolt11Impl) _then)
       : super(_value, _then);
 
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? details = null,
+    Object? bolt11 = null,
   }) {
-    return _then(_$LiquidSdkEvent_PaymentRefundedImpl(
-      details: null == details
-          ? _value.details
-          : details // ignore: cast_nullable_to_non_nullable
-              as Payment,
+    return _then(_$GetPaymentRequest_Bolt11Impl(
+      bolt11: null == bolt11
+          ? _value.bolt11
+          : bolt11 // ignore: cast_nullable_to_non_nullable
+              as String,
     ));
   }
 }
 
 /// @nodoc
 
-class _$LiquidSdkEvent_PaymentRefundedImpl extends LiquidSdkEvent_PaymentRefunded {
-  const _$LiquidSdkEvent_PaymentRefundedImpl({required this.details}) : super._();
+class _$GetPaymentRequest_Bolt11Impl extends GetPaymentRequest_Bolt11 {
+  const _$GetPaymentRequest_Bolt11Impl({required this.bolt11}) : super._();
 
   @override
-  final Payment details;
+  final String bolt11;
 
   @override
   String toString() {
-    return 'LiquidSdkEvent.paymentRefunded(details: $details)';
+    return 'GetPaymentRequest.bolt11(bolt11: $bolt11)';
   }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$LiquidSdkEvent_PaymentRefundedImpl &&
-            (identical(other.details, details) || other.details == details));
+            other is _$GetPaymentRequest_Bolt11Impl &&
+            (identical(other.bolt11, bolt11) || other.bolt11 == bolt11));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, details);
+  int get hashCode => Object.hash(runtimeType, bolt11);
 
   @JsonKey(ignore: true)
   @override
   @pragma('vm:prefer-inline')
-  _$$LiquidSdkEvent_PaymentRefundedImplCopyWith<_$LiquidSdkEvent_PaymentRefundedImpl> get copyWith =>
-      __$$LiquidSdkEvent_PaymentRefundedImplCopyWithImpl<_$LiquidSdkEvent_PaymentRefundedImpl>(
-          this, _$identity);
+  _$$GetPaymentRequest_Bolt11ImplCopyWith<_$GetPaymentRequest_Bolt11Impl> get copyWith =>
+      __$$GetPaymentRequest_Bolt11ImplCopyWithImpl<_$GetPaymentRequest_Bolt11Impl>(this, _$identity);
 }
 
-abstract class LiquidSdkEvent_PaymentRefunded extends LiquidSdkEvent {
-  const factory LiquidSdkEvent_PaymentRefunded({required final Payment details}) =
-      _$LiquidSdkEvent_PaymentRefundedImpl;
-  const LiquidSdkEvent_PaymentRefunded._() : super._();
+abstract class GetPaymentRequest_Bolt11 extends GetPaymentRequest {
+  const factory GetPaymentRequest_Bolt11({required final String bolt11}) = _$GetPaymentRequest_Bolt11Impl;
+  const GetPaymentRequest_Bolt11._() : super._();
 
-  Payment get details;
+  String get bolt11;
   @JsonKey(ignore: true)
-  _$$LiquidSdkEvent_PaymentRefundedImplCopyWith<_$LiquidSdkEvent_PaymentRefundedImpl> get copyWith =>
+  _$$GetPaymentRequest_Bolt11ImplCopyWith<_$GetPaymentRequest_Bolt11Impl> get copyWith =>
       throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class _$$LiquidSdkEvent_PaymentRefundPendingImplCopyWith<$Res> {
-  factory _$$LiquidSdkEvent_PaymentRefundPendingImplCopyWith(_$LiquidSdkEvent_PaymentRefundPendingImpl value,
-          $Res Function(_$LiquidSdkEvent_PaymentRefundPendingImpl) then) =
-      __$$LiquidSdkEvent_PaymentRefundPendingImplCopyWithImpl<$Res>;
+abstract class _$$GetPaymentRequest_PaymentHashImplCopyWith<$Res> {
+  factory _$$GetPaymentRequest_PaymentHashImplCopyWith(_$GetPaymentRequest_PaymentHashImpl value,
+          $Res Function(_$GetPaymentRequest_PaymentHashImpl) then) =
+      __$$GetPaymentRequest_PaymentHashImplCopyWithImpl<$Res>;
   @useResult
-  $Res call({Payment details});
+  $Res call({String paymentHash});
 }
 
 /// @nodoc
-class __$$LiquidSdkEvent_PaymentRefundPendingImplCopyWithImpl<$Res>
-    extends _$LiquidSdkEventCopyWithImpl<$Res, _$LiquidSdkEvent_PaymentRefundPendingImpl>
-    implements _$$LiquidSdkEvent_PaymentRefundPendingImplCopyWith<$Res> {
-  __$$LiquidSdkEvent_PaymentRefundPendingImplCopyWithImpl(_$LiquidSdkEvent_PaymentRefundPendingImpl _value,
-      $Res Function(_$LiquidSdkEvent_PaymentRefundPendingImpl) _then)
+class __$$GetPaymentRequest_PaymentHashImplCopyWithImpl<$Res>
+    extends _$GetPaymentRequestCopyWithImpl<$Res, _$GetPaymentRequest_PaymentHashImpl>
+    implements _$$GetPaymentRequest_PaymentHashImplCopyWith<$Res> {
+  __$$GetPaymentRequest_PaymentHashImplCopyWithImpl(
+      _$GetPaymentRequest_PaymentHashImpl _value, $Res Function(_$GetPaymentRequest_PaymentHashImpl) _then)
       : super(_value, _then);
 
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? details = null,
+    Object? paymentHash = null,
   }) {
-    return _then(_$LiquidSdkEvent_PaymentRefundPendingImpl(
-      details: null == details
-          ? _value.details
-          : details // ignore: cast_nullable_to_non_nullable
-              as Payment,
+    return _then(_$GetPaymentRequest_PaymentHashImpl(
+      paymentHash: null == paymentHash
+          ? _value.paymentHash
+          : paymentHash // ignore: cast_nullable_to_non_nullable
+              as String,
     ));
   }
 }
 
 /// @nodoc
 
-class _$LiquidSdkEvent_PaymentRefundPendingImpl extends LiquidSdkEvent_PaymentRefundPending {
-  const _$LiquidSdkEvent_PaymentRefundPendingImpl({required this.details}) : super._();
+class _$GetPaymentRequest_PaymentHashImpl extends GetPaymentRequest_PaymentHash {
+  const _$GetPaymentRequest_PaymentHashImpl({required this.paymentHash}) : super._();
 
   @override
-  final Payment details;
+  final String paymentHash;
 
   @override
   String toString() {
-    return 'LiquidSdkEvent.paymentRefundPending(details: $details)';
+    return 'GetPaymentRequest.paymentHash(paymentHash: $paymentHash)';
   }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$LiquidSdkEvent_PaymentRefundPendingImpl &&
-            (identical(other.details, details) || other.details == details));
+            other is _$GetPaymentRequest_PaymentHashImpl &&
+            (identical(other.paymentHash, paymentHash) || other.paymentHash == paymentHash));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, details);
+  int get hashCode => Object.hash(runtimeType, paymentHash);
 
   @JsonKey(ignore: true)
   @override
   @pragma('vm:prefer-inline')
-  _$$LiquidSdkEvent_PaymentRefundPendingImplCopyWith<_$LiquidSdkEvent_PaymentRefundPendingImpl>
-      get copyWith =>
-          __$$LiquidSdkEvent_PaymentRefundPendingImplCopyWithImpl<_$LiquidSdkEvent_PaymentRefundPendingImpl>(
-              this, _$identity);
+  _$$GetPaymentRequest_PaymentHashImplCopyWith<_$GetPaymentRequest_PaymentHashImpl> get copyWith =>
+      __$$GetPaymentRequest_PaymentHashImplCopyWithImpl<_$GetPaymentRequest_PaymentHashImpl>(
+          this, _$identity);
 }
 
-abstract class LiquidSdkEvent_PaymentRefundPending extends LiquidSdkEvent {
-  const factory LiquidSdkEvent_PaymentRefundPending({required final Payment details}) =
-      _$LiquidSdkEvent_PaymentRefundPendingImpl;
-  const LiquidSdkEvent_PaymentRefundPending._() : super._();
+abstract class GetPaymentRequest_PaymentHash extends GetPaymentRequest {
+  const factory GetPaymentRequest_PaymentHash({required final String paymentHash}) =
+      _$GetPaymentRequest_PaymentHashImpl;
+  const GetPaymentRequest_PaymentHash._() : super._();
 
-  Payment get details;
+  String get paymentHash;
   @JsonKey(ignore: true)
-  _$$LiquidSdkEvent_PaymentRefundPendingImplCopyWith<_$LiquidSdkEvent_PaymentRefundPendingImpl>
-      get copyWith => throw _privateConstructorUsedError;
+  _$$GetPaymentRequest_PaymentHashImplCopyWith<_$GetPaymentRequest_PaymentHashImpl> get copyWith =>
+      throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class _$$LiquidSdkEvent_PaymentSucceededImplCopyWith<$Res> {
-  factory _$$LiquidSdkEvent_PaymentSucceededImplCopyWith(_$LiquidSdkEvent_PaymentSucceededImpl value,
-          $Res Function(_$LiquidSdkEvent_PaymentSucceededImpl) then) =
-      __$$LiquidSdkEvent_PaymentSucceededImplCopyWithImpl<$Res>;
+mixin _$InputType {}
+
+/// @nodoc
+abstract class $InputTypeCopyWith<$Res> {
+  factory $InputTypeCopyWith(InputType value, $Res Function(InputType) then) =
+      _$InputTypeCopyWithImpl<$Res, InputType>;
+}
+
+/// @nodoc
+class _$InputTypeCopyWithImpl<$Res, $Val extends InputType> implements $InputTypeCopyWith<$Res> {
+  _$InputTypeCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+}
+
+/// @nodoc
+abstract class _$$InputType_Bolt11ImplCopyWith<$Res> {
+  factory _$$InputType_Bolt11ImplCopyWith(
+          _$InputType_Bolt11Impl value, $Res Function(_$InputType_Bolt11Impl) then) =
+      __$$InputType_Bolt11ImplCopyWithImpl<$Res>;
   @useResult
-  $Res call({Payment details});
+  $Res call({LNInvoice invoice});
 }
 
 /// @nodoc
-class __$$LiquidSdkEvent_PaymentSucceededImplCopyWithImpl<$Res>
-    extends _$LiquidSdkEventCopyWithImpl<$Res, _$LiquidSdkEvent_PaymentSucceededImpl>
-    implements _$$LiquidSdkEvent_PaymentSucceededImplCopyWith<$Res> {
-  __$$LiquidSdkEvent_PaymentSucceededImplCopyWithImpl(_$LiquidSdkEvent_PaymentSucceededImpl _value,
-      $Res Function(_$LiquidSdkEvent_PaymentSucceededImpl) _then)
+class __$$InputType_Bolt11ImplCopyWithImpl<$Res>
+    extends _$InputTypeCopyWithImpl<$Res, _$InputType_Bolt11Impl>
+    implements _$$InputType_Bolt11ImplCopyWith<$Res> {
+  __$$InputType_Bolt11ImplCopyWithImpl(
+      _$InputType_Bolt11Impl _value, $Res Function(_$InputType_Bolt11Impl) _then)
       : super(_value, _then);
 
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? details = null,
+    Object? invoice = null,
   }) {
-    return _then(_$LiquidSdkEvent_PaymentSucceededImpl(
-      details: null == details
-          ? _value.details
-          : details // ignore: cast_nullable_to_non_nullable
-              as Payment,
+    return _then(_$InputType_Bolt11Impl(
+      invoice: null == invoice
+          ? _value.invoice
+          : invoice // ignore: cast_nullable_to_non_nullable
+              as LNInvoice,
     ));
   }
 }
 
 /// @nodoc
 
-class _$LiquidSdkEvent_PaymentSucceededImpl extends LiquidSdkEvent_PaymentSucceeded {
-  const _$LiquidSdkEvent_PaymentSucceededImpl({required this.details}) : super._();
+class _$InputType_Bolt11Impl extends InputType_Bolt11 {
+  const _$InputType_Bolt11Impl({required this.invoice}) : super._();
 
   @override
-  final Payment details;
+  final LNInvoice invoice;
 
   @override
   String toString() {
-    return 'LiquidSdkEvent.paymentSucceeded(details: $details)';
+    return 'InputType.bolt11(invoice: $invoice)';
   }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$LiquidSdkEvent_PaymentSucceededImpl &&
-            (identical(other.details, details) || other.details == details));
+            other is _$InputType_Bolt11Impl &&
+            (identical(other.invoice, invoice) || other.invoice == invoice));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, details);
+  int get hashCode => Object.hash(runtimeType, invoice);
 
   @JsonKey(ignore: true)
   @override
   @pragma('vm:prefer-inline')
-  _$$LiquidSdkEvent_PaymentSucceededImplCopyWith<_$LiquidSdkEvent_PaymentSucceededImpl> get copyWith =>
-      __$$LiquidSdkEvent_PaymentSucceededImplCopyWithImpl<_$LiquidSdkEvent_PaymentSucceededImpl>(
-          this, _$identity);
+  _$$InputType_Bolt11ImplCopyWith<_$InputType_Bolt11Impl> get copyWith =>
+      __$$InputType_Bolt11ImplCopyWithImpl<_$InputType_Bolt11Impl>(this, _$identity);
 }
 
-abstract class LiquidSdkEvent_PaymentSucceeded extends LiquidSdkEvent {
-  const factory LiquidSdkEvent_PaymentSucceeded({required final Payment details}) =
-      _$LiquidSdkEvent_PaymentSucceededImpl;
-  const LiquidSdkEvent_PaymentSucceeded._() : super._();
+abstract class InputType_Bolt11 extends InputType {
+  const factory InputType_Bolt11({required final LNInvoice invoice}) = _$InputType_Bolt11Impl;
+  const InputType_Bolt11._() : super._();
 
-  Payment get details;
+  LNInvoice get invoice;
   @JsonKey(ignore: true)
-  _$$LiquidSdkEvent_PaymentSucceededImplCopyWith<_$LiquidSdkEvent_PaymentSucceededImpl> get copyWith =>
+  _$$InputType_Bolt11ImplCopyWith<_$InputType_Bolt11Impl> get copyWith =>
       throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class _$$LiquidSdkEvent_PaymentWaitingConfirmationImplCopyWith<$Res> {
-  factory _$$LiquidSdkEvent_PaymentWaitingConfirmationImplCopyWith(
-          _$LiquidSdkEvent_PaymentWaitingConfirmationImpl value,
-          $Res Function(_$LiquidSdkEvent_PaymentWaitingConfirmationImpl) then) =
-      __$$LiquidSdkEvent_PaymentWaitingConfirmationImplCopyWithImpl<$Res>;
+abstract class _$$InputType_LiquidAddressImplCopyWith<$Res> {
+  factory _$$InputType_LiquidAddressImplCopyWith(
+          _$InputType_LiquidAddressImpl value, $Res Function(_$InputType_LiquidAddressImpl) then) =
+      __$$InputType_LiquidAddressImplCopyWithImpl<$Res>;
   @useResult
-  $Res call({Payment details});
+  $Res call({LiquidAddressData address});
 }
 
 /// @nodoc
-class __$$LiquidSdkEvent_PaymentWaitingConfirmationImplCopyWithImpl<$Res>
-    extends _$LiquidSdkEventCopyWithImpl<$Res, _$LiquidSdkEvent_PaymentWaitingConfirmationImpl>
-    implements _$$LiquidSdkEvent_PaymentWaitingConfirmationImplCopyWith<$Res> {
-  __$$LiquidSdkEvent_PaymentWaitingConfirmationImplCopyWithImpl(
-      _$LiquidSdkEvent_PaymentWaitingConfirmationImpl _value,
-      $Res Function(_$LiquidSdkEvent_PaymentWaitingConfirmationImpl) _then)
+class __$$InputType_LiquidAddressImplCopyWithImpl<$Res>
+    extends _$InputTypeCopyWithImpl<$Res, _$InputType_LiquidAddressImpl>
+    implements _$$InputType_LiquidAddressImplCopyWith<$Res> {
+  __$$InputType_LiquidAddressImplCopyWithImpl(
+      _$InputType_LiquidAddressImpl _value, $Res Function(_$InputType_LiquidAddressImpl) _then)
       : super(_value, _then);
 
   @pragma('vm:prefer-inline')
   @override
   $Res call({
-    Object? details = null,
+    Object? address = null,
   }) {
-    return _then(_$LiquidSdkEvent_PaymentWaitingConfirmationImpl(
-      details: null == details
-          ? _value.details
-          : details // ignore: cast_nullable_to_non_nullable
-              as Payment,
+    return _then(_$InputType_LiquidAddressImpl(
+      address: null == address
+          ? _value.address
+          : address // ignore: cast_nullable_to_non_nullable
+              as LiquidAddressData,
     ));
   }
 }
 
 /// @nodoc
 
-class _$LiquidSdkEvent_PaymentWaitingConfirmationImpl extends LiquidSdkEvent_PaymentWaitingConfirmation {
-  const _$LiquidSdkEvent_PaymentWaitingConfirmationImpl({required this.details}) : super._();
+class _$InputType_LiquidAddressImpl extends InputType_LiquidAddress {
+  const _$InputType_LiquidAddressImpl({required this.address}) : super._();
 
   @override
-  final Payment details;
+  final LiquidAddressData address;
 
   @override
   String toString() {
-    return 'LiquidSdkEvent.paymentWaitingConfirmation(details: $details)';
+    return 'InputType.liquidAddress(address: $address)';
   }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
         (other.runtimeType == runtimeType &&
-            other is _$LiquidSdkEvent_PaymentWaitingConfirmationImpl &&
-            (identical(other.details, details) || other.details == details));
+            other is _$InputType_LiquidAddressImpl &&
+            (identical(other.address, address) || other.address == address));
   }
 
   @override
-  int get hashCode => Object.hash(runtimeType, details);
+  int get hashCode => Object.hash(runtimeType, address);
 
   @JsonKey(ignore: true)
   @override
   @pragma('vm:prefer-inline')
-  _$$LiquidSdkEvent_PaymentWaitingConfirmationImplCopyWith<_$LiquidSdkEvent_PaymentWaitingConfirmationImpl>
-      get copyWith => __$$LiquidSdkEvent_PaymentWaitingConfirmationImplCopyWithImpl<
-          _$LiquidSdkEvent_PaymentWaitingConfirmationImpl>(this, _$identity);
+  _$$InputType_LiquidAddressImplCopyWith<_$InputType_LiquidAddressImpl> get copyWith =>
+      __$$InputType_LiquidAddressImplCopyWithImpl<_$InputType_LiquidAddressImpl>(this, _$identity);
 }
 
-abstract class LiquidSdkEvent_PaymentWaitingConfirmation extends LiquidSdkEvent {
-  const factory LiquidSdkEvent_PaymentWaitingConfirmation({required final Payment details}) =
-      _$LiquidSdkEvent_PaymentWaitingConfirmationImpl;
-  const LiquidSdkEvent_PaymentWaitingConfirmation._() : super._();
+abstract class InputType_LiquidAddress extends InputType {
+  const factory InputType_LiquidAddress({required final LiquidAddressData address}) =
+      _$InputType_LiquidAddressImpl;
+  const InputType_LiquidAddress._() : super._();
 
-  Payment get details;
+  LiquidAddressData get address;
   @JsonKey(ignore: true)
-  _$$LiquidSdkEvent_PaymentWaitingConfirmationImplCopyWith<_$LiquidSdkEvent_PaymentWaitingConfirmationImpl>
-      get copyWith => throw _privateConstructorUsedError;
+  _$$InputType_LiquidAddressImplCopyWith<_$InputType_LiquidAddressImpl> get copyWith =>
+      throw _privateConstructorUsedError;
 }
 
 /// @nodoc
-abstract class _$$LiquidSdkEvent_SyncedImplCopyWith<$Res> {
-  factory _$$LiquidSdkEvent_SyncedImplCopyWith(
-          _$LiquidSdkEvent_SyncedImpl value, $Res Function(_$LiquidSdkEvent_SyncedImpl) then) =
-      __$$LiquidSdkEvent_SyncedImplCopyWithImpl<$Res>;
+abstract class _$$InputType_BitcoinAddressImplCopyWith<$Res> {
+  factory _$$InputType_BitcoinAddressImplCopyWith(
+          _$InputType_BitcoinAddressImpl value, $Res Function(_$InputType_BitcoinAddressImpl) then) =
+      __$$InputType_BitcoinAddressImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({BitcoinAddressData address});
 }
 
 /// @nodoc
-class __$$LiquidSdkEvent_SyncedImplCopyWithImpl<$Res>
-    extends _$LiquidSdkEventCopyWithImpl<$Res, _$LiquidSdkEvent_SyncedImpl>
-    implements _$$LiquidSdkEvent_SyncedImplCopyWith<$Res> {
-  __$$LiquidSdkEvent_SyncedImplCopyWithImpl(
-      _$LiquidSdkEvent_SyncedImpl _value, $Res Function(_$LiquidSdkEvent_SyncedImpl) _then)
+class __$$InputType_BitcoinAddressImplCopyWithImpl<$Res>
+    extends _$InputTypeCopyWithImpl<$Res, _$InputType_BitcoinAddressImpl>
+    implements _$$InputType_BitcoinAddressImplCopyWith<$Res> {
+  __$$InputType_BitcoinAddressImplCopyWithImpl(
+      _$InputType_BitcoinAddressImpl _value, $Res Function(_$InputType_BitcoinAddressImpl) _then)
       : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? address = null,
+  }) {
+    return _then(_$InputType_BitcoinAddressImpl(
+      address: null == address
+          ? _value.address
+          : address // ignore: cast_nullable_to_non_nullable
+              as BitcoinAddressData,
+    ));
+  }
 }
 
 /// @nodoc
 
-class _$LiquidSdkEvent_SyncedImpl extends LiquidSdkEvent_Synced {
-  const _$LiquidSdkEvent_SyncedImpl() : super._();
+class _$InputType_BitcoinAddressImpl extends InputType_BitcoinAddress {
+  const _$InputType_BitcoinAddressImpl({required this.address}) : super._();
+
+  @override
+  final BitcoinAddressData address;
 
   @override
   String toString() {
-    return 'LiquidSdkEvent.synced()';
+    return 'InputType.bitcoinAddress(address: $address)';
   }
 
   @override
   bool operator ==(Object other) {
     return identical(this, other) ||
-        (other.runtimeType == runtimeType && other is _$LiquidSdkEvent_SyncedImpl);
+        (other.runtimeType == runtimeType &&
+            other is _$InputType_BitcoinAddressImpl &&
+            (identical(other.address, address) || other.address == address));
   }
 
   @override
-  int get hashCode => runtimeType.hashCode;
+  int get hashCode => Object.hash(runtimeType, address);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$InputType_BitcoinAddressImplCopyWith<_$InputType_BitcoinAddressImpl> get copyWith =>
+      __$$InputType_BitcoinAddressImplCopyWithImpl<_$InputType_BitcoinAddressImpl>(this, _$identity);
 }
 
-abstract class LiquidSdkEvent_Synced extends LiquidSdkEvent {
-  const factory LiquidSdkEvent_Synced() = _$LiquidSdkEvent_SyncedImpl;
-  const LiquidSdkEvent_Synced._() : super._();
+abstract class InputType_BitcoinAddress extends InputType {
+  const factory InputType_BitcoinAddress({required final BitcoinAddressData address}) =
+      _$InputType_BitcoinAddressImpl;
+  const InputType_BitcoinAddress._() : super._();
+
+  BitcoinAddressData get address;
+  @JsonKey(ignore: true)
+  _$$InputType_BitcoinAddressImplCopyWith<_$InputType_BitcoinAddressImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$InputType_LnUrlImplCopyWith<$Res> {
+  factory _$$InputType_LnUrlImplCopyWith(
+          _$InputType_LnUrlImpl value, $Res Function(_$InputType_LnUrlImpl) then) =
+      __$$InputType_LnUrlImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String url});
+}
+
+/// @nodoc
+class __$$InputType_LnUrlImplCopyWithImpl<$Res>
+    extends _$InputTypeCopyWithImpl<$Res, _$InputType_LnUrlImpl>
+    implements _$$InputType_LnUrlImplCopyWith<$Res> {
+  __$$InputType_LnUrlImplCopyWithImpl(
+      _$InputType_LnUrlImpl _value, $Res Function(_$InputType_LnUrlImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? url = null,
+  }) {
+    return _then(_$InputType_LnUrlImpl(
+      url: null == url
+          ? _value.url
+          : url // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$InputType_LnUrlImpl extends InputType_LnUrl {
+  const _$InputType_LnUrlImpl({required this.url}) : super._();
+
+  @override
+  final String url;
+
+  @override
+  String toString() {
+    return 'InputType.lnUrl(url: $url)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$InputType_LnUrlImpl &&
+            (identical(other.url, url) || other.url == url));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, url);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$InputType_LnUrlImplCopyWith<_$InputType_LnUrlImpl> get copyWith =>
+      __$$InputType_LnUrlImplCopyWithImpl<_$InputType_LnUrlImpl>(this, _$identity);
+}
+
+abstract class InputType_LnUrl extends InputType {
+  const factory InputType_LnUrl({required final String url}) = _$InputType_LnUrlImpl;
+  const InputType_LnUrl._() : super._();
+
+  String get url;
+  @JsonKey(ignore: true)
+  _$$InputType_LnUrlImplCopyWith<_$InputType_LnUrlImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$InputType_LightningAddressImplCopyWith<$Res> {
+  factory _$$InputType_LightningAddressImplCopyWith(
+          _$InputType_LightningAddressImpl value, $Res Function(_$InputType_LightningAddressImpl) then) =
+      __$$InputType_LightningAddressImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String address, String url});
+}
+
+/// @nodoc
+class __$$InputType_LightningAddressImplCopyWithImpl<$Res>
+    extends _$InputTypeCopyWithImpl<$Res, _$InputType_LightningAddressImpl>
+    implements _$$InputType_LightningAddressImplCopyWith<$Res> {
+  __$$InputType_LightningAddressImplCopyWithImpl(
+      _$InputType_LightningAddressImpl _value, $Res Function(_$InputType_LightningAddressImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? address = null,
+    Object? url = null,
+  }) {
+    return _then(_$InputType_LightningAddressImpl(
+      address: null == address
+          ? _value.address
+          : address // ignore: cast_nullable_to_non_nullable
+              as String,
+      url: null == url
+          ? _value.url
+          : url // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$InputType_LightningAddressImpl extends InputType_LightningAddress {
+  const _$InputType_LightningAddressImpl({required this.address, required this.url}) : super._();
+
+  @override
+  final String address;
+  @override
+  final String url;
+
+  @override
+  String toString() {
+    return 'InputType.lightningAddress(address: $address, url: $url)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$InputType_LightningAddressImpl &&
+            (identical(other.address, address) || other.address == address) &&
+            (identical(other.url, url) || other.url == url));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, address, url);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$InputType_LightningAddressImplCopyWith<_$InputType_LightningAddressImpl> get copyWith =>
+      __$$InputType_LightningAddressImplCopyWithImpl<_$InputType_LightningAddressImpl>(this, _$identity);
+}
+
+abstract class InputType_LightningAddress extends InputType {
+  const factory InputType_LightningAddress({required final String address, required final String url}) =
+      _$InputType_LightningAddressImpl;
+  const InputType_LightningAddress._() : super._();
+
+  String get address;
+  String get url;
+  @JsonKey(ignore: true)
+  _$$InputType_LightningAddressImplCopyWith<_$InputType_LightningAddressImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$LiquidSdkEvent {}
+
+/// @nodoc
+abstract class $LiquidSdkEventCopyWith<$Res> {
+  factory $LiquidSdkEventCopyWith(LiquidSdkEvent value, $Res Function(LiquidSdkEvent) then) =
+      _$LiquidSdkEventCopyWithImpl<$Res, LiquidSdkEvent>;
+}
+
+/// @nodoc
+class _$LiquidSdkEventCopyWithImpl<$Res, $Val extends LiquidSdkEvent>
+    implements $LiquidSdkEventCopyWith<$Res> {
+  _$LiquidSdkEventCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+}
+
+/// @nodoc
+abstract class _$$LiquidSdkEvent_PaymentFailedImplCopyWith<$Res> {
+  factory _$$LiquidSdkEvent_PaymentFailedImplCopyWith(
+          _$LiquidSdkEvent_PaymentFailedImpl value, $Res Function(_$LiquidSdkEvent_PaymentFailedImpl) then) =
+      __$$LiquidSdkEvent_PaymentFailedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({Payment details});
+}
+
+/// @nodoc
+class __$$LiquidSdkEvent_PaymentFailedImplCopyWithImpl<$Res>
+    extends _$LiquidSdkEventCopyWithImpl<$Res, _$LiquidSdkEvent_PaymentFailedImpl>
+    implements _$$LiquidSdkEvent_PaymentFailedImplCopyWith<$Res> {
+  __$$LiquidSdkEvent_PaymentFailedImplCopyWithImpl(
+      _$LiquidSdkEvent_PaymentFailedImpl _value, $Res Function(_$LiquidSdkEvent_PaymentFailedImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? details = null,
+  }) {
+    return _then(_$LiquidSdkEvent_PaymentFailedImpl(
+      details: null == details
+          ? _value.details
+          : details // ignore: cast_nullable_to_non_nullable
+              as Payment,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$LiquidSdkEvent_PaymentFailedImpl extends LiquidSdkEvent_PaymentFailed {
+  const _$LiquidSdkEvent_PaymentFailedImpl({required this.details}) : super._();
+
+  @override
+  final Payment details;
+
+  @override
+  String toString() {
+    return 'LiquidSdkEvent.paymentFailed(details: $details)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$LiquidSdkEvent_PaymentFailedImpl &&
+            (identical(other.details, details) || other.details == details));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, details);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$LiquidSdkEvent_PaymentFailedImplCopyWith<_$LiquidSdkEvent_PaymentFailedImpl> get copyWith =>
+      __$$LiquidSdkEvent_PaymentFailedImplCopyWithImpl<_$LiquidSdkEvent_PaymentFailedImpl>(this, _$identity);
+}
+
+abstract class LiquidSdkEvent_PaymentFailed extends LiquidSdkEvent {
+  const factory LiquidSdkEvent_PaymentFailed({required final Payment details}) =
+      _$LiquidSdkEvent_PaymentFailedImpl;
+  const LiquidSdkEvent_PaymentFailed._() : super._();
+
+  Payment get details;
+  @JsonKey(ignore: true)
+  _$$LiquidSdkEvent_PaymentFailedImplCopyWith<_$LiquidSdkEvent_PaymentFailedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$LiquidSdkEvent_PaymentPendingImplCopyWith<$Res> {
+  factory _$$LiquidSdkEvent_PaymentPendingImplCopyWith(_$LiquidSdkEvent_PaymentPendingImpl value,
+          $Res Function(_$LiquidSdkEvent_PaymentPendingImpl) then) =
+      __$$LiquidSdkEvent_PaymentPendingImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({Payment details});
+}
+
+/// @nodoc
+class __$$LiquidSdkEvent_PaymentPendingImplCopyWithImpl<$Res>
+    extends _$LiquidSdkEventCopyWithImpl<$Res, _$LiquidSdkEvent_PaymentPendingImpl>
+    implements _$$LiquidSdkEvent_PaymentPendingImplCopyWith<$Res> {
+  __$$LiquidSdkEvent_PaymentPendingImplCopyWithImpl(
+      _$LiquidSdkEvent_PaymentPendingImpl _value, $Res Function(_$LiquidSdkEvent_PaymentPendingImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? details = null,
+  }) {
+    return _then(_$LiquidSdkEvent_PaymentPendingImpl(
+      details: null == details
+          ? _value.details
+          : details // ignore: cast_nullable_to_non_nullable
+              as Payment,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$LiquidSdkEvent_PaymentPendingImpl extends LiquidSdkEvent_PaymentPending {
+  const _$LiquidSdkEvent_PaymentPendingImpl({required this.details}) : super._();
+
+  @override
+  final Payment details;
+
+  @override
+  String toString() {
+    return 'LiquidSdkEvent.paymentPending(details: $details)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$LiquidSdkEvent_PaymentPendingImpl &&
+            (identical(other.details, details) || other.details == details));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, details);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$LiquidSdkEvent_PaymentPendingImplCopyWith<_$LiquidSdkEvent_PaymentPendingImpl> get copyWith =>
+      __$$LiquidSdkEvent_PaymentPendingImplCopyWithImpl<_$LiquidSdkEvent_PaymentPendingImpl>(
+          this, _$identity);
+}
+
+abstract class LiquidSdkEvent_PaymentPending extends LiquidSdkEvent {
+  const factory LiquidSdkEvent_PaymentPending({required final Payment details}) =
+      _$LiquidSdkEvent_PaymentPendingImpl;
+  const LiquidSdkEvent_PaymentPending._() : super._();
+
+  Payment get details;
+  @JsonKey(ignore: true)
+  _$$LiquidSdkEvent_PaymentPendingImplCopyWith<_$LiquidSdkEvent_PaymentPendingImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$LiquidSdkEvent_PaymentRefundedImplCopyWith<$Res> {
+  factory _$$LiquidSdkEvent_PaymentRefundedImplCopyWith(_$LiquidSdkEvent_PaymentRefundedImpl value,
+          $Res Function(_$LiquidSdkEvent_PaymentRefundedImpl) then) =
+      __$$LiquidSdkEvent_PaymentRefundedImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({Payment details});
+}
+
+/// @nodoc
+class __$$LiquidSdkEvent_PaymentRefundedImplCopyWithImpl<$Res>
+    extends _$LiquidSdkEventCopyWithImpl<$Res, _$LiquidSdkEvent_PaymentRefundedImpl>
+    implements _$$LiquidSdkEvent_PaymentRefundedImplCopyWith<$Res> {
+  __$$LiquidSdkEvent_PaymentRefundedImplCopyWithImpl(
+      _$LiquidSdkEvent_PaymentRefundedImpl _value, $Res Function(_$LiquidSdkEvent_PaymentRefundedImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? details = null,
+  }) {
+    return _then(_$LiquidSdkEvent_PaymentRefundedImpl(
+      details: null == details
+          ? _value.details
+          : details // ignore: cast_nullable_to_non_nullable
+              as Payment,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$LiquidSdkEvent_PaymentRefundedImpl extends LiquidSdkEvent_PaymentRefunded {
+  const _$LiquidSdkEvent_PaymentRefundedImpl({required this.details}) : super._();
+
+  @override
+  final Payment details;
+
+  @override
+  String toString() {
+    return 'LiquidSdkEvent.paymentRefunded(details: $details)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$LiquidSdkEvent_PaymentRefundedImpl &&
+            (identical(other.details, details) || other.details == details));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, details);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$LiquidSdkEvent_PaymentRefundedImplCopyWith<_$LiquidSdkEvent_PaymentRefundedImpl> get copyWith =>
+      __$$LiquidSdkEvent_PaymentRefundedImplCopyWithImpl<_$LiquidSdkEvent_PaymentRefundedImpl>(
+          this, _$identity);
+}
+
+abstract class LiquidSdkEvent_PaymentRefunded extends LiquidSdkEvent {
+  const factory LiquidSdkEvent_PaymentRefunded({required final Payment details}) =
+      _$LiquidSdkEvent_PaymentRefundedImpl;
+  const LiquidSdkEvent_PaymentRefunded._() : super._();
+
+  Payment get details;
+  @JsonKey(ignore: true)
+  _$$LiquidSdkEvent_PaymentRefundedImplCopyWith<_$LiquidSdkEvent_PaymentRefundedImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$LiquidSdkEvent_PaymentRefundableImplCopyWith<$Res> {
+  factory _$$LiquidSdkEvent_PaymentRefundableImplCopyWith(_$LiquidSdkEvent_PaymentRefundableImpl value,
+          $Res Function(_$LiquidSdkEvent_PaymentRefundableImpl) then) =
+      __$$LiquidSdkEvent_PaymentRefundableImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({Payment details});
+}
+
+/// @nodoc
+class __$$LiquidSdkEvent_PaymentRefundableImplCopyWithImpl<$Res>
+    extends _$LiquidSdkEventCopyWithImpl<$Res, _$LiquidSdkEvent_PaymentRefundableImpl>
+    implements _$$LiquidSdkEvent_PaymentRefundableImplCopyWith<$Res> {
+  __$$LiquidSdkEvent_PaymentRefundableImplCopyWithImpl(_$LiquidSdkEvent_PaymentRefundableImpl _value,
+      $Res Function(_$LiquidSdkEvent_PaymentRefundableImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? details = null,
+  }) {
+    return _then(_$LiquidSdkEvent_PaymentRefundableImpl(
+      details: null == details
+          ? _value.details
+          : details // ignore: cast_nullable_to_non_nullable
+              as Payment,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$LiquidSdkEvent_PaymentRefundableImpl extends LiquidSdkEvent_PaymentRefundable {
+  const _$LiquidSdkEvent_PaymentRefundableImpl({required this.details}) : super._();
+
+  @override
+  final Payment details;
+
+  @override
+  String toString() {
+    return 'LiquidSdkEvent.paymentRefundable(details: $details)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$LiquidSdkEvent_PaymentRefundableImpl &&
+            (identical(other.details, details) || other.details == details));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, details);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$LiquidSdkEvent_PaymentRefundableImplCopyWith<_$LiquidSdkEvent_PaymentRefundableImpl> get copyWith =>
+      __$$LiquidSdkEvent_PaymentRefundableImplCopyWithImpl<_$LiquidSdkEvent_PaymentRefundableImpl>(
+          this, _$identity);
+}
+
+abstract class LiquidSdkEvent_PaymentRefundable extends LiquidSdkEvent {
+  const factory LiquidSdkEvent_PaymentRefundable({required final Payment details}) =
+      _$LiquidSdkEvent_PaymentRefundableImpl;
+  const LiquidSdkEvent_PaymentRefundable._() : super._();
+
+  Payment get details;
+  @JsonKey(ignore: true)
+  _$$LiquidSdkEvent_PaymentRefundableImplCopyWith<_$LiquidSdkEvent_PaymentRefundableImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$LiquidSdkEvent_PaymentRefundPendingImplCopyWith<$Res> {
+  factory _$$LiquidSdkEvent_PaymentRefundPendingImplCopyWith(_$LiquidSdkEvent_PaymentRefundPendingImpl value,
+          $Res Function(_$LiquidSdkEvent_PaymentRefundPendingImpl) then) =
+      __$$LiquidSdkEvent_PaymentRefundPendingImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({Payment details});
+}
+
+/// @nodoc
+class __$$LiquidSdkEvent_PaymentRefundPendingImplCopyWithImpl<$Res>
+    extends _$LiquidSdkEventCopyWithImpl<$Res, _$LiquidSdkEvent_PaymentRefundPendingImpl>
+    implements _$$LiquidSdkEvent_PaymentRefundPendingImplCopyWith<$Res> {
+  __$$LiquidSdkEvent_PaymentRefundPendingImplCopyWithImpl(_$LiquidSdkEvent_PaymentRefundPendingImpl _value,
+      $Res Function(_$LiquidSdkEvent_PaymentRefundPendingImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? details = null,
+  }) {
+    return _then(_$LiquidSdkEvent_PaymentRefundPendingImpl(
+      details: null == details
+          ? _value.details
+          : details // ignore: cast_nullable_to_non_nullable
+              as Payment,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$LiquidSdkEvent_PaymentRefundPendingImpl extends LiquidSdkEvent_PaymentRefundPending {
+  const _$LiquidSdkEvent_PaymentRefundPendingImpl({required this.details}) : super._();
+
+  @override
+  final Payment details;
+
+  @override
+  String toString() {
+    return 'LiquidSdkEvent.paymentRefundPending(details: $details)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$LiquidSdkEvent_PaymentRefundPendingImpl &&
+            (identical(other.details, details) || other.details == details));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, details);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$LiquidSdkEvent_PaymentRefundPendingImplCopyWith<_$LiquidSdkEvent_PaymentRefundPendingImpl>
+      get copyWith =>
+          __$$LiquidSdkEvent_PaymentRefundPendingImplCopyWithImpl<_$LiquidSdkEvent_PaymentRefundPendingImpl>(
+              this, _$identity);
+}
+
+abstract class LiquidSdkEvent_PaymentRefundPending extends LiquidSdkEvent {
+  const factory LiquidSdkEvent_PaymentRefundPending({required final Payment details}) =
+      _$LiquidSdkEvent_PaymentRefundPendingImpl;
+  const LiquidSdkEvent_PaymentRefundPending._() : super._();
+
+  Payment get details;
+  @JsonKey(ignore: true)
+  _$$LiquidSdkEvent_PaymentRefundPendingImplCopyWith<_$LiquidSdkEvent_PaymentRefundPendingImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$LiquidSdkEvent_PaymentSucceededImplCopyWith<$Res> {
+  factory _$$LiquidSdkEvent_PaymentSucceededImplCopyWith(_$LiquidSdkEvent_PaymentSucceededImpl value,
+          $Res Function(_$LiquidSdkEvent_PaymentSucceededImpl) then) =
+      __$$LiquidSdkEvent_PaymentSucceededImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({Payment details});
+}
+
+/// @nodoc
+class __$$LiquidSdkEvent_PaymentSucceededImplCopyWithImpl<$Res>
+    extends _$LiquidSdkEventCopyWithImpl<$Res, _$LiquidSdkEvent_PaymentSucceededImpl>
+    implements _$$LiquidSdkEvent_PaymentSucceededImplCopyWith<$Res> {
+  __$$LiquidSdkEvent_PaymentSucceededImplCopyWithImpl(_$LiquidSdkEvent_PaymentSucceededImpl _value,
+      $Res Function(_$LiquidSdkEvent_PaymentSucceededImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? details = null,
+  }) {
+    return _then(_$LiquidSdkEvent_PaymentSucceededImpl(
+      details: null == details
+          ? _value.details
+          : details // ignore: cast_nullable_to_non_nullable
+              as Payment,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$LiquidSdkEvent_PaymentSucceededImpl extends LiquidSdkEvent_PaymentSucceeded {
+  const _$LiquidSdkEvent_PaymentSucceededImpl({required this.details}) : super._();
+
+  @override
+  final Payment details;
+
+  @override
+  String toString() {
+    return 'LiquidSdkEvent.paymentSucceeded(details: $details)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$LiquidSdkEvent_PaymentSucceededImpl &&
+            (identical(other.details, details) || other.details == details));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, details);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$LiquidSdkEvent_PaymentSucceededImplCopyWith<_$LiquidSdkEvent_PaymentSucceededImpl> get copyWith =>
+      __$$LiquidSdkEvent_PaymentSucceededImplCopyWithImpl<_$LiquidSdkEvent_PaymentSucceededImpl>(
+          this, _$identity);
+}
+
+abstract class LiquidSdkEvent_PaymentSucceeded extends LiquidSdkEvent {
+  const factory LiquidSdkEvent_PaymentSucceeded({required final Payment details}) =
+      _$LiquidSdkEvent_PaymentSucceededImpl;
+  const LiquidSdkEvent_PaymentSucceeded._() : super._();
+
+  Payment get details;
+  @JsonKey(ignore: true)
+  _$$LiquidSdkEvent_PaymentSucceededImplCopyWith<_$LiquidSdkEvent_PaymentSucceededImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$LiquidSdkEvent_PaymentWaitingConfirmationImplCopyWith<$Res> {
+  factory _$$LiquidSdkEvent_PaymentWaitingConfirmationImplCopyWith(
+          _$LiquidSdkEvent_PaymentWaitingConfirmationImpl value,
+          $Res Function(_$LiquidSdkEvent_PaymentWaitingConfirmationImpl) then) =
+      __$$LiquidSdkEvent_PaymentWaitingConfirmationImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({Payment details});
+}
+
+/// @nodoc
+class __$$LiquidSdkEvent_PaymentWaitingConfirmationImplCopyWithImpl<$Res>
+    extends _$LiquidSdkEventCopyWithImpl<$Res, _$LiquidSdkEvent_PaymentWaitingConfirmationImpl>
+    implements _$$LiquidSdkEvent_PaymentWaitingConfirmationImplCopyWith<$Res> {
+  __$$LiquidSdkEvent_PaymentWaitingConfirmationImplCopyWithImpl(
+      _$LiquidSdkEvent_PaymentWaitingConfirmationImpl _value,
+      $Res Function(_$LiquidSdkEvent_PaymentWaitingConfirmationImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? details = null,
+  }) {
+    return _then(_$LiquidSdkEvent_PaymentWaitingConfirmationImpl(
+      details: null == details
+          ? _value.details
+          : details // ignore: cast_nullable_to_non_nullable
+              as Payment,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$LiquidSdkEvent_PaymentWaitingConfirmationImpl extends LiquidSdkEvent_PaymentWaitingConfirmation {
+  const _$LiquidSdkEvent_PaymentWaitingConfirmationImpl({required this.details}) : super._();
+
+  @override
+  final Payment details;
+
+  @override
+  String toString() {
+    return 'LiquidSdkEvent.paymentWaitingConfirmation(details: $details)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$LiquidSdkEvent_PaymentWaitingConfirmationImpl &&
+            (identical(other.details, details) || other.details == details));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, details);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$LiquidSdkEvent_PaymentWaitingConfirmationImplCopyWith<_$LiquidSdkEvent_PaymentWaitingConfirmationImpl>
+      get copyWith => __$$LiquidSdkEvent_PaymentWaitingConfirmationImplCopyWithImpl<
+          _$LiquidSdkEvent_PaymentWaitingConfirmationImpl>(this, _$identity);
+}
+
+abstract class LiquidSdkEvent_PaymentWaitingConfirmation extends LiquidSdkEvent {
+  const factory LiquidSdkEvent_PaymentWaitingConfirmation({required final Payment details}) =
+      _$LiquidSdkEvent_PaymentWaitingConfirmationImpl;
+  const LiquidSdkEvent_PaymentWaitingConfirmation._() : super._();
+
+  Payment get details;
+  @JsonKey(ignore: true)
+  _$$LiquidSdkEvent_PaymentWaitingConfirmationImplCopyWith<_$LiquidSdkEvent_PaymentWaitingConfirmationImpl>
+      get copyWith => throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$LiquidSdkEvent_SyncedImplCopyWith<$Res> {
+  factory _$$LiquidSdkEvent_SyncedImplCopyWith(
+          _$LiquidSdkEvent_SyncedImpl value, $Res Function(_$LiquidSdkEvent_SyncedImpl) then) =
+      __$$LiquidSdkEvent_SyncedImplCopyWithImpl<$Res>;
+}
+
+/// @nodoc
+class __$$LiquidSdkEvent_SyncedImplCopyWithImpl<$Res>
+    extends _$LiquidSdkEventCopyWithImpl<$Res, _$LiquidSdkEvent_SyncedImpl>
+    implements _$$LiquidSdkEvent_SyncedImplCopyWith<$Res> {
+  __$$LiquidSdkEvent_SyncedImplCopyWithImpl(
+      _$LiquidSdkEvent_SyncedImpl _value, $Res Function(_$LiquidSdkEvent_SyncedImpl) _then)
+      : super(_value, _then);
+}
+
+/// @nodoc
+
+class _$LiquidSdkEvent_SyncedImpl extends LiquidSdkEvent_Synced {
+  const _$LiquidSdkEvent_SyncedImpl() : super._();
+
+  @override
+  String toString() {
+    return 'LiquidSdkEvent.synced()';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType && other is _$LiquidSdkEvent_SyncedImpl);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+}
+
+abstract class LiquidSdkEvent_Synced extends LiquidSdkEvent {
+  const factory LiquidSdkEvent_Synced() = _$LiquidSdkEvent_SyncedImpl;
+  const LiquidSdkEvent_Synced._() : super._();
+}
+
+/// @nodoc
+mixin _$OnchainFeeRate {}
+
+/// @nodoc
+abstract class $OnchainFeeRateCopyWith<$Res> {
+  factory $OnchainFeeRateCopyWith(OnchainFeeRate value, $Res Function(OnchainFeeRate) then) =
+      _$OnchainFeeRateCopyWithImpl<$Res, OnchainFeeRate>;
+}
+
+/// @nodoc
+class _$OnchainFeeRateCopyWithImpl<$Res, $Val extends OnchainFeeRate>
+    implements $OnchainFeeRateCopyWith<$Res> {
+  _$OnchainFeeRateCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+}
+
+/// @nodoc
+abstract class _$$OnchainFeeRate_SatPerVbyteImplCopyWith<$Res> {
+  factory _$$OnchainFeeRate_SatPerVbyteImplCopyWith(
+          _$OnchainFeeRate_SatPerVbyteImpl value, $Res Function(_$OnchainFeeRate_SatPerVbyteImpl) then) =
+      __$$OnchainFeeRate_SatPerVbyteImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({double satPerVbyte});
+}
+
+/// @nodoc
+class __$$OnchainFeeRate_SatPerVbyteImplCopyWithImpl<$Res>
+    extends _$OnchainFeeRateCopyWithImpl<$Res, _$OnchainFeeRate_SatPerVbyteImpl>
+    implements _$$OnchainFeeRate_SatPerVbyteImplCopyWith<$Res> {
+  __$$OnchainFeeRate_SatPerVbyteImplCopyWithImpl(
+      _$OnchainFeeRate_SatPerVbyteImpl _value, $Res Function(_$OnchainFeeRate_SatPerVbyteImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? satPerVbyte = null,
+  }) {
+    return _then(_$OnchainFeeRate_SatPerVbyteImpl(
+      satPerVbyte: null == satPerVbyte
+          ? _value.satPerVbyte
+          : satPerVbyte // ignore: cast_nullable_to_non_nullable
+              as double,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$OnchainFeeRate_SatPerVbyteImpl extends OnchainFeeRate_SatPerVbyte {
+  const _$OnchainFeeRate_SatPerVbyteImpl({required this.satPerVbyte}) : super._();
+
+  @override
+  final double satPerVbyte;
+
+  @override
+  String toString() {
+    return 'OnchainFeeRate.satPerVbyte(satPerVbyte: $satPerVbyte)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$OnchainFeeRate_SatPerVbyteImpl &&
+            (identical(other.satPerVbyte, satPerVbyte) || other.satPerVbyte == satPerVbyte));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, satPerVbyte);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$OnchainFeeRate_SatPerVbyteImplCopyWith<_$OnchainFeeRate_SatPerVbyteImpl> get copyWith =>
+      __$$OnchainFeeRate_SatPerVbyteImplCopyWithImpl<_$OnchainFeeRate_SatPerVbyteImpl>(this, _$identity);
+}
+
+abstract class OnchainFeeRate_SatPerVbyte extends OnchainFeeRate {
+  const factory OnchainFeeRate_SatPerVbyte({required final double satPerVbyte}) =
+      _$OnchainFeeRate_SatPerVbyteImpl;
+  const OnchainFeeRate_SatPerVbyte._() : super._();
+
+  double get satPerVbyte;
+  @JsonKey(ignore: true)
+  _$$OnchainFeeRate_SatPerVbyteImplCopyWith<_$OnchainFeeRate_SatPerVbyteImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$OnchainFeeRate_PriorityImplCopyWith<$Res> {
+  factory _$$OnchainFeeRate_PriorityImplCopyWith(
+          _$OnchainFeeRate_PriorityImpl value, $Res Function(_$OnchainFeeRate_PriorityImpl) then) =
+      __$$OnchainFeeRate_PriorityImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({FeePriority priority});
+}
+
+/// @nodoc
+class __$$OnchainFeeRate_PriorityImplCopyWithImpl<$Res>
+    extends _$OnchainFeeRateCopyWithImpl<$Res, _$OnchainFeeRate_PriorityImpl>
+    implements _$$OnchainFeeRate_PriorityImplCopyWith<$Res> {
+  __$$OnchainFeeRate_PriorityImplCopyWithImpl(
+      _$OnchainFeeRate_PriorityImpl _value, $Res Function(_$OnchainFeeRate_PriorityImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? priority = null,
+  }) {
+    return _then(_$OnchainFeeRate_PriorityImpl(
+      priority: null == priority
+          ? _value.priority
+          : priority // ignore: cast_nullable_to_non_nullable
+              as FeePriority,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$OnchainFeeRate_PriorityImpl extends OnchainFeeRate_Priority {
+  const _$OnchainFeeRate_PriorityImpl({required this.priority}) : super._();
+
+  @override
+  final FeePriority priority;
+
+  @override
+  String toString() {
+    return 'OnchainFeeRate.priority(priority: $priority)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$OnchainFeeRate_PriorityImpl &&
+            (identical(other.priority, priority) || other.priority == priority));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, priority);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$OnchainFeeRate_PriorityImplCopyWith<_$OnchainFeeRate_PriorityImpl> get copyWith =>
+      __$$OnchainFeeRate_PriorityImplCopyWithImpl<_$OnchainFeeRate_PriorityImpl>(this, _$identity);
+}
+
+abstract class OnchainFeeRate_Priority extends OnchainFeeRate {
+  const factory OnchainFeeRate_Priority({required final FeePriority priority}) =
+      _$OnchainFeeRate_PriorityImpl;
+  const OnchainFeeRate_Priority._() : super._();
+
+  FeePriority get priority;
+  @JsonKey(ignore: true)
+  _$$OnchainFeeRate_PriorityImplCopyWith<_$OnchainFeeRate_PriorityImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$SuccessAction {}
+
+/// @nodoc
+abstract class $SuccessActionCopyWith<$Res> {
+  factory $SuccessActionCopyWith(SuccessAction value, $Res Function(SuccessAction) then) =
+      _$SuccessActionCopyWithImpl<$Res, SuccessAction>;
+}
+
+/// @nodoc
+class _$SuccessActionCopyWithImpl<$Res, $Val extends SuccessAction> implements $SuccessActionCopyWith<$Res> {
+  _$SuccessActionCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+}
+
+/// @nodoc
+abstract class _$$SuccessAction_MessageImplCopyWith<$Res> {
+  factory _$$SuccessAction_MessageImplCopyWith(
+          _$SuccessAction_MessageImpl value, $Res Function(_$SuccessAction_MessageImpl) then) =
+      __$$SuccessAction_MessageImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String message});
+}
+
+/// @nodoc
+class __$$SuccessAction_MessageImplCopyWithImpl<$Res>
+    extends _$SuccessActionCopyWithImpl<$Res, _$SuccessAction_MessageImpl>
+    implements _$$SuccessAction_MessageImplCopyWith<$Res> {
+  __$$SuccessAction_MessageImplCopyWithImpl(
+      _$SuccessAction_MessageImpl _value, $Res Function(_$SuccessAction_MessageImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? message = null,
+  }) {
+    return _then(_$SuccessAction_MessageImpl(
+      message: null == message
+          ? _value.message
+          : message // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SuccessAction_MessageImpl extends SuccessAction_Message {
+  const _$SuccessAction_MessageImpl({required this.message}) : super._();
+
+  @override
+  final String message;
+
+  @override
+  String toString() {
+    return 'SuccessAction.message(message: $message)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SuccessAction_MessageImpl &&
+            (identical(other.message, message) || other.message == message));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, message);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SuccessAction_MessageImplCopyWith<_$SuccessAction_MessageImpl> get copyWith =>
+      __$$SuccessAction_MessageImplCopyWithImpl<_$SuccessAction_MessageImpl>(this, _$identity);
+}
+
+abstract class SuccessAction_Message extends SuccessAction {
+  const factory SuccessAction_Message({required final String message}) = _$SuccessAction_MessageImpl;
+  const SuccessAction_Message._() : super._();
+
+  String get message;
+  @JsonKey(ignore: true)
+  _$$SuccessAction_MessageImplCopyWith<_$SuccessAction_MessageImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SuccessAction_UrlImplCopyWith<$Res> {
+  factory _$$SuccessAction_UrlImplCopyWith(
+          _$SuccessAction_UrlImpl value, $Res Function(_$SuccessAction_UrlImpl) then) =
+      __$$SuccessAction_UrlImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String description, String url});
+}
+
+/// @nodoc
+class __$$SuccessAction_UrlImplCopyWithImpl<$Res>
+    extends _$SuccessActionCopyWithImpl<$Res, _$SuccessAction_UrlImpl>
+    implements _$$SuccessAction_UrlImplCopyWith<$Res> {
+  __$$SuccessAction_UrlImplCopyWithImpl(
+      _$SuccessAction_UrlImpl _value, $Res Function(_$SuccessAction_UrlImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? description = null,
+    Object? url = null,
+  }) {
+    return _then(_$SuccessAction_UrlImpl(
+      description: null == description
+          ? _value.description
+          : description // ignore: cast_nullable_to_non_nullable
+              as String,
+      url: null == url
+          ? _value.url
+          : url // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SuccessAction_UrlImpl extends SuccessAction_Url {
+  const _$SuccessAction_UrlImpl({required this.description, required this.url}) : super._();
+
+  @override
+  final String description;
+  @override
+  final String url;
+
+  @override
+  String toString() {
+    return 'SuccessAction.url(description: $description, url: $url)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SuccessAction_UrlImpl &&
+            (identical(other.description, description) || other.description == description) &&
+            (identical(other.url, url) || other.url == url));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, description, url);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SuccessAction_UrlImplCopyWith<_$SuccessAction_UrlImpl> get copyWith =>
+      __$$SuccessAction_UrlImplCopyWithImpl<_$SuccessAction_UrlImpl>(this, _$identity);
+}
+
+abstract class SuccessAction_Url extends SuccessAction {
+  const factory SuccessAction_Url({required final String description, required final String url}) =
+      _$SuccessAction_UrlImpl;
+  const SuccessAction_Url._() : super._();
+
+  String get description;
+  String get url;
+  @JsonKey(ignore: true)
+  _$$SuccessAction_UrlImplCopyWith<_$SuccessAction_UrlImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SuccessAction_AesImplCopyWith<$Res> {
+  factory _$$SuccessAction_AesImplCopyWith(
+          _$SuccessAction_AesImpl value, $Res Function(_$SuccessAction_AesImpl) then) =
+      __$$SuccessAction_AesImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String description, String ciphertext, String iv});
+}
+
+/// @nodoc
+class __$$SuccessAction_AesImplCopyWithImpl<$Res>
+    extends _$SuccessActionCopyWithImpl<$Res, _$SuccessAction_AesImpl>
+    implements _$$SuccessAction_AesImplCopyWith<$Res> {
+  __$$SuccessAction_AesImplCopyWithImpl(
+      _$SuccessAction_AesImpl _value, $Res Function(_$SuccessAction_AesImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? description = null,
+    Object? ciphertext = null,
+    Object? iv = null,
+  }) {
+    return _then(_$SuccessAction_AesImpl(
+      description: null == description
+          ? _value.description
+          : description // ignore: cast_nullable_to_non_nullable
+              as String,
+      ciphertext: null == ciphertext
+          ? _value.ciphertext
+          : ciphertext // ignore: cast_nullable_to_non_nullable
+              as String,
+      iv: null == iv
+          ? _value.iv
+          : iv // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SuccessAction_AesImpl extends SuccessAction_Aes {
+  const _$SuccessAction_AesImpl({required this.description, required this.ciphertext, required this.iv})
+      : super._();
+
+  @override
+  final String description;
+  @override
+  final String ciphertext;
+  @override
+  final String iv;
+
+  @override
+  String toString() {
+    return 'SuccessAction.aes(description: $description, ciphertext: $ciphertext, iv: $iv)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SuccessAction_AesImpl &&
+            (identical(other.description, description) || other.description == description) &&
+            (identical(other.ciphertext, ciphertext) || other.ciphertext == ciphertext) &&
+            (identical(other.iv, iv) || other.iv == iv));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, description, ciphertext, iv);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SuccessAction_AesImplCopyWith<_$SuccessAction_AesImpl> get copyWith =>
+      __$$SuccessAction_AesImplCopyWithImpl<_$SuccessAction_AesImpl>(this, _$identity);
+}
+
+abstract class SuccessAction_Aes extends SuccessAction {
+  const factory SuccessAction_Aes(
+      {required final String description,
+      required final String ciphertext,
+      required final String iv}) =
+          _$SuccessAction_AesImpl;
+  const SuccessAction_Aes._() : super._();
+
+  String get description;
+  String get ciphertext;
+  String get iv;
+  @JsonKey(ignore: true)
+  _$$SuccessAction_AesImplCopyWith<_$SuccessAction_AesImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+mixin _$SuccessActionProcessed {}
+
+/// @nodoc
+abstract class $SuccessActionProcessedCopyWith<$Res> {
+  factory $SuccessActionProcessedCopyWith(
+          SuccessActionProcessed value, $Res Function(SuccessActionProcessed) then) =
+      _$SuccessActionProcessedCopyWithImpl<$Res, SuccessActionProcessed>;
+}
+
+/// @nodoc
+class _$SuccessActionProcessedCopyWithImpl<$Res, $Val extends SuccessActionProcessed>
+    implements $SuccessActionProcessedCopyWith<$Res> {
+  _$SuccessActionProcessedCopyWithImpl(this._value, this._then);
+
+  // ignore: unused_field
+  final $Val _value;
+  // ignore: unused_field
+  final $Res Function($Val) _then;
+}
+
+/// @nodoc
+abstract class _$$SuccessActionProcessed_MessageImplCopyWith<$Res> {
+  factory _$$SuccessActionProcessed_MessageImplCopyWith(_$SuccessActionProcessed_MessageImpl value,
+          $Res Function(_$SuccessActionProcessed_MessageImpl) then) =
+      __$$SuccessActionProcessed_MessageImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String message});
+}
+
+/// @nodoc
+class __$$SuccessActionProcessed_MessageImplCopyWithImpl<$Res>
+    extends _$SuccessActionProcessedCopyWithImpl<$Res, _$SuccessActionProcessed_MessageImpl>
+    implements _$$SuccessActionProcessed_MessageImplCopyWith<$Res> {
+  __$$SuccessActionProcessed_MessageImplCopyWithImpl(
+      _$SuccessActionProcessed_MessageImpl _value, $Res Function(_$SuccessActionProcessed_MessageImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? message = null,
+  }) {
+    return _then(_$SuccessActionProcessed_MessageImpl(
+      message: null == message
+          ? _value.message
+          : message // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SuccessActionProcessed_MessageImpl extends SuccessActionProcessed_Message {
+  const _$SuccessActionProcessed_MessageImpl({required this.message}) : super._();
+
+  @override
+  final String message;
+
+  @override
+  String toString() {
+    return 'SuccessActionProcessed.message(message: $message)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SuccessActionProcessed_MessageImpl &&
+            (identical(other.message, message) || other.message == message));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, message);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SuccessActionProcessed_MessageImplCopyWith<_$SuccessActionProcessed_MessageImpl> get copyWith =>
+      __$$SuccessActionProcessed_MessageImplCopyWithImpl<_$SuccessActionProcessed_MessageImpl>(
+          this, _$identity);
+}
+
+abstract class SuccessActionProcessed_Message extends SuccessActionProcessed {
+  const factory SuccessActionProcessed_Message({required final String message}) =
+      _$SuccessActionProcessed_MessageImpl;
+  const SuccessActionProcessed_Message._() : super._();
+
+  String get message;
+  @JsonKey(ignore: true)
+  _$$SuccessActionProcessed_MessageImplCopyWith<_$SuccessActionProcessed_MessageImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SuccessActionProcessed_UrlImplCopyWith<$Res> {
+  factory _$$SuccessActionProcessed_UrlImplCopyWith(
+          _$SuccessActionProcessed_UrlImpl value, $Res Function(_$SuccessActionProcessed_UrlImpl) then) =
+      __$$SuccessActionProcessed_UrlImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String description, String url});
+}
+
+/// @nodoc
+class __$$SuccessActionProcessed_UrlImplCopyWithImpl<$Res>
+    extends _$SuccessActionProcessedCopyWithImpl<$Res, _$SuccessActionProcessed_UrlImpl>
+    implements _$$SuccessActionProcessed_UrlImplCopyWith<$Res> {
+  __$$SuccessActionProcessed_UrlImplCopyWithImpl(
+      _$SuccessActionProcessed_UrlImpl _value, $Res Function(_$SuccessActionProcessed_UrlImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? description = null,
+    Object? url = null,
+  }) {
+    return _then(_$SuccessActionProcessed_UrlImpl(
+      description: null == description
+          ? _value.description
+          : description // ignore: cast_nullable_to_non_nullable
+              as String,
+      url: null == url
+          ? _value.url
+          : url // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SuccessActionProcessed_UrlImpl extends SuccessActionProcessed_Url {
+  const _$SuccessActionProcessed_UrlImpl({required this.description, required this.url}) : super._();
+
+  @override
+  final String description;
+  @override
+  final String url;
+
+  @override
+  String toString() {
+    return 'SuccessActionProcessed.url(description: $description, url: $url)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SuccessActionProcessed_UrlImpl &&
+            (identical(other.description, description) || other.description == description) &&
+            (identical(other.url, url) || other.url == url));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, description, url);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SuccessActionProcessed_UrlImplCopyWith<_$SuccessActionProcessed_UrlImpl> get copyWith =>
+      __$$SuccessActionProcessed_UrlImplCopyWithImpl<_$SuccessActionProcessed_UrlImpl>(this, _$identity);
+}
+
+abstract class SuccessActionProcessed_Url extends SuccessActionProcessed {
+  const factory SuccessActionProcessed_Url({required final String description, required final String url}) =
+      _$SuccessActionProcessed_UrlImpl;
+  const SuccessActionProcessed_Url._() : super._();
+
+  String get description;
+  String get url;
+  @JsonKey(ignore: true)
+  _$$SuccessActionProcessed_UrlImplCopyWith<_$SuccessActionProcessed_UrlImpl> get copyWith =>
+      throw _privateConstructorUsedError;
+}
+
+/// @nodoc
+abstract class _$$SuccessActionProcessed_AesImplCopyWith<$Res> {
+  factory _$$SuccessActionProcessed_AesImplCopyWith(
+          _$SuccessActionProcessed_AesImpl value, $Res Function(_$SuccessActionProcessed_AesImpl) then) =
+      __$$SuccessActionProcessed_AesImplCopyWithImpl<$Res>;
+  @useResult
+  $Res call({String description, String plaintext});
+}
+
+/// @nodoc
+class __$$SuccessActionProcessed_AesImplCopyWithImpl<$Res>
+    extends _$SuccessActionProcessedCopyWithImpl<$Res, _$SuccessActionProcessed_AesImpl>
+    implements _$$SuccessActionProcessed_AesImplCopyWith<$Res> {
+  __$$SuccessActionProcessed_AesImplCopyWithImpl(
+      _$SuccessActionProcessed_AesImpl _value, $Res Function(_$SuccessActionProcessed_AesImpl) _then)
+      : super(_value, _then);
+
+  @pragma('vm:prefer-inline')
+  @override
+  $Res call({
+    Object? description = null,
+    Object? plaintext = null,
+  }) {
+    return _then(_$SuccessActionProcessed_AesImpl(
+      description: null == description
+          ? _value.description
+          : description // ignore: cast_nullable_to_non_nullable
+              as String,
+      plaintext: null == plaintext
+          ? _value.plaintext
+          : plaintext // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class _$SuccessActionProcessed_AesImpl extends SuccessActionProcessed_Aes {
+  const _$SuccessActionProcessed_AesImpl({required this.description, required this.plaintext}) : super._();
+
+  @override
+  final String description;
+  @override
+  final String plaintext;
+
+  @override
+  String toString() {
+    return 'SuccessActionProcessed.aes(description: $description, plaintext: $plaintext)';
+  }
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is _$SuccessActionProcessed_AesImpl &&
+            (identical(other.description, description) || other.description == description) &&
+            (identical(other.plaintext, plaintext) || other.plaintext == plaintext));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, description, plaintext);
+
+  @JsonKey(ignore: true)
+  @override
+  @pragma('vm:prefer-inline')
+  _$$SuccessActionProcessed_AesImplCopyWith<_$SuccessActionProcessed_AesImpl> get copyWith =>
+      __$$SuccessActionProcessed_AesImplCopyWithImpl<_$SuccessActionProcessed_AesImpl>(this, _$identity);
+}
+
+abstract class SuccessActionProcessed_Aes extends SuccessActionProcessed {
+  const factory SuccessActionProcessed_Aes(
+      {required final String description,
+      required final String plaintext}) =
+          _$SuccessActionProcessed_AesImpl;
+  const SuccessActionProcessed_Aes._() : super._();
+
+  String get description;
+  String get plaintext;
+  @JsonKey(ignore: true)
+  _$$SuccessActionProcessed_AesImplCopyWith<_$SuccessActionProcessed_AesImpl> get copyWith =>
+      throw _privateConstructorUsedError;
 }
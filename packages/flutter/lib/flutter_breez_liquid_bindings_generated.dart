@@ -107,6 +107,60 @@ class FlutterBreezLiquidBindings {
       _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_empty_wallet_cachePtr
           .asFunction<WireSyncRust2DartDco Function(int)>();
 
+  void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_export_payments(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_export_payments_request> req,
+  ) {
+    return _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_export_payments(
+      port_,
+      that,
+      req,
+    );
+  }
+
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_export_paymentsPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_export_payments_request>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_export_payments');
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_export_payments =
+      _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_export_paymentsPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_export_payments_request>)>();
+
+  void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fetch_lightning_limits(
+    int port_,
+    int that,
+  ) {
+    return _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fetch_lightning_limits(
+      port_,
+      that,
+    );
+  }
+
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fetch_lightning_limitsPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.UintPtr)>>(
+          'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fetch_lightning_limits');
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fetch_lightning_limits =
+      _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fetch_lightning_limitsPtr
+          .asFunction<void Function(int, int)>();
+
+  void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fetch_recommended_fees(
+    int port_,
+    int that,
+  ) {
+    return _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fetch_recommended_fees(
+      port_,
+      that,
+    );
+  }
+
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fetch_recommended_feesPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.UintPtr)>>(
+          'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fetch_recommended_fees');
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fetch_recommended_fees =
+      _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_fetch_recommended_feesPtr
+          .asFunction<void Function(int, int)>();
+
   void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_info(
     int port_,
     int that,
@@ -124,23 +178,220 @@ class FlutterBreezLiquidBindings {
       _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_infoPtr
           .asFunction<void Function(int, int)>();
 
+  void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_payment(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_get_payment_request> req,
+  ) {
+    return _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_payment(
+      port_,
+      that,
+      req,
+    );
+  }
+
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_paymentPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_get_payment_request>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_payment');
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_payment =
+      _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_get_paymentPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_get_payment_request>)>();
+
+  void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_electrum_servers(
+    int port_,
+    int that,
+  ) {
+    return _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_electrum_servers(
+      port_,
+      that,
+    );
+  }
+
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_electrum_serversPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.UintPtr)>>(
+          'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_electrum_servers');
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_electrum_servers =
+      _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_electrum_serversPtr
+          .asFunction<void Function(int, int)>();
+
   void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_payments(
     int port_,
     int that,
+    ffi.Pointer<wire_cst_list_payments_request> req,
   ) {
     return _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_payments(
       port_,
       that,
+      req,
     );
   }
 
-  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_paymentsPtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.UintPtr)>>(
-          'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_payments');
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_paymentsPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_list_payments_request>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_payments');
   late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_payments =
       _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_paymentsPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_list_payments_request>)>();
+
+  void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_refundables(
+    int port_,
+    int that,
+  ) {
+    return _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_refundables(
+      port_,
+      that,
+    );
+  }
+
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_refundablesPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Int64, ffi.UintPtr)>>(
+          'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_refundables');
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_refundables =
+      _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_list_refundablesPtr
           .asFunction<void Function(int, int)>();
 
+  void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_lnurl_pay(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_prepare_ln_url_pay_response> req,
+  ) {
+    return _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_lnurl_pay(
+      port_,
+      that,
+      req,
+    );
+  }
+
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_lnurl_payPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_prepare_ln_url_pay_response>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_lnurl_pay');
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_lnurl_pay =
+      _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_lnurl_payPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_prepare_ln_url_pay_response>)>();
+
+  void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_lnurl_withdraw(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_ln_url_withdraw_request> req,
+  ) {
+    return _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_lnurl_withdraw(
+      port_,
+      that,
+      req,
+    );
+  }
+
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_lnurl_withdrawPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_ln_url_withdraw_request>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_lnurl_withdraw');
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_lnurl_withdraw =
+      _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_lnurl_withdrawPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_ln_url_withdraw_request>)>();
+
+  WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_parse(
+    int that,
+    ffi.Pointer<wire_cst_list_prim_u_8_strict> input,
+  ) {
+    return _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_parse(
+      that,
+      input,
+    );
+  }
+
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_parsePtr = _lookup<
+          ffi.NativeFunction<
+              WireSyncRust2DartDco Function(ffi.UintPtr, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_parse');
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_parse =
+      _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_parsePtr
+          .asFunction<WireSyncRust2DartDco Function(int, ffi.Pointer<wire_cst_list_prim_u_8_strict>)>();
+
+  void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_pay_onchain(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_pay_onchain_request> req,
+  ) {
+    return _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_pay_onchain(
+      port_,
+      that,
+      req,
+    );
+  }
+
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_pay_onchainPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_pay_onchain_request>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_pay_onchain');
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_pay_onchain =
+      _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_pay_onchainPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_pay_onchain_request>)>();
+
+  void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_lnurl_pay(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_prepare_ln_url_pay_request> req,
+  ) {
+    return _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_lnurl_pay(
+      port_,
+      that,
+      req,
+    );
+  }
+
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_lnurl_payPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_prepare_ln_url_pay_request>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_lnurl_pay');
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_lnurl_pay =
+      _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_lnurl_payPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_prepare_ln_url_pay_request>)>();
+
+  void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_pay_onchain(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_prepare_pay_onchain_request> req,
+  ) {
+    return _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_pay_onchain(
+      port_,
+      that,
+      req,
+    );
+  }
+
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_pay_onchainPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_prepare_pay_onchain_request>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_pay_onchain');
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_pay_onchain =
+      _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_pay_onchainPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_prepare_pay_onchain_request>)>();
+
+  void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_receive_onchain(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_prepare_receive_onchain_request> req,
+  ) {
+    return _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_receive_onchain(
+      port_,
+      that,
+      req,
+    );
+  }
+
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_receive_onchainPtr =
+      _lookup<
+              ffi.NativeFunction<
+                  ffi.Void Function(
+                      ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_prepare_receive_onchain_request>)>>(
+          'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_receive_onchain');
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_receive_onchain =
+      _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_receive_onchainPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_prepare_receive_onchain_request>)>();
+
   void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_receive_payment(
     int port_,
     int that,
@@ -162,6 +413,46 @@ class FlutterBreezLiquidBindings {
       _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_receive_paymentPtr
           .asFunction<void Function(int, int, ffi.Pointer<wire_cst_prepare_receive_request>)>();
 
+  void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_refund(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_prepare_refund_request> req,
+  ) {
+    return _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_refund(
+      port_,
+      that,
+      req,
+    );
+  }
+
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_refundPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_prepare_refund_request>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_refund');
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_refund =
+      _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_refundPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_prepare_refund_request>)>();
+
+  void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_send_onchain(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_prepare_send_onchain_request> req,
+  ) {
+    return _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_send_onchain(
+      port_,
+      that,
+      req,
+    );
+  }
+
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_send_onchainPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_prepare_send_onchain_request>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_send_onchain');
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_send_onchain =
+      _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_send_onchainPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_prepare_send_onchain_request>)>();
+
   void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_send_payment(
     int port_,
     int that,
@@ -182,6 +473,47 @@ class FlutterBreezLiquidBindings {
       _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_prepare_send_paymentPtr
           .asFunction<void Function(int, int, ffi.Pointer<wire_cst_prepare_send_request>)>();
 
+  void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_receive_liquid(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_receive_liquid_request> req,
+  ) {
+    return _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_receive_liquid(
+      port_,
+      that,
+      req,
+    );
+  }
+
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_receive_liquidPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_receive_liquid_request>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_receive_liquid');
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_receive_liquid =
+      _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_receive_liquidPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_receive_liquid_request>)>();
+
+  void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_receive_onchain(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_prepare_receive_onchain_response> req,
+  ) {
+    return _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_receive_onchain(
+      port_,
+      that,
+      req,
+    );
+  }
+
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_receive_onchainPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(
+                  ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_prepare_receive_onchain_response>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_receive_onchain');
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_receive_onchain =
+      _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_receive_onchainPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_prepare_receive_onchain_response>)>();
+
   void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_receive_payment(
     int port_,
     int that,
@@ -202,6 +534,26 @@ class FlutterBreezLiquidBindings {
       _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_receive_paymentPtr
           .asFunction<void Function(int, int, ffi.Pointer<wire_cst_prepare_receive_response>)>();
 
+  void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_refund(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_refund_request> req,
+  ) {
+    return _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_refund(
+      port_,
+      that,
+      req,
+    );
+  }
+
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_refundPtr = _lookup<
+          ffi
+          .NativeFunction<ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_refund_request>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_refund');
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_refund =
+      _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_refundPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_refund_request>)>();
+
   WireSyncRust2DartDco frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_restore(
     int that,
     ffi.Pointer<wire_cst_restore_request> req,
@@ -220,6 +572,27 @@ class FlutterBreezLiquidBindings {
       _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_restorePtr
           .asFunction<WireSyncRust2DartDco Function(int, ffi.Pointer<wire_cst_restore_request>)>();
 
+  void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_send_onchain(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_prepare_send_onchain_response> req,
+  ) {
+    return _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_send_onchain(
+      port_,
+      that,
+      req,
+    );
+  }
+
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_send_onchainPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(
+                  ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_prepare_send_onchain_response>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_send_onchain');
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_send_onchain =
+      _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_send_onchainPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_prepare_send_onchain_response>)>();
+
   void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_send_payment(
     int port_,
     int that,
@@ -240,6 +613,26 @@ class FlutterBreezLiquidBindings {
       _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_send_paymentPtr
           .asFunction<void Function(int, int, ffi.Pointer<wire_cst_prepare_send_response>)>();
 
+  void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_set_payment_metadata(
+    int port_,
+    int that,
+    ffi.Pointer<wire_cst_set_payment_metadata_request> req,
+  ) {
+    return _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_set_payment_metadata(
+      port_,
+      that,
+      req,
+    );
+  }
+
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_set_payment_metadataPtr = _lookup<
+          ffi.NativeFunction<
+              ffi.Void Function(ffi.Int64, ffi.UintPtr, ffi.Pointer<wire_cst_set_payment_metadata_request>)>>(
+      'frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_set_payment_metadata');
+  late final _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_set_payment_metadata =
+      _frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_set_payment_metadataPtr
+          .asFunction<void Function(int, int, ffi.Pointer<wire_cst_set_payment_metadata_request>)>();
+
   void frbgen_breez_liquid_wire__crate__bindings__BindingLiquidSdk_sync(
     int port_,
     int that,
@@ -375,6 +768,17 @@ class FlutterBreezLiquidBindings {
       _frbgen_breez_liquid_rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBindingLiquidSdkPtr
           .asFunction<void Function(ffi.Pointer<ffi.Void>)>();
 
+  ffi.Pointer<wire_cst_asset_metadata> frbgen_breez_liquid_cst_new_box_autoadd_asset_metadata() {
+    return _frbgen_breez_liquid_cst_new_box_autoadd_asset_metadata();
+  }
+
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_asset_metadataPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_asset_metadata> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_asset_metadata');
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_asset_metadata =
+      _frbgen_breez_liquid_cst_new_box_autoadd_asset_metadataPtr
+          .asFunction<ffi.Pointer<wire_cst_asset_metadata> Function()>();
+
   ffi.Pointer<wire_cst_backup_request> frbgen_breez_liquid_cst_new_box_autoadd_backup_request() {
     return _frbgen_breez_liquid_cst_new_box_autoadd_backup_request();
   }
@@ -398,6 +802,31 @@ class FlutterBreezLiquidBindings {
       _frbgen_breez_liquid_cst_new_box_autoadd_binding_event_listenerPtr
           .asFunction<ffi.Pointer<wire_cst_binding_event_listener> Function()>();
 
+  ffi.Pointer<wire_cst_bitcoin_address_data> frbgen_breez_liquid_cst_new_box_autoadd_bitcoin_address_data() {
+    return _frbgen_breez_liquid_cst_new_box_autoadd_bitcoin_address_data();
+  }
+
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_bitcoin_address_dataPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_bitcoin_address_data> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_bitcoin_address_data');
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_bitcoin_address_data =
+      _frbgen_breez_liquid_cst_new_box_autoadd_bitcoin_address_dataPtr
+          .asFunction<ffi.Pointer<wire_cst_bitcoin_address_data> Function()>();
+
+  ffi.Pointer<ffi.Bool> frbgen_breez_liquid_cst_new_box_autoadd_bool(
+    bool value,
+  ) {
+    return _frbgen_breez_liquid_cst_new_box_autoadd_bool(
+      value,
+    );
+  }
+
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_boolPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Bool> Function(ffi.Bool)>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_bool');
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_bool =
+      _frbgen_breez_liquid_cst_new_box_autoadd_boolPtr.asFunction<ffi.Pointer<ffi.Bool> Function(bool)>();
+
   ffi.Pointer<wire_cst_connect_request> frbgen_breez_liquid_cst_new_box_autoadd_connect_request() {
     return _frbgen_breez_liquid_cst_new_box_autoadd_connect_request();
   }
@@ -409,6 +838,65 @@ class FlutterBreezLiquidBindings {
       _frbgen_breez_liquid_cst_new_box_autoadd_connect_requestPtr
           .asFunction<ffi.Pointer<wire_cst_connect_request> Function()>();
 
+  ffi.Pointer<wire_cst_export_payments_request>
+      frbgen_breez_liquid_cst_new_box_autoadd_export_payments_request() {
+    return _frbgen_breez_liquid_cst_new_box_autoadd_export_payments_request();
+  }
+
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_export_payments_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_export_payments_request> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_export_payments_request');
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_export_payments_request =
+      _frbgen_breez_liquid_cst_new_box_autoadd_export_payments_requestPtr
+          .asFunction<ffi.Pointer<wire_cst_export_payments_request> Function()>();
+
+  ffi.Pointer<ffi.Double> frbgen_breez_liquid_cst_new_box_autoadd_f_64(
+    double value,
+  ) {
+    return _frbgen_breez_liquid_cst_new_box_autoadd_f_64(
+      value,
+    );
+  }
+
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_f_64Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Double> Function(ffi.Double)>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_f_64');
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_f_64 =
+      _frbgen_breez_liquid_cst_new_box_autoadd_f_64Ptr.asFunction<ffi.Pointer<ffi.Double> Function(double)>();
+
+  ffi.Pointer<wire_cst_fee_breakdown> frbgen_breez_liquid_cst_new_box_autoadd_fee_breakdown() {
+    return _frbgen_breez_liquid_cst_new_box_autoadd_fee_breakdown();
+  }
+
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_fee_breakdownPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_fee_breakdown> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_fee_breakdown');
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_fee_breakdown =
+      _frbgen_breez_liquid_cst_new_box_autoadd_fee_breakdownPtr
+          .asFunction<ffi.Pointer<wire_cst_fee_breakdown> Function()>();
+
+  ffi.Pointer<wire_cst_get_payment_request> frbgen_breez_liquid_cst_new_box_autoadd_get_payment_request() {
+    return _frbgen_breez_liquid_cst_new_box_autoadd_get_payment_request();
+  }
+
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_get_payment_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_get_payment_request> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_get_payment_request');
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_get_payment_request =
+      _frbgen_breez_liquid_cst_new_box_autoadd_get_payment_requestPtr
+          .asFunction<ffi.Pointer<wire_cst_get_payment_request> Function()>();
+
+  ffi.Pointer<wire_cst_liquid_address_data> frbgen_breez_liquid_cst_new_box_autoadd_liquid_address_data() {
+    return _frbgen_breez_liquid_cst_new_box_autoadd_liquid_address_data();
+  }
+
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_liquid_address_dataPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_liquid_address_data> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_liquid_address_data');
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_liquid_address_data =
+      _frbgen_breez_liquid_cst_new_box_autoadd_liquid_address_dataPtr
+          .asFunction<ffi.Pointer<wire_cst_liquid_address_data> Function()>();
+
   ffi.Pointer<wire_cst_liquid_sdk_event> frbgen_breez_liquid_cst_new_box_autoadd_liquid_sdk_event() {
     return _frbgen_breez_liquid_cst_new_box_autoadd_liquid_sdk_event();
   }
@@ -420,17 +908,145 @@ class FlutterBreezLiquidBindings {
       _frbgen_breez_liquid_cst_new_box_autoadd_liquid_sdk_eventPtr
           .asFunction<ffi.Pointer<wire_cst_liquid_sdk_event> Function()>();
 
-  ffi.Pointer<wire_cst_payment> frbgen_breez_liquid_cst_new_box_autoadd_payment() {
-    return _frbgen_breez_liquid_cst_new_box_autoadd_payment();
+  ffi.Pointer<wire_cst_list_payments_request>
+      frbgen_breez_liquid_cst_new_box_autoadd_list_payments_request() {
+    return _frbgen_breez_liquid_cst_new_box_autoadd_list_payments_request();
   }
 
-  late final _frbgen_breez_liquid_cst_new_box_autoadd_paymentPtr =
-      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_payment> Function()>>(
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_list_payments_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_payments_request> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_list_payments_request');
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_list_payments_request =
+      _frbgen_breez_liquid_cst_new_box_autoadd_list_payments_requestPtr
+          .asFunction<ffi.Pointer<wire_cst_list_payments_request> Function()>();
+
+  ffi.Pointer<wire_cst_ln_invoice> frbgen_breez_liquid_cst_new_box_autoadd_ln_invoice() {
+    return _frbgen_breez_liquid_cst_new_box_autoadd_ln_invoice();
+  }
+
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_ln_invoicePtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_ln_invoice> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_ln_invoice');
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_ln_invoice =
+      _frbgen_breez_liquid_cst_new_box_autoadd_ln_invoicePtr
+          .asFunction<ffi.Pointer<wire_cst_ln_invoice> Function()>();
+
+  ffi.Pointer<wire_cst_ln_url_withdraw_request>
+      frbgen_breez_liquid_cst_new_box_autoadd_ln_url_withdraw_request() {
+    return _frbgen_breez_liquid_cst_new_box_autoadd_ln_url_withdraw_request();
+  }
+
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_ln_url_withdraw_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_ln_url_withdraw_request> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_ln_url_withdraw_request');
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_ln_url_withdraw_request =
+      _frbgen_breez_liquid_cst_new_box_autoadd_ln_url_withdraw_requestPtr
+          .asFunction<ffi.Pointer<wire_cst_ln_url_withdraw_request> Function()>();
+
+  ffi.Pointer<wire_cst_onchain_fee_rate> frbgen_breez_liquid_cst_new_box_autoadd_onchain_fee_rate() {
+    return _frbgen_breez_liquid_cst_new_box_autoadd_onchain_fee_rate();
+  }
+
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_onchain_fee_ratePtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_onchain_fee_rate> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_onchain_fee_rate');
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_onchain_fee_rate =
+      _frbgen_breez_liquid_cst_new_box_autoadd_onchain_fee_ratePtr
+          .asFunction<ffi.Pointer<wire_cst_onchain_fee_rate> Function()>();
+
+  ffi.Pointer<wire_cst_pay_onchain_request> frbgen_breez_liquid_cst_new_box_autoadd_pay_onchain_request() {
+    return _frbgen_breez_liquid_cst_new_box_autoadd_pay_onchain_request();
+  }
+
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_pay_onchain_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_pay_onchain_request> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_pay_onchain_request');
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_pay_onchain_request =
+      _frbgen_breez_liquid_cst_new_box_autoadd_pay_onchain_requestPtr
+          .asFunction<ffi.Pointer<wire_cst_pay_onchain_request> Function()>();
+
+  ffi.Pointer<wire_cst_payment> frbgen_breez_liquid_cst_new_box_autoadd_payment() {
+    return _frbgen_breez_liquid_cst_new_box_autoadd_payment();
+  }
+
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_paymentPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_payment> Function()>>(
           'frbgen_breez_liquid_cst_new_box_autoadd_payment');
   late final _frbgen_breez_liquid_cst_new_box_autoadd_payment =
       _frbgen_breez_liquid_cst_new_box_autoadd_paymentPtr
           .asFunction<ffi.Pointer<wire_cst_payment> Function()>();
 
+  ffi.Pointer<wire_cst_payment_metadata> frbgen_breez_liquid_cst_new_box_autoadd_payment_metadata() {
+    return _frbgen_breez_liquid_cst_new_box_autoadd_payment_metadata();
+  }
+
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_payment_metadataPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_payment_metadata> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_payment_metadata');
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_payment_metadata =
+      _frbgen_breez_liquid_cst_new_box_autoadd_payment_metadataPtr
+          .asFunction<ffi.Pointer<wire_cst_payment_metadata> Function()>();
+
+  ffi.Pointer<wire_cst_prepare_ln_url_pay_request>
+      frbgen_breez_liquid_cst_new_box_autoadd_prepare_ln_url_pay_request() {
+    return _frbgen_breez_liquid_cst_new_box_autoadd_prepare_ln_url_pay_request();
+  }
+
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_prepare_ln_url_pay_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_prepare_ln_url_pay_request> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_prepare_ln_url_pay_request');
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_prepare_ln_url_pay_request =
+      _frbgen_breez_liquid_cst_new_box_autoadd_prepare_ln_url_pay_requestPtr
+          .asFunction<ffi.Pointer<wire_cst_prepare_ln_url_pay_request> Function()>();
+
+  ffi.Pointer<wire_cst_prepare_ln_url_pay_response>
+      frbgen_breez_liquid_cst_new_box_autoadd_prepare_ln_url_pay_response() {
+    return _frbgen_breez_liquid_cst_new_box_autoadd_prepare_ln_url_pay_response();
+  }
+
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_prepare_ln_url_pay_responsePtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_prepare_ln_url_pay_response> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_prepare_ln_url_pay_response');
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_prepare_ln_url_pay_response =
+      _frbgen_breez_liquid_cst_new_box_autoadd_prepare_ln_url_pay_responsePtr
+          .asFunction<ffi.Pointer<wire_cst_prepare_ln_url_pay_response> Function()>();
+
+  ffi.Pointer<wire_cst_prepare_pay_onchain_request>
+      frbgen_breez_liquid_cst_new_box_autoadd_prepare_pay_onchain_request() {
+    return _frbgen_breez_liquid_cst_new_box_autoadd_prepare_pay_onchain_request();
+  }
+
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_prepare_pay_onchain_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_prepare_pay_onchain_request> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_prepare_pay_onchain_request');
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_prepare_pay_onchain_request =
+      _frbgen_breez_liquid_cst_new_box_autoadd_prepare_pay_onchain_requestPtr
+          .asFunction<ffi.Pointer<wire_cst_prepare_pay_onchain_request> Function()>();
+
+  ffi.Pointer<wire_cst_prepare_receive_onchain_request>
+      frbgen_breez_liquid_cst_new_box_autoadd_prepare_receive_onchain_request() {
+    return _frbgen_breez_liquid_cst_new_box_autoadd_prepare_receive_onchain_request();
+  }
+
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_prepare_receive_onchain_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_prepare_receive_onchain_request> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_prepare_receive_onchain_request');
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_prepare_receive_onchain_request =
+      _frbgen_breez_liquid_cst_new_box_autoadd_prepare_receive_onchain_requestPtr
+          .asFunction<ffi.Pointer<wire_cst_prepare_receive_onchain_request> Function()>();
+
+  ffi.Pointer<wire_cst_prepare_receive_onchain_response>
+      frbgen_breez_liquid_cst_new_box_autoadd_prepare_receive_onchain_response() {
+    return _frbgen_breez_liquid_cst_new_box_autoadd_prepare_receive_onchain_response();
+  }
+
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_prepare_receive_onchain_responsePtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_prepare_receive_onchain_response> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_prepare_receive_onchain_response');
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_prepare_receive_onchain_response =
+      _frbgen_breez_liquid_cst_new_box_autoadd_prepare_receive_onchain_responsePtr
+          .asFunction<ffi.Pointer<wire_cst_prepare_receive_onchain_response> Function()>();
+
   ffi.Pointer<wire_cst_prepare_receive_request>
       frbgen_breez_liquid_cst_new_box_autoadd_prepare_receive_request() {
     return _frbgen_breez_liquid_cst_new_box_autoadd_prepare_receive_request();
@@ -455,6 +1071,42 @@ class FlutterBreezLiquidBindings {
       _frbgen_breez_liquid_cst_new_box_autoadd_prepare_receive_responsePtr
           .asFunction<ffi.Pointer<wire_cst_prepare_receive_response> Function()>();
 
+  ffi.Pointer<wire_cst_prepare_refund_request>
+      frbgen_breez_liquid_cst_new_box_autoadd_prepare_refund_request() {
+    return _frbgen_breez_liquid_cst_new_box_autoadd_prepare_refund_request();
+  }
+
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_prepare_refund_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_prepare_refund_request> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_prepare_refund_request');
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_prepare_refund_request =
+      _frbgen_breez_liquid_cst_new_box_autoadd_prepare_refund_requestPtr
+          .asFunction<ffi.Pointer<wire_cst_prepare_refund_request> Function()>();
+
+  ffi.Pointer<wire_cst_prepare_send_onchain_request>
+      frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_onchain_request() {
+    return _frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_onchain_request();
+  }
+
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_onchain_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_prepare_send_onchain_request> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_onchain_request');
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_onchain_request =
+      _frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_onchain_requestPtr
+          .asFunction<ffi.Pointer<wire_cst_prepare_send_onchain_request> Function()>();
+
+  ffi.Pointer<wire_cst_prepare_send_onchain_response>
+      frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_onchain_response() {
+    return _frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_onchain_response();
+  }
+
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_onchain_responsePtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_prepare_send_onchain_response> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_onchain_response');
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_onchain_response =
+      _frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_onchain_responsePtr
+          .asFunction<ffi.Pointer<wire_cst_prepare_send_onchain_response> Function()>();
+
   ffi.Pointer<wire_cst_prepare_send_request> frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_request() {
     return _frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_request();
   }
@@ -478,6 +1130,29 @@ class FlutterBreezLiquidBindings {
       _frbgen_breez_liquid_cst_new_box_autoadd_prepare_send_responsePtr
           .asFunction<ffi.Pointer<wire_cst_prepare_send_response> Function()>();
 
+  ffi.Pointer<wire_cst_receive_liquid_request>
+      frbgen_breez_liquid_cst_new_box_autoadd_receive_liquid_request() {
+    return _frbgen_breez_liquid_cst_new_box_autoadd_receive_liquid_request();
+  }
+
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_receive_liquid_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_receive_liquid_request> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_receive_liquid_request');
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_receive_liquid_request =
+      _frbgen_breez_liquid_cst_new_box_autoadd_receive_liquid_requestPtr
+          .asFunction<ffi.Pointer<wire_cst_receive_liquid_request> Function()>();
+
+  ffi.Pointer<wire_cst_refund_request> frbgen_breez_liquid_cst_new_box_autoadd_refund_request() {
+    return _frbgen_breez_liquid_cst_new_box_autoadd_refund_request();
+  }
+
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_refund_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_refund_request> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_refund_request');
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_refund_request =
+      _frbgen_breez_liquid_cst_new_box_autoadd_refund_requestPtr
+          .asFunction<ffi.Pointer<wire_cst_refund_request> Function()>();
+
   ffi.Pointer<wire_cst_restore_request> frbgen_breez_liquid_cst_new_box_autoadd_restore_request() {
     return _frbgen_breez_liquid_cst_new_box_autoadd_restore_request();
   }
@@ -489,6 +1164,55 @@ class FlutterBreezLiquidBindings {
       _frbgen_breez_liquid_cst_new_box_autoadd_restore_requestPtr
           .asFunction<ffi.Pointer<wire_cst_restore_request> Function()>();
 
+  ffi.Pointer<wire_cst_set_payment_metadata_request>
+      frbgen_breez_liquid_cst_new_box_autoadd_set_payment_metadata_request() {
+    return _frbgen_breez_liquid_cst_new_box_autoadd_set_payment_metadata_request();
+  }
+
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_set_payment_metadata_requestPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_set_payment_metadata_request> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_set_payment_metadata_request');
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_set_payment_metadata_request =
+      _frbgen_breez_liquid_cst_new_box_autoadd_set_payment_metadata_requestPtr
+          .asFunction<ffi.Pointer<wire_cst_set_payment_metadata_request> Function()>();
+
+  ffi.Pointer<wire_cst_success_action> frbgen_breez_liquid_cst_new_box_autoadd_success_action() {
+    return _frbgen_breez_liquid_cst_new_box_autoadd_success_action();
+  }
+
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_success_actionPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_success_action> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_success_action');
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_success_action =
+      _frbgen_breez_liquid_cst_new_box_autoadd_success_actionPtr
+          .asFunction<ffi.Pointer<wire_cst_success_action> Function()>();
+
+  ffi.Pointer<wire_cst_success_action_processed>
+      frbgen_breez_liquid_cst_new_box_autoadd_success_action_processed() {
+    return _frbgen_breez_liquid_cst_new_box_autoadd_success_action_processed();
+  }
+
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_success_action_processedPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_success_action_processed> Function()>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_success_action_processed');
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_success_action_processed =
+      _frbgen_breez_liquid_cst_new_box_autoadd_success_action_processedPtr
+          .asFunction<ffi.Pointer<wire_cst_success_action_processed> Function()>();
+
+  ffi.Pointer<ffi.Uint32> frbgen_breez_liquid_cst_new_box_autoadd_u_32(
+    int value,
+  ) {
+    return _frbgen_breez_liquid_cst_new_box_autoadd_u_32(
+      value,
+    );
+  }
+
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_u_32Ptr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Uint32> Function(ffi.Uint32)>>(
+          'frbgen_breez_liquid_cst_new_box_autoadd_u_32');
+  late final _frbgen_breez_liquid_cst_new_box_autoadd_u_32 =
+      _frbgen_breez_liquid_cst_new_box_autoadd_u_32Ptr.asFunction<ffi.Pointer<ffi.Uint32> Function(int)>();
+
   ffi.Pointer<ffi.Uint64> frbgen_breez_liquid_cst_new_box_autoadd_u_64(
     int value,
   ) {
@@ -503,6 +1227,66 @@ class FlutterBreezLiquidBindings {
   late final _frbgen_breez_liquid_cst_new_box_autoadd_u_64 =
       _frbgen_breez_liquid_cst_new_box_autoadd_u_64Ptr.asFunction<ffi.Pointer<ffi.Uint64> Function(int)>();
 
+  ffi.Pointer<wire_cst_list_asset_balance> frbgen_breez_liquid_cst_new_list_asset_balance(
+    int len,
+  ) {
+    return _frbgen_breez_liquid_cst_new_list_asset_balance(
+      len,
+    );
+  }
+
+  late final _frbgen_breez_liquid_cst_new_list_asset_balancePtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_asset_balance> Function(ffi.Int32)>>(
+          'frbgen_breez_liquid_cst_new_list_asset_balance');
+  late final _frbgen_breez_liquid_cst_new_list_asset_balance =
+      _frbgen_breez_liquid_cst_new_list_asset_balancePtr
+          .asFunction<ffi.Pointer<wire_cst_list_asset_balance> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_asset_metadata> frbgen_breez_liquid_cst_new_list_asset_metadata(
+    int len,
+  ) {
+    return _frbgen_breez_liquid_cst_new_list_asset_metadata(
+      len,
+    );
+  }
+
+  late final _frbgen_breez_liquid_cst_new_list_asset_metadataPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_asset_metadata> Function(ffi.Int32)>>(
+          'frbgen_breez_liquid_cst_new_list_asset_metadata');
+  late final _frbgen_breez_liquid_cst_new_list_asset_metadata =
+      _frbgen_breez_liquid_cst_new_list_asset_metadataPtr
+          .asFunction<ffi.Pointer<wire_cst_list_asset_metadata> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_electrum_server> frbgen_breez_liquid_cst_new_list_electrum_server(
+    int len,
+  ) {
+    return _frbgen_breez_liquid_cst_new_list_electrum_server(
+      len,
+    );
+  }
+
+  late final _frbgen_breez_liquid_cst_new_list_electrum_serverPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_electrum_server> Function(ffi.Int32)>>(
+          'frbgen_breez_liquid_cst_new_list_electrum_server');
+  late final _frbgen_breez_liquid_cst_new_list_electrum_server =
+      _frbgen_breez_liquid_cst_new_list_electrum_serverPtr
+          .asFunction<ffi.Pointer<wire_cst_list_electrum_server> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_electrum_server_status> frbgen_breez_liquid_cst_new_list_electrum_server_status(
+    int len,
+  ) {
+    return _frbgen_breez_liquid_cst_new_list_electrum_server_status(
+      len,
+    );
+  }
+
+  late final _frbgen_breez_liquid_cst_new_list_electrum_server_statusPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_electrum_server_status> Function(ffi.Int32)>>(
+          'frbgen_breez_liquid_cst_new_list_electrum_server_status');
+  late final _frbgen_breez_liquid_cst_new_list_electrum_server_status =
+      _frbgen_breez_liquid_cst_new_list_electrum_server_statusPtr
+          .asFunction<ffi.Pointer<wire_cst_list_electrum_server_status> Function(int)>();
+
   ffi.Pointer<wire_cst_list_payment> frbgen_breez_liquid_cst_new_list_payment(
     int len,
   ) {
@@ -517,6 +1301,36 @@ class FlutterBreezLiquidBindings {
   late final _frbgen_breez_liquid_cst_new_list_payment = _frbgen_breez_liquid_cst_new_list_paymentPtr
       .asFunction<ffi.Pointer<wire_cst_list_payment> Function(int)>();
 
+  ffi.Pointer<wire_cst_list_payment_state> frbgen_breez_liquid_cst_new_list_payment_state(
+    int len,
+  ) {
+    return _frbgen_breez_liquid_cst_new_list_payment_state(
+      len,
+    );
+  }
+
+  late final _frbgen_breez_liquid_cst_new_list_payment_statePtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_payment_state> Function(ffi.Int32)>>(
+          'frbgen_breez_liquid_cst_new_list_payment_state');
+  late final _frbgen_breez_liquid_cst_new_list_payment_state =
+      _frbgen_breez_liquid_cst_new_list_payment_statePtr
+          .asFunction<ffi.Pointer<wire_cst_list_payment_state> Function(int)>();
+
+  ffi.Pointer<wire_cst_list_payment_type> frbgen_breez_liquid_cst_new_list_payment_type(
+    int len,
+  ) {
+    return _frbgen_breez_liquid_cst_new_list_payment_type(
+      len,
+    );
+  }
+
+  late final _frbgen_breez_liquid_cst_new_list_payment_typePtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_payment_type> Function(ffi.Int32)>>(
+          'frbgen_breez_liquid_cst_new_list_payment_type');
+  late final _frbgen_breez_liquid_cst_new_list_payment_type =
+      _frbgen_breez_liquid_cst_new_list_payment_typePtr
+          .asFunction<ffi.Pointer<wire_cst_list_payment_type> Function(int)>();
+
   ffi.Pointer<wire_cst_list_prim_u_8_strict> frbgen_breez_liquid_cst_new_list_prim_u_8_strict(
     int len,
   ) {
@@ -532,6 +1346,21 @@ class FlutterBreezLiquidBindings {
       _frbgen_breez_liquid_cst_new_list_prim_u_8_strictPtr
           .asFunction<ffi.Pointer<wire_cst_list_prim_u_8_strict> Function(int)>();
 
+  ffi.Pointer<wire_cst_list_refundable_swap> frbgen_breez_liquid_cst_new_list_refundable_swap(
+    int len,
+  ) {
+    return _frbgen_breez_liquid_cst_new_list_refundable_swap(
+      len,
+    );
+  }
+
+  late final _frbgen_breez_liquid_cst_new_list_refundable_swapPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<wire_cst_list_refundable_swap> Function(ffi.Int32)>>(
+          'frbgen_breez_liquid_cst_new_list_refundable_swap');
+  late final _frbgen_breez_liquid_cst_new_list_refundable_swap =
+      _frbgen_breez_liquid_cst_new_list_refundable_swapPtr
+          .asFunction<ffi.Pointer<wire_cst_list_refundable_swap> Function(int)>();
+
   ffi.Pointer<wire_cst_list_route_hint> frbgen_breez_liquid_cst_new_list_route_hint(
     int len,
   ) {
@@ -600,206 +1429,620 @@ final class wire_cst_backup_request extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> backup_path;
 }
 
-final class wire_cst_prepare_receive_request extends ffi.Struct {
-  @ffi.Uint64()
-  external int payer_amount_sat;
-}
-
-final class wire_cst_prepare_send_request extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> invoice;
-}
-
-final class wire_cst_prepare_receive_response extends ffi.Struct {
-  @ffi.Uint64()
-  external int payer_amount_sat;
-
-  @ffi.Uint64()
-  external int fees_sat;
-}
+final class wire_cst_list_payment_type extends ffi.Struct {
+  external ffi.Pointer<ffi.Int32> ptr;
 
-final class wire_cst_restore_request extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> backup_path;
+  @ffi.Int32()
+  external int len;
 }
 
-final class wire_cst_prepare_send_response extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> invoice;
-
-  @ffi.Uint64()
-  external int fees_sat;
-}
+final class wire_cst_list_payment_state extends ffi.Struct {
+  external ffi.Pointer<ffi.Int32> ptr;
 
-final class wire_cst_binding_event_listener extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> stream;
+  @ffi.Int32()
+  external int len;
 }
 
-final class wire_cst_payment extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> tx_id;
-
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_id;
+final class wire_cst_list_payments_request extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_payment_type> payment_types;
 
-  @ffi.Uint32()
-  external int timestamp;
+  external ffi.Pointer<wire_cst_list_payment_state> states;
 
-  @ffi.Uint64()
-  external int amount_sat;
+  external ffi.Pointer<ffi.Uint32> from_timestamp;
 
-  @ffi.Uint64()
-  external int fees_sat;
+  external ffi.Pointer<ffi.Uint32> to_timestamp;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> preimage;
+  external ffi.Pointer<ffi.Uint32> offset;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> refund_tx_id;
+  external ffi.Pointer<ffi.Uint32> limit;
 
-  external ffi.Pointer<ffi.Uint64> refund_tx_amount_sat;
+  external ffi.Pointer<ffi.Bool> sort_descending;
 
-  @ffi.Int32()
-  external int payment_type;
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> metadata_search;
+}
 
+final class wire_cst_export_payments_request extends ffi.Struct {
   @ffi.Int32()
-  external int status;
-}
+  external int format;
 
-final class wire_cst_LiquidSdkEvent_PaymentFailed extends ffi.Struct {
-  external ffi.Pointer<wire_cst_payment> details;
-}
+  external wire_cst_list_payments_request filter;
 
-final class wire_cst_LiquidSdkEvent_PaymentPending extends ffi.Struct {
-  external ffi.Pointer<wire_cst_payment> details;
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> path;
 }
 
-final class wire_cst_LiquidSdkEvent_PaymentRefunded extends ffi.Struct {
-  external ffi.Pointer<wire_cst_payment> details;
+final class wire_cst_GetPaymentRequest_TxId extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> tx_id;
 }
 
-final class wire_cst_LiquidSdkEvent_PaymentRefundPending extends ffi.Struct {
-  external ffi.Pointer<wire_cst_payment> details;
+final class wire_cst_GetPaymentRequest_SwapId extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_id;
 }
 
-final class wire_cst_LiquidSdkEvent_PaymentSucceeded extends ffi.Struct {
-  external ffi.Pointer<wire_cst_payment> details;
+final class wire_cst_GetPaymentRequest_Bolt11 extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> bolt11;
 }
 
-final class wire_cst_LiquidSdkEvent_PaymentWaitingConfirmation extends ffi.Struct {
-  external ffi.Pointer<wire_cst_payment> details;
+final class wire_cst_GetPaymentRequest_PaymentHash extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> payment_hash;
 }
 
-final class LiquidSdkEventKind extends ffi.Union {
-  external wire_cst_LiquidSdkEvent_PaymentFailed PaymentFailed;
-
-  external wire_cst_LiquidSdkEvent_PaymentPending PaymentPending;
-
-  external wire_cst_LiquidSdkEvent_PaymentRefunded PaymentRefunded;
+final class GetPaymentRequestKind extends ffi.Union {
+  external wire_cst_GetPaymentRequest_TxId TxId;
 
-  external wire_cst_LiquidSdkEvent_PaymentRefundPending PaymentRefundPending;
+  external wire_cst_GetPaymentRequest_SwapId SwapId;
 
-  external wire_cst_LiquidSdkEvent_PaymentSucceeded PaymentSucceeded;
+  external wire_cst_GetPaymentRequest_Bolt11 Bolt11;
 
-  external wire_cst_LiquidSdkEvent_PaymentWaitingConfirmation PaymentWaitingConfirmation;
+  external wire_cst_GetPaymentRequest_PaymentHash PaymentHash;
 }
 
-final class wire_cst_liquid_sdk_event extends ffi.Struct {
+final class wire_cst_get_payment_request extends ffi.Struct {
   @ffi.Int32()
   external int tag;
 
-  external LiquidSdkEventKind kind;
+  external GetPaymentRequestKind kind;
 }
 
-final class wire_cst_config extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> boltz_url;
+final class wire_cst_payment_metadata extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> label;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> electrum_url;
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> note;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> working_dir;
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> external_id;
+}
 
-  @ffi.Int32()
-  external int network;
+final class wire_cst_fee_breakdown extends ffi.Struct {
+  @ffi.Double()
+  external double service_fee_percentage;
 
   @ffi.Uint64()
-  external int payment_timeout_sec;
+  external int service_fee_sat;
 
-  @ffi.Float()
-  external double zero_conf_min_fee_rate;
+  @ffi.Uint64()
+  external int swapper_miner_fees_sat;
 
-  external ffi.Pointer<ffi.Uint64> zero_conf_max_amount_sat;
+  @ffi.Uint64()
+  external int network_fee_sat;
+
+  @ffi.Uint64()
+  external int total_fees_sat;
 }
 
-final class wire_cst_connect_request extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> mnemonic;
+final class wire_cst_prepare_send_response extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> invoice;
 
-  external wire_cst_config config;
-}
+  @ffi.Uint64()
+  external int fees_sat;
 
-final class wire_cst_list_payment extends ffi.Struct {
-  external ffi.Pointer<wire_cst_payment> ptr;
+  external ffi.Pointer<wire_cst_payment_metadata> metadata;
 
-  @ffi.Int32()
-  external int len;
-}
+  external wire_cst_fee_breakdown fee_breakdown;
 
-final class wire_cst_route_hint_hop extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> src_node_id;
+  external ffi.Pointer<ffi.Uint64> max_fee_sat;
 
-  @ffi.Uint64()
-  external int short_channel_id;
+  external ffi.Pointer<ffi.Double> max_fee_percent;
 
-  @ffi.Uint32()
-  external int fees_base_msat;
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> pair_hash;
 
   @ffi.Uint32()
-  external int fees_proportional_millionths;
+  external int expires_at;
 
-  @ffi.Uint64()
-  external int cltv_expiry_delta;
-
-  external ffi.Pointer<ffi.Uint64> htlc_minimum_msat;
+  external ffi.Pointer<ffi.Double> fee_rate_sat_per_vbyte;
+}
 
-  external ffi.Pointer<ffi.Uint64> htlc_maximum_msat;
+final class wire_cst_SuccessAction_Message extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> message;
 }
 
-final class wire_cst_list_route_hint_hop extends ffi.Struct {
-  external ffi.Pointer<wire_cst_route_hint_hop> ptr;
+final class wire_cst_SuccessAction_Url extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> description;
 
-  @ffi.Int32()
-  external int len;
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> url;
 }
 
-final class wire_cst_route_hint extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_route_hint_hop> hops;
-}
+final class wire_cst_SuccessAction_Aes extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> description;
 
-final class wire_cst_list_route_hint extends ffi.Struct {
-  external ffi.Pointer<wire_cst_route_hint> ptr;
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> ciphertext;
 
-  @ffi.Int32()
-  external int len;
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> iv;
 }
 
-final class wire_cst_get_info_response extends ffi.Struct {
-  @ffi.Uint64()
-  external int balance_sat;
-
-  @ffi.Uint64()
-  external int pending_send_sat;
+final class SuccessActionKind extends ffi.Union {
+  external wire_cst_SuccessAction_Message Message;
 
-  @ffi.Uint64()
-  external int pending_receive_sat;
+  external wire_cst_SuccessAction_Url Url;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> pubkey;
+  external wire_cst_SuccessAction_Aes Aes;
 }
 
-final class wire_cst_LiquidSdkError_Generic extends ffi.Struct {
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+final class wire_cst_success_action extends ffi.Struct {
+  @ffi.Int32()
+  external int tag;
+
+  external SuccessActionKind kind;
+}
+
+final class wire_cst_prepare_ln_url_pay_response extends ffi.Struct {
+  external wire_cst_prepare_send_response prepare_send_response;
+
+  external ffi.Pointer<wire_cst_success_action> success_action;
+}
+
+final class wire_cst_ln_url_withdraw_request extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> lnurl;
+
+  @ffi.Uint64()
+  external int amount_sat;
+}
+
+final class wire_cst_prepare_pay_onchain_response extends ffi.Struct {
+  @ffi.Uint64()
+  external int receiver_amount_sat;
+
+  @ffi.Uint64()
+  external int fees_sat;
+
+  external ffi.Pointer<ffi.Double> fee_rate_sat_per_vbyte;
+}
+
+final class wire_cst_pay_onchain_request extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> address;
+
+  external wire_cst_prepare_pay_onchain_response prepare_res;
+}
+
+final class wire_cst_prepare_ln_url_pay_request extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> lnurl;
+
+  @ffi.Uint64()
+  external int amount_sat;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> comment;
+}
+
+final class wire_cst_OnchainFeeRate_SatPerVbyte extends ffi.Struct {
+  @ffi.Double()
+  external double sat_per_vbyte;
+}
+
+final class wire_cst_OnchainFeeRate_Priority extends ffi.Struct {
+  @ffi.Int32()
+  external int priority;
+}
+
+final class OnchainFeeRateKind extends ffi.Union {
+  external wire_cst_OnchainFeeRate_SatPerVbyte SatPerVbyte;
+
+  external wire_cst_OnchainFeeRate_Priority Priority;
+}
+
+final class wire_cst_onchain_fee_rate extends ffi.Struct {
+  @ffi.Int32()
+  external int tag;
+
+  external OnchainFeeRateKind kind;
+}
+
+final class wire_cst_prepare_pay_onchain_request extends ffi.Struct {
+  @ffi.Uint64()
+  external int receiver_amount_sat;
+
+  external ffi.Pointer<wire_cst_onchain_fee_rate> fee_rate;
+}
+
+final class wire_cst_prepare_receive_onchain_request extends ffi.Struct {
+  @ffi.Uint64()
+  external int payer_amount_sat;
+
+  external ffi.Pointer<wire_cst_onchain_fee_rate> fee_rate;
+}
+
+final class wire_cst_prepare_receive_request extends ffi.Struct {
+  external ffi.Pointer<ffi.Uint64> payer_amount_sat;
+
+  external ffi.Pointer<ffi.Uint64> receiver_amount_sat;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> description;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> description_hash;
+
+  external ffi.Pointer<ffi.Uint64> invoice_expiry_secs;
+
+  external ffi.Pointer<wire_cst_payment_metadata> metadata;
+
+  external ffi.Pointer<ffi.Uint64> max_fee_sat;
+
+  external ffi.Pointer<ffi.Double> max_fee_percent;
+
+  external ffi.Pointer<wire_cst_onchain_fee_rate> fee_rate;
+}
+
+final class wire_cst_prepare_refund_request extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> refund_address;
+
+  external wire_cst_onchain_fee_rate fee_rate;
+}
+
+final class wire_cst_prepare_send_onchain_request extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> destination;
+
+  external ffi.Pointer<ffi.Uint64> amount_sat;
+
+  @ffi.Bool()
+  external bool send_all;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> asset_id;
+
+  external ffi.Pointer<wire_cst_onchain_fee_rate> fee_rate;
+}
+
+final class wire_cst_prepare_send_request extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> invoice;
+
+  external ffi.Pointer<wire_cst_payment_metadata> metadata;
+
+  external ffi.Pointer<ffi.Uint64> max_fee_sat;
+
+  external ffi.Pointer<ffi.Double> max_fee_percent;
+
+  external ffi.Pointer<wire_cst_onchain_fee_rate> fee_rate;
+}
+
+final class wire_cst_receive_liquid_request extends ffi.Struct {
+  external ffi.Pointer<ffi.Uint64> amount_sat;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> label;
+}
+
+final class wire_cst_prepare_receive_onchain_response extends ffi.Struct {
+  @ffi.Uint64()
+  external int payer_amount_sat;
+
+  @ffi.Uint64()
+  external int fees_sat;
+
+  external ffi.Pointer<ffi.Double> fee_rate_sat_per_vbyte;
+}
+
+final class wire_cst_prepare_receive_response extends ffi.Struct {
+  @ffi.Uint64()
+  external int payer_amount_sat;
+
+  @ffi.Uint64()
+  external int receiver_amount_sat;
+
+  @ffi.Uint64()
+  external int fees_sat;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> description;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> description_hash;
+
+  external ffi.Pointer<ffi.Uint64> invoice_expiry_secs;
+
+  external ffi.Pointer<wire_cst_payment_metadata> metadata;
+
+  external wire_cst_fee_breakdown fee_breakdown;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> pair_hash;
+
+  @ffi.Uint32()
+  external int expires_at;
+
+  external ffi.Pointer<ffi.Double> fee_rate_sat_per_vbyte;
+}
+
+final class wire_cst_refund_request extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> refund_address;
+
+  @ffi.Double()
+  external double fee_rate_sat_per_vbyte;
+}
+
+final class wire_cst_restore_request extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> backup_path;
+}
+
+final class wire_cst_prepare_send_onchain_response extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> address;
+
+  @ffi.Uint64()
+  external int receiver_amount_sat;
+
+  @ffi.Uint64()
+  external int fees_sat;
+
+  @ffi.Bool()
+  external bool send_all;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> asset_id;
+
+  external ffi.Pointer<ffi.Double> fee_rate_sat_per_vbyte;
+}
+
+final class wire_cst_set_payment_metadata_request extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> id;
+
+  external wire_cst_payment_metadata metadata;
+}
+
+final class wire_cst_binding_event_listener extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> stream;
+}
+
+final class wire_cst_payment extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> tx_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_id;
+
+  @ffi.Int32()
+  external int swap_type;
+
+  @ffi.Uint32()
+  external int timestamp;
+
+  @ffi.Uint64()
+  external int amount_sat;
+
+  @ffi.Uint64()
+  external int fees_sat;
+
+  external ffi.Pointer<ffi.Uint64> network_fees_sat;
+
+  external ffi.Pointer<wire_cst_fee_breakdown> fee_breakdown;
+
+  external ffi.Pointer<ffi.Double> fee_rate_sat_per_vbyte;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> preimage;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> bolt11;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> payment_hash;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> payee_pubkey;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> lockup_tx_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> claim_tx_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> refund_tx_id;
+
+  external ffi.Pointer<ffi.Uint64> refund_tx_amount_sat;
+
+  @ffi.Int32()
+  external int payment_type;
+
+  @ffi.Int32()
+  external int status;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> label;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> description;
+
+  external ffi.Pointer<wire_cst_payment_metadata> metadata;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> asset_id;
+
+  external ffi.Pointer<ffi.Uint64> asset_amount;
+}
+
+final class wire_cst_LiquidSdkEvent_PaymentFailed extends ffi.Struct {
+  external ffi.Pointer<wire_cst_payment> details;
+}
+
+final class wire_cst_LiquidSdkEvent_PaymentPending extends ffi.Struct {
+  external ffi.Pointer<wire_cst_payment> details;
+}
+
+final class wire_cst_LiquidSdkEvent_PaymentRefunded extends ffi.Struct {
+  external ffi.Pointer<wire_cst_payment> details;
+}
+
+final class wire_cst_LiquidSdkEvent_PaymentRefundable extends ffi.Struct {
+  external ffi.Pointer<wire_cst_payment> details;
+}
+
+final class wire_cst_LiquidSdkEvent_PaymentRefundPending extends ffi.Struct {
+  external ffi.Pointer<wire_cst_payment> details;
+}
+
+final class wire_cst_LiquidSdkEvent_PaymentSucceeded extends ffi.Struct {
+  external ffi.Pointer<wire_cst_payment> details;
+}
+
+final class wire_cst_LiquidSdkEvent_PaymentWaitingConfirmation extends ffi.Struct {
+  external ffi.Pointer<wire_cst_payment> details;
+}
+
+final class LiquidSdkEventKind extends ffi.Union {
+  external wire_cst_LiquidSdkEvent_PaymentFailed PaymentFailed;
+
+  external wire_cst_LiquidSdkEvent_PaymentPending PaymentPending;
+
+  external wire_cst_LiquidSdkEvent_PaymentRefunded PaymentRefunded;
+
+  external wire_cst_LiquidSdkEvent_PaymentRefundable PaymentRefundable;
+
+  external wire_cst_LiquidSdkEvent_PaymentRefundPending PaymentRefundPending;
+
+  external wire_cst_LiquidSdkEvent_PaymentSucceeded PaymentSucceeded;
+
+  external wire_cst_LiquidSdkEvent_PaymentWaitingConfirmation PaymentWaitingConfirmation;
+}
+
+final class wire_cst_liquid_sdk_event extends ffi.Struct {
+  @ffi.Int32()
+  external int tag;
+
+  external LiquidSdkEventKind kind;
+}
+
+final class wire_cst_electrum_server extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> url;
+
+  @ffi.Bool()
+  external bool tls;
+
+  @ffi.Bool()
+  external bool validate_domain;
+}
+
+final class wire_cst_list_electrum_server extends ffi.Struct {
+  external ffi.Pointer<wire_cst_electrum_server> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_asset_metadata extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> asset_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> ticker;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> name;
+
+  @ffi.Uint8()
+  external int precision;
+}
+
+final class wire_cst_list_asset_metadata extends ffi.Struct {
+  external ffi.Pointer<wire_cst_asset_metadata> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_config extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> boltz_url;
+
+  external ffi.Pointer<wire_cst_list_electrum_server> electrum_servers;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> electrum_url;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> bitcoin_electrum_url;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> esplora_url;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> working_dir;
+
+  @ffi.Int32()
+  external int network;
+
+  @ffi.Uint64()
+  external int payment_timeout_sec;
+
+  @ffi.Uint64()
+  external int sync_fallback_interval_sec;
+
+  @ffi.Float()
+  external double zero_conf_min_fee_rate;
+
+  external ffi.Pointer<ffi.Uint64> zero_conf_max_amount_sat;
+
+  external ffi.Pointer<wire_cst_list_asset_metadata> asset_metadata;
+
+  external ffi.Pointer<ffi.Uint64> max_fee_sat;
+
+  external ffi.Pointer<ffi.Double> max_fee_percent;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> regtest_policy_asset;
 }
 
-final class LiquidSdkErrorKind extends ffi.Union {
-  external wire_cst_LiquidSdkError_Generic Generic;
+final class wire_cst_connect_request extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> mnemonic;
+
+  external wire_cst_config config;
 }
 
-final class wire_cst_liquid_sdk_error extends ffi.Struct {
+final class wire_cst_bitcoin_address_data extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> address;
+
   @ffi.Int32()
-  external int tag;
+  external int network;
 
-  external LiquidSdkErrorKind kind;
+  external ffi.Pointer<ffi.Uint64> amount_sat;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> label;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> message;
+}
+
+final class wire_cst_liquid_address_data extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> address;
+
+  @ffi.Int32()
+  external int network;
+
+  external ffi.Pointer<ffi.Uint64> amount_sat;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> asset_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> label;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> message;
+}
+
+final class wire_cst_route_hint_hop extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> src_node_id;
+
+  @ffi.Uint64()
+  external int short_channel_id;
+
+  @ffi.Uint32()
+  external int fees_base_msat;
+
+  @ffi.Uint32()
+  external int fees_proportional_millionths;
+
+  @ffi.Uint64()
+  external int cltv_expiry_delta;
+
+  external ffi.Pointer<ffi.Uint64> htlc_minimum_msat;
+
+  external ffi.Pointer<ffi.Uint64> htlc_maximum_msat;
+}
+
+final class wire_cst_list_route_hint_hop extends ffi.Struct {
+  external ffi.Pointer<wire_cst_route_hint_hop> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_route_hint extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_route_hint_hop> hops;
+}
+
+final class wire_cst_list_route_hint extends ffi.Struct {
+  external ffi.Pointer<wire_cst_route_hint> ptr;
+
+  @ffi.Int32()
+  external int len;
 }
 
 final class wire_cst_ln_invoice extends ffi.Struct {
@@ -832,16 +2075,254 @@ final class wire_cst_ln_invoice extends ffi.Struct {
   external int min_final_cltv_expiry_delta;
 }
 
+final class wire_cst_SuccessActionProcessed_Message extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> message;
+}
+
+final class wire_cst_SuccessActionProcessed_Url extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> description;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> url;
+}
+
+final class wire_cst_SuccessActionProcessed_Aes extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> description;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> plaintext;
+}
+
+final class SuccessActionProcessedKind extends ffi.Union {
+  external wire_cst_SuccessActionProcessed_Message Message;
+
+  external wire_cst_SuccessActionProcessed_Url Url;
+
+  external wire_cst_SuccessActionProcessed_Aes Aes;
+}
+
+final class wire_cst_success_action_processed extends ffi.Struct {
+  @ffi.Int32()
+  external int tag;
+
+  external SuccessActionProcessedKind kind;
+}
+
+final class wire_cst_asset_balance extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> asset_id;
+
+  @ffi.Uint64()
+  external int balance;
+
+  external ffi.Pointer<wire_cst_asset_metadata> asset_metadata;
+}
+
+final class wire_cst_list_asset_balance extends ffi.Struct {
+  external ffi.Pointer<wire_cst_asset_balance> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_electrum_server_status extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> url;
+
+  @ffi.Bool()
+  external bool in_use;
+
+  @ffi.Bool()
+  external bool healthy;
+
+  @ffi.Uint32()
+  external int consecutive_failures;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> last_error;
+}
+
+final class wire_cst_list_electrum_server_status extends ffi.Struct {
+  external ffi.Pointer<wire_cst_electrum_server_status> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_list_payment extends ffi.Struct {
+  external ffi.Pointer<wire_cst_payment> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_refundable_swap extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> swap_address;
+
+  @ffi.Uint32()
+  external int timestamp;
+
+  @ffi.Uint64()
+  external int amount_sat;
+
+  @ffi.Int32()
+  external int payment_type;
+}
+
+final class wire_cst_list_refundable_swap extends ffi.Struct {
+  external ffi.Pointer<wire_cst_refundable_swap> ptr;
+
+  @ffi.Int32()
+  external int len;
+}
+
+final class wire_cst_export_payments_response extends ffi.Struct {
+  @ffi.Uint32()
+  external int payments_count;
+}
+
+final class wire_cst_get_info_response extends ffi.Struct {
+  @ffi.Uint64()
+  external int balance_sat;
+
+  @ffi.Uint64()
+  external int pending_send_sat;
+
+  @ffi.Uint64()
+  external int pending_receive_sat;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> pubkey;
+
+  external ffi.Pointer<wire_cst_list_asset_balance> asset_balances;
+}
+
+final class wire_cst_InputType_Bolt11 extends ffi.Struct {
+  external ffi.Pointer<wire_cst_ln_invoice> invoice;
+}
+
+final class wire_cst_InputType_LiquidAddress extends ffi.Struct {
+  external ffi.Pointer<wire_cst_liquid_address_data> address;
+}
+
+final class wire_cst_InputType_BitcoinAddress extends ffi.Struct {
+  external ffi.Pointer<wire_cst_bitcoin_address_data> address;
+}
+
+final class wire_cst_InputType_LnUrl extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> url;
+}
+
+final class wire_cst_InputType_LightningAddress extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> address;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> url;
+}
+
+final class InputTypeKind extends ffi.Union {
+  external wire_cst_InputType_Bolt11 Bolt11;
+
+  external wire_cst_InputType_LiquidAddress LiquidAddress;
+
+  external wire_cst_InputType_BitcoinAddress BitcoinAddress;
+
+  external wire_cst_InputType_LnUrl LnUrl;
+
+  external wire_cst_InputType_LightningAddress LightningAddress;
+}
+
+final class wire_cst_input_type extends ffi.Struct {
+  @ffi.Int32()
+  external int tag;
+
+  external InputTypeKind kind;
+}
+
+final class wire_cst_lightning_limits extends ffi.Struct {
+  @ffi.Uint64()
+  external int min_sat;
+
+  @ffi.Uint64()
+  external int max_sat;
+
+  @ffi.Double()
+  external double service_fee_percentage;
+
+  @ffi.Uint64()
+  external int miner_fees_sat;
+}
+
+final class wire_cst_lightning_payment_limits_response extends ffi.Struct {
+  external wire_cst_lightning_limits send;
+
+  external wire_cst_lightning_limits receive;
+}
+
+final class wire_cst_LiquidSdkError_Generic extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+}
+
+final class LiquidSdkErrorKind extends ffi.Union {
+  external wire_cst_LiquidSdkError_Generic Generic;
+}
+
+final class wire_cst_liquid_sdk_error extends ffi.Struct {
+  @ffi.Int32()
+  external int tag;
+
+  external LiquidSdkErrorKind kind;
+}
+
+final class wire_cst_ln_url_pay_result extends ffi.Struct {
+  external wire_cst_payment payment;
+
+  external ffi.Pointer<wire_cst_success_action_processed> success_action;
+}
+
+final class wire_cst_receive_payment_response extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> id;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> invoice;
+}
+
+final class wire_cst_ln_url_withdraw_result extends ffi.Struct {
+  external wire_cst_receive_payment_response receive_payment_response;
+}
+
 final class wire_cst_log_entry extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> line;
 
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> level;
 }
 
+final class wire_cst_PaymentError_UnrecognizedInput extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+}
+
+final class wire_cst_PaymentError_NetworkMismatch extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+}
+
+final class wire_cst_PaymentError_LnUrlError extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+}
+
+final class wire_cst_PaymentError_LnUrlServiceUnreachable extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+}
+
+final class wire_cst_PaymentError_LnUrlServiceError extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
+}
+
 final class wire_cst_PaymentError_Generic extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
 }
 
+final class wire_cst_PaymentError_FeeLimitExceeded extends ffi.Struct {
+  @ffi.Uint64()
+  external int fee_sat;
+
+  @ffi.Uint64()
+  external int limit_sat;
+}
+
 final class wire_cst_PaymentError_InvalidInvoice extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> err;
 }
@@ -869,8 +2350,20 @@ final class wire_cst_PaymentError_SignerError extends ffi.Struct {
 }
 
 final class PaymentErrorKind extends ffi.Union {
+  external wire_cst_PaymentError_UnrecognizedInput UnrecognizedInput;
+
+  external wire_cst_PaymentError_NetworkMismatch NetworkMismatch;
+
+  external wire_cst_PaymentError_LnUrlError LnUrlError;
+
+  external wire_cst_PaymentError_LnUrlServiceUnreachable LnUrlServiceUnreachable;
+
+  external wire_cst_PaymentError_LnUrlServiceError LnUrlServiceError;
+
   external wire_cst_PaymentError_Generic Generic;
 
+  external wire_cst_PaymentError_FeeLimitExceeded FeeLimitExceeded;
+
   external wire_cst_PaymentError_InvalidInvoice InvalidInvoice;
 
   external wire_cst_PaymentError_LwkError LwkError;
@@ -891,10 +2384,59 @@ final class wire_cst_payment_error extends ffi.Struct {
   external PaymentErrorKind kind;
 }
 
-final class wire_cst_receive_payment_response extends ffi.Struct {
+final class wire_cst_prepare_refund_response extends ffi.Struct {
+  @ffi.Uint32()
+  external int tx_vsize;
+
+  @ffi.Uint64()
+  external int tx_fee_sat;
+
+  @ffi.Uint64()
+  external int refund_amount_sat;
+
+  @ffi.Double()
+  external double fee_rate_sat_per_vbyte;
+}
+
+final class wire_cst_receive_liquid_response extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> address;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> bip21;
+}
+
+final class wire_cst_receive_onchain_response extends ffi.Struct {
   external ffi.Pointer<wire_cst_list_prim_u_8_strict> id;
 
-  external ffi.Pointer<wire_cst_list_prim_u_8_strict> invoice;
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> address;
+
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> bip21;
+}
+
+final class wire_cst_recommended_fees extends ffi.Struct {
+  @ffi.Double()
+  external double fastest_fee;
+
+  @ffi.Double()
+  external double half_hour_fee;
+
+  @ffi.Double()
+  external double hour_fee;
+
+  @ffi.Double()
+  external double economy_fee;
+
+  @ffi.Double()
+  external double minimum_fee;
+}
+
+final class wire_cst_recommended_fees_response extends ffi.Struct {
+  external wire_cst_recommended_fees liquid;
+
+  external wire_cst_recommended_fees bitcoin;
+}
+
+final class wire_cst_refund_response extends ffi.Struct {
+  external ffi.Pointer<wire_cst_list_prim_u_8_strict> refund_tx_id;
 }
 
 final class wire_cst_send_payment_response extends ffi.Struct {